@@ -364,6 +364,18 @@ impl Color32 {
         )
     }
 
+    /// Lerp this color towards `other` by `t` in [`crate::Oklaba`],
+    /// which gives perceptually even gradients.
+    pub fn lerp_oklab(&self, other: Self, t: f32) -> Self {
+        crate::Oklaba::from(*self).lerp(other.into(), t).into()
+    }
+
+    /// Lerp this color towards `other` by `t` in [`crate::Oklcha`],
+    /// which keeps the colors in between as colorful as the ends.
+    pub fn lerp_oklch(&self, other: Self, t: f32) -> Self {
+        crate::Oklcha::from(*self).lerp(other.into(), t).into()
+    }
+
     /// Blend two colors in gamma space, so that `self` is behind the argument.
     pub fn blend(self, on_top: Self) -> Self {
         self.gamma_multiply_u8(255 - on_top.a()) + on_top
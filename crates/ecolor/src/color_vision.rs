@@ -0,0 +1,115 @@
+use crate::{Color32, Rgba};
+
+/// A kind of color vision, for simulating how colors look to people with color blindness.
+///
+/// Uses the matrices from Machado, Oliveira & Fernandes (2009),
+/// "A Physiologically-based Model for Simulation of Color Vision Deficiency", at full severity.
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ColorVision {
+    /// Colors are left as they are.
+    #[default]
+    Normal,
+
+    /// No red cones: red and green are hard to tell apart, and red looks dark.
+    Protanopia,
+
+    /// No green cones: red and green are hard to tell apart.
+    Deuteranopia,
+
+    /// No blue cones: blue and green, and yellow and pink, are hard to tell apart.
+    Tritanopia,
+}
+
+impl ColorVision {
+    pub const ALL: [Self; 4] = [
+        Self::Normal,
+        Self::Protanopia,
+        Self::Deuteranopia,
+        Self::Tritanopia,
+    ];
+
+    /// The matrix to apply to linear RGB, or `None` for [`Self::Normal`].
+    pub fn matrix(self) -> Option<[[f32; 3]; 3]> {
+        match self {
+            Self::Normal => None,
+            Self::Protanopia => Some([
+                [0.152_286, 1.052_583, -0.204_868],
+                [0.114_503, 0.786_281, 0.099_216],
+                [-0.003_882, -0.048_116, 1.051_998],
+            ]),
+            Self::Deuteranopia => Some([
+                [0.367_322, 0.860_646, -0.227_968],
+                [0.280_085, 0.672_501, 0.047_413],
+                [-0.011_820, 0.042_940, 0.968_881],
+            ]),
+            Self::Tritanopia => Some([
+                [1.255_528, -0.076_749, -0.178_779],
+                [-0.078_411, 0.930_809, 0.147_602],
+                [0.004_733, 0.691_367, 0.303_900],
+            ]),
+        }
+    }
+
+    /// How the color looks with this kind of color vision.
+    pub fn simulate(self, color: Color32) -> Color32 {
+        let Some(matrix) = self.matrix() else {
+            return color;
+        };
+        let rgba = Rgba::from(color);
+        // The matrix is linear, so it works on premultiplied colors too:
+        let [r, g, b] = matrix.map(|row| {
+            (row[0] * rgba.r() + row[1] * rgba.g() + row[2] * rgba.b()).clamp(0.0, rgba.a())
+        });
+        Rgba::from_rgba_premultiplied(r, g, b, rgba.a()).into()
+    }
+}
+
+impl std::fmt::Display for ColorVision {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Normal => "Normal",
+            Self::Protanopia => "Protanopia",
+            Self::Deuteranopia => "Deuteranopia",
+            Self::Tritanopia => "Tritanopia",
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn color_vision_simulation() {
+        for vision in ColorVision::ALL {
+            // Grays stay gray:
+            for gray in [Color32::BLACK, Color32::GRAY, Color32::WHITE] {
+                let simulated = vision.simulate(gray);
+                for (a, b) in simulated.to_array().into_iter().zip(gray.to_array()) {
+                    assert!(
+                        a.abs_diff(b) <= 1,
+                        "{vision}: {gray:?} became {simulated:?}"
+                    );
+                }
+            }
+        }
+
+        // Red and green look much the same without red or green cones:
+        let red = Color32::from_rgb(200, 60, 50);
+        let green = Color32::from_rgb(110, 140, 50);
+        let distance = |a: Color32, b: Color32| {
+            let (a, b) = (Rgba::from(a), Rgba::from(b));
+            (a.r() - b.r()).abs() + (a.g() - b.g()).abs() + (a.b() - b.b()).abs()
+        };
+        for vision in [ColorVision::Protanopia, ColorVision::Deuteranopia] {
+            let simulated = distance(vision.simulate(red), vision.simulate(green));
+            assert!(simulated < 0.5 * distance(red, green), "{vision}");
+        }
+
+        let transparent = Color32::from_rgba_premultiplied(20, 0, 0, 40);
+        let simulated = ColorVision::Tritanopia.simulate(transparent);
+        assert_eq!(simulated.a(), 40);
+        assert!(simulated.r() <= 40 && simulated.g() <= 40 && simulated.b() <= 40);
+    }
+}
@@ -6,7 +6,8 @@
 //!
 //! If you want a compact color representation, use [`Color32`].
 //! If you want to manipulate RGBA colors in linear space use [`Rgba`].
-//! If you want to manipulate colors in a way closer to how humans think about colors, use [`HsvaGamma`].
+//! If you want to manipulate colors in a way closer to how humans think about colors, use [`HsvaGamma`],
+//! or [`Oklcha`] for perceptually uniform lightness and colorfulness.
 //!
 //! ## Conventions
 //! The word "gamma" or "srgb" is used to refer to values in the non-linear space defined by
@@ -27,12 +28,18 @@ mod cint_impl;
 mod color32;
 pub use color32::*;
 
+mod color_vision;
+pub use color_vision::*;
+
 mod hsva_gamma;
 pub use hsva_gamma::*;
 
 mod hsva;
 pub use hsva::*;
 
+mod oklab;
+pub use oklab::*;
+
 #[cfg(feature = "color-hex")]
 mod hex_color_macro;
 #[cfg(feature = "color-hex")]
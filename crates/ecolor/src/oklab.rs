@@ -0,0 +1,254 @@
+use crate::{Color32, Rgba};
+
+/// A color in the [Oklab](https://bottosson.github.io/posts/oklab/) color space, with alpha.
+///
+/// Oklab is perceptually uniform: the same change in the numbers looks like the same change in color,
+/// so gradients and color scales made in it look even. No premultiplied alpha.
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Oklaba {
+    /// Perceived lightness, 0-1.
+    pub l: f32,
+
+    /// Green (negative) to red (positive), about -0.4 to 0.4.
+    pub a: f32,
+
+    /// Blue (negative) to yellow (positive), about -0.4 to 0.4.
+    pub b: f32,
+
+    /// Alpha, 0-1.
+    pub alpha: f32,
+}
+
+/// [`Oklaba`] in polar coordinates: lightness, chroma (colorfulness) and hue, with alpha.
+///
+/// The best space to edit colors in by hand, e.g. to make a lighter version of a color
+/// keeping its hue, or several colors with the same lightness.
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Oklcha {
+    /// Perceived lightness, 0-1.
+    pub l: f32,
+
+    /// Chroma, from 0 for gray up to about 0.37 for the most colorful sRGB colors.
+    pub c: f32,
+
+    /// Hue 0-1, starting at pink-red.
+    pub h: f32,
+
+    /// Alpha, 0-1.
+    pub alpha: f32,
+}
+
+impl Oklaba {
+    #[inline]
+    pub fn new(l: f32, a: f32, b: f32, alpha: f32) -> Self {
+        Self { l, a, b, alpha }
+    }
+
+    /// Interpolate in Oklab, which gives more even gradients than in RGB.
+    pub fn lerp(self, other: Self, t: f32) -> Self {
+        use emath::lerp;
+        Self {
+            l: lerp(self.l..=other.l, t),
+            a: lerp(self.a..=other.a, t),
+            b: lerp(self.b..=other.b, t),
+            alpha: lerp(self.alpha..=other.alpha, t),
+        }
+    }
+
+    /// Linear RGB, which may be out of the 0-1 range if the color is outside of the sRGB gamut.
+    pub fn to_linear_rgb(self) -> [f32; 3] {
+        let Self { l, a, b, .. } = self;
+        let l_ = l + 0.396_337_78 * a + 0.215_803_76 * b;
+        let m_ = l - 0.105_561_346 * a - 0.063_854_17 * b;
+        let s_ = l - 0.089_484_18 * a - 1.291_485_5 * b;
+        let (l, m, s) = (l_ * l_ * l_, m_ * m_ * m_, s_ * s_ * s_);
+        [
+            4.076_741_7 * l - 3.307_711_6 * m + 0.230_969_94 * s,
+            -1.268_438 * l + 2.609_757_4 * m - 0.341_319_38 * s,
+            -0.004_196_086_3 * l - 0.703_418_6 * m + 1.707_614_7 * s,
+        ]
+    }
+
+    /// Is the color inside the sRGB gamut, i.e. can it be shown exactly?
+    pub fn is_in_srgb_gamut(self) -> bool {
+        self.to_linear_rgb()
+            .iter()
+            .all(|c| (-1e-4..=1.0 + 1e-4).contains(c))
+    }
+
+    fn from_linear_rgb([r, g, b]: [f32; 3], alpha: f32) -> Self {
+        let l = 0.412_221_46 * r + 0.536_332_55 * g + 0.051_445_995 * b;
+        let m = 0.211_903_5 * r + 0.680_699_5 * g + 0.107_396_96 * b;
+        let s = 0.088_302_46 * r + 0.281_718_85 * g + 0.629_978_7 * b;
+        let (l_, m_, s_) = (l.cbrt(), m.cbrt(), s.cbrt());
+        Self {
+            l: 0.210_454_26 * l_ + 0.793_617_8 * m_ - 0.004_072_047 * s_,
+            a: 1.977_998_5 * l_ - 2.428_592_2 * m_ + 0.450_593_7 * s_,
+            b: 0.025_904_037 * l_ + 0.782_771_77 * m_ - 0.808_675_77 * s_,
+            alpha,
+        }
+    }
+}
+
+impl Oklcha {
+    #[inline]
+    pub fn new(l: f32, c: f32, h: f32, alpha: f32) -> Self {
+        Self { l, c, h, alpha }
+    }
+
+    /// Interpolate in lightness, chroma and hue, going the short way around the hue circle.
+    ///
+    /// Unlike [`Oklaba::lerp`], the colors in between stay as colorful as the ends,
+    /// instead of going through gray for opposite hues.
+    pub fn lerp(self, other: Self, t: f32) -> Self {
+        use emath::lerp;
+        // Gray has no hue, so use the other one:
+        let (h0, h1) = match (self.c == 0.0, other.c == 0.0) {
+            (true, false) => (other.h, other.h),
+            (false, true) => (self.h, self.h),
+            _ => (self.h, other.h),
+        };
+        let dh = (h1 - h0 + 0.5).rem_euclid(1.0) - 0.5;
+        Self {
+            l: lerp(self.l..=other.l, t),
+            c: lerp(self.c..=other.c, t),
+            h: (h0 + t * dh).rem_euclid(1.0),
+            alpha: lerp(self.alpha..=other.alpha, t),
+        }
+    }
+
+    /// Reduce the chroma until the color fits in the sRGB gamut, keeping lightness and hue.
+    pub fn clamp_to_srgb_gamut(self) -> Self {
+        if Oklaba::from(self).is_in_srgb_gamut() {
+            return self;
+        }
+        let (mut low, mut high) = (0.0, self.c);
+        for _ in 0..16 {
+            let mid = 0.5 * (low + high);
+            if Oklaba::from(Self { c: mid, ..self }).is_in_srgb_gamut() {
+                low = mid;
+            } else {
+                high = mid;
+            }
+        }
+        Self { c: low, ..self }
+    }
+}
+
+impl From<Oklaba> for Oklcha {
+    fn from(lab: Oklaba) -> Self {
+        let Oklaba { l, a, b, alpha } = lab;
+        let c = a.hypot(b);
+        let h = if c < 1e-6 {
+            0.0
+        } else {
+            (b.atan2(a) / std::f32::consts::TAU).rem_euclid(1.0)
+        };
+        Self { l, c, h, alpha }
+    }
+}
+
+impl From<Oklcha> for Oklaba {
+    fn from(lch: Oklcha) -> Self {
+        let Oklcha { l, c, h, alpha } = lch;
+        let (sin, cos) = (std::f32::consts::TAU * h).sin_cos();
+        Self {
+            l,
+            a: c * cos,
+            b: c * sin,
+            alpha,
+        }
+    }
+}
+
+impl From<Rgba> for Oklaba {
+    fn from(rgba: Rgba) -> Self {
+        let [r, g, b, a] = rgba.to_rgba_unmultiplied();
+        Self::from_linear_rgb([r, g, b], a)
+    }
+}
+
+impl From<Oklaba> for Rgba {
+    /// Colors outside of the sRGB gamut are clipped.
+    fn from(lab: Oklaba) -> Self {
+        let [r, g, b] = lab.to_linear_rgb().map(|c| c.clamp(0.0, 1.0));
+        Self::from_rgba_unmultiplied(r, g, b, lab.alpha.clamp(0.0, 1.0))
+    }
+}
+
+impl From<Color32> for Oklaba {
+    fn from(srgba: Color32) -> Self {
+        Rgba::from(srgba).into()
+    }
+}
+
+impl From<Oklaba> for Color32 {
+    /// Colors outside of the sRGB gamut are clipped.
+    fn from(lab: Oklaba) -> Self {
+        Rgba::from(lab).into()
+    }
+}
+
+impl From<Rgba> for Oklcha {
+    fn from(rgba: Rgba) -> Self {
+        Oklaba::from(rgba).into()
+    }
+}
+
+impl From<Oklcha> for Rgba {
+    /// Colors outside of the sRGB gamut are clipped, see [`Oklcha::clamp_to_srgb_gamut`].
+    fn from(lch: Oklcha) -> Self {
+        Oklaba::from(lch).into()
+    }
+}
+
+impl From<Color32> for Oklcha {
+    fn from(srgba: Color32) -> Self {
+        Oklaba::from(srgba).into()
+    }
+}
+
+impl From<Oklcha> for Color32 {
+    /// Colors outside of the sRGB gamut are clipped, see [`Oklcha::clamp_to_srgb_gamut`].
+    fn from(lch: Oklcha) -> Self {
+        Oklaba::from(lch).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn oklab_reference_values() {
+        // From https://bottosson.github.io/posts/oklab/
+        let white = Oklaba::from(Color32::WHITE);
+        assert!((white.l - 1.0).abs() < 1e-3 && white.a.abs() < 1e-3 && white.b.abs() < 1e-3);
+        let red = Oklaba::from(Color32::RED);
+        assert!((red.l - 0.628).abs() < 1e-3, "{red:?}");
+        assert!((red.a - 0.225).abs() < 1e-3 && (red.b - 0.126).abs() < 1e-3);
+    }
+
+    #[test]
+    fn oklch_round_trip() {
+        for r in (0..=255).step_by(15) {
+            for b in (0..=255).step_by(15) {
+                let color = Color32::from_rgb(r, 100, b);
+                assert_eq!(Color32::from(Oklcha::from(color)), color);
+            }
+        }
+    }
+
+    #[test]
+    fn oklch_lerp_takes_short_way() {
+        let a = Oklcha::new(0.5, 0.1, 0.9, 1.0);
+        let b = Oklcha::new(0.5, 0.1, 0.1, 1.0);
+        let h = a.lerp(b, 0.5).h;
+        assert!(h < 1e-4 || 1.0 - 1e-4 < h, "{h}");
+
+        let too_colorful = Oklcha::new(0.5, 1.0, 0.3, 1.0);
+        assert!(Oklaba::from(too_colorful.clamp_to_srgb_gamut()).is_in_srgb_gamut());
+    }
+}
@@ -13,12 +13,12 @@ use std::any::Any;
 #[cfg(any(feature = "glow", feature = "wgpu"))]
 pub use crate::native::winit_integration::UserEvent;
 
-#[cfg(not(target_arch = "wasm32"))]
 use raw_window_handle::{
-    DisplayHandle, HandleError, HasDisplayHandle, HasWindowHandle, RawDisplayHandle,
-    RawWindowHandle, WindowHandle,
+    DisplayHandle, HandleError, HasDisplayHandle, HasWindowHandle, WindowHandle,
 };
 #[cfg(not(target_arch = "wasm32"))]
+use raw_window_handle::{RawDisplayHandle, RawWindowHandle};
+#[cfg(not(target_arch = "wasm32"))]
 use static_assertions::assert_not_impl_any;
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -89,6 +89,10 @@ pub struct CreationContext<'s> {
     /// Raw platform display handle for window
     #[cfg(not(target_arch = "wasm32"))]
     pub(crate) raw_display_handle: Result<RawDisplayHandle, HandleError>,
+
+    /// The canvas we are painting to.
+    #[cfg(target_arch = "wasm32")]
+    pub(crate) canvas: Option<&'s wasm_bindgen::JsValue>,
 }
 
 #[expect(unsafe_code)]
@@ -109,6 +113,33 @@ impl HasDisplayHandle for CreationContext<'_> {
     }
 }
 
+/// On web, the window handle is the canvas we are painting to.
+#[cfg(target_arch = "wasm32")]
+impl HasWindowHandle for CreationContext<'_> {
+    fn window_handle(&self) -> Result<WindowHandle<'_>, HandleError> {
+        Ok(canvas_window_handle(
+            self.canvas.ok_or(HandleError::NotSupported)?,
+        ))
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+impl HasDisplayHandle for CreationContext<'_> {
+    fn display_handle(&self) -> Result<DisplayHandle<'_>, HandleError> {
+        Ok(DisplayHandle::web())
+    }
+}
+
+/// A [`WindowHandle`] to the given `HtmlCanvasElement`.
+#[expect(unsafe_code)]
+#[cfg(target_arch = "wasm32")]
+fn canvas_window_handle(canvas: &wasm_bindgen::JsValue) -> WindowHandle<'_> {
+    let handle =
+        raw_window_handle::WebCanvasWindowHandle::new(std::ptr::NonNull::from(canvas).cast());
+    // Safety: the handle points to the `JsValue`, which lives for as long as the returned borrow.
+    unsafe { WindowHandle::borrow_raw(handle.into()) }
+}
+
 impl CreationContext<'_> {
     /// Create a new empty [CreationContext] for testing [App]s in kittest.
     #[doc(hidden)]
@@ -127,6 +158,8 @@ impl CreationContext<'_> {
             raw_window_handle: Err(HandleError::NotSupported),
             #[cfg(not(target_arch = "wasm32"))]
             raw_display_handle: Err(HandleError::NotSupported),
+            #[cfg(target_arch = "wasm32")]
+            canvas: None,
         }
     }
 }
@@ -477,6 +510,14 @@ pub struct WebOptions {
     /// Unused by webgl context as of writing.
     pub depth_buffer: u8,
 
+    /// Which renderer to use on the canvas.
+    ///
+    /// With both the `glow` and `wgpu` features enabled, [`Renderer::Wgpu`] falls back
+    /// to [`Renderer::Glow`] (WebGL) at runtime if the browser doesn't support WebGPU.
+    ///
+    /// Default: [`Renderer::Wgpu`] if the `wgpu` feature is enabled, otherwise [`Renderer::Glow`].
+    pub renderer: Renderer,
+
     /// Which version of WebGL context to select
     ///
     /// Default: [`WebGlContextOption::BestFirst`].
@@ -509,6 +550,50 @@ pub struct WebOptions {
     ///
     /// Defaults to true.
     pub should_prevent_default: Box<dyn Fn(&egui::Event) -> bool>,
+
+    /// Run the app in a dedicated web worker, to keep the main thread responsive.
+    ///
+    /// The control of the canvas is transferred to the worker with an `OffscreenCanvas`,
+    /// and input events are forwarded to it from the main thread.
+    ///
+    /// This is the URL of a module worker script that loads your wasm
+    /// and calls [`crate::web::WorkerRunner::start`].
+    /// The `app_creator` given to [`crate::WebRunner::start`] is then never called.
+    ///
+    /// Defaults to `None`, meaning the app runs on the main thread.
+    #[cfg(feature = "web_worker")]
+    pub worker_url: Option<String>,
+
+    /// Prefix for everything this app stores in `localStorage`,
+    /// both the egui memory and what the app writes to [`Storage`].
+    /// With [`WebStorageBackend::IndexedDb`] this picks the name of the database instead.
+    ///
+    /// Set this to something unique for each app, e.g. the id of its canvas,
+    /// when running several apps on the same page
+    /// so that they don't overwrite each other's state.
+    ///
+    /// Defaults to `None`, meaning keys are used as-is.
+    pub storage_key: Option<String>,
+
+    /// Where the app and the egui memory are persisted.
+    ///
+    /// Defaults to [`WebStorageBackend::LocalStorage`].
+    pub storage_backend: WebStorageBackend,
+
+    /// Dropped files larger than this are not loaded into [`egui::DroppedFile::bytes`].
+    ///
+    /// Instead they show up in [`egui::RawInput::dropped_files`] right away,
+    /// and can be read in pieces with [`crate::web::dropped_files::read`].
+    /// Call [`crate::web::dropped_files::forget`] once you are done with such a file.
+    /// Use this for files that are too big to keep in memory.
+    ///
+    /// Defaults to `None`, meaning all dropped files are loaded.
+    pub max_dropped_file_bytes: Option<u64>,
+
+    /// When to repaint the app.
+    ///
+    /// Defaults to [`WebRepaintMode::Reactive`].
+    pub repaint_mode: WebRepaintMode,
 }
 
 #[cfg(target_arch = "wasm32")]
@@ -517,6 +602,8 @@ impl Default for WebOptions {
         Self {
             depth_buffer: 0,
 
+            renderer: Renderer::default(),
+
             #[cfg(feature = "glow")]
             webgl_context_option: WebGlContextOption::BestFirst,
 
@@ -527,6 +614,16 @@ impl Default for WebOptions {
 
             should_stop_propagation: Box::new(|_| true),
             should_prevent_default: Box::new(|_| true),
+
+            #[cfg(feature = "web_worker")]
+            worker_url: None,
+
+            storage_key: None,
+            storage_backend: WebStorageBackend::default(),
+
+            max_dropped_file_bytes: None,
+
+            repaint_mode: WebRepaintMode::default(),
         }
     }
 }
@@ -552,6 +649,45 @@ pub enum WebGlContextOption {
 
 // ----------------------------------------------------------------------------
 
+/// Where a web app stores its [`Storage`], see [`WebOptions::storage_backend`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum WebStorageBackend {
+    /// `localStorage`, which is limited to around 5 MB per site.
+    #[default]
+    LocalStorage,
+
+    /// `IndexedDB`, which can hold much more, e.g. large layouts or cached images.
+    ///
+    /// Everything is read into memory when the app starts,
+    /// and written back in the background when the app is saved.
+    ///
+    /// The first time an app uses this, it gets a copy of what it had in `localStorage`.
+    /// If `IndexedDB` is not available, `localStorage` is used instead.
+    IndexedDb,
+}
+
+// ----------------------------------------------------------------------------
+
+/// When a web app is repainted, see [`WebOptions::repaint_mode`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum WebRepaintMode {
+    /// Only repaint when there is new input, an animation,
+    /// or a repaint has been requested with e.g. [`egui::Context::request_repaint_after`].
+    ///
+    /// Between repaints the app uses no CPU at all.
+    #[default]
+    Reactive,
+
+    /// Ask the browser for an animation frame every frame, even when there is nothing to repaint.
+    ///
+    /// The app is still only repainted when needed, but this keeps the CPU awake.
+    Continuous,
+}
+
+// ----------------------------------------------------------------------------
+
 /// What rendering backend to use.
 ///
 /// You need to enable the "glow" and "wgpu" features to have a choice.
@@ -660,6 +796,12 @@ pub struct Frame {
     /// Raw platform display handle for window
     #[cfg(not(target_arch = "wasm32"))]
     pub(crate) raw_display_handle: Result<RawDisplayHandle, HandleError>,
+
+    /// The canvas we are painting to.
+    ///
+    /// Boxed, so that the address stays the same for the window handle.
+    #[cfg(target_arch = "wasm32")]
+    pub(crate) canvas: Option<Box<wasm_bindgen::JsValue>>,
 }
 
 // Implementing `Clone` would violate the guarantees of `HasWindowHandle` and `HasDisplayHandle`.
@@ -684,6 +826,23 @@ impl HasDisplayHandle for Frame {
     }
 }
 
+/// On web, the window handle is the canvas we are painting to.
+#[cfg(target_arch = "wasm32")]
+impl HasWindowHandle for Frame {
+    fn window_handle(&self) -> Result<WindowHandle<'_>, HandleError> {
+        Ok(canvas_window_handle(
+            self.canvas.as_deref().ok_or(HandleError::NotSupported)?,
+        ))
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+impl HasDisplayHandle for Frame {
+    fn display_handle(&self) -> Result<DisplayHandle<'_>, HandleError> {
+        Ok(DisplayHandle::web())
+    }
+}
+
 impl Frame {
     /// Create a new empty [Frame] for testing [App]s in kittest.
     #[doc(hidden)]
@@ -701,6 +860,8 @@ impl Frame {
             storage: None,
             #[cfg(feature = "wgpu")]
             wgpu_render_state: None,
+            #[cfg(target_arch = "wasm32")]
+            canvas: None,
         }
     }
 
@@ -762,11 +923,81 @@ impl Frame {
     pub fn wgpu_render_state(&self) -> Option<&egui_wgpu::RenderState> {
         self.wgpu_render_state.as_ref()
     }
+
+    /// The [`wgpu::Device`] used for rendering.
+    ///
+    /// Only available when compiling with the `wgpu` feature and using [`Renderer::Wgpu`].
+    ///
+    /// Can be used to share the device with external render engines or video decoders.
+    #[cfg(feature = "wgpu")]
+    pub fn wgpu_device(&self) -> Option<&wgpu::Device> {
+        self.wgpu_render_state.as_ref().map(|rs| &rs.device)
+    }
+
+    /// The [`wgpu::Queue`] used for rendering.
+    ///
+    /// Only available when compiling with the `wgpu` feature and using [`Renderer::Wgpu`].
+    #[cfg(feature = "wgpu")]
+    pub fn wgpu_queue(&self) -> Option<&wgpu::Queue> {
+        self.wgpu_render_state.as_ref().map(|rs| &rs.queue)
+    }
+
+    /// The canvas we are painting to, unless we are running in a web worker.
+    #[cfg(target_arch = "wasm32")]
+    fn html_canvas(&self) -> Option<&web_sys::HtmlCanvasElement> {
+        wasm_bindgen::JsCast::dyn_ref(self.canvas.as_deref()?)
+    }
+
+    /// Make the canvas fill the screen, or leave fullscreen.
+    ///
+    /// Browsers only allow entering fullscreen shortly after the user clicked or pressed a key,
+    /// so call this in response to e.g. a button click.
+    /// The user can always leave fullscreen by pressing escape.
+    ///
+    /// Does nothing in a web worker.
+    #[cfg(target_arch = "wasm32")]
+    pub fn set_fullscreen(&self, fullscreen: bool) {
+        if let Some(canvas) = self.html_canvas() {
+            crate::web::set_fullscreen(canvas, fullscreen);
+        }
+    }
+
+    /// Is the canvas fullscreen?
+    ///
+    /// Also available as [`egui::ViewportInfo::fullscreen`].
+    #[cfg(target_arch = "wasm32")]
+    pub fn is_fullscreen(&self) -> bool {
+        self.html_canvas().is_some_and(crate::web::is_fullscreen)
+    }
+
+    /// Lock the pointer to the canvas and hide it, e.g. for a first-person camera.
+    ///
+    /// While locked, the mouse movement is only reported as [`egui::PointerState::motion`],
+    /// and the pointer position stays where it was.
+    ///
+    /// Browsers only allow this shortly after the user clicked or pressed a key,
+    /// and unlock the pointer when the user presses escape.
+    ///
+    /// Does nothing in a web worker.
+    #[cfg(target_arch = "wasm32")]
+    pub fn set_pointer_locked(&self, locked: bool) {
+        if let Some(canvas) = self.html_canvas() {
+            crate::web::set_pointer_locked(canvas, locked);
+        }
+    }
+
+    /// Is the pointer locked to the canvas, see [`Self::set_pointer_locked`]?
+    #[cfg(target_arch = "wasm32")]
+    pub fn is_pointer_locked(&self) -> bool {
+        self.html_canvas()
+            .is_some_and(crate::web::is_pointer_locked)
+    }
 }
 
 /// Information about the web environment (if applicable).
 #[derive(Clone, Debug)]
-#[cfg(target_arch = "wasm32")]
+#[cfg(any(target_arch = "wasm32", all(test, feature = "web_worker")))]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct WebInfo {
     /// The browser user agent.
     pub user_agent: String,
@@ -778,8 +1009,9 @@ pub struct WebInfo {
 /// Information about the URL.
 ///
 /// Everything has been percent decoded (`%20` -> ` ` etc).
-#[cfg(target_arch = "wasm32")]
+#[cfg(any(target_arch = "wasm32", all(test, feature = "web_worker")))]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct Location {
     /// The full URL (`location.href`) without the hash, percent-decoded.
     ///
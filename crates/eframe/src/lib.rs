@@ -169,6 +169,11 @@ pub use epi::*;
 
 pub(crate) mod stopwatch;
 
+// The web worker messages are platform independent, so they can be tested natively:
+#[cfg(feature = "web_worker")]
+#[cfg(any(target_arch = "wasm32", test))]
+mod worker_protocol;
+
 // ----------------------------------------------------------------------------
 // When compiling for web
 
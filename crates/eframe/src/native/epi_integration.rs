@@ -266,6 +266,7 @@ impl EpiIntegration {
         mut raw_input: egui::RawInput,
     ) -> egui::FullOutput {
         raw_input.time = Some(self.beginning.elapsed().as_secs_f64());
+        raw_input.frame_cost = self.frame.info.cpu_usage;
 
         let close_requested = raw_input.viewport().close_requested();
 
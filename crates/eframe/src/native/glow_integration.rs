@@ -538,18 +538,18 @@ impl GlowWinitRunning<'_> {
 
         let (raw_input, viewport_ui_cb) = {
             let mut glutin = self.glutin.borrow_mut();
-            let egui_ctx = glutin.egui_ctx.clone();
             let Some(viewport) = glutin.viewports.get_mut(&viewport_id) else {
                 return Ok(EventResult::Wait);
             };
             let Some(window) = viewport.window.as_ref() else {
                 return Ok(EventResult::Wait);
             };
-            egui_winit::update_viewport_info(&mut viewport.info, &egui_ctx, window, false);
 
             let Some(egui_winit) = viewport.egui_winit.as_mut() else {
                 return Ok(EventResult::Wait);
             };
+            egui_winit.update_viewport_info(&mut viewport.info, window, false);
+
             let mut raw_input = egui_winit.take_egui_input(window);
             let viewport_ui_cb = viewport.viewport_ui_cb.clone();
 
@@ -691,6 +691,12 @@ impl GlowWinitRunning<'_> {
                     ActionRequested::Cut => {
                         egui_winit.egui_input_mut().events.push(egui::Event::Cut);
                     }
+                    ActionRequested::NativePixelsPerPoint(native_pixels_per_point) => {
+                        egui_winit.set_native_pixels_per_point(
+                            native_pixels_per_point
+                                .map(|native_pixels_per_point| native_pixels_per_point.0),
+                        );
+                    }
                     ActionRequested::Copy => {
                         egui_winit.egui_input_mut().events.push(egui::Event::Copy);
                     }
@@ -1139,14 +1145,16 @@ impl GlutinWindowContext {
 
         viewport.egui_winit.get_or_insert_with(|| {
             log::debug!("Initializing egui_winit for viewport {viewport_id:?}");
-            egui_winit::State::new(
+            let mut egui_winit = egui_winit::State::new(
                 self.egui_ctx.clone(),
                 viewport_id,
                 event_loop,
                 Some(window.scale_factor() as f32),
                 event_loop.system_theme(),
                 self.max_texture_side,
-            )
+            );
+            egui_winit.set_native_pixels_per_point(viewport.builder.native_pixels_per_point);
+            egui_winit
         });
 
         if viewport.gl_surface.is_none() {
@@ -1455,7 +1463,7 @@ fn render_immediate_viewport(
         let (Some(egui_winit), Some(window)) = (&mut viewport.egui_winit, &viewport.window) else {
             return;
         };
-        egui_winit::update_viewport_info(&mut viewport.info, egui_ctx, window, false);
+        egui_winit.update_viewport_info(&mut viewport.info, window, false);
 
         let mut raw_input = egui_winit.take_egui_input(window);
         raw_input.viewports = glutin
@@ -245,6 +245,7 @@ impl<'app> WgpuWinitApp<'app> {
             event_loop.system_theme(),
             painter.max_texture_side(),
         );
+        egui_winit.set_native_pixels_per_point(builder.native_pixels_per_point);
 
         #[cfg(feature = "accesskit")]
         {
@@ -275,7 +276,7 @@ impl<'app> WgpuWinitApp<'app> {
         viewport_from_window.insert(window.id(), ViewportId::ROOT);
 
         let mut info = ViewportInfo::default();
-        egui_winit::update_viewport_info(&mut info, &egui_ctx, &window, true);
+        egui_winit.update_viewport_info(&mut info, &window, true);
 
         let mut viewports = Viewports::default();
         viewports.insert(
@@ -588,7 +589,6 @@ impl WgpuWinitRunning<'_> {
             let Some(window) = window else {
                 return Ok(EventResult::Wait);
             };
-            egui_winit::update_viewport_info(info, &integration.egui_ctx, window, false);
 
             {
                 profiling::scope!("set_window");
@@ -598,6 +598,7 @@ impl WgpuWinitRunning<'_> {
             let Some(egui_winit) = egui_winit.as_mut() else {
                 return Ok(EventResult::Wait);
             };
+            egui_winit.update_viewport_info(info, window, false);
             let mut raw_input = egui_winit.take_egui_input(window);
 
             integration.pre_update();
@@ -686,6 +687,12 @@ impl WgpuWinitRunning<'_> {
                 ActionRequested::Cut => {
                     egui_winit.egui_input_mut().events.push(egui::Event::Cut);
                 }
+                ActionRequested::NativePixelsPerPoint(native_pixels_per_point) => {
+                    egui_winit.set_native_pixels_per_point(
+                        native_pixels_per_point
+                            .map(|native_pixels_per_point| native_pixels_per_point.0),
+                    );
+                }
                 ActionRequested::Copy => {
                     egui_winit.egui_input_mut().events.push(egui::Event::Copy);
                 }
@@ -875,16 +882,18 @@ impl Viewport {
                     log::error!("on set_window: viewport_id {viewport_id:?} {err}");
                 }
 
-                self.egui_winit = Some(egui_winit::State::new(
+                let mut egui_winit = egui_winit::State::new(
                     egui_ctx.clone(),
                     viewport_id,
                     event_loop,
                     Some(window.scale_factor() as f32),
                     event_loop.system_theme(),
                     painter.max_texture_side(),
-                ));
+                );
+                egui_winit.set_native_pixels_per_point(self.builder.native_pixels_per_point);
 
-                egui_winit::update_viewport_info(&mut self.info, egui_ctx, &window, true);
+                egui_winit.update_viewport_info(&mut self.info, &window, true);
+                self.egui_winit = Some(egui_winit);
                 self.window = Some(window);
             }
             Err(err) => {
@@ -955,7 +964,7 @@ fn render_immediate_viewport(
         let (Some(window), Some(egui_winit)) = (&viewport.window, &mut viewport.egui_winit) else {
             return;
         };
-        egui_winit::update_viewport_info(&mut viewport.info, egui_ctx, window, false);
+        egui_winit.update_viewport_info(&mut viewport.info, window, false);
 
         let mut input = egui_winit.take_egui_input(window);
         input.viewports = viewports
@@ -56,6 +56,21 @@ pub enum UserEvent {
     AccessKitActionRequest(accesskit_winit::Event),
 }
 
+impl From<egui_winit::RepaintRequest> for UserEvent {
+    fn from(request: egui_winit::RepaintRequest) -> Self {
+        let egui_winit::RepaintRequest {
+            viewport_id,
+            when,
+            cumulative_pass_nr,
+        } = request;
+        Self::RequestRepaint {
+            viewport_id,
+            when,
+            cumulative_pass_nr,
+        }
+    }
+}
+
 #[cfg(feature = "accesskit")]
 impl From<accesskit_winit::Event> for UserEvent {
     fn from(inner: accesskit_winit::Event) -> Self {
@@ -1,21 +1,35 @@
+use std::collections::BTreeSet;
+
 use egui::{TexturesDelta, UserData, ViewportCommand};
 
 use crate::{App, epi};
 
-use super::{NeedRepaint, now_sec, text_agent::TextAgent, web_painter::WebPainter as _};
+use super::{
+    NeedRepaint, now_sec,
+    text_agent::TextAgent,
+    web_painter::{PaintCanvas, WebPainter as _},
+};
 
 pub struct AppRunner {
     #[allow(dead_code, clippy::allow_attributes)]
     pub(crate) web_options: crate::WebOptions,
     pub(crate) frame: epi::Frame,
     egui_ctx: egui::Context,
+    canvas: web_sys::HtmlCanvasElement,
     painter: super::ActiveWebPainter,
     pub(crate) input: super::WebInput,
     app: Box<dyn epi::App>,
     pub(crate) needs_repaint: std::sync::Arc<NeedRepaint>,
     last_save_time: f64,
+
+    /// Has the app run since it was last saved?
+    ran_since_last_save: bool,
+
     pub(crate) text_agent: TextAgent,
 
+    /// Pasted text and images that were read asynchronously.
+    pub(crate) clipboard_events: super::ClipboardEvents,
+
     // If not empty, the painter should capture n frames from now.
     // zero means capture the exact next frame.
     screenshot_commands_with_frame_delay: Vec<(UserData, usize)>,
@@ -33,7 +47,7 @@ impl Drop for AppRunner {
 
 impl AppRunner {
     /// # Errors
-    /// Failure to initialize WebGL renderer, or failure to create app.
+    /// Failure to initialize the renderer, or failure to create app.
     pub async fn new(
         canvas: web_sys::HtmlCanvasElement,
         web_options: crate::WebOptions,
@@ -41,7 +55,21 @@ impl AppRunner {
         text_agent: TextAgent,
     ) -> Result<Self, String> {
         let egui_ctx = egui::Context::default();
-        let painter = super::ActiveWebPainter::new(egui_ctx.clone(), canvas, &web_options).await?;
+        let canvas_value: wasm_bindgen::JsValue = canvas.clone().into();
+        let painter = super::ActiveWebPainter::new(
+            egui_ctx.clone(),
+            PaintCanvas::Element(canvas.clone()),
+            &web_options,
+        )
+        .await?;
+
+        // The real app is created by the worker, so here we only forward input to it:
+        #[cfg(feature = "web_worker")]
+        let app_creator = if let super::ActiveWebPainter::Worker(painter) = &painter {
+            painter.proxy_app_creator()
+        } else {
+            app_creator
+        };
 
         let info = epi::IntegrationInfo {
             web_info: epi::WebInfo {
@@ -50,12 +78,12 @@ impl AppRunner {
             },
             cpu_usage: None,
         };
-        let storage = LocalStorage::default();
+        let storage = create_storage(&web_options).await;
 
         egui_ctx.set_os(egui::os::OperatingSystem::from_user_agent(
             &super::user_agent().unwrap_or_default(),
         ));
-        super::storage::load_memory(&egui_ctx);
+        super::storage::load_memory(&egui_ctx, storage.as_ref());
 
         egui_ctx.options_mut(|o| {
             // On web by default egui follows the zoom factor of the browser,
@@ -68,32 +96,32 @@ impl AppRunner {
         let cc = epi::CreationContext {
             egui_ctx: egui_ctx.clone(),
             integration_info: info.clone(),
-            storage: Some(&storage),
+            storage: Some(storage.as_ref()),
 
             #[cfg(feature = "glow")]
-            gl: Some(painter.gl().clone()),
+            gl: painter.gl(),
 
             #[cfg(feature = "glow")]
             get_proc_address: None,
 
-            #[cfg(all(feature = "wgpu", not(feature = "glow")))]
+            #[cfg(feature = "wgpu")]
             wgpu_render_state: painter.render_state(),
-            #[cfg(all(feature = "wgpu", feature = "glow"))]
-            wgpu_render_state: None,
+
+            canvas: Some(&canvas_value),
         };
         let app = app_creator(&cc).map_err(|err| err.to_string())?;
 
         let frame = epi::Frame {
             info,
-            storage: Some(Box::new(storage)),
+            storage: Some(storage),
 
             #[cfg(feature = "glow")]
-            gl: Some(painter.gl().clone()),
+            gl: painter.gl(),
 
-            #[cfg(all(feature = "wgpu", not(feature = "glow")))]
+            #[cfg(feature = "wgpu")]
             wgpu_render_state: painter.render_state(),
-            #[cfg(all(feature = "wgpu", feature = "glow"))]
-            wgpu_render_state: None,
+
+            canvas: Some(Box::new(canvas_value)),
         };
 
         let needs_repaint: std::sync::Arc<NeedRepaint> = Default::default();
@@ -108,12 +136,15 @@ impl AppRunner {
             web_options,
             frame,
             egui_ctx,
+            canvas,
             painter,
             input: Default::default(),
             app,
             needs_repaint,
             last_save_time: now_sec(),
+            ran_since_last_save: false,
             text_agent,
+            clipboard_events: Default::default(),
             screenshot_commands_with_frame_delay: vec![],
             textures_delta: Default::default(),
             clipped_primitives: None,
@@ -155,17 +186,40 @@ impl AppRunner {
     }
 
     pub fn save(&mut self) {
-        if self.app.persist_egui_memory() {
-            super::storage::save_memory(&self.egui_ctx);
-        }
         if let Some(storage) = self.frame.storage_mut() {
+            if self.app.persist_egui_memory() {
+                super::storage::save_memory(&self.egui_ctx, storage);
+            }
             self.app.save(storage);
+            storage.flush();
         }
         self.last_save_time = now_sec();
+        self.ran_since_last_save = false;
+    }
+
+    /// When [`Self::auto_save_if_needed`] should next be called, in [`now_sec`] scale.
+    ///
+    /// Infinity if nothing has happened since the last save.
+    pub fn next_auto_save_time(&self) -> f64 {
+        if self.ran_since_last_save {
+            self.last_save_time + self.app.auto_save_interval().as_secs_f64()
+        } else {
+            f64::INFINITY
+        }
     }
 
     pub fn canvas(&self) -> &web_sys::HtmlCanvasElement {
-        self.painter.canvas()
+        &self.canvas
+    }
+
+    /// Size of the drawing buffer of the canvas, in physical pixels.
+    pub fn canvas_size_in_pixels(&self) -> [u32; 2] {
+        self.painter.size_in_pixels()
+    }
+
+    /// Resize the drawing buffer of the canvas, in physical pixels.
+    pub fn set_canvas_size_in_pixels(&mut self, size: [u32; 2]) {
+        self.painter.set_size_in_pixels(size);
     }
 
     pub fn destroy(mut self) {
@@ -208,19 +262,32 @@ impl AppRunner {
     ///
     /// The result can be painted later with a call to [`Self::run_and_paint`] or [`Self::paint`].
     pub fn logic(&mut self) {
+        self.ran_since_last_save = true;
+
         // We sometimes miss blur/focus events due to the text agent, so let's just poll each frame:
         self.update_focus();
         // We might have received a screenshot
         self.painter.handle_screenshots(&mut self.input.raw.events);
 
-        let canvas_size = super::canvas_size_in_points(self.canvas(), self.egui_ctx());
+        self.input
+            .raw
+            .events
+            .append(&mut self.clipboard_events.borrow_mut());
+
+        let canvas_size =
+            super::canvas_size_in_points(self.canvas_size_in_pixels(), self.egui_ctx());
         let mut raw_input = self.input.new_frame(canvas_size);
+        raw_input
+            .viewports
+            .entry(egui::ViewportId::ROOT)
+            .or_default()
+            .fullscreen = Some(super::is_fullscreen(&self.canvas));
+        raw_input.frame_cost = self.frame.info.cpu_usage;
 
         if super::DEBUG_RESIZE {
             log::info!(
-                "egui running at canvas size: {}x{}, DPR: {}, zoom_factor: {}. egui size: {}x{} points",
-                self.canvas().width(),
-                self.canvas().height(),
+                "egui running at canvas size: {:?}, DPR: {}, zoom_factor: {}. egui size: {}x{} points",
+                self.canvas_size_in_pixels(),
                 super::native_pixels_per_point(),
                 self.egui_ctx.zoom_factor(),
                 canvas_size.x,
@@ -251,6 +318,29 @@ impl AppRunner {
                         self.screenshot_commands_with_frame_delay
                             .push((user_data, 1));
                     }
+                    ViewportCommand::RequestCopy => {
+                        self.input.raw.events.push(egui::Event::Copy);
+                        self.needs_repaint.repaint_asap();
+                    }
+                    ViewportCommand::RequestCut => {
+                        self.input.raw.events.push(egui::Event::Cut);
+                        self.needs_repaint.repaint_asap();
+                    }
+                    ViewportCommand::RequestPaste => {
+                        super::read_clipboard(
+                            self.clipboard_events.clone(),
+                            self.needs_repaint.clone(),
+                        );
+                    }
+                    ViewportCommand::Fullscreen(fullscreen) => {
+                        super::set_fullscreen(&self.canvas, fullscreen);
+                    }
+                    ViewportCommand::CursorGrab(grab) => {
+                        super::set_pointer_locked(
+                            &self.canvas,
+                            grab == egui::viewport::CursorGrab::Locked,
+                        );
+                    }
                     _ => {
                         // TODO(emilk): handle some of the commands
                         log::warn!(
@@ -336,6 +426,9 @@ impl AppRunner {
                 egui::OutputCommand::OpenUrl(open_url) => {
                     super::open_url(&open_url.url, open_url.new_tab);
                 }
+                egui::OutputCommand::Haptic(feedback) => {
+                    super::vibrate(feedback.duration_ms());
+                }
             }
         }
 
@@ -375,16 +468,56 @@ impl AppRunner {
 
 // ----------------------------------------------------------------------------
 
-#[derive(Default)]
-struct LocalStorage {}
+async fn create_storage(web_options: &crate::WebOptions) -> Box<dyn epi::Storage> {
+    let storage_key = web_options.storage_key.clone();
+    match web_options.storage_backend {
+        crate::WebStorageBackend::LocalStorage => Box::new(LocalStorage::new(storage_key)),
+        crate::WebStorageBackend::IndexedDb => {
+            match super::indexed_db::IndexedDbStorage::open(storage_key.as_deref()).await {
+                Ok(storage) => Box::new(storage),
+                Err(err) => {
+                    log::warn!(
+                        "Failed to open IndexedDB, using localStorage instead: {}",
+                        super::string_from_js_value(&err)
+                    );
+                    Box::new(LocalStorage::new(storage_key))
+                }
+            }
+        }
+    }
+}
+
+struct LocalStorage {
+    /// See [`crate::WebOptions::storage_key`].
+    storage_key: Option<String>,
+
+    /// The keys this app has written, so that they can be moved to `IndexedDB` later.
+    keys: BTreeSet<String>,
+}
+
+impl LocalStorage {
+    fn new(storage_key: Option<String>) -> Self {
+        let keys = super::storage::local_storage_keys(storage_key.as_deref());
+        Self { storage_key, keys }
+    }
+}
 
 impl epi::Storage for LocalStorage {
     fn get_string(&self, key: &str) -> Option<String> {
-        super::storage::local_storage_get(key)
+        super::storage::local_storage_get(&super::storage::prefixed_key(
+            self.storage_key.as_deref(),
+            key,
+        ))
     }
 
     fn set_string(&mut self, key: &str, value: String) {
-        super::storage::local_storage_set(key, &value);
+        super::storage::local_storage_set(
+            &super::storage::prefixed_key(self.storage_key.as_deref(), key),
+            &value,
+        );
+        if self.keys.insert(key.to_owned()) {
+            super::storage::set_local_storage_keys(self.storage_key.as_deref(), &self.keys);
+        }
     }
 
     fn flush(&mut self) {}
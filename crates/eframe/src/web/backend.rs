@@ -1,4 +1,8 @@
-use std::collections::BTreeMap;
+use std::{
+    cell::{Cell, RefCell},
+    collections::{BTreeMap, HashMap},
+    rc::Rc,
+};
 
 use egui::mutex::Mutex;
 
@@ -49,12 +53,26 @@ impl WebInput {
 
 // ----------------------------------------------------------------------------
 
+thread_local! {
+    /// The wakers of [`NeedRepaint`], which aren't `Send` and so can't be stored in it.
+    static WAKERS: RefCell<HashMap<u64, Rc<dyn Fn()>>> = RefCell::new(HashMap::new());
+    static NEXT_WAKER_ID: Cell<u64> = const { Cell::new(0) };
+}
+
 /// Stores when to do the next repaint.
-pub(crate) struct NeedRepaint(Mutex<f64>);
+pub(crate) struct NeedRepaint {
+    repaint_time: Mutex<f64>,
+
+    /// Called whenever a repaint is requested, see [`Self::set_waker`].
+    waker: Mutex<Option<u64>>,
+}
 
 impl Default for NeedRepaint {
     fn default() -> Self {
-        Self(Mutex::new(f64::NEG_INFINITY)) // start with a repaint
+        Self {
+            repaint_time: Mutex::new(f64::NEG_INFINITY), // start with a repaint
+            waker: Mutex::new(None),
+        }
     }
 }
 
@@ -62,17 +80,20 @@ impl NeedRepaint {
     /// Returns the time (in [`now_sec`] scale) when
     /// we should next repaint.
     pub fn when_to_repaint(&self) -> f64 {
-        *self.0.lock()
+        *self.repaint_time.lock()
     }
 
     /// Unschedule repainting.
     pub fn clear(&self) {
-        *self.0.lock() = f64::INFINITY;
+        *self.repaint_time.lock() = f64::INFINITY;
     }
 
     pub fn repaint_after(&self, num_seconds: f64) {
-        let mut repaint_time = self.0.lock();
-        *repaint_time = repaint_time.min(super::now_sec() + num_seconds);
+        {
+            let mut repaint_time = self.repaint_time.lock();
+            *repaint_time = repaint_time.min(super::now_sec() + num_seconds);
+        }
+        self.wake_up();
     }
 
     pub fn needs_repaint(&self) -> bool {
@@ -80,7 +101,33 @@ impl NeedRepaint {
     }
 
     pub fn repaint_asap(&self) {
-        *self.0.lock() = f64::NEG_INFINITY;
+        *self.repaint_time.lock() = f64::NEG_INFINITY;
+        self.wake_up();
+    }
+
+    /// Call `waker` every time a repaint is requested, so that it can be scheduled.
+    ///
+    /// The waker is only called on the thread that set it.
+    pub fn set_waker(&self, waker: Option<Rc<dyn Fn()>>) {
+        let id = waker.map(|waker| {
+            let id = NEXT_WAKER_ID.replace(NEXT_WAKER_ID.get() + 1);
+            WAKERS.with_borrow_mut(|wakers| wakers.insert(id, waker));
+            id
+        });
+        if let Some(old_id) = std::mem::replace(&mut *self.waker.lock(), id) {
+            WAKERS.with_borrow_mut(|wakers| wakers.remove(&old_id));
+        }
+    }
+
+    fn wake_up(&self) {
+        let Some(id) = *self.waker.lock() else {
+            return;
+        };
+        // Don't hold on to `WAKERS` while waking, in case the waker changes it:
+        let waker = WAKERS.with_borrow(|wakers| wakers.get(&id).cloned());
+        if let Some(waker) = waker {
+            waker();
+        }
     }
 }
 
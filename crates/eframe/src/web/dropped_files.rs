@@ -0,0 +1,62 @@
+//! Files dropped on the canvas are kept here, so that they can be read on demand.
+//!
+//! This lets apps handle files too big to load into memory,
+//! see [`crate::WebOptions::max_dropped_file_bytes`].
+//! Only those files are kept, until they are [`forget`]-ed.
+
+use std::{
+    cell::{Cell, RefCell},
+    collections::HashMap,
+    future::Future,
+    ops::Range,
+};
+
+use wasm_bindgen::prelude::*;
+
+thread_local! {
+    static FILES: RefCell<HashMap<u64, web_sys::File>> = RefCell::new(HashMap::new());
+    static NEXT_HANDLE: Cell<u64> = const { Cell::new(0) };
+}
+
+/// Keep the file, and return the [`egui::DroppedFile::handle`] for it.
+pub(crate) fn register(file: web_sys::File) -> u64 {
+    let handle = NEXT_HANDLE.replace(NEXT_HANDLE.get() + 1);
+    FILES.with_borrow_mut(|files| files.insert(handle, file));
+    handle
+}
+
+/// The browser `File` that was dropped.
+///
+/// Returns `None` if the file was loaded into [`egui::DroppedFile::bytes`] instead,
+/// if it was not dropped on the web, or if it has been [`forget`]-ed.
+pub fn file(dropped_file: &egui::DroppedFile) -> Option<web_sys::File> {
+    let handle = dropped_file.handle?;
+    FILES.with_borrow(|files| files.get(&handle).cloned())
+}
+
+/// Read the bytes in `range` of a dropped file.
+///
+/// The range is limited to the size of the file.
+pub fn read(
+    dropped_file: &egui::DroppedFile,
+    range: Range<u64>,
+) -> impl Future<Output = Result<Vec<u8>, JsValue>> + 'static {
+    let file = file(dropped_file);
+    async move {
+        let file = file.ok_or_else(|| JsValue::from_str("Unknown dropped file"))?;
+        read_blob(&file, range).await
+    }
+}
+
+/// Stop keeping a dropped file around, after which it can no longer be read.
+pub fn forget(dropped_file: &egui::DroppedFile) {
+    if let Some(handle) = dropped_file.handle {
+        FILES.with_borrow_mut(|files| files.remove(&handle));
+    }
+}
+
+pub(crate) async fn read_blob(blob: &web_sys::Blob, range: Range<u64>) -> Result<Vec<u8>, JsValue> {
+    let slice = blob.slice_with_f64_and_f64(range.start as f64, range.end as f64)?;
+    let array_buffer = wasm_bindgen_futures::JsFuture::from(slice.array_buffer()).await?;
+    Ok(js_sys::Uint8Array::new(&array_buffer).to_vec())
+}
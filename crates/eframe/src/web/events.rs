@@ -14,15 +14,24 @@ use web_sys::{Document, EventTarget, ShadowRoot};
 
 // ------------------------------------------------------------------------
 
-/// Calls `request_animation_frame` to schedule repaint.
+/// Paints if needed, and schedules the next time to do so.
 ///
-/// It will only paint if needed, but will always call `request_animation_frame` immediately.
+/// With [`crate::WebRepaintMode::Continuous`] this always calls `request_animation_frame` immediately.
+/// With [`crate::WebRepaintMode::Reactive`] it waits until the next repaint or auto-save is due.
 pub(crate) fn paint_and_schedule(runner_ref: &WebRunner) -> Result<(), JsValue> {
     // Only paint and schedule if there has been no panic
     if let Some(mut runner_lock) = runner_ref.try_lock() {
         paint_if_needed(&mut runner_lock);
+        let repaint_mode = runner_lock.web_options.repaint_mode;
+        let wake_time = runner_lock
+            .needs_repaint
+            .when_to_repaint()
+            .min(runner_lock.next_auto_save_time());
         drop(runner_lock);
-        runner_ref.request_animation_frame()?;
+        match repaint_mode {
+            crate::WebRepaintMode::Reactive => runner_ref.schedule_wake_up(wake_time)?,
+            crate::WebRepaintMode::Continuous => runner_ref.request_animation_frame()?,
+        }
     }
     Ok(())
 }
@@ -101,6 +110,7 @@ pub(crate) fn install_event_handlers(runner_ref: &WebRunner) -> Result<(), JsVal
     install_touchcancel(runner_ref, &canvas)?;
 
     install_wheel(runner_ref, &canvas)?;
+    install_fullscreen_and_pointer_lock_change(runner_ref, &document)?;
     install_drag_and_drop(runner_ref, &canvas)?;
     install_window_events(runner_ref, &window)?;
     install_color_scheme_change_event(runner_ref, &window)?;
@@ -317,6 +327,21 @@ fn install_copy_cut_paste(runner_ref: &WebRunner, target: &EventTarget) -> Resul
         }
 
         if let Some(data) = event.clipboard_data() {
+            // Images, e.g. screenshots, show up as files:
+            if let Some(files) = data.files() {
+                for i in 0..files.length() {
+                    if let Some(file) = files.get(i) {
+                        if file.type_().starts_with("image/") {
+                            super::paste_image(
+                                file.into(),
+                                runner.clipboard_events.clone(),
+                                runner.needs_repaint.clone(),
+                            );
+                        }
+                    }
+                }
+            }
+
             if let Ok(text) = data.get_data("text") {
                 let text = text.replace("\r\n", "\n");
 
@@ -442,17 +467,16 @@ fn install_dpr_change_event(web_runner: &WebRunner) -> Result<(), JsValue> {
         let new_dpr = native_pixels_per_point();
         log::debug!("Device Pixel Ratio changed from {original_dpr} to {new_dpr}");
 
-        if true {
-            // Explicitly resize canvas to match the new DPR.
-            // This is a bit ugly, but I haven't found a better way to do it.
-            let canvas = app_runner.canvas();
-            canvas.set_width((canvas.width() as f32 * new_dpr / original_dpr).round() as _);
-            canvas.set_height((canvas.height() as f32 * new_dpr / original_dpr).round() as _);
-            log::debug!("Resized canvas to {}x{}", canvas.width(), canvas.height());
-        }
-
-        // It may be tempting to call `resize_observer.observe(&canvas)` here,
-        // but unfortunately this has no effect.
+        // The CSS size of the canvas stays the same, so the `ResizeObserver` is only called
+        // in browsers that support observing the device pixel size.
+        // So we resize the canvas ourselves, and paint right away so that
+        // the old frame isn't shown stretched or blurry:
+        let css_size = super::canvas_content_rect(app_runner.canvas()).size();
+        let size = (new_dpr * css_size).round();
+        app_runner.set_canvas_size_in_pixels([size.x as u32, size.y as u32]);
+        log::debug!("Resized canvas to {}x{}", size.x, size.y);
+        app_runner.needs_repaint.repaint_asap();
+        paint_if_needed(app_runner);
 
         if let Err(err) = install_dpr_change_event(web_runner) {
             log::error!(
@@ -628,6 +652,15 @@ fn install_mousemove(runner_ref: &WebRunner, target: &EventTarget) -> Result<(),
         let modifiers = modifiers_from_mouse_event(&event);
         runner.input.raw.modifiers = modifiers;
 
+        if super::is_pointer_locked(runner.canvas()) {
+            // The position doesn't change while locked, only the movement is of interest:
+            let delta = egui::vec2(event.movement_x() as f32, event.movement_y() as f32);
+            runner.input.raw.events.push(egui::Event::MouseMoved(delta));
+            runner.needs_repaint.repaint_asap();
+            event.prevent_default();
+            return;
+        }
+
         let pos = pos_from_mouse_event(runner.canvas(), &event, runner.egui_ctx());
 
         if is_interested_in_pointer_event(
@@ -652,6 +685,19 @@ fn install_mousemove(runner_ref: &WebRunner, target: &EventTarget) -> Result<(),
     })
 }
 
+fn install_fullscreen_and_pointer_lock_change(
+    runner_ref: &WebRunner,
+    target: &EventTarget,
+) -> Result<(), JsValue> {
+    for event_name in ["fullscreenchange", "pointerlockchange"] {
+        runner_ref.add_event_listener(target, event_name, |_: web_sys::Event, runner| {
+            // Let the app know:
+            runner.needs_repaint.repaint_asap();
+        })?;
+    }
+    Ok(())
+}
+
 fn install_mouseleave(runner_ref: &WebRunner, target: &EventTarget) -> Result<(), JsValue> {
     runner_ref.add_event_listener(
         target,
@@ -898,41 +944,38 @@ fn install_drag_and_drop(runner_ref: &WebRunner, target: &EventTarget) -> Result
                 if let Some(files) = data_transfer.files() {
                     for i in 0..files.length() {
                         if let Some(file) = files.get(i) {
-                            let name = file.name();
-                            let mime = file.type_();
-                            let last_modified = std::time::UNIX_EPOCH
-                                + std::time::Duration::from_millis(file.last_modified() as u64);
-
-                            log::debug!("Loading {:?} ({} bytes)…", name, file.size());
-
-                            let future = wasm_bindgen_futures::JsFuture::from(file.array_buffer());
-
-                            let runner_ref = runner_ref.clone();
-                            let future = async move {
-                                match future.await {
-                                    Ok(array_buffer) => {
-                                        let bytes = js_sys::Uint8Array::new(&array_buffer).to_vec();
-                                        log::debug!("Loaded {:?} ({} bytes).", name, bytes.len());
-
-                                        if let Some(mut runner_lock) = runner_ref.try_lock() {
-                                            runner_lock.input.raw.dropped_files.push(
-                                                egui::DroppedFile {
-                                                    name,
-                                                    mime,
-                                                    last_modified: Some(last_modified),
-                                                    bytes: Some(bytes.into()),
-                                                    ..Default::default()
-                                                },
-                                            );
-                                            runner_lock.needs_repaint.repaint_asap();
-                                        }
-                                    }
-                                    Err(err) => {
-                                        log::error!("Failed to read file: {:?}", err);
-                                    }
-                                }
+                            let size = file.size() as u64;
+                            let is_large = runner
+                                .web_options
+                                .max_dropped_file_bytes
+                                .is_some_and(|max_bytes| max_bytes < size);
+                            let dropped_file = egui::DroppedFile {
+                                name: file.name(),
+                                mime: file.type_(),
+                                last_modified: Some(
+                                    std::time::UNIX_EPOCH
+                                        + std::time::Duration::from_millis(
+                                            file.last_modified() as u64
+                                        ),
+                                ),
+                                size: Some(size),
+                                handle: is_large
+                                    .then(|| super::dropped_files::register(file.clone())),
+                                ..Default::default()
                             };
-                            wasm_bindgen_futures::spawn_local(future);
+
+                            if is_large {
+                                log::debug!("Dropped {:?} ({size} bytes).", dropped_file.name);
+                                runner.input.raw.dropped_files.push(dropped_file);
+                                continue;
+                            }
+
+                            log::debug!("Loading {:?} ({size} bytes)…", dropped_file.name);
+                            wasm_bindgen_futures::spawn_local(load_dropped_file(
+                                runner_ref.clone(),
+                                file,
+                                dropped_file,
+                            ));
                         }
                     }
                 }
@@ -945,6 +988,51 @@ fn install_drag_and_drop(runner_ref: &WebRunner, target: &EventTarget) -> Result
     Ok(())
 }
 
+/// Load the whole file into [`egui::DroppedFile::bytes`], piece by piece,
+/// sending [`egui::Event::DroppedFileProgress`] along the way.
+async fn load_dropped_file(
+    runner_ref: WebRunner,
+    file: web_sys::File,
+    mut dropped_file: egui::DroppedFile,
+) {
+    const CHUNK_SIZE: u64 = 16 * 1024 * 1024;
+
+    let total = file.size() as u64;
+    let mut bytes = Vec::with_capacity(total as usize);
+    while (bytes.len() as u64) < total {
+        let start = bytes.len() as u64;
+        let end = (start + CHUNK_SIZE).min(total);
+        match super::dropped_files::read_blob(&file, start..end).await {
+            Ok(chunk) if !chunk.is_empty() => bytes.extend_from_slice(&chunk),
+            Ok(_) => break, // The file shrunk while we were reading it
+            Err(err) => {
+                log::error!("Failed to read file: {err:?}");
+                return;
+            }
+        }
+
+        if let Some(mut runner_lock) = runner_ref.try_lock() {
+            runner_lock
+                .input
+                .raw
+                .events
+                .push(egui::Event::DroppedFileProgress {
+                    name: dropped_file.name.clone(),
+                    loaded: bytes.len() as u64,
+                    total,
+                });
+            runner_lock.needs_repaint.repaint_asap();
+        }
+    }
+
+    log::debug!("Loaded {:?} ({} bytes).", dropped_file.name, bytes.len());
+    dropped_file.bytes = Some(bytes.into());
+    if let Some(mut runner_lock) = runner_ref.try_lock() {
+        runner_lock.input.raw.dropped_files.push(dropped_file);
+        runner_lock.needs_repaint.repaint_asap();
+    }
+}
+
 /// A `ResizeObserver` is used to observe changes to the size of the canvas.
 ///
 /// The resize observer is called the by the browser at `observe` time, instead of just on the first actual resize.
@@ -973,7 +1061,6 @@ impl ResizeObserverContext {
                 }
                 // Only call the wrapped closure if the egui code has not panicked
                 if let Some(mut runner_lock) = runner_ref.try_lock() {
-                    let canvas = runner_lock.canvas();
                     let (width, height) = match get_display_size(&entries) {
                         Ok(v) => v,
                         Err(err) => {
@@ -987,8 +1074,7 @@ impl ResizeObserverContext {
                             web_sys::window().unwrap().device_pixel_ratio()
                         );
                     }
-                    canvas.set_width(width);
-                    canvas.set_height(height);
+                    runner_lock.set_canvas_size_in_pixels((width, height).into());
 
                     // force an immediate repaint
                     runner_lock.needs_repaint.repaint_asap();
@@ -1016,12 +1102,29 @@ impl ResizeObserverContext {
         if DEBUG_RESIZE {
             log::info!("Calling observe on canvas…");
         }
+        // Observing the size in device pixels means we are also called when only the DPR changes,
+        // e.g. when the window is moved to another monitor, and the size is exact.
+        // Browsers that don't support it throw on the unknown option, so check first:
+        let box_option = if supports_device_pixel_content_box() {
+            web_sys::ResizeObserverBoxOptions::DevicePixelContentBox
+        } else {
+            web_sys::ResizeObserverBoxOptions::ContentBox
+        };
         let options = web_sys::ResizeObserverOptions::new();
-        options.set_box(web_sys::ResizeObserverBoxOptions::ContentBox);
+        options.set_box(box_option);
         self.observer.observe_with_options(canvas, &options);
     }
 }
 
+fn supports_device_pixel_content_box() -> bool {
+    let Some(window) = web_sys::window() else {
+        return false;
+    };
+    js_sys::Reflect::get(&window, &JsValue::from_str("ResizeObserverEntry"))
+        .and_then(|entry| js_sys::Reflect::get(&entry, &JsValue::from_str("prototype")))
+        .is_ok_and(|prototype| JsValue::from_str("devicePixelContentBoxSize").js_in(&prototype))
+}
+
 // Code ported to Rust from:
 // https://webglfundamentals.org/webgl/lessons/webgl-resizing-the-canvas.html
 fn get_display_size(resize_observer_entries: &js_sys::Array) -> Result<(u32, u32), JsValue> {
@@ -0,0 +1,179 @@
+//! [`epi::Storage`] backed by `IndexedDB`, see [`crate::WebStorageBackend::IndexedDb`].
+//!
+//! `IndexedDB` is asynchronous, but [`epi::Storage`] is not,
+//! so everything is read into memory when the app starts,
+//! and changes are written back in the background on [`epi::Storage::flush`].
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use wasm_bindgen::{JsCast as _, prelude::*};
+
+use crate::epi;
+
+const OBJECT_STORE: &str = "eframe";
+
+pub(crate) struct IndexedDbStorage {
+    db: web_sys::IdbDatabase,
+    entries: BTreeMap<String, String>,
+    dirty: BTreeSet<String>,
+}
+
+impl IndexedDbStorage {
+    /// Open the database of the app with the given [`crate::WebOptions::storage_key`]
+    /// and load everything in it.
+    ///
+    /// When the database is new, whatever the app had stored in `localStorage` is copied over.
+    pub async fn open(storage_key: Option<&str>) -> Result<Self, JsValue> {
+        let factory = web_sys::window()
+            .ok_or_else(|| JsValue::from_str("No window"))?
+            .indexed_db()?
+            .ok_or_else(|| JsValue::from_str("IndexedDB is not available"))?;
+
+        let name = super::storage::prefixed_key(storage_key, "eframe");
+        let request = factory.open_with_u32(&name, 1)?;
+
+        let created = std::rc::Rc::new(std::cell::Cell::new(false));
+        let on_upgrade_needed = Closure::<dyn FnMut(web_sys::Event)>::new({
+            let request = request.clone();
+            let created = created.clone();
+            move |_event: web_sys::Event| {
+                let db = request
+                    .result()
+                    .and_then(|db| db.dyn_into::<web_sys::IdbDatabase>());
+                match db.and_then(|db| db.create_object_store(OBJECT_STORE)) {
+                    Ok(_) => created.set(true),
+                    Err(err) => log::error!(
+                        "Failed to create IndexedDB object store: {}",
+                        super::string_from_js_value(&err)
+                    ),
+                }
+            }
+        });
+        request.set_onupgradeneeded(Some(on_upgrade_needed.as_ref().unchecked_ref()));
+        let db = wait_for(&request).await;
+        request.set_onupgradeneeded(None);
+        drop(on_upgrade_needed);
+        let db: web_sys::IdbDatabase = db?.dyn_into()?;
+
+        let mut storage = Self {
+            db,
+            entries: Default::default(),
+            dirty: Default::default(),
+        };
+
+        if created.get() {
+            storage.migrate_from_local_storage(storage_key);
+            storage.flush_dirty();
+        } else {
+            storage.load_all().await?;
+        }
+
+        Ok(storage)
+    }
+
+    async fn load_all(&mut self) -> Result<(), JsValue> {
+        let transaction = self.db.transaction_with_str(OBJECT_STORE)?;
+        let store = transaction.object_store(OBJECT_STORE)?;
+        let keys = store.get_all_keys()?;
+        let values = store.get_all()?;
+        let keys: js_sys::Array = wait_for(&keys).await?.dyn_into()?;
+        let values: js_sys::Array = wait_for(&values).await?.dyn_into()?;
+
+        for (key, value) in keys.iter().zip(values.iter()) {
+            if let (Some(key), Some(value)) = (key.as_string(), value.as_string()) {
+                self.entries.insert(key, value);
+            }
+        }
+        Ok(())
+    }
+
+    /// Copy the keys the app has written to `localStorage`, leaving them there in case the app is downgraded.
+    ///
+    /// Only the keys recorded by the `localStorage` backend, and those of eframe itself, are copied,
+    /// so that we don't pick up other apps' data from the same origin.
+    fn migrate_from_local_storage(&mut self, storage_key: Option<&str>) {
+        let mut keys = super::storage::local_storage_keys(storage_key);
+        keys.insert(super::storage::MEMORY_KEY.to_owned());
+        keys.insert(crate::APP_KEY.to_owned());
+
+        for key in keys {
+            let full_key = super::storage::prefixed_key(storage_key, &key);
+            if let Some(value) = super::storage::local_storage_get(&full_key) {
+                self.dirty.insert(key.clone());
+                self.entries.insert(key, value);
+            }
+        }
+
+        if !self.entries.is_empty() {
+            log::debug!(
+                "Copied {} entries from localStorage to IndexedDB",
+                self.entries.len()
+            );
+        }
+    }
+
+    /// Start writing everything that changed. This finishes in the background.
+    fn flush_dirty(&mut self) {
+        if self.dirty.is_empty() {
+            return;
+        }
+
+        let result = (|| {
+            let transaction = self.db.transaction_with_str_and_mode(
+                OBJECT_STORE,
+                web_sys::IdbTransactionMode::Readwrite,
+            )?;
+            let store = transaction.object_store(OBJECT_STORE)?;
+            for key in &self.dirty {
+                if let Some(value) = self.entries.get(key) {
+                    store.put_with_key(&JsValue::from_str(value), &JsValue::from_str(key))?;
+                }
+            }
+            Ok::<_, JsValue>(())
+        })();
+
+        match result {
+            Ok(()) => self.dirty.clear(),
+            Err(err) => log::warn!(
+                "Failed to write to IndexedDB: {}",
+                super::string_from_js_value(&err)
+            ),
+        }
+    }
+}
+
+impl epi::Storage for IndexedDbStorage {
+    fn get_string(&self, key: &str) -> Option<String> {
+        self.entries.get(key).cloned()
+    }
+
+    fn set_string(&mut self, key: &str, value: String) {
+        if self.entries.get(key) != Some(&value) {
+            self.entries.insert(key.to_owned(), value);
+            self.dirty.insert(key.to_owned());
+        }
+    }
+
+    fn flush(&mut self) {
+        self.flush_dirty();
+    }
+}
+
+/// Wait for the request to succeed or fail, and return its result.
+async fn wait_for(request: &web_sys::IdbRequest) -> Result<JsValue, JsValue> {
+    let promise = js_sys::Promise::new(&mut |resolve, reject| {
+        request.set_onsuccess(Some(&resolve));
+        request.set_onerror(Some(&reject));
+    });
+    let result = wasm_bindgen_futures::JsFuture::from(promise).await;
+    request.set_onsuccess(None);
+    request.set_onerror(None);
+
+    match result {
+        Ok(_) => request.result(),
+        Err(_) => Err(request.error().ok().flatten().map_or_else(
+            || JsValue::from_str("IndexedDB request failed"),
+            JsValue::from,
+        )),
+    }
+}
@@ -5,6 +5,7 @@
 mod app_runner;
 mod backend;
 mod events;
+mod indexed_db;
 mod input;
 mod panic_handler;
 mod text_agent;
@@ -15,6 +16,9 @@ mod web_runner;
 #[cfg(feature = "web_screen_reader")]
 pub mod screen_reader;
 
+/// Access to files dropped on the canvas.
+pub mod dropped_files;
+
 /// Access to local browser storage.
 pub mod storage;
 
@@ -27,16 +31,18 @@ pub use web_runner::WebRunner;
 compile_error!("You must enable either the 'glow' or 'wgpu' feature");
 
 mod web_painter;
+pub(crate) use web_painter::ActiveWebPainter;
 
 #[cfg(feature = "glow")]
 mod web_painter_glow;
-#[cfg(feature = "glow")]
-pub(crate) type ActiveWebPainter = web_painter_glow::WebPainterGlow;
 
 #[cfg(feature = "wgpu")]
 mod web_painter_wgpu;
-#[cfg(all(feature = "wgpu", not(feature = "glow")))]
-pub(crate) type ActiveWebPainter = web_painter_wgpu::WebPainterWgpu;
+
+#[cfg(feature = "web_worker")]
+mod worker;
+#[cfg(feature = "web_worker")]
+pub use worker::WorkerRunner;
 
 pub use backend::*;
 
@@ -166,14 +172,14 @@ fn canvas_content_rect(canvas: &web_sys::HtmlCanvasElement) -> egui::Rect {
     rect
 }
 
-fn canvas_size_in_points(canvas: &web_sys::HtmlCanvasElement, ctx: &egui::Context) -> egui::Vec2 {
+fn canvas_size_in_points(size_in_pixels: [u32; 2], ctx: &egui::Context) -> egui::Vec2 {
     // ctx.pixels_per_point can be outdated
 
     let pixels_per_point = ctx.zoom_factor() * native_pixels_per_point();
 
     egui::vec2(
-        canvas.width() as f32 / pixels_per_point,
-        canvas.height() as f32 / pixels_per_point,
+        size_in_pixels[0] as f32 / pixels_per_point,
+        size_in_pixels[1] as f32 / pixels_per_point,
     )
 }
 
@@ -254,6 +260,127 @@ fn set_clipboard_image(image: &egui::ColorImage) {
     }
 }
 
+/// Events from reading the clipboard asynchronously,
+/// added to the input of the next frame.
+pub(crate) type ClipboardEvents = std::rc::Rc<std::cell::RefCell<Vec<egui::Event>>>;
+
+/// Read text and images from the clipboard, e.g. for [`egui::ViewportCommand::RequestPaste`].
+///
+/// The browser may ask the user for permission first.
+/// If that is denied, or the browser can't read clipboard items, we fall back to reading text.
+/// If that fails too, the user can still paste with the keyboard as usual.
+fn read_clipboard(events: ClipboardEvents, needs_repaint: std::sync::Arc<NeedRepaint>) {
+    let Some(window) = web_sys::window() else {
+        return;
+    };
+    if !window.is_secure_context() {
+        log::error!(
+            "Clipboard is not available because we are not in a secure context. \
+            See https://developer.mozilla.org/en-US/docs/Web/Security/Secure_Contexts"
+        );
+        return;
+    }
+
+    let clipboard = window.navigator().clipboard();
+    let future = async move {
+        let pasted = match read_clipboard_items(&clipboard).await {
+            Ok(pasted) => pasted,
+            Err(err) => {
+                log::debug!(
+                    "Failed to read clipboard items: {}",
+                    string_from_js_value(&err)
+                );
+                match wasm_bindgen_futures::JsFuture::from(clipboard.read_text()).await {
+                    Ok(text) => text
+                        .as_string()
+                        .filter(|text| !text.is_empty())
+                        .map(|text| egui::Event::Paste(text.replace("\r\n", "\n")))
+                        .into_iter()
+                        .collect(),
+                    Err(err) => {
+                        log::warn!("Failed to read clipboard: {}", string_from_js_value(&err));
+                        return;
+                    }
+                }
+            }
+        };
+        events.borrow_mut().extend(pasted);
+        needs_repaint.repaint_asap();
+    };
+    wasm_bindgen_futures::spawn_local(future);
+}
+
+/// Read every item on the clipboard, preferring images over text.
+async fn read_clipboard_items(clipboard: &web_sys::Clipboard) -> Result<Vec<egui::Event>, JsValue> {
+    let items: js_sys::Array = wasm_bindgen_futures::JsFuture::from(clipboard.read())
+        .await?
+        .dyn_into()?;
+
+    let mut pasted = vec![];
+    for item in items {
+        let item: web_sys::ClipboardItem = item.dyn_into()?;
+        let types: Vec<String> = item.types().iter().filter_map(|t| t.as_string()).collect();
+        if let Some(mime) = types.iter().find(|mime| mime.starts_with("image/")) {
+            let blob: web_sys::Blob = wasm_bindgen_futures::JsFuture::from(item.get_type(mime))
+                .await?
+                .dyn_into()?;
+            match image_from_blob(&blob).await {
+                Ok(image) => pasted.push(egui::Event::PasteImage(image.into())),
+                Err(err) => log::warn!("Failed to paste {mime}: {err}"),
+            }
+        } else if types.iter().any(|mime| mime == "text/plain") {
+            let blob: web_sys::Blob =
+                wasm_bindgen_futures::JsFuture::from(item.get_type("text/plain"))
+                    .await?
+                    .dyn_into()?;
+            let text = wasm_bindgen_futures::JsFuture::from(blob.text())
+                .await?
+                .as_string()
+                .unwrap_or_default();
+            if !text.is_empty() {
+                pasted.push(egui::Event::Paste(text.replace("\r\n", "\n")));
+            }
+        }
+    }
+    Ok(pasted)
+}
+
+/// Decode an image that was pasted with a "paste" event, and add it to `events`.
+fn paste_image(
+    blob: web_sys::Blob,
+    events: ClipboardEvents,
+    needs_repaint: std::sync::Arc<NeedRepaint>,
+) {
+    let future = async move {
+        match image_from_blob(&blob).await {
+            Ok(image) => {
+                events
+                    .borrow_mut()
+                    .push(egui::Event::PasteImage(image.into()));
+                needs_repaint.repaint_asap();
+            }
+            Err(err) => {
+                log::warn!("Failed to paste {}: {err}", blob.type_());
+            }
+        }
+    };
+    wasm_bindgen_futures::spawn_local(future);
+}
+
+async fn image_from_blob(blob: &web_sys::Blob) -> Result<egui::ColorImage, String> {
+    let array_buffer = wasm_bindgen_futures::JsFuture::from(blob.array_buffer())
+        .await
+        .map_err(|err| string_from_js_value(&err))?;
+    let bytes = js_sys::Uint8Array::new(&array_buffer).to_vec();
+    let image = image::load_from_memory(&bytes)
+        .map_err(|err| err.to_string())?
+        .into_rgba8();
+    Ok(egui::ColorImage::from_rgba_unmultiplied(
+        [image.width() as _, image.height() as _],
+        image.as_flat_samples().as_slice(),
+    ))
+}
+
 fn to_image(image: &egui::ColorImage) -> Result<image::RgbaImage, String> {
     profiling::function_scope!();
     image::RgbaImage::from_raw(
@@ -351,6 +478,71 @@ pub fn open_url(url: &str, new_tab: bool) -> Option<()> {
     Some(())
 }
 
+/// Vibrate the device, if it supports it.
+pub fn vibrate(duration_ms: u32) {
+    if let Some(window) = web_sys::window() {
+        window.navigator().vibrate_with_duration(duration_ms);
+    }
+}
+
+/// Make the canvas fill the screen, or leave fullscreen.
+///
+/// Browsers only allow entering fullscreen shortly after the user clicked or pressed a key.
+pub(crate) fn set_fullscreen(canvas: &web_sys::HtmlCanvasElement, fullscreen: bool) {
+    if fullscreen == is_fullscreen(canvas) {
+        return;
+    }
+    if fullscreen {
+        if let Err(err) = canvas.request_fullscreen() {
+            log::warn!("Failed to enter fullscreen: {}", string_from_js_value(&err));
+        }
+    } else if let Some(document) = web_sys::window().and_then(|window| window.document()) {
+        document.exit_fullscreen();
+    }
+}
+
+/// Is the canvas fullscreen?
+pub(crate) fn is_fullscreen(canvas: &web_sys::HtmlCanvasElement) -> bool {
+    let root = canvas.get_root_node();
+    let element = if let Some(document) = root.dyn_ref::<Document>() {
+        document.fullscreen_element()
+    } else if let Some(shadow) = root.dyn_ref::<web_sys::ShadowRoot>() {
+        shadow.fullscreen_element()
+    } else {
+        None
+    };
+    element.is_some_and(|element| element.eq(canvas))
+}
+
+/// Lock the pointer to the canvas, hiding it, or let go of it.
+///
+/// While locked, mouse movement is sent as [`egui::Event::MouseMoved`] instead of [`egui::Event::PointerMoved`].
+/// Browsers only allow locking shortly after the user clicked or pressed a key,
+/// and unlock when the user presses escape.
+pub(crate) fn set_pointer_locked(canvas: &web_sys::HtmlCanvasElement, locked: bool) {
+    if locked == is_pointer_locked(canvas) {
+        return;
+    }
+    if locked {
+        canvas.request_pointer_lock();
+    } else if let Some(document) = web_sys::window().and_then(|window| window.document()) {
+        document.exit_pointer_lock();
+    }
+}
+
+/// Is the pointer locked to the canvas?
+pub(crate) fn is_pointer_locked(canvas: &web_sys::HtmlCanvasElement) -> bool {
+    let root = canvas.get_root_node();
+    let element = if let Some(document) = root.dyn_ref::<Document>() {
+        document.pointer_lock_element()
+    } else if let Some(shadow) = root.dyn_ref::<web_sys::ShadowRoot>() {
+        shadow.pointer_lock_element()
+    } else {
+        None
+    };
+    element.is_some_and(|element| element.eq(canvas))
+}
+
 /// e.g. "#fragment" part of "www.example.com/index.html#fragment",
 ///
 /// Percent decoded
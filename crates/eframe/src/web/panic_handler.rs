@@ -13,7 +13,17 @@ pub struct PanicHandler(Arc<Mutex<PanicHandlerInner>>);
 
 impl PanicHandler {
     /// Install a panic hook.
+    ///
+    /// The hook is only installed once, so calling this again
+    /// (e.g. when starting several [`crate::WebRunner`]s on the same page)
+    /// returns a handle to the same handler.
+    /// A panic poisons the whole wasm module, so it stops all runners.
     pub fn install() -> Self {
+        static HANDLER: std::sync::OnceLock<PanicHandler> = std::sync::OnceLock::new();
+        HANDLER.get_or_init(Self::install_hook).clone()
+    }
+
+    fn install_hook() -> Self {
         let handler = Self(Arc::new(Mutex::new(Default::default())));
 
         let handler_clone = handler.clone();
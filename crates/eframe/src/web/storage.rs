@@ -1,3 +1,7 @@
+use std::collections::BTreeSet;
+
+use wasm_bindgen::JsValue;
+
 fn local_storage() -> Option<web_sys::Storage> {
     web_sys::window()?.local_storage().ok()?
 }
@@ -12,9 +16,46 @@ pub fn local_storage_set(key: &str, value: &str) {
     local_storage().map(|storage| storage.set_item(key, value));
 }
 
+/// The actual `localStorage` key of `key` for an app with the given [`crate::WebOptions::storage_key`].
+pub(crate) fn prefixed_key(storage_key: Option<&str>, key: &str) -> String {
+    match storage_key {
+        Some(storage_key) => format!("{storage_key}/{key}"),
+        None => key.to_owned(),
+    }
+}
+
+/// Where the egui memory is stored.
+pub(crate) const MEMORY_KEY: &str = "egui_memory_ron";
+
+/// Where an app lists the keys it has written to `localStorage`,
+/// so that they can be told apart from the keys of other apps on the same origin.
+const KEYS_KEY: &str = "eframe_keys";
+
+/// The keys the app with the given [`crate::WebOptions::storage_key`] has written to `localStorage`,
+/// as recorded by [`set_local_storage_keys`].
+pub(crate) fn local_storage_keys(storage_key: Option<&str>) -> BTreeSet<String> {
+    local_storage_get(&prefixed_key(storage_key, KEYS_KEY))
+        .and_then(|json| js_sys::JSON::parse(&json).ok())
+        .map(|keys| {
+            js_sys::Array::from(&keys)
+                .iter()
+                .filter_map(|key| key.as_string())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Record which keys the app with the given [`crate::WebOptions::storage_key`] has written to `localStorage`.
+pub(crate) fn set_local_storage_keys(storage_key: Option<&str>, keys: &BTreeSet<String>) {
+    let keys: js_sys::Array = keys.iter().map(|key| JsValue::from_str(key)).collect();
+    if let Ok(json) = js_sys::JSON::stringify(&keys) {
+        local_storage_set(&prefixed_key(storage_key, KEYS_KEY), &String::from(json));
+    }
+}
+
 #[cfg(feature = "persistence")]
-pub(crate) fn load_memory(ctx: &egui::Context) {
-    if let Some(memory_string) = local_storage_get("egui_memory_ron") {
+pub(crate) fn load_memory(ctx: &egui::Context, storage: &dyn crate::Storage) {
+    if let Some(memory_string) = storage.get_string(MEMORY_KEY) {
         match ron::from_str(&memory_string) {
             Ok(memory) => {
                 ctx.memory_mut(|m| *m = memory);
@@ -27,13 +68,13 @@ pub(crate) fn load_memory(ctx: &egui::Context) {
 }
 
 #[cfg(not(feature = "persistence"))]
-pub(crate) fn load_memory(_: &egui::Context) {}
+pub(crate) fn load_memory(_: &egui::Context, _: &dyn crate::Storage) {}
 
 #[cfg(feature = "persistence")]
-pub(crate) fn save_memory(ctx: &egui::Context) {
+pub(crate) fn save_memory(ctx: &egui::Context, storage: &mut dyn crate::Storage) {
     match ctx.memory(ron::to_string) {
         Ok(ron) => {
-            local_storage_set("egui_memory_ron", &ron);
+            storage.set_string(MEMORY_KEY, ron);
         }
         Err(err) => {
             log::warn!("Failed to serialize memory as RON: {err}");
@@ -42,4 +83,4 @@ pub(crate) fn save_memory(ctx: &egui::Context) {
 }
 
 #[cfg(not(feature = "persistence"))]
-pub(crate) fn save_memory(_: &egui::Context) {}
+pub(crate) fn save_memory(_: &egui::Context, _: &mut dyn crate::Storage) {}
@@ -1,7 +1,7 @@
 //! The text agent is a hidden `<input>` element used to capture
 //! IME and mobile keyboard input events.
 
-use std::cell::Cell;
+use std::{cell::Cell, rc::Rc};
 
 use wasm_bindgen::prelude::*;
 use web_sys::{Document, Node};
@@ -35,6 +35,7 @@ impl TextAgent {
         style.set_property("width", "1px")?;
         style.set_property("height", "1px")?;
         style.set_property("caret-color", "transparent")?;
+        style.set_property("color", "transparent")?;
         style.set_property("position", "absolute")?;
         style.set_property("top", "0")?;
         style.set_property("left", "0")?;
@@ -52,20 +53,31 @@ impl TextAgent {
 
         // attach event listeners
 
+        // Safari sends the final `input` event of a composition with `is_composing` set to false,
+        // before `compositionend`, so we keep track of compositions ourselves.
+        let composing = Rc::new(Cell::new(false));
+
         let on_input = {
             let input = input.clone();
+            let composing = composing.clone();
             move |event: web_sys::InputEvent, runner: &mut AppRunner| {
-                let text = input.value();
-                // Fix android virtual keyboard Gboard
-                // This removes the virtual keyboard's suggestion.
-                if !event.is_composing() {
-                    input.blur().ok();
-                    input.focus().ok();
-                }
                 // if `is_composing` is true, then user is using IME, for example: emoji, pinyin, kanji, hangul, etc.
                 // In that case, the browser emits both `input` and `compositionupdate` events,
                 // and we need to ignore the `input` event.
-                if !text.is_empty() && !event.is_composing() {
+                if event.is_composing()
+                    || composing.get()
+                    || event.input_type().contains("Composition")
+                {
+                    return;
+                }
+
+                let text = input.value();
+                // Fix android virtual keyboard Gboard
+                // This removes the virtual keyboard's suggestion.
+                input.blur().ok();
+                input.focus().ok();
+
+                if !text.is_empty() {
                     input.set_value("");
                     let event = egui::Event::Text(text);
                     runner.input.raw.events.push(event);
@@ -76,7 +88,9 @@ impl TextAgent {
 
         let on_composition_start = {
             let input = input.clone();
+            let composing = composing.clone();
             move |_: web_sys::CompositionEvent, runner: &mut AppRunner| {
+                composing.set(true);
                 input.set_value("");
                 let event = egui::Event::Ime(egui::ImeEvent::Enabled);
                 runner.input.raw.events.push(event);
@@ -98,10 +112,21 @@ impl TextAgent {
         let on_composition_end = {
             let input = input.clone();
             move |event: web_sys::CompositionEvent, runner: &mut AppRunner| {
-                let Some(text) = event.data() else { return };
+                composing.set(false);
                 input.set_value("");
-                let event = egui::Event::Ime(egui::ImeEvent::Commit(text));
-                runner.input.raw.events.push(event);
+                let text = event.data().unwrap_or_default();
+                let event = if text.is_empty() {
+                    // The composition was canceled, so remove the preedit text:
+                    egui::ImeEvent::Preedit(text)
+                } else {
+                    egui::ImeEvent::Commit(text)
+                };
+                runner.input.raw.events.push(egui::Event::Ime(event));
+                runner
+                    .input
+                    .raw
+                    .events
+                    .push(egui::Event::Ime(egui::ImeEvent::Disabled));
                 runner.needs_repaint.repaint_asap();
             }
         };
@@ -141,19 +166,17 @@ impl TextAgent {
         if is_mobile_safari() {
             canvas_rect.min.y = canvas.offset_top() as f32;
         }
-        let cursor_rect = ime.cursor_rect.translate(canvas_rect.min.to_vec2());
+        // From egui points to CSS pixels:
+        let cursor_rect = (ime.cursor_rect * zoom_factor).translate(canvas_rect.min.to_vec2());
 
         let style = self.input.style();
 
-        // This is where the IME input will point to:
-        style.set_property(
-            "left",
-            &format!("{}px", cursor_rect.center().x * zoom_factor),
-        )?;
-        style.set_property(
-            "top",
-            &format!("{}px", cursor_rect.center().y * zoom_factor),
-        )?;
+        // This is where the IME input will point to.
+        // Covering the text cursor puts the candidate window right below the line being edited.
+        style.set_property("left", &format!("{}px", cursor_rect.min.x))?;
+        style.set_property("top", &format!("{}px", cursor_rect.min.y))?;
+        style.set_property("height", &format!("{}px", cursor_rect.height()))?;
+        style.set_property("font-size", &format!("{}px", cursor_rect.height()))?;
 
         Ok(())
     }
@@ -1,17 +1,71 @@
 use egui::{Event, UserData};
 use wasm_bindgen::JsValue;
 
+/// The canvas a painter paints to.
+#[derive(Clone)]
+pub(crate) enum PaintCanvas {
+    /// A `<canvas>` element on the page.
+    Element(web_sys::HtmlCanvasElement),
+
+    /// A canvas whose control was transferred to a web worker, see [`super::WorkerRunner`].
+    #[cfg(feature = "web_worker")]
+    Offscreen(web_sys::OffscreenCanvas),
+}
+
+impl PaintCanvas {
+    /// Size in physical pixels.
+    pub fn size_in_pixels(&self) -> [u32; 2] {
+        match self {
+            Self::Element(canvas) => [canvas.width(), canvas.height()],
+
+            #[cfg(feature = "web_worker")]
+            Self::Offscreen(canvas) => [canvas.width(), canvas.height()],
+        }
+    }
+
+    /// Resize the drawing buffer, in physical pixels.
+    pub fn set_size_in_pixels(&self, [width, height]: [u32; 2]) {
+        match self {
+            Self::Element(canvas) => {
+                canvas.set_width(width);
+                canvas.set_height(height);
+            }
+
+            #[cfg(feature = "web_worker")]
+            Self::Offscreen(canvas) => {
+                canvas.set_width(width);
+                canvas.set_height(height);
+            }
+        }
+    }
+
+    /// Calls `getContext` on the canvas.
+    #[cfg(feature = "glow")]
+    pub fn get_context(&self, context_id: &str) -> Result<Option<js_sys::Object>, JsValue> {
+        match self {
+            Self::Element(canvas) => canvas.get_context(context_id),
+
+            #[cfg(feature = "web_worker")]
+            Self::Offscreen(canvas) => canvas.get_context(context_id),
+        }
+    }
+}
+
 /// Renderer for a browser canvas.
-/// As of writing we're not allowing to decide on the painter at runtime,
-/// therefore this trait is merely there for specifying and documenting the interface.
+///
+/// Implemented by [`super::web_painter_glow::WebPainterGlow`] and [`super::web_painter_wgpu::WebPainterWgpu`],
+/// and picked at runtime by [`ActiveWebPainter`].
 pub(crate) trait WebPainter {
     // Create a new web painter targeting a given canvas.
-    // fn new(canvas: HtmlCanvasElement, options: &WebOptions) -> Result<Self, String>
+    // fn new(canvas: PaintCanvas, options: &WebOptions) -> Result<Self, String>
     // where
     //     Self: Sized;
 
-    /// Reference to the canvas in use.
-    fn canvas(&self) -> &web_sys::HtmlCanvasElement;
+    /// Size of the canvas in use, in physical pixels.
+    fn size_in_pixels(&self) -> [u32; 2];
+
+    /// Resize the canvas in use, in physical pixels.
+    fn set_size_in_pixels(&mut self, size: [u32; 2]);
 
     /// Maximum size of a texture in one direction.
     fn max_texture_side(&self) -> usize;
@@ -33,3 +87,210 @@ pub(crate) trait WebPainter {
     /// Destroy all resources.
     fn destroy(&mut self);
 }
+
+/// The painter picked at runtime from [`crate::WebOptions::renderer`].
+pub(crate) enum ActiveWebPainter {
+    #[cfg(feature = "glow")]
+    Glow(super::web_painter_glow::WebPainterGlow),
+
+    #[cfg(feature = "wgpu")]
+    Wgpu(super::web_painter_wgpu::WebPainterWgpu),
+
+    /// The app is painted by a web worker, see [`crate::WebOptions::worker_url`].
+    #[cfg(feature = "web_worker")]
+    Worker(super::worker::WorkerPainter),
+}
+
+impl ActiveWebPainter {
+    /// Create the painter asked for in [`crate::WebOptions::renderer`].
+    ///
+    /// If WebGPU was asked for but the browser doesn't support it (`navigator.gpu` is missing),
+    /// or no adapter could be found, we fall back to WebGL when the `glow` feature is enabled.
+    /// We check for an adapter before touching the canvas,
+    /// since a canvas that has a WebGPU context can't give us a WebGL one.
+    ///
+    /// If [`crate::WebOptions::worker_url`] is set, the canvas is instead transferred to a web worker.
+    pub async fn new(
+        ctx: egui::Context,
+        canvas: PaintCanvas,
+        options: &crate::WebOptions,
+    ) -> Result<Self, String> {
+        #[cfg(feature = "web_worker")]
+        if let (Some(worker_url), PaintCanvas::Element(canvas)) = (&options.worker_url, &canvas) {
+            return super::worker::WorkerPainter::new(ctx, canvas, worker_url).map(Self::Worker);
+        }
+
+        match options.renderer {
+            #[cfg(feature = "glow")]
+            crate::Renderer::Glow => {
+                log::debug!("Using the glow renderer");
+                super::web_painter_glow::WebPainterGlow::new(ctx, canvas, options)
+                    .await
+                    .map(Self::Glow)
+            }
+
+            #[cfg(feature = "wgpu")]
+            crate::Renderer::Wgpu => {
+                #[cfg(feature = "glow")]
+                if !has_webgpu_adapter().await {
+                    log::info!("WebGPU is not available in this browser, falling back to WebGL");
+                    return super::web_painter_glow::WebPainterGlow::new(ctx, canvas, options)
+                        .await
+                        .map(Self::Glow);
+                }
+
+                log::debug!("Using the wgpu renderer");
+                match super::web_painter_wgpu::WebPainterWgpu::new(
+                    ctx.clone(),
+                    canvas.clone(),
+                    options,
+                )
+                .await
+                {
+                    Ok(painter) => Ok(Self::Wgpu(painter)),
+
+                    #[cfg(feature = "glow")]
+                    Err(wgpu_err) => {
+                        log::warn!("Failed to start wgpu ({wgpu_err}), falling back to WebGL");
+                        super::web_painter_glow::WebPainterGlow::new(ctx, canvas, options)
+                            .await
+                            .map(Self::Glow)
+                            .map_err(|glow_err| {
+                                format!("wgpu failed: {wgpu_err}. glow failed: {glow_err}")
+                            })
+                    }
+
+                    #[cfg(not(feature = "glow"))]
+                    Err(err) => Err(err),
+                }
+            }
+        }
+    }
+
+    /// The WebGL context, if using [`crate::Renderer::Glow`].
+    #[cfg(feature = "glow")]
+    #[cfg_attr(
+        not(any(feature = "wgpu", feature = "web_worker")),
+        expect(clippy::unnecessary_wraps)
+    )]
+    pub fn gl(&self) -> Option<std::sync::Arc<egui_glow::glow::Context>> {
+        match self {
+            Self::Glow(painter) => Some(painter.gl().clone()),
+
+            #[cfg(feature = "wgpu")]
+            Self::Wgpu(_) => None,
+
+            #[cfg(feature = "web_worker")]
+            Self::Worker(_) => None,
+        }
+    }
+
+    /// The wgpu render state, if using [`crate::Renderer::Wgpu`].
+    #[cfg(feature = "wgpu")]
+    pub fn render_state(&self) -> Option<egui_wgpu::RenderState> {
+        match self {
+            #[cfg(feature = "glow")]
+            Self::Glow(_) => None,
+
+            Self::Wgpu(painter) => painter.render_state(),
+
+            #[cfg(feature = "web_worker")]
+            Self::Worker(_) => None,
+        }
+    }
+
+    fn inner(&self) -> &dyn WebPainter {
+        match self {
+            #[cfg(feature = "glow")]
+            Self::Glow(painter) => painter,
+
+            #[cfg(feature = "wgpu")]
+            Self::Wgpu(painter) => painter,
+
+            #[cfg(feature = "web_worker")]
+            Self::Worker(painter) => painter,
+        }
+    }
+
+    fn inner_mut(&mut self) -> &mut dyn WebPainter {
+        match self {
+            #[cfg(feature = "glow")]
+            Self::Glow(painter) => painter,
+
+            #[cfg(feature = "wgpu")]
+            Self::Wgpu(painter) => painter,
+
+            #[cfg(feature = "web_worker")]
+            Self::Worker(painter) => painter,
+        }
+    }
+}
+
+impl WebPainter for ActiveWebPainter {
+    fn size_in_pixels(&self) -> [u32; 2] {
+        self.inner().size_in_pixels()
+    }
+
+    fn set_size_in_pixels(&mut self, size: [u32; 2]) {
+        self.inner_mut().set_size_in_pixels(size);
+    }
+
+    fn max_texture_side(&self) -> usize {
+        self.inner().max_texture_side()
+    }
+
+    fn paint_and_update_textures(
+        &mut self,
+        clear_color: [f32; 4],
+        clipped_primitives: &[egui::ClippedPrimitive],
+        pixels_per_point: f32,
+        textures_delta: &egui::TexturesDelta,
+        capture: Vec<UserData>,
+    ) -> Result<(), JsValue> {
+        self.inner_mut().paint_and_update_textures(
+            clear_color,
+            clipped_primitives,
+            pixels_per_point,
+            textures_delta,
+            capture,
+        )
+    }
+
+    fn handle_screenshots(&mut self, events: &mut Vec<Event>) {
+        self.inner_mut().handle_screenshots(events);
+    }
+
+    fn destroy(&mut self) {
+        self.inner_mut().destroy();
+    }
+}
+
+/// Can the browser run WebGPU, i.e. is there a `navigator.gpu` that gives us an adapter?
+///
+/// Works both on the main thread and in web workers.
+#[cfg(all(feature = "glow", feature = "wgpu"))]
+async fn has_webgpu_adapter() -> bool {
+    use wasm_bindgen::JsCast as _;
+
+    let get = |target: &JsValue, key: &str| {
+        js_sys::Reflect::get(target, &JsValue::from_str(key))
+            .ok()
+            .filter(|value| !value.is_undefined() && !value.is_null())
+    };
+    let Some(gpu) =
+        get(&js_sys::global(), "navigator").and_then(|navigator| get(&navigator, "gpu"))
+    else {
+        return false;
+    };
+    let Some(promise) = get(&gpu, "requestAdapter")
+        .and_then(|request_adapter| request_adapter.dyn_into::<js_sys::Function>().ok())
+        .and_then(|request_adapter| request_adapter.call0(&gpu).ok())
+        .and_then(|promise| promise.dyn_into::<js_sys::Promise>().ok())
+    else {
+        return false;
+    };
+    // Resolves to `null` if there is no adapter:
+    wasm_bindgen_futures::JsFuture::from(promise)
+        .await
+        .is_ok_and(|adapter| !adapter.is_undefined() && !adapter.is_null())
+}
@@ -3,14 +3,13 @@ use egui_glow::glow;
 use std::sync::Arc;
 use wasm_bindgen::JsCast as _;
 use wasm_bindgen::JsValue;
-use web_sys::HtmlCanvasElement;
 
 use crate::{WebGlContextOption, WebOptions};
 
-use super::web_painter::WebPainter;
+use super::web_painter::{PaintCanvas, WebPainter};
 
 pub(crate) struct WebPainterGlow {
-    canvas: HtmlCanvasElement,
+    canvas: PaintCanvas,
     painter: egui_glow::Painter,
     screenshots: Vec<(egui::ColorImage, Vec<UserData>)>,
 }
@@ -22,7 +21,7 @@ impl WebPainterGlow {
 
     pub async fn new(
         _ctx: egui::Context,
-        canvas: HtmlCanvasElement,
+        canvas: PaintCanvas,
         options: &WebOptions,
     ) -> Result<Self, String> {
         let (gl, shader_prefix) =
@@ -47,8 +46,12 @@ impl WebPainter for WebPainterGlow {
         self.painter.max_texture_side()
     }
 
-    fn canvas(&self) -> &HtmlCanvasElement {
-        &self.canvas
+    fn size_in_pixels(&self) -> [u32; 2] {
+        self.canvas.size_in_pixels()
+    }
+
+    fn set_size_in_pixels(&mut self, size: [u32; 2]) {
+        self.canvas.set_size_in_pixels(size);
     }
 
     fn paint_and_update_textures(
@@ -59,7 +62,7 @@ impl WebPainter for WebPainterGlow {
         textures_delta: &egui::TexturesDelta,
         capture: Vec<UserData>,
     ) -> Result<(), JsValue> {
-        let canvas_dimension = [self.canvas.width(), self.canvas.height()];
+        let canvas_dimension = self.canvas.size_in_pixels();
 
         for (id, image_delta) in &textures_delta.set {
             self.painter.set_texture(*id, image_delta);
@@ -101,7 +104,7 @@ impl WebPainter for WebPainterGlow {
 
 /// Returns glow context and shader prefix.
 fn init_glow_context_from_canvas(
-    canvas: &HtmlCanvasElement,
+    canvas: &PaintCanvas,
     options: WebGlContextOption,
 ) -> Result<(glow::Context, &'static str), String> {
     let result = match options {
@@ -124,7 +127,7 @@ fn init_glow_context_from_canvas(
     }
 }
 
-fn init_webgl1(canvas: &HtmlCanvasElement) -> Option<(glow::Context, &'static str)> {
+fn init_webgl1(canvas: &PaintCanvas) -> Option<(glow::Context, &'static str)> {
     let gl1_ctx = canvas
         .get_context("webgl")
         .expect("Failed to query about WebGL2 context");
@@ -148,7 +151,7 @@ fn init_webgl1(canvas: &HtmlCanvasElement) -> Option<(glow::Context, &'static st
     Some((gl, shader_prefix))
 }
 
-fn init_webgl2(canvas: &HtmlCanvasElement) -> Option<(glow::Context, &'static str)> {
+fn init_webgl2(canvas: &PaintCanvas) -> Option<(glow::Context, &'static str)> {
     let gl2_ctx = canvas
         .get_context("webgl2")
         .expect("Failed to query about WebGL2 context");
@@ -1,15 +1,14 @@
 use std::sync::Arc;
 
-use super::web_painter::WebPainter;
+use super::web_painter::{PaintCanvas, WebPainter};
 use crate::WebOptions;
 use egui::{Event, UserData, ViewportId};
 use egui_wgpu::capture::{CaptureReceiver, CaptureSender, CaptureState, capture_channel};
 use egui_wgpu::{RenderState, SurfaceErrorAction};
 use wasm_bindgen::JsValue;
-use web_sys::HtmlCanvasElement;
 
 pub(crate) struct WebPainterWgpu {
-    canvas: HtmlCanvasElement,
+    canvas: PaintCanvas,
     surface: wgpu::Surface<'static>,
     surface_configuration: wgpu::SurfaceConfiguration,
     render_state: Option<RenderState>,
@@ -23,7 +22,6 @@ pub(crate) struct WebPainterWgpu {
 }
 
 impl WebPainterWgpu {
-    #[expect(unused)] // only used if `wgpu` is the only active feature.
     pub fn render_state(&self) -> Option<RenderState> {
         self.render_state.clone()
     }
@@ -55,17 +53,22 @@ impl WebPainterWgpu {
         })
     }
 
-    #[expect(unused)] // only used if `wgpu` is the only active feature.
     pub async fn new(
         ctx: egui::Context,
-        canvas: web_sys::HtmlCanvasElement,
+        canvas: PaintCanvas,
         options: &WebOptions,
     ) -> Result<Self, String> {
         log::debug!("Creating wgpu painter");
 
         let instance = options.wgpu_options.wgpu_setup.new_instance().await;
+        let surface_target = match &canvas {
+            PaintCanvas::Element(canvas) => wgpu::SurfaceTarget::Canvas(canvas.clone()),
+
+            #[cfg(feature = "web_worker")]
+            PaintCanvas::Offscreen(canvas) => wgpu::SurfaceTarget::OffscreenCanvas(canvas.clone()),
+        };
         let surface = instance
-            .create_surface(wgpu::SurfaceTarget::Canvas(canvas.clone()))
+            .create_surface(surface_target)
             .map_err(|err| format!("failed to create wgpu surface: {err}"))?;
 
         let depth_format = egui_wgpu::depth_format_from_bits(options.depth_buffer, 0);
@@ -113,8 +116,12 @@ impl WebPainterWgpu {
 }
 
 impl WebPainter for WebPainterWgpu {
-    fn canvas(&self) -> &HtmlCanvasElement {
-        &self.canvas
+    fn size_in_pixels(&self) -> [u32; 2] {
+        self.canvas.size_in_pixels()
+    }
+
+    fn set_size_in_pixels(&mut self, size: [u32; 2]) {
+        self.canvas.set_size_in_pixels(size);
     }
 
     fn max_texture_side(&self) -> usize {
@@ -133,7 +140,7 @@ impl WebPainter for WebPainterWgpu {
     ) -> Result<(), JsValue> {
         let capture = !capture_data.is_empty();
 
-        let size_in_pixels = [self.canvas.width(), self.canvas.height()];
+        let size_in_pixels = self.canvas.size_in_pixels();
 
         let Some(render_state) = &self.render_state else {
             return Err(JsValue::from_str(
@@ -14,6 +14,10 @@ use super::{
 ///
 /// This is cheap to clone.
 ///
+/// You can run several apps on the same page by starting one [`WebRunner`] per canvas.
+/// Each gets its own [`egui::Context`] and repaint loop.
+/// Give them different [`crate::WebOptions::storage_key`]s if they use persistence.
+///
 /// See [the crate level docs](crate) for an example.
 #[derive(Clone)]
 pub struct WebRunner {
@@ -32,6 +36,9 @@ pub struct WebRunner {
     /// Current animation frame in flight.
     frame: Rc<RefCell<Option<AnimationFrameRequest>>>,
 
+    /// Timer for the next delayed repaint, in [`crate::WebRepaintMode::Reactive`].
+    repaint_timer: Rc<RefCell<Option<RepaintTimer>>>,
+
     resize_observer: Rc<RefCell<Option<ResizeObserverContext>>>,
 }
 
@@ -46,6 +53,7 @@ impl WebRunner {
             app_runner: Rc::new(RefCell::new(None)),
             events_to_unsubscribe: Rc::new(RefCell::new(Default::default())),
             frame: Default::default(),
+            repaint_timer: Default::default(),
             resize_observer: Default::default(),
         }
     }
@@ -69,6 +77,18 @@ impl WebRunner {
 
             // Don't outline the canvas when it has focus:
             canvas.style().set_property("outline", "none")?;
+
+            // Inside a CSS flex or grid container, the canvas can't shrink below its contents
+            // (the size of its drawing buffer), so it would only ever grow.
+            // Let the container decide its size instead, unless the page has an opinion:
+            let window = web_sys::window().unwrap();
+            if let Some(computed) = window.get_computed_style(&canvas)? {
+                for property in ["min-width", "min-height"] {
+                    if computed.get_property_value(property)? == "auto" {
+                        canvas.style().set_property(property, "0")?;
+                    }
+                }
+            }
         }
 
         {
@@ -76,6 +96,21 @@ impl WebRunner {
             let text_agent = TextAgent::attach(self, canvas.get_root_node())?;
             let app_runner =
                 AppRunner::new(canvas.clone(), web_options, app_creator, text_agent).await?;
+
+            if app_runner.web_options.repaint_mode == crate::WebRepaintMode::Reactive {
+                // Input, animations and `request_repaint` all end up here:
+                let web_runner = self.clone();
+                let needs_repaint = app_runner.needs_repaint.clone();
+                app_runner.needs_repaint.set_waker(Some(Rc::new(move || {
+                    if let Err(err) = web_runner.schedule_wake_up(needs_repaint.when_to_repaint()) {
+                        log::error!(
+                            "Failed to schedule repaint: {}",
+                            super::string_from_js_value(&err)
+                        );
+                    }
+                })));
+            }
+
             self.app_runner.replace(Some(app_runner));
         }
 
@@ -122,6 +157,15 @@ impl WebRunner {
         }
 
         self.resize_observer.replace(None);
+
+        if let Some(timer) = self.repaint_timer.take() {
+            let window = web_sys::window().unwrap();
+            window.clear_timeout_with_handle(timer.id);
+        }
+        if let Ok(Some(runner)) = self.app_runner.try_borrow().as_deref() {
+            // The waker holds on to us, so we must let go of it:
+            runner.needs_repaint.set_waker(None);
+        }
     }
 
     /// Shut down eframe and clean up resources.
@@ -260,6 +304,54 @@ impl WebRunner {
 
         Ok(())
     }
+
+    /// Wake up at `wake_time` (in [`super::now_sec`] scale) to paint if needed,
+    /// with an animation frame if that is now, or else with a timer.
+    ///
+    /// Multiple calls are merged, and nothing is scheduled for a `wake_time` of infinity.
+    pub(crate) fn schedule_wake_up(&self, wake_time: f64) -> Result<(), JsValue> {
+        if wake_time <= super::now_sec() {
+            return self.request_animation_frame();
+        }
+        if !wake_time.is_finite() || self.frame.borrow().is_some() {
+            // Nothing to do, or the coming animation frame will schedule the next one.
+            return Ok(());
+        }
+        if let Some(timer) = &*self.repaint_timer.borrow() {
+            if timer.wake_time <= wake_time {
+                return Ok(());
+            }
+        }
+
+        let window = web_sys::window().unwrap();
+        if let Some(timer) = self.repaint_timer.take() {
+            window.clear_timeout_with_handle(timer.id);
+        }
+
+        let closure = Closure::once({
+            let web_runner = self.clone();
+            move || {
+                let _ = web_runner.repaint_timer.take();
+                web_runner.request_animation_frame()
+            }
+        });
+
+        // Round up, so that we don't wake up just before it is time:
+        let delay_ms = (1e3 * (wake_time - super::now_sec()))
+            .ceil()
+            .min(i32::MAX as f64) as i32;
+        let id = window.set_timeout_with_callback_and_timeout_and_arguments_0(
+            closure.as_ref().unchecked_ref(),
+            delay_ms,
+        )?;
+        self.repaint_timer.borrow_mut().replace(RepaintTimer {
+            id,
+            wake_time,
+            _closure: closure,
+        });
+
+        Ok(())
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -274,6 +366,16 @@ struct AnimationFrameRequest {
     _closure: Closure<dyn FnMut() -> Result<(), JsValue>>,
 }
 
+struct RepaintTimer {
+    /// Handle of the `setTimeout`.
+    id: i32,
+
+    /// When it fires, in [`super::now_sec`] scale.
+    wake_time: f64,
+
+    _closure: Closure<dyn FnMut() -> Result<(), JsValue>>,
+}
+
 struct TargetEvent {
     target: web_sys::EventTarget,
     event_name: String,
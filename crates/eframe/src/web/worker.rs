@@ -0,0 +1,552 @@
+//! Running the app in a web worker, see [`crate::WebOptions::worker_url`].
+//!
+//! The main thread handles all DOM events exactly as without a worker,
+//! but instead of running the app it forwards the [`egui::RawInput`] of each frame to the worker.
+//! The worker runs the app, paints it to an `OffscreenCanvas`,
+//! and sends back the [`egui::PlatformOutput`], so that the main thread can
+//! set the cursor, copy text, move the IME text agent, etc.
+//!
+//! The first message to the worker is `[offscreen_canvas, web_info]`,
+//! where the canvas is transferred and `web_info` is a [`ron`]-encoded [`epi::WebInfo`].
+//! After that all messages in either direction are [`ron`]-encoded `ToWorker` and `FromWorker`,
+//! see `worker_protocol.rs`.
+//!
+//! Only one frame is in flight at a time:
+//! input that arrives while the worker is busy is merged into the next frame.
+
+use std::{cell::RefCell, rc::Rc, sync::Arc};
+
+use egui::mutex::Mutex;
+use wasm_bindgen::prelude::*;
+
+use crate::{
+    App, epi,
+    worker_protocol::{FromWorker, MainState, NextFrame, ToWorker, from_ron, to_ron},
+};
+
+use super::{
+    PanicHandler, now_sec, string_from_js_value,
+    web_painter::{ActiveWebPainter, PaintCanvas, WebPainter},
+};
+
+fn encode(message: &impl serde::Serialize) -> JsValue {
+    to_ron(message).map_or(JsValue::UNDEFINED, |string| JsValue::from_str(&string))
+}
+
+fn decode<T: serde::de::DeserializeOwned>(data: &JsValue) -> Option<T> {
+    from_ron(&data.as_string()?)
+}
+
+// ----------------------------------------------------------------------------
+// Main thread:
+
+/// Stands in for the painter on the main thread, when the app runs in a worker.
+pub(crate) struct WorkerPainter {
+    worker: web_sys::Worker,
+    state: Rc<RefCell<MainState>>,
+
+    // Kept so it is not dropped until we are done with it.
+    _on_message: Closure<dyn FnMut(web_sys::MessageEvent)>,
+}
+
+impl WorkerPainter {
+    /// Start the worker and transfer the control of `canvas` to it.
+    pub fn new(
+        ctx: egui::Context,
+        canvas: &web_sys::HtmlCanvasElement,
+        worker_url: &str,
+    ) -> Result<Self, String> {
+        log::debug!("Starting web worker {worker_url:?}");
+
+        let size_in_pixels = [canvas.width(), canvas.height()];
+        let offscreen = canvas
+            .transfer_control_to_offscreen()
+            .map_err(|err| string_from_js_value(&err))?;
+
+        let options = web_sys::WorkerOptions::new();
+        options.set_type(web_sys::WorkerType::Module);
+        let worker = web_sys::Worker::new_with_options(worker_url, &options)
+            .map_err(|err| string_from_js_value(&err))?;
+
+        let state = Rc::new(RefCell::new(MainState::new(size_in_pixels)));
+
+        let on_message = Closure::wrap(Box::new({
+            let state = state.clone();
+            move |event: web_sys::MessageEvent| match decode(&event.data()) {
+                Some(FromWorker::Output {
+                    platform_output,
+                    zoom_factor,
+                    repaint_delay,
+                }) => {
+                    state.borrow_mut().on_output(
+                        now_sec(),
+                        *platform_output,
+                        zoom_factor,
+                        repaint_delay,
+                    );
+                    // Apply the output, and send any input that arrived in the meantime:
+                    ctx.request_repaint();
+                }
+                Some(FromWorker::RequestRepaint { delay }) => {
+                    state.borrow_mut().repaint_after(now_sec(), delay);
+                    ctx.request_repaint_after_secs(delay as f32);
+                }
+                Some(FromWorker::Error(err)) => {
+                    log::error!("Web worker failed: {err}");
+                }
+                None => {}
+            }
+        }) as Box<dyn FnMut(web_sys::MessageEvent)>);
+        worker.set_onmessage(Some(on_message.as_ref().unchecked_ref()));
+
+        let web_info = epi::WebInfo {
+            user_agent: super::user_agent().unwrap_or_default(),
+            location: super::web_location(),
+        };
+        worker
+            .post_message_with_transfer(
+                &js_sys::Array::of2(&offscreen, &encode(&web_info)),
+                &js_sys::Array::of1(&offscreen),
+            )
+            .map_err(|err| string_from_js_value(&err))?;
+
+        Ok(Self {
+            worker,
+            state,
+            _on_message: on_message,
+        })
+    }
+
+    /// Creates the app that runs on the main thread, forwarding input to the worker.
+    pub fn proxy_app_creator(&self) -> epi::AppCreator<'static> {
+        let app = ProxyApp {
+            worker: self.worker.clone(),
+            state: self.state.clone(),
+        };
+        Box::new(move |_cc| Ok(Box::new(app)))
+    }
+}
+
+impl WebPainter for WorkerPainter {
+    fn size_in_pixels(&self) -> [u32; 2] {
+        self.state.borrow().size_in_pixels
+    }
+
+    fn set_size_in_pixels(&mut self, size: [u32; 2]) {
+        self.state.borrow_mut().size_in_pixels = size;
+    }
+
+    fn max_texture_side(&self) -> usize {
+        // Nothing is painted on the main thread.
+        8192
+    }
+
+    fn paint_and_update_textures(
+        &mut self,
+        _clear_color: [f32; 4],
+        _clipped_primitives: &[egui::ClippedPrimitive],
+        _pixels_per_point: f32,
+        _textures_delta: &egui::TexturesDelta,
+        _capture: Vec<egui::UserData>,
+    ) -> Result<(), JsValue> {
+        Ok(())
+    }
+
+    fn handle_screenshots(&mut self, _events: &mut Vec<egui::Event>) {
+        // Screenshots are taken and handled by the worker.
+    }
+
+    fn destroy(&mut self) {
+        self.worker.terminate();
+    }
+}
+
+/// Forwards the input of each frame to the worker, and applies its output.
+struct ProxyApp {
+    worker: web_sys::Worker,
+    state: Rc<RefCell<MainState>>,
+}
+
+impl App for ProxyApp {
+    fn raw_input_hook(&mut self, _ctx: &egui::Context, raw_input: &mut egui::RawInput) {
+        self.state.borrow_mut().add_input(raw_input);
+    }
+
+    fn update(&mut self, ctx: &egui::Context, frame: &mut epi::Frame) {
+        let mut state = self.state.borrow_mut();
+
+        if let Some((platform_output, zoom_factor)) = state.take_output() {
+            ctx.output_mut(|o| o.append(platform_output));
+            if ctx.zoom_factor() != zoom_factor {
+                ctx.options_mut(|o| o.zoom_factor = zoom_factor);
+            }
+        }
+
+        let raw_input = match state.next_frame(now_sec()) {
+            NextFrame::Send(raw_input) => raw_input,
+            NextFrame::Wait(delay) => {
+                if let Some(delay) = delay {
+                    ctx.request_repaint_after_secs(delay as f32);
+                }
+                return;
+            }
+        };
+
+        let message = ToWorker {
+            raw_input,
+            size_in_pixels: state.size_in_pixels,
+            web_info: frame.info().web_info.clone(),
+        };
+        if let Err(err) = self.worker.post_message(&encode(&message)) {
+            log::error!(
+                "Failed to send input to web worker: {}",
+                string_from_js_value(&err)
+            );
+            return;
+        }
+        state.sent(message.raw_input);
+    }
+
+    fn persist_egui_memory(&self) -> bool {
+        false // The egui memory that matters is in the worker.
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Worker:
+
+#[derive(Default)]
+struct RepaintState {
+    /// Are we in the middle of running a frame?
+    in_frame: bool,
+
+    /// The shortest delay asked for during the current frame.
+    delay: Option<f64>,
+}
+
+struct WorkerAppRunner {
+    egui_ctx: egui::Context,
+    painter: ActiveWebPainter,
+    app: Box<dyn App>,
+    frame: epi::Frame,
+    repaint: Arc<Mutex<RepaintState>>,
+    screenshot_commands: Vec<egui::UserData>,
+}
+
+impl WorkerAppRunner {
+    async fn new(
+        canvas: web_sys::OffscreenCanvas,
+        web_info: epi::WebInfo,
+        web_options: &crate::WebOptions,
+        app_creator: epi::AppCreator<'static>,
+    ) -> Result<Self, String> {
+        let egui_ctx = egui::Context::default();
+        let painter = ActiveWebPainter::new(
+            egui_ctx.clone(),
+            PaintCanvas::Offscreen(canvas.clone()),
+            web_options,
+        )
+        .await?;
+
+        egui_ctx.set_os(egui::os::OperatingSystem::from_user_agent(
+            &web_info.user_agent,
+        ));
+        egui_ctx.options_mut(|o| {
+            // Same as on the main thread, see `AppRunner::new`.
+            o.zoom_with_keyboard = false;
+            o.zoom_factor = 1.0;
+        });
+
+        let repaint: Arc<Mutex<RepaintState>> = Default::default();
+        egui_ctx.set_request_repaint_callback({
+            let repaint = repaint.clone();
+            move |info| {
+                let delay = info.delay.as_secs_f64();
+                let mut repaint = repaint.lock();
+                if repaint.in_frame {
+                    repaint.delay = Some(repaint.delay.map_or(delay, |old| old.min(delay)));
+                } else {
+                    post_to_main(&FromWorker::RequestRepaint { delay });
+                }
+            }
+        });
+
+        let info = epi::IntegrationInfo {
+            web_info,
+            cpu_usage: None,
+        };
+        let canvas_value: JsValue = canvas.into();
+
+        let cc = epi::CreationContext {
+            egui_ctx: egui_ctx.clone(),
+            integration_info: info.clone(),
+            storage: None, // There is no `localStorage` in workers.
+
+            #[cfg(feature = "glow")]
+            gl: painter.gl(),
+
+            #[cfg(feature = "glow")]
+            get_proc_address: None,
+
+            #[cfg(feature = "wgpu")]
+            wgpu_render_state: painter.render_state(),
+
+            canvas: Some(&canvas_value),
+        };
+        let app = app_creator(&cc).map_err(|err| err.to_string())?;
+
+        let frame = epi::Frame {
+            info,
+            storage: None,
+
+            #[cfg(feature = "glow")]
+            gl: painter.gl(),
+
+            #[cfg(feature = "wgpu")]
+            wgpu_render_state: painter.render_state(),
+
+            canvas: Some(Box::new(canvas_value)),
+        };
+
+        Ok(Self {
+            egui_ctx,
+            painter,
+            app,
+            frame,
+            repaint,
+            screenshot_commands: vec![],
+        })
+    }
+
+    fn run_frame(&mut self, message: ToWorker) -> FromWorker {
+        let ToWorker {
+            mut raw_input,
+            size_in_pixels,
+            web_info,
+        } = message;
+
+        if self.painter.size_in_pixels() != size_in_pixels {
+            self.painter.set_size_in_pixels(size_in_pixels);
+        }
+        self.frame.info.web_info = web_info;
+        raw_input.max_texture_side = Some(self.painter.max_texture_side());
+        raw_input.frame_cost = self.frame.info.cpu_usage;
+        self.painter.handle_screenshots(&mut raw_input.events);
+
+        let mut stopwatch = crate::stopwatch::Stopwatch::new();
+        stopwatch.start();
+
+        self.repaint.lock().in_frame = true;
+
+        self.app.raw_input_hook(&self.egui_ctx, &mut raw_input);
+        let full_output = self.egui_ctx.run(raw_input, |egui_ctx| {
+            self.app.update(egui_ctx, &mut self.frame);
+        });
+        let egui::FullOutput {
+            platform_output,
+            textures_delta,
+            shapes,
+            pixels_per_point,
+            viewport_output,
+        } = full_output;
+
+        let capture = std::mem::take(&mut self.screenshot_commands);
+        for (_viewport_id, viewport_output) in viewport_output {
+            for command in viewport_output.commands {
+                if let egui::ViewportCommand::Screenshot(user_data) = command {
+                    // Take it next frame, when everything has been painted:
+                    self.screenshot_commands.push(user_data);
+                    self.egui_ctx.request_repaint();
+                } else {
+                    log::debug!("Unhandled egui viewport command in web worker: {command:?}");
+                }
+            }
+        }
+
+        let clipped_primitives = self.egui_ctx.tessellate(shapes, pixels_per_point);
+        if let Err(err) = self.painter.paint_and_update_textures(
+            self.app.clear_color(&self.egui_ctx.style().visuals),
+            &clipped_primitives,
+            pixels_per_point,
+            &textures_delta,
+            capture,
+        ) {
+            log::error!("Failed to paint: {}", string_from_js_value(&err));
+        }
+
+        self.frame.info.cpu_usage = Some(stopwatch.total_time_sec());
+
+        let repaint_delay = {
+            let mut repaint = self.repaint.lock();
+            repaint.in_frame = false;
+            repaint.delay.take()
+        };
+
+        FromWorker::Output {
+            platform_output: Box::new(platform_output),
+            zoom_factor: self.egui_ctx.zoom_factor(),
+            repaint_delay,
+        }
+    }
+}
+
+fn worker_scope() -> Option<web_sys::DedicatedWorkerGlobalScope> {
+    js_sys::global().dyn_into().ok()
+}
+
+fn post_to_main(message: &FromWorker) {
+    if let Some(scope) = worker_scope() {
+        if let Err(err) = scope.post_message(&encode(message)) {
+            log::error!(
+                "Failed to post message from web worker: {}",
+                string_from_js_value(&err)
+            );
+        }
+    }
+}
+
+#[derive(Default)]
+struct WorkerState {
+    /// Taken when the canvas arrives.
+    start: Option<(crate::WebOptions, epi::AppCreator<'static>)>,
+
+    /// `None` until the app has been created.
+    runner: Option<WorkerAppRunner>,
+
+    /// A frame that arrived before the app was created.
+    pending: Option<ToWorker>,
+
+    /// Kept so it is not dropped until we are done with it.
+    on_message: Option<Closure<dyn FnMut(web_sys::MessageEvent)>>,
+}
+
+/// Runs your app in a web worker, painting to a canvas transferred from the main thread.
+///
+/// On the main thread, set [`crate::WebOptions::worker_url`] and call [`crate::WebRunner::start`] as usual.
+/// In the worker script, call [`Self::start`] with your app:
+///
+/// ```ignore
+/// #[wasm_bindgen]
+/// pub fn start_worker() -> Result<(), wasm_bindgen::JsValue> {
+///     eframe::web::WorkerRunner::new().start(
+///         eframe::WebOptions::default(),
+///         Box::new(|cc| Ok(Box::new(MyApp::new(cc)))),
+///     )
+/// }
+/// ```
+///
+/// The app has no [`epi::Storage`] in the worker, since there is no `localStorage` there.
+/// Platform output like the cursor icon, copied text and opened URLs is forwarded to the main thread.
+///
+/// This is cheap to clone.
+#[derive(Clone)]
+pub struct WorkerRunner {
+    panic_handler: PanicHandler,
+    state: Rc<RefCell<WorkerState>>,
+}
+
+impl WorkerRunner {
+    /// Will install a panic handler that will catch and log any panics
+    #[expect(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Self {
+            panic_handler: PanicHandler::install(),
+            state: Default::default(),
+        }
+    }
+
+    /// Wait for the canvas from the main thread, then create the app and start running it.
+    ///
+    /// # Errors
+    /// If not called from a dedicated web worker.
+    pub fn start(
+        &self,
+        web_options: crate::WebOptions,
+        app_creator: epi::AppCreator<'static>,
+    ) -> Result<(), JsValue> {
+        let scope = worker_scope()
+            .ok_or_else(|| JsValue::from_str("WorkerRunner must be started in a web worker"))?;
+
+        let on_message = Closure::wrap(Box::new({
+            let runner = self.clone();
+            move |event: web_sys::MessageEvent| runner.on_message(&event.data())
+        }) as Box<dyn FnMut(web_sys::MessageEvent)>);
+        scope.set_onmessage(Some(on_message.as_ref().unchecked_ref()));
+
+        let mut state = self.state.borrow_mut();
+        state.start = Some((web_options, app_creator));
+        state.on_message = Some(on_message);
+        Ok(())
+    }
+
+    /// Has there been a panic?
+    pub fn has_panicked(&self) -> bool {
+        self.panic_handler.has_panicked()
+    }
+
+    /// What was the panic message and callstack?
+    pub fn panic_summary(&self) -> Option<super::PanicSummary> {
+        self.panic_handler.panic_summary()
+    }
+
+    fn on_message(&self, data: &JsValue) {
+        if self.has_panicked() {
+            return;
+        }
+
+        if let Some(init) = data.dyn_ref::<js_sys::Array>() {
+            let (Ok(canvas), Some(web_info)) = (
+                init.get(0).dyn_into::<web_sys::OffscreenCanvas>(),
+                decode::<epi::WebInfo>(&init.get(1)),
+            ) else {
+                log::error!("Unexpected first message to web worker");
+                return;
+            };
+            let Some((web_options, app_creator)) = self.state.borrow_mut().start.take() else {
+                log::warn!("Web worker got a second canvas");
+                return;
+            };
+
+            let runner = self.clone();
+            wasm_bindgen_futures::spawn_local(async move {
+                match WorkerAppRunner::new(canvas, web_info, &web_options, app_creator).await {
+                    Ok(app_runner) => {
+                        let pending = {
+                            let mut state = runner.state.borrow_mut();
+                            state.runner = Some(app_runner);
+                            state.pending.take()
+                        };
+                        if let Some(message) = pending {
+                            runner.run_frame(message);
+                        }
+                    }
+                    Err(err) => {
+                        log::error!("Failed to start app in web worker: {err}");
+                        post_to_main(&FromWorker::Error(err));
+                    }
+                }
+            });
+        } else if let Some(message) = decode::<ToWorker>(data) {
+            let mut state = self.state.borrow_mut();
+            if state.runner.is_some() {
+                drop(state);
+                self.run_frame(message);
+            } else {
+                // The main thread waits for our answer before sending more,
+                // so there can only be one frame waiting.
+                state.pending = Some(message);
+            }
+        }
+    }
+
+    fn run_frame(&self, message: ToWorker) {
+        let output = {
+            let mut state = self.state.borrow_mut();
+            let Some(runner) = &mut state.runner else {
+                return;
+            };
+            runner.run_frame(message)
+        };
+        post_to_main(&output);
+    }
+}
@@ -0,0 +1,371 @@
+//! The messages between the main thread and the web worker, and the main thread bookkeeping.
+//!
+//! This is kept apart from the `wasm_bindgen` glue in `web/worker.rs` so that it can be tested natively.
+
+use crate::epi;
+
+/// Main thread → worker.
+#[derive(serde::Deserialize, serde::Serialize)]
+pub(crate) struct ToWorker {
+    pub raw_input: egui::RawInput,
+    pub size_in_pixels: [u32; 2],
+    pub web_info: epi::WebInfo,
+}
+
+/// Worker → main thread.
+#[derive(serde::Deserialize, serde::Serialize)]
+pub(crate) enum FromWorker {
+    /// The worker has run and painted the last frame we sent.
+    Output {
+        platform_output: Box<egui::PlatformOutput>,
+
+        /// So that the main thread can compute pointer positions in the same points.
+        zoom_factor: f32,
+
+        /// When the worker wants to run again, in seconds from now.
+        repaint_delay: Option<f64>,
+    },
+
+    /// The app asked for a repaint in between frames, e.g. after loading an image.
+    RequestRepaint { delay: f64 },
+
+    /// The worker failed to start the app.
+    Error(String),
+}
+
+pub(crate) fn to_ron(message: &impl serde::Serialize) -> Option<String> {
+    ron::to_string(message)
+        .map_err(|err| log::error!("Failed to encode worker message: {err}"))
+        .ok()
+}
+
+pub(crate) fn from_ron<T: serde::de::DeserializeOwned>(string: &str) -> Option<T> {
+    ron::from_str(string)
+        .map_err(|err| log::error!("Failed to decode worker message: {err}"))
+        .ok()
+}
+
+// ----------------------------------------------------------------------------
+
+/// What the main thread should do about the worker this frame, see [`MainState::next_frame`].
+#[derive(Debug, PartialEq)]
+pub(crate) enum NextFrame {
+    /// Send this input to the worker, then call [`MainState::sent`].
+    Send(egui::RawInput),
+
+    /// Nothing to send yet. Check again after this many seconds, if any.
+    Wait(Option<f64>),
+}
+
+/// The state of the main thread, when the app runs in a worker.
+///
+/// All times are in seconds, from the same clock as the `now` arguments.
+#[derive(Default)]
+pub(crate) struct MainState {
+    /// Size of the canvas in physical pixels.
+    ///
+    /// We can't resize the canvas element once its control has been transferred,
+    /// so the worker resizes the `OffscreenCanvas` instead.
+    pub size_in_pixels: [u32; 2],
+
+    /// Is the worker still busy with the last frame we sent?
+    busy: bool,
+
+    /// Input not yet sent to the worker.
+    pending_input: Option<egui::RawInput>,
+
+    /// The input of the last frame we sent.
+    last_sent: Option<egui::RawInput>,
+
+    /// Output from the worker, not yet applied, and its zoom factor.
+    output: Option<(egui::PlatformOutput, f32)>,
+
+    /// When the worker wants to run again.
+    repaint_at: Option<f64>,
+}
+
+impl MainState {
+    pub fn new(size_in_pixels: [u32; 2]) -> Self {
+        Self {
+            size_in_pixels,
+            ..Default::default()
+        }
+    }
+
+    /// The input of a frame on the main thread.
+    ///
+    /// It is merged with any earlier input that the worker hasn't seen yet.
+    pub fn add_input(&mut self, raw_input: &egui::RawInput) {
+        self.pending_input = Some(match self.pending_input.take() {
+            Some(older) => merge_input(older, raw_input.clone()),
+            None => raw_input.clone(),
+        });
+    }
+
+    /// The worker is done with the frame we sent.
+    pub fn on_output(
+        &mut self,
+        now: f64,
+        platform_output: egui::PlatformOutput,
+        zoom_factor: f32,
+        repaint_delay: Option<f64>,
+    ) {
+        self.busy = false;
+        match &mut self.output {
+            Some((output, old_zoom_factor)) => {
+                output.append(platform_output);
+                *old_zoom_factor = zoom_factor;
+            }
+            None => self.output = Some((platform_output, zoom_factor)),
+        }
+        if let Some(delay) = repaint_delay {
+            self.repaint_after(now, delay);
+        }
+    }
+
+    /// The worker wants to run again after `delay` seconds.
+    pub fn repaint_after(&mut self, now: f64, delay: f64) {
+        let at = now + delay;
+        self.repaint_at = Some(self.repaint_at.map_or(at, |old| old.min(at)));
+    }
+
+    /// The output from the worker, and its zoom factor, to apply this frame.
+    pub fn take_output(&mut self) -> Option<(egui::PlatformOutput, f32)> {
+        self.output.take()
+    }
+
+    /// Should we send the pending input to the worker?
+    ///
+    /// We only send when the worker is idle, and only if something happened or it asked to be repainted.
+    pub fn next_frame(&mut self, now: f64) -> NextFrame {
+        if self.busy {
+            // We'll get a repaint when the output arrives.
+            return NextFrame::Wait(None);
+        }
+
+        let repaint_due = self.repaint_at.is_some_and(|at| at <= now);
+        let Some(raw_input) = self.pending_input.take() else {
+            return NextFrame::Wait(None);
+        };
+
+        if repaint_due || has_news(&raw_input, self.last_sent.as_ref()) {
+            NextFrame::Send(raw_input)
+        } else {
+            NextFrame::Wait(self.repaint_at.map(|at| at - now))
+        }
+    }
+
+    /// We have sent `raw_input` to the worker, and must wait for its output.
+    pub fn sent(&mut self, raw_input: egui::RawInput) {
+        self.busy = true;
+        self.repaint_at = None;
+        self.last_sent = Some(raw_input);
+    }
+}
+
+/// Combine the input of two frames that the worker hasn't seen yet.
+fn merge_input(older: egui::RawInput, mut newer: egui::RawInput) -> egui::RawInput {
+    let egui::RawInput {
+        mut events,
+        mut dropped_files,
+        ..
+    } = older;
+    events.append(&mut newer.events);
+    dropped_files.append(&mut newer.dropped_files);
+    newer.events = events;
+    newer.dropped_files = dropped_files;
+    newer
+}
+
+/// Does the worker need to run a frame for this input?
+fn has_news(raw_input: &egui::RawInput, last_sent: Option<&egui::RawInput>) -> bool {
+    let without_time = |raw_input: &egui::RawInput| egui::RawInput {
+        time: None,
+        predicted_dt: 0.0,
+        ..raw_input.clone()
+    };
+    !raw_input.events.is_empty()
+        || !raw_input.dropped_files.is_empty()
+        || last_sent.is_none_or(|last_sent| without_time(raw_input) != without_time(last_sent))
+}
+
+#[cfg(test)]
+mod tests {
+    use egui::{Event, PlatformOutput, RawInput, pos2, vec2};
+
+    use super::*;
+
+    fn web_info() -> epi::WebInfo {
+        epi::WebInfo {
+            user_agent: "test".to_owned(),
+            location: epi::Location {
+                url: "http://localhost/?foo=bar".to_owned(),
+                protocol: "http:".to_owned(),
+                host: "localhost".to_owned(),
+                hostname: "localhost".to_owned(),
+                port: String::new(),
+                hash: "#hash".to_owned(),
+                query: "foo=bar".to_owned(),
+                query_map: [("foo".to_owned(), vec!["bar".to_owned()])].into(),
+                origin: "http://localhost".to_owned(),
+            },
+        }
+    }
+
+    fn input_at(time: f64, events: Vec<Event>) -> RawInput {
+        RawInput {
+            time: Some(time),
+            events,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn message_round_trip() {
+        let raw_input = input_at(
+            1.5,
+            vec![
+                Event::PointerMoved(pos2(10.0, 20.0)),
+                Event::Text("hello".to_owned()),
+                Event::MouseWheel {
+                    unit: egui::MouseWheelUnit::Line,
+                    delta: vec2(0.0, -1.0),
+                    modifiers: egui::Modifiers::CTRL,
+                },
+            ],
+        );
+        let message = ToWorker {
+            raw_input: raw_input.clone(),
+            size_in_pixels: [640, 480],
+            web_info: web_info(),
+        };
+        let decoded: ToWorker = from_ron(&to_ron(&message).unwrap()).unwrap();
+        assert_eq!(decoded.raw_input, raw_input);
+        assert_eq!(decoded.size_in_pixels, [640, 480]);
+        assert_eq!(decoded.web_info.user_agent, "test");
+        assert_eq!(decoded.web_info.location.query_map["foo"], ["bar"]);
+
+        let mut platform_output = PlatformOutput {
+            cursor_icon: egui::CursorIcon::Text,
+            ..Default::default()
+        };
+        platform_output
+            .commands
+            .push(egui::OutputCommand::CopyText("copied".to_owned()));
+        let message = FromWorker::Output {
+            platform_output: Box::new(platform_output.clone()),
+            zoom_factor: 1.5,
+            repaint_delay: Some(0.25),
+        };
+        let Some(FromWorker::Output {
+            platform_output: decoded_output,
+            zoom_factor,
+            repaint_delay,
+        }) = from_ron(&to_ron(&message).unwrap())
+        else {
+            panic!("Expected output");
+        };
+        assert!(*decoded_output == platform_output);
+        assert_eq!(zoom_factor, 1.5);
+        assert_eq!(repaint_delay, Some(0.25));
+
+        assert!(matches!(
+            from_ron(&to_ron(&FromWorker::RequestRepaint { delay: 2.0 }).unwrap()),
+            Some(FromWorker::RequestRepaint { delay }) if delay == 2.0
+        ));
+        assert!(from_ron::<ToWorker>("not ron").is_none());
+    }
+
+    #[test]
+    fn input_is_merged_while_busy() {
+        let mut state = MainState::new([100, 100]);
+        assert_eq!(state.size_in_pixels, [100, 100]);
+        let press = Event::Key {
+            key: egui::Key::A,
+            physical_key: None,
+            pressed: true,
+            repeat: false,
+            modifiers: Default::default(),
+        };
+
+        state.add_input(&input_at(0.0, vec![press.clone()]));
+        let NextFrame::Send(first) = state.next_frame(0.0) else {
+            panic!("Expected to send the first frame");
+        };
+        assert_eq!(first.events, [press]);
+        state.sent(first);
+
+        // The worker is busy, so these are held back and merged:
+        state.add_input(&input_at(1.0, vec![Event::Text("a".to_owned())]));
+        assert_eq!(state.next_frame(1.0), NextFrame::Wait(None));
+        state.add_input(&input_at(2.0, vec![Event::Text("b".to_owned())]));
+        assert_eq!(state.next_frame(2.0), NextFrame::Wait(None));
+
+        state.on_output(2.5, PlatformOutput::default(), 1.0, None);
+        let NextFrame::Send(merged) = state.next_frame(2.5) else {
+            panic!("Expected to send the merged input");
+        };
+        assert_eq!(merged.time, Some(2.0), "The newest input wins");
+        assert_eq!(
+            merged.events,
+            [Event::Text("a".to_owned()), Event::Text("b".to_owned())]
+        );
+    }
+
+    #[test]
+    fn only_send_news_or_when_asked() {
+        let mut state = MainState::new([100, 100]);
+
+        state.add_input(&input_at(0.0, vec![]));
+        let NextFrame::Send(first) = state.next_frame(0.0) else {
+            panic!("The first frame is always news");
+        };
+        state.sent(first);
+        state.on_output(0.25, PlatformOutput::default(), 1.0, Some(1.0));
+
+        // Only the time has changed, so wait until the worker wants to repaint:
+        state.add_input(&input_at(0.5, vec![]));
+        assert_eq!(state.next_frame(0.75), NextFrame::Wait(Some(0.5)));
+        state.add_input(&input_at(1.25, vec![]));
+        let NextFrame::Send(repaint) = state.next_frame(1.25) else {
+            panic!("The worker asked to be repainted");
+        };
+        state.sent(repaint);
+        state.on_output(1.5, PlatformOutput::default(), 1.0, None);
+        state.add_input(&input_at(2.0, vec![]));
+        assert_eq!(state.next_frame(2.0), NextFrame::Wait(None));
+
+        // A new screen size is news:
+        let mut resized = input_at(2.5, vec![]);
+        resized.screen_rect = Some(egui::Rect::from_min_size(pos2(0.0, 0.0), vec2(50.0, 50.0)));
+        state.add_input(&resized);
+        assert!(matches!(state.next_frame(2.5), NextFrame::Send(_)));
+    }
+
+    #[test]
+    fn output_is_merged_until_applied() {
+        let mut state = MainState::new([100, 100]);
+
+        let copy = |text: &str| {
+            let mut output = PlatformOutput::default();
+            output
+                .commands
+                .push(egui::OutputCommand::CopyText(text.to_owned()));
+            output
+        };
+        state.on_output(0.0, copy("first"), 1.0, Some(2.0));
+        state.on_output(1.0, copy("second"), 2.0, Some(0.5));
+
+        let (output, zoom_factor) = state.take_output().unwrap();
+        assert_eq!(
+            output.commands,
+            [
+                egui::OutputCommand::CopyText("first".to_owned()),
+                egui::OutputCommand::CopyText("second".to_owned()),
+            ]
+        );
+        assert_eq!(zoom_factor, 2.0);
+        assert!(state.take_output().is_none());
+        assert_eq!(state.repaint_at, Some(1.5), "The earliest repaint is kept");
+    }
+}
@@ -177,7 +177,8 @@ pub struct Texture {
 
 /// Renderer for a egui based GUI.
 pub struct Renderer {
-    pipeline: wgpu::RenderPipeline,
+    /// One pipeline per [`epaint::BlendMode`], see [`Self::pipeline`].
+    pipelines: [wgpu::RenderPipeline; 3],
 
     index_buffer: SlicedBuffer,
     vertex_buffer: SlicedBuffer,
@@ -307,10 +308,20 @@ impl Renderer {
             bias: wgpu::DepthBiasState::default(),
         });
 
-        let pipeline = {
+        let fragment_entry_point = if output_color_format.is_srgb() {
+            log::warn!(
+                "Detected a linear (sRGBA aware) framebuffer {:?}. egui prefers Rgba8Unorm or Bgra8Unorm",
+                output_color_format
+            );
+            "fs_main_linear_framebuffer"
+        } else {
+            "fs_main_gamma_framebuffer" // this is what we prefer
+        };
+
+        let create_pipeline = |label: &str, color_blend: wgpu::BlendComponent| {
             profiling::scope!("create_render_pipeline");
             device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
-                label: Some("egui_pipeline"),
+                label: Some(label),
                 layout: Some(&pipeline_layout),
                 vertex: wgpu::VertexState {
                     entry_point: Some("vs_main"),
@@ -334,7 +345,7 @@ impl Renderer {
                     polygon_mode: wgpu::PolygonMode::default(),
                     strip_index_format: None,
                 },
-                depth_stencil,
+                depth_stencil: depth_stencil.clone(),
                 multisample: wgpu::MultisampleState {
                     alpha_to_coverage_enabled: false,
                     count: msaa_samples,
@@ -343,20 +354,11 @@ impl Renderer {
 
                 fragment: Some(wgpu::FragmentState {
                     module: &module,
-                    entry_point: Some(if output_color_format.is_srgb() {
-                        log::warn!("Detected a linear (sRGBA aware) framebuffer {:?}. egui prefers Rgba8Unorm or Bgra8Unorm", output_color_format);
-                        "fs_main_linear_framebuffer"
-                    } else {
-                        "fs_main_gamma_framebuffer" // this is what we prefer
-                    }),
+                    entry_point: Some(fragment_entry_point),
                     targets: &[Some(wgpu::ColorTargetState {
                         format: output_color_format,
                         blend: Some(wgpu::BlendState {
-                            color: wgpu::BlendComponent {
-                                src_factor: wgpu::BlendFactor::One,
-                                dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
-                                operation: wgpu::BlendOperation::Add,
-                            },
+                            color: color_blend,
                             alpha: wgpu::BlendComponent {
                                 src_factor: wgpu::BlendFactor::OneMinusDstAlpha,
                                 dst_factor: wgpu::BlendFactor::One,
@@ -373,13 +375,41 @@ impl Renderer {
         )
         };
 
+        // egui outputs colors with premultiplied alpha:
+        let pipelines = [
+            create_pipeline(
+                "egui_pipeline",
+                wgpu::BlendComponent {
+                    src_factor: wgpu::BlendFactor::One,
+                    dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
+                    operation: wgpu::BlendOperation::Add,
+                },
+            ),
+            create_pipeline(
+                "egui_pipeline_additive",
+                wgpu::BlendComponent {
+                    src_factor: wgpu::BlendFactor::One,
+                    dst_factor: wgpu::BlendFactor::One,
+                    operation: wgpu::BlendOperation::Add,
+                },
+            ),
+            create_pipeline(
+                "egui_pipeline_multiply",
+                wgpu::BlendComponent {
+                    src_factor: wgpu::BlendFactor::Dst,
+                    dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
+                    operation: wgpu::BlendOperation::Add,
+                },
+            ),
+        ];
+
         const VERTEX_BUFFER_START_CAPACITY: wgpu::BufferAddress =
             (std::mem::size_of::<Vertex>() * 1024) as _;
         const INDEX_BUFFER_START_CAPACITY: wgpu::BufferAddress =
             (std::mem::size_of::<u32>() * 1024 * 3) as _;
 
         Self {
-            pipeline,
+            pipelines,
             vertex_buffer: SlicedBuffer {
                 buffer: create_vertex_buffer(device, VERTEX_BUFFER_START_CAPACITY),
                 slices: Vec::with_capacity(64),
@@ -407,6 +437,14 @@ impl Renderer {
         }
     }
 
+    fn pipeline(&self, blend_mode: epaint::BlendMode) -> &wgpu::RenderPipeline {
+        match blend_mode {
+            epaint::BlendMode::Normal => &self.pipelines[0],
+            epaint::BlendMode::Additive => &self.pipelines[1],
+            epaint::BlendMode::Multiply => &self.pipelines[2],
+        }
+    }
+
     /// Executes the egui renderer onto an existing wgpu renderpass.
     ///
     /// Note that the lifetime of `render_pass` is `'static` which requires a call to [`wgpu::RenderPass::forget_lifetime`].
@@ -428,6 +466,7 @@ impl Renderer {
         // Whether or not we need to reset the render pass because a paint callback has just
         // run.
         let mut needs_reset = true;
+        let mut current_blend_mode = epaint::BlendMode::Normal;
 
         let mut index_buffer_slices = self.index_buffer.slices.iter();
         let mut vertex_buffer_slices = self.vertex_buffer.slices.iter();
@@ -435,6 +474,7 @@ impl Renderer {
         for epaint::ClippedPrimitive {
             clip_rect,
             primitive,
+            blend_mode,
         } in paint_jobs
         {
             if needs_reset {
@@ -446,7 +486,8 @@ impl Renderer {
                     0.0,
                     1.0,
                 );
-                render_pass.set_pipeline(&self.pipeline);
+                render_pass.set_pipeline(self.pipeline(*blend_mode));
+                current_blend_mode = *blend_mode;
                 render_pass.set_bind_group(0, &self.uniform_bind_group, &[]);
                 needs_reset = false;
             }
@@ -473,6 +514,10 @@ impl Renderer {
                     let vertex_buffer_slice = vertex_buffer_slices.next().unwrap();
 
                     if let Some(Texture { bind_group, .. }) = self.textures.get(&mesh.texture_id) {
+                        if *blend_mode != current_blend_mode {
+                            render_pass.set_pipeline(self.pipeline(*blend_mode));
+                            current_blend_mode = *blend_mode;
+                        }
                         render_pass.set_bind_group(1, bind_group, &[]);
                         render_pass.set_index_buffer(
                             self.index_buffer.buffer.slice(
@@ -565,25 +610,53 @@ impl Renderer {
                 Cow::Borrowed(&image.pixels)
             }
         };
-        let data_bytes: &[u8] = bytemuck::cast_slice(data_color32.as_slice());
 
-        let queue_write_data_to_texture = |texture, origin| {
+        let queue_write_data_to_texture = |texture: &wgpu::Texture, origin: wgpu::Origin3d| {
             profiling::scope!("write_texture");
-            queue.write_texture(
-                wgpu::TexelCopyTextureInfo {
-                    texture,
-                    mip_level: 0,
-                    origin,
-                    aspect: wgpu::TextureAspect::All,
-                },
-                data_bytes,
-                wgpu::TexelCopyBufferLayout {
-                    offset: 0,
-                    bytes_per_row: Some(4 * width),
-                    rows_per_image: Some(height),
-                },
-                size,
-            );
+            let mut pixels = Cow::Borrowed(data_color32.as_slice());
+            let mut pixels_size = [width as usize, height as usize];
+
+            // Each mip level is half the size of the previous one.
+            // For partial updates we downsample just the updated region, which is exact
+            // as long as the region is aligned to (and a multiple of) the mip block size.
+            for mip_level in 0..texture.mip_level_count() {
+                if 0 < mip_level {
+                    let (half, half_size) = downsample(&pixels, pixels_size);
+                    pixels = Cow::Owned(half);
+                    pixels_size = half_size;
+                }
+                let origin = wgpu::Origin3d {
+                    x: origin.x >> mip_level,
+                    y: origin.y >> mip_level,
+                    z: 0,
+                };
+                let level_size = texture
+                    .size()
+                    .mip_level_size(mip_level, wgpu::TextureDimension::D2);
+                let copy_size = wgpu::Extent3d {
+                    width: (pixels_size[0] as u32).min(level_size.width.saturating_sub(origin.x)),
+                    height: (pixels_size[1] as u32).min(level_size.height.saturating_sub(origin.y)),
+                    depth_or_array_layers: 1,
+                };
+                if copy_size.width == 0 || copy_size.height == 0 {
+                    continue;
+                }
+                queue.write_texture(
+                    wgpu::TexelCopyTextureInfo {
+                        texture,
+                        mip_level,
+                        origin,
+                        aspect: wgpu::TextureAspect::All,
+                    },
+                    bytemuck::cast_slice(&pixels),
+                    wgpu::TexelCopyBufferLayout {
+                        offset: 0,
+                        bytes_per_row: Some(4 * pixels_size[0] as u32),
+                        rows_per_image: Some(pixels_size[1] as u32),
+                    },
+                    copy_size,
+                );
+            }
         };
 
         // Use same label for all resources associated with this texture id (no point in retyping the type)
@@ -621,12 +694,17 @@ impl Renderer {
             )
         } else {
             // allocate a new texture
+            let mip_level_count = if image_delta.options.mipmap_mode.is_some() {
+                width.max(height).max(1).ilog2() + 1
+            } else {
+                1
+            };
             let texture = {
                 profiling::scope!("create_texture");
                 device.create_texture(&wgpu::TextureDescriptor {
                     label,
                     size,
-                    mip_level_count: 1,
+                    mip_level_count,
                     sample_count: 1,
                     dimension: wgpu::TextureDimension::D2,
                     format: wgpu::TextureFormat::Rgba8Unorm,
@@ -1012,18 +1090,53 @@ fn create_sampler(
         epaint::textures::TextureWrapMode::Repeat => wgpu::AddressMode::Repeat,
         epaint::textures::TextureWrapMode::MirroredRepeat => wgpu::AddressMode::MirrorRepeat,
     };
+    let mipmap_filter = match options.mipmap_mode {
+        Some(epaint::textures::TextureFilter::Linear) => wgpu::FilterMode::Linear,
+        Some(epaint::textures::TextureFilter::Nearest) | None => wgpu::FilterMode::Nearest,
+    };
     device.create_sampler(&wgpu::SamplerDescriptor {
         label: Some(&format!(
-            "egui sampler (mag: {mag_filter:?}, min {min_filter:?})"
+            "egui sampler (mag: {mag_filter:?}, min {min_filter:?}, mipmap {mipmap_filter:?})"
         )),
         mag_filter,
         min_filter,
+        mipmap_filter,
         address_mode_u: address_mode,
         address_mode_v: address_mode,
         ..Default::default()
     })
 }
 
+/// Halve the size of an image (rounding up), averaging each 2x2 block of texels.
+///
+/// This is how the mip levels of textures with [`epaint::textures::TextureOptions::mipmap_mode`] are made.
+/// Like the GPU, we average the (premultiplied, gamma-space) values directly.
+fn downsample(
+    pixels: &[epaint::Color32],
+    [width, height]: [usize; 2],
+) -> (Vec<epaint::Color32>, [usize; 2]) {
+    let half_size = [width.div_ceil(2).max(1), height.div_ceil(2).max(1)];
+    let mut half = Vec::with_capacity(half_size[0] * half_size[1]);
+    for y in 0..half_size[1] {
+        let rows = [2 * y, (2 * y + 1).min(height - 1)];
+        for x in 0..half_size[0] {
+            let columns = [2 * x, (2 * x + 1).min(width - 1)];
+            let mut sum = [0_u32; 4];
+            for row in rows {
+                for column in columns {
+                    let texel = pixels[row * width + column].to_array();
+                    for (sum, value) in sum.iter_mut().zip(texel) {
+                        *sum += value as u32;
+                    }
+                }
+            }
+            let [r, g, b, a] = sum.map(|sum| ((sum + 2) / 4) as u8);
+            half.push(epaint::Color32::from_rgba_premultiplied(r, g, b, a));
+        }
+    }
+    (half, half_size)
+}
+
 fn create_vertex_buffer(device: &wgpu::Device, size: u64) -> wgpu::Buffer {
     profiling::function_scope!();
     device.create_buffer(&wgpu::BufferDescriptor {
@@ -1091,3 +1204,29 @@ fn renderer_impl_send_sync() {
     fn assert_send_sync<T: Send + Sync>() {}
     assert_send_sync::<Renderer>();
 }
+
+#[test]
+fn test_downsample() {
+    use epaint::Color32;
+
+    let pixels = [
+        Color32::WHITE,
+        Color32::BLACK,
+        Color32::RED,
+        Color32::BLACK,
+        Color32::WHITE,
+        Color32::RED,
+    ];
+    let (half, half_size) = downsample(&pixels, [3, 2]);
+    assert_eq!(half_size, [2, 1]);
+    assert_eq!(half[0], Color32::from_rgb(128, 128, 128));
+    assert_eq!(
+        half[1],
+        Color32::RED,
+        "The odd column is averaged with itself"
+    );
+
+    let (single, single_size) = downsample(&half, half_size);
+    assert_eq!(single_size, [1, 1]);
+    assert_eq!(single.len(), 1);
+}
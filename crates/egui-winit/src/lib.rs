@@ -18,8 +18,10 @@ use egui::{Pos2, Rect, Theme, Vec2, ViewportBuilder, ViewportCommand, ViewportId
 pub use winit;
 
 pub mod clipboard;
+pub mod repaint;
 mod window_settings;
 
+pub use repaint::{RepaintRequest, RepaintScheduler, install_repaint_proxy};
 pub use window_settings::WindowSettings;
 
 use raw_window_handle::HasDisplayHandle;
@@ -105,6 +107,11 @@ pub struct State {
 
     allow_ime: bool,
     ime_rect_px: Option<egui::Rect>,
+
+    /// Used instead of the scale factor of the window, if set.
+    ///
+    /// See [`Self::set_native_pixels_per_point`].
+    native_pixels_per_point: Option<f32>,
 }
 
 impl State {
@@ -147,6 +154,8 @@ impl State {
 
             allow_ime: false,
             ime_rect_px: None,
+
+            native_pixels_per_point: None,
         };
 
         slf.egui_input
@@ -223,12 +232,59 @@ impl State {
         &mut self.egui_input
     }
 
+    /// The scale factor of the window, unless overridden with [`Self::set_native_pixels_per_point`].
+    pub fn native_pixels_per_point(&self, window: &Window) -> f32 {
+        self.native_pixels_per_point
+            .unwrap_or(window.scale_factor() as f32)
+    }
+
+    /// Calculate the `pixels_per_point` for the window, given the current egui zoom factor.
+    ///
+    /// Like [`pixels_per_point`], but respects [`Self::set_native_pixels_per_point`].
+    pub fn pixels_per_point(&self, window: &Window) -> f32 {
+        self.egui_ctx.zoom_factor() * self.native_pixels_per_point(window)
+    }
+
+    /// Use this instead of the scale factor of the window, or go back to using that with `None`.
+    ///
+    /// See [`egui::ViewportBuilder::with_native_pixels_per_point`].
+    pub fn set_native_pixels_per_point(&mut self, native_pixels_per_point: Option<f32>) {
+        if self.native_pixels_per_point == native_pixels_per_point {
+            return;
+        }
+        self.native_pixels_per_point = native_pixels_per_point;
+        if let Some(native_pixels_per_point) = native_pixels_per_point {
+            self.egui_input
+                .viewports
+                .entry(self.viewport_id)
+                .or_default()
+                .native_pixels_per_point = Some(native_pixels_per_point);
+        }
+        self.egui_ctx.request_repaint_of(self.viewport_id);
+    }
+
+    /// Like [`update_viewport_info`], but respects [`Self::set_native_pixels_per_point`].
+    pub fn update_viewport_info(
+        &self,
+        viewport_info: &mut ViewportInfo,
+        window: &Window,
+        is_init: bool,
+    ) {
+        update_viewport_info_with(
+            viewport_info,
+            &self.egui_ctx,
+            window,
+            self.native_pixels_per_point(window),
+            is_init,
+        );
+    }
+
     /// Prepare for a new frame by extracting the accumulated input,
     ///
     /// as well as setting [the time](egui::RawInput::time) and [screen rectangle](egui::RawInput::screen_rect).
     ///
     /// You need to set [`egui::RawInput::viewports`] yourself though.
-    /// Use [`update_viewport_info`] to update the info for each
+    /// Use [`Self::update_viewport_info`] to update the info for each
     /// viewport.
     pub fn take_egui_input(&mut self, window: &Window) -> egui::RawInput {
         profiling::function_scope!();
@@ -239,8 +295,7 @@ impl State {
         // See: https://github.com/rust-windowing/winit/issues/208
         // This solves an issue where egui window positions would be changed when minimizing on Windows.
         let screen_size_in_pixels = screen_size_in_pixels(window);
-        let screen_size_in_points =
-            screen_size_in_pixels / pixels_per_point(&self.egui_ctx, window);
+        let screen_size_in_points = screen_size_in_pixels / self.pixels_per_point(window);
 
         self.egui_input.screen_rect = (screen_size_in_points.x > 0.0
             && screen_size_in_points.y > 0.0)
@@ -253,7 +308,7 @@ impl State {
             .viewports
             .entry(self.viewport_id)
             .or_default()
-            .native_pixels_per_point = Some(window.scale_factor() as f32);
+            .native_pixels_per_point = Some(self.native_pixels_per_point(window));
 
         self.egui_input.take()
     }
@@ -276,7 +331,8 @@ impl State {
         use winit::event::WindowEvent;
         match event {
             WindowEvent::ScaleFactorChanged { scale_factor, .. } => {
-                let native_pixels_per_point = *scale_factor as f32;
+                let native_pixels_per_point =
+                    self.native_pixels_per_point.unwrap_or(*scale_factor as f32);
 
                 self.egui_input
                     .viewports
@@ -594,7 +650,7 @@ impl State {
         window: &Window,
         pos_in_pixels: winit::dpi::PhysicalPosition<f64>,
     ) {
-        let pixels_per_point = pixels_per_point(&self.egui_ctx, window);
+        let pixels_per_point = self.pixels_per_point(window);
 
         let pos_in_points = egui::pos2(
             pos_in_pixels.x as f32 / pixels_per_point,
@@ -624,7 +680,7 @@ impl State {
     }
 
     fn on_touch(&mut self, window: &Window, touch: &winit::event::Touch) {
-        let pixels_per_point = pixels_per_point(&self.egui_ctx, window);
+        let pixels_per_point = self.pixels_per_point(window);
 
         // Emit touch event
         self.egui_input.events.push(egui::Event::Touch {
@@ -689,7 +745,7 @@ impl State {
     }
 
     fn on_mouse_wheel(&mut self, window: &Window, delta: winit::event::MouseScrollDelta) {
-        let pixels_per_point = pixels_per_point(&self.egui_ctx, window);
+        let pixels_per_point = self.pixels_per_point(window);
 
         {
             let (unit, delta) = match delta {
@@ -855,6 +911,9 @@ impl State {
                 egui::OutputCommand::OpenUrl(open_url) => {
                     open_url_in_browser(&open_url.url);
                 }
+                egui::OutputCommand::Haptic(feedback) => {
+                    log::trace!("Haptic feedback is not supported by egui-winit: {feedback:?}");
+                }
             }
         }
 
@@ -876,7 +935,7 @@ impl State {
         }
 
         if let Some(ime) = ime {
-            let pixels_per_point = pixels_per_point(&self.egui_ctx, window);
+            let pixels_per_point = self.pixels_per_point(window);
             let ime_rect_px = pixels_per_point * ime.rect;
             if self.ime_rect_px != Some(ime_rect_px)
                 || self.egui_ctx.input(|i| !i.events.is_empty())
@@ -972,9 +1031,26 @@ pub fn update_viewport_info(
     egui_ctx: &egui::Context,
     window: &Window,
     is_init: bool,
+) {
+    let native_pixels_per_point = window.scale_factor() as f32;
+    update_viewport_info_with(
+        viewport_info,
+        egui_ctx,
+        window,
+        native_pixels_per_point,
+        is_init,
+    );
+}
+
+fn update_viewport_info_with(
+    viewport_info: &mut ViewportInfo,
+    egui_ctx: &egui::Context,
+    window: &Window,
+    native_pixels_per_point: f32,
+    is_init: bool,
 ) {
     profiling::function_scope!();
-    let pixels_per_point = pixels_per_point(egui_ctx, window);
+    let pixels_per_point = egui_ctx.zoom_factor() * native_pixels_per_point;
 
     let has_a_position = match window.is_minimized() {
         Some(true) => false,
@@ -1004,7 +1080,7 @@ pub fn update_viewport_info(
     };
 
     viewport_info.title = Some(window.title());
-    viewport_info.native_pixels_per_point = Some(window.scale_factor() as f32);
+    viewport_info.native_pixels_per_point = Some(native_pixels_per_point);
 
     viewport_info.monitor_size = monitor_size;
     viewport_info.inner_rect = inner_rect;
@@ -1326,6 +1402,9 @@ pub enum ActionRequested {
     Cut,
     Copy,
     Paste,
+
+    /// Call [`State::set_native_pixels_per_point`] with this.
+    NativePixelsPerPoint(Option<egui::emath::OrderedFloat<f32>>),
 }
 
 pub fn process_viewport_commands(
@@ -1353,7 +1432,11 @@ fn process_viewport_command(
 
     log::trace!("Processing ViewportCommand::{command:?}");
 
-    let pixels_per_point = pixels_per_point(egui_ctx, window);
+    // Respects `State::set_native_pixels_per_point`, via `State::update_viewport_info`:
+    let native_pixels_per_point = info
+        .native_pixels_per_point
+        .unwrap_or(window.scale_factor() as f32);
+    let pixels_per_point = egui_ctx.zoom_factor() * native_pixels_per_point;
 
     match command {
         ViewportCommand::Close => {
@@ -1537,6 +1620,11 @@ fn process_viewport_command(
                 log::warn!("{command:?}: {err}");
             }
         }
+        ViewportCommand::NativePixelsPerPoint(native_pixels_per_point) => {
+            actions_requested.push(ActionRequested::NativePixelsPerPoint(
+                native_pixels_per_point.map(egui::emath::OrderedFloat),
+            ));
+        }
         ViewportCommand::Screenshot(user_data) => {
             actions_requested.push(ActionRequested::Screenshot(user_data));
         }
@@ -1615,6 +1703,7 @@ pub fn create_winit_window_attributes(
         window_type: _window_type,
 
         mouse_passthrough: _, // handled in `apply_viewport_builder_to_window`
+        native_pixels_per_point: _, // handled by `State::set_native_pixels_per_point`
         clamp_size_to_monitor_size: _, // Handled in `viewport_builder` in `epi_integration.rs`
     } = viewport_builder;
 
@@ -1882,3 +1971,43 @@ pub fn short_window_event_description(event: &winit::event::WindowEvent) -> &'st
         WindowEvent::PanGesture { .. } => "WindowEvent::PanGesture",
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// No display, so no clipboard.
+    struct NoDisplay;
+
+    impl HasDisplayHandle for NoDisplay {
+        fn display_handle(
+            &self,
+        ) -> Result<raw_window_handle::DisplayHandle<'_>, raw_window_handle::HandleError> {
+            Err(raw_window_handle::HandleError::Unavailable)
+        }
+    }
+
+    #[test]
+    fn native_pixels_per_point_survives_memory_reset() {
+        let ctx = egui::Context::default();
+        let mut state = State::new(
+            ctx.clone(),
+            ViewportId::ROOT,
+            &NoDisplay,
+            Some(1.0),
+            None,
+            None,
+        );
+        state.set_native_pixels_per_point(Some(2.0));
+
+        ctx.memory_mut(|memory| *memory = Default::default());
+
+        let raw_input = state.egui_input().clone();
+        assert_eq!(
+            raw_input.viewports[&ViewportId::ROOT].native_pixels_per_point,
+            Some(2.0)
+        );
+        let _ = ctx.run(raw_input, |_| {});
+        assert_eq!(ctx.pixels_per_point(), 2.0);
+    }
+}
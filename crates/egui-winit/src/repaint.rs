@@ -0,0 +1,224 @@
+//! Repaint scheduling for when egui-winit is embedded in an event loop owned by someone else,
+//! e.g. a game engine.
+//!
+//! `eframe` does all of this for you. If you own the event loop yourself
+//! (and drive it with [`winit::platform::run_on_demand`] or [`winit::platform::pump_events`]),
+//! you need to make sure egui gets repainted when it asks for it:
+//!
+//! * call [`install_repaint_proxy`] once, so repaint requests from other threads (and delayed
+//!   repaints) wake up your event loop with a user event,
+//! * forward those user events to [`RepaintScheduler::on_repaint_request`],
+//! * after each egui pass, call [`RepaintScheduler::on_viewport_output`] for every viewport,
+//! * in [`winit::application::ApplicationHandler::about_to_wait`],
+//!   call [`RepaintScheduler::request_redraws`] and use the returned [`ControlFlow`].
+
+use std::time::Duration;
+
+use egui::{ViewportId, ViewportIdMap};
+use web_time::Instant;
+use winit::event_loop::{ControlFlow, EventLoopProxy};
+
+/// Sent to your event loop by [`install_repaint_proxy`] when egui wants to be repainted.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RepaintRequest {
+    /// What to repaint.
+    pub viewport_id: ViewportId,
+
+    /// When to repaint.
+    pub when: Instant,
+
+    /// What the cumulative pass number was when the repaint was _requested_.
+    pub cumulative_pass_nr: u64,
+}
+
+/// Make egui wake up your event loop whenever it needs a repaint.
+///
+/// Each request is sent as a user event through `proxy`.
+/// Your user event type must be constructible from a [`RepaintRequest`],
+/// which you should then pass on to [`RepaintScheduler::on_repaint_request`].
+///
+/// This replaces any callback set with [`egui::Context::set_request_repaint_callback`].
+pub fn install_repaint_proxy<T: From<RepaintRequest> + Send + 'static>(
+    egui_ctx: &egui::Context,
+    proxy: EventLoopProxy<T>,
+) {
+    let proxy = egui::mutex::Mutex::new(proxy);
+    egui_ctx.set_request_repaint_callback(move |info| {
+        log::trace!("request_repaint_callback: {info:?}");
+        let when = Instant::now() + info.delay;
+        let request = RepaintRequest {
+            viewport_id: info.viewport_id,
+            when,
+            cumulative_pass_nr: info.current_cumulative_pass_nr,
+        };
+        proxy.lock().send_event(T::from(request)).ok();
+    });
+}
+
+/// Keeps track of when each egui viewport needs to be repainted.
+///
+/// See the [module-level docs](self) for how to use it.
+#[derive(Clone, Debug, Default)]
+pub struct RepaintScheduler {
+    next_repaint_times: ViewportIdMap<Instant>,
+}
+
+impl RepaintScheduler {
+    /// Call this with the [`RepaintRequest`]s sent by [`install_repaint_proxy`].
+    ///
+    /// Requests that are already satisfied by a later pass are ignored.
+    pub fn on_repaint_request(&mut self, egui_ctx: &egui::Context, request: RepaintRequest) {
+        let RepaintRequest {
+            viewport_id,
+            when,
+            cumulative_pass_nr,
+        } = request;
+
+        let current_pass_nr = egui_ctx.cumulative_pass_nr_for(viewport_id);
+        if current_pass_nr == cumulative_pass_nr || current_pass_nr == cumulative_pass_nr + 1 {
+            self.repaint_at(viewport_id, when);
+        } else {
+            log::trace!("Got outdated RepaintRequest for {viewport_id:?}");
+        }
+    }
+
+    /// Call this after each egui pass with the [`egui::ViewportOutput::repaint_delay`]
+    /// of each viewport.
+    pub fn on_viewport_output(&mut self, viewport_id: ViewportId, repaint_delay: Duration) {
+        if repaint_delay == Duration::MAX {
+            return;
+        }
+        if let Some(when) = Instant::now().checked_add(repaint_delay) {
+            self.repaint_at(viewport_id, when);
+        }
+    }
+
+    /// Schedule a repaint of the given viewport, keeping any earlier scheduled repaint.
+    pub fn repaint_at(&mut self, viewport_id: ViewportId, when: Instant) {
+        self.next_repaint_times
+            .entry(viewport_id)
+            .and_modify(|existing| *existing = (*existing).min(when))
+            .or_insert(when);
+    }
+
+    /// Forget about a viewport, e.g. because its window was closed.
+    pub fn remove_viewport(&mut self, viewport_id: ViewportId) {
+        self.next_repaint_times.remove(&viewport_id);
+    }
+
+    /// Returns the viewports that are due for a repaint, and forgets about them.
+    ///
+    /// You should call [`winit::window::Window::request_redraw`] for each of them.
+    pub fn take_due(&mut self, now: Instant) -> Vec<ViewportId> {
+        let mut due = Vec::new();
+        self.next_repaint_times.retain(|viewport_id, when| {
+            if *when <= now {
+                due.push(*viewport_id);
+                false
+            } else {
+                true
+            }
+        });
+        due
+    }
+
+    /// When is the next repaint scheduled, if any?
+    pub fn next_repaint_time(&self) -> Option<Instant> {
+        self.next_repaint_times.values().min().copied()
+    }
+
+    /// The [`ControlFlow`] your event loop should use to honor the scheduled repaints.
+    pub fn control_flow(&self) -> ControlFlow {
+        match self.next_repaint_time() {
+            Some(when) => ControlFlow::WaitUntil(when),
+            None => ControlFlow::Wait,
+        }
+    }
+
+    /// Request a redraw of every viewport that is due, and return the [`ControlFlow`]
+    /// to use until the next scheduled repaint.
+    ///
+    /// `window` should look up the window of a viewport, if it has one.
+    /// Call this from [`winit::application::ApplicationHandler::about_to_wait`].
+    pub fn request_redraws<'w>(
+        &mut self,
+        mut window: impl FnMut(ViewportId) -> Option<&'w winit::window::Window>,
+    ) -> ControlFlow {
+        for viewport_id in self.take_due(Instant::now()) {
+            if let Some(window) = window(viewport_id) {
+                log::trace!("request_redraw for {viewport_id:?}");
+                window.request_redraw();
+            } else {
+                log::trace!("No window found for {viewport_id:?}");
+            }
+        }
+        self.control_flow()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn take_due() {
+        let now = Instant::now();
+        let other = ViewportId::from_hash_of("other");
+        let mut scheduler = RepaintScheduler::default();
+
+        scheduler.repaint_at(ViewportId::ROOT, now + Duration::from_secs(2));
+        scheduler.repaint_at(other, now + Duration::from_secs(5));
+        assert!(scheduler.take_due(now).is_empty(), "Nothing is due yet");
+
+        // The earlier of two requests for the same viewport wins:
+        scheduler.repaint_at(ViewportId::ROOT, now + Duration::from_secs(1));
+        scheduler.repaint_at(ViewportId::ROOT, now + Duration::from_secs(3));
+        assert_eq!(
+            scheduler.next_repaint_time(),
+            Some(now + Duration::from_secs(1))
+        );
+
+        let later = now + Duration::from_secs(1);
+        assert_eq!(scheduler.take_due(later), vec![ViewportId::ROOT]);
+        assert!(scheduler.take_due(later).is_empty(), "Only taken once");
+        assert_eq!(
+            scheduler.control_flow(),
+            ControlFlow::WaitUntil(now + Duration::from_secs(5))
+        );
+
+        assert_eq!(
+            scheduler.take_due(now + Duration::from_secs(10)),
+            vec![other]
+        );
+        assert_eq!(scheduler.control_flow(), ControlFlow::Wait);
+    }
+
+    #[test]
+    fn outdated_requests_are_ignored() {
+        let ctx = egui::Context::default();
+        for _ in 0..3 {
+            let _ = ctx.run(Default::default(), |_| {});
+        }
+        let current_pass_nr = ctx.cumulative_pass_nr_for(ViewportId::ROOT);
+
+        let now = Instant::now();
+        let request = |cumulative_pass_nr| RepaintRequest {
+            viewport_id: ViewportId::ROOT,
+            when: now,
+            cumulative_pass_nr,
+        };
+        let mut scheduler = RepaintScheduler::default();
+
+        scheduler.on_repaint_request(&ctx, request(current_pass_nr - 2));
+        assert!(
+            scheduler.take_due(now).is_empty(),
+            "Already repainted since"
+        );
+
+        // Requested during the last pass, or the current one:
+        for pass_nr in [current_pass_nr - 1, current_pass_nr] {
+            scheduler.on_repaint_request(&ctx, request(pass_nr));
+            assert_eq!(scheduler.take_due(now), vec![ViewportId::ROOT]);
+        }
+    }
+}
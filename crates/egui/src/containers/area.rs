@@ -607,21 +607,28 @@ impl Prepared {
         ui.set_clip_rect(self.constrain_rect); // Don't paint outside our bounds
 
         if self.fade_in {
-            if let Some(last_became_visible_at) = self.state.last_became_visible_at {
-                let age =
-                    ctx.input(|i| (i.time - last_became_visible_at) as f32 + i.predicted_dt / 2.0);
-                let opacity = crate::remap_clamp(age, 0.0..=ctx.style().animation_time, 0.0..=1.0);
-                let opacity = emath::easing::quadratic_out(opacity); // slow fade-out = quick fade-in
-                ui.multiply_opacity(opacity);
-                if opacity < 1.0 {
-                    ctx.request_repaint();
-                }
-            }
+            let opacity = emath::easing::quadratic_out(self.fade_in_progress(ctx)); // slow fade-out = quick fade-in
+            ui.multiply_opacity(opacity);
         }
 
         ui
     }
 
+    /// How far along the fade-in animation is, from `0.0` when the area appears to `1.0` when done.
+    ///
+    /// Requests a repaint while the animation is running.
+    pub(crate) fn fade_in_progress(&self, ctx: &Context) -> f32 {
+        let Some(last_became_visible_at) = self.state.last_became_visible_at else {
+            return 1.0;
+        };
+        let age = ctx.input(|i| (i.time - last_became_visible_at) as f32 + i.predicted_dt / 2.0);
+        let progress = crate::remap_clamp(age, 0.0..=ctx.style().animation_time, 0.0..=1.0);
+        if progress < 1.0 {
+            ctx.request_repaint();
+        }
+        progress
+    }
+
     pub(crate) fn with_widget_info(&self, make_info: impl Fn() -> crate::WidgetInfo) {
         self.move_response.widget_info(make_info);
     }
@@ -0,0 +1,311 @@
+use emath::{Align, NumExt as _, Rect, vec2};
+
+use crate::{Id, InnerResponse, Layout, Sense, Ui, UiBuilder};
+
+/// How [`Distribute`] fills the available width.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum Distribution {
+    /// Every item is stretched to the same width.
+    #[default]
+    EqualWidths,
+
+    /// Items keep their natural width, and the space left over is split evenly between them.
+    ///
+    /// The first item is at the left edge and the last item at the right edge.
+    EqualGaps,
+
+    /// Every item is stretched to a width proportional to its weight.
+    ///
+    /// Items without a weight get a weight of `1.0`.
+    Weighted(Vec<f32>),
+}
+
+#[derive(Clone, Default)]
+struct State {
+    /// The width of each item last frame.
+    widths: Vec<f32>,
+
+    /// The height of the tallest item last frame.
+    height: f32,
+}
+
+/// Lay out items in a row that exactly fills the available width,
+/// e.g. the buttons of a dialog or a toolbar.
+///
+/// The items are measured the first frame, in an invisible sizing pass,
+/// so there is no need to know their sizes in advance.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// egui::containers::Distribute::equal_widths().show(ui, |row| {
+///     row.item(|ui| ui.button("Save"));
+///     row.item(|ui| ui.button("Don't save"));
+///     row.item(|ui| ui.button("Cancel"));
+/// });
+/// # });
+/// ```
+///
+/// See also [`Ui::horizontal_justified`] and [`Ui::distribute`].
+#[must_use = "You should call .show()"]
+#[derive(Clone, Debug, Default)]
+pub struct Distribute {
+    distribution: Distribution,
+    id_salt: Option<Id>,
+}
+
+impl Distribute {
+    #[inline]
+    pub fn new(distribution: Distribution) -> Self {
+        Self {
+            distribution,
+            id_salt: None,
+        }
+    }
+
+    /// Stretch all items to the same width. See [`Distribution::EqualWidths`].
+    #[inline]
+    pub fn equal_widths() -> Self {
+        Self::new(Distribution::EqualWidths)
+    }
+
+    /// Put equal gaps between the items. See [`Distribution::EqualGaps`].
+    #[inline]
+    pub fn equal_gaps() -> Self {
+        Self::new(Distribution::EqualGaps)
+    }
+
+    /// Stretch the items proportionally to their weights. See [`Distribution::Weighted`].
+    #[inline]
+    pub fn weighted(weights: impl Into<Vec<f32>>) -> Self {
+        Self::new(Distribution::Weighted(weights.into()))
+    }
+
+    /// Where the sizes of the items are stored.
+    ///
+    /// Only needed if you have several rows in the same [`Ui`].
+    #[inline]
+    pub fn id_salt(mut self, id_salt: impl std::hash::Hash) -> Self {
+        self.id_salt = Some(Id::new(id_salt));
+        self
+    }
+
+    pub fn show<R>(
+        self,
+        ui: &mut Ui,
+        add_contents: impl FnOnce(&mut DistributeUi<'_>) -> R,
+    ) -> InnerResponse<R> {
+        let Self {
+            distribution,
+            id_salt,
+        } = self;
+        let id = ui.make_persistent_id(id_salt.unwrap_or_else(|| Id::new("distribute")));
+        let prev_state: Option<State> = ui.data(|d| d.get_temp(id));
+        let is_first_frame = prev_state.is_none();
+        let prev_state = prev_state.unwrap_or_default();
+
+        let available = ui.available_rect_before_wrap();
+        let spacing = ui.spacing().item_spacing.x;
+        let height = prev_state.height.at_least(ui.spacing().interact_size.y);
+
+        // When auto-sizing, make the row as narrow as possible:
+        let packed = is_first_frame || ui.is_sizing_pass();
+
+        let mut row = DistributeUi {
+            ui,
+            distribution,
+            packed,
+            available,
+            spacing,
+            height,
+            prev_widths: prev_state.widths,
+            widths: vec![],
+            next_x: available.left(),
+            max_height: 0.0,
+            sizing_pass: is_first_frame,
+        };
+        let inner = add_contents(&mut row);
+
+        let DistributeUi {
+            ui,
+            distribution,
+            prev_widths,
+            widths,
+            next_x,
+            max_height,
+            ..
+        } = row;
+
+        let width = if packed {
+            (next_x - spacing - available.left()).at_least(0.0)
+        } else {
+            available.width()
+        };
+        let rect = Rect::from_min_size(available.min, vec2(width, max_height));
+
+        let changed = if distribution == Distribution::EqualGaps || packed {
+            widths.len() != prev_widths.len()
+                || widths
+                    .iter()
+                    .zip(&prev_widths)
+                    .any(|(a, b)| 0.5 < (a - b).abs())
+        } else {
+            widths.len() != prev_widths.len()
+        };
+        if changed && ui.is_visible() {
+            ui.ctx().request_discard("Distribute: items changed size");
+        }
+        ui.data_mut(|d| {
+            d.insert_temp(
+                id,
+                State {
+                    widths,
+                    height: max_height,
+                },
+            );
+        });
+
+        let response = ui.allocate_rect(rect, Sense::hover());
+        InnerResponse::new(inner, response)
+    }
+}
+
+/// Add items to a [`Distribute`] row with [`Self::item`].
+pub struct DistributeUi<'a> {
+    ui: &'a mut Ui,
+    distribution: Distribution,
+
+    /// Use the natural widths with normal spacing, e.g. in a sizing pass.
+    packed: bool,
+
+    available: Rect,
+    spacing: f32,
+    height: f32,
+    prev_widths: Vec<f32>,
+    widths: Vec<f32>,
+    next_x: f32,
+    max_height: f32,
+    sizing_pass: bool,
+}
+
+impl DistributeUi<'_> {
+    /// Add the next item of the row.
+    ///
+    /// The [`Ui`] of a stretched item is top-down justified, so e.g. buttons fill its width.
+    pub fn item<R>(&mut self, add_contents: impl FnOnce(&mut Ui) -> R) -> R {
+        let index = self.widths.len();
+        let num_items = self.prev_widths.len();
+        let known = index < num_items && !self.packed;
+        let total_spacing = self.spacing * num_items.saturating_sub(1) as f32;
+
+        let (width, layout) = match &self.distribution {
+            Distribution::EqualGaps => (None, Layout::left_to_right(Align::Center)),
+            _ if !known => (None, Layout::left_to_right(Align::Center)),
+            Distribution::EqualWidths => (
+                Some((self.available.width() - total_spacing) / num_items as f32),
+                Layout::top_down_justified(Align::Center),
+            ),
+            Distribution::Weighted(weights) => {
+                let weight = |i| weights.get(i).copied().unwrap_or(1.0).at_least(0.0);
+                let total_weight: f32 = (0..num_items).map(weight).sum();
+                let fraction = if 0.0 < total_weight {
+                    weight(index) / total_weight
+                } else {
+                    1.0 / num_items as f32
+                };
+                (
+                    Some((self.available.width() - total_spacing) * fraction),
+                    Layout::top_down_justified(Align::Center),
+                )
+            }
+        };
+
+        let max_rect = if let Some(width) = width {
+            Rect::from_min_size(
+                emath::pos2(self.next_x, self.available.top()),
+                vec2(width.at_least(0.0), self.height),
+            )
+        } else {
+            Rect::from_min_max(
+                emath::pos2(self.next_x, self.available.top()),
+                emath::pos2(
+                    self.available.right().at_least(self.next_x),
+                    self.available.top() + self.height,
+                ),
+            )
+        };
+
+        let mut builder = UiBuilder::new().max_rect(max_rect).layout(layout);
+        if self.sizing_pass {
+            builder = builder.sizing_pass().invisible();
+        }
+        let mut child_ui = self.ui.new_child(builder);
+        let inner = add_contents(&mut child_ui);
+        let rect = child_ui.min_rect();
+        let item_width = width.unwrap_or(rect.width()).at_least(rect.width());
+
+        let gap = if self.distribution == Distribution::EqualGaps && known && 1 < num_items {
+            let natural_width: f32 = self.prev_widths.iter().sum();
+            ((self.available.width() - natural_width) / (num_items - 1) as f32)
+                .at_least(self.spacing)
+        } else {
+            self.spacing
+        };
+
+        self.widths.push(rect.width());
+        self.max_height = self.max_height.max(rect.height());
+        self.next_x += item_width + gap;
+        inner
+    }
+
+    /// The parent [`Ui`] of the row.
+    pub fn ui(&mut self) -> &mut Ui {
+        self.ui
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Distribute;
+    use crate::{CentralPanel, Context, RawInput, Rect};
+
+    #[test]
+    fn test_distribute() {
+        let ctx = Context::default();
+        let run = |distribute: fn() -> Distribute| {
+            let mut rects = [Rect::NOTHING; 3];
+            let mut available = Rect::NOTHING;
+            for _ in 0..3 {
+                let _ = ctx.run(RawInput::default(), |ctx| {
+                    CentralPanel::default().show(ctx, |ui| {
+                        available = ui.available_rect_before_wrap();
+                        distribute().show(ui, |row| {
+                            for (rect, text) in rects.iter_mut().zip(["A", "Longer", "B"]) {
+                                *rect = row.item(|ui| ui.button(text)).rect;
+                            }
+                        });
+                    });
+                });
+            }
+            (available, rects)
+        };
+
+        let (available, rects) = run(Distribute::equal_widths);
+        assert_eq!(rects[0].left(), available.left());
+        assert!((rects[2].right() - available.right()).abs() < 1.0);
+        assert!((rects[0].width() - rects[1].width()).abs() < 1.0);
+        assert!((rects[1].width() - rects[2].width()).abs() < 1.0);
+
+        let (available, rects) = run(Distribute::equal_gaps);
+        assert_eq!(rects[0].left(), available.left());
+        assert!((rects[2].right() - available.right()).abs() < 1.0);
+        assert!(rects[0].width() < rects[1].width(), "Natural widths");
+        let gaps = [
+            rects[1].left() - rects[0].right(),
+            rects[2].left() - rects[1].right(),
+        ];
+        assert!((gaps[0] - gaps[1]).abs() < 1.0);
+
+        let (_, rects) = run(|| Distribute::weighted([1.0, 2.0, 1.0]));
+        assert!((rects[1].width() - 2.0 * rects[0].width()).abs() < 1.0);
+    }
+}
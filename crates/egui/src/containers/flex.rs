@@ -0,0 +1,507 @@
+use emath::{Align, NumExt as _, Rect, Vec2, pos2, vec2};
+
+use crate::{Id, InnerResponse, Layout, Sense, Ui, UiBuilder};
+
+/// How a [`FlexLayout`] distributes the space left over in a line,
+/// when no item in it grows.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum FlexJustify {
+    /// Pack the items at the start of the line.
+    #[default]
+    Start,
+
+    /// Pack the items at the end of the line.
+    End,
+
+    /// Pack the items in the middle of the line.
+    Center,
+
+    /// The first item at the start, the last item at the end, and equal gaps between.
+    SpaceBetween,
+
+    /// Equal space around every item, i.e. half as much at the ends as between items.
+    SpaceAround,
+
+    /// Equal space between the items and at the ends.
+    SpaceEvenly,
+}
+
+/// How an item of a [`FlexLayout`] is sized and aligned.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FlexItem {
+    /// How much of the space left over in the line this item gets, relative to the other items.
+    ///
+    /// Default: `0.0`, i.e. don't grow.
+    pub grow: f32,
+
+    /// How much this item shrinks when the line is too narrow, relative to the other items.
+    ///
+    /// Default: `1.0`.
+    pub shrink: f32,
+
+    /// The width of the item before growing or shrinking.
+    ///
+    /// Default: `None`, the natural width of the contents.
+    pub basis: Option<f32>,
+
+    /// The vertical alignment of the item within its line.
+    ///
+    /// Default: `None`, use [`FlexLayout::align_items`].
+    pub align_self: Option<Align>,
+}
+
+impl Default for FlexItem {
+    fn default() -> Self {
+        Self {
+            grow: 0.0,
+            shrink: 1.0,
+            basis: None,
+            align_self: None,
+        }
+    }
+}
+
+impl FlexItem {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// See [`Self::grow`].
+    #[inline]
+    pub fn grow(mut self, grow: f32) -> Self {
+        self.grow = grow;
+        self
+    }
+
+    /// See [`Self::shrink`].
+    #[inline]
+    pub fn shrink(mut self, shrink: f32) -> Self {
+        self.shrink = shrink;
+        self
+    }
+
+    /// See [`Self::basis`].
+    #[inline]
+    pub fn basis(mut self, basis: f32) -> Self {
+        self.basis = Some(basis);
+        self
+    }
+
+    /// See [`Self::align_self`].
+    #[inline]
+    pub fn align_self(mut self, align: Align) -> Self {
+        self.align_self = Some(align);
+        self
+    }
+}
+
+#[derive(Clone, Default)]
+struct State {
+    /// Every item of the last frame, with the natural size of its contents.
+    items: Vec<(FlexItem, Vec2)>,
+}
+
+/// A container that lays out items in rows, like a CSS flexbox,
+/// e.g. for tag clouds, grids of buttons or toolbars that wrap when narrow.
+///
+/// Items can wrap onto new lines, grow to fill the space left over in their line,
+/// shrink when it is too narrow, and be aligned individually.
+///
+/// The natural sizes of the items are remembered from the previous frame.
+/// The first frame is an invisible sizing pass, and whenever an item changes size
+/// the frame is laid out again (see [`crate::Context::request_discard`]).
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui::containers::{FlexItem, FlexLayout};
+/// # let mut query = String::new();
+/// FlexLayout::new().wrap(true).show(ui, |flex| {
+///     for tag in ["rust", "gui", "immediate mode"] {
+///         flex.item(|ui| ui.button(tag));
+///     }
+///     flex.item_with(FlexItem::new().grow(1.0), |ui| ui.text_edit_singleline(&mut query));
+/// });
+/// # });
+/// ```
+#[must_use = "You should call .show()"]
+#[derive(Clone, Copy, Debug)]
+pub struct FlexLayout {
+    wrap: bool,
+    gap: Option<Vec2>,
+    justify: FlexJustify,
+    align_items: Align,
+    id_salt: Option<Id>,
+}
+
+impl Default for FlexLayout {
+    fn default() -> Self {
+        Self {
+            wrap: false,
+            gap: None,
+            justify: FlexJustify::Start,
+            align_items: Align::Center,
+            id_salt: None,
+        }
+    }
+}
+
+impl FlexLayout {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Start a new line when the items don't fit in the available width.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn wrap(mut self, wrap: bool) -> Self {
+        self.wrap = wrap;
+        self
+    }
+
+    /// The horizontal gap between items and the vertical gap between lines.
+    ///
+    /// Default: [`crate::Spacing::item_spacing`].
+    #[inline]
+    pub fn gap(mut self, gap: impl Into<Vec2>) -> Self {
+        self.gap = Some(gap.into());
+        self
+    }
+
+    /// How to distribute the space left over in a line. See [`FlexJustify`].
+    ///
+    /// Default: [`FlexJustify::Start`].
+    #[inline]
+    pub fn justify(mut self, justify: FlexJustify) -> Self {
+        self.justify = justify;
+        self
+    }
+
+    /// The vertical alignment of items within their line, unless set per item with [`FlexItem::align_self`].
+    ///
+    /// Default: [`Align::Center`].
+    #[inline]
+    pub fn align_items(mut self, align: Align) -> Self {
+        self.align_items = align;
+        self
+    }
+
+    /// Where the sizes of the items are stored.
+    ///
+    /// Only needed if you have several flex layouts in the same [`Ui`].
+    #[inline]
+    pub fn id_salt(mut self, id_salt: impl std::hash::Hash) -> Self {
+        self.id_salt = Some(Id::new(id_salt));
+        self
+    }
+
+    pub fn show<R>(
+        self,
+        ui: &mut Ui,
+        add_contents: impl FnOnce(&mut FlexUi<'_>) -> R,
+    ) -> InnerResponse<R> {
+        let id = ui.make_persistent_id(self.id_salt.unwrap_or_else(|| Id::new("flex")));
+        let prev_state: Option<State> = ui.data(|d| d.get_temp(id));
+        let is_first_frame = prev_state.is_none();
+        let prev_items = prev_state.unwrap_or_default().items;
+
+        let available = ui.available_rect_before_wrap();
+        let gap = self.gap.unwrap_or_else(|| ui.spacing().item_spacing);
+        // When auto-sizing, put everything on one line at its natural size:
+        let packed = is_first_frame || ui.is_sizing_pass();
+        let (rects, _) = if packed {
+            (vec![], Vec2::ZERO)
+        } else {
+            self.layout(&prev_items, available.width(), gap)
+        };
+
+        let mut flex = FlexUi {
+            ui,
+            layout: self,
+            gap,
+            available,
+            prev_items,
+            rects,
+            items: vec![],
+            next_pos: available.min,
+            bounds: Rect::from_min_size(available.min, Vec2::ZERO),
+            sizing_pass: is_first_frame,
+        };
+        let inner = add_contents(&mut flex);
+        let FlexUi {
+            ui,
+            prev_items,
+            items,
+            bounds,
+            ..
+        } = flex;
+
+        let changed = items.len() != prev_items.len()
+            || items
+                .iter()
+                .zip(&prev_items)
+                .any(|(a, b)| a.0 != b.0 || 0.5 < (a.1 - b.1).abs().max_elem());
+        if changed && ui.is_visible() {
+            ui.ctx().request_discard("FlexLayout: items changed size");
+        }
+        ui.data_mut(|d| d.insert_temp(id, State { items }));
+
+        let mut rect = bounds;
+        if !packed {
+            rect.max.x = rect.max.x.max(available.right());
+        }
+        let response = ui.allocate_rect(rect, Sense::hover());
+        InnerResponse::new(inner, response)
+    }
+
+    /// Where to put every item, relative to the top left corner, and the total size.
+    fn layout(&self, items: &[(FlexItem, Vec2)], width: f32, gap: Vec2) -> (Vec<Rect>, Vec2) {
+        let basis = |(item, size): &(FlexItem, Vec2)| item.basis.unwrap_or(size.x).at_least(0.0);
+
+        // Break into lines:
+        let mut lines = vec![];
+        let mut start = 0;
+        let mut line_width = 0.0;
+        for (i, item) in items.iter().enumerate() {
+            let item_width = basis(item);
+            if self.wrap && start < i && width < line_width + gap.x + item_width {
+                lines.push(start..i);
+                start = i;
+                line_width = 0.0;
+            }
+            if start < i {
+                line_width += gap.x;
+            }
+            line_width += item_width;
+        }
+        if start < items.len() {
+            lines.push(start..items.len());
+        }
+
+        let mut rects = Vec::with_capacity(items.len());
+        let mut y = 0.0;
+        let mut total_width: f32 = 0.0;
+        for line in lines {
+            let line_items = &items[line];
+            let num_gaps = line_items.len().saturating_sub(1) as f32;
+            let natural: f32 = line_items.iter().map(basis).sum();
+            let free = width - natural - gap.x * num_gaps;
+
+            let total_grow: f32 = line_items
+                .iter()
+                .map(|(item, _)| item.grow.at_least(0.0))
+                .sum();
+            let total_shrink: f32 = line_items
+                .iter()
+                .map(|item| item.0.shrink.at_least(0.0) * basis(item))
+                .sum();
+            let widths: Vec<f32> = line_items
+                .iter()
+                .map(|item| {
+                    let basis = basis(item);
+                    if 0.0 < free && 0.0 < total_grow {
+                        basis + free * item.0.grow.at_least(0.0) / total_grow
+                    } else if free < 0.0 && 0.0 < total_shrink {
+                        (basis + free * item.0.shrink.at_least(0.0) * basis / total_shrink)
+                            .at_least(0.0)
+                    } else {
+                        basis
+                    }
+                })
+                .collect();
+
+            let free = (width - widths.iter().sum::<f32>() - gap.x * num_gaps).at_least(0.0);
+            let n = line_items.len() as f32;
+            let (mut x, extra_gap) = match self.justify {
+                FlexJustify::Start => (0.0, 0.0),
+                FlexJustify::End => (free, 0.0),
+                FlexJustify::Center => (0.5 * free, 0.0),
+                FlexJustify::SpaceBetween => (0.0, free / (n - 1.0).at_least(1.0)),
+                FlexJustify::SpaceAround => (0.5 * free / n, free / n),
+                FlexJustify::SpaceEvenly => (free / (n + 1.0), free / (n + 1.0)),
+            };
+
+            let line_height = line_items
+                .iter()
+                .map(|(_, size)| size.y)
+                .fold(0.0, f32::max);
+            for ((item, size), width) in line_items.iter().zip(widths) {
+                let align = item.align_self.unwrap_or(self.align_items);
+                let top = y + (line_height - size.y) * align.to_factor();
+                rects.push(Rect::from_min_size(pos2(x, top), vec2(width, size.y)));
+                x += width + gap.x + extra_gap;
+            }
+            total_width = total_width.max(x - gap.x - extra_gap);
+            y += line_height + gap.y;
+        }
+
+        let height = (y - gap.y).at_least(0.0);
+        (rects, vec2(total_width, height))
+    }
+}
+
+/// Add items to a [`FlexLayout`] with [`Self::item`] and [`Self::item_with`].
+pub struct FlexUi<'a> {
+    ui: &'a mut Ui,
+    layout: FlexLayout,
+    gap: Vec2,
+    available: Rect,
+    prev_items: Vec<(FlexItem, Vec2)>,
+
+    /// Where to put each item, relative to the top left of the available rect.
+    rects: Vec<Rect>,
+
+    items: Vec<(FlexItem, Vec2)>,
+
+    /// Where to put an item that wasn't there last frame.
+    next_pos: emath::Pos2,
+
+    bounds: Rect,
+    sizing_pass: bool,
+}
+
+impl FlexUi<'_> {
+    /// Add an item with the default [`FlexItem`]: its natural size, shrinking if needed.
+    pub fn item<R>(&mut self, add_contents: impl FnOnce(&mut Ui) -> R) -> R {
+        self.item_with(FlexItem::default(), add_contents)
+    }
+
+    /// Add an item that grows, shrinks and is aligned according to `item`.
+    ///
+    /// The [`Ui`] of an item that grows or shrinks is justified, so e.g. a button fills its width.
+    pub fn item_with<R>(&mut self, item: FlexItem, add_contents: impl FnOnce(&mut Ui) -> R) -> R {
+        let index = self.items.len();
+        let prev = self
+            .prev_items
+            .get(index)
+            .filter(|prev| prev.0 == item)
+            .copied();
+        let placed = prev.zip(self.rects.get(index).copied());
+
+        let (max_rect, stretched) = if let Some(((_, natural_size), rect)) = placed {
+            let rect = rect.translate(self.available.min.to_vec2());
+            (rect, 0.5 < (rect.width() - natural_size.x).abs())
+        } else {
+            let max_rect = Rect::from_min_max(
+                self.next_pos,
+                pos2(
+                    self.available.right().at_least(self.next_pos.x),
+                    self.available.bottom(),
+                ),
+            );
+            (max_rect, false)
+        };
+
+        let align = item.align_self.unwrap_or(self.layout.align_items);
+        let mut layout = Layout::left_to_right(align);
+        if stretched {
+            layout = layout.with_main_justify(true);
+        }
+        let mut builder = UiBuilder::new().max_rect(max_rect).layout(layout);
+        if self.sizing_pass {
+            builder = builder.sizing_pass().invisible();
+        }
+        let mut child_ui = self.ui.new_child(builder);
+        let inner = add_contents(&mut child_ui);
+        let rect = child_ui.min_rect();
+
+        // A stretched item fills its width, so we only notice if it has become larger:
+        let natural_width = match prev {
+            Some((_, natural_size)) if stretched && rect.width() <= max_rect.width() + 0.5 => {
+                natural_size.x
+            }
+            _ => rect.width(),
+        };
+        self.items.push((item, vec2(natural_width, rect.height())));
+        self.bounds |= rect;
+        self.next_pos = pos2(rect.right() + self.gap.x, rect.top());
+        inner
+    }
+
+    /// The parent [`Ui`] of the flex layout.
+    pub fn ui(&mut self) -> &mut Ui {
+        self.ui
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{FlexItem, FlexJustify, FlexLayout};
+    use crate::{Align, vec2};
+
+    #[test]
+    fn test_flex_layout() {
+        let size = vec2(30.0, 20.0);
+        let items = [(FlexItem::new(), size); 3];
+        let gap = vec2(10.0, 5.0);
+
+        // Wrapping:
+        let (rects, total) = FlexLayout::new().wrap(true).layout(&items, 75.0, gap);
+        assert_eq!(rects[0].min, crate::pos2(0.0, 0.0));
+        assert_eq!(rects[1].min, crate::pos2(40.0, 0.0));
+        assert_eq!(rects[2].min, crate::pos2(0.0, 25.0), "Should wrap");
+        assert_eq!(total, vec2(70.0, 45.0));
+
+        // Growing:
+        let mut grow = items;
+        grow[1].0 = FlexItem::new().grow(1.0);
+        let (rects, _) = FlexLayout::new().layout(&grow, 200.0, gap);
+        assert_eq!(rects[1].width(), 200.0 - 2.0 * 30.0 - 2.0 * 10.0);
+        assert_eq!(rects[2].right(), 200.0);
+
+        // Shrinking:
+        let (rects, _) = FlexLayout::new().layout(&items, 50.0, gap);
+        assert_eq!(rects[2].right(), 50.0);
+        assert!((rects[0].width() - 10.0).abs() < 1e-4);
+
+        // Justify:
+        let justify = |justify| {
+            let (rects, _) = FlexLayout::new()
+                .justify(justify)
+                .layout(&items, 150.0, gap);
+            [rects[0].left(), rects[2].right()]
+        };
+        assert_eq!(justify(FlexJustify::Start), [0.0, 110.0]);
+        assert_eq!(justify(FlexJustify::End), [40.0, 150.0]);
+        assert_eq!(justify(FlexJustify::Center), [20.0, 130.0]);
+        assert_eq!(justify(FlexJustify::SpaceBetween), [0.0, 150.0]);
+        assert_eq!(justify(FlexJustify::SpaceEvenly), [10.0, 140.0]);
+
+        // Alignment within the line:
+        let mut tall = items;
+        tall[0].1.y = 40.0;
+        tall[2].0 = FlexItem::new().align_self(Align::Max);
+        let (rects, _) = FlexLayout::new().layout(&tall, 200.0, gap);
+        assert_eq!(rects[1].top(), 10.0, "Centered by default");
+        assert_eq!(rects[2].bottom(), 40.0);
+    }
+
+    #[test]
+    fn test_flex_ui() {
+        use crate::{CentralPanel, Context, RawInput, Rect};
+
+        let ctx = Context::default();
+        let mut rects = [Rect::NOTHING; 3];
+        let mut available = Rect::NOTHING;
+        let _ = ctx.run(RawInput::default(), |ctx| {
+            CentralPanel::default().show(ctx, |ui| {
+                available = ui.available_rect_before_wrap();
+                FlexLayout::new().show(ui, |flex| {
+                    rects[0] = flex.item(|ui| ui.button("A")).rect;
+                    rects[1] = flex
+                        .item_with(FlexItem::new().grow(1.0), |ui| ui.button("B"))
+                        .rect;
+                    rects[2] = flex.item(|ui| ui.button("C")).rect;
+                });
+            });
+        });
+        assert_eq!(rects[0].left(), available.left());
+        assert!(
+            (rects[2].right() - available.right()).abs() < 1.0,
+            "The growing button should fill the line, already in the first frame"
+        );
+        assert!(rects[0].width() < rects[1].width());
+    }
+}
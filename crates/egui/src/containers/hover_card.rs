@@ -0,0 +1,256 @@
+use emath::RectAlign;
+
+use crate::{AreaState, Frame, Id, InnerResponse, Popup, PopupCloseBehavior, Response, Ui};
+
+#[derive(Clone, Copy, Default)]
+struct State {
+    /// When the pointer started resting on the widget, while the card is closed.
+    hover_start: Option<f64>,
+
+    /// When the pointer was last over the widget or the card, while the card is open.
+    last_inside: Option<f64>,
+
+    /// The card was closed with a click or escape, and stays closed until the pointer leaves the widget.
+    dismissed: bool,
+}
+
+/// A rich popup for a widget, e.g. a user profile card, a link preview or a definition.
+///
+/// Unlike a tooltip, a hover card:
+/// * opens only after the pointer has rested on the widget for a while,
+/// * stays open while the pointer is over the card, so it can contain buttons, links and other interactive widgets,
+/// * closes only once the pointer has been away from both the widget and the card for a grace period,
+///   so the pointer can cross the gap between them.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// let response = ui.link("@emilk");
+/// egui::HoverCard::new(&response).show(|ui| {
+///     ui.strong("Emil Ernerfeldt");
+///     if ui.button("Follow").clicked() {
+///         // …
+///     }
+/// });
+/// # });
+/// ```
+///
+/// See also [`Response::on_hover_card`].
+#[must_use = "Call `.show()` to actually display the hover card"]
+pub struct HoverCard {
+    popup: Popup<'static>,
+    widget_hovered: bool,
+    delay: f32,
+    grace_time: f32,
+}
+
+impl HoverCard {
+    /// A hover card for this widget.
+    pub fn new(response: &Response) -> Self {
+        Self {
+            popup: Popup::from_response(response)
+                .id(response.id.with("hover_card"))
+                .close_behavior(PopupCloseBehavior::CloseOnClickOutside)
+                .gap(4.0),
+            widget_hovered: response.hovered(),
+            delay: response.ctx.style().interaction.tooltip_delay,
+            grace_time: 0.3,
+        }
+    }
+
+    /// How long the pointer must rest on the widget before the card opens, in seconds.
+    ///
+    /// Default: [`crate::style::Interaction::tooltip_delay`].
+    #[inline]
+    pub fn delay(mut self, delay: f32) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// How long the card stays open after the pointer has left both the widget and the card, in seconds.
+    ///
+    /// Default: 0.3.
+    #[inline]
+    pub fn grace_time(mut self, grace_time: f32) -> Self {
+        self.grace_time = grace_time;
+        self
+    }
+
+    /// Where to show the card relative to the widget.
+    ///
+    /// Default: [`RectAlign::BOTTOM_START`].
+    #[inline]
+    pub fn align(mut self, align: RectAlign) -> Self {
+        self.popup = self.popup.align(align);
+        self
+    }
+
+    /// Set the default width of the card.
+    #[inline]
+    pub fn width(mut self, width: f32) -> Self {
+        self.popup = self.popup.width(width);
+        self
+    }
+
+    /// Set the frame of the card.
+    ///
+    /// Default: [`Frame::popup`].
+    #[inline]
+    pub fn frame(mut self, frame: Frame) -> Self {
+        self.popup = self.popup.frame(frame);
+        self
+    }
+
+    /// Change the id of the card.
+    #[inline]
+    pub fn id(mut self, id: Id) -> Self {
+        self.popup = self.popup.id(id);
+        self
+    }
+
+    /// Is the card open, i.e. was it shown last frame?
+    pub fn is_open(&self) -> bool {
+        let id = self.popup.get_id();
+        self.popup
+            .ctx()
+            .data(|d| d.get_temp::<State>(id))
+            .is_some_and(|state| state.last_inside.is_some())
+    }
+
+    /// Show the card, if it is open.
+    pub fn show<R>(self, content: impl FnOnce(&mut Ui) -> R) -> Option<InnerResponse<R>> {
+        let Self {
+            popup,
+            widget_hovered,
+            delay,
+            grace_time,
+        } = self;
+        let ctx = popup.ctx().clone();
+        let id = popup.get_id();
+        let mut state: State = ctx.data(|d| d.get_temp(id)).unwrap_or_default();
+        let (now, hover_pos, press_origin) = ctx.input(|i| {
+            (
+                i.time,
+                i.pointer.hover_pos(),
+                i.pointer
+                    .any_down()
+                    .then(|| i.pointer.press_origin())
+                    .flatten(),
+            )
+        });
+
+        if let Some(last_inside) = state.last_inside {
+            let card_rect = AreaState::load(&ctx, id).map(|area| area.rect());
+            let in_card = |pos: Option<_>| {
+                pos.zip(card_rect)
+                    .is_some_and(|(pos, rect): (_, crate::Rect)| rect.contains(pos))
+            };
+            // Keep it open while dragging something in the card, e.g. a slider:
+            if widget_hovered || in_card(hover_pos) || in_card(press_origin) {
+                state.last_inside = Some(now);
+            } else {
+                let time_left = grace_time - (now - last_inside) as f32;
+                if 0.0 < time_left {
+                    ctx.request_repaint_after_secs(time_left);
+                } else {
+                    state.last_inside = None;
+                }
+            }
+        } else if !widget_hovered {
+            state.hover_start = None;
+            state.dismissed = false;
+        } else if !state.dismissed {
+            let hover_start = *state.hover_start.get_or_insert(now);
+            let time_left = delay - (now - hover_start) as f32;
+            if 0.0 < time_left {
+                ctx.request_repaint_after_secs(time_left);
+            } else {
+                state.hover_start = None;
+                state.last_inside = Some(now);
+            }
+        }
+
+        let response = if state.last_inside.is_some() {
+            popup.open(true).show(content)
+        } else {
+            None
+        };
+        if response
+            .as_ref()
+            .is_some_and(|response| response.response.should_close())
+        {
+            state = State {
+                dismissed: widget_hovered,
+                ..Default::default()
+            };
+        }
+
+        ctx.data_mut(|d| d.insert_temp(id, state));
+        response
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{CentralPanel, Context, Event, Modifiers, PointerButton, Pos2, RawInput, Rect};
+
+    #[test]
+    fn test_hover_card() {
+        let ctx = Context::default();
+        // Returns the rect of the label, the rect of the card if open, and if "Follow" was clicked:
+        let run = |time: f64, events: Vec<Event>| {
+            let mut result = (Rect::NOTHING, None, false);
+            let _ = ctx.run(
+                RawInput {
+                    time: Some(time),
+                    events,
+                    ..Default::default()
+                },
+                |ctx| {
+                    CentralPanel::default().show(ctx, |ui| {
+                        let response = ui.label("@user");
+                        result.0 = response.rect;
+                        let card = super::HoverCard::new(&response)
+                            .show(|ui| ui.button("Follow").clicked());
+                        if let Some(card) = card {
+                            result.1 = Some(card.response.rect);
+                            result.2 = card.inner;
+                        }
+                    });
+                },
+            );
+            result
+        };
+        let moved = |pos: Pos2| vec![Event::PointerMoved(pos)];
+
+        let (label, ..) = run(0.0, vec![]);
+        assert!(run(0.1, moved(label.center())).1.is_none());
+        assert!(
+            run(0.3, moved(label.center())).1.is_none(),
+            "Opened too early"
+        );
+        assert!(run(0.7, vec![]).1.is_some(), "Should open after the delay");
+        let card = run(0.8, vec![]).1.unwrap();
+
+        // Moving into the card keeps it open, and it can be clicked:
+        let button = card.shrink(8.0).left_top() + crate::vec2(4.0, 4.0);
+        assert!(run(0.85, moved(label.left_bottom())).1.is_some());
+        assert!(run(0.9, moved(button)).1.is_some());
+        assert!(run(2.0, moved(button)).1.is_some());
+        let click = [true, false].map(|pressed| Event::PointerButton {
+            pos: button,
+            button: PointerButton::Primary,
+            pressed,
+            modifiers: Modifiers::NONE,
+        });
+        assert!(run(2.1, click.to_vec()).2, "The button should be clickable");
+
+        // Closes after the grace time once the pointer has left:
+        let outside = card.right_bottom() + crate::vec2(50.0, 50.0);
+        assert!(run(2.2, moved(outside)).1.is_some());
+        assert!(run(2.3, vec![]).1.is_some(), "Closed before the grace time");
+        assert!(
+            run(2.5, vec![]).1.is_none(),
+            "Should close after the grace time"
+        );
+    }
+}
@@ -6,13 +6,17 @@ pub(crate) mod area;
 mod close_tag;
 pub mod collapsing_header;
 mod combo_box;
+mod distribute;
+mod flex;
 pub mod frame;
+mod hover_card;
 pub mod menu;
 pub mod modal;
 pub mod old_popup;
 pub mod panel;
 mod popup;
 pub(crate) mod resize;
+mod responsive;
 mod scene;
 pub mod scroll_area;
 mod sides;
@@ -24,12 +28,16 @@ pub use {
     close_tag::ClosableTag,
     collapsing_header::{CollapsingHeader, CollapsingResponse},
     combo_box::*,
+    distribute::{Distribute, DistributeUi, Distribution},
+    flex::{FlexItem, FlexJustify, FlexLayout, FlexUi},
     frame::Frame,
+    hover_card::HoverCard,
     modal::{Modal, ModalResponse},
     old_popup::*,
     panel::{CentralPanel, SidePanel, TopBottomPanel},
     popup::*,
     resize::Resize,
+    responsive::{Responsive, SizeClass},
     scene::{DragPanButtons, Scene},
     scroll_area::ScrollArea,
     sides::Sides,
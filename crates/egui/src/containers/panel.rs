@@ -292,7 +292,13 @@ impl SidePanel {
         let inner_response = frame.show(&mut panel_ui, |ui| {
             ui.set_min_height(ui.max_rect().height()); // Make sure the frame fills the full height
             ui.set_min_width((width_range.min - frame.inner_margin.sum().x).at_least(0.0));
-            add_contents(ui)
+            let kind = match side {
+                Side::Left => UiKind::LeftPanel,
+                Side::Right => UiKind::RightPanel,
+            };
+            ui.ctx()
+                .clone()
+                .with_frame_cost_scope(id, kind, || add_contents(ui))
         });
 
         let rect = inner_response.response.rect;
@@ -788,7 +794,13 @@ impl TopBottomPanel {
         let inner_response = frame.show(&mut panel_ui, |ui| {
             ui.set_min_width(ui.max_rect().width()); // Make the frame fill full width
             ui.set_min_height((height_range.min - frame.inner_margin.sum().y).at_least(0.0));
-            add_contents(ui)
+            let kind = match side {
+                TopBottomSide::Top => UiKind::TopPanel,
+                TopBottomSide::Bottom => UiKind::BottomPanel,
+            };
+            ui.ctx()
+                .clone()
+                .with_frame_cost_scope(id, kind, || add_contents(ui))
         });
 
         let rect = inner_response.response.rect;
@@ -1123,7 +1135,10 @@ impl CentralPanel {
         let frame = frame.unwrap_or_else(|| Frame::central_panel(ui.style()));
         frame.show(&mut panel_ui, |ui| {
             ui.expand_to_include_rect(ui.max_rect()); // Expand frame to include it all
-            add_contents(ui)
+            let id = ui.id();
+            ui.ctx()
+                .clone()
+                .with_frame_cost_scope(id, UiKind::CentralPanel, || add_contents(ui))
         })
     }
 
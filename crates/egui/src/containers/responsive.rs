@@ -0,0 +1,187 @@
+use crate::{Context, Id, Ui};
+
+/// A coarse classification of the available width, see [`Responsive`].
+///
+/// The default breakpoints follow common window size classes:
+/// phones in portrait are compact, tablets and small windows are medium,
+/// and desktops are expanded.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SizeClass {
+    /// Narrower than the first breakpoint, e.g. a phone.
+    Compact,
+
+    /// Between the first and second breakpoint, e.g. a tablet.
+    Medium,
+
+    /// Wider than the second breakpoint, e.g. a desktop.
+    Expanded,
+}
+
+impl SizeClass {
+    /// From the index returned by [`Responsive::select`].
+    pub fn from_index(index: usize) -> Self {
+        match index {
+            0 => Self::Compact,
+            1 => Self::Medium,
+            _ => Self::Expanded,
+        }
+    }
+}
+
+/// Pick a layout based on the available width, e.g. to switch between
+/// phone, tablet and desktop arrangements of panels and grids.
+///
+/// The width is compared to a list of breakpoints.
+/// Near a breakpoint the previous choice is kept until the width has moved past it
+/// by half the [`Self::hysteresis`], so the layout doesn't flicker back and forth
+/// when resizing a window right at the boundary.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// egui::containers::Responsive::new().show(
+///     ui,
+///     |ui| {
+///         ui.label("Everything stacked in one column");
+///     },
+///     |ui| {
+///         ui.label("A narrow sidebar");
+///     },
+///     |ui| {
+///         ui.label("Sidebar, content and inspector side by side");
+///     },
+/// );
+/// # });
+/// ```
+#[must_use = "You should call .show() or .select()"]
+#[derive(Clone, Debug)]
+pub struct Responsive {
+    breakpoints: Vec<f32>,
+    hysteresis: f32,
+    id_salt: Id,
+}
+
+impl Default for Responsive {
+    fn default() -> Self {
+        Self {
+            breakpoints: vec![600.0, 840.0],
+            hysteresis: 16.0,
+            id_salt: Id::new("responsive"),
+        }
+    }
+}
+
+impl Responsive {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The widths at which to switch layout, in increasing order.
+    ///
+    /// `n` breakpoints give `n + 1` layouts.
+    ///
+    /// Default: `[600.0, 840.0]`, see [`SizeClass`].
+    #[inline]
+    pub fn breakpoints(mut self, breakpoints: impl Into<Vec<f32>>) -> Self {
+        self.breakpoints = breakpoints.into();
+        self
+    }
+
+    /// How many points the width must move past a breakpoint before switching,
+    /// half on either side of it.
+    ///
+    /// Default: `16.0`.
+    #[inline]
+    pub fn hysteresis(mut self, hysteresis: f32) -> Self {
+        self.hysteresis = hysteresis;
+        self
+    }
+
+    /// Where the current choice is stored.
+    ///
+    /// Only needed if you have several [`Responsive`] in the same [`Ui`].
+    #[inline]
+    pub fn id_salt(mut self, id_salt: impl std::hash::Hash) -> Self {
+        self.id_salt = Id::new(id_salt);
+        self
+    }
+
+    /// Which layout to use for the available width of this [`Ui`].
+    ///
+    /// `0` is narrower than the first breakpoint, `1` is between the first and the second, and so on.
+    pub fn select(&self, ui: &Ui) -> usize {
+        self.select_for_width(ui.ctx(), ui.id().with(self.id_salt), ui.available_width())
+    }
+
+    /// Which layout to use for the whole screen, e.g. to decide which panels to show.
+    pub fn select_for_screen(&self, ctx: &Context) -> usize {
+        self.select_for_width(ctx, self.id_salt, ctx.screen_rect().width())
+    }
+
+    /// [`Self::select`] as a [`SizeClass`].
+    pub fn size_class(&self, ui: &Ui) -> SizeClass {
+        SizeClass::from_index(self.select(ui))
+    }
+
+    /// Show one of three layouts depending on the [`SizeClass`] of the available width.
+    pub fn show<R>(
+        &self,
+        ui: &mut Ui,
+        compact: impl FnOnce(&mut Ui) -> R,
+        medium: impl FnOnce(&mut Ui) -> R,
+        expanded: impl FnOnce(&mut Ui) -> R,
+    ) -> R {
+        match self.size_class(ui) {
+            SizeClass::Compact => compact(ui),
+            SizeClass::Medium => medium(ui),
+            SizeClass::Expanded => expanded(ui),
+        }
+    }
+
+    fn select_for_width(&self, ctx: &Context, id: Id, width: f32) -> usize {
+        let prev: Option<usize> = ctx.data(|d| d.get_temp(id));
+        let index = select_index(&self.breakpoints, self.hysteresis, prev, width);
+        if prev != Some(index) {
+            ctx.data_mut(|d| d.insert_temp(id, index));
+        }
+        index
+    }
+}
+
+fn select_index(breakpoints: &[f32], hysteresis: f32, prev: Option<usize>, width: f32) -> usize {
+    let Some(prev) = prev else {
+        return breakpoints.iter().filter(|&&bp| bp <= width).count();
+    };
+    let margin = 0.5 * hysteresis;
+    let mut index = prev.min(breakpoints.len());
+    while index < breakpoints.len() && breakpoints[index] + margin <= width {
+        index += 1;
+    }
+    while 0 < index && width < breakpoints[index - 1] - margin {
+        index -= 1;
+    }
+    index
+}
+
+#[cfg(test)]
+mod tests {
+    use super::select_index;
+
+    #[test]
+    fn test_responsive_hysteresis() {
+        let breakpoints = [600.0, 840.0];
+        assert_eq!(select_index(&breakpoints, 16.0, None, 300.0), 0);
+        assert_eq!(select_index(&breakpoints, 16.0, None, 600.0), 1);
+        assert_eq!(select_index(&breakpoints, 16.0, None, 2000.0), 2);
+
+        // Near a breakpoint the previous choice sticks:
+        assert_eq!(select_index(&breakpoints, 16.0, Some(0), 605.0), 0);
+        assert_eq!(select_index(&breakpoints, 16.0, Some(0), 610.0), 1);
+        assert_eq!(select_index(&breakpoints, 16.0, Some(1), 595.0), 1);
+        assert_eq!(select_index(&breakpoints, 16.0, Some(1), 590.0), 0);
+
+        // Big jumps skip over several breakpoints:
+        assert_eq!(select_index(&breakpoints, 16.0, Some(0), 2000.0), 2);
+        assert_eq!(select_index(&breakpoints, 16.0, Some(2), 100.0), 0);
+    }
+}
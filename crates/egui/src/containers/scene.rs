@@ -3,8 +3,8 @@ use core::f32;
 use emath::{GuiRounding as _, Pos2};
 
 use crate::{
-    InnerResponse, LayerId, PointerButton, Rangef, Rect, Response, Sense, Ui, UiBuilder, Vec2,
-    emath::TSTransform,
+    Align2, FontId, InnerResponse, KineticPan, LayerId, NumExt as _, Painter, PointerButton,
+    Rangef, Rect, Response, Sense, Stroke, Ui, UiBuilder, Vec2, emath::TSTransform, pos2,
 };
 
 /// Creates a transformation that fits a given scene rectangle into the available screen size.
@@ -41,6 +41,14 @@ fn fit_to_rect_in_scene(
 /// * Supports zooming
 /// * Has no scroll bars
 /// * Has no limits on the scrolling
+///
+/// Zooming is centered on the mouse cursor.
+/// The transform is applied to both painting and input, so the contents
+/// can use scene coordinates everywhere. Use [`Self::to_global`] to convert
+/// between scene and screen coordinates.
+///
+/// Use [`Self::grid`] and [`Self::rulers`] for a background grid and rulers, e.g. for diagrams.
+#[doc(alias = "Canvas")]
 #[derive(Clone, Debug)]
 #[must_use = "You should call .show()"]
 pub struct Scene {
@@ -48,6 +56,9 @@ pub struct Scene {
     sense: Sense,
     max_inner_size: Vec2,
     drag_pan_buttons: DragPanButtons,
+    grid: Option<f32>,
+    rulers: bool,
+    kinetic_pan: KineticPan,
 }
 
 /// Specifies which pointer buttons can be used to pan the scene by dragging.
@@ -80,6 +91,9 @@ impl Default for Scene {
             sense: Sense::click_and_drag(),
             max_inner_size: Vec2::splat(1000.0),
             drag_pan_buttons: DragPanButtons::all(),
+            grid: None,
+            rulers: false,
+            kinetic_pan: KineticPan::default(),
         }
     }
 }
@@ -130,6 +144,51 @@ impl Scene {
         self
     }
 
+    /// How the scene keeps moving after being dragged and released.
+    ///
+    /// The scene has no bounds, so [`KineticPan::rubber_band`] is not used.
+    /// Use [`KineticPan::OFF`] to stop as soon as the drag is released.
+    ///
+    /// Default: [`KineticPan::default`].
+    #[inline]
+    pub fn kinetic_pan(mut self, kinetic_pan: KineticPan) -> Self {
+        self.kinetic_pan = kinetic_pan;
+        self
+    }
+
+    /// Paint a grid in the background, with lines `spacing` apart in scene coordinates.
+    ///
+    /// When zoomed out, only every 10th line is shown, and so on,
+    /// so that the grid never gets too dense.
+    ///
+    /// By default there is no grid.
+    #[inline]
+    pub fn grid(mut self, spacing: f32) -> Self {
+        self.grid = Some(spacing);
+        self
+    }
+
+    /// Show rulers with scene coordinates along the top and left edges.
+    ///
+    /// The rulers use the same spacing as [`Self::grid`], or 100 if there is no grid.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn rulers(mut self, rulers: bool) -> Self {
+        self.rulers = rulers;
+        self
+    }
+
+    /// The transform from scene coordinates to global (screen) coordinates.
+    ///
+    /// Call this with the [`Ui`] given to the contents in [`Self::show`].
+    /// Use [`TSTransform::inverse`] to go from screen coordinates to scene coordinates.
+    pub fn to_global(ui: &Ui) -> TSTransform {
+        ui.ctx()
+            .layer_transform_to_global(ui.layer_id())
+            .unwrap_or_default()
+    }
+
     /// `scene_rect` contains the view bounds of the inner [`Ui`].
     ///
     /// `scene_rect` will be mutated by any panning/zooming done by the user.
@@ -143,9 +202,14 @@ impl Scene {
         scene_rect: &mut Rect,
         add_contents: impl FnOnce(&mut Ui) -> R,
     ) -> InnerResponse<R> {
-        let (outer_rect, _outer_response) =
+        let (mut outer_rect, _outer_response) =
             parent_ui.allocate_exact_size(parent_ui.available_size_before_wrap(), Sense::hover());
 
+        let ruler_rect = outer_rect;
+        if self.rulers {
+            outer_rect.min += Vec2::splat(RULER_SIZE);
+        }
+
         let mut to_global = fit_to_rect_in_scene(outer_rect, *scene_rect, self.zoom_range);
 
         let scene_rect_was_good =
@@ -159,6 +223,10 @@ impl Scene {
             r
         });
 
+        if self.rulers {
+            self.paint_rulers(parent_ui, ruler_rect, outer_rect, to_global);
+        }
+
         if ret.response.changed() {
             // Only update if changed, both to avoid numeric drift,
             // and to avoid expanding the scene rect unnecessarily.
@@ -208,6 +276,10 @@ impl Scene {
         // Set a correct global clip rect:
         local_ui.set_clip_rect(to_global.inverse() * outer_rect);
 
+        if let Some(spacing) = self.grid {
+            paint_grid(local_ui.painter(), spacing, to_global.scaling);
+        }
+
         // Tell egui to apply the transform on the layer:
         local_ui
             .ctx()
@@ -225,19 +297,98 @@ impl Scene {
         }
     }
 
+    fn paint_rulers(&self, ui: &Ui, ruler_rect: Rect, scene_rect: Rect, to_global: TSTransform) {
+        let visuals = ui.visuals();
+        let painter = ui.painter_at(ruler_rect);
+        let step = grid_step(self.grid.unwrap_or(100.0), to_global.scaling, 64.0);
+        let font_id = FontId::monospace(RULER_SIZE * 0.6);
+        let stroke = visuals.widgets.noninteractive.fg_stroke;
+        let to_scene = to_global.inverse();
+        let visible = to_scene * scene_rect;
+        if !(0.0 < step && step.is_finite() && visible.is_finite()) {
+            return;
+        }
+
+        let top = Rect::from_x_y_ranges(scene_rect.x_range(), ruler_rect.top()..=scene_rect.top());
+        let left =
+            Rect::from_x_y_ranges(ruler_rect.left()..=scene_rect.left(), scene_rect.y_range());
+        painter.rect_filled(top, 0.0, visuals.faint_bg_color);
+        painter.rect_filled(left, 0.0, visuals.faint_bg_color);
+
+        for i in (visible.left() / step).floor() as i64..=(visible.right() / step).ceil() as i64 {
+            let value = i as f32 * step;
+            let x = (to_global * pos2(value, 0.0)).x;
+            if top.x_range().contains(x) {
+                painter.vline(x, top.bottom() - 0.5 * RULER_SIZE..=top.bottom(), stroke);
+                painter.text(
+                    pos2(x + 2.0, top.top()),
+                    Align2::LEFT_TOP,
+                    emath::format_with_decimals_in_range(value as f64, 0..=3),
+                    font_id.clone(),
+                    stroke.color,
+                );
+            }
+        }
+        for i in (visible.top() / step).floor() as i64..=(visible.bottom() / step).ceil() as i64 {
+            let value = i as f32 * step;
+            let y = (to_global * pos2(0.0, value)).y;
+            if left.y_range().contains(y) {
+                painter.hline(left.right() - 0.5 * RULER_SIZE..=left.right(), y, stroke);
+                painter.text(
+                    pos2(left.left() + 1.0, y + 2.0),
+                    Align2::LEFT_TOP,
+                    emath::format_with_decimals_in_range(value as f64, 0..=3),
+                    font_id.clone(),
+                    stroke.color,
+                );
+            }
+        }
+    }
+
     /// Helper function to handle pan and zoom interactions on a response.
     pub fn register_pan_and_zoom(&self, ui: &Ui, resp: &mut Response, to_global: &mut TSTransform) {
-        let dragged = self.drag_pan_buttons.iter().any(|button| match button {
-            DragPanButtons::PRIMARY => resp.dragged_by(PointerButton::Primary),
-            DragPanButtons::SECONDARY => resp.dragged_by(PointerButton::Secondary),
-            DragPanButtons::MIDDLE => resp.dragged_by(PointerButton::Middle),
-            DragPanButtons::EXTRA_1 => resp.dragged_by(PointerButton::Extra1),
-            DragPanButtons::EXTRA_2 => resp.dragged_by(PointerButton::Extra2),
-            _ => false,
-        });
+        let pan_button = |button| match button {
+            DragPanButtons::PRIMARY => Some(PointerButton::Primary),
+            DragPanButtons::SECONDARY => Some(PointerButton::Secondary),
+            DragPanButtons::MIDDLE => Some(PointerButton::Middle),
+            DragPanButtons::EXTRA_1 => Some(PointerButton::Extra1),
+            DragPanButtons::EXTRA_2 => Some(PointerButton::Extra2),
+            _ => None,
+        };
+        let dragged = self
+            .drag_pan_buttons
+            .iter()
+            .filter_map(pan_button)
+            .any(|button| resp.dragged_by(button));
+        let velocity_id = resp.id.with("kinetic_pan");
         if dragged {
             to_global.translation += to_global.scaling * resp.drag_delta();
             resp.mark_changed();
+            ui.data_mut(|d| d.remove_temp::<Vec2>(velocity_id));
+        } else {
+            let mut velocity = if self
+                .drag_pan_buttons
+                .iter()
+                .filter_map(pan_button)
+                .any(|button| resp.drag_stopped_by(button))
+            {
+                ui.input(|i| i.pointer.velocity())
+            } else {
+                ui.data(|d| d.get_temp(velocity_id)).unwrap_or_default()
+            };
+            if velocity != Vec2::ZERO {
+                let dt = ui.input(|i| i.stable_dt).at_most(0.1);
+                let mut translation = to_global.translation;
+                if self
+                    .kinetic_pan
+                    .step_2d(&mut translation, &mut velocity, Rect::EVERYTHING, dt)
+                {
+                    to_global.translation = translation;
+                    resp.mark_changed();
+                    ui.ctx().request_repaint();
+                }
+                ui.data_mut(|d| d.insert_temp(velocity_id, velocity));
+            }
         }
 
         if let Some(mouse_pos) = ui.input(|i| i.pointer.latest_pos()) {
@@ -275,3 +426,73 @@ impl Scene {
         }
     }
 }
+
+/// Width of the rulers, see [`Scene::rulers`].
+const RULER_SIZE: f32 = 16.0;
+
+/// A multiple of 10 of `spacing` that is at least `min_spacing_on_screen` points on screen.
+fn grid_step(spacing: f32, scaling: f32, min_spacing_on_screen: f32) -> f32 {
+    if !(0.0 < spacing && spacing.is_finite() && 0.0 < scaling && scaling.is_finite()) {
+        return f32::INFINITY;
+    }
+    let exponent = (min_spacing_on_screen / (spacing * scaling)).log10().ceil();
+    spacing * 10_f32.powf(exponent)
+}
+
+/// Paint grid lines in scene coordinates, covering the clip rect of the painter.
+fn paint_grid(painter: &Painter, spacing: f32, scaling: f32) {
+    let visuals = painter.ctx().style().visuals.clone();
+    let step = grid_step(spacing, scaling, 8.0);
+    let visible = painter.clip_rect();
+    if !(0.0 < step && step.is_finite() && visible.is_finite()) {
+        return;
+    }
+    let color = visuals.widgets.noninteractive.bg_stroke.color;
+
+    // Keep the lines one point wide on screen, whatever the zoom:
+    let minor = Stroke::new(1.0 / scaling, color.gamma_multiply(0.4));
+    let major = Stroke::new(1.0 / scaling, color);
+
+    let is_major = |i: i64| i.rem_euclid(10) == 0;
+    for i in (visible.left() / step).floor() as i64..=(visible.right() / step).ceil() as i64 {
+        let stroke = if is_major(i) { major } else { minor };
+        painter.vline(i as f32 * step, visible.y_range(), stroke);
+    }
+    for i in (visible.top() / step).floor() as i64..=(visible.bottom() / step).ceil() as i64 {
+        let stroke = if is_major(i) { major } else { minor };
+        painter.hline(visible.x_range(), i as f32 * step, stroke);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Scene, grid_step};
+    use emath::{Rect, pos2, vec2};
+
+    #[test]
+    fn test_grid_step() {
+        assert_eq!(grid_step(10.0, 1.0, 8.0), 10.0);
+        assert_eq!(grid_step(10.0, 0.5, 8.0), 100.0);
+        assert_eq!(grid_step(10.0, 0.01, 8.0), 1000.0);
+        assert_eq!(grid_step(10.0, 10.0, 8.0), 1.0);
+        assert!(grid_step(0.0, 1.0, 8.0).is_infinite());
+    }
+
+    #[test]
+    fn rulers_with_degenerate_spacing() {
+        for spacing in [0.0, -1.0, f32::NAN, f32::INFINITY, 1e-30] {
+            for rect in [
+                Rect::ZERO,
+                Rect::from_min_size(pos2(0.0, 0.0), vec2(1e-30, 1e-30)),
+            ] {
+                crate::__run_test_ui(|ui| {
+                    let mut scene_rect = rect;
+                    Scene::new()
+                        .grid(spacing)
+                        .rulers(true)
+                        .show(ui, &mut scene_rect, |_| {});
+                });
+            }
+        }
+    }
+}
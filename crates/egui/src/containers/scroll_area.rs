@@ -3,8 +3,8 @@
 use std::ops::{Add, AddAssign, BitOr, BitOrAssign};
 
 use crate::{
-    Context, CursorIcon, Id, NumExt as _, Pos2, Rangef, Rect, Sense, Ui, UiBuilder, UiKind,
-    UiStackInfo, Vec2, Vec2b, emath, epaint, lerp, pass_state, pos2, remap, remap_clamp,
+    Context, CursorIcon, Id, KineticPan, NumExt as _, Pos2, Rangef, Rect, Sense, Ui, UiBuilder,
+    UiKind, UiStackInfo, Vec2, Vec2b, emath, epaint, lerp, pass_state, pos2, remap, remap_clamp,
 };
 
 #[derive(Clone, Copy, Debug)]
@@ -47,6 +47,14 @@ pub struct State {
 
     /// Area that can be dragged. This is the size of the content from the last frame.
     interact_rect: Option<Rect>,
+
+    /// The largest offset, as of last frame.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    max_offset: Option<Vec2>,
+
+    /// Were the contents last dragged with a finger (rather than a mouse)?
+    #[cfg_attr(feature = "serde", serde(skip))]
+    touch_drag: bool,
 }
 
 impl Default for State {
@@ -61,6 +69,8 @@ impl Default for State {
             scroll_start_offset_from_top_left: [None; 2],
             scroll_stuck_to_end: Vec2b::TRUE,
             interact_rect: None,
+            max_offset: None,
+            touch_drag: false,
         }
     }
 }
@@ -299,6 +309,10 @@ pub struct ScrollArea {
 
     /// If false, `scroll_to_*` functions will not be animated
     animated: bool,
+
+    kinetic_pan: KineticPan,
+
+    auto_scroll_on_drag: bool,
 }
 
 impl ScrollArea {
@@ -346,6 +360,8 @@ impl ScrollArea {
             wheel_scroll_multiplier: Vec2::splat(1.0),
             stick_to_end: Vec2b::FALSE,
             animated: true,
+            kinetic_pan: KineticPan::default(),
+            auto_scroll_on_drag: false,
         }
     }
 
@@ -586,6 +602,35 @@ impl ScrollArea {
         self
     }
 
+    /// How the contents move after being dragged and released,
+    /// and how far they can be pulled past the ends.
+    ///
+    /// Only used when scrolling by dragging the contents, see [`Self::scroll_source`].
+    /// The [`KineticPan::rubber_band`] is only used when dragging with a finger;
+    /// with a mouse the contents stop at the ends.
+    ///
+    /// Default: [`KineticPan::default`].
+    #[inline]
+    pub fn kinetic_pan(mut self, kinetic_pan: KineticPan) -> Self {
+        self.kinetic_pan = kinetic_pan;
+        self
+    }
+
+    /// Scroll towards the pointer when something that is dragged inside the scroll area,
+    /// e.g. a drag-selection of list items, is dragged past its edges.
+    ///
+    /// The further outside the pointer is, the faster it scrolls.
+    /// Widgets that already do this themselves, like [`crate::TextEdit`], don't need it.
+    ///
+    /// See also [`Ui::auto_scroll_towards_pointer`].
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn auto_scroll_on_drag(mut self, auto_scroll_on_drag: bool) -> Self {
+        self.auto_scroll_on_drag = auto_scroll_on_drag;
+        self
+    }
+
     /// Is any scrolling enabled?
     pub(crate) fn is_any_scroll_enabled(&self) -> bool {
         self.direction_enabled[0] || self.direction_enabled[1]
@@ -660,6 +705,13 @@ struct Prepared {
     saved_scroll_target: [Option<pass_state::ScrollTarget>; 2],
 
     animated: bool,
+
+    kinetic_pan: KineticPan,
+
+    /// May the offset be past the ends this frame, because the contents are dragged or flung?
+    elastic: Vec2b,
+
+    auto_scroll_on_drag: bool,
 }
 
 impl ScrollArea {
@@ -680,6 +732,8 @@ impl ScrollArea {
             wheel_scroll_multiplier,
             stick_to_end,
             animated,
+            mut kinetic_pan,
+            auto_scroll_on_drag,
         } = self;
 
         let ctx = ui.ctx().clone();
@@ -771,6 +825,7 @@ impl ScrollArea {
 
         let viewport = Rect::from_min_size(Pos2::ZERO + state.offset, inner_size);
         let dt = ui.input(|i| i.stable_dt).at_most(0.1);
+        let mut elastic = Vec2b::FALSE;
 
         if scroll_source.drag
             && ui.is_enabled()
@@ -783,17 +838,32 @@ impl ScrollArea {
                 .interact_rect
                 .map(|rect| ui.interact(rect, id.with("area"), Sense::drag()));
 
-            if content_response_option
+            let is_dragged = content_response_option
                 .as_ref()
-                .is_some_and(|response| response.dragged())
-            {
+                .is_some_and(|response| response.dragged());
+            if is_dragged {
+                state.touch_drag = ui.input(|i| i.any_touches());
+            }
+            if !state.touch_drag {
+                // Only touch screens are expected to let you pull the contents past the ends:
+                kinetic_pan.rubber_band = 0.0;
+            }
+
+            if is_dragged {
                 for d in 0..2 {
                     if direction_enabled[d] {
-                        ui.input(|input| {
-                            state.offset[d] -= input.pointer.delta()[d];
-                        });
+                        let delta = ui.input(|input| input.pointer.delta()[d]);
+                        state.offset[d] = match state.max_offset {
+                            Some(max_offset) => kinetic_pan.drag(
+                                state.offset[d],
+                                -delta,
+                                Rangef::new(0.0, max_offset[d]),
+                            ),
+                            None => state.offset[d] - delta,
+                        };
                         state.scroll_stuck_to_end[d] = false;
                         state.offset_target[d] = None;
+                        elastic[d] = true;
                     }
                 }
             } else {
@@ -805,20 +875,17 @@ impl ScrollArea {
                     state.vel =
                         direction_enabled.to_vec2() * ui.input(|input| input.pointer.velocity());
                 }
-                for d in 0..2 {
-                    // Kinetic scrolling
-                    let stop_speed = 20.0; // Pixels per second.
-                    let friction_coeff = 1000.0; // Pixels per second squared.
-
-                    let friction = friction_coeff * dt;
-                    if friction > state.vel[d].abs() || state.vel[d].abs() < stop_speed {
-                        state.vel[d] = 0.0;
-                    } else {
-                        state.vel[d] -= friction * state.vel[d].signum();
+                if let Some(max_offset) = state.max_offset {
+                    for d in 0..2 {
                         // Offset has an inverted coordinate system compared to
-                        // the velocity, so we subtract it instead of adding it
-                        state.offset[d] -= state.vel[d] * dt;
-                        ctx.request_repaint();
+                        // the velocity, so we flip it:
+                        let mut velocity = -state.vel[d];
+                        let bounds = Rangef::new(0.0, max_offset[d]);
+                        if kinetic_pan.step(&mut state.offset[d], &mut velocity, bounds, dt) {
+                            elastic[d] = true;
+                            ctx.request_repaint();
+                        }
+                        state.vel[d] = -velocity;
                     }
                 }
             }
@@ -889,6 +956,9 @@ impl ScrollArea {
             stick_to_end,
             saved_scroll_target,
             animated,
+            kinetic_pan,
+            elastic,
+            auto_scroll_on_drag,
         }
     }
 
@@ -1004,10 +1074,38 @@ impl Prepared {
             stick_to_end,
             saved_scroll_target,
             animated,
+            kinetic_pan,
+            elastic,
+            auto_scroll_on_drag,
         } = self;
 
         let content_size = content_ui.min_size();
 
+        if auto_scroll_on_drag {
+            let ctx = ui.ctx();
+            let drag_started_inside = ctx.input(|i| {
+                i.pointer.any_down()
+                    && i.pointer
+                        .press_origin()
+                        .is_some_and(|pos| inner_rect.contains(pos))
+            });
+            // Not when dragging the contents or the scroll bars, nor if a widget already scrolls:
+            let is_own_drag = |dragged_id| {
+                dragged_id == id.with("area")
+                    || dragged_id == id.with(0_usize)
+                    || dragged_id == id.with(1_usize)
+            };
+            let already_scrolling = ctx.pass_state(|state| state.scroll_delta.0 != Vec2::ZERO);
+            if drag_started_inside
+                && ctx
+                    .dragged_id()
+                    .is_some_and(|dragged_id| !is_own_drag(dragged_id))
+                && !already_scrolling
+            {
+                content_ui.auto_scroll_towards_pointer();
+            }
+        }
+
         let scroll_delta = content_ui
             .ctx()
             .pass_state_mut(|state| std::mem::take(&mut state.scroll_delta));
@@ -1331,8 +1429,13 @@ impl Prepared {
             }
 
             let unbounded_offset = state.offset[d];
-            state.offset[d] = state.offset[d].max(0.0);
-            state.offset[d] = state.offset[d].min(max_offset[d]);
+            let rubber_band = if elastic[d] {
+                kinetic_pan.rubber_band
+            } else {
+                0.0
+            };
+            state.offset[d] = state.offset[d].max(-rubber_band);
+            state.offset[d] = state.offset[d].min(max_offset[d] + rubber_band);
 
             if state.offset[d] != unbounded_offset {
                 state.vel[d] = 0.0;
@@ -1424,8 +1527,15 @@ impl Prepared {
         }
 
         let available_offset = content_size - inner_rect.size();
-        state.offset = state.offset.min(available_offset);
-        state.offset = state.offset.max(Vec2::ZERO);
+        for d in 0..2 {
+            let rubber_band = if elastic[d] {
+                kinetic_pan.rubber_band
+            } else {
+                0.0
+            };
+            state.offset[d] = state.offset[d].min(available_offset[d] + rubber_band);
+            state.offset[d] = state.offset[d].max(-rubber_band);
+        }
 
         // Is scroll handle at end of content, or is there no scrollbar
         // yet (not enough content), but sticking is requested? If so, enter sticky mode.
@@ -1442,9 +1552,164 @@ impl Prepared {
         state.show_scroll = show_scroll_this_frame;
         state.content_is_too_large = content_is_too_large;
         state.interact_rect = Some(inner_rect);
+        state.max_offset = Some(max_offset);
 
         state.store(ui.ctx(), id);
 
         (content_size, state)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        CentralPanel, Context, Event, Modifiers, PointerButton, Pos2, RawInput, Rect, ScrollArea,
+        Sense, TouchDeviceId, TouchId, TouchPhase, vec2,
+    };
+
+    #[test]
+    fn test_auto_scroll_on_drag() {
+        let ctx = Context::default();
+        let run = |time: f64, events: Vec<Event>| {
+            let mut result = (Rect::NOTHING, 0.0);
+            let _ = ctx.run(
+                RawInput {
+                    time: Some(time),
+                    events,
+                    ..Default::default()
+                },
+                |ctx| {
+                    CentralPanel::default().show(ctx, |ui| {
+                        let output = ScrollArea::vertical()
+                            .max_height(100.0)
+                            .auto_scroll_on_drag(true)
+                            .show(ui, |ui| {
+                                for _ in 0..100 {
+                                    ui.allocate_response(vec2(100.0, 20.0), Sense::drag());
+                                }
+                            });
+                        result = (output.inner_rect, output.state.offset.y);
+                    });
+                },
+            );
+            result
+        };
+        let button = |pos: Pos2, pressed| Event::PointerButton {
+            pos,
+            button: PointerButton::Primary,
+            pressed,
+            modifiers: Modifiers::NONE,
+        };
+
+        let (rect, _) = run(0.0, vec![]);
+        let start = rect.left_top() + vec2(10.0, 10.0);
+        run(0.05, vec![Event::PointerMoved(start)]);
+        run(0.1, vec![button(start, true)]);
+        let below = rect.left_bottom() + vec2(10.0, 50.0);
+        assert_eq!(run(0.2, vec![Event::PointerMoved(below)]).1, 0.0);
+
+        let mut offset = 0.0;
+        for i in 0..10 {
+            let new_offset = run(0.3 + 0.1 * i as f64, vec![]).1;
+            assert!(offset < new_offset, "Should keep scrolling down");
+            offset = new_offset;
+        }
+
+        let offset = run(1.5, vec![button(below, false)]).1;
+        assert_eq!(run(1.6, vec![]).1, offset, "Should stop when released");
+    }
+
+    #[test]
+    fn test_rubber_band_only_for_touch() {
+        let ctx = Context::default();
+        let run = |time: f64, events: Vec<Event>| {
+            let mut offset = 0.0;
+            let _ = ctx.run(
+                RawInput {
+                    time: Some(time),
+                    events,
+                    ..Default::default()
+                },
+                |ctx| {
+                    CentralPanel::default().show(ctx, |ui| {
+                        let output = ScrollArea::vertical()
+                            .id_salt("scroll_area")
+                            .max_height(100.0)
+                            .show(ui, |ui| {
+                                for _ in 0..100 {
+                                    ui.allocate_space(vec2(100.0, 20.0));
+                                }
+                            });
+                        offset = output.state.offset.y;
+                    });
+                },
+            );
+            offset
+        };
+        let pointer = |pos: Pos2, pressed: Option<bool>, touch: Option<TouchPhase>| {
+            let mut events = vec![];
+            if let Some(phase) = touch {
+                events.push(Event::Touch {
+                    device_id: TouchDeviceId(0),
+                    id: TouchId(0),
+                    phase,
+                    pos,
+                    force: None,
+                });
+            }
+            events.push(Event::PointerMoved(pos));
+            if let Some(pressed) = pressed {
+                events.push(Event::PointerButton {
+                    pos,
+                    button: PointerButton::Primary,
+                    pressed,
+                    modifiers: Modifiers::NONE,
+                });
+            }
+            events
+        };
+
+        // Drag from the top, returning how far past the top the contents went, and where they ended up:
+        let mut time = 0.0;
+        let mut drag = |touch: bool, dy: f32| {
+            let phase = |phase| touch.then_some(phase);
+            let start = Pos2::new(50.0, 30.0);
+            time += 1.0;
+            run(time, pointer(start, None, None));
+            time += 0.02;
+            run(time, pointer(start, Some(true), phase(TouchPhase::Start)));
+            let mut max_overscroll = 0.0_f32;
+            for i in 1..=5 {
+                time += 0.02;
+                let pos = start + vec2(0.0, 0.2 * dy * i as f32);
+                let offset = run(time, pointer(pos, None, phase(TouchPhase::Move)));
+                max_overscroll = max_overscroll.max(-offset);
+            }
+            let end = start + vec2(0.0, dy);
+            time += 0.02;
+            run(time, pointer(end, Some(false), phase(TouchPhase::End)));
+            for _ in 0..100 {
+                time += 0.02;
+                run(time, vec![]);
+            }
+            (max_overscroll, run(time + 0.02, vec![]))
+        };
+
+        run(0.0, vec![]);
+        assert_eq!(
+            drag(false, 50.0),
+            (0.0, 0.0),
+            "A mouse should stop hard at the top"
+        );
+
+        let (max_overscroll, offset) = drag(true, 50.0);
+        assert!(
+            0.0 < max_overscroll,
+            "A finger should pull the contents past the top"
+        );
+        assert_eq!(offset, 0.0, "…and they should spring back");
+
+        let (_, offset) = drag(false, -50.0);
+        assert!(50.0 <= offset, "Dragging with a mouse should still scroll");
+    }
+}
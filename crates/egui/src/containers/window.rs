@@ -2,7 +2,7 @@
 
 use std::sync::Arc;
 
-use emath::GuiRounding as _;
+use emath::{GuiRounding as _, TSTransform};
 use epaint::{CornerRadiusF32, RectShape};
 
 use crate::collapsing_header::CollapsingState;
@@ -30,6 +30,9 @@ use super::{Area, Frame, Resize, ScrollArea, area, resize};
 ///
 /// The previous rectangle used by this window can be obtained through [`crate::Memory::area_rect()`].
 ///
+/// When the title bar has keyboard focus, the arrow keys move the window,
+/// and alt + arrow keys resize it (hold shift for smaller steps).
+///
 /// Note that this is NOT a native OS window.
 /// To create a new native OS window, use [`crate::Context::show_viewport_deferred`].
 #[must_use = "You should call .show()"]
@@ -43,7 +46,11 @@ pub struct Window<'open> {
     collapsible: bool,
     default_open: bool,
     with_title_bar: bool,
+    fade_in: bool,
     fade_out: bool,
+    fade_scale: f32,
+    opacity: f32,
+    cull_when_covered: bool,
 }
 
 impl<'open> Window<'open> {
@@ -66,7 +73,11 @@ impl<'open> Window<'open> {
             collapsible: true,
             default_open: true,
             with_title_bar: true,
+            fade_in: true,
             fade_out: true,
+            fade_scale: 1.0,
+            opacity: 1.0,
+            cull_when_covered: false,
         }
     }
 
@@ -125,7 +136,7 @@ impl<'open> Window<'open> {
     /// Default: `true`.
     #[inline]
     pub fn fade_in(mut self, fade_in: bool) -> Self {
-        self.area = self.area.fade_in(fade_in);
+        self.fade_in = fade_in;
         self
     }
 
@@ -140,6 +151,48 @@ impl<'open> Window<'open> {
         self
     }
 
+    /// While fading in or out, also scale the window (around its center) from this size.
+    ///
+    /// For instance, `0.9` makes the window grow a little as it appears,
+    /// and shrink as it closes. This is ignored if [`crate::Style::reduce_motion`] is set.
+    ///
+    /// Only the graphics are scaled (see [`Context::transform_layer_shapes`]),
+    /// so any transform you set on the layer of the window is kept.
+    ///
+    /// Default: `1.0` (no scaling).
+    #[inline]
+    pub fn fade_scale(mut self, fade_scale: f32) -> Self {
+        self.fade_scale = fade_scale;
+        self
+    }
+
+    /// Make the whole window, including its frame, semi-transparent.
+    ///
+    /// This is multiplied with the fade in/out animations.
+    /// See also [`Context::multiply_layer_opacity`].
+    ///
+    /// Default: `1.0` (opaque).
+    #[inline]
+    pub fn opacity(mut self, opacity: f32) -> Self {
+        self.opacity = opacity;
+        self
+    }
+
+    /// If `true`, the contents of the window are not shown while it is completely covered
+    /// by opaque windows above it.
+    ///
+    /// The window keeps its size and state, and its contents are shown again as soon as it is uncovered.
+    /// This saves a lot of work for apps with many stacked windows.
+    ///
+    /// Only turn this on for windows whose contents don't need to run every frame while hidden.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn cull_when_covered(mut self, cull_when_covered: bool) -> Self {
+        self.cull_when_covered = cull_when_covered;
+        self
+    }
+
     /// Usage: `Window::new(…).mutate(|w| w.resize = w.resize.auto_expand_width(true))`
     // TODO(emilk): I'm not sure this is a good interface for this.
     #[inline]
@@ -437,8 +490,13 @@ impl Window<'_> {
             collapsible,
             default_open,
             with_title_bar,
+            fade_in,
             fade_out,
+            fade_scale,
+            opacity: window_opacity,
+            cull_when_covered,
         } = self;
+        let area = area.fade_in(false); // We fade the whole layer instead
 
         let header_color =
             frame.map_or_else(|| ctx.style().visuals.widgets.open.weak_bg_fill, |f| f.fill);
@@ -468,8 +526,38 @@ impl Window<'_> {
         let mut resize = resize.id(resize_id);
 
         let on_top = Some(area_layer_id) == ctx.top_layer_id();
+        let is_movable = area.is_enabled() && area.is_movable();
         let mut area = area.begin(ctx);
 
+        let fade = if !is_open {
+            if fade_out { opacity } else { 1.0 }
+        } else if fade_in {
+            emath::easing::quadratic_out(area.fade_in_progress(ctx)) // slow fade-out = quick fade-in
+        } else {
+            1.0
+        };
+        ctx.multiply_layer_opacity(area_layer_id, window_opacity * fade);
+
+        // Only the graphics are scaled, so that we don't touch any transform of the layer itself:
+        let fade_transform = {
+            let scale = if ctx.style().reduce_motion {
+                1.0
+            } else {
+                emath::lerp(fade_scale..=1.0, fade)
+            };
+            let center = area.state().rect().center().to_vec2();
+            (scale != 1.0 && center.is_finite()).then(|| {
+                TSTransform::from_translation(center)
+                    * TSTransform::from_scaling(scale)
+                    * TSTransform::from_translation(-center)
+            })
+        };
+        let scale_graphics = || {
+            if let Some(transform) = fade_transform {
+                ctx.transform_layer_shapes(area_layer_id, transform);
+            }
+        };
+
         area.with_widget_info(|| WidgetInfo::labeled(WidgetType::Window, true, title.text()));
 
         // Calculate roughly how much larger the full window inner size is compared to the content rect
@@ -515,10 +603,9 @@ impl Window<'_> {
             )
         });
 
+        let margins = window_frame.total_margin().sum()
+            + vec2(0.0, title_bar_height_with_margin + title_content_spacing);
         {
-            let margins = window_frame.total_margin().sum()
-                + vec2(0.0, title_bar_height_with_margin + title_content_spacing);
-
             resize_response(
                 resize_interaction,
                 ctx,
@@ -530,14 +617,45 @@ impl Window<'_> {
         }
 
         let mut area_content_ui = area.content_ui(ctx);
-        if is_open {
-            // `Area` already takes care of fade-in animations,
-            // so we only need to handle fade-out animations here.
-        } else if fade_out {
-            area_content_ui.multiply_opacity(opacity);
+
+        // Coverage is only tracked in global coordinates, so leave transformed layers alone:
+        let is_transformed = ctx.layer_transform_to_global(area_layer_id).is_some();
+        let is_opaque = window_frame.fill.is_opaque()
+            && window_opacity * fade >= 1.0
+            && area_content_ui.is_visible()
+            && area_content_ui.opacity() >= 1.0
+            && !is_transformed;
+        // Only the inside of the rounded corners is opaque:
+        let corner_radius = {
+            let cr = window_frame.corner_radius;
+            f32::from(cr.nw.max(cr.ne).max(cr.sw).max(cr.se))
+        };
+        let opaque_rect = |outer_rect: Rect| outer_rect.shrink(corner_radius);
+
+        if cull_when_covered
+            && !is_transformed
+            && area_content_ui.is_visible()
+            && !ctx.memory(|mem| mem.everything_is_visible())
+            && ctx.memory(|mem| mem.areas().is_covered(area_layer_id, last_frame_outer_rect))
+        {
+            // Keep the window as it is, but skip its contents:
+            area_content_ui.allocate_space(last_frame_outer_rect.size());
+            ctx.memory_mut(|mem| {
+                let areas = mem.areas_mut();
+                areas.set_covered(area_layer_id, last_frame_outer_rect);
+                if is_opaque {
+                    areas.set_opaque_rect(area_layer_id, opaque_rect(last_frame_outer_rect));
+                }
+            });
+            let response = area.end(ctx, area_content_ui);
+            scale_graphics();
+            return Some(InnerResponse {
+                inner: None,
+                response,
+            });
         }
 
-        let content_inner = {
+        let (content_inner, title_bar_response) = {
             ctx.with_accessibility_parent(area.id(), || {
                 // BEGIN FRAME --------------------------------
                 let mut frame = window_frame.begin(&mut area_content_ui);
@@ -578,11 +696,13 @@ impl Window<'_> {
                 let (content_inner, content_response) = collapsing
                     .show_body_unindented(&mut frame.content_ui, |ui| {
                         resize.show(ui, |ui| {
-                            if scroll.is_any_scroll_enabled() {
-                                scroll.show(ui, add_contents).inner
-                            } else {
-                                add_contents(ui)
-                            }
+                            ctx.with_frame_cost_scope(area_id, UiKind::Window, || {
+                                if scroll.is_any_scroll_enabled() {
+                                    scroll.show(ui, add_contents).inner
+                                } else {
+                                    add_contents(ui)
+                                }
+                            })
                         })
                     })
                     .map_or((None, None), |ir| (Some(ir.inner), Some(ir.response)));
@@ -598,6 +718,7 @@ impl Window<'_> {
 
                 // END FRAME --------------------------------
 
+                let mut title_bar_response = None;
                 if let Some(mut title_bar) = title_bar {
                     title_bar.inner_rect = outer_rect.shrink(window_frame.stroke.width);
                     title_bar.inner_rect.max.y =
@@ -626,24 +747,46 @@ impl Window<'_> {
                         );
                     }
 
-                    title_bar.ui(
+                    let title_galley = title_bar.title_galley.clone();
+                    let response = title_bar.ui(
                         &mut area_content_ui,
                         &content_response,
                         open.as_deref_mut(),
                         &mut collapsing,
                         collapsible,
                     );
+                    title_bar_response = Some((response, title_galley));
                 }
 
                 collapsing.store(ctx);
 
                 paint_frame_interaction(&area_content_ui, outer_rect, resize_interaction);
 
-                content_inner
+                if is_opaque {
+                    ctx.memory_mut(|mem| {
+                        mem.areas_mut()
+                            .set_opaque_rect(area_layer_id, opaque_rect(outer_rect));
+                    });
+                }
+
+                (content_inner, title_bar_response)
             })
         };
 
+        if let Some((title_bar_response, title_galley)) = &title_bar_response {
+            keyboard_move_and_resize(
+                ctx,
+                title_bar_response,
+                title_galley.text(),
+                &mut area,
+                resize_id,
+                is_movable,
+                possible,
+            );
+        }
+
         let full_response = area.end(ctx, area_content_ui);
+        scale_graphics();
 
         if full_response.should_close() {
             if let Some(open) = open {
@@ -849,6 +992,96 @@ fn resize_response(
     ctx.memory_mut(|mem| mem.areas_mut().move_to_top(area_layer_id));
 }
 
+/// While the title bar is focused, the arrow keys move the window,
+/// and holding alt resizes it instead.
+///
+/// Holding shift moves in smaller steps.
+fn keyboard_move_and_resize(
+    ctx: &Context,
+    title_bar_response: &Response,
+    title: &str,
+    area: &mut area::Prepared,
+    resize_id: Id,
+    is_movable: bool,
+    possible: PossibleInteractions,
+) {
+    if !title_bar_response.has_focus() {
+        return;
+    }
+    ctx.memory_mut(|mem| {
+        mem.set_focus_lock_filter(
+            title_bar_response.id,
+            EventFilter {
+                horizontal_arrows: true,
+                vertical_arrows: true,
+                ..Default::default()
+            },
+        );
+    });
+
+    let (delta, resize) = ctx.input(|i| {
+        let mut delta = Vec2::ZERO;
+        for (key, dir) in [
+            (Key::ArrowLeft, Vec2::LEFT),
+            (Key::ArrowRight, Vec2::RIGHT),
+            (Key::ArrowUp, Vec2::UP),
+            (Key::ArrowDown, Vec2::DOWN),
+        ] {
+            delta += i.num_presses(key) as f32 * dir;
+        }
+        let step = if i.modifiers.shift { 1.0 } else { 10.0 };
+        (step * delta, i.modifiers.alt)
+    });
+    if delta == Vec2::ZERO {
+        return;
+    }
+
+    let announcement = if resize {
+        let delta = vec2(
+            if possible.resize_left || possible.resize_right {
+                delta.x
+            } else {
+                0.0
+            },
+            if possible.resize_top || possible.resize_bottom {
+                delta.y
+            } else {
+                0.0
+            },
+        );
+        let Some(mut state) = resize::State::load(ctx, resize_id) else {
+            return;
+        };
+        if delta == Vec2::ZERO {
+            return;
+        }
+        let size = (state.desired_size + delta).max(Vec2::ZERO);
+        state.requested_size = Some(size);
+        state.store(ctx, resize_id);
+        format!("Resized to {:.0} by {:.0}", size.x, size.y)
+    } else if is_movable {
+        let mut rect = area.state().rect().translate(delta);
+        if area.constrain() {
+            rect = Context::constrain_window_rect_to_area(rect, area.constrain_rect());
+        }
+        area.state_mut().set_left_top_pos(rect.left_top());
+        format!("Moved to {:.0}, {:.0}", rect.left(), rect.top())
+    } else {
+        return;
+    };
+
+    // Announce the new position or size to screen readers:
+    let mut info = WidgetInfo::labeled(WidgetType::Other, true, title);
+    info.current_text_value = Some(announcement);
+    title_bar_response.output_event(output::OutputEvent::ValueChanged(info));
+    #[cfg(feature = "accesskit")]
+    ctx.accesskit_node_builder(title_bar_response.id, |builder| {
+        builder.set_live(accesskit::Live::Polite);
+    });
+
+    ctx.request_repaint();
+}
+
 /// Acts on outer rect (outside the stroke)
 fn move_and_resize_window(ctx: &Context, interaction: &ResizeInteraction) -> Option<Rect> {
     if !interaction.any_dragged() {
@@ -1193,7 +1426,7 @@ impl TitleBar {
         open: Option<&mut bool>,
         collapsing: &mut CollapsingState,
         collapsible: bool,
-    ) {
+    ) -> Response {
         let window_frame = self.window_frame;
         let title_inner_rect = self.inner_rect;
 
@@ -1261,14 +1494,26 @@ impl TitleBar {
         }
 
         let id = ui.unique_id().with("__window_title_bar");
+        let response = ui.interact(double_click_rect, id, Sense::click());
+        response.widget_info(|| {
+            WidgetInfo::labeled(WidgetType::Other, ui.is_enabled(), self.title_galley.text())
+        });
 
-        if ui
-            .interact(double_click_rect, id, Sense::click())
-            .double_clicked()
-            && collapsible
-        {
+        if response.double_clicked() && collapsible {
             collapsing.toggle(ui);
         }
+
+        if response.has_focus() {
+            // The title bar can be moved and resized with the keyboard, see `keyboard_move_and_resize`:
+            ui.painter().rect_stroke(
+                title_inner_rect.shrink(1.0),
+                window_frame.corner_radius,
+                ui.visuals().selection.stroke,
+                epaint::StrokeKind::Inside,
+            );
+        }
+
+        response
     }
 
     /// Paints the "Close" button at the right side of the title bar
@@ -1314,3 +1559,184 @@ fn close_button(ui: &mut Ui, rect: Rect) -> Response {
         .line_segment([rect.right_top(), rect.left_bottom()], stroke);
     response
 }
+
+#[test]
+fn test_keyboard_move_and_resize() {
+    let ctx = Context::default();
+    let key = |key, modifiers| Event::Key {
+        key,
+        physical_key: None,
+        pressed: true,
+        repeat: false,
+        modifiers,
+    };
+    let run = |events: Vec<Event>| {
+        let modifiers = events.iter().fold(Modifiers::NONE, |m, e| match e {
+            Event::Key { modifiers, .. } => m | *modifiers,
+            _ => m,
+        });
+        let input = RawInput {
+            screen_rect: Some(Rect::from_min_size(Pos2::ZERO, vec2(800.0, 600.0))),
+            modifiers,
+            events,
+            ..Default::default()
+        };
+        let output = ctx.run(input, |ctx| {
+            Window::new("Window")
+                .collapsible(false)
+                .resizable(true)
+                .default_pos(pos2(100.0, 100.0))
+                .show(ctx, |ui| {
+                    // Fill the window, like e.g. a scroll area would, so it can be resized:
+                    ui.allocate_space(ui.available_size());
+                });
+        });
+        let rect = ctx.memory(|m| m.area_rect(Id::new("Window"))).unwrap();
+        (rect, output.platform_output.events)
+    };
+
+    let (start, _) = run(vec![]);
+
+    // Tab to the title bar:
+    let title_bar_focused = |events: &[output::OutputEvent]| {
+        events.iter().any(|event| {
+            matches!(event, output::OutputEvent::FocusGained(info)
+                if info.typ == WidgetType::Other && info.label.as_deref() == Some("Window"))
+        })
+    };
+    assert!(
+        (0..10).any(|_| title_bar_focused(&run(vec![key(Key::Tab, Modifiers::NONE)]).1)),
+        "Title bar should be reachable with tab"
+    );
+    run(vec![]);
+
+    let (_, events) = run(vec![key(Key::ArrowRight, Modifiers::NONE)]);
+    assert!(events.iter().any(|event| {
+        event.widget_info().current_text_value.as_deref() == Some("Moved to 110, 100")
+    }));
+    let (moved, _) = run(vec![]);
+
+    assert_eq!(moved.min, start.min + vec2(10.0, 0.0));
+
+    run(vec![key(Key::ArrowDown, Modifiers::ALT)]);
+    let (resized, _) = run(vec![]);
+    assert_eq!(resized.height(), start.height() + 10.0);
+}
+
+#[test]
+fn test_opacity_and_fade_scale() {
+    let ctx = Context::default();
+    let layer_id = LayerId::new(Order::Middle, Id::new("Window"));
+    let user_transform = TSTransform::new(vec2(10.0, 20.0), 2.0);
+    ctx.set_transform_layer(layer_id, user_transform);
+
+    let run = |time: f64, open: bool| {
+        let input = RawInput {
+            screen_rect: Some(Rect::from_min_size(Pos2::ZERO, vec2(800.0, 600.0))),
+            time: Some(time),
+            ..Default::default()
+        };
+        let mut open = open;
+        let output = ctx.run(input, |ctx| {
+            Window::new("Window")
+                .open(&mut open)
+                .opacity(0.5)
+                .fade_scale(0.5)
+                .show(ctx, |ui| ui.label("Hello"));
+        });
+        let mut rects = vec![];
+        let mut add = |shape: &Shape| {
+            if let Shape::Rect(rect) = shape {
+                rects.push(rect.clone());
+            }
+        };
+        for clipped in &output.shapes {
+            match &clipped.shape {
+                Shape::Vec(shapes) => shapes.iter().for_each(&mut add),
+                shape => add(shape),
+            }
+        }
+        rects
+    };
+    let width = |rects: &[epaint::RectShape]| {
+        rects
+            .iter()
+            .map(|rect| rect.rect.width())
+            .max_by(f32::total_cmp)
+    };
+
+    run(0.0, true);
+    let growing = width(&run(0.01, true)).unwrap();
+    let shown = run(10.0, true);
+    assert!(
+        growing < width(&shown).unwrap(),
+        "The window should grow as it fades in"
+    );
+    let window_fill = ctx.style().visuals.window_fill;
+    assert!(
+        shown
+            .iter()
+            .any(|rect| rect.fill == window_fill.gamma_multiply(0.5))
+    );
+    assert_eq!(
+        ctx.layer_transform_to_global(layer_id),
+        Some(user_transform)
+    );
+
+    let shrinking = width(&run(10.01, false)).unwrap();
+    assert!(
+        shrinking < width(&shown).unwrap(),
+        "The window should shrink as it fades out"
+    );
+    assert!(run(20.0, false).is_empty());
+    assert_eq!(
+        ctx.layer_transform_to_global(layer_id),
+        Some(user_transform),
+        "The transform of the layer should be kept"
+    );
+}
+
+#[test]
+fn test_cull_when_covered() {
+    let ctx = Context::default();
+    let time = std::cell::Cell::new(0.0);
+    let run = |cull_when_covered: bool, cover: bool| {
+        time.set(time.get() + 1.0); // Let the windows fade in
+        let input = RawInput {
+            screen_rect: Some(Rect::from_min_size(Pos2::ZERO, vec2(800.0, 600.0))),
+            time: Some(time.get()),
+            ..Default::default()
+        };
+        let mut shown = false;
+        let _ = ctx.run(input, |ctx| {
+            Window::new("Below")
+                .fixed_pos(pos2(100.0, 100.0))
+                .cull_when_covered(cull_when_covered)
+                .show(ctx, |ui| {
+                    shown = true;
+                    ui.label("Hidden");
+                });
+            if cover {
+                Window::new("Above")
+                    .fixed_pos(pos2(0.0, 0.0))
+                    .show(ctx, |ui| ui.set_min_size(vec2(600.0, 400.0)));
+            }
+        });
+        shown
+    };
+
+    run(true, true);
+    run(true, true);
+    let rect = ctx.memory(|mem| mem.area_rect(Id::new("Below")));
+    assert!(!run(true, true), "Covered windows skip their contents");
+    assert_eq!(
+        ctx.memory(|mem| mem.area_rect(Id::new("Below"))),
+        rect,
+        "Covered windows keep their size"
+    );
+    assert!(run(false, true));
+    assert!(
+        run(true, false),
+        "Uncovered windows are shown in the same frame"
+    );
+}
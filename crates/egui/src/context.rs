@@ -4,10 +4,10 @@ use std::{borrow::Cow, cell::RefCell, panic::Location, sync::Arc, time::Duration
 
 use emath::{GuiRounding as _, OrderedFloat};
 use epaint::{
-    ClippedPrimitive, ClippedShape, Color32, ImageData, ImageDelta, Pos2, Rect, StrokeKind,
-    TessellationOptions, TextureAtlas, TextureId, Vec2,
+    BlendMode, ClippedPrimitive, ClippedShape, Color32, ColorImage, ImageData, ImageDelta, Pos2,
+    Rect, StrokeKind, TessellationOptions, TextureAtlas, TextureId, Vec2,
     emath::{self, TSTransform},
-    mutex::RwLock,
+    mutex::{Mutex, RwLock},
     stats::PaintStats,
     tessellator,
     text::{FontInsert, FontPriority, Fonts},
@@ -15,16 +15,18 @@ use epaint::{
 };
 
 use crate::{
-    Align2, CursorIcon, DeferredViewportUiCallback, FontDefinitions, Grid, Id, ImmediateViewport,
-    ImmediateViewportRendererCallback, Key, KeyboardShortcut, Label, LayerId, Memory,
-    ModifierNames, Modifiers, NumExt as _, Order, Painter, RawInput, Response, RichText,
-    ScrollArea, Sense, Style, TextStyle, TextureHandle, TextureOptions, Ui, ViewportBuilder,
-    ViewportCommand, ViewportId, ViewportIdMap, ViewportIdPair, ViewportIdSet, ViewportOutput,
-    Widget as _, WidgetRect, WidgetText,
+    Align2, CursorIcon, DeferredViewportUiCallback, FontDefinitions, FrameCost, Grid, Id,
+    ImmediateViewport, ImmediateViewportRendererCallback, Key, KeyboardShortcut, Label, LayerId,
+    Memory, ModifierNames, Modifiers, NumExt as _, Order, Painter, RawInput, Response, RichText,
+    ScopeCost, ScrollArea, Sense, Style, TextStyle, TextureHandle, TextureOptions, Ui, UiKind,
+    UiSound, ViewportBuilder, ViewportCommand, ViewportId, ViewportIdMap, ViewportIdPair,
+    ViewportIdSet, ViewportOutput, Widget as _, WidgetRect, WidgetText,
     animation_manager::AnimationManager,
     containers::{self, area::AreaState},
-    data::output::PlatformOutput,
-    epaint, hit_test,
+    data::output::{HapticFeedback, PlatformOutput},
+    epaint,
+    frame_cost::FrameCostTracker,
+    hit_test,
     input_state::{InputState, MultiTouchInfo, PointerEvent},
     interaction,
     layers::GraphicLayers,
@@ -96,6 +98,9 @@ impl Default for WrappedTextureManager {
 /// Generic event callback.
 pub type ContextCallback = Arc<dyn Fn(&Context) + Send + Sync>;
 
+/// See [`Context::enqueue`].
+type QueuedCommand = Box<dyn FnOnce(&Context) + Send>;
+
 #[derive(Clone)]
 struct NamedContextCallback {
     debug_name: &'static str,
@@ -262,6 +267,14 @@ pub struct ViewportState {
     /// State related to repaint scheduling.
     repaint: ViewportRepaintInfo,
 
+    /// Scroll this widget into view when it is next created.
+    ///
+    /// See [`Context::scroll_to_widget`].
+    scroll_to_widget: Option<(Id, Option<crate::Align>)>,
+
+    /// The popup that was open at the end of the last pass, for [`UiSound::PopupClose`].
+    open_popup: Option<Id>,
+
     // ----------------------
     // Updated at the start of the pass:
     //
@@ -284,6 +297,9 @@ pub struct ViewportState {
     // ----------------------
     // Cross-frame statistics:
     pub num_multipass_in_row: usize,
+
+    /// See [`Context::frame_cost`].
+    frame_cost: FrameCostTracker,
 }
 
 /// What called [`Context::request_repaint`] or [`Context::request_discard`]?
@@ -404,6 +420,16 @@ impl ViewportRepaintInfo {
 
 // ----------------------------------------------------------------------------
 
+#[derive(Clone, Copy)]
+struct ZoomAnimation {
+    from: f32,
+    to: f32,
+
+    /// In seconds.
+    duration: f32,
+    elapsed: f32,
+}
+
 #[derive(Default)]
 struct ContextImpl {
     /// Since we could have multiple viewports across multiple monitors with
@@ -430,6 +456,9 @@ struct ContextImpl {
     /// Set during the pass, becomes active at the start of the next pass.
     new_zoom_factor: Option<f32>,
 
+    /// Set by [`Context::animate_zoom_factor`].
+    zoom_animation: Option<ZoomAnimation>,
+
     os: OperatingSystem,
 
     /// How deeply nested are we?
@@ -442,6 +471,16 @@ struct ContextImpl {
 
     request_repaint_callback: Option<Box<dyn Fn(RequestRepaintInfo) + Send + Sync>>,
 
+    sound_callback: Option<Arc<dyn Fn(UiSound) + Send + Sync>>,
+
+    /// Sounds to report at the end of the pass, when the context is no longer locked.
+    sounds: Vec<UiSound>,
+
+    /// Added with [`Context::enqueue`], run at the start of the next pass.
+    ///
+    /// In a [`Mutex`] so that the commands only need to be `Send`.
+    queued_commands: Mutex<Vec<QueuedCommand>>,
+
     viewport_parents: ViewportIdMap<ViewportId>,
     viewports: ViewportIdMap<ViewportState>,
 
@@ -468,6 +507,37 @@ impl ContextImpl {
 
         self.begin_pass_repaint_logic(viewport_id);
 
+        let frame_budget = self.memory.options.frame_budget;
+        let degrade_over_budget = self.memory.options.degrade_over_budget;
+        self.viewports
+            .entry(viewport_id)
+            .or_default()
+            .frame_cost
+            .begin_pass(frame_budget, new_raw_input.frame_cost, degrade_over_budget);
+
+        if is_outermost_viewport && viewport_id == ViewportId::ROOT {
+            if let Some(animation) = &mut self.zoom_animation {
+                animation.elapsed += new_raw_input.predicted_dt;
+                let t = if 0.0 < animation.duration {
+                    (animation.elapsed / animation.duration).min(1.0)
+                } else {
+                    1.0
+                };
+                let zoom_factor = emath::lerp(
+                    animation.from..=animation.to,
+                    emath::easing::cubic_in_out(t),
+                );
+                if t < 1.0 {
+                    self.request_repaint(viewport_id, RepaintCause::new());
+                } else {
+                    self.zoom_animation = None;
+                }
+                if zoom_factor != self.memory.options.zoom_factor {
+                    self.new_zoom_factor = Some(zoom_factor);
+                }
+            }
+        }
+
         let viewport = self.viewports.entry(viewport_id).or_default();
 
         if is_outermost_viewport {
@@ -475,14 +545,23 @@ impl ContextImpl {
                 let ratio = self.memory.options.zoom_factor / new_zoom_factor;
                 self.memory.options.zoom_factor = new_zoom_factor;
 
-                let input = &viewport.input;
-                // This is a bit hacky, but is required to avoid jitter:
-                let mut rect = input.screen_rect;
-                rect.min = (ratio * rect.min.to_vec2()).to_pos2();
-                rect.max = (ratio * rect.max.to_vec2()).to_pos2();
-                new_raw_input.screen_rect = Some(rect);
-                // We should really scale everything else in the input too,
-                // but the `screen_rect` is the most important part.
+                // The integration converted the input to points using the old zoom factor,
+                // so we convert it to the new one to avoid jitter:
+                let scale = |pos: Pos2| (ratio * pos.to_vec2()).to_pos2();
+                let input = &mut viewport.input;
+                let rect = input.screen_rect;
+                new_raw_input.screen_rect =
+                    Some(Rect::from_min_max(scale(rect.min), scale(rect.max)));
+                input.pointer.rescale(ratio);
+                for event in &mut new_raw_input.events {
+                    match event {
+                        crate::Event::PointerMoved(pos)
+                        | crate::Event::PointerButton { pos, .. }
+                        | crate::Event::Touch { pos, .. } => *pos = scale(*pos),
+                        crate::Event::MouseMoved(delta) => *delta *= ratio,
+                        _ => {}
+                    }
+                }
             }
         }
         let native_pixels_per_point = new_raw_input
@@ -527,6 +606,7 @@ impl ContextImpl {
                 WidgetHits::default()
             };
 
+            let prev_hovered = std::mem::take(&mut viewport.interact_widgets.hovered);
             viewport.interact_widgets = crate::interaction::interact(
                 &viewport.interact_widgets,
                 &viewport.prev_pass.widgets,
@@ -534,6 +614,33 @@ impl ContextImpl {
                 &viewport.input,
                 self.memory.interaction_mut(),
             );
+
+            let interaction = &viewport.interact_widgets;
+            for (id, feedback) in [
+                (interaction.drag_started, HapticFeedback::Light),
+                (interaction.long_pressed, HapticFeedback::Medium),
+            ] {
+                if id.is_some() {
+                    viewport
+                        .output
+                        .commands
+                        .push(crate::OutputCommand::Haptic(feedback));
+                }
+            }
+
+            if self.sound_callback.is_some() {
+                let started_hovering = viewport.interact_widgets.hovered.iter().any(|id| {
+                    !prev_hovered.contains(id)
+                        && viewport
+                            .prev_pass
+                            .widgets
+                            .get(*id)
+                            .is_some_and(|w| w.enabled && w.sense.senses_click())
+                });
+                if started_hovering {
+                    self.sounds.push(UiSound::Hover);
+                }
+            }
         }
 
         // Ensure we register the background area so panels and background ui can catch clicks:
@@ -777,6 +884,7 @@ impl Default for Context {
 
         // Register built-in plugins:
         crate::debug_text::register(&ctx);
+        crate::inspector::register(&ctx);
         crate::text_selection::LabelSelectionState::register(&ctx);
         crate::DragAndDrop::register(&ctx);
 
@@ -913,6 +1021,11 @@ impl Context {
 
         self.write(|ctx| ctx.begin_pass(new_input));
 
+        let queued_commands = self.write(|ctx| std::mem::take(&mut *ctx.queued_commands.lock()));
+        for command in queued_commands {
+            command(self);
+        }
+
         // Plugins run just after the pass starts:
         self.read(|ctx| ctx.plugins.clone()).on_begin_pass(self);
     }
@@ -1195,6 +1308,25 @@ impl Context {
             // but also to know when we have reached the widget we are checking for cover.
             viewport.this_pass.widgets.insert(w.layer_id, w);
 
+            if let Some((id, align)) = viewport.scroll_to_widget {
+                if id == w.id && w.rect.is_positive() && w.rect.is_finite() {
+                    viewport.scroll_to_widget = None;
+                    let animation = ctx.memory.options.style().scroll_animation;
+                    ctx.viewport().this_pass.scroll_target = [
+                        Some(crate::pass_state::ScrollTarget::new(
+                            w.rect.x_range(),
+                            align,
+                            animation,
+                        )),
+                        Some(crate::pass_state::ScrollTarget::new(
+                            w.rect.y_range(),
+                            align,
+                            animation,
+                        )),
+                    ];
+                }
+            }
+
             if allow_focus && interested_in_focus {
                 ctx.memory.interested_in_focus(w.id, w.layer_id);
             }
@@ -1209,6 +1341,9 @@ impl Context {
             self.check_for_id_clash(w.id, w.rect, "widget");
         }
 
+        #[cfg(debug_assertions)]
+        crate::inspector::on_create_widget(self, &w);
+
         #[allow(clippy::let_and_return, clippy::allow_attributes)]
         let res = self.get_response(w);
 
@@ -1298,6 +1433,17 @@ impl Context {
         .map(|widget_rect| self.get_response(widget_rect))
     }
 
+    /// Scroll the widget with the given [`Id`] into view, next time it is shown.
+    ///
+    /// Unlike [`Response::scroll_to_me`], this can be called from anywhere,
+    /// e.g. before the widget is created, or from outside the [`crate::ScrollArea`] it is in.
+    ///
+    /// See also [`Self::read_response`].
+    pub fn scroll_to_widget(&self, id: Id, align: Option<crate::Align>) {
+        self.write(|ctx| ctx.viewport().scroll_to_widget = Some((id, align)));
+        self.request_repaint();
+    }
+
     /// Do all interaction for an existing widget, without (re-)registering it.
     pub(crate) fn get_response(&self, widget_rect: WidgetRect) -> Response {
         use response::Flags;
@@ -1362,6 +1508,11 @@ impl Context {
                 res.flags.set(Flags::LONG_TOUCHED, true);
             }
 
+            if enabled && sense.senses_click() && Some(id) == viewport.interact_widgets.long_pressed
+            {
+                res.flags.set(Flags::LONG_PRESSED, true);
+            }
+
             let interaction = memory.interaction();
 
             res.flags.set(
@@ -1412,7 +1563,7 @@ impl Context {
             // is_pointer_button_down_on is false when released, but we want interact_pointer_pos
             // to still work.
             let is_interacted_with = res.is_pointer_button_down_on()
-                || res.long_touched()
+                || res.long_pressed()
                 || clicked
                 || res.drag_stopped();
             if is_interacted_with {
@@ -1528,6 +1679,18 @@ impl Context {
         self.output_mut(|o| o.commands.push(cmd));
     }
 
+    /// Ask the integration to give the user physical feedback, e.g. a short vibration.
+    ///
+    /// Equivalent to:
+    /// ```
+    /// # let ctx = egui::Context::default();
+    /// # let feedback = egui::HapticFeedback::Light;
+    /// ctx.send_cmd(egui::OutputCommand::Haptic(feedback));
+    /// ```
+    pub fn haptic_feedback(&self, feedback: HapticFeedback) {
+        self.send_cmd(crate::OutputCommand::Haptic(feedback));
+    }
+
     /// Open an URL in a browser.
     ///
     /// Equivalent to:
@@ -1780,6 +1943,34 @@ impl Context {
         self.write(|ctx| ctx.request_repaint_after(duration, id, cause));
     }
 
+    /// Run `command` at the start of the next pass, and make sure there is one soon.
+    ///
+    /// This is a safe way for background threads and async tasks to change the state of the UI,
+    /// e.g. to store a result in [`crate::Memory::data`] or show a notification.
+    /// Commands run in the order they were queued, on the UI thread, with the [`Context`] unlocked.
+    ///
+    /// ```
+    /// # let ctx = egui::Context::default();
+    /// let ctx_clone = ctx.clone();
+    /// std::thread::spawn(move || {
+    ///     let answer = 42; // Something expensive
+    ///     ctx_clone.enqueue(move |ctx| {
+    ///         ctx.data_mut(|data| data.insert_temp(egui::Id::new("answer"), answer));
+    ///     });
+    /// });
+    /// ```
+    ///
+    /// For updating a texture from a background thread you don't need this:
+    /// [`TextureHandle`] is `Send` and [`TextureHandle::set`] can be called from any thread.
+    #[track_caller]
+    pub fn enqueue(&self, command: impl FnOnce(&Self) + Send + 'static) {
+        let cause = RepaintCause::new();
+        self.write(|ctx| {
+            ctx.queued_commands.lock().push(Box::new(command));
+            ctx.request_repaint(ViewportId::ROOT, cause);
+        });
+    }
+
     /// Was a repaint requested last pass for the current viewport?
     #[must_use]
     pub fn requested_repaint_last_pass(&self) -> bool {
@@ -1829,6 +2020,31 @@ impl Context {
         self.write(|ctx| ctx.request_repaint_callback = Some(callback));
     }
 
+    /// Call this with every [`UiSound`] that happens, e.g. to play a click sound when a button is clicked.
+    ///
+    /// The callback is called at the end of each pass.
+    /// Only one callback can be set. Any new call overrides the previous callback.
+    pub fn set_sound_callback(&self, callback: impl Fn(UiSound) + Send + Sync + 'static) {
+        let callback = Arc::new(callback);
+        self.write(|ctx| ctx.sound_callback = Some(callback));
+    }
+
+    /// Stop calling the callback set with [`Self::set_sound_callback`].
+    pub fn clear_sound_callback(&self) {
+        self.write(|ctx| ctx.sound_callback = None);
+    }
+
+    /// Report a sound, e.g. [`UiSound::Error`] from a custom widget.
+    ///
+    /// Does nothing unless a callback was set with [`Self::set_sound_callback`].
+    pub fn play_sound(&self, sound: UiSound) {
+        self.write(|ctx| {
+            if ctx.sound_callback.is_some() {
+                ctx.sounds.push(sound);
+            }
+        });
+    }
+
     /// Request to discard the visual output of this pass,
     /// and to immediately do another one.
     ///
@@ -2161,6 +2377,7 @@ impl Context {
     pub fn set_zoom_factor(&self, zoom_factor: f32) {
         let cause = RepaintCause::new();
         self.write(|ctx| {
+            ctx.zoom_animation = None;
             if ctx.memory.options.zoom_factor != zoom_factor {
                 ctx.new_zoom_factor = Some(zoom_factor);
                 #[expect(clippy::iter_over_hash_type)]
@@ -2171,6 +2388,46 @@ impl Context {
         });
     }
 
+    /// Smoothly change the zoom factor of the UI to `zoom_factor` over `duration` seconds.
+    ///
+    /// Pointer positions are kept correct during the transition,
+    /// so the user can keep interacting with the UI while it is scaling.
+    ///
+    /// Calling [`Self::set_zoom_factor`] stops the animation.
+    pub fn animate_zoom_factor(&self, zoom_factor: f32, duration: f32) {
+        let cause = RepaintCause::new();
+        self.write(|ctx| {
+            let from = ctx
+                .new_zoom_factor
+                .unwrap_or(ctx.memory.options.zoom_factor);
+            ctx.zoom_animation = Some(ZoomAnimation {
+                from,
+                to: zoom_factor,
+                duration,
+                elapsed: 0.0,
+            });
+            #[expect(clippy::iter_over_hash_type)]
+            for viewport_id in ctx.all_viewport_ids() {
+                ctx.request_repaint(viewport_id, cause.clone());
+            }
+        });
+    }
+
+    /// Smoothly change the number of physical pixels for each logical point over `duration` seconds.
+    ///
+    /// This will actually translate to a call to [`Self::animate_zoom_factor`].
+    pub fn animate_pixels_per_point(&self, pixels_per_point: f32, duration: f32) {
+        self.animate_zoom_factor(
+            pixels_per_point / self.native_pixels_per_point().unwrap_or(1.0),
+            duration,
+        );
+    }
+
+    /// Is the zoom factor being animated, see [`Self::animate_zoom_factor`]?
+    pub fn is_zoom_animating(&self) -> bool {
+        self.read(|ctx| ctx.zoom_animation.is_some())
+    }
+
     /// Allocate a texture.
     ///
     /// This is for advanced users.
@@ -2277,7 +2534,20 @@ impl Context {
         #[cfg(debug_assertions)]
         self.debug_painting();
 
-        self.write(|ctx| ctx.end_pass())
+        let (output, sounds, sound_callback) = self.write(|ctx| {
+            let output = ctx.end_pass();
+            (
+                output,
+                std::mem::take(&mut ctx.sounds),
+                ctx.sound_callback.clone(),
+            )
+        });
+        if let Some(sound_callback) = sound_callback {
+            for sound in sounds {
+                sound_callback(sound);
+            }
+        }
+        output
     }
 
     /// Call at the end of each frame if you called [`Context::begin_pass`].
@@ -2336,6 +2606,7 @@ impl Context {
                 let interact_widgets = self.write(|ctx| ctx.viewport().interact_widgets.clone());
                 let InteractionSnapshot {
                     clicked,
+                    long_pressed: _,
                     long_touched: _,
                     drag_started: _,
                     dragged,
@@ -2443,12 +2714,33 @@ impl ContextImpl {
         let viewport = self.viewports.entry(ended_viewport_id).or_default();
         let pixels_per_point = viewport.input.pixels_per_point;
 
+        viewport.frame_cost.end_pass();
+
         self.loaders.end_pass(viewport.repaint.cumulative_pass_nr);
 
         viewport.repaint.cumulative_pass_nr += 1;
 
         self.memory.end_pass(&viewport.this_pass.used_ids);
 
+        if self.memory.areas_mut().take_uncovered_skipped_layer() {
+            // A window skipped its contents, but the window covering it moved away:
+            viewport
+                .output
+                .request_discard_reasons
+                .push(RepaintCause::new_reason("A covered window was uncovered"));
+        }
+
+        let open_popup = self.memory.open_popup_id();
+        if self.sound_callback.is_some() && open_popup != viewport.open_popup {
+            if viewport.open_popup.is_some() {
+                self.sounds.push(UiSound::PopupClose);
+            }
+            if open_popup.is_some() {
+                self.sounds.push(UiSound::PopupOpen);
+            }
+        }
+        viewport.open_popup = open_popup;
+
         if let Some(fonts) = self.fonts.get(&pixels_per_point.into()) {
             let tex_mngr = &mut self.tex_manager.0.write();
             if let Some(font_image_delta) = fonts.font_image_delta() {
@@ -2505,9 +2797,17 @@ impl ContextImpl {
             }
         }
 
-        let shapes = viewport
-            .graphics
-            .drain(self.memory.areas().order(), &self.memory.to_global);
+        let layers = &viewport.this_pass.layers;
+        let shapes = viewport.graphics.drain(
+            self.memory.areas().order(),
+            &self.memory.to_global,
+            |layer_id| layers.get(&layer_id).map_or(1.0, |layer| layer.opacity),
+            |layer_id| {
+                layers
+                    .get(&layer_id)
+                    .map_or(BlendMode::Normal, |layer| layer.blend_mode)
+            },
+        );
 
         let mut repaint_needed = false;
 
@@ -2663,7 +2963,10 @@ impl Context {
         // it takes to tessellate them, so it is not a worth optimization.
 
         self.write(|ctx| {
-            let tessellation_options = ctx.memory.options.tessellation_options;
+            let mut tessellation_options = ctx.memory.options.tessellation_options;
+            if ctx.viewport().frame_cost.last.is_degraded {
+                tessellation_options.feathering = false;
+            }
             let texture_atlas = if let Some(fonts) = ctx.fonts.get(&pixels_per_point.into()) {
                 fonts.texture_atlas()
             } else {
@@ -2839,7 +3142,9 @@ impl Context {
 impl Context {
     /// Transform the graphics of the given layer.
     ///
-    /// This will also affect input.
+    /// This will also affect input: widgets on the layer are hit-tested in the transformed space,
+    /// and positions like [`Response::hover_pos`] and [`Response::interact_pointer_pos`]
+    /// are in the coordinates of the layer.
     /// The direction of the given transform is "into the global coordinate system".
     ///
     /// This is a sticky setting, remembered from one frame to the next.
@@ -2858,9 +3163,37 @@ impl Context {
         });
     }
 
+    /// Multiply the opacity of everything painted on the given layer this pass.
+    ///
+    /// The colors of all shapes on the layer are multiplied when the pass ends,
+    /// so this also affects what was painted before this call.
+    /// This is used by [`crate::Window`] to fade windows in and out.
+    ///
+    /// The opacity is reset at the start of each pass.
+    pub fn multiply_layer_opacity(&self, layer_id: LayerId, opacity: f32) {
+        if opacity.is_finite() {
+            self.pass_state_mut(|fs| {
+                fs.layers.entry(layer_id).or_default().opacity *= opacity.clamp(0.0, 1.0);
+            });
+        }
+    }
+
+    /// Set how everything painted on the given layer this pass is blended with what is behind it.
+    ///
+    /// Use [`BlendMode::Additive`] for glows and highlights,
+    /// and [`BlendMode::Multiply`] for shadows and tinting.
+    /// Combine with [`Self::multiply_layer_opacity`] to fade the effect.
+    ///
+    /// The blend mode is reset to [`BlendMode::Normal`] at the start of each pass.
+    pub fn set_layer_blend_mode(&self, layer_id: LayerId, blend_mode: BlendMode) {
+        self.pass_state_mut(|fs| {
+            fs.layers.entry(layer_id).or_default().blend_mode = blend_mode;
+        });
+    }
+
     /// Return how to transform the graphics of the given layer into the global coordinate system.
     ///
-    /// Set this with [`Self::layer_transform_to_global`].
+    /// Set this with [`Self::set_transform_layer`].
     pub fn layer_transform_to_global(&self, layer_id: LayerId) -> Option<TSTransform> {
         self.memory(|m| m.to_global.get(&layer_id).copied())
     }
@@ -3032,7 +3365,7 @@ impl Context {
 
         let animation_in_progress = 0.0 < animated_value && animated_value < 1.0;
         if animation_in_progress {
-            self.request_repaint();
+            self.request_animation_repaint();
         }
 
         if target_value {
@@ -3058,18 +3391,135 @@ impl Context {
         });
         let animation_in_progress = animated_value != target_value;
         if animation_in_progress {
-            self.request_repaint();
+            self.request_animation_repaint();
         }
 
         animated_value
     }
 
+    /// Request the next frame of an animation, at a lower frame rate if [`Self::is_degraded`].
+    #[track_caller]
+    fn request_animation_repaint(&self) {
+        if self.is_degraded() {
+            self.request_repaint_after_secs(1.0 / 30.0);
+        } else {
+            self.request_repaint();
+        }
+    }
+
     /// Clear memory of any animations.
     pub fn clear_animations(&self) {
         self.write(|ctx| ctx.animation_manager = Default::default());
     }
 }
 
+/// ## Frame budget
+impl Context {
+    /// How expensive the last frame was, and which panels and windows painted the most.
+    ///
+    /// This is only tracked when [`crate::Options::frame_budget`] is set,
+    /// and the integration reports [`crate::RawInput::frame_cost`].
+    pub fn frame_cost(&self) -> FrameCost {
+        self.read(|ctx| {
+            ctx.viewports
+                .get(&ctx.viewport_id())
+                .map(|viewport| viewport.frame_cost.last.clone())
+                .unwrap_or_default()
+        })
+    }
+
+    /// Is egui cutting corners because it is over its [`crate::Options::frame_budget`]?
+    ///
+    /// Expensive widgets can check this to do less work.
+    ///
+    /// See [`crate::Options::degrade_over_budget`].
+    pub fn is_degraded(&self) -> bool {
+        self.read(|ctx| {
+            ctx.viewports
+                .get(&ctx.viewport_id())
+                .is_some_and(|viewport| viewport.frame_cost.last.is_degraded)
+        })
+    }
+
+    /// Count how many shapes `add_contents` paints, for [`Self::frame_cost`].
+    pub(crate) fn with_frame_cost_scope<R>(
+        &self,
+        id: Id,
+        kind: UiKind,
+        add_contents: impl FnOnce() -> R,
+    ) -> R {
+        let is_measuring = self.read(|ctx| {
+            ctx.viewports
+                .get(&ctx.viewport_id())
+                .is_some_and(|viewport| viewport.frame_cost.is_measuring())
+        });
+        if !is_measuring {
+            return add_contents();
+        }
+
+        let start = self.graphics(|graphics| graphics.num_shapes());
+        let result = add_contents();
+        let num_shapes = self
+            .graphics(|graphics| graphics.num_shapes())
+            .saturating_sub(start);
+        self.write(|ctx| {
+            ctx.viewport().frame_cost.add_scope(ScopeCost {
+                id,
+                kind,
+                num_shapes,
+            });
+        });
+        result
+    }
+
+    /// Show the [`Self::frame_cost`] of the last pass, and how well text layout was cached.
+    pub fn frame_cost_ui(&self, ui: &mut Ui) {
+        let text_stats = self.fonts(|f| f.galley_cache_stats());
+        ui.label(format!(
+            "Text layout: {} cached without allocating, {} cached, {} laid out",
+            text_stats.allocation_free_hits, text_stats.hits, text_stats.misses
+        ))
+        .on_hover_text("Plain text shown as an egui::text::InternedStr doesn't allocate at all");
+        ui.label(format!(
+            "{} galleys cached, {} interned strings",
+            text_stats.num_galleys,
+            epaint::text::InternedStr::num_interned()
+        ));
+
+        let frame_cost = self.frame_cost();
+        let Some(frame_budget) = self.options(|o| o.frame_budget) else {
+            ui.label("Set a frame budget in the options to track the frame cost.");
+            return;
+        };
+        if self.input(|i| i.raw.frame_cost.is_none()) {
+            ui.label("The integration does not report the frame cost.");
+        }
+
+        ui.label(format!(
+            "{:.2} ms of {:.2} ms budget (average {:.2} ms)",
+            1e3 * frame_cost.seconds,
+            1e3 * frame_budget,
+            1e3 * frame_cost.average_seconds,
+        ));
+        if frame_cost.is_degraded {
+            ui.colored_label(ui.visuals().warn_fg_color, "Degraded to get within budget");
+        } else if frame_cost.is_over_budget {
+            ui.colored_label(ui.visuals().warn_fg_color, "Over budget");
+        }
+
+        Grid::new("frame_cost_scopes")
+            .num_columns(2)
+            .striped(true)
+            .show(ui, |ui| {
+                for scope in frame_cost.scopes.iter().take(10) {
+                    ui.label(format!("{:?} {:?}", scope.kind, scope.id));
+                    ui.label(format!("{} shapes", scope.num_shapes));
+                    ui.end_row();
+                }
+            });
+    }
+}
+
 impl Context {
     /// Show a ui for settings (style and tessellation options).
     pub fn settings_ui(&self, ui: &mut Ui) {
@@ -3187,6 +3637,12 @@ impl Context {
                 input.ui(ui);
             });
 
+        CollapsingHeader::new("⏱ Frame cost")
+            .default_open(false)
+            .show(ui, |ui| {
+                self.frame_cost_ui(ui);
+            });
+
         CollapsingHeader::new("📊 Paint stats")
             .default_open(false)
             .show(ui, |ui| {
@@ -3716,6 +4172,24 @@ impl Context {
         })
     }
 
+    /// A low-resolution preview of an image that is still loading, from the first image loader that has one.
+    ///
+    /// See [`load::ImageLoader::load_preview`].
+    ///
+    /// ⚠ May deadlock if called from within an `ImageLoader`!
+    pub fn try_load_image_preview(&self, uri: &str) -> Option<Arc<ColorImage>> {
+        profiling::function_scope!(uri);
+
+        let loaders = self.loaders();
+        let image_loaders = loaders.image.lock();
+
+        // Try most recently added loaders first (hence `.rev()`)
+        image_loaders
+            .iter()
+            .rev()
+            .find_map(|loader| loader.load_preview(uri))
+    }
+
     /// Try loading the texture from the given uri using any available texture loaders.
     ///
     /// Loaders are expected to cache results, so that this call is immediate-mode safe.
@@ -3756,6 +4230,26 @@ impl Context {
         Err(load::LoadError::NoMatchingTextureLoader)
     }
 
+    /// A low-resolution preview of a texture that is still loading, from the first texture loader that has one.
+    ///
+    /// See [`load::TextureLoader::load_preview`].
+    pub fn try_load_texture_preview(
+        &self,
+        uri: &str,
+        texture_options: TextureOptions,
+    ) -> Option<SizedTexture> {
+        profiling::function_scope!(uri);
+
+        let loaders = self.loaders();
+        let texture_loaders = loaders.texture.lock();
+
+        // Try most recently added loaders first (hence `.rev()`)
+        texture_loaders
+            .iter()
+            .rev()
+            .find_map(|loader| loader.load_preview(self, uri, texture_options))
+    }
+
     /// The loaders of bytes, images, and textures.
     pub fn loaders(&self) -> Arc<Loaders> {
         self.read(|this| this.loaders.clone())
@@ -3795,6 +4289,11 @@ impl Context {
         self.write(|ctx| reader(ctx.viewport()))
     }
 
+    /// Modify the state of the current viewport.
+    pub(crate) fn viewport_mut<R>(&self, writer: impl FnOnce(&mut ViewportState) -> R) -> R {
+        self.write(|ctx| writer(ctx.viewport()))
+    }
+
     /// Read the state of a specific current viewport.
     pub fn viewport_for<R>(
         &self,
@@ -4085,6 +4584,7 @@ fn context_impl_send_sync() {
 #[cfg(test)]
 mod test {
     use super::Context;
+    use crate::{Id, Pos2, RawInput, Rect, Sense, Vec2};
 
     #[test]
     fn test_single_pass() {
@@ -4224,4 +4724,138 @@ mod test {
             );
         }
     }
+
+    #[test]
+    fn test_scroll_to_widget() {
+        let ctx = Context::default();
+        ctx.style_mut(|s| s.scroll_animation = crate::style::ScrollAnimation::none());
+        let input = || RawInput {
+            screen_rect: Some(Rect::from_min_size(Pos2::ZERO, Vec2::splat(200.0))),
+            ..Default::default()
+        };
+        let target = Id::new("target");
+        let run = || {
+            let mut offset = 0.0;
+            let _ = ctx.run(input(), |ctx| {
+                crate::CentralPanel::default().show(ctx, |ui| {
+                    offset = crate::ScrollArea::vertical()
+                        .show(ui, |ui| {
+                            ui.add_space(1000.0);
+                            ui.interact(
+                                Rect::from_min_size(ui.cursor().min, Vec2::splat(10.0)),
+                                target,
+                                Sense::hover(),
+                            );
+                        })
+                        .state
+                        .offset
+                        .y;
+                });
+            });
+            offset
+        };
+
+        assert_eq!(run(), 0.0);
+        ctx.scroll_to_widget(target, None);
+        run();
+        assert!(800.0 < run());
+    }
+
+    #[test]
+    fn test_animate_zoom_factor() {
+        let ctx = Context::default();
+        let pointer = Pos2::new(100.0, 100.0);
+        let _ = ctx.run(
+            RawInput {
+                events: vec![crate::Event::PointerMoved(pointer)],
+                ..Default::default()
+            },
+            |_| {},
+        );
+
+        ctx.animate_zoom_factor(2.0, 0.5);
+        let mut zoom_factors = vec![];
+        for _ in 0..40 {
+            let _ = ctx.run(RawInput::default(), |_| {});
+            zoom_factors.push(ctx.zoom_factor());
+        }
+        assert!(zoom_factors.windows(2).all(|w| w[0] <= w[1]));
+        assert!(1.0 < zoom_factors[5] && zoom_factors[5] < 2.0);
+        assert_eq!(ctx.zoom_factor(), 2.0);
+        assert!(!ctx.is_zoom_animating());
+
+        // The pointer hasn't moved on the screen, so it is now at half the position in points:
+        let pos = ctx.pointer_latest_pos().unwrap();
+        assert!((pos - Pos2::new(50.0, 50.0)).length() < 0.01, "{pos:?}");
+    }
+
+    #[test]
+    fn test_long_press() {
+        let ctx = Context::default();
+        let run = |time: f64, events: Vec<crate::Event>| {
+            let mut response = None;
+            let _ = ctx.run(
+                RawInput {
+                    time: Some(time),
+                    events,
+                    ..Default::default()
+                },
+                |ctx| {
+                    crate::CentralPanel::default().show(ctx, |ui| {
+                        response = Some(ui.add_sized([100.0, 20.0], crate::Button::new("")));
+                    });
+                },
+            );
+            response.unwrap()
+        };
+        let button = |pos, pressed| crate::Event::PointerButton {
+            pos,
+            button: crate::PointerButton::Primary,
+            pressed,
+            modifiers: crate::Modifiers::NONE,
+        };
+
+        let pos = run(0.0, vec![]).rect.center();
+        let response = run(
+            0.1,
+            vec![crate::Event::PointerMoved(pos), button(pos, true)],
+        );
+        assert!(!response.long_pressed());
+        assert!(!run(0.5, vec![]).long_pressed());
+        assert!(run(1.0, vec![]).long_pressed(), "held long enough");
+        assert!(!run(1.1, vec![]).long_pressed(), "only reported once");
+        let response = run(1.2, vec![button(pos, false)]);
+        assert!(!response.clicked() && !response.secondary_clicked());
+    }
+
+    #[test]
+    fn test_enqueue() {
+        let ctx = Context::default();
+        let id = Id::new("log");
+        let woken = std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false));
+        ctx.set_request_repaint_callback({
+            let woken = woken.clone();
+            move |_| woken.store(true, std::sync::atomic::Ordering::Relaxed)
+        });
+
+        let thread_ctx = ctx.clone();
+        std::thread::Builder::new()
+            .name("enqueue".to_owned())
+            .spawn(move || {
+                for i in 0..3 {
+                    thread_ctx.enqueue(move |ctx| {
+                        ctx.data_mut(|d| d.get_temp_mut_or_default::<Vec<i32>>(id).push(i));
+                    });
+                }
+            })
+            .unwrap()
+            .join()
+            .unwrap();
+        assert!(woken.load(std::sync::atomic::Ordering::Relaxed));
+
+        let _ = ctx.run(Default::default(), |ctx| {
+            let log = ctx.data(|d| d.get_temp::<Vec<i32>>(id));
+            assert_eq!(log, Some(vec![0, 1, 2]), "Run before the ui, in order");
+        });
+    }
 }
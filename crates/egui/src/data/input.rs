@@ -81,6 +81,14 @@ pub struct RawInput {
     ///
     /// `None` means "don't know".
     pub system_theme: Option<Theme>,
+
+    /// How many seconds of CPU time the previous frame took,
+    /// including tessellation and painting, as measured by the integration.
+    ///
+    /// This is compared against [`crate::Options::frame_budget`].
+    ///
+    /// `None` means "not measured".
+    pub frame_cost: Option<f32>,
 }
 
 impl Default for RawInput {
@@ -98,6 +106,7 @@ impl Default for RawInput {
             dropped_files: Default::default(),
             focused: true, // integrations opt into global focus tracking
             system_theme: None,
+            frame_cost: None,
         }
     }
 }
@@ -131,6 +140,7 @@ impl RawInput {
             dropped_files: std::mem::take(&mut self.dropped_files),
             focused: self.focused,
             system_theme: self.system_theme,
+            frame_cost: self.frame_cost.take(),
         }
     }
 
@@ -149,6 +159,7 @@ impl RawInput {
             mut dropped_files,
             focused,
             system_theme,
+            frame_cost,
         } = newer;
 
         self.viewport_id = viewport_ids;
@@ -163,6 +174,7 @@ impl RawInput {
         self.dropped_files.append(&mut dropped_files);
         self.focused = focused;
         self.system_theme = system_theme;
+        self.frame_cost = frame_cost.or(self.frame_cost);
     }
 }
 
@@ -377,7 +389,24 @@ pub struct DroppedFile {
     pub last_modified: Option<std::time::SystemTime>,
 
     /// Set by the `eframe` web backend.
+    ///
+    /// Not set for files larger than `eframe::WebOptions::max_dropped_file_bytes`,
+    /// which can instead be read piece by piece using [`Self::handle`].
     pub bytes: Option<std::sync::Arc<[u8]>>,
+
+    /// The size of the file in bytes. Set by the `eframe` web backend.
+    pub size: Option<u64>,
+
+    /// Identifies the file to the backend, for reading it on demand.
+    ///
+    /// With the `eframe` web backend, this is only set for files larger than
+    /// `eframe::WebOptions::max_dropped_file_bytes`.
+    /// Use it with the functions in `eframe::web::dropped_files`
+    /// to access the browser `File` and to read parts of it.
+    ///
+    /// The backend keeps the file around until you call `eframe::web::dropped_files::forget`,
+    /// so do that once you are done with it.
+    pub handle: Option<u64>,
 }
 
 /// An input event generated by the integration.
@@ -395,6 +424,26 @@ pub enum Event {
     /// The integration detected a "paste" event (e.g. Cmd+V).
     Paste(String),
 
+    /// The integration detected a "paste" event with an image on the clipboard,
+    /// e.g. after the user took a screenshot.
+    ///
+    /// Only sent by the `eframe` web backend.
+    PasteImage(std::sync::Arc<ColorImage>),
+
+    /// Progress of loading a dropped file, before it shows up in [`RawInput::dropped_files`].
+    ///
+    /// Only sent by the `eframe` web backend.
+    DroppedFileProgress {
+        /// The [`DroppedFile::name`].
+        name: String,
+
+        /// How many bytes have been loaded so far.
+        loaded: u64,
+
+        /// The size of the file in bytes.
+        total: u64,
+    },
+
     /// Text input, e.g. via keyboard.
     ///
     /// When the user presses enter/return, do not send a [`Text`](Event::Text) (just [`Key::Enter`]).
@@ -1129,6 +1178,7 @@ impl RawInput {
             dropped_files,
             focused,
             system_theme,
+            frame_cost,
         } = self;
 
         ui.label(format!("Active viewport: {viewport_id:?}"));
@@ -1158,6 +1208,11 @@ impl RawInput {
         ui.label(format!("dropped_files: {}", dropped_files.len()));
         ui.label(format!("focused: {focused}"));
         ui.label(format!("system_theme: {system_theme:?}"));
+        if let Some(frame_cost) = frame_cost {
+            ui.label(format!("frame_cost: {:.2} ms", 1e3 * frame_cost));
+        } else {
+            ui.label("frame_cost: None");
+        }
         ui.scope(|ui| {
             ui.set_min_height(150.0);
             ui.label(format!("events: {events:#?}"))
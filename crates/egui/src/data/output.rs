@@ -97,6 +97,39 @@ pub enum OutputCommand {
 
     /// Open this url in a browser.
     OpenUrl(OpenUrl),
+
+    /// Give the user physical feedback, e.g. by vibrating the phone, trackpad or gamepad.
+    ///
+    /// `eframe` web uses `navigator.vibrate`; native integrations have to implement this themselves.
+    Haptic(HapticFeedback),
+}
+
+/// How strong a [`OutputCommand::Haptic`] should feel.
+///
+/// egui emits [`Self::Light`] when a slider snaps to a new step and when a drag starts,
+/// and [`Self::Medium`] for long-presses.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum HapticFeedback {
+    /// A faint tick.
+    Light,
+
+    /// A clear tick.
+    Medium,
+
+    /// A strong bump, e.g. for errors.
+    Heavy,
+}
+
+impl HapticFeedback {
+    /// A suggested vibration duration, for platforms that can only vibrate at one strength.
+    pub fn duration_ms(self) -> u32 {
+        match self {
+            Self::Light => 10,
+            Self::Medium => 20,
+            Self::Heavy => 40,
+        }
+    }
 }
 
 /// The non-rendering part of what egui emits each frame.
@@ -718,6 +751,7 @@ impl WidgetInfo {
             WidgetType::CollapsingHeader => "collapsing header",
             WidgetType::ProgressIndicator => "progress indicator",
             WidgetType::Window => "window",
+            WidgetType::Tab => "tab",
             WidgetType::Label | WidgetType::Other => "",
         };
 
@@ -0,0 +1,156 @@
+//! Keeping track of how much CPU time each frame costs, see [`crate::Options::frame_budget`].
+
+use crate::{Id, UiKind};
+
+/// How much the contents of a panel or window painted, see [`FrameCost::scopes`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ScopeCost {
+    pub id: Id,
+
+    /// What kind of panel or window it was.
+    pub kind: UiKind,
+
+    /// How many shapes it painted, including those of any panels and windows shown inside of it.
+    ///
+    /// egui has no clock of its own, so this stands in for how long it took to build and paint.
+    pub num_shapes: usize,
+}
+
+/// How expensive the last pass was, see [`crate::Context::frame_cost`].
+///
+/// This is only measured when [`crate::Options::frame_budget`] is set.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FrameCost {
+    /// The CPU time of the last frame in seconds, from [`crate::RawInput::frame_cost`].
+    pub seconds: f32,
+
+    /// [`Self::seconds`] smoothed over the last few frames.
+    pub average_seconds: f32,
+
+    /// The panels and windows of the last pass, the ones that painted the most first.
+    pub scopes: Vec<ScopeCost>,
+
+    /// Is [`Self::average_seconds`] above [`crate::Options::frame_budget`]?
+    pub is_over_budget: bool,
+
+    /// Is egui cutting corners to get back within the budget?
+    ///
+    /// See [`crate::Options::degrade_over_budget`].
+    pub is_degraded: bool,
+}
+
+/// Per-viewport bookkeeping for [`FrameCost`].
+#[derive(Clone, Debug, Default)]
+pub(crate) struct FrameCostTracker {
+    is_measuring: bool,
+    scopes: Vec<ScopeCost>,
+    pub last: FrameCost,
+}
+
+impl FrameCostTracker {
+    /// `frame_cost` is [`crate::RawInput::frame_cost`].
+    pub fn begin_pass(
+        &mut self,
+        frame_budget: Option<f32>,
+        frame_cost: Option<f32>,
+        degrade_over_budget: bool,
+    ) {
+        self.scopes.clear();
+        let Some(frame_budget) = frame_budget else {
+            self.is_measuring = false;
+            self.last = Default::default();
+            return;
+        };
+        self.is_measuring = true;
+        if let Some(seconds) = frame_cost {
+            let last = std::mem::take(&mut self.last);
+            self.last = next_frame_cost(last, seconds, frame_budget, degrade_over_budget);
+        }
+    }
+
+    pub fn is_measuring(&self) -> bool {
+        self.is_measuring
+    }
+
+    pub fn add_scope(&mut self, scope: ScopeCost) {
+        self.scopes.push(scope);
+    }
+
+    pub fn end_pass(&mut self) {
+        if !std::mem::take(&mut self.is_measuring) {
+            return;
+        }
+        let mut scopes = std::mem::take(&mut self.scopes);
+        scopes.sort_by(|a, b| b.num_shapes.cmp(&a.num_shapes));
+        self.last.scopes = scopes;
+    }
+}
+
+fn next_frame_cost(
+    last: FrameCost,
+    seconds: f32,
+    frame_budget: f32,
+    degrade_over_budget: bool,
+) -> FrameCost {
+    let average_seconds = if last.average_seconds > 0.0 {
+        emath::lerp(last.average_seconds..=seconds, 0.1)
+    } else {
+        seconds
+    };
+    let is_over_budget = frame_budget < average_seconds;
+
+    // Only stop degrading once we are well within budget, so we don't flip back and forth:
+    let is_degraded = degrade_over_budget
+        && if last.is_degraded {
+            0.5 * frame_budget < average_seconds
+        } else {
+            is_over_budget
+        };
+
+    #[cfg(feature = "log")]
+    if is_degraded && !last.is_degraded {
+        log::debug!(
+            "egui is over its frame budget of {:.1} ms ({:.1} ms). Painting the most: {:?}",
+            1e3 * frame_budget,
+            1e3 * average_seconds,
+            last.scopes.first()
+        );
+    }
+
+    FrameCost {
+        seconds,
+        average_seconds,
+        scopes: last.scopes,
+        is_over_budget,
+        is_degraded,
+    }
+}
+
+#[test]
+fn test_frame_cost_degradation() {
+    let budget = 0.010;
+    let mut cost = FrameCost::default();
+    for _ in 0..10 {
+        cost = next_frame_cost(cost, 0.005, budget, true);
+    }
+    assert!(!cost.is_over_budget && !cost.is_degraded);
+
+    for _ in 0..20 {
+        cost = next_frame_cost(cost, 0.020, budget, true);
+    }
+    assert!(cost.is_over_budget && cost.is_degraded);
+
+    // Just under budget is not enough to stop degrading:
+    for _ in 0..50 {
+        cost = next_frame_cost(cost, 0.008, budget, true);
+    }
+    assert!(!cost.is_over_budget && cost.is_degraded);
+
+    for _ in 0..50 {
+        cost = next_frame_cost(cost, 0.002, budget, true);
+    }
+    assert!(!cost.is_degraded);
+
+    let cost = next_frame_cost(FrameCost::default(), 0.020, budget, false);
+    assert!(cost.is_over_budget && !cost.is_degraded);
+}
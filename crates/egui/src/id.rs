@@ -79,8 +79,9 @@ impl Id {
         self.0.get()
     }
 
+    /// The [`accesskit::NodeId`] of the widget with this id.
     #[cfg(feature = "accesskit")]
-    pub(crate) fn accesskit_id(&self) -> accesskit::NodeId {
+    pub fn accesskit_id(&self) -> accesskit::NodeId {
         self.value().into()
     }
 }
@@ -51,6 +51,7 @@ impl SurrenderFocusOn {
 /// Options for input state handling.
 #[derive(Clone, Copy, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct InputOptions {
     /// Multiplier for the scroll speed when reported in [`crate::MouseWheelUnit::Line`]s.
     pub line_scroll_speed: f32,
@@ -69,6 +70,12 @@ pub struct InputOptions {
     /// This is to support "press and hold for context menu" on touch screens.
     pub max_click_duration: f64,
 
+    /// Press and hold a widget for this many seconds for it to be long-pressed,
+    /// see [`crate::Response::long_pressed`].
+    ///
+    /// On touch screens, a long-press is also a secondary click (context menu).
+    pub long_press_duration: f64,
+
     /// The new pointer press must come within this many seconds from previous pointer release
     /// for double click (or when this value is doubled, triple click) to count.
     pub max_double_click_delay: f64,
@@ -109,6 +116,7 @@ impl Default for InputOptions {
             scroll_zoom_speed: 1.0 / 200.0,
             max_click_dist: 6.0,
             max_click_duration: 0.8,
+            long_press_duration: 0.8,
             max_double_click_delay: 0.3,
             zoom_modifier: Modifiers::COMMAND,
             horizontal_scroll_modifier: Modifiers::SHIFT,
@@ -126,6 +134,7 @@ impl InputOptions {
             scroll_zoom_speed,
             max_click_dist,
             max_click_duration,
+            long_press_duration,
             max_double_click_delay,
             zoom_modifier,
             horizontal_scroll_modifier,
@@ -170,6 +179,15 @@ impl InputOptions {
                     );
                 ui.end_row();
 
+                ui.label("Long press duration");
+                ui.add(
+                    crate::DragValue::new(long_press_duration)
+                        .range(0.1..=f64::INFINITY)
+                        .speed(0.1),
+                )
+                .on_hover_text("Press and hold for this long for a long-press");
+                ui.end_row();
+
                 ui.label("Max double click delay");
                 ui.add(
                     crate::DragValue::new(max_double_click_delay)
@@ -654,15 +672,18 @@ impl InputState {
             return Some(Duration::ZERO);
         }
 
-        if self.any_touches() && !self.pointer.is_decidedly_dragging() {
-            // We need to wake up and check for press-and-hold for the context menu.
+        if self.pointer.primary_down()
+            && !self.pointer.has_moved_too_much_for_a_click
+            && !self.pointer.long_pressing
+        {
+            // We need to wake up and check for press-and-hold, e.g. for the context menu.
             if let Some(press_start_time) = self.pointer.press_start_time {
                 let press_duration = self.time - press_start_time;
-                if self.options.max_click_duration.is_finite()
-                    && press_duration < self.options.max_click_duration
+                if self.options.long_press_duration.is_finite()
+                    && press_duration < self.options.long_press_duration
                 {
-                    let secs_until_menu = self.options.max_click_duration - press_duration;
-                    return Some(Duration::from_secs_f64(secs_until_menu));
+                    let secs_until_long_press = self.options.long_press_duration - press_duration;
+                    return Some(Duration::from_secs_f64(secs_until_long_press));
                 }
             }
         }
@@ -1017,10 +1038,14 @@ pub struct PointerState {
     pub(crate) has_moved_too_much_for_a_click: bool,
 
     /// Did [`Self::is_decidedly_dragging`] go from `false` to `true` this frame?
-    ///
-    /// This could also be the trigger point for a long-touch.
     pub(crate) started_decidedly_dragging: bool,
 
+    /// Has the primary button been held still for [`InputOptions::long_press_duration`]?
+    long_pressing: bool,
+
+    /// Did [`Self::long_pressing`] go from `false` to `true` this frame?
+    started_long_press: bool,
+
     /// When did the pointer get click last?
     /// Used to check for double-clicks.
     last_click_time: f64,
@@ -1058,6 +1083,8 @@ impl Default for PointerState {
             press_start_time: None,
             has_moved_too_much_for_a_click: false,
             started_decidedly_dragging: false,
+            long_pressing: false,
+            started_long_press: false,
             last_click_time: f64::NEG_INFINITY,
             last_last_click_time: f64::NEG_INFINITY,
             last_move_time: f64::NEG_INFINITY,
@@ -1068,6 +1095,17 @@ impl Default for PointerState {
 }
 
 impl PointerState {
+    /// Convert the stored positions to a new size of a point, e.g. when the zoom factor changes.
+    pub(crate) fn rescale(&mut self, ratio: f32) {
+        let scale = |pos: Pos2| (ratio * pos.to_vec2()).to_pos2();
+        self.latest_pos = self.latest_pos.map(scale);
+        self.interact_pos = self.interact_pos.map(scale);
+        self.press_origin = self.press_origin.map(scale);
+        self.pos_history.clear();
+        self.velocity = Vec2::ZERO;
+        self.direction = Vec2::ZERO;
+    }
+
     #[must_use]
     pub(crate) fn begin_pass(mut self, time: f64, new: &RawInput, options: InputOptions) -> Self {
         let was_decidedly_dragging = self.is_decidedly_dragging();
@@ -1213,6 +1251,12 @@ impl PointerState {
 
         self.started_decidedly_dragging = self.is_decidedly_dragging() && !was_decidedly_dragging;
 
+        let long_pressing = self
+            .long_press_progress()
+            .is_some_and(|progress| 1.0 <= progress);
+        self.started_long_press = long_pressing && !self.long_pressing;
+        self.long_pressing = long_pressing;
+
         self
     }
 
@@ -1467,17 +1511,24 @@ impl PointerState {
             && !self.any_click()
     }
 
-    /// A long press is something we detect on touch screens
-    /// to trigger a secondary click (context menu).
+    /// Was the primary button held still for [`InputOptions::long_press_duration`]?
+    ///
+    /// On touch screens this triggers a secondary click (context menu).
     ///
     /// Returns `true` only on one frame.
     pub(crate) fn is_long_press(&self) -> bool {
-        self.started_decidedly_dragging
-            && !self.has_moved_too_much_for_a_click
-            && self.button_down(PointerButton::Primary)
-            && self.press_start_time.is_some_and(|press_start_time| {
-                self.time - press_start_time > self.options.max_click_duration
-            })
+        self.started_long_press
+    }
+
+    /// How far along a press-and-hold is towards being a long-press, from 0 to 1.
+    ///
+    /// `None` if the primary button isn't held down, or if the pointer has moved since it was pressed.
+    pub fn long_press_progress(&self) -> Option<f32> {
+        if !self.primary_down() || self.has_moved_too_much_for_a_click {
+            return None;
+        }
+        let press_duration = self.time - self.press_start_time?;
+        Some((press_duration / self.options.long_press_duration).clamp(0.0, 1.0) as f32)
     }
 
     /// Is the primary button currently down?
@@ -1621,6 +1672,8 @@ impl PointerState {
             press_start_time,
             has_moved_too_much_for_a_click,
             started_decidedly_dragging,
+            long_pressing,
+            started_long_press: _,
             last_click_time,
             last_last_click_time,
             pointer_events,
@@ -1646,6 +1699,7 @@ impl PointerState {
         ui.label(format!(
             "started_decidedly_dragging: {started_decidedly_dragging}"
         ));
+        ui.label(format!("long_pressing: {long_pressing}"));
         ui.label(format!("last_click_time: {last_click_time:#?}"));
         ui.label(format!("last_last_click_time: {last_last_click_time:#?}"));
         ui.label(format!("last_move_time: {last_move_time:#?}"));
@@ -0,0 +1,326 @@
+//! A widget inspector ("pick mode") for debugging large UIs.
+//!
+//! Press [`kb_shortcuts::TOGGLE_INSPECTOR`] (or call [`set_active`]) to enter inspect mode.
+//! While active, hovering any widget highlights its rectangle and shows its [`Id`],
+//! [`crate::Sense`], enabled state, layer and (if available) where it was created.
+//! Clicking copies that information to the clipboard.
+//!
+//! While the inspector is active, widgets do not receive any pointer interaction.
+//!
+//! The source location is captured using a callstack,
+//! which requires the `callstack` feature and a debug build.
+//!
+//! This is a built-in plugin in egui, registered by [`Context`]'s `Default` implementation.
+
+use crate::{
+    Align2, Color32, Context, FontId, Id, Key, KeyboardShortcut, Modifiers, NumExt as _, Pos2,
+    Rect, Shape, StrokeKind, Vec2, WidgetRect, pos2,
+};
+
+/// The suggested keyboard shortcuts for the inspector.
+pub mod kb_shortcuts {
+    use super::{Key, KeyboardShortcut, Modifiers};
+
+    /// Keyboard shortcut for toggling the inspector (`Cmd` + `Alt` + `I`).
+    pub const TOGGLE_INSPECTOR: KeyboardShortcut =
+        KeyboardShortcut::new(Modifiers::COMMAND.plus(Modifiers::ALT), Key::I);
+}
+
+pub(crate) fn register(ctx: &Context) {
+    ctx.on_begin_pass("inspector", std::sync::Arc::new(State::begin_pass));
+    ctx.on_end_pass("inspector", std::sync::Arc::new(State::end_pass));
+}
+
+/// Is the inspector currently active?
+pub fn is_active(ctx: &Context) -> bool {
+    ctx.data(|data| data.get_temp::<State>(state_id()))
+        .is_some_and(|state| state.active)
+}
+
+/// Enter or leave inspect mode.
+pub fn set_active(ctx: &Context, active: bool) {
+    ctx.data_mut(|data| {
+        let state = data.get_temp_mut_or_default::<State>(state_id());
+        state.active = active;
+        if !active {
+            state.picked = None;
+            state.callstack = None;
+        }
+    });
+    ctx.request_repaint();
+}
+
+/// Toggle inspect mode on or off.
+pub fn toggle(ctx: &Context) {
+    set_active(ctx, !is_active(ctx));
+}
+
+fn state_id() -> Id {
+    Id::new("egui::inspector")
+}
+
+/// The id of the widget currently being inspected, stored separately from [`State`]
+/// so that it is cheap to check for in [`Context::create_widget`].
+#[derive(Clone, Copy)]
+struct InspectedId(Id);
+
+fn inspected_id() -> Id {
+    Id::new("egui::inspector::inspected")
+}
+
+/// Called for every widget that is created.
+///
+/// If this is the widget we are inspecting, remember where it was created.
+#[cfg(debug_assertions)]
+pub(crate) fn on_create_widget(ctx: &Context, widget: &WidgetRect) {
+    let Some(InspectedId(id)) = ctx.data(|data| data.get_temp::<InspectedId>(inspected_id()))
+    else {
+        return;
+    };
+    if id != widget.id {
+        return;
+    }
+
+    #[cfg(feature = "callstack")]
+    let callstack = crate::callstack::capture();
+
+    #[cfg(not(feature = "callstack"))]
+    let callstack = String::default();
+
+    ctx.data_mut(|data| {
+        let state = data.get_temp_mut_or_default::<State>(state_id());
+        state.callstack = Some(callstack);
+    });
+}
+
+#[derive(Clone, Default)]
+struct State {
+    active: bool,
+
+    /// The widget under the pointer, in global coordinates.
+    picked: Option<WidgetRect>,
+
+    /// Where [`Self::picked`] was created, if known.
+    callstack: Option<String>,
+
+    /// Copy the info about the picked widget at the end of the pass.
+    copy: bool,
+}
+
+impl State {
+    fn begin_pass(ctx: &Context) {
+        if ctx.options(|o| o.inspect_with_keyboard)
+            && ctx.input_mut(|i| i.consume_shortcut(&kb_shortcuts::TOGGLE_INSPECTOR))
+        {
+            toggle(ctx);
+        }
+
+        let mut state = ctx.data(|data| data.get_temp::<Self>(state_id()).unwrap_or_default());
+        if !state.active {
+            ctx.data_mut(|data| data.remove::<InspectedId>(inspected_id()));
+            return;
+        }
+
+        // Consume the key, so that it doesn't also close e.g. a popup below the inspector:
+        if ctx.input_mut(|i| i.consume_key(Modifiers::NONE, Key::Escape)) {
+            set_active(ctx, false);
+            ctx.data_mut(|data| data.remove::<InspectedId>(inspected_id()));
+            return;
+        }
+
+        // The innermost widget is the last one registered:
+        let picked = ctx.viewport(|vp| vp.hits.contains_pointer.last().copied());
+        if picked.map(|w| w.id) != state.picked.map(|w| w.id) {
+            state.callstack = None;
+        }
+        state.picked = picked;
+        state.copy = ctx.input(|i| i.pointer.primary_clicked());
+
+        ctx.data_mut(|data| {
+            if let Some(picked) = picked {
+                data.insert_temp(inspected_id(), InspectedId(picked.id));
+            } else {
+                data.remove::<InspectedId>(inspected_id());
+            }
+            data.insert_temp(state_id(), state);
+        });
+
+        // Block all interaction while inspecting:
+        ctx.viewport_mut(|vp| {
+            vp.hits = Default::default();
+            vp.interact_widgets = Default::default();
+        });
+        ctx.set_cursor_icon(crate::CursorIcon::Crosshair);
+    }
+
+    fn end_pass(ctx: &Context) {
+        let Some(state) = ctx.data(|data| data.get_temp::<Self>(state_id())) else {
+            return;
+        };
+        if !state.active {
+            return;
+        }
+
+        let painter = ctx.debug_painter();
+
+        let Some(widget) = state.picked else {
+            let pos = ctx
+                .input(|i| i.pointer.latest_pos())
+                .unwrap_or_else(|| ctx.screen_rect().center());
+            painter.debug_text(
+                pos + 12.0 * Vec2::splat(1.0),
+                Align2::LEFT_TOP,
+                Color32::WHITE,
+                "Inspector: hover a widget (Esc to exit)",
+            );
+            return;
+        };
+
+        let text = state.describe(ctx, &widget);
+
+        if state.copy {
+            ctx.copy_text(text.clone());
+        }
+
+        // Highlight the widget:
+        let rect = widget.interact_rect;
+        painter.rect(
+            rect,
+            0.0,
+            Color32::from_rgb(0, 100, 200).gamma_multiply(0.3),
+            (1.0, Color32::LIGHT_BLUE),
+            StrokeKind::Outside,
+        );
+
+        // Paint the info box either below or above the widget:
+        let font_id = FontId::monospace(12.0);
+        let galley = painter.layout_no_wrap(
+            format!("{text}\n\n(click to copy, Esc to exit)"),
+            font_id,
+            Color32::WHITE,
+        );
+        let screen_rect = ctx.screen_rect();
+        let y = if rect.bottom() + galley.size().y + 8.0 <= screen_rect.bottom() {
+            rect.bottom() + 8.0
+        } else {
+            rect.top() - galley.size().y - 8.0
+        };
+        let x = rect.left().at_most(screen_rect.right() - galley.size().x);
+        let text_pos = pos2(x.max(0.0), y.max(0.0));
+        let text_rect = Rect::from_min_size(text_pos, galley.size()).expand(4.0);
+
+        painter.add(Shape::rect_filled(
+            text_rect,
+            2.0,
+            Color32::from_black_alpha(220),
+        ));
+        painter.galley(text_pos, galley, Color32::WHITE);
+
+        // Keep painting while the pointer moves around.
+        ctx.request_repaint();
+    }
+
+    fn describe(&self, ctx: &Context, widget: &WidgetRect) -> String {
+        let WidgetRect {
+            id,
+            layer_id,
+            rect,
+            interact_rect: _,
+            sense,
+            enabled,
+        } = *widget;
+
+        let mut text = format!(
+            "{id:?}\n{sense:?}\nenabled: {enabled}\nlayer: {:?} {:?}\nrect: {}",
+            layer_id.order,
+            layer_id.id,
+            format_rect(rect),
+        );
+
+        if let Some(info) = ctx.pass_state(|fs| fs.widgets.info(id).cloned()) {
+            text += &format!("\ninfo: {:?}", info.typ);
+            if let Some(label) = &info.label {
+                text += &format!(" {label:?}");
+            }
+        }
+
+        match &self.callstack {
+            Some(callstack) if !callstack.is_empty() => {
+                text += &format!("\n\n{callstack}");
+            }
+            _ => {
+                if !cfg!(all(feature = "callstack", debug_assertions)) {
+                    text += "\n\nEnable the `callstack` feature in a debug build to see where this widget was created.";
+                }
+            }
+        }
+
+        text
+    }
+}
+
+fn format_rect(rect: Rect) -> String {
+    let Pos2 { x, y } = rect.min;
+    let Vec2 { x: w, y: h } = rect.size();
+    format!("[{x:.1} {y:.1}] size [{w:.1} {h:.1}]")
+}
+
+#[test]
+fn test_inspector() {
+    use crate::{Event, PointerButton, RawInput, vec2};
+
+    let ctx = Context::default();
+    ctx.options_mut(|o| o.inspect_with_keyboard = true);
+    let key = |key: Key, modifiers: Modifiers| Event::Key {
+        key,
+        physical_key: None,
+        pressed: true,
+        repeat: false,
+        modifiers,
+    };
+    let click = |pos: Pos2| {
+        [true, false].map(|pressed| Event::PointerButton {
+            pos,
+            button: PointerButton::Primary,
+            pressed,
+            modifiers: Modifiers::NONE,
+        })
+    };
+
+    let run = |events: Vec<Event>| {
+        let input = RawInput {
+            screen_rect: Some(Rect::from_min_size(Pos2::ZERO, vec2(800.0, 600.0))),
+            events,
+            ..Default::default()
+        };
+        let mut button = None;
+        let mut escape_reached_ui = false;
+        let _ = ctx.run(input, |ctx| {
+            crate::CentralPanel::default().show(ctx, |ui| {
+                button = Some(ui.button("Button"));
+                escape_reached_ui = ui.input(|i| i.key_pressed(Key::Escape));
+            });
+        });
+        (button.unwrap(), escape_reached_ui)
+    };
+
+    let (button, _) = run(vec![]);
+    let center = button.rect.center();
+    let mut events = vec![Event::PointerMoved(center)];
+    events.extend(click(center));
+    assert!(run(events.clone()).0.clicked(), "Sanity check");
+
+    let toggle = kb_shortcuts::TOGGLE_INSPECTOR;
+    run(vec![key(toggle.logical_key, toggle.modifiers)]);
+    assert!(is_active(&ctx));
+
+    assert!(
+        !run(events).0.clicked(),
+        "Clicks should not reach widgets while inspecting"
+    );
+    assert!(is_active(&ctx));
+
+    let (_, escape_reached_ui) = run(vec![key(Key::Escape, Modifiers::NONE)]);
+    assert!(!is_active(&ctx));
+    assert!(!escape_reached_ui, "The inspector should consume Escape");
+}
@@ -14,6 +14,9 @@ pub struct InteractionSnapshot {
     /// The widget that got clicked this frame.
     pub clicked: Option<Id>,
 
+    /// This widget was pressed and held, see [`crate::Response::long_pressed`].
+    pub long_pressed: Option<Id>,
+
     /// This widget was long-pressed on a touch screen,
     /// so trigger a secondary click on it (context menu).
     pub long_touched: Option<Id>,
@@ -60,6 +63,7 @@ impl InteractionSnapshot {
     pub fn ui(&self, ui: &mut crate::Ui) {
         let Self {
             clicked,
+            long_pressed,
             long_touched,
             drag_started,
             dragged,
@@ -79,6 +83,10 @@ impl InteractionSnapshot {
             id_ui(ui, clicked);
             ui.end_row();
 
+            ui.label("long_pressed");
+            id_ui(ui, long_pressed);
+            ui.end_row();
+
             ui.label("long_touched");
             id_ui(ui, long_touched);
             ui.end_row();
@@ -132,6 +140,7 @@ pub(crate) fn interact(
 
     let mut clicked = None;
     let mut dragged = prev_snapshot.dragged;
+    let mut long_pressed = None;
     let mut long_touched = None;
 
     if input.key_pressed(Key::Escape) {
@@ -140,17 +149,22 @@ pub(crate) fn interact(
         interaction.potential_drag_id = None;
     }
 
-    if input.is_long_touch() {
-        // We implement "press-and-hold for context menu" on touch screens here
+    if input.pointer.is_long_press() {
         if let Some(widget) = interaction
             .potential_click_id
             .and_then(|id| widgets.get(id))
         {
-            dragged = None;
-            clicked = Some(widget.id);
-            long_touched = Some(widget.id);
+            long_pressed = Some(widget.id);
+            // Releasing after a long-press is not a click:
             interaction.potential_click_id = None;
-            interaction.potential_drag_id = None;
+
+            if input.is_long_touch() {
+                // We implement "press-and-hold for context menu" on touch screens here
+                dragged = None;
+                clicked = Some(widget.id);
+                long_touched = Some(widget.id);
+                interaction.potential_drag_id = None;
+            }
         }
     }
 
@@ -289,6 +303,7 @@ pub(crate) fn interact(
 
     InteractionSnapshot {
         clicked,
+        long_pressed,
         long_touched,
         drag_started,
         dragged,
@@ -153,6 +153,7 @@ impl Widget for &mut epaint::TessellationOptions {
                 epsilon: _,
                 parallel_tessellation,
                 validate_meshes,
+                color_vision,
             } = self;
 
             ui.horizontal(|ui| {
@@ -201,6 +202,19 @@ impl Widget for &mut epaint::TessellationOptions {
                 ui.checkbox(debug_ignore_clip_rects, "Ignore clip rectangles");
                 ui.checkbox(debug_paint_clip_rects, "Paint clip rectangles");
                 ui.checkbox(debug_paint_text_rects, "Paint text bounds");
+
+                ui.horizontal(|ui| {
+                    ui.label("Simulate color vision");
+                    crate::ComboBox::from_id_salt("color_vision")
+                        .selected_text(color_vision.to_string())
+                        .show_ui(ui, |ui| {
+                            for vision in epaint::ColorVision::ALL {
+                                ui.selectable_value(color_vision, vision, vision.to_string());
+                            }
+                        });
+                })
+                .response
+                .on_hover_text("Check that the colors can be told apart by people with color blindness.");
             });
         })
         .response
@@ -0,0 +1,195 @@
+use crate::{Rangef, Rect, Vec2};
+
+/// Kinetic panning, shared by everything that can be dragged around,
+/// like [`crate::ScrollArea`] and [`crate::Scene`].
+///
+/// When a drag is released the content keeps moving with the speed of the pointer,
+/// slowing down because of friction ("inertia").
+/// Content can also be pulled a bit past its bounds, with increasing resistance,
+/// and springs back when let go ("rubber-banding").
+///
+/// This only does the math. The widget keeps the offset and velocity in its own state,
+/// and calls [`Self::drag`] while the content is dragged and [`Self::step`] every other frame.
+/// Offsets and velocities are in the same units, e.g. points and points per second.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct KineticPan {
+    /// How quickly the content slows down after being flung, in points per second squared.
+    pub friction: f32,
+
+    /// Below this speed (points per second) the content stops.
+    pub stop_speed: f32,
+
+    /// How far past the bounds the content can be pulled, in points.
+    ///
+    /// Zero means the content stops hard at the bounds.
+    pub rubber_band: f32,
+
+    /// About how long it takes for the content to spring back to the bounds, in seconds.
+    pub spring_back_time: f32,
+}
+
+impl Default for KineticPan {
+    fn default() -> Self {
+        Self {
+            friction: 1000.0,
+            stop_speed: 20.0,
+            rubber_band: 64.0,
+            spring_back_time: 0.3,
+        }
+    }
+}
+
+impl KineticPan {
+    /// No inertia and no rubber-banding: the content only moves while dragged.
+    pub const OFF: Self = Self {
+        friction: f32::INFINITY,
+        stop_speed: f32::INFINITY,
+        rubber_band: 0.0,
+        spring_back_time: 0.0,
+    };
+
+    /// The new offset after dragging the content by `delta`.
+    ///
+    /// Past the bounds, the content moves less and less the further out it is,
+    /// but never more than [`Self::rubber_band`].
+    pub fn drag(&self, offset: f32, delta: f32, bounds: Rangef) -> f32 {
+        let bounds = Rangef::new(bounds.min, bounds.max.max(bounds.min));
+        if self.rubber_band <= 0.0 {
+            return bounds.clamp(offset + delta);
+        }
+        let bound = bounds.clamp(offset);
+        let overshoot = (offset - bound).clamp(-0.99 * self.rubber_band, 0.99 * self.rubber_band);
+        // Undo the resistance to get how far the pointer has moved past the bound:
+        let unstretched =
+            bound + overshoot * self.rubber_band / (self.rubber_band - overshoot.abs());
+        let offset = unstretched + delta;
+        let bound = bounds.clamp(offset);
+        let overshoot = offset - bound;
+        bound + overshoot * self.rubber_band / (self.rubber_band + overshoot.abs())
+    }
+
+    /// Move the content after it has been released.
+    ///
+    /// Set `velocity` to the velocity of the pointer (in the direction of the offset)
+    /// when the drag is released, and then call this every frame the content isn't dragged.
+    ///
+    /// Returns `true` while the content is moving, i.e. when you should request a repaint.
+    pub fn step(&self, offset: &mut f32, velocity: &mut f32, bounds: Rangef, dt: f32) -> bool {
+        let bounds = Rangef::new(bounds.min, bounds.max.max(bounds.min));
+        let bound = bounds.clamp(*offset);
+        let overshoot = *offset - bound;
+
+        if overshoot == 0.0 {
+            // Inertia:
+            let friction = self.friction * dt;
+            if friction > velocity.abs() || velocity.abs() < self.stop_speed {
+                *velocity = 0.0;
+                return false;
+            }
+            *velocity -= friction * velocity.signum();
+            *offset += *velocity * dt;
+            if self.rubber_band <= 0.0 && !bounds.contains(*offset) {
+                *offset = bounds.clamp(*offset);
+                *velocity = 0.0;
+            }
+            return true;
+        }
+
+        if self.rubber_band <= 0.0 || self.spring_back_time <= 0.0 {
+            *offset = bound;
+            *velocity = 0.0;
+            return false;
+        }
+
+        // A critically damped spring pulls the content back to the bound:
+        let omega = 4.0 / self.spring_back_time;
+        if 0.0 < *velocity * overshoot {
+            // Still moving away from the bounds:
+            *velocity -= (omega * omega * overshoot + 2.0 * omega * *velocity) * dt;
+            if *velocity * overshoot < 0.0 {
+                *velocity = 0.0;
+            }
+            let overshoot = (overshoot + *velocity * dt).clamp(-self.rubber_band, self.rubber_band);
+            *offset = bound + overshoot;
+        } else {
+            *velocity = 0.0;
+            let overshoot = overshoot * (-omega * dt).exp();
+            *offset = if overshoot.abs() < 0.5 {
+                bound
+            } else {
+                bound + overshoot
+            };
+        }
+        true
+    }
+
+    /// [`Self::drag`] in two dimensions.
+    pub fn drag_2d(&self, offset: Vec2, delta: Vec2, bounds: Rect) -> Vec2 {
+        Vec2::new(
+            self.drag(offset.x, delta.x, bounds.x_range()),
+            self.drag(offset.y, delta.y, bounds.y_range()),
+        )
+    }
+
+    /// [`Self::step`] in two dimensions.
+    pub fn step_2d(&self, offset: &mut Vec2, velocity: &mut Vec2, bounds: Rect, dt: f32) -> bool {
+        let x = self.step(&mut offset.x, &mut velocity.x, bounds.x_range(), dt);
+        let y = self.step(&mut offset.y, &mut velocity.y, bounds.y_range(), dt);
+        x || y
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_kinetic_pan() {
+        let kinetic = KineticPan::default();
+        let bounds = Rangef::new(0.0, 100.0);
+        let dt = 1.0 / 60.0;
+
+        // Dragging inside the bounds follows the pointer exactly:
+        assert_eq!(kinetic.drag(50.0, 20.0, bounds), 70.0);
+
+        // Past the bounds there is more and more resistance:
+        let mut offset = 90.0;
+        for _ in 0..100 {
+            offset = kinetic.drag(offset, 10.0, bounds);
+        }
+        assert!(100.0 < offset && offset < 100.0 + kinetic.rubber_band);
+        // Dragging back the same distance gets back to where we were:
+        for _ in 0..100 {
+            offset = kinetic.drag(offset, -10.0, bounds);
+        }
+        assert!((offset - 90.0).abs() < 0.1, "{offset}");
+
+        // A fling slows down and stops:
+        let (mut offset, mut velocity) = (0.0, 500.0);
+        let mut frames = 0;
+        while kinetic.step(&mut offset, &mut velocity, Rangef::EVERYTHING, dt) {
+            frames += 1;
+            assert!(frames < 60, "Should stop within a second");
+        }
+        assert!(100.0 < offset && offset < 200.0, "{offset}");
+
+        // Flung past the bounds it springs back:
+        let (mut offset, mut velocity) = (90.0, 2000.0);
+        let mut max_offset: f32 = offset;
+        let mut frames = 0;
+        while kinetic.step(&mut offset, &mut velocity, bounds, dt) {
+            max_offset = max_offset.max(offset);
+            frames += 1;
+            assert!(frames < 120, "Should settle within two seconds");
+        }
+        assert!(100.0 < max_offset && max_offset <= 100.0 + kinetic.rubber_band);
+        assert_eq!(offset, 100.0);
+
+        // Without rubber-banding it stops at the bounds:
+        let (mut offset, mut velocity) = (90.0, 2000.0);
+        KineticPan::OFF.step(&mut offset, &mut velocity, bounds, dt);
+        assert_eq!((offset, velocity), (90.0, 0.0));
+        assert_eq!(KineticPan::OFF.drag(90.0, 20.0, bounds), 100.0);
+    }
+}
@@ -2,7 +2,7 @@
 //! are sometimes painted behind or in front of other things.
 
 use crate::{Id, IdMap, Rect, ahash, epaint};
-use epaint::{ClippedShape, Shape, emath::TSTransform};
+use epaint::{BlendMode, ClippedShape, Shape, emath::TSTransform};
 
 /// Different layer categories
 #[derive(Clone, Copy, Debug, Hash, Eq, PartialEq, Ord, PartialOrd)]
@@ -132,16 +132,20 @@ impl PaintList {
     #[inline(always)]
     pub fn add(&mut self, clip_rect: Rect, shape: Shape) -> ShapeIdx {
         let idx = self.next_idx();
-        self.0.push(ClippedShape { clip_rect, shape });
+        self.0.push(ClippedShape {
+            clip_rect,
+            shape,
+            blend_mode: BlendMode::Normal,
+        });
         idx
     }
 
     pub fn extend<I: IntoIterator<Item = Shape>>(&mut self, clip_rect: Rect, shapes: I) {
-        self.0.extend(
-            shapes
-                .into_iter()
-                .map(|shape| ClippedShape { clip_rect, shape }),
-        );
+        self.0.extend(shapes.into_iter().map(|shape| ClippedShape {
+            clip_rect,
+            shape,
+            blend_mode: BlendMode::Normal,
+        }));
     }
 
     /// Modify an existing [`Shape`].
@@ -159,7 +163,11 @@ impl PaintList {
             return;
         }
 
-        self.0[idx.0] = ClippedShape { clip_rect, shape };
+        self.0[idx.0] = ClippedShape {
+            clip_rect,
+            shape,
+            blend_mode: BlendMode::Normal,
+        };
     }
 
     /// Set the given shape to be empty (a `Shape::Noop`).
@@ -175,7 +183,10 @@ impl PaintList {
 
     /// Transform each [`Shape`] and clip rectangle by this much, in-place
     pub fn transform(&mut self, transform: TSTransform) {
-        for ClippedShape { clip_rect, shape } in &mut self.0 {
+        for ClippedShape {
+            clip_rect, shape, ..
+        } in &mut self.0
+        {
             *clip_rect = transform.mul_rect(*clip_rect);
             shape.transform(transform);
         }
@@ -183,12 +194,31 @@ impl PaintList {
 
     /// Transform each [`Shape`] and clip rectangle in range by this much, in-place
     pub fn transform_range(&mut self, start: ShapeIdx, end: ShapeIdx, transform: TSTransform) {
-        for ClippedShape { clip_rect, shape } in &mut self.0[start.0..end.0] {
+        for ClippedShape {
+            clip_rect, shape, ..
+        } in &mut self.0[start.0..end.0]
+        {
             *clip_rect = transform.mul_rect(*clip_rect);
             shape.transform(transform);
         }
     }
 
+    /// Multiply the opacity of each [`Shape`] by this much, in-place
+    pub fn multiply_opacity(&mut self, opacity: f32) {
+        if opacity < 1.0 {
+            for ClippedShape { shape, .. } in &mut self.0 {
+                crate::painter::multiply_opacity(shape, opacity.max(0.0));
+            }
+        }
+    }
+
+    /// Set how each [`Shape`] is blended with what is painted behind it, in-place
+    pub fn set_blend_mode(&mut self, blend_mode: BlendMode) {
+        for clipped_shape in &mut self.0 {
+            clipped_shape.blend_mode = blend_mode;
+        }
+    }
+
     /// Read-only access to all held shapes.
     pub fn all_entries(&self) -> impl ExactSizeIterator<Item = &ClippedShape> {
         self.0.iter()
@@ -212,6 +242,15 @@ impl GraphicLayers {
         self.0[layer_id.order as usize].get(&layer_id.id)
     }
 
+    /// How many shapes have been added to all layers so far.
+    pub fn num_shapes(&self) -> usize {
+        self.0
+            .iter()
+            .flat_map(|order_map| order_map.values())
+            .map(|list| list.0.len())
+            .sum()
+    }
+
     /// Get the [`PaintList`] for the given [`LayerId`].
     pub fn get_mut(&mut self, layer_id: LayerId) -> Option<&mut PaintList> {
         self.0[layer_id.order as usize].get_mut(&layer_id.id)
@@ -221,6 +260,8 @@ impl GraphicLayers {
         &mut self,
         area_order: &[LayerId],
         to_global: &ahash::HashMap<LayerId, TSTransform>,
+        opacity: impl Fn(LayerId) -> f32,
+        blend_mode: impl Fn(LayerId) -> BlendMode,
     ) -> Vec<ClippedShape> {
         profiling::function_scope!();
 
@@ -243,6 +284,8 @@ impl GraphicLayers {
                                 clipped_shape.transform(*to_global);
                             }
                         }
+                        list.multiply_opacity(opacity(*layer_id));
+                        list.set_blend_mode(blend_mode(*layer_id));
                         all_shapes.append(&mut list.0);
                     }
                 }
@@ -260,6 +303,8 @@ impl GraphicLayers {
                         clipped_shape.transform(*to_global);
                     }
                 }
+                list.multiply_opacity(opacity(layer_id));
+                list.set_blend_mode(blend_mode(layer_id));
 
                 all_shapes.append(&mut list.0);
             }
@@ -412,13 +412,16 @@ mod context;
 mod data;
 pub mod debug_text;
 mod drag_and_drop;
+mod frame_cost;
 pub(crate) mod grid;
 pub mod gui_zoom;
 mod hit_test;
 mod id;
 mod input_state;
+pub mod inspector;
 mod interaction;
 pub mod introspection;
+mod kinetic;
 pub mod layers;
 mod layout;
 pub mod load;
@@ -429,8 +432,10 @@ pub mod os;
 mod painter;
 mod pass_state;
 pub(crate) mod placer;
+mod reorder;
 pub mod response;
 mod sense;
+mod sound;
 pub mod style;
 pub mod text_selection;
 mod ui;
@@ -465,7 +470,7 @@ pub use emath::{
     remap_clamp, vec2,
 };
 pub use epaint::{
-    ClippedPrimitive, ColorImage, CornerRadius, ImageData, Margin, Mesh, PaintCallback,
+    BlendMode, ClippedPrimitive, ColorImage, CornerRadius, ImageData, Margin, Mesh, PaintCallback,
     PaintCallbackInfo, Shadow, Shape, Stroke, StrokeKind, TextureHandle, TextureId, mutex,
     text::{FontData, FontDefinitions, FontFamily, FontId, FontTweak},
     textures::{TextureFilter, TextureOptions, TextureWrapMode, TexturesDelta},
@@ -474,8 +479,8 @@ pub use epaint::{
 pub mod text {
     pub use crate::text_selection::CCursorRange;
     pub use epaint::text::{
-        FontData, FontDefinitions, FontFamily, Fonts, Galley, LayoutJob, LayoutSection, TAB_SIZE,
-        TextFormat, TextWrapping, cursor::CCursor,
+        FontData, FontDefinitions, FontFamily, Fonts, Galley, GalleyCacheStats, InternedStr,
+        LayoutJob, LayoutSection, TAB_SIZE, TextFormat, TextWrapping, cursor::CCursor,
     };
 }
 
@@ -487,23 +492,27 @@ pub use self::{
         Key, UserData,
         input::*,
         output::{
-            self, CursorIcon, FullOutput, OpenUrl, OutputCommand, PlatformOutput,
+            self, CursorIcon, FullOutput, HapticFeedback, OpenUrl, OutputCommand, PlatformOutput,
             UserAttentionType, WidgetInfo,
         },
     },
     drag_and_drop::DragAndDrop,
     epaint::text::TextWrapMode,
+    frame_cost::{FrameCost, ScopeCost},
     grid::Grid,
     id::{Id, IdMap},
     input_state::{InputOptions, InputState, MultiTouchInfo, PointerState, SurrenderFocusOn},
+    kinetic::KineticPan,
     layers::{LayerId, Order},
     layout::*,
     load::SizeHint,
     memory::{Memory, Options, Theme, ThemePreference},
     painter::Painter,
+    reorder::ListMove,
     response::{InnerResponse, Response},
     sense::Sense,
-    style::{FontSelection, Spacing, Style, TextStyle, Visuals},
+    sound::UiSound,
+    style::{Density, FontSelection, Spacing, Style, TextStyle, Visuals},
     text::{Galley, TextFormat},
     ui::Ui,
     ui_builder::UiBuilder,
@@ -678,6 +687,9 @@ pub enum WidgetType {
 
     Window,
 
+    /// A tab in a [`TabBar`].
+    Tab,
+
     /// If you cannot fit any of the above slots.
     ///
     /// If this is something you think should be added, file an issue.
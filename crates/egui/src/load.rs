@@ -410,6 +410,17 @@ pub trait ImageLoader {
     /// - [`LoadError::Loading`] if the loading process failed.
     fn load(&self, ctx: &Context, uri: &str, size_hint: SizeHint) -> ImageLoadResult;
 
+    /// A low-resolution version of an image that is still [`ImagePoll::Pending`],
+    /// to show until it has loaded.
+    ///
+    /// Its [`ColorImage::source_size`] should be the size of the full image.
+    ///
+    /// The default implementation returns `None`.
+    fn load_preview(&self, uri: &str) -> Option<Arc<ColorImage>> {
+        let _ = uri;
+        None
+    }
+
     /// Forget the given `uri`.
     ///
     /// If `uri` is cached, it should be evicted from cache,
@@ -560,6 +571,22 @@ pub trait TextureLoader {
         size_hint: SizeHint,
     ) -> TextureLoadResult;
 
+    /// A low-resolution version of a texture that is still [`TexturePoll::Pending`],
+    /// to show until it has loaded.
+    ///
+    /// Its [`SizedTexture::size`] should be the size of the full image.
+    ///
+    /// The default implementation returns `None`.
+    fn load_preview(
+        &self,
+        ctx: &Context,
+        uri: &str,
+        texture_options: TextureOptions,
+    ) -> Option<SizedTexture> {
+        let _ = (ctx, uri, texture_options);
+        None
+    }
+
     /// Forget the given `uri`.
     ///
     /// If `uri` is cached, it should be evicted from cache,
@@ -3,8 +3,8 @@ use std::sync::atomic::{AtomicU64, Ordering::Relaxed};
 use emath::Vec2;
 
 use super::{
-    BytesLoader as _, Context, HashMap, ImagePoll, Mutex, SizeHint, SizedTexture, TextureHandle,
-    TextureLoadResult, TextureLoader, TextureOptions, TexturePoll,
+    Arc, BytesLoader as _, ColorImage, Context, HashMap, ImagePoll, Mutex, SizeHint, SizedTexture,
+    TextureHandle, TextureLoadResult, TextureLoader, TextureOptions, TexturePoll,
 };
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
@@ -29,6 +29,9 @@ struct Entry {
 pub struct DefaultTextureLoader {
     pass_index: AtomicU64,
     cache: Mutex<HashMap<PrimaryKey, Bucket>>,
+
+    /// Textures of the previews of images that are still loading, and the image each was made from.
+    previews: Mutex<HashMap<PrimaryKey, (Arc<ColorImage>, TextureHandle)>>,
 }
 
 impl TextureLoader for DefaultTextureLoader {
@@ -55,13 +58,12 @@ impl TextureLoader for DefaultTextureLoader {
             None
         };
 
+        let key = PrimaryKey {
+            uri: uri.to_owned(),
+            texture_options,
+        };
         let mut cache = self.cache.lock();
-        let bucket = cache
-            .entry(PrimaryKey {
-                uri: uri.to_owned(),
-                texture_options,
-            })
-            .or_default();
+        let bucket = cache.entry(key.clone()).or_default();
 
         if let Some(texture) = bucket.get(&svg_size_hint) {
             texture
@@ -70,9 +72,13 @@ impl TextureLoader for DefaultTextureLoader {
             let texture = SizedTexture::new(texture.handle.id(), texture.source_size);
             Ok(TexturePoll::Ready { texture })
         } else {
-            match ctx.try_load_image(uri, size_hint)? {
+            let poll = ctx.try_load_image(uri, size_hint).inspect_err(|_| {
+                self.previews.lock().remove(&key);
+            })?;
+            match poll {
                 ImagePoll::Pending { size } => Ok(TexturePoll::Pending { size }),
                 ImagePoll::Ready { image } => {
+                    self.previews.lock().remove(&key);
                     let source_size = image.source_size;
                     let handle = ctx.load_texture(uri, image, texture_options);
                     let texture = SizedTexture::new(handle.id(), source_size);
@@ -101,11 +107,40 @@ impl TextureLoader for DefaultTextureLoader {
         }
     }
 
+    fn load_preview(
+        &self,
+        ctx: &Context,
+        uri: &str,
+        texture_options: TextureOptions,
+    ) -> Option<SizedTexture> {
+        let image = ctx.try_load_image_preview(uri)?;
+        let key = PrimaryKey {
+            uri: uri.to_owned(),
+            texture_options,
+        };
+        let mut previews = self.previews.lock();
+        let (_, handle) = previews
+            .entry(key)
+            .and_modify(|(previous, handle)| {
+                if !Arc::ptr_eq(previous, &image) {
+                    // A more refined preview:
+                    handle.set(image.clone(), texture_options);
+                    *previous = image.clone();
+                }
+            })
+            .or_insert_with(|| {
+                let handle = ctx.load_texture(uri, image.clone(), texture_options);
+                (image.clone(), handle)
+            });
+        Some(SizedTexture::new(handle.id(), image.source_size))
+    }
+
     fn forget(&self, uri: &str) {
         #[cfg(feature = "log")]
         log::trace!("forget {uri:?}");
 
         self.cache.lock().retain(|key, _value| key.uri != uri);
+        self.previews.lock().retain(|key, _value| key.uri != uri);
     }
 
     fn forget_all(&self) {
@@ -113,6 +148,7 @@ impl TextureLoader for DefaultTextureLoader {
         log::trace!("forget all");
 
         self.cache.lock().clear();
+        self.previews.lock().clear();
     }
 
     fn end_pass(&self, pass_index: u64) {
@@ -140,7 +176,13 @@ impl TextureLoader for DefaultTextureLoader {
                     .map(|texture| texture.handle.byte_size())
                     .sum::<usize>()
             })
-            .sum()
+            .sum::<usize>()
+            + self
+                .previews
+                .lock()
+                .values()
+                .map(|(_, handle)| handle.byte_size())
+                .sum::<usize>()
     }
 }
 
@@ -7,7 +7,7 @@ use epaint::emath::TSTransform;
 
 use crate::{
     EventFilter, Id, IdMap, LayerId, Order, Pos2, Rangef, RawInput, Rect, Style, Vec2, ViewportId,
-    ViewportIdMap, ViewportIdSet, area, vec2,
+    ViewportIdMap, ViewportIdSet, area, pos2, vec2,
 };
 
 mod theme;
@@ -233,6 +233,15 @@ pub struct Options {
     #[cfg_attr(feature = "serde", serde(skip))]
     pub zoom_with_keyboard: bool,
 
+    /// If `true`, the widget inspector can be toggled by pressing
+    /// [`crate::inspector::kb_shortcuts::TOGGLE_INSPECTOR`] (Cmd+Alt+I).
+    ///
+    /// See [`crate::inspector`] for more.
+    ///
+    /// This is `true` by default in debug builds.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub inspect_with_keyboard: bool,
+
     /// Controls the tessellator.
     pub tessellation_options: epaint::TessellationOptions,
 
@@ -299,6 +308,23 @@ pub struct Options {
     ///
     /// Default is `false`.
     pub reduce_texture_memory: bool,
+
+    /// How many seconds of CPU time each frame may take, e.g. `1.0 / 60.0`.
+    ///
+    /// When set, egui compares the [`crate::RawInput::frame_cost`] reported by the integration
+    /// against this, and counts what each panel and window paints,
+    /// see [`crate::Context::frame_cost`].
+    ///
+    /// Default is `None`.
+    pub frame_budget: Option<f32>,
+
+    /// If the [`Self::frame_budget`] is exceeded, cut some corners to get back within it.
+    ///
+    /// While degraded, egui turns off feathering (anti-aliasing) and animates at a lower frame rate.
+    /// Expensive widgets can check [`crate::Context::is_degraded`] to do less work too.
+    ///
+    /// Default is `true`.
+    pub degrade_over_budget: bool,
 }
 
 impl Default for Options {
@@ -311,6 +337,7 @@ impl Default for Options {
             system_theme: None,
             zoom_factor: 1.0,
             zoom_with_keyboard: true,
+            inspect_with_keyboard: cfg!(debug_assertions),
             tessellation_options: Default::default(),
             repaint_on_widget_change: false,
             max_passes: NonZeroUsize::new(2).unwrap(),
@@ -321,6 +348,9 @@ impl Default for Options {
             // Input:
             input_options: Default::default(),
             reduce_texture_memory: false,
+
+            frame_budget: None,
+            degrade_over_budget: true,
         }
     }
 }
@@ -367,6 +397,7 @@ impl Options {
             system_theme: _,
             zoom_factor: _, // TODO(emilk)
             zoom_with_keyboard,
+            inspect_with_keyboard,
             tessellation_options,
             repaint_on_widget_change,
             max_passes,
@@ -375,6 +406,8 @@ impl Options {
             warn_on_id_clash,
             input_options,
             reduce_texture_memory,
+            frame_budget,
+            degrade_over_budget,
         } = self;
 
         use crate::Widget as _;
@@ -398,9 +431,31 @@ impl Options {
                     "Zoom with keyboard (Cmd +, Cmd -, Cmd 0)",
                 );
 
+                ui.checkbox(
+                    inspect_with_keyboard,
+                    "Toggle widget inspector with keyboard (Cmd Alt I)",
+                );
+
                 ui.checkbox(warn_on_id_clash, "Warn if two widgets have the same Id");
 
                 ui.checkbox(reduce_texture_memory, "Reduce texture memory");
+
+                ui.horizontal(|ui| {
+                    let mut has_budget = frame_budget.is_some();
+                    ui.checkbox(&mut has_budget, "Frame budget:");
+                    let mut ms = 1e3 * frame_budget.unwrap_or(1.0 / 60.0);
+                    ui.add_enabled(
+                        has_budget,
+                        crate::DragValue::new(&mut ms)
+                            .range(1.0..=100.0)
+                            .suffix(" ms"),
+                    );
+                    *frame_budget = has_budget.then_some(ms / 1e3);
+                });
+                ui.add_enabled(
+                    frame_budget.is_some(),
+                    crate::Checkbox::new(degrade_over_budget, "Degrade when over budget"),
+                );
             });
 
         CollapsingHeader::new("🎑 Style")
@@ -1012,6 +1067,13 @@ impl OpenPopup {
     }
 }
 
+impl Memory {
+    /// The popup that is open in the current viewport, if any.
+    pub(crate) fn open_popup_id(&self) -> Option<Id> {
+        self.popups.get(&self.viewport_id).map(|popup| popup.id)
+    }
+}
+
 /// ## Deprecated popup API
 /// Use [`crate::Popup`] instead.
 impl Memory {
@@ -1155,6 +1217,22 @@ pub struct Areas {
     ///
     /// The parent sublayer is moved directly above the child sublayers in the ordering.
     sublayers: ahash::HashMap<LayerId, HashSet<LayerId>>,
+
+    /// The parts of each layer that were painted fully opaque, in global coordinates.
+    ///
+    /// Used to skip the contents of windows that are completely covered by other windows.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    opaque_rects_last_frame: ahash::HashMap<LayerId, Rect>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    opaque_rects_current_frame: ahash::HashMap<LayerId, Rect>,
+
+    /// Layers whose contents were skipped this frame because they were covered, and their rects.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    covered_current_frame: Vec<(LayerId, Rect)>,
+
+    /// Set at the end of the frame if a layer that was skipped turned out not to be covered after all.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    uncovered_skipped_layer: bool,
 }
 
 impl Areas {
@@ -1217,6 +1295,31 @@ impl Areas {
         None
     }
 
+    /// Mark this part of the layer as painted fully opaque this frame, in global coordinates.
+    ///
+    /// See [`Self::is_covered`].
+    pub(crate) fn set_opaque_rect(&mut self, layer_id: LayerId, rect: Rect) {
+        self.opaque_rects_current_frame.insert(layer_id, rect);
+    }
+
+    /// Is `rect` of the given layer completely hidden behind the opaque parts of the layers above it,
+    /// as painted last frame?
+    pub(crate) fn is_covered(&self, layer_id: LayerId, rect: Rect) -> bool {
+        is_covered(&self.order, &self.opaque_rects_last_frame, layer_id, rect)
+    }
+
+    /// Remember that the contents of this layer were skipped because [`Self::is_covered`].
+    ///
+    /// If it turns out not to be covered at the end of the frame, it is shown again in the next pass.
+    pub(crate) fn set_covered(&mut self, layer_id: LayerId, rect: Rect) {
+        self.covered_current_frame.push((layer_id, rect));
+    }
+
+    /// Did a layer skip its contents even though it was not covered at the end of the last frame?
+    pub(crate) fn take_uncovered_skipped_layer(&mut self) -> bool {
+        std::mem::take(&mut self.uncovered_skipped_layer)
+    }
+
     pub fn visible_last_frame(&self, layer_id: &LayerId) -> bool {
         self.visible_areas_last_frame.contains(layer_id)
     }
@@ -1348,9 +1451,76 @@ impl Areas {
             .enumerate()
             .map(|(i, id)| (*id, i))
             .collect();
+
+        self.uncovered_skipped_layer =
+            self.covered_current_frame
+                .drain(..)
+                .any(|(layer_id, rect)| {
+                    !is_covered(
+                        &self.order,
+                        &self.opaque_rects_current_frame,
+                        layer_id,
+                        rect,
+                    )
+                });
+        std::mem::swap(
+            &mut self.opaque_rects_last_frame,
+            &mut self.opaque_rects_current_frame,
+        );
+        self.opaque_rects_current_frame.clear();
     }
 }
 
+/// Is `rect` completely hidden behind the `opaque_rects` of the layers above `layer_id` in `order`?
+fn is_covered(
+    order: &[LayerId],
+    opaque_rects: &ahash::HashMap<LayerId, Rect>,
+    layer_id: LayerId,
+    rect: Rect,
+) -> bool {
+    // Give up on complicated overlaps instead of spending a lot of time on them:
+    const MAX_PIECES: usize = 64;
+
+    let Some(index) = order.iter().position(|layer| *layer == layer_id) else {
+        return false;
+    };
+
+    let mut uncovered = vec![rect];
+    for opaque_rect in order[index + 1..]
+        .iter()
+        .filter_map(|layer| opaque_rects.get(layer))
+    {
+        uncovered = uncovered
+            .into_iter()
+            .flat_map(|piece| subtract_rect(piece, *opaque_rect))
+            .collect();
+        if uncovered.is_empty() {
+            return true;
+        }
+        if MAX_PIECES < uncovered.len() {
+            return false;
+        }
+    }
+    false
+}
+
+/// The parts of `rect` outside of `hole`, as up to four rectangles.
+fn subtract_rect(rect: Rect, hole: Rect) -> Vec<Rect> {
+    let hole = rect.intersect(hole);
+    if !hole.is_positive() {
+        return vec![rect];
+    }
+    [
+        Rect::from_min_max(rect.min, pos2(rect.max.x, hole.min.y)),
+        Rect::from_min_max(pos2(rect.min.x, hole.max.y), rect.max),
+        Rect::from_min_max(pos2(rect.min.x, hole.min.y), pos2(hole.min.x, hole.max.y)),
+        Rect::from_min_max(pos2(hole.max.x, hole.min.y), pos2(rect.max.x, hole.max.y)),
+    ]
+    .into_iter()
+    .filter(|piece| piece.is_positive())
+    .collect()
+}
+
 // ----------------------------------------------------------------------------
 
 #[test]
@@ -542,6 +542,14 @@ impl Painter {
         self.fonts(|f| f.layout(text, font_id, color, f32::INFINITY))
     }
 
+    /// The size the given text would have if laid out with [`Self::layout_no_wrap`].
+    ///
+    /// See also [`Fonts::font_metrics`].
+    #[inline]
+    pub fn measure_text(&self, text: String, font_id: FontId) -> Vec2 {
+        self.fonts(|f| f.measure_text(text, font_id))
+    }
+
     /// Lay out this text layut job in a galley.
     ///
     /// Paint the results with [`Self::galley`].
@@ -593,7 +601,7 @@ fn tint_shape_towards(shape: &mut Shape, target: Color32) {
     });
 }
 
-fn multiply_opacity(shape: &mut Shape, opacity: f32) {
+pub(crate) fn multiply_opacity(shape: &mut Shape, opacity: f32) {
     epaint::shape_transform::adjust_colors(shape, move |color| {
         if *color != Color32::PLACEHOLDER {
             *color = color.gamma_multiply(opacity);
@@ -29,7 +29,7 @@ pub struct PerWidgetTooltipState {
     pub tooltip_count: usize,
 }
 
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug)]
 pub struct PerLayerState {
     /// Is there any open popup (menus, combo-boxes, etc)?
     ///
@@ -41,6 +41,27 @@ pub struct PerLayerState {
     /// Only one widget per layer may show a tooltip.
     /// But if a tooltip contains a tooltip, you can show a tooltip on top of a tooltip.
     pub widget_with_tooltip: Option<Id>,
+
+    /// Multiply the opacity of everything painted on the layer with this.
+    ///
+    /// Set with [`crate::Context::multiply_layer_opacity`].
+    pub opacity: f32,
+
+    /// How everything painted on the layer is blended with what is behind it.
+    ///
+    /// Set with [`crate::Context::set_layer_blend_mode`].
+    pub blend_mode: epaint::BlendMode,
+}
+
+impl Default for PerLayerState {
+    fn default() -> Self {
+        Self {
+            open_popups: Default::default(),
+            widget_with_tooltip: None,
+            opacity: 1.0,
+            blend_mode: Default::default(),
+        }
+    }
 }
 
 #[derive(Clone, Debug)]
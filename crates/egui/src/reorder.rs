@@ -0,0 +1,297 @@
+use crate::{
+    Align, CursorIcon, EventFilter, Id, Key, LayerId, Layout, Modifiers, Order, Rect, Response,
+    Sense, Shape, Ui, UiBuilder, Vec2, WidgetInfo, WidgetType, pos2, vec2,
+};
+
+/// A move made by the user in [`Ui::reorderable_list`]: the item at `from` is now at `to`.
+///
+/// The items in between have shifted by one to make room.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ListMove {
+    pub from: usize,
+    pub to: usize,
+}
+
+impl ListMove {
+    /// Make the same move in another slice, e.g. one that is kept in the same order as the list.
+    pub fn apply<T>(self, items: &mut [T]) {
+        let Self { from, to } = self;
+        if from < to {
+            items[from..=to].rotate_left(1);
+        } else {
+            items[to..=from].rotate_right(1);
+        }
+    }
+}
+
+#[derive(Clone, Default)]
+struct State {
+    /// The item being dragged, and how far below its top it was grabbed.
+    dragged: Option<(usize, f32)>,
+
+    /// The height of each item, as of when it was last shown in the list.
+    heights: Vec<f32>,
+
+    /// The vertical centers of the other items, while dragging.
+    centers: Vec<f32>,
+}
+
+impl State {
+    /// Where the dragged item would go if dropped now.
+    fn target(&self, ui: &Ui) -> Option<(usize, f32)> {
+        let (dragged, grab_offset) = self.dragged?;
+        let pointer = ui.ctx().pointer_latest_pos()?;
+        let center = pointer.y - grab_offset + 0.5 * self.heights.get(dragged).copied()?;
+        let target = self.centers.iter().filter(|&&y| y < center).count();
+        Some((target, pointer.y - grab_offset))
+    }
+}
+
+pub(crate) fn reorderable_list<T>(
+    ui: &mut Ui,
+    items: &mut [T],
+    mut add_item: impl FnMut(&mut Ui, &mut T),
+) -> Option<ListMove> {
+    let id = ui.id();
+    let handle_id = |index: usize| id.with(("handle", index));
+    let mut state: State = ui.data(|d| d.get_temp(id)).unwrap_or_default();
+    state
+        .heights
+        .resize(items.len(), ui.spacing().interact_size.y);
+    let animation_time = ui.style().animation_time;
+    let gap_id = |slot: usize| id.with(("gap", slot));
+    let mut applied = None;
+
+    // Was the dragged item dropped?
+    if let Some((dragged, _)) = state.dragged {
+        if items.len() <= dragged {
+            state.dragged = None;
+        } else if !ui.ctx().is_being_dragged(handle_id(dragged)) {
+            let cancelled = ui.input(|i| i.key_pressed(Key::Escape));
+            let target = state.target(ui).map(|(target, _)| target);
+            if let Some(to) = target.filter(|&to| !cancelled && to != dragged) {
+                let list_move = ListMove { from: dragged, to };
+                list_move.apply(items);
+                applied = Some(list_move);
+            }
+            // Close the gap right away, since the item is back in the list:
+            for slot in 0..items.len() {
+                ui.ctx().animate_bool_with_time(gap_id(slot), false, 0.0);
+            }
+            state.dragged = None;
+        }
+    }
+
+    let target = state.target(ui);
+    let dragged = state.dragged.map(|(dragged, _)| dragged);
+    let mut centers = Vec::new();
+    let mut keyboard_move = None;
+    let mut slot = 0;
+    for index in 0..items.len() {
+        if Some(index) == dragged {
+            continue;
+        }
+        let gap = ui.ctx().animate_bool_with_time(
+            gap_id(slot),
+            target.is_some_and(|(target, _)| target == slot),
+            animation_time,
+        );
+        if let Some(dragged) = dragged {
+            ui.add_space(gap * (state.heights[dragged] + ui.spacing().item_spacing.y));
+        }
+        slot += 1;
+
+        let row = ui.horizontal(|ui| {
+            let handle = drag_handle(ui, handle_id(index));
+            add_item(ui, &mut items[index]);
+            handle
+        });
+        let (row_rect, handle) = (row.response.rect, row.inner);
+        state.heights[index] = row_rect.height();
+        centers.push(row_rect.center().y);
+
+        if handle.drag_started() {
+            if let Some(press_origin) = ui.input(|i| i.pointer.press_origin()) {
+                state.dragged = Some((index, press_origin.y - row_rect.top()));
+                // The item leaves a gap where it was:
+                ui.ctx().animate_bool_with_time(gap_id(index), true, 0.0);
+                ui.ctx().request_repaint();
+            }
+        }
+
+        if handle.has_focus() {
+            ui.memory_mut(|m| {
+                m.set_focus_lock_filter(
+                    handle.id,
+                    EventFilter {
+                        vertical_arrows: true,
+                        ..Default::default()
+                    },
+                );
+            });
+            let (up, down) = ui.input_mut(|i| {
+                (
+                    i.consume_key(Modifiers::COMMAND, Key::ArrowUp),
+                    i.consume_key(Modifiers::COMMAND, Key::ArrowDown),
+                )
+            });
+            if up && 0 < index {
+                keyboard_move = Some(ListMove {
+                    from: index,
+                    to: index - 1,
+                });
+            } else if down && index + 1 < items.len() {
+                keyboard_move = Some(ListMove {
+                    from: index,
+                    to: index + 1,
+                });
+            }
+        }
+    }
+    if let Some(dragged) = dragged {
+        // Room for dropping it last:
+        let gap = ui.ctx().animate_bool_with_time(
+            gap_id(slot),
+            target.is_some_and(|(target, _)| target == slot),
+            animation_time,
+        );
+        ui.add_space(gap * (state.heights[dragged] + ui.spacing().item_spacing.y));
+
+        // Show the dragged item under the pointer, above everything else:
+        if let Some((_, top)) = target {
+            let rect = Rect::from_min_size(
+                pos2(ui.min_rect().left(), top),
+                vec2(ui.available_width(), state.heights[dragged]),
+            );
+            let mut floating = ui.new_child(
+                UiBuilder::new()
+                    .id_salt(handle_id(dragged))
+                    .layer_id(LayerId::new(Order::Tooltip, handle_id(dragged)))
+                    .max_rect(rect)
+                    .layout(Layout::left_to_right(Align::Center)),
+            );
+            let background = floating.painter().add(Shape::Noop);
+            let (handle_rect, _) = floating.allocate_exact_size(handle_size(ui), Sense::hover());
+            paint_grip(&floating, handle_rect, ui.visuals().strong_text_color());
+            add_item(&mut floating, &mut items[dragged]);
+            let visuals = ui.visuals();
+            floating.painter().set(
+                background,
+                Shape::rect_filled(
+                    floating.min_rect().expand(2.0),
+                    visuals.widgets.active.corner_radius,
+                    visuals.widgets.active.weak_bg_fill,
+                ),
+            );
+            ui.ctx().set_cursor_icon(CursorIcon::Grabbing);
+        }
+    }
+    state.centers = centers;
+
+    if let Some(list_move) = keyboard_move {
+        list_move.apply(items);
+        state.heights.swap(list_move.from, list_move.to);
+        ui.memory_mut(|m| m.request_focus(handle_id(list_move.to)));
+        ui.ctx().request_repaint();
+        applied = Some(list_move);
+    }
+
+    ui.data_mut(|d| d.insert_temp(id, state));
+    applied
+}
+
+fn handle_size(ui: &Ui) -> Vec2 {
+    vec2(0.6 * ui.spacing().icon_width, ui.spacing().interact_size.y)
+}
+
+fn drag_handle(ui: &mut Ui, id: Id) -> Response {
+    let (rect, _) = ui.allocate_exact_size(handle_size(ui), Sense::hover());
+    let response = ui
+        .interact(rect, id, Sense::click_and_drag())
+        .on_hover_cursor(CursorIcon::Grab);
+    response
+        .widget_info(|| WidgetInfo::labeled(WidgetType::Other, ui.is_enabled(), "Drag to reorder"));
+    if ui.is_rect_visible(rect) {
+        let color = ui.style().interact(&response).fg_stroke.color;
+        paint_grip(ui, rect, color);
+        if response.has_focus() {
+            let stroke = ui.visuals().selection.stroke;
+            ui.painter().rect_stroke(
+                rect,
+                ui.visuals().widgets.hovered.corner_radius,
+                stroke,
+                crate::StrokeKind::Inside,
+            );
+        }
+    }
+    response
+}
+
+/// Two columns of three dots.
+fn paint_grip(ui: &Ui, rect: Rect, color: crate::Color32) {
+    let spacing = 4.0;
+    for row in -1..=1 {
+        for column in [-0.5, 0.5] {
+            let offset = vec2(column * spacing, row as f32 * spacing);
+            ui.painter()
+                .circle_filled(rect.center() + offset, 1.0, color);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Context, Event, PointerButton, RawInput};
+
+    #[test]
+    fn test_list_move() {
+        let mut items = [0, 1, 2, 3];
+        ListMove { from: 0, to: 2 }.apply(&mut items);
+        assert_eq!(items, [1, 2, 0, 3]);
+        ListMove { from: 3, to: 1 }.apply(&mut items);
+        assert_eq!(items, [1, 3, 2, 0]);
+    }
+
+    #[test]
+    fn test_drag_to_reorder() {
+        let ctx = Context::default();
+        let mut items = vec!["a", "b", "c"];
+        let run = |items: &mut Vec<&str>, events: Vec<Event>| {
+            let input = RawInput {
+                events,
+                ..Default::default()
+            };
+            let mut rects = Vec::new();
+            let mut list_move = None;
+            let _ = ctx.run(input, |ctx| {
+                crate::CentralPanel::default().show(ctx, |ui| {
+                    list_move = ui.reorderable_list(items, |ui, item| {
+                        rects.push(ui.label(*item).rect);
+                    });
+                });
+            });
+            (rects, list_move)
+        };
+        let button = |pos, pressed| Event::PointerButton {
+            pos,
+            button: PointerButton::Primary,
+            pressed,
+            modifiers: Modifiers::NONE,
+        };
+
+        let (rects, _) = run(&mut items, vec![]);
+        // The handle is just left of the label:
+        let grab = rects[0].left_center() - vec2(5.0, 0.0);
+        let drop = grab + vec2(0.0, rects[2].bottom() - rects[0].center().y);
+        run(
+            &mut items,
+            vec![Event::PointerMoved(grab), button(grab, true)],
+        );
+        run(&mut items, vec![Event::PointerMoved(drop)]);
+        run(&mut items, vec![Event::PointerMoved(drop)]);
+        let (_, list_move) = run(&mut items, vec![button(drop, false)]);
+        assert_eq!(list_move, Some(ListMove { from: 0, to: 2 }));
+        assert_eq!(items, ["b", "c", "a"]);
+    }
+}
@@ -137,6 +137,9 @@ bitflags::bitflags! {
 
         /// Should this container be closed?
         const CLOSE = 1<<12;
+
+        /// The widget was pressed and held for [`crate::InputOptions::long_press_duration`].
+        const LONG_PRESSED = 1<<13;
     }
 }
 
@@ -187,6 +190,70 @@ impl Response {
         self.flags.contains(Flags::LONG_TOUCHED)
     }
 
+    /// Was this widget pressed and held still for [`crate::InputOptions::long_press_duration`]?
+    ///
+    /// This is `true` for one frame, while the button is still held down.
+    /// Releasing the button afterwards doesn't count as a click.
+    ///
+    /// On touch screens, this is also a [`Self::secondary_clicked`] (context menu).
+    /// Use [`Self::show_long_press_progress`] to show the user how long to hold.
+    #[inline]
+    pub fn long_pressed(&self) -> bool {
+        self.flags.contains(Flags::LONG_PRESSED)
+    }
+
+    /// While the user presses and holds this widget, show a radial progress indicator
+    /// around the pointer, filling up until the widget is [`Self::long_pressed`].
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let response = ui.button("Hold to delete").show_long_press_progress();
+    /// if response.long_pressed() {
+    ///     // …
+    /// }
+    /// # });
+    /// ```
+    pub fn show_long_press_progress(self) -> Self {
+        if !self.is_pointer_button_down_on() {
+            return self;
+        }
+        let (progress, center) = self
+            .ctx
+            .input(|i| (i.pointer.long_press_progress(), i.pointer.press_origin()));
+        let (Some(progress), Some(center)) = (progress, center) else {
+            return self;
+        };
+        // Don't flash for normal clicks:
+        let progress = emath::remap_clamp(progress, 0.2..=1.0, 0.0..=1.0);
+        if 0.0 < progress && progress < 1.0 {
+            let painter = self.ctx.layer_painter(crate::LayerId::new(
+                crate::Order::Tooltip,
+                self.id.with("long_press_progress"),
+            ));
+            let visuals = self.ctx.style().visuals.clone();
+            let radius = 2.0 * self.ctx.style().spacing.interact_size.y;
+            let width = 4.0;
+            painter.circle_stroke(
+                center,
+                radius,
+                (width, visuals.extreme_bg_color.gamma_multiply(0.8)),
+            );
+            let n = 32;
+            let points = (0..=n)
+                .map(|i| {
+                    let angle = std::f32::consts::TAU * (progress * i as f32 / n as f32 - 0.25);
+                    center + radius * crate::Vec2::angled(angle)
+                })
+                .collect();
+            painter.add(epaint::PathShape::line(
+                points,
+                (width, visuals.selection.bg_fill),
+            ));
+        }
+        self.ctx.request_repaint();
+        self
+    }
+
     /// Returns true if this widget was clicked this frame by the middle mouse button.
     #[inline]
     pub fn middle_clicked(&self) -> bool {
@@ -590,6 +657,15 @@ impl Response {
         self
     }
 
+    /// Show this UI in a [`crate::HoverCard`] after the widget has been hovered for a while.
+    ///
+    /// Unlike [`Self::on_hover_ui`], the card stays open while the pointer is over it,
+    /// so it is a good fit for interactive content like profile cards and link previews.
+    pub fn on_hover_card(self, add_contents: impl FnOnce(&mut Ui)) -> Self {
+        crate::HoverCard::new(&self).show(add_contents);
+        self
+    }
+
     /// Always show this tooltip, even if disabled and the user isn't hovering it.
     ///
     /// This can be used to give attention to a widget during a tutorial.
@@ -791,6 +867,9 @@ impl Response {
         };
 
         if let Some(event) = event {
+            if let OutputEvent::Clicked(info) = &event {
+                self.ctx.play_sound(crate::UiSound::click(info.typ));
+            }
             self.output_event(event);
         } else {
             #[cfg(feature = "accesskit")]
@@ -861,6 +940,7 @@ impl Response {
             WidgetType::ColorButton => Role::ColorWell,
             WidgetType::ProgressIndicator => Role::ProgressIndicator,
             WidgetType::Window => Role::Window,
+            WidgetType::Tab => Role::Tab,
             WidgetType::Other => Role::Unknown,
         });
         if !info.enabled {
@@ -879,7 +959,9 @@ impl Response {
         if let Some(value) = info.value {
             builder.set_numeric_value(value);
         }
-        if let Some(selected) = info.selected {
+        if let (WidgetType::Tab, Some(selected)) = (info.typ, info.selected) {
+            builder.set_selected(selected);
+        } else if let Some(selected) = info.selected {
             builder.set_toggled(if selected {
                 Toggled::True
             } else {
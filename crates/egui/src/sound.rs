@@ -0,0 +1,117 @@
+/// Something happened in the UI that an app may want to play a sound for.
+///
+/// egui doesn't play any sounds itself.
+/// Instead, set a callback with [`crate::Context::set_sound_callback`] and play your own sounds,
+/// so that all widgets give the same audio feedback.
+///
+/// Custom widgets can report sounds with [`crate::Context::play_sound`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum UiSound {
+    /// The pointer started hovering an interactive widget.
+    Hover,
+
+    /// A button or similar was clicked.
+    Click,
+
+    /// A checkbox, radio button or selectable label was clicked.
+    Toggle,
+
+    /// Something went wrong, e.g. invalid input.
+    ///
+    /// egui never reports this itself, but your widgets can.
+    Error,
+
+    /// A popup, menu or combo box was opened.
+    PopupOpen,
+
+    /// A popup, menu or combo box was closed.
+    PopupClose,
+}
+
+impl UiSound {
+    /// The sound for clicking a widget of this type.
+    pub(crate) fn click(typ: crate::WidgetType) -> Self {
+        use crate::WidgetType;
+        match typ {
+            WidgetType::Checkbox | WidgetType::RadioButton | WidgetType::SelectableLabel => {
+                Self::Toggle
+            }
+            _ => Self::Click,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::UiSound;
+    use crate::{
+        CentralPanel, Context, Event, Id, Modifiers, PointerButton, Popup, RawInput, Rect,
+        epaint::mutex::Mutex,
+    };
+
+    #[test]
+    fn test_ui_sounds() {
+        let ctx = Context::default();
+        let sounds = Arc::new(Mutex::new(Vec::new()));
+        ctx.set_sound_callback({
+            let sounds = sounds.clone();
+            move |sound| sounds.lock().push(sound)
+        });
+
+        let mut checked = false;
+        let mut run = |events: Vec<Event>| {
+            let mut rects = [Rect::NOTHING; 2];
+            let _ = ctx.run(
+                RawInput {
+                    events,
+                    ..Default::default()
+                },
+                |ctx| {
+                    CentralPanel::default().show(ctx, |ui| {
+                        rects[0] = ui.add_sized([100.0, 20.0], crate::Button::new("")).rect;
+                        rects[1] = ui.checkbox(&mut checked, "").rect;
+                    });
+                },
+            );
+            rects
+        };
+        let click = |pos| {
+            [true, false].map(|pressed| Event::PointerButton {
+                pos,
+                button: PointerButton::Primary,
+                pressed,
+                modifiers: Modifiers::NONE,
+            })
+        };
+
+        let rects = run(vec![]);
+        run(vec![Event::PointerMoved(rects[0].center())]);
+        run(click(rects[0].center()).to_vec());
+        run(vec![Event::PointerMoved(rects[1].center())]);
+        run(click(rects[1].center()).to_vec());
+        assert_eq!(
+            sounds.lock().as_slice(),
+            [
+                UiSound::Hover,
+                UiSound::Click,
+                UiSound::Hover,
+                UiSound::Toggle
+            ]
+        );
+
+        sounds.lock().clear();
+        Popup::open_id(&ctx, Id::new("popup"));
+        run(vec![]);
+        Popup::close_all(&ctx);
+        run(vec![]);
+        ctx.play_sound(UiSound::Error);
+        run(vec![]);
+        assert_eq!(
+            sounds.lock().as_slice(),
+            [UiSound::PopupOpen, UiSound::PopupClose, UiSound::Error]
+        );
+    }
+}
@@ -14,22 +14,49 @@ use crate::{
     reset_button_with,
 };
 
-/// How to format numbers in e.g. a [`crate::DragValue`].
+/// How to format numbers in e.g. a [`crate::DragValue`], and how to parse them back.
+///
+/// Use [`Self::from_locale`] to get thousands separators, a decimal comma, etc.
 #[derive(Clone)]
-pub struct NumberFormatter(
-    Arc<dyn 'static + Sync + Send + Fn(f64, RangeInclusive<usize>) -> String>,
-);
+pub struct NumberFormatter {
+    formatter: Arc<dyn 'static + Sync + Send + Fn(f64, RangeInclusive<usize>) -> String>,
+    parser: Arc<dyn 'static + Sync + Send + Fn(&str) -> Option<f64>>,
+}
 
 impl NumberFormatter {
     /// The first argument is the number to be formatted.
     /// The second argument is the range of the number of decimals to show.
     ///
     /// See [`Self::format`] for the meaning of the `decimals` argument.
+    ///
+    /// Text is parsed with the default parser, which ignores whitespace.
+    /// Use [`Self::with_parser`] to change this.
     #[inline]
     pub fn new(
         formatter: impl 'static + Sync + Send + Fn(f64, RangeInclusive<usize>) -> String,
     ) -> Self {
-        Self(Arc::new(formatter))
+        Self {
+            formatter: Arc::new(formatter),
+            parser: Arc::new(crate::widgets::drag_value::default_parser),
+        }
+    }
+
+    /// Format and parse numbers according to the given [`NumberLocale`].
+    pub fn from_locale(locale: NumberLocale) -> Self {
+        Self::new(move |value, decimals| locale.format(value, decimals))
+            .with_parser(move |text| locale.parse(text))
+    }
+
+    /// Set how to parse text the user typed back into a number.
+    ///
+    /// This should accept whatever [`Self::format`] produces.
+    #[inline]
+    pub fn with_parser(
+        mut self,
+        parser: impl 'static + Sync + Send + Fn(&str) -> Option<f64>,
+    ) -> Self {
+        self.parser = Arc::new(parser);
+        self
     }
 
     /// Format the given number with the given number of decimals.
@@ -42,7 +69,19 @@ impl NumberFormatter {
     /// more decimals will be shown, up to the given max.
     #[inline]
     pub fn format(&self, value: f64, decimals: RangeInclusive<usize>) -> String {
-        (self.0)(value, decimals)
+        (self.formatter)(value, decimals)
+    }
+
+    /// Parse text the user typed, e.g. into a [`crate::DragValue`].
+    #[inline]
+    pub fn parse(&self, text: &str) -> Option<f64> {
+        (self.parser)(text)
+    }
+}
+
+impl Default for NumberFormatter {
+    fn default() -> Self {
+        Self::new(emath::format_with_decimals_in_range)
     }
 }
 
@@ -55,7 +94,128 @@ impl std::fmt::Debug for NumberFormatter {
 impl PartialEq for NumberFormatter {
     #[inline]
     fn eq(&self, other: &Self) -> bool {
-        Arc::ptr_eq(&self.0, &other.0)
+        Arc::ptr_eq(&self.formatter, &other.formatter) && Arc::ptr_eq(&self.parser, &other.parser)
+    }
+}
+
+/// How numbers are written in a specific locale.
+///
+/// Used with [`NumberFormatter::from_locale`].
+///
+/// ```
+/// # use egui::style::NumberLocale;
+/// assert_eq!(NumberLocale::ENGLISH.format(1234567.5, 1..=1), "1,234,567.5");
+/// assert_eq!(NumberLocale::GERMAN.format(1234567.5, 1..=1), "1.234.567,5");
+/// assert_eq!(NumberLocale::ENGLISH.format(-1234.0, 0..=0), "-1,234");
+/// assert_eq!(NumberLocale::ENGLISH.format(123.0, 0..=0), "123");
+/// assert_eq!(NumberLocale::GERMAN.parse("1.234,5"), Some(1234.5));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct NumberLocale {
+    /// Separates the integer part from the fraction, e.g. `.` or `,`.
+    pub decimal_separator: char,
+
+    /// Separates groups of digits in the integer part, e.g. `,` or `.`.
+    ///
+    /// `None` means no grouping.
+    pub group_separator: Option<char>,
+
+    /// How many digits in each group, usually 3.
+    pub group_size: usize,
+}
+
+impl Default for NumberLocale {
+    /// Same as egui's default formatting: a decimal point and no grouping.
+    fn default() -> Self {
+        Self::PLAIN
+    }
+}
+
+impl NumberLocale {
+    /// `1234567.5`
+    pub const PLAIN: Self = Self {
+        decimal_separator: '.',
+        group_separator: None,
+        group_size: 3,
+    };
+
+    /// `1,234,567.5`
+    pub const ENGLISH: Self = Self {
+        decimal_separator: '.',
+        group_separator: Some(','),
+        group_size: 3,
+    };
+
+    /// `1.234.567,5`
+    pub const GERMAN: Self = Self {
+        decimal_separator: ',',
+        group_separator: Some('.'),
+        group_size: 3,
+    };
+
+    /// `1 234 567,5`, using a narrow no-break space.
+    pub const FRENCH: Self = Self {
+        decimal_separator: ',',
+        group_separator: Some('\u{202F}'),
+        group_size: 3,
+    };
+
+    /// `1'234'567.5`
+    pub const SWISS: Self = Self {
+        decimal_separator: '.',
+        group_separator: Some('\''),
+        group_size: 3,
+    };
+
+    /// Format a number with the given range of decimals.
+    ///
+    /// See [`NumberFormatter::format`].
+    pub fn format(&self, value: f64, decimals: RangeInclusive<usize>) -> String {
+        let text = emath::format_with_decimals_in_range(value, decimals);
+        if !value.is_finite() {
+            return text;
+        }
+
+        let (sign, unsigned) = match text.strip_prefix('-') {
+            Some(unsigned) => ("-", unsigned),
+            None => ("", text.as_str()),
+        };
+        let (integer, fraction) = match unsigned.split_once('.') {
+            Some((integer, fraction)) => (integer, Some(fraction)),
+            None => (unsigned, None),
+        };
+
+        let mut result = String::with_capacity(text.len() + integer.len() / 3 + 1);
+        result.push_str(sign);
+        match self.group_separator {
+            Some(separator) if 0 < self.group_size => {
+                for (i, digit) in integer.chars().enumerate() {
+                    if 0 < i && (integer.len() - i) % self.group_size == 0 {
+                        result.push(separator);
+                    }
+                    result.push(digit);
+                }
+            }
+            _ => result.push_str(integer),
+        }
+        if let Some(fraction) = fraction {
+            result.push(self.decimal_separator);
+            result.push_str(fraction);
+        }
+        result
+    }
+
+    /// Parse a number written in this locale.
+    ///
+    /// Like the default parser, whitespace is ignored.
+    pub fn parse(&self, text: &str) -> Option<f64> {
+        let text: String = text
+            .chars()
+            .filter(|&c| Some(c) != self.group_separator)
+            .map(|c| if c == self.decimal_separator { '.' } else { c })
+            .collect();
+        crate::widgets::drag_value::default_parser(&text)
     }
 }
 
@@ -291,7 +451,9 @@ pub struct Style {
 
     /// How to format numbers as strings, e.g. in a [`crate::DragValue`].
     ///
-    /// You can override this to e.g. add thousands separators.
+    /// You can override this to e.g. add thousands separators,
+    /// see [`NumberFormatter::from_locale`].
+    /// This is also used to parse the text the user types.
     #[cfg_attr(feature = "serde", serde(skip))]
     pub number_formatter: NumberFormatter,
 
@@ -326,6 +488,12 @@ pub struct Style {
     /// How many seconds a typical animation should last.
     pub animation_time: f32,
 
+    /// Avoid animations that move or scale things, for users who get distracted or
+    /// nauseous by motion (e.g. the "reduce motion" setting of the operating system).
+    ///
+    /// Fades are still animated.
+    pub reduce_motion: bool,
+
     /// Options to help debug why egui behaves strangely.
     ///
     /// Only available in debug builds.
@@ -485,6 +653,102 @@ impl Spacing {
 
         (small_icon_rect, big_icon_rect)
     }
+
+    /// Dense layout with small widgets, for showing a lot at once, e.g. in tool panels.
+    pub fn compact() -> Self {
+        Self {
+            item_spacing: vec2(6.0, 2.0),
+            window_margin: Margin::same(4),
+            menu_margin: Margin::same(4),
+            button_padding: vec2(3.0, 0.0),
+            indent: 14.0,
+            interact_size: vec2(32.0, 16.0),
+            slider_rail_height: 6.0,
+            icon_width: 12.0,
+            icon_width_inner: 7.0,
+            icon_spacing: 3.0,
+            menu_spacing: 1.0,
+            scroll: ScrollStyle {
+                bar_width: 8.0,
+                handle_min_length: 10.0,
+                bar_inner_margin: 2.0,
+                ..ScrollStyle::floating()
+            },
+            ..Self::comfortable()
+        }
+    }
+
+    /// The default spacing.
+    pub fn comfortable() -> Self {
+        Self::default()
+    }
+
+    /// Large widgets with plenty of space between them, for touch screens.
+    pub fn touch() -> Self {
+        Self {
+            item_spacing: vec2(12.0, 8.0),
+            window_margin: Margin::same(10),
+            menu_margin: Margin::same(10),
+            button_padding: vec2(10.0, 6.0),
+            indent: 30.0,
+            interact_size: vec2(56.0, 40.0),
+            slider_width: 160.0,
+            slider_rail_height: 12.0,
+            icon_width: 22.0,
+            icon_width_inner: 12.0,
+            icon_spacing: 8.0,
+            menu_spacing: 4.0,
+            scroll: ScrollStyle {
+                bar_width: 16.0,
+                handle_min_length: 32.0,
+                bar_inner_margin: 6.0,
+                floating_width: 6.0,
+                ..ScrollStyle::floating()
+            },
+            ..Self::comfortable()
+        }
+    }
+}
+
+/// How densely widgets are laid out, see [`Spacing::compact`], [`Spacing::comfortable`] and [`Spacing::touch`].
+///
+/// Use [`crate::widgets::global_density_buttons`] to let the user pick one.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum Density {
+    Compact,
+
+    #[default]
+    Comfortable,
+
+    Touch,
+}
+
+impl Density {
+    pub const ALL: [Self; 3] = [Self::Compact, Self::Comfortable, Self::Touch];
+
+    pub fn spacing(self) -> Spacing {
+        match self {
+            Self::Compact => Spacing::compact(),
+            Self::Comfortable => Spacing::comfortable(),
+            Self::Touch => Spacing::touch(),
+        }
+    }
+
+    /// Which density the spacing is from, if it hasn't been changed since.
+    pub fn of(spacing: &Spacing) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|density| &density.spacing() == spacing)
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Compact => "Compact",
+            Self::Comfortable => "Comfortable",
+            Self::Touch => "Touch",
+        }
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -1267,6 +1531,13 @@ pub struct DebugOptions {
     /// Show which widgets make their parent higher
     pub show_expand_height: bool,
 
+    /// Warn about widgets that asked for more space than was available.
+    ///
+    /// This paints warning stripes over the overflowing part of the widget
+    /// and logs the widget id (once per widget) if the `log` feature is enabled.
+    /// With the `callstack` feature, the log also includes where the widget was created.
+    pub warn_if_overflowing: bool,
+
     pub show_resize: bool,
 
     /// Show an overlay on all interactive widgets.
@@ -1291,6 +1562,7 @@ impl Default for DebugOptions {
             hover_shows_next: false,
             show_expand_width: false,
             show_expand_height: false,
+            warn_if_overflowing: false,
             show_resize: false,
             show_interactive_widgets: false,
             show_widget_hits: false,
@@ -1324,13 +1596,14 @@ impl Default for Style {
             override_text_valign: Some(Align::Center),
             text_styles: default_text_styles(),
             drag_value_text_style: TextStyle::Button,
-            number_formatter: NumberFormatter(Arc::new(emath::format_with_decimals_in_range)),
+            number_formatter: NumberFormatter::default(),
             wrap: None,
             wrap_mode: None,
             spacing: Spacing::default(),
             interaction: Interaction::default(),
             visuals: Visuals::default(),
             animation_time: 1.0 / 12.0,
+            reduce_motion: false,
             #[cfg(debug_assertions)]
             debug: Default::default(),
             explanation_tooltips: false,
@@ -1639,6 +1912,7 @@ impl Style {
             interaction,
             visuals,
             animation_time,
+            reduce_motion,
             #[cfg(debug_assertions)]
             debug,
             explanation_tooltips,
@@ -1740,6 +2014,10 @@ impl Style {
                     .suffix(" s"),
             );
             ui.end_row();
+
+            ui.label("Reduce motion");
+            ui.checkbox(reduce_motion, "");
+            ui.end_row();
         });
 
         ui.collapsing("🔠 Text styles", |ui| text_styles_ui(ui, text_styles));
@@ -1785,6 +2063,18 @@ fn text_styles_ui(ui: &mut Ui, text_styles: &mut BTreeMap<TextStyle, FontId>) ->
 
 impl Spacing {
     pub fn ui(&mut self, ui: &mut crate::Ui) {
+        ui.horizontal(|ui| {
+            ui.label("Presets:");
+            for density in Density::ALL {
+                if ui
+                    .selectable_label(Density::of(self) == Some(density), density.name())
+                    .clicked()
+                {
+                    *self = density.spacing();
+                }
+            }
+        });
+
         let Self {
             item_spacing,
             window_margin,
@@ -2434,6 +2724,7 @@ impl DebugOptions {
             hover_shows_next,
             show_expand_width,
             show_expand_height,
+            warn_if_overflowing,
             show_resize,
             show_interactive_widgets,
             show_widget_hits,
@@ -2458,6 +2749,10 @@ impl DebugOptions {
             show_expand_height,
             "Show which widgets make their parent higher",
         );
+        ui.checkbox(
+            warn_if_overflowing,
+            "Warn about widgets that overflow the available space",
+        );
         ui.checkbox(show_resize, "Debug Resize");
 
         ui.checkbox(
@@ -1405,6 +1405,14 @@ impl Ui {
         let id = Id::new(self.next_auto_id_salt);
         self.next_auto_id_salt = self.next_auto_id_salt.wrapping_add(1);
 
+        #[cfg(debug_assertions)]
+        if self.style().debug.warn_if_overflowing {
+            let overflow = desired_size - original_available;
+            if 0.0 < overflow.x || 0.0 < overflow.y {
+                warn_about_overflow(self, id, rect, overflow.max(Vec2::ZERO));
+            }
+        }
+
         (id, rect)
     }
 
@@ -1701,6 +1709,31 @@ impl Ui {
             state.scroll_delta.1 = animation;
         });
     }
+
+    /// Scroll the enclosing [`crate::ScrollArea`] towards the pointer when it is outside
+    /// [`Self::clip_rect`], e.g. when drag-selecting past the edge of the visible area.
+    ///
+    /// The further outside the pointer is, the faster it scrolls.
+    /// Call this every frame while the drag is going on.
+    ///
+    /// [`crate::TextEdit`] does this while selecting text,
+    /// and [`crate::ScrollArea::auto_scroll_on_drag`] does it for any drag that started inside it.
+    pub fn auto_scroll_towards_pointer(&self) {
+        /// Points per second of scrolling, per point the pointer is outside.
+        const SPEED: f32 = 10.0;
+
+        let Some(pointer_pos) = self.ctx().input(|i| i.pointer.latest_pos()) else {
+            return;
+        };
+        let clip_rect = self.clip_rect();
+        let outside = pointer_pos - clip_rect.clamp(pointer_pos);
+        if outside == Vec2::ZERO {
+            return;
+        }
+        let dt = self.ctx().input(|i| i.stable_dt).min(0.1);
+        self.scroll_with_delta_animation(-SPEED * dt * outside, style::ScrollAnimation::none());
+        self.ctx().request_repaint();
+    }
 }
 
 /// # Adding widgets
@@ -2444,6 +2477,64 @@ impl Ui {
         InnerResponse::new(ret, response)
     }
 
+    /// Add contents whose size is only known after it has been added,
+    /// and place it aligned within the available space,
+    /// e.g. right-aligned status text or a centered row of varying width.
+    ///
+    /// The size of the contents is remembered from the previous pass.
+    /// The first time, and whenever the size changes, the pass is discarded with [`Context::request_discard`]
+    /// and the contents is laid out again with the new size,
+    /// so it is never shown in the wrong place.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let num_files = 42;
+    /// ui.place_aligned(egui::Align2::RIGHT_TOP, |ui| {
+    ///     ui.label(format!("{num_files} files"));
+    /// });
+    /// # });
+    /// ```
+    pub fn place_aligned<R>(
+        &mut self,
+        align: emath::Align2,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> InnerResponse<R> {
+        self.place_aligned_dyn(align, Box::new(add_contents))
+    }
+
+    fn place_aligned_dyn<'c, R>(
+        &mut self,
+        align: emath::Align2,
+        add_contents: Box<dyn FnOnce(&mut Ui) -> R + 'c>,
+    ) -> InnerResponse<R> {
+        let id = self.next_auto_id().with("place_aligned");
+        let prev_size: Option<Vec2> = self.data(|d| d.get_temp(id));
+        let available = self.available_rect_before_wrap();
+
+        let layout = self
+            .layout()
+            .with_main_justify(false)
+            .with_cross_justify(false);
+        let builder = UiBuilder::new().layout(layout);
+        let builder = match prev_size {
+            Some(size) if !self.is_sizing_pass() => {
+                builder.max_rect(align.align_size_within_rect(size, available))
+            }
+            Some(_) => builder.max_rect(available),
+            None => builder.max_rect(available).sizing_pass().invisible(),
+        };
+        let inner_response = self.scope_builder(builder, add_contents);
+
+        let size = inner_response.response.rect.size();
+        if prev_size.is_none_or(|prev_size| 0.5 < (size - prev_size).abs().max_elem()) {
+            self.data_mut(|d| d.insert_temp(id, size));
+            if self.is_visible() {
+                self.ctx().request_discard("Ui::place_aligned: new size");
+            }
+        }
+        inner_response
+    }
+
     /// Redirect shapes to another paint layer.
     ///
     /// ```
@@ -2596,6 +2687,48 @@ impl Ui {
         self.allocate_ui_with_layout_dyn(initial_size, layout, Box::new(add_contents))
     }
 
+    /// A row of items that are stretched to the same width, so that they exactly fill the available width.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// ui.horizontal_justified(|row| {
+    ///     row.item(|ui| ui.button("Yes"));
+    ///     row.item(|ui| ui.button("No"));
+    /// });
+    /// # });
+    /// ```
+    ///
+    /// This is [`crate::Distribute::equal_widths`]. See [`Self::distribute`] for other options.
+    pub fn horizontal_justified<R>(
+        &mut self,
+        add_contents: impl FnOnce(&mut crate::DistributeUi<'_>) -> R,
+    ) -> InnerResponse<R> {
+        crate::Distribute::equal_widths().show(self, add_contents)
+    }
+
+    /// A row of items that exactly fills the available width,
+    /// with equal widths, equal gaps, or weighted widths.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// use egui::containers::Distribution;
+    /// ui.distribute(Distribution::EqualGaps, |row| {
+    ///     row.item(|ui| ui.label("Left"));
+    ///     row.item(|ui| ui.label("Middle"));
+    ///     row.item(|ui| ui.label("Right"));
+    /// });
+    /// # });
+    /// ```
+    ///
+    /// See [`crate::Distribute`] for more.
+    pub fn distribute<R>(
+        &mut self,
+        distribution: crate::Distribution,
+        add_contents: impl FnOnce(&mut crate::DistributeUi<'_>) -> R,
+    ) -> InnerResponse<R> {
+        crate::Distribute::new(distribution).show(self, add_contents)
+    }
+
     /// Start a ui with horizontal layout that wraps to a new row
     /// when it reaches the right edge of the `max_size`.
     /// After you have called this, the function registers the contents as any other widget.
@@ -2987,6 +3120,35 @@ impl Ui {
         (InnerResponse { inner, response }, payload)
     }
 
+    /// Show a list of items that the user can reorder, by dragging the handle in front of each item.
+    ///
+    /// While dragging, the other items slide apart to show where it will go.
+    /// With a handle focused, ctrl/cmd + up/down moves the item one step.
+    ///
+    /// The move is applied to `items`, and returned, so you can e.g. make the
+    /// same move in related data with [`crate::ListMove::apply`].
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let mut tasks = vec!["Write", "Test", "Ship"];
+    /// if let Some(list_move) = ui.reorderable_list(&mut tasks, |ui, task| {
+    ///     ui.label(*task);
+    /// }) {
+    ///     println!("Moved {} to position {}", tasks[list_move.to], list_move.to);
+    /// }
+    /// # });
+    /// ```
+    #[doc(alias = "drag and drop")]
+    #[doc(alias = "sortable")]
+    pub fn reorderable_list<T>(
+        &mut self,
+        items: &mut [T],
+        add_item: impl FnMut(&mut Self, &mut T),
+    ) -> Option<crate::ListMove> {
+        self.vertical(|ui| crate::reorder::reorderable_list(ui, items, add_item))
+            .inner
+    }
+
     /// Create a new Scope and transform its contents via a [`emath::TSTransform`].
     /// This only affects visuals, inputs will not be transformed. So this is mostly useful
     /// to create visual effects on interactions, e.g. scaling a button on hover / click.
@@ -3163,6 +3325,77 @@ impl Drop for Ui {
     }
 }
 
+/// The widgets we have already logged an overflow warning for.
+#[cfg(debug_assertions)]
+#[derive(Clone, Default)]
+struct OverflowWarnings(crate::id::IdSet);
+
+/// Paint warning stripes over the part of `rect` that overflows the available space,
+/// and log the offending widget (once).
+///
+/// See [`style::DebugOptions::warn_if_overflowing`].
+#[cfg(debug_assertions)]
+fn warn_about_overflow(ui: &Ui, id: Id, rect: Rect, overflow: Vec2) {
+    let mut overflow_rect = rect;
+    if 0.0 < overflow.x {
+        overflow_rect.min.x = overflow_rect.max.x - overflow.x;
+    }
+    if 0.0 < overflow.y {
+        overflow_rect.min.y = overflow_rect.max.y - overflow.y;
+    }
+
+    // Yellow and black stripes, like Flutter:
+    let painter = ui
+        .ctx()
+        .layer_painter(LayerId::debug())
+        .with_clip_rect(overflow_rect);
+    painter.rect_filled(overflow_rect, 0.0, Color32::from_black_alpha(160));
+    let stripe_spacing = 8.0;
+    let stroke = Stroke::new(stripe_spacing / 2.0, Color32::from_rgb(255, 200, 0));
+    let height = overflow_rect.height();
+    let mut x = overflow_rect.left() - height;
+    while x < overflow_rect.right() {
+        painter.line_segment(
+            [
+                pos2(x, overflow_rect.bottom()),
+                pos2(x + height, overflow_rect.top()),
+            ],
+            stroke,
+        );
+        x += stripe_spacing;
+    }
+    ui.ctx().debug_painter().rect_stroke(
+        rect,
+        0.0,
+        (1.0, Color32::from_rgb(255, 200, 0)),
+        crate::StrokeKind::Outside,
+    );
+
+    let is_new = ui.ctx().data_mut(|data| {
+        data.get_temp_mut_or_default::<OverflowWarnings>(Id::NULL)
+            .0
+            .insert(id)
+    });
+    if is_new {
+        #[cfg(feature = "callstack")]
+        let location = format!("\n{}", crate::callstack::capture());
+
+        #[cfg(not(feature = "callstack"))]
+        let location = String::new();
+
+        #[cfg(feature = "log")]
+        log::warn!(
+            "egui: widget {id:?} in ui {:?} overflowed the available space by {:.1} x {:.1} points{location}",
+            ui.id(),
+            overflow.x,
+            overflow.y,
+        );
+
+        #[cfg(not(feature = "log"))]
+        let _ = location;
+    }
+}
+
 /// Show this rectangle to the user if certain debug options are set.
 #[cfg(debug_assertions)]
 fn register_rect(ui: &Ui, rect: Rect) {
@@ -3255,3 +3488,24 @@ fn ui_impl_send_sync() {
     fn assert_send_sync<T: Send + Sync>() {}
     assert_send_sync::<Ui>();
 }
+
+#[test]
+fn place_aligned() {
+    let ctx = Context::default();
+    let mut rects = (Rect::NOTHING, Rect::NOTHING);
+    let _ = ctx.run(Default::default(), |ctx| {
+        crate::CentralPanel::default().show(ctx, |ui| {
+            let available = ui.available_rect_before_wrap();
+            let label = ui
+                .place_aligned(emath::Align2::RIGHT_TOP, |ui| ui.label("Status"))
+                .inner;
+            rects = (available, label.rect);
+        });
+    });
+    let (available, label) = rects;
+    assert!(
+        (label.right() - available.right()).abs() < 1.0,
+        "Should be right-aligned already in the first frame"
+    );
+    assert_eq!(label.top(), available.top());
+}
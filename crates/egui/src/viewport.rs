@@ -276,7 +276,7 @@ pub type ImmediateViewportRendererCallback = dyn for<'a> Fn(&Context, ImmediateV
 ///
 /// The default values are implementation defined, so you may want to explicitly
 /// configure the size of the window, and what buttons are shown.
-#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[derive(Clone, Debug, Default, PartialEq)]
 pub struct ViewportBuilder {
     /// The title of the viewport.
     /// `eframe` will use this as the title of the native window.
@@ -327,8 +327,13 @@ pub struct ViewportBuilder {
 
     // X11
     pub window_type: Option<X11WindowType>,
+
+    /// See [`Self::with_native_pixels_per_point`].
+    pub native_pixels_per_point: Option<f32>,
 }
 
+impl Eq for ViewportBuilder {}
+
 impl ViewportBuilder {
     /// Sets the initial title of the window in the title bar.
     ///
@@ -665,6 +670,16 @@ impl ViewportBuilder {
         self
     }
 
+    /// Use this instead of the scale factor reported by the operating system.
+    ///
+    /// This is useful for kiosks and embedded displays where the DPI reported by the display is wrong.
+    /// The [`crate::Context::zoom_factor`] is applied on top of this.
+    #[inline]
+    pub fn with_native_pixels_per_point(mut self, pixels_per_point: f32) -> Self {
+        self.native_pixels_per_point = Some(pixels_per_point);
+        self
+    }
+
     /// Update this `ViewportBuilder` with a delta,
     /// returning a list of commands and a bool indicating if the window needs to be recreated.
     #[must_use]
@@ -701,6 +716,7 @@ impl ViewportBuilder {
             mouse_passthrough: new_mouse_passthrough,
             taskbar: new_taskbar,
             window_type: new_window_type,
+            native_pixels_per_point: new_native_pixels_per_point,
         } = new_vp_builder;
 
         let mut commands = Vec::new();
@@ -801,6 +817,15 @@ impl ViewportBuilder {
             }
         }
 
+        if let Some(new_native_pixels_per_point) = new_native_pixels_per_point {
+            if Some(new_native_pixels_per_point) != self.native_pixels_per_point {
+                self.native_pixels_per_point = Some(new_native_pixels_per_point);
+                commands.push(ViewportCommand::NativePixelsPerPoint(Some(
+                    new_native_pixels_per_point,
+                )));
+            }
+        }
+
         if let Some(new_window_level) = new_window_level {
             if Some(new_window_level) != self.window_level {
                 self.window_level = Some(new_window_level);
@@ -1019,7 +1044,7 @@ pub enum ResizeDirection {
 ///
 /// Only commands specific to a viewport are part of [`ViewportCommand`].
 /// Other commands should be put in [`crate::OutputCommand`].
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub enum ViewportCommand {
     /// Request this viewport to be closed.
@@ -1132,6 +1157,12 @@ pub enum ViewportCommand {
     /// Enable mouse pass-through: mouse clicks pass through the window, used for non-interactable overlays.
     MousePassthrough(bool),
 
+    /// Use this instead of the scale factor reported by the operating system,
+    /// or go back to using that with `None`.
+    ///
+    /// See [`ViewportBuilder::with_native_pixels_per_point`].
+    NativePixelsPerPoint(Option<f32>),
+
     /// Take a screenshot of the next frame after this.
     ///
     /// The results are returned in [`crate::Event::Screenshot`].
@@ -1176,6 +1207,8 @@ impl ViewportCommand {
     }
 }
 
+impl Eq for ViewportCommand {}
+
 // ----------------------------------------------------------------------------
 
 /// Describes a viewport, i.e. a native window.
@@ -1,5 +1,5 @@
 use emath::GuiRounding as _;
-use epaint::text::TextFormat;
+use epaint::text::{InternedStr, TextFormat};
 use std::fmt::Formatter;
 use std::{borrow::Cow, sync::Arc};
 
@@ -115,6 +115,13 @@ impl From<Box<str>> for RichText {
     }
 }
 
+impl From<&InternedStr> for RichText {
+    #[inline]
+    fn from(text: &InternedStr) -> Self {
+        Self::new(text.as_str())
+    }
+}
+
 impl From<Cow<'_, str>> for RichText {
     #[inline]
     fn from(text: Cow<'_, str>) -> Self {
@@ -497,6 +504,13 @@ pub enum WidgetText {
     /// and it uses less memory than [`Self::RichText`].
     Text(String),
 
+    /// Plain unstyled text that is cheap to clone.
+    ///
+    /// Unlike [`Self::Text`], showing this doesn't allocate or hash anything
+    /// when the text was laid out in the previous frame.
+    /// Use it when showing thousands of labels each frame.
+    Interned(InternedStr),
+
     /// Text and optional style choices for it.
     ///
     /// Prefer [`Self::Text`] if there is no styling, as it will be faster.
@@ -528,6 +542,7 @@ impl std::fmt::Debug for WidgetText {
         let text = self.text();
         match self {
             Self::Text(_) => write!(f, "Text({text:?})"),
+            Self::Interned(_) => write!(f, "Interned({text:?})"),
             Self::RichText(_) => write!(f, "RichText({text:?})"),
             Self::LayoutJob(_) => write!(f, "LayoutJob({text:?})"),
             Self::Galley(_) => write!(f, "Galley({text:?})"),
@@ -546,6 +561,7 @@ impl WidgetText {
     pub fn is_empty(&self) -> bool {
         match self {
             Self::Text(text) => text.is_empty(),
+            Self::Interned(text) => text.is_empty(),
             Self::RichText(text) => text.is_empty(),
             Self::LayoutJob(job) => job.is_empty(),
             Self::Galley(galley) => galley.is_empty(),
@@ -556,6 +572,7 @@ impl WidgetText {
     pub fn text(&self) -> &str {
         match self {
             Self::Text(text) => text,
+            Self::Interned(text) => text,
             Self::RichText(text) => text.text(),
             Self::LayoutJob(job) => &job.text,
             Self::Galley(galley) => galley.text(),
@@ -564,7 +581,7 @@ impl WidgetText {
 
     /// Map the contents based on the provided closure.
     ///
-    /// - [`Self::Text`] => convert to [`RichText`] and call f
+    /// - [`Self::Text`] or [`Self::Interned`] => convert to [`RichText`] and call f
     /// - [`Self::RichText`] => call f
     /// - else do nothing
     #[must_use]
@@ -574,6 +591,7 @@ impl WidgetText {
     {
         match self {
             Self::Text(text) => Self::RichText(Arc::new(f(RichText::new(text)))),
+            Self::Interned(text) => Self::RichText(Arc::new(f(RichText::new(text.as_str())))),
             Self::RichText(text) => Self::RichText(Arc::new(f(Arc::unwrap_or_clone(text)))),
             other => other,
         }
@@ -678,7 +696,9 @@ impl WidgetText {
     /// Returns a value rounded to [`emath::GUI_ROUNDING`].
     pub(crate) fn font_height(&self, fonts: &epaint::Fonts, style: &Style) -> f32 {
         match self {
-            Self::Text(_) => fonts.row_height(&FontSelection::Default.resolve(style)),
+            Self::Text(_) | Self::Interned(_) => {
+                fonts.row_height(&FontSelection::Default.resolve(style))
+            }
             Self::RichText(text) => text.font_height(fonts, style),
             Self::LayoutJob(job) => job.font_height(fonts),
             Self::Galley(galley) => {
@@ -707,6 +727,9 @@ impl WidgetText {
                     ..Default::default()
                 },
             )),
+            Self::Interned(text) => {
+                Self::Text(text.to_string()).into_layout_job(style, fallback_font, default_valign)
+            }
             Self::RichText(text) => Arc::new(Arc::unwrap_or_clone(text).into_layout_job(
                 style,
                 fallback_font,
@@ -744,25 +767,23 @@ impl WidgetText {
         fallback_font: FontSelection,
         default_valign: Align,
     ) -> Arc<Galley> {
+        let plain_format = |fallback_font: FontSelection| TextFormat {
+            // We want the style overrides to take precedence over the fallback font
+            font_id: FontSelection::default().resolve_with_fallback(style, fallback_font),
+            color: style
+                .visuals
+                .override_text_color
+                .unwrap_or(crate::Color32::PLACEHOLDER),
+            valign: default_valign,
+            ..Default::default()
+        };
+
         match self {
             Self::Text(text) => {
-                let color = style
-                    .visuals
-                    .override_text_color
-                    .unwrap_or(crate::Color32::PLACEHOLDER);
-                let mut layout_job = LayoutJob::simple_format(
-                    text,
-                    TextFormat {
-                        // We want the style overrides to take precedence over the fallback font
-                        font_id: FontSelection::default()
-                            .resolve_with_fallback(style, fallback_font),
-                        color,
-                        valign: default_valign,
-                        ..Default::default()
-                    },
-                );
-                layout_job.wrap = text_wrapping;
-                ctx.fonts(|f| f.layout_job(layout_job))
+                ctx.fonts(|f| f.layout_format(&text, plain_format(fallback_font), text_wrapping))
+            }
+            Self::Interned(text) => {
+                ctx.fonts(|f| f.layout_interned(&text, plain_format(fallback_font), text_wrapping))
             }
             Self::RichText(text) => {
                 let mut layout_job = Arc::unwrap_or_clone(text).into_layout_job(
@@ -825,6 +846,20 @@ impl From<Cow<'_, str>> for WidgetText {
     }
 }
 
+impl From<InternedStr> for WidgetText {
+    #[inline]
+    fn from(text: InternedStr) -> Self {
+        Self::Interned(text)
+    }
+}
+
+impl From<&InternedStr> for WidgetText {
+    #[inline]
+    fn from(text: &InternedStr) -> Self {
+        Self::Interned(text.clone())
+    }
+}
+
 impl From<RichText> for WidgetText {
     #[inline]
     fn from(rich_text: RichText) -> Self {
@@ -6,8 +6,8 @@ use crate::{
     WidgetInfo, WidgetType, epaint, lerp, remap_clamp,
 };
 use epaint::{
-    Mesh, Rect, Shape, Stroke, StrokeKind, Vec2,
-    ecolor::{Color32, Hsva, HsvaGamma, Rgba},
+    Mesh, Pos2, Rect, Shape, Stroke, StrokeKind, Vec2,
+    ecolor::{Color32, Hsva, HsvaGamma, Oklcha, Rgba},
     pos2, vec2,
 };
 
@@ -233,6 +233,206 @@ fn color_slider_2d(
     response
 }
 
+/// Which related hues to mark on the color wheel of [`color_picker_hsva_wheel`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ColorHarmony {
+    /// No markers.
+    #[default]
+    None,
+
+    /// The opposite hue.
+    Complementary,
+
+    /// The two hues next to the opposite one.
+    SplitComplementary,
+
+    /// The hues on either side.
+    Analogous,
+
+    /// Three hues, evenly spaced.
+    Triadic,
+
+    /// Four hues, evenly spaced.
+    Tetradic,
+}
+
+impl ColorHarmony {
+    /// How far the related hues are from the selected one, as fractions of a full turn.
+    pub fn hue_offsets(self) -> &'static [f32] {
+        match self {
+            Self::None => &[],
+            Self::Complementary => &[0.5],
+            Self::SplitComplementary => &[5.0 / 12.0, 7.0 / 12.0],
+            Self::Analogous => &[-1.0 / 12.0, 1.0 / 12.0],
+            Self::Triadic => &[1.0 / 3.0, 2.0 / 3.0],
+            Self::Tetradic => &[0.25, 0.5, 0.75],
+        }
+    }
+}
+
+/// The geometry of a hue ring around a saturation/value triangle.
+struct Wheel {
+    center: Pos2,
+    inner_radius: f32,
+}
+
+impl Wheel {
+    /// Direction of a hue, with red to the right and going counter-clockwise.
+    fn dir(h: f32) -> Vec2 {
+        Vec2::angled(-std::f32::consts::TAU * h)
+    }
+
+    /// The corners of the triangle: the pure hue, white, and black.
+    fn corners(&self, h: f32) -> [Pos2; 3] {
+        [0.0, 1.0 / 3.0, 2.0 / 3.0]
+            .map(|offset| self.center + self.inner_radius * Self::dir(h + offset))
+    }
+
+    fn pos(&self, h: f32, s: f32, v: f32) -> Pos2 {
+        let [hue, white, black] = self.corners(h);
+        black + v * (white - black) + v * s * (hue - white)
+    }
+
+    /// The saturation and value at a point in the triangle, clamped to it.
+    fn saturation_value(&self, h: f32, pos: Pos2) -> (f32, f32) {
+        let [hue, white, black] = self.corners(h);
+        // Solve `pos - black = v * (white - black) + (v * s) * (hue - white)`:
+        let (a, b, q) = (white - black, hue - white, pos - black);
+        let det = a.x * b.y - a.y * b.x;
+        let v = ((q.x * b.y - q.y * b.x) / det).clamp(0.0, 1.0);
+        let vs = ((a.x * q.y - a.y * q.x) / det).clamp(0.0, v);
+        let s = if v == 0.0 { 0.0 } else { vs / v };
+        (s, v)
+    }
+}
+
+/// A hue ring around a saturation/value triangle.
+fn color_wheel(ui: &mut Ui, h: &mut f32, s: &mut f32, v: &mut f32, harmony: ColorHarmony) {
+    let size = ui.spacing().slider_width;
+    let (rect, response) = ui.allocate_exact_size(Vec2::splat(size), Sense::click_and_drag());
+    let outer_radius = 0.5 * size;
+    let ring_width = 0.15 * size;
+    let wheel = Wheel {
+        center: rect.center(),
+        inner_radius: outer_radius - ring_width,
+    };
+
+    // Which part is being dragged is decided when the drag starts, so it doesn't jump between them:
+    let dragging_ring_id = response.id.with("ring");
+    if let Some(pos) = response.interact_pointer_pos() {
+        let on_ring = if response.drag_started() || response.clicked() {
+            let on_ring = wheel.inner_radius < pos.distance(wheel.center);
+            ui.data_mut(|d| d.insert_temp(dragging_ring_id, on_ring));
+            on_ring
+        } else {
+            ui.data(|d| d.get_temp(dragging_ring_id)).unwrap_or(false)
+        };
+        if on_ring {
+            let delta = pos - wheel.center;
+            *h = (-delta.y.atan2(delta.x) / std::f32::consts::TAU).rem_euclid(1.0);
+        } else {
+            (*s, *v) = wheel.saturation_value(*h, pos);
+        }
+    }
+
+    if !ui.is_rect_visible(rect) {
+        return;
+    }
+    let visuals = ui.style().interact(&response);
+    let hue_color = |h: f32| -> Color32 {
+        HsvaGamma {
+            h: h.rem_euclid(1.0),
+            s: 1.0,
+            v: 1.0,
+            a: 1.0,
+        }
+        .into()
+    };
+
+    // The hue ring:
+    let segments = 2 * N;
+    let mut mesh = Mesh::default();
+    for i in 0..=segments {
+        let t = i as f32 / segments as f32;
+        let dir = Wheel::dir(t);
+        mesh.colored_vertex(wheel.center + wheel.inner_radius * dir, hue_color(t));
+        mesh.colored_vertex(wheel.center + outer_radius * dir, hue_color(t));
+        if i < segments {
+            mesh.add_triangle(2 * i, 2 * i + 1, 2 * i + 2);
+            mesh.add_triangle(2 * i + 1, 2 * i + 2, 2 * i + 3);
+        }
+    }
+
+    // The saturation/value triangle, as rows of vertices from the black corner:
+    let row_start = |vi: u32| vi * (vi + 1) / 2;
+    for vi in 0..=N {
+        for si in 0..=vi {
+            let tv = vi as f32 / N as f32;
+            let ts = if vi == 0 { 0.0 } else { si as f32 / vi as f32 };
+            let color = HsvaGamma {
+                h: *h,
+                s: ts,
+                v: tv,
+                a: 1.0,
+            }
+            .into();
+            mesh.colored_vertex(wheel.pos(*h, ts, tv), color);
+        }
+    }
+    let offset = 2 * (segments + 1);
+    for vi in 0..N {
+        for si in 0..=vi {
+            let (this, next) = (offset + row_start(vi) + si, offset + row_start(vi + 1) + si);
+            mesh.add_triangle(this, next, next + 1);
+            if si < vi {
+                mesh.add_triangle(this, next + 1, this + 1);
+            }
+        }
+    }
+    ui.painter().add(Shape::mesh(mesh));
+
+    // Where the related hues are:
+    for offset in harmony.hue_offsets() {
+        let dir = Wheel::dir(*h + offset);
+        let color = contrast_color(hue_color(*h + offset));
+        ui.painter().line_segment(
+            [
+                wheel.center + 0.5 * wheel.inner_radius * dir,
+                wheel.center + outer_radius * dir,
+            ],
+            Stroke::new(1.0, color.gamma_multiply(0.5)),
+        );
+        ui.painter().circle_stroke(
+            wheel.center + (outer_radius - 0.5 * ring_width) * dir,
+            0.25 * ring_width,
+            Stroke::new(1.0, color),
+        );
+    }
+
+    // Show where the selected hue and color are:
+    let stroke_width = visuals.fg_stroke.width.max(1.0);
+    let hue_pos = wheel.center + (outer_radius - 0.5 * ring_width) * Wheel::dir(*h);
+    ui.painter().circle(
+        hue_pos,
+        0.35 * ring_width,
+        hue_color(*h),
+        Stroke::new(stroke_width, contrast_color(hue_color(*h))),
+    );
+    let picked_color: Color32 = HsvaGamma {
+        h: *h,
+        s: *s,
+        v: *v,
+        a: 1.0,
+    }
+    .into();
+    ui.painter().circle(
+        wheel.pos(*h, *s, *v),
+        size / 24.0,
+        picked_color,
+        Stroke::new(stroke_width, contrast_color(picked_color)),
+    );
+}
+
 /// We use a negative alpha for additive colors within this file (a bit ironic).
 ///
 /// We use alpha=0 to mean "transparent".
@@ -253,7 +453,12 @@ pub enum Alpha {
     BlendOrAdditive,
 }
 
-fn color_picker_hsvag_2d(ui: &mut Ui, hsvag: &mut HsvaGamma, alpha: Alpha) {
+fn color_picker_hsvag_2d(
+    ui: &mut Ui,
+    hsvag: &mut HsvaGamma,
+    alpha: Alpha,
+    wheel: Option<ColorHarmony>,
+) {
     use crate::style::NumericColorSpace;
 
     let alpha_control = if is_additive_alpha(hsvag.a) {
@@ -346,18 +551,22 @@ fn color_picker_hsvag_2d(ui: &mut Ui, hsvag: &mut HsvaGamma, alpha: Alpha) {
         color_slider_1d(ui, v, |v| HsvaGamma { v, ..opaque }.into()).on_hover_text("Value");
     }
 
-    color_slider_2d(ui, s, v, |s, v| HsvaGamma { s, v, ..opaque }.into());
-
-    color_slider_1d(ui, h, |h| {
-        HsvaGamma {
-            h,
-            s: 1.0,
-            v: 1.0,
-            a: 1.0,
-        }
-        .into()
-    })
-    .on_hover_text("Hue");
+    if let Some(harmony) = wheel {
+        color_wheel(ui, h, s, v, harmony);
+    } else {
+        color_slider_2d(ui, s, v, |s, v| HsvaGamma { s, v, ..opaque }.into());
+
+        color_slider_1d(ui, h, |h| {
+            HsvaGamma {
+                h,
+                s: 1.0,
+                v: 1.0,
+                a: 1.0,
+            }
+            .into()
+        })
+        .on_hover_text("Hue");
+    }
 
     let additive = is_additive_alpha(hsvag.a);
 
@@ -467,7 +676,7 @@ fn rgba_edit_ui(ui: &mut Ui, [r, g, b, a]: &mut [f32; 4], alpha: Alpha) -> bool
 pub fn color_picker_hsva_2d(ui: &mut Ui, hsva: &mut Hsva, alpha: Alpha) -> bool {
     let mut hsvag = HsvaGamma::from(*hsva);
     ui.vertical(|ui| {
-        color_picker_hsvag_2d(ui, &mut hsvag, alpha);
+        color_picker_hsvag_2d(ui, &mut hsvag, alpha, None);
     });
     let new_hasva = Hsva::from(hsvag);
     if *hsva == new_hasva {
@@ -489,6 +698,123 @@ pub fn color_picker_color32(ui: &mut Ui, srgba: &mut Color32, alpha: Alpha) -> b
     changed
 }
 
+/// Like [`color_picker_hsva_2d`], but with a hue wheel around a saturation/value triangle,
+/// optionally marking related hues (see [`ColorHarmony`]).
+///
+/// Returns `true` on change.
+pub fn color_picker_hsva_wheel(
+    ui: &mut Ui,
+    hsva: &mut Hsva,
+    alpha: Alpha,
+    harmony: ColorHarmony,
+) -> bool {
+    let mut hsvag = HsvaGamma::from(*hsva);
+    ui.vertical(|ui| {
+        color_picker_hsvag_2d(ui, &mut hsvag, alpha, Some(harmony));
+    });
+    let new_hsva = Hsva::from(hsvag);
+    if *hsva == new_hsva {
+        false
+    } else {
+        *hsva = new_hsva;
+        true
+    }
+}
+
+/// Like [`color_picker_color32`], but with a hue wheel, see [`color_picker_hsva_wheel`].
+///
+/// Returns `true` on change.
+pub fn color_picker_color32_wheel(
+    ui: &mut Ui,
+    srgba: &mut Color32,
+    alpha: Alpha,
+    harmony: ColorHarmony,
+) -> bool {
+    let mut hsva = color_cache_get(ui.ctx(), *srgba);
+    let changed = color_picker_hsva_wheel(ui, &mut hsva, alpha, harmony);
+    *srgba = Color32::from(hsva);
+    color_cache_set(ui.ctx(), *srgba, hsva);
+    changed
+}
+
+/// The most colorful an [`Oklcha`] color in the sRGB gamut gets.
+const MAX_CHROMA: f32 = 0.37;
+
+/// Shows a color picker with sliders for perceived lightness, chroma and hue ([`Oklcha`]).
+///
+/// Unlike with HSV, changing the hue keeps the color equally light,
+/// which makes it easy to pick a set of colors that look balanced, e.g. for a theme.
+/// Chroma that doesn't fit in sRGB is reduced, keeping the lightness and hue.
+///
+/// Returns `true` on change.
+pub fn color_picker_color32_oklch(ui: &mut Ui, srgba: &mut Color32, alpha: Alpha) -> bool {
+    // Remember the hue of grays, like the HSV pickers do:
+    let cache_id = Id::new("color_picker_oklch");
+    let ctx = ui.ctx().clone();
+    let use_cache = |f: &mut dyn FnMut(&mut FixedCache<Color32, Oklcha>)| {
+        ctx.data_mut(|d| f(d.get_temp_mut_or_default(cache_id)));
+    };
+    let mut lch = Oklcha::from(*srgba);
+    use_cache(&mut |cache| {
+        if let Some(cached) = cache.get(srgba) {
+            lch = *cached;
+        }
+    });
+    let old_lch = lch;
+
+    ui.vertical(|ui| {
+        ui.horizontal(|ui| {
+            let Oklcha { l, c, h, alpha: a } = lch;
+            let css = if alpha == Alpha::Opaque {
+                format!("oklch({:.1}% {c:.3} {:.1})", 100.0 * l, 360.0 * h)
+            } else {
+                format!("oklch({:.1}% {c:.3} {:.1} / {a:.2})", 100.0 * l, 360.0 * h)
+            };
+            if ui
+                .button("📋")
+                .on_hover_text("Click to copy color value")
+                .clicked()
+            {
+                ui.ctx().copy_text(css.clone());
+            }
+            ui.label(css);
+        });
+
+        let current_color_size = vec2(ui.spacing().slider_width, ui.spacing().interact_size.y);
+        show_color(ui, *srgba, current_color_size).on_hover_text("Selected color");
+
+        let opaque = Oklcha { alpha: 1.0, ..lch };
+        let shown = |lch: Oklcha| Color32::from(lch.clamp_to_srgb_gamut());
+        color_slider_1d(ui, &mut lch.l, |l| shown(Oklcha { l, ..opaque }))
+            .on_hover_text("Lightness");
+        let mut chroma = lch.c / MAX_CHROMA;
+        color_slider_1d(ui, &mut chroma, |c| {
+            shown(Oklcha {
+                c: c * MAX_CHROMA,
+                ..opaque
+            })
+        })
+        .on_hover_text("Chroma");
+        lch.c = chroma * MAX_CHROMA;
+        color_slider_1d(ui, &mut lch.h, |h| shown(Oklcha { h, ..opaque })).on_hover_text("Hue");
+        if alpha == Alpha::Opaque {
+            lch.alpha = 1.0;
+        } else {
+            color_slider_1d(ui, &mut lch.alpha, |alpha| {
+                shown(Oklcha { alpha, ..opaque })
+            })
+            .on_hover_text("Alpha");
+        }
+    });
+
+    if lch == old_lch {
+        return false;
+    }
+    *srgba = Color32::from(lch.clamp_to_srgb_gamut());
+    use_cache(&mut |cache| cache.set(*srgba, lch));
+    true
+}
+
 pub fn color_edit_button_hsva(ui: &mut Ui, hsva: &mut Hsva, alpha: Alpha) -> Response {
     let popup_id = ui.auto_id_with("popup");
     let open = Popup::is_id_open(ui.ctx(), popup_id);
@@ -571,3 +897,24 @@ fn color_cache_set(ctx: &Context, rgba: impl Into<Rgba>, hsva: Hsva) {
 fn use_color_cache<R>(ctx: &Context, f: impl FnOnce(&mut FixedCache<Rgba, Hsva>) -> R) -> R {
     ctx.data_mut(|d| f(d.get_temp_mut_or_default(Id::NULL)))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wheel_triangle() {
+        let wheel = Wheel {
+            center: pos2(50.0, 50.0),
+            inner_radius: 40.0,
+        };
+        for (h, s, v) in [(0.0, 1.0, 1.0), (0.3, 0.5, 0.25), (0.9, 0.0, 0.8)] {
+            let (s2, v2) = wheel.saturation_value(h, wheel.pos(h, s, v));
+            assert!((s - s2).abs() < 1e-4 && (v - v2).abs() < 1e-4, "{s2} {v2}");
+        }
+
+        // Outside the triangle, the color is clamped to it:
+        let (s, v) = wheel.saturation_value(0.0, pos2(200.0, 50.0));
+        assert_eq!((s, v), (1.0, 1.0));
+    }
+}
@@ -302,6 +302,22 @@ impl<'a> DragValue<'a> {
         self
     }
 
+    /// Format and parse the number with this [`crate::style::NumberFormatter`]
+    /// instead of [`crate::Style::number_formatter`].
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut my_f64: f64 = 0.0;
+    /// use egui::style::{NumberFormatter, NumberLocale};
+    /// ui.add(egui::DragValue::new(&mut my_f64).number_formatter(NumberFormatter::from_locale(NumberLocale::GERMAN)));
+    /// # });
+    /// ```
+    pub fn number_formatter(self, number_formatter: crate::style::NumberFormatter) -> Self {
+        let parser = number_formatter.clone();
+        self.custom_formatter(move |n, decimals| number_formatter.format(n, decimals))
+            .custom_parser(move |text| parser.parse(text))
+    }
+
     /// Set `custom_formatter` and `custom_parser` to display and parse numbers as binary integers. Floating point
     /// numbers are *not* supported.
     ///
@@ -540,7 +556,7 @@ impl Widget for DragValue<'_> {
             if let Some(value_text) = value_text {
                 // We were editing the value as text last frame, but lost focus.
                 // Make sure we applied the last text value:
-                let parsed_value = parse(&custom_parser, &value_text);
+                let parsed_value = parse(ui, &custom_parser, &value_text);
                 if let Some(mut parsed_value) = parsed_value {
                     // User edits always clamps:
                     parsed_value = clamp_value_to_range(parsed_value, range.clone());
@@ -582,7 +598,7 @@ impl Widget for DragValue<'_> {
                 response.lost_focus() && !ui.input(|i| i.key_pressed(Key::Escape))
             };
             if update {
-                let parsed_value = parse(&custom_parser, &value_text);
+                let parsed_value = parse(ui, &custom_parser, &value_text);
                 if let Some(mut parsed_value) = parsed_value {
                     // User edits always clamps:
                     parsed_value = clamp_value_to_range(parsed_value, range.clone());
@@ -720,17 +736,17 @@ impl Widget for DragValue<'_> {
     }
 }
 
-fn parse(custom_parser: &Option<NumParser<'_>>, value_text: &str) -> Option<f64> {
+fn parse(ui: &Ui, custom_parser: &Option<NumParser<'_>>, value_text: &str) -> Option<f64> {
     match &custom_parser {
         Some(parser) => parser(value_text),
-        None => default_parser(value_text),
+        None => ui.style().number_formatter.parse(value_text),
     }
 }
 
 /// The default egui parser of numbers.
 ///
 /// It ignored whitespaces anywhere in the input, and treats the special minus character (U+2212) as a normal minus.
-fn default_parser(text: &str) -> Option<f64> {
+pub(crate) fn default_parser(text: &str) -> Option<f64> {
     let text: String = text
         .chars()
         // Ignore whitespace (trailing, leading, and thousands separators):
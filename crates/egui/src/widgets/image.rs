@@ -8,7 +8,7 @@ use epaint::{
 
 use crate::{
     Color32, Context, CornerRadius, Id, Mesh, Painter, Rect, Response, Sense, Shape, Spinner,
-    TextStyle, TextureOptions, Ui, Vec2, Widget, WidgetInfo, WidgetType,
+    TextStyle, TextureOptions, Ui, Vec2, Vec2b, Widget, WidgetInfo, WidgetType,
     load::{Bytes, SizeHint, SizedTexture, TextureLoadResult, TexturePoll},
     pos2,
 };
@@ -225,6 +225,39 @@ impl<'a> Image<'a> {
         self
     }
 
+    /// Multiply the image color with a gradient, given by the colors at the corners:
+    /// `[left_top, right_top, left_bottom, right_bottom]`.
+    ///
+    /// This is multiplied with [`Self::tint`].
+    /// For instance, `[WHITE, WHITE, TRANSPARENT, TRANSPARENT]` fades the image out towards the bottom.
+    ///
+    /// Due to limitations in the current implementation,
+    /// this will turn off rounding of the image.
+    #[inline]
+    pub fn tint_gradient(mut self, corners: [Color32; 4]) -> Self {
+        self.image_options.tint_gradient = Some(corners);
+        self.image_options.corner_radius = CornerRadius::ZERO; // incompatible with gradients
+        self
+    }
+
+    /// Make the image (and its [`Self::bg_fill`]) semi-transparent.
+    ///
+    /// Default: `1.0` (opaque).
+    #[inline]
+    pub fn opacity(mut self, opacity: f32) -> Self {
+        self.image_options.opacity = opacity;
+        self
+    }
+
+    /// Mirror the image horizontally (`x`) and/or vertically (`y`).
+    ///
+    /// This is applied to the [`Self::uv`] range.
+    #[inline]
+    pub fn flip(mut self, flip: impl Into<Vec2b>) -> Self {
+        self.image_options.flip = flip.into();
+        self
+    }
+
     /// Rotate the image about an origin by some angle
     ///
     /// Positive angle is clockwise.
@@ -246,12 +279,14 @@ impl<'a> Image<'a> {
     /// The default is no rounding ([`CornerRadius::ZERO`]).
     ///
     /// Due to limitations in the current implementation,
-    /// this will turn off any rotation of the image.
+    /// this will turn off any rotation and tint gradient of the image.
     #[inline]
     pub fn corner_radius(mut self, corner_radius: impl Into<CornerRadius>) -> Self {
         self.image_options.corner_radius = corner_radius.into();
         if self.image_options.corner_radius != CornerRadius::ZERO {
-            self.image_options.rotation = None; // incompatible with rounding
+            // incompatible with rounding:
+            self.image_options.rotation = None;
+            self.image_options.tint_gradient = None;
         }
         self
     }
@@ -365,6 +400,22 @@ impl<'a> Image<'a> {
             .load(ctx, self.texture_options, size_hint)
     }
 
+    /// While the image is still loading, a low-resolution preview of it to paint instead,
+    /// if its loader has one.
+    ///
+    /// See [`Context::try_load_texture_preview`].
+    pub(crate) fn load_preview(
+        &self,
+        ctx: &Context,
+        tlr: &TextureLoadResult,
+    ) -> Option<TextureLoadResult> {
+        if !matches!(tlr, Ok(TexturePoll::Pending { .. })) {
+            return None;
+        }
+        let texture = self.source(ctx).load_preview(ctx, self.texture_options)?;
+        Some(Ok(TexturePoll::Ready { texture }))
+    }
+
     /// Paint the image in the given rectangle.
     ///
     /// ```
@@ -396,9 +447,10 @@ impl<'a> Image<'a> {
             },
         );
 
+        let preview = self.load_preview(ui.ctx(), &texture);
         paint_texture_load_result(
             ui,
-            &texture,
+            preview.as_ref().unwrap_or(&texture),
             rect,
             self.show_loading_spinner,
             &self.image_options,
@@ -410,7 +462,13 @@ impl<'a> Image<'a> {
 impl Widget for Image<'_> {
     fn ui(self, ui: &mut Ui) -> Response {
         let tlr = self.load_for_size(ui.ctx(), ui.available_size());
-        let image_source_size = tlr.as_ref().ok().and_then(|t| t.size());
+        let preview = self.load_preview(ui.ctx(), &tlr);
+        let image_source_size = preview
+            .as_ref()
+            .unwrap_or(&tlr)
+            .as_ref()
+            .ok()
+            .and_then(|t| t.size());
         let ui_size = self.calc_size(ui.available_size(), image_source_size);
 
         let (rect, response) = ui.allocate_exact_size(ui_size, self.sense);
@@ -422,7 +480,7 @@ impl Widget for Image<'_> {
         if ui.is_rect_visible(rect) {
             paint_texture_load_result(
                 ui,
-                &tlr,
+                preview.as_ref().unwrap_or(&tlr),
                 rect,
                 self.show_loading_spinner,
                 &self.image_options,
@@ -656,6 +714,22 @@ impl ImageSource<'_> {
         }
     }
 
+    /// A low-resolution preview of the image while it is still loading, if its loader has one.
+    ///
+    /// See [`Context::try_load_texture_preview`].
+    pub fn load_preview(
+        &self,
+        ctx: &Context,
+        texture_options: TextureOptions,
+    ) -> Option<SizedTexture> {
+        match self {
+            Self::Texture(_) => None,
+            Self::Uri(uri) | Self::Bytes { uri, .. } => {
+                ctx.try_load_texture_preview(uri, texture_options)
+            }
+        }
+    }
+
     /// Get the `uri` that this image was constructed from.
     ///
     /// This will return `None` for [`Self::Texture`].
@@ -806,6 +880,7 @@ impl<T: Into<SizedTexture>> From<T> for ImageSource<'static> {
 
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct ImageOptions {
     /// Select UV range. Default is (0,0) in top-left, (1,1) bottom right.
     pub uv: Rect,
@@ -816,6 +891,19 @@ pub struct ImageOptions {
     /// Multiply image color with this. Default is WHITE (no tint).
     pub tint: Color32,
 
+    /// Multiply the image color with a gradient, given by the colors at the corners:
+    /// `[left_top, right_top, left_bottom, right_bottom]`.
+    ///
+    /// Due to limitations in the current implementation,
+    /// this will turn off rounding of the image.
+    pub tint_gradient: Option<[Color32; 4]>,
+
+    /// Multiply the opacity of the image and its background with this. Default is `1.0`.
+    pub opacity: f32,
+
+    /// Mirror the image horizontally (`x`) and/or vertically (`y`).
+    pub flip: Vec2b,
+
     /// Rotate the image about an origin by some angle
     ///
     /// Positive angle is clockwise.
@@ -832,7 +920,7 @@ pub struct ImageOptions {
     /// The default is no rounding ([`CornerRadius::ZERO`]).
     ///
     /// Due to limitations in the current implementation,
-    /// this will turn off any rotation of the image.
+    /// this will turn off any rotation and tint gradient of the image.
     pub corner_radius: CornerRadius,
 }
 
@@ -842,6 +930,9 @@ impl Default for ImageOptions {
             uv: Rect::from_min_max(pos2(0.0, 0.0), pos2(1.0, 1.0)),
             bg_fill: Default::default(),
             tint: Color32::WHITE,
+            tint_gradient: None,
+            opacity: 1.0,
+            flip: Vec2b::FALSE,
             rotation: None,
             corner_radius: CornerRadius::ZERO,
         }
@@ -854,34 +945,47 @@ pub fn paint_texture_at(
     options: &ImageOptions,
     texture: &SizedTexture,
 ) {
+    let opacity = options.opacity.clamp(0.0, 1.0);
     if options.bg_fill != Default::default() {
         painter.add(RectShape::filled(
             rect,
             options.corner_radius,
-            options.bg_fill,
+            options.bg_fill.gamma_multiply(opacity),
         ));
     }
 
-    match options.rotation {
-        Some((rot, origin)) => {
-            // TODO(emilk): implement this using `PathShape` (add texture support to it).
-            // This will also give us anti-aliasing of rotated images.
-            debug_assert!(
-                options.corner_radius == CornerRadius::ZERO,
-                "Image had both rounding and rotation. Please pick only one"
-            );
+    let mut uv = options.uv;
+    if options.flip.x {
+        std::mem::swap(&mut uv.min.x, &mut uv.max.x);
+    }
+    if options.flip.y {
+        std::mem::swap(&mut uv.min.y, &mut uv.max.y);
+    }
+    let tint = options.tint.gamma_multiply(opacity);
+
+    if options.rotation.is_some() || options.tint_gradient.is_some() {
+        // TODO(emilk): implement this using `PathShape` (add texture support to it).
+        // This will also give us anti-aliasing of rotated images.
+        debug_assert!(
+            options.corner_radius == CornerRadius::ZERO,
+            "Image had both rounding and rotation or a tint gradient. Please pick only one"
+        );
 
-            let mut mesh = Mesh::with_texture(texture.id);
-            mesh.add_rect_with_uv(rect, options.uv, options.tint);
-            mesh.rotate(rot, rect.min + origin * rect.size());
-            painter.add(Shape::mesh(mesh));
+        let mut mesh = Mesh::with_texture(texture.id);
+        mesh.add_rect_with_uv(rect, uv, tint);
+        if let Some(corners) = options.tint_gradient {
+            // `add_rect_with_uv` adds the corners in this same order:
+            for (vertex, color) in mesh.vertices.iter_mut().zip(corners) {
+                vertex.color = tint * color;
+            }
         }
-        None => {
-            painter.add(
-                RectShape::filled(rect, options.corner_radius, options.tint)
-                    .with_texture(texture.id, options.uv),
-            );
+        if let Some((rot, origin)) = options.rotation {
+            mesh.rotate(rot, rect.min + origin * rect.size());
         }
+        painter.add(Shape::mesh(mesh));
+    } else {
+        painter
+            .add(RectShape::filled(rect, options.corner_radius, tint).with_texture(texture.id, uv));
     }
 }
 
@@ -970,3 +1074,38 @@ fn is_animated_image_uri(uri: &str) -> bool {
 fn are_animated_image_bytes(bytes: &[u8]) -> bool {
     has_gif_magic_header(bytes) || has_webp_header(bytes)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CentralPanel, TextureId, vec2};
+
+    #[test]
+    fn test_flip_and_tint_gradient() {
+        let ctx = Context::default();
+        let output = ctx.run(Default::default(), |ctx| {
+            CentralPanel::default().show(ctx, |ui| {
+                let rect = Rect::from_min_size(pos2(10.0, 10.0), vec2(20.0, 20.0));
+                Image::from_texture((TextureId::Managed(0), vec2(20.0, 20.0)))
+                    .flip([true, false])
+                    .tint_gradient([Color32::RED, Color32::WHITE, Color32::WHITE, Color32::WHITE])
+                    .opacity(0.5)
+                    .paint_at(ui, rect);
+            });
+        });
+
+        let mesh = output
+            .shapes
+            .iter()
+            .find_map(|clipped| match &clipped.shape {
+                Shape::Mesh(mesh) => Some(mesh.clone()),
+                _ => None,
+            })
+            .expect("a tint gradient is painted as a mesh");
+        let left_top = mesh.vertices[0];
+        assert_eq!(left_top.pos, pos2(10.0, 10.0));
+        assert_eq!(left_top.uv, pos2(1.0, 0.0), "flipped horizontally");
+        assert_eq!(left_top.color, Color32::RED.gamma_multiply(0.5));
+        assert_eq!(mesh.vertices[1].color, Color32::WHITE.gamma_multiply(0.5));
+    }
+}
@@ -93,7 +93,13 @@ impl Widget for ImageButton<'_> {
 
         let available_size_for_image = ui.available_size() - 2.0 * padding;
         let tlr = self.image.load_for_size(ui.ctx(), available_size_for_image);
-        let image_source_size = tlr.as_ref().ok().and_then(|t| t.size());
+        let preview = self.image.load_preview(ui.ctx(), &tlr);
+        let image_source_size = preview
+            .as_ref()
+            .unwrap_or(&tlr)
+            .as_ref()
+            .ok()
+            .and_then(|t| t.size());
         let image_size = self
             .image
             .calc_size(available_size_for_image, image_source_size);
@@ -140,7 +146,7 @@ impl Widget for ImageButton<'_> {
 
             widgets::image::paint_texture_load_result(
                 ui,
-                &tlr,
+                preview.as_ref().unwrap_or(&tlr),
                 image_rect,
                 None,
                 &image_options,
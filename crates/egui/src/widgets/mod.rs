@@ -14,12 +14,17 @@ mod hyperlink;
 mod image;
 mod image_button;
 mod label;
+mod password_edit;
 mod progress_bar;
 mod radio_button;
+mod rating;
 mod selected_label;
 mod separator;
+mod shortcut_recorder;
 mod slider;
 mod spinner;
+mod steps;
+mod tab_bar;
 pub mod text_edit;
 
 #[expect(deprecated)]
@@ -35,11 +40,16 @@ pub use self::{
     },
     image_button::ImageButton,
     label::Label,
+    password_edit::PasswordEdit,
     progress_bar::ProgressBar,
     radio_button::RadioButton,
+    rating::{Rating, RatingSymbol},
     separator::Separator,
+    shortcut_recorder::ShortcutRecorder,
     slider::{Slider, SliderClamping, SliderOrientation},
     spinner::Spinner,
+    steps::Steps,
+    tab_bar::{TabBar, TabBarResponse},
     text_edit::{TextBuffer, TextEdit},
 };
 
@@ -147,6 +157,20 @@ pub fn global_theme_preference_buttons(ui: &mut Ui) {
     ui.ctx().set_theme(theme_preference);
 }
 
+/// Show buttons for switching between [`crate::Density`] presets (globally, for both themes).
+pub fn global_density_buttons(ui: &mut Ui) {
+    let current = crate::Density::of(&ui.style().spacing);
+    for density in crate::Density::ALL {
+        if ui
+            .selectable_label(current == Some(density), density.name())
+            .clicked()
+        {
+            ui.ctx()
+                .all_styles_mut(|style| style.spacing = density.spacing());
+        }
+    }
+}
+
 /// Show a small button to switch to/from dark/light mode (globally).
 #[deprecated = "Use global_theme_preference_switch instead"]
 pub fn global_dark_light_mode_switch(ui: &mut Ui) {
@@ -0,0 +1,282 @@
+use crate::{
+    Button, Color32, Event, Id, NumExt as _, Response, RichText, TextEdit, Ui, Vec2, Widget, vec2,
+};
+
+/// A single-line [`TextEdit`] for passwords.
+///
+/// * The password is hidden, with a button to reveal it.
+/// * It can never be copied, and is kept out of the undo history.
+/// * A warning is shown if caps lock seems to be on (guessed from the letters typed).
+/// * Optionally, a meter shows how strong the password is, see [`Self::strength_meter`].
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut password = String::new();
+/// ui.add(egui::PasswordEdit::new(&mut password).strength_meter(true));
+/// # });
+/// ```
+#[must_use = "You should put this widget in a ui with `ui.add(widget);`"]
+pub struct PasswordEdit<'a> {
+    password: &'a mut String,
+    id_salt: Option<Id>,
+    hint_text: Option<String>,
+    desired_width: Option<f32>,
+    strength_meter: bool,
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+struct State {
+    revealed: bool,
+
+    /// Guessed from the text typed, since egui doesn't know the state of caps lock.
+    caps_lock: bool,
+}
+
+impl<'a> PasswordEdit<'a> {
+    pub fn new(password: &'a mut String) -> Self {
+        Self {
+            password,
+            id_salt: None,
+            hint_text: None,
+            desired_width: None,
+            strength_meter: false,
+        }
+    }
+
+    /// Use this if the id of the password field isn't unique, or changes from pass to pass.
+    #[inline]
+    pub fn id_salt(mut self, id_salt: impl std::hash::Hash) -> Self {
+        self.id_salt = Some(Id::new(id_salt));
+        self
+    }
+
+    /// Shown when the field is empty, like [`TextEdit::hint_text`].
+    #[inline]
+    pub fn hint_text(mut self, hint_text: impl Into<String>) -> Self {
+        self.hint_text = Some(hint_text.into());
+        self
+    }
+
+    /// The width of the text field, not counting the reveal button.
+    ///
+    /// Default: [`crate::style::Spacing::text_edit_width`].
+    #[inline]
+    pub fn desired_width(mut self, desired_width: f32) -> Self {
+        self.desired_width = Some(desired_width);
+        self
+    }
+
+    /// Show a bar below the field with an estimate of how strong the password is.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn strength_meter(mut self, strength_meter: bool) -> Self {
+        self.strength_meter = strength_meter;
+        self
+    }
+
+    /// A rough estimate of the entropy of a password, in bits.
+    ///
+    /// Based on the length and the kinds of characters used (lowercase, uppercase, digits, symbols),
+    /// so a dictionary word with a digit appended scores higher than it should.
+    pub fn entropy_bits(password: &str) -> f32 {
+        let mut pool = 0;
+        let mut has = |kind: fn(&char) -> bool, size: u32| {
+            if password.chars().any(|c| kind(&c)) {
+                pool += size;
+            }
+        };
+        has(char::is_ascii_lowercase, 26);
+        has(char::is_ascii_uppercase, 26);
+        has(char::is_ascii_digit, 10);
+        has(char::is_ascii_punctuation, 32);
+        has(|c| *c == ' ', 1);
+        has(|c| !c.is_ascii(), 100);
+        if pool == 0 {
+            0.0
+        } else {
+            password.chars().count() as f32 * (pool as f32).log2()
+        }
+    }
+}
+
+/// How strong a password with the given entropy is, from 0 to 4, and what to call it.
+fn strength(bits: f32) -> (usize, &'static str) {
+    if bits < 28.0 {
+        (0, "Very weak")
+    } else if bits < 36.0 {
+        (1, "Weak")
+    } else if bits < 60.0 {
+        (2, "Fair")
+    } else if bits < 128.0 {
+        (3, "Strong")
+    } else {
+        (4, "Very strong")
+    }
+}
+
+impl Widget for PasswordEdit<'_> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Self {
+            password,
+            id_salt,
+            hint_text,
+            desired_width,
+            strength_meter,
+        } = self;
+
+        let id = id_salt.map_or_else(
+            || ui.next_auto_id(),
+            |id_salt| ui.make_persistent_id(id_salt),
+        );
+        let state_id = id.with("password");
+        let mut state: State = ui.data(|d| d.get_temp(state_id)).unwrap_or_default();
+
+        if ui.memory(|m| m.has_focus(id)) {
+            ui.input(|i| {
+                for event in &i.events {
+                    if let Event::Text(text) = event {
+                        // The last letter that has a case:
+                        let letter = text
+                            .chars()
+                            .rev()
+                            .find(|c| c.is_lowercase() != c.is_uppercase());
+                        if let Some(c) = letter {
+                            // An uppercase letter without shift, or a lowercase one with it:
+                            state.caps_lock = c.is_uppercase() != i.modifiers.shift;
+                        }
+                    }
+                }
+            });
+        }
+
+        let inner = ui.vertical(|ui| {
+            let text_edit_response = ui
+                .horizontal(|ui| {
+                    ui.spacing_mut().item_spacing.x = 0.0;
+                    let mut text_edit = TextEdit::singleline(password)
+                        .id(id)
+                        .password(true)
+                        .reveal_password(state.revealed)
+                        .desired_width(desired_width.unwrap_or(ui.spacing().text_edit_width));
+                    if let Some(hint_text) = hint_text {
+                        text_edit = text_edit.hint_text(hint_text);
+                    }
+                    let response = ui.add(text_edit);
+
+                    let (icon, tooltip) = if state.revealed {
+                        ("🔒", "Hide password")
+                    } else {
+                        ("👁", "Show password")
+                    };
+                    let button = Button::new(icon)
+                        .selected(state.revealed)
+                        .min_size(Vec2::splat(response.rect.height()));
+                    if ui.add(button).on_hover_text(tooltip).clicked() {
+                        state.revealed = !state.revealed;
+                    }
+                    response
+                })
+                .inner;
+
+            if state.caps_lock && text_edit_response.has_focus() {
+                ui.colored_label(ui.visuals().warn_fg_color, "⚠ Caps lock is on");
+            }
+
+            if strength_meter && !password.is_empty() {
+                let (level, name) = strength(PasswordEdit::entropy_bits(password));
+                let color = match level {
+                    0 => ui.visuals().error_fg_color,
+                    1 => ui.visuals().warn_fg_color,
+                    2 => Color32::from_rgb(160, 190, 40),
+                    _ => Color32::from_rgb(40, 170, 70),
+                };
+                ui.horizontal(|ui| {
+                    let label_spacing = ui.spacing().item_spacing.x;
+                    let gap = 2.0;
+                    let width = (0.5 * text_edit_response.rect.width() - 3.0 * gap) / 4.0;
+                    let segment_size = vec2(width.at_least(1.0), 4.0);
+                    ui.spacing_mut().item_spacing.x = gap;
+                    for i in 0..4 {
+                        let (rect, _) = ui.allocate_exact_size(segment_size, crate::Sense::hover());
+                        let fill = if i < level.max(1) {
+                            color
+                        } else {
+                            ui.visuals().extreme_bg_color
+                        };
+                        ui.painter().rect_filled(rect, 1.0, fill);
+                    }
+                    ui.add_space(label_spacing - gap);
+                    ui.label(RichText::new(name).small().color(color));
+                });
+            }
+
+            text_edit_response
+        });
+
+        ui.data_mut(|d| d.insert_temp(state_id, state));
+        inner.inner
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Context, Key, Modifiers, RawInput};
+
+    #[test]
+    fn test_password_strength() {
+        assert_eq!(PasswordEdit::entropy_bits(""), 0.0);
+        assert_eq!(strength(PasswordEdit::entropy_bits("abc")).0, 0);
+        assert_eq!(
+            strength(PasswordEdit::entropy_bits("correct horse battery")).0,
+            3
+        );
+    }
+
+    #[test]
+    fn test_password_not_undoable() {
+        let ctx = Context::default();
+        let mut password = String::new();
+        let run = |password: &mut String, events: Vec<Event>| {
+            let input = RawInput {
+                events,
+                ..Default::default()
+            };
+            let mut id = Id::NULL;
+            let output = ctx.run(input, |ctx| {
+                crate::CentralPanel::default().show(ctx, |ui| {
+                    id = ui.add(PasswordEdit::new(password).id_salt("password")).id;
+                    ui.memory_mut(|m| m.request_focus(id));
+                });
+            });
+            (id, output.platform_output.commands)
+        };
+
+        run(&mut password, vec![]);
+        run(&mut password, vec![]);
+        run(&mut password, vec![Event::Text("hunter2".to_owned())]);
+        assert_eq!(password, "hunter2");
+
+        let command = |key| Event::Key {
+            key,
+            physical_key: None,
+            pressed: true,
+            repeat: false,
+            modifiers: Modifiers::COMMAND,
+        };
+        let (id, commands) = run(&mut password, vec![command(Key::A), Event::Copy]);
+        assert!(
+            !commands
+                .iter()
+                .any(|command| matches!(command, crate::OutputCommand::CopyText(_))),
+            "Passwords should never be copied"
+        );
+        run(&mut password, vec![command(Key::Z)]);
+        assert_eq!(password, "hunter2", "Undo should not know the password");
+
+        let state = TextEdit::load_state(&ctx, id).unwrap();
+        let other_state = (Default::default(), "something else".to_owned());
+        assert!(!state.undoer().has_undo(&other_state));
+    }
+}
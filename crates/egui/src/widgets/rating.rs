@@ -0,0 +1,320 @@
+use crate::{
+    Color32, EventFilter, Key, Painter, Pos2, Rect, Response, Sense, Shape, Stroke, Ui, Vec2,
+    Widget, WidgetInfo, epaint, vec2,
+};
+
+/// The kind of symbol a [`Rating`] is shown with.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum RatingSymbol {
+    #[default]
+    Star,
+    Heart,
+}
+
+/// A row of stars (or hearts) for showing and picking a rating.
+///
+/// The value can be fractional, e.g. `3.5` out of `5`, and is drawn as a partially filled symbol.
+/// Hovering shows a preview of what a click would pick.
+/// When focused, the arrow keys, `Home` and `End` adjust the value.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut rating = 3.5;
+/// ui.add(egui::Rating::new(&mut rating).max(5).step(0.5));
+///
+/// // Just show the value:
+/// ui.add(egui::Rating::new(&mut 4.2).interactive(false));
+/// # });
+/// ```
+#[must_use = "You should put this widget in a ui with `ui.add(widget);`"]
+pub struct Rating<'a> {
+    value: &'a mut f32,
+    max: usize,
+    step: f32,
+    symbol: RatingSymbol,
+    size: Option<f32>,
+    fill: Option<Color32>,
+    interactive: bool,
+}
+
+impl<'a> Rating<'a> {
+    pub fn new(value: &'a mut f32) -> Self {
+        Self {
+            value,
+            max: 5,
+            step: 1.0,
+            symbol: RatingSymbol::Star,
+            size: None,
+            fill: None,
+            interactive: true,
+        }
+    }
+
+    /// How many symbols to show, i.e. the highest possible value.
+    ///
+    /// Default: 5.
+    #[inline]
+    pub fn max(mut self, max: usize) -> Self {
+        self.max = max;
+        self
+    }
+
+    /// The user can pick values in multiples of this.
+    ///
+    /// Default: `1.0`, use `0.5` to allow half stars.
+    #[inline]
+    pub fn step(mut self, step: f32) -> Self {
+        self.step = step;
+        self
+    }
+
+    /// Default: [`RatingSymbol::Star`].
+    #[inline]
+    pub fn symbol(mut self, symbol: RatingSymbol) -> Self {
+        self.symbol = symbol;
+        self
+    }
+
+    /// The size of each symbol.
+    ///
+    /// Default: [`crate::style::Spacing::interact_size`]`.y`.
+    #[inline]
+    pub fn size(mut self, size: f32) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// The color of the filled part.
+    ///
+    /// Default: [`crate::Visuals::warn_fg_color`] for stars and
+    /// [`crate::Visuals::error_fg_color`] for hearts.
+    #[inline]
+    pub fn fill(mut self, fill: Color32) -> Self {
+        self.fill = Some(fill);
+        self
+    }
+
+    /// If `false`, the rating is only shown, and can't be changed.
+    ///
+    /// Default: `true`.
+    #[inline]
+    pub fn interactive(mut self, interactive: bool) -> Self {
+        self.interactive = interactive;
+        self
+    }
+}
+
+impl Widget for Rating<'_> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Self {
+            value,
+            max,
+            step,
+            symbol,
+            size,
+            fill,
+            interactive,
+        } = self;
+
+        let max_value = max as f32;
+        let step = if 0.0 < step { step } else { 1.0 };
+        let snap = |v: f32| ((v / step).round() * step).clamp(0.0, max_value);
+
+        let size = size.unwrap_or(ui.spacing().interact_size.y);
+        let gap = 0.2 * size;
+        let desired_size = vec2(max as f32 * (size + gap) - gap, size).max(Vec2::ZERO);
+        let sense = if interactive {
+            Sense::click_and_drag()
+        } else {
+            Sense::hover()
+        };
+        let (rect, mut response) = ui.allocate_exact_size(desired_size, sense);
+
+        // The value the pointer is over, rounded up to the next step:
+        let value_at = |pos: Pos2| {
+            let x = pos.x - rect.left();
+            let index = (x / (size + gap)).floor();
+            let within = ((x - index * (size + gap)) / size).clamp(0.0, 1.0);
+            (((index + within) / step).ceil() * step).clamp(step.min(max_value), max_value)
+        };
+
+        let mut preview = None;
+        if interactive {
+            if let Some(pos) = response.hover_pos() {
+                preview = Some(value_at(pos));
+            }
+            if response.clicked() || response.dragged() {
+                if let Some(pos) = response.interact_pointer_pos() {
+                    let new_value = value_at(pos);
+                    if *value != new_value {
+                        *value = new_value;
+                        response.mark_changed();
+                    }
+                }
+            }
+
+            let mut delta = 0.0;
+            if response.has_focus() {
+                ui.ctx().memory_mut(|m| {
+                    m.set_focus_lock_filter(
+                        response.id,
+                        EventFilter {
+                            horizontal_arrows: true,
+                            ..Default::default()
+                        },
+                    );
+                });
+                ui.input(|i| {
+                    delta += (i.num_presses(Key::ArrowRight) + i.num_presses(Key::ArrowUp)) as f32;
+                    delta -= (i.num_presses(Key::ArrowLeft) + i.num_presses(Key::ArrowDown)) as f32;
+                    if i.key_pressed(Key::Home) {
+                        delta = -max_value / step;
+                    }
+                    if i.key_pressed(Key::End) {
+                        delta = max_value / step;
+                    }
+                });
+            }
+
+            #[cfg(feature = "accesskit")]
+            {
+                use accesskit::{Action, ActionData};
+                ui.input(|input| {
+                    delta +=
+                        input.num_accesskit_action_requests(response.id, Action::Increment) as f32;
+                    delta -=
+                        input.num_accesskit_action_requests(response.id, Action::Decrement) as f32;
+                    for request in input.accesskit_action_requests(response.id, Action::SetValue) {
+                        if let Some(ActionData::NumericValue(new_value)) = request.data {
+                            *value = snap(new_value as f32);
+                            response.mark_changed();
+                        }
+                    }
+                });
+            }
+
+            if delta != 0.0 {
+                let new_value = snap(*value + delta * step);
+                if *value != new_value {
+                    *value = new_value;
+                    response.mark_changed();
+                }
+            }
+        }
+
+        response.widget_info(|| WidgetInfo::slider(ui.is_enabled(), *value as f64, "Rating"));
+
+        #[cfg(feature = "accesskit")]
+        ui.ctx().accesskit_node_builder(response.id, |builder| {
+            builder.set_min_numeric_value(0.0);
+            builder.set_max_numeric_value(max_value as f64);
+            builder.set_numeric_value_step(step as f64);
+            if interactive {
+                use accesskit::Action;
+                builder.add_action(Action::SetValue);
+                builder.add_action(Action::Increment);
+                builder.add_action(Action::Decrement);
+            }
+        });
+
+        if ui.is_rect_visible(rect) {
+            let visuals = ui.style().interact(&response);
+            let fill = fill.unwrap_or(match symbol {
+                RatingSymbol::Star => ui.visuals().warn_fg_color,
+                RatingSymbol::Heart => ui.visuals().error_fg_color,
+            });
+            let shown = preview.unwrap_or(*value).clamp(0.0, max_value);
+            let fill = if preview.is_some() {
+                fill.gamma_multiply(0.7)
+            } else {
+                fill
+            };
+            let empty_stroke = Stroke::new(1.0, visuals.fg_stroke.color.gamma_multiply(0.5));
+
+            for i in 0..max {
+                let symbol_rect = Rect::from_min_size(
+                    rect.min + vec2(i as f32 * (size + gap), 0.0),
+                    Vec2::splat(size),
+                );
+                let points = symbol_outline(symbol, symbol_rect);
+                let fraction = (shown - i as f32).clamp(0.0, 1.0);
+                if 0.0 < fraction {
+                    let mut clip = symbol_rect;
+                    clip.max.x = symbol_rect.left() + fraction * symbol_rect.width();
+                    let painter = ui.painter().with_clip_rect(clip.intersect(ui.clip_rect()));
+                    paint_filled(&painter, symbol_rect.center(), &points, fill);
+                }
+                let stroke = if 1.0 <= fraction {
+                    Stroke::new(1.0, fill)
+                } else {
+                    empty_stroke
+                };
+                ui.painter().add(Shape::closed_line(points, stroke));
+            }
+
+            if response.has_focus() {
+                ui.painter().rect_stroke(
+                    rect.expand(2.0),
+                    visuals.corner_radius,
+                    ui.visuals().selection.stroke,
+                    epaint::StrokeKind::Outside,
+                );
+            }
+        }
+
+        response
+    }
+}
+
+/// The outline of the symbol, fitted to `rect`, going around its center.
+fn symbol_outline(symbol: RatingSymbol, rect: Rect) -> Vec<Pos2> {
+    let center = rect.center();
+    let radius = 0.5 * rect.width().min(rect.height());
+    match symbol {
+        RatingSymbol::Star => (0..10)
+            .map(|i| {
+                let angle = std::f32::consts::TAU * i as f32 / 10.0 - std::f32::consts::FRAC_PI_2;
+                let r = if i % 2 == 0 { radius } else { 0.4 * radius };
+                center + r * Vec2::angled(angle)
+            })
+            .collect(),
+        RatingSymbol::Heart => (0..32)
+            .map(|i| {
+                // The classic heart curve, which spans roughly -16..=16 horizontally:
+                let t = std::f32::consts::TAU * i as f32 / 32.0;
+                let x = 16.0 * t.sin().powi(3);
+                let y = 13.0 * t.cos()
+                    - 5.0 * (2.0 * t).cos()
+                    - 2.0 * (3.0 * t).cos()
+                    - (4.0 * t).cos();
+                center + radius / 17.0 * vec2(x, -y - 2.5)
+            })
+            .collect(),
+    }
+}
+
+/// Fill a polygon that is star-shaped around `center` (which is all we need here).
+fn paint_filled(painter: &Painter, center: Pos2, points: &[Pos2], fill: Color32) {
+    let mut mesh = epaint::Mesh::default();
+    mesh.colored_vertex(center, fill);
+    for &point in points {
+        mesh.colored_vertex(point, fill);
+    }
+    let n = points.len() as u32;
+    for i in 0..n {
+        mesh.add_triangle(0, 1 + i, 1 + (i + 1) % n);
+    }
+    painter.add(mesh);
+}
+
+#[test]
+fn test_symbol_outline_fits() {
+    let rect = Rect::from_min_size(crate::pos2(10.0, 20.0), Vec2::splat(16.0));
+    for symbol in [RatingSymbol::Star, RatingSymbol::Heart] {
+        let bounds = Rect::from_points(&symbol_outline(symbol, rect));
+        assert!(
+            rect.expand(0.5).contains_rect(bounds),
+            "{symbol:?}: {bounds:?}"
+        );
+    }
+}
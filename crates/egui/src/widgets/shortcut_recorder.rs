@@ -0,0 +1,208 @@
+use crate::{
+    Button, Event, EventFilter, Key, KeyboardShortcut, Response, RichText, Ui, Widget, vec2,
+};
+
+/// A button for rebinding a keyboard shortcut: click it, then press the new key combination.
+///
+/// While recording, the modifiers being held are shown live, and all key presses go to the recorder
+/// (so e.g. `Tab` and other shortcuts can be bound). Press `Escape`, or click anywhere else, to cancel.
+///
+/// [`Response::changed`] is set when a new shortcut was recorded.
+///
+/// To warn about a shortcut already being used for something else, pass the other shortcuts
+/// to [`Self::existing`]. A conflict is shown with a warning color and a tooltip,
+/// but the shortcut is still set, since the user may be about to rebind the other one.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut save = Some(egui::KeyboardShortcut::new(egui::Modifiers::COMMAND, egui::Key::S));
+/// # let open = egui::KeyboardShortcut::new(egui::Modifiers::COMMAND, egui::Key::O);
+/// ui.horizontal(|ui| {
+///     ui.label("Save");
+///     ui.add(egui::ShortcutRecorder::new(&mut save).existing(&[("Open", open)]));
+/// });
+/// # });
+/// ```
+#[doc(alias = "keybinding")]
+#[doc(alias = "hotkey")]
+#[must_use = "You should put this widget in a ui with `ui.add(widget);`"]
+pub struct ShortcutRecorder<'a> {
+    shortcut: &'a mut Option<KeyboardShortcut>,
+    existing: &'a [(&'a str, KeyboardShortcut)],
+}
+
+impl<'a> ShortcutRecorder<'a> {
+    /// `None` means no shortcut is bound.
+    pub fn new(shortcut: &'a mut Option<KeyboardShortcut>) -> Self {
+        Self {
+            shortcut,
+            existing: &[],
+        }
+    }
+
+    /// Other shortcuts, and what they are for, to warn about conflicts with.
+    #[inline]
+    pub fn existing(mut self, existing: &'a [(&'a str, KeyboardShortcut)]) -> Self {
+        self.existing = existing;
+        self
+    }
+}
+
+impl Widget for ShortcutRecorder<'_> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Self { shortcut, existing } = self;
+
+        let id = ui.next_auto_id();
+        let was_recording =
+            ui.data(|d| d.get_temp::<bool>(id)).unwrap_or(false) && ui.memory(|m| m.has_focus(id));
+        let mut recording = was_recording;
+        let mut recorded = false;
+
+        if recording {
+            ui.memory_mut(|m| {
+                m.set_focus_lock_filter(
+                    id,
+                    EventFilter {
+                        tab: true,
+                        horizontal_arrows: true,
+                        vertical_arrows: true,
+                        escape: true,
+                    },
+                );
+            });
+
+            // Take the key presses, so they don't trigger anything else:
+            let pressed = ui.input_mut(|i| {
+                let pressed = i.events.iter().find_map(|event| match event {
+                    Event::Key {
+                        key,
+                        pressed: true,
+                        repeat: false,
+                        modifiers,
+                        ..
+                    } => Some(KeyboardShortcut::new(*modifiers, *key)),
+                    _ => None,
+                });
+                i.events.retain(|event| !matches!(event, Event::Key { .. }));
+                pressed
+            });
+            if let Some(pressed) = pressed {
+                recording = false;
+                if pressed.logical_key != Key::Escape || !pressed.modifiers.is_none() {
+                    recorded = *shortcut != Some(pressed);
+                    *shortcut = Some(pressed);
+                }
+            }
+        }
+
+        let conflict = shortcut.and_then(|shortcut| {
+            existing
+                .iter()
+                .find(|(_, other)| *other == shortcut)
+                .map(|(name, _)| *name)
+        });
+
+        let text = if recording {
+            let modifiers = ui.ctx().format_modifiers(ui.input(|i| i.modifiers));
+            if modifiers.is_empty() {
+                RichText::new("Press a shortcut…")
+            } else {
+                RichText::new(format!("{modifiers}+…"))
+            }
+        } else if let Some(shortcut) = shortcut {
+            let text = ui.ctx().format_shortcut(shortcut);
+            if conflict.is_some() {
+                RichText::new(format!("⚠ {text}")).color(ui.visuals().warn_fg_color)
+            } else {
+                RichText::new(text)
+            }
+        } else {
+            RichText::new("None").weak()
+        };
+
+        let mut response = Button::new(text)
+            .selected(recording)
+            .min_size(vec2(2.0 * ui.spacing().interact_size.x, 0.0))
+            .ui(ui);
+        debug_assert_eq!(response.id, id, "The id should be the next auto id");
+
+        if response.clicked() {
+            recording = !was_recording;
+            if recording {
+                response.request_focus();
+            }
+        }
+        if response.lost_focus() {
+            recording = false;
+        }
+        if recorded {
+            response.mark_changed();
+        }
+        if recording != was_recording {
+            ui.ctx().request_repaint();
+        }
+        ui.data_mut(|d| d.insert_temp(id, recording));
+
+        if let Some(name) = conflict.filter(|_| !recording) {
+            response = response.on_hover_text(format!("Also used for {name}"));
+        }
+        response
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Context, Modifiers, PointerButton, RawInput};
+
+    #[test]
+    fn test_record_shortcut() {
+        let ctx = Context::default();
+        let mut shortcut = None;
+        let mut run = |events: Vec<Event>| {
+            let input = RawInput {
+                events,
+                ..Default::default()
+            };
+            let mut response = None;
+            let _ = ctx.run(input, |ctx| {
+                crate::CentralPanel::default().show(ctx, |ui| {
+                    response = Some(ui.add(ShortcutRecorder::new(&mut shortcut)));
+                });
+            });
+            let response = response.unwrap();
+            (response.rect, response.changed(), shortcut)
+        };
+        let key = |key, modifiers| Event::Key {
+            key,
+            physical_key: None,
+            pressed: true,
+            repeat: false,
+            modifiers,
+        };
+
+        let (rect, _, _) = run(vec![]);
+        let pos = rect.center();
+        let button = |pressed| Event::PointerButton {
+            pos,
+            button: PointerButton::Primary,
+            pressed,
+            modifiers: Modifiers::NONE,
+        };
+        run(vec![Event::PointerMoved(pos), button(true)]);
+        run(vec![button(false)]);
+        run(vec![]);
+
+        let ctrl_k = Some(KeyboardShortcut::new(Modifiers::CTRL, Key::K));
+        let (_, changed, shortcut) = run(vec![key(Key::K, Modifiers::CTRL)]);
+        assert!(changed);
+        assert_eq!(shortcut, ctrl_k);
+
+        // Escape cancels:
+        run(vec![Event::PointerMoved(pos), button(true)]);
+        run(vec![button(false)]);
+        let (_, changed, shortcut) = run(vec![key(Key::Escape, Modifiers::NONE)]);
+        assert!(!changed);
+        assert_eq!(shortcut, ctrl_k);
+    }
+}
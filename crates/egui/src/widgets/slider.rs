@@ -481,6 +481,22 @@ impl<'a> Slider<'a> {
         self
     }
 
+    /// Format and parse the number with this [`crate::style::NumberFormatter`]
+    /// instead of [`crate::Style::number_formatter`].
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut my_f64: f64 = 0.0;
+    /// use egui::style::{NumberFormatter, NumberLocale};
+    /// ui.add(egui::Slider::new(&mut my_f64, 0.0..=1e6).number_formatter(NumberFormatter::from_locale(NumberLocale::GERMAN)));
+    /// # });
+    /// ```
+    pub fn number_formatter(self, number_formatter: crate::style::NumberFormatter) -> Self {
+        let parser = number_formatter.clone();
+        self.custom_formatter(move |n, decimals| number_formatter.format(n, decimals))
+            .custom_parser(move |text| parser.parse(text))
+    }
+
     /// Set `custom_formatter` and `custom_parser` to display and parse numbers as binary integers. Floating point
     /// numbers are *not* supported.
     ///
@@ -680,7 +696,11 @@ impl Slider<'_> {
             } else {
                 self.value_from_position(position, position_range)
             };
+            let old_value = self.get_value();
             self.set_value(new_value);
+            if self.step.is_some() && self.get_value() != old_value {
+                ui.ctx().haptic_feedback(crate::HapticFeedback::Light);
+            }
         }
 
         let mut decrement = 0usize;
@@ -1221,3 +1241,57 @@ fn logarithmic_zero_cutoff(min: f64, max: f64) -> f64 {
     );
     cutoff
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        CentralPanel, Context, Event, HapticFeedback, Modifiers, OutputCommand, PointerButton,
+        RawInput, Rect, Slider,
+    };
+
+    #[test]
+    fn test_haptic_ticks_on_steps() {
+        let ctx = Context::default();
+        let mut value = 0.0;
+        let mut run = |events: Vec<Event>| {
+            let mut rect = Rect::NOTHING;
+            let output = ctx.run(
+                RawInput {
+                    events,
+                    ..Default::default()
+                },
+                |ctx| {
+                    CentralPanel::default().show(ctx, |ui| {
+                        rect = ui
+                            .add(Slider::new(&mut value, 0.0..=10.0).step_by(1.0))
+                            .rect;
+                    });
+                },
+            );
+            let haptics = output
+                .platform_output
+                .commands
+                .iter()
+                .filter(|cmd| matches!(cmd, OutputCommand::Haptic(HapticFeedback::Light)))
+                .count();
+            (rect, haptics)
+        };
+
+        let (rect, _) = run(vec![]);
+        let pos = rect.left_center() + crate::vec2(10.0, 0.0);
+        let (_, haptics) = run(vec![
+            Event::PointerMoved(pos),
+            Event::PointerButton {
+                pos,
+                button: PointerButton::Primary,
+                pressed: true,
+                modifiers: Modifiers::NONE,
+            },
+        ]);
+        assert_eq!(haptics, 1, "snapping to a new step");
+        let (_, haptics) = run(vec![Event::PointerMoved(pos + crate::vec2(6.0, 0.0))]);
+        assert_eq!(haptics, 1, "the drag started");
+        let (_, haptics) = run(vec![Event::PointerMoved(pos + crate::vec2(7.0, 0.0))]);
+        assert_eq!(haptics, 0, "still on the same step");
+    }
+}
@@ -0,0 +1,165 @@
+use crate::{
+    Align2, Response, Sense, Stroke, TextStyle, Ui, Widget, WidgetInfo, WidgetText, WidgetType,
+    pos2, vec2,
+};
+
+/// A progress indicator for wizards and other multi-step flows.
+///
+/// Shows numbered circles connected by lines. Steps before [`Self::new`]'s `current`
+/// are shown as done (with a check mark), the current step is highlighted and the
+/// remaining ones are dimmed.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// ui.add(egui::Steps::new(["Account", "Profile", "Confirm"], 1));
+/// # });
+/// ```
+#[must_use = "You should put this widget in a ui with `ui.add(widget);`"]
+pub struct Steps {
+    labels: Vec<WidgetText>,
+    current: usize,
+    desired_width: Option<f32>,
+}
+
+impl Steps {
+    /// `current` is the index of the current step.
+    /// Use `labels.len()` to show all steps as done.
+    pub fn new(labels: impl IntoIterator<Item = impl Into<WidgetText>>, current: usize) -> Self {
+        Self {
+            labels: labels.into_iter().map(Into::into).collect(),
+            current,
+            desired_width: None,
+        }
+    }
+
+    /// The desired width of the whole indicator. Will use all horizontal space if not set.
+    #[inline]
+    pub fn desired_width(mut self, desired_width: f32) -> Self {
+        self.desired_width = Some(desired_width);
+        self
+    }
+}
+
+impl Widget for Steps {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Self {
+            labels,
+            current,
+            desired_width,
+        } = self;
+
+        let num_steps = labels.len();
+        let radius = 0.5 * ui.spacing().interact_size.y;
+        let wrap_width = desired_width.unwrap_or_else(|| ui.available_size_before_wrap().x)
+            / num_steps.max(1) as f32;
+        let galleys: Vec<_> = labels
+            .iter()
+            .map(|label| {
+                label.clone().into_galley(
+                    ui,
+                    Some(crate::TextWrapMode::Truncate),
+                    wrap_width,
+                    TextStyle::Small,
+                )
+            })
+            .collect();
+        let text_height = galleys
+            .iter()
+            .map(|galley| galley.size().y)
+            .fold(0.0, f32::max);
+        let width = desired_width.unwrap_or_else(|| ui.available_size_before_wrap().x);
+        let spacing = ui.spacing().item_spacing.y;
+        let (rect, response) = ui.allocate_exact_size(
+            vec2(width, 2.0 * radius + spacing + text_height),
+            Sense::hover(),
+        );
+
+        response.widget_info(|| {
+            let text = match labels.get(current) {
+                Some(label) => format!("Step {} of {num_steps}: {}", current + 1, label.text()),
+                None => format!("All {num_steps} steps done"),
+            };
+            let mut info =
+                WidgetInfo::labeled(WidgetType::ProgressIndicator, ui.is_enabled(), text);
+            info.value = Some(current as f64);
+            info
+        });
+
+        if ui.is_rect_visible(rect) && 0 < num_steps {
+            let visuals = ui.visuals();
+            let column_width = rect.width() / num_steps as f32;
+            let center_of = |i: usize| {
+                pos2(
+                    rect.left() + (i as f32 + 0.5) * column_width,
+                    rect.top() + radius,
+                )
+            };
+
+            for i in 1..num_steps {
+                let stroke = if i <= current {
+                    Stroke::new(2.0, visuals.selection.bg_fill)
+                } else {
+                    visuals.widgets.noninteractive.bg_stroke
+                };
+                ui.painter().line_segment(
+                    [
+                        center_of(i - 1) + vec2(radius, 0.0),
+                        center_of(i) - vec2(radius, 0.0),
+                    ],
+                    stroke,
+                );
+            }
+
+            for (i, galley) in galleys.into_iter().enumerate() {
+                let center = center_of(i);
+                let (fill, stroke, text_color) = if i < current {
+                    (
+                        visuals.selection.bg_fill,
+                        Stroke::NONE,
+                        visuals.selection.stroke.color,
+                    )
+                } else if i == current {
+                    (
+                        visuals.extreme_bg_color,
+                        Stroke::new(2.0, visuals.selection.bg_fill),
+                        visuals.strong_text_color(),
+                    )
+                } else {
+                    (
+                        visuals.extreme_bg_color,
+                        visuals.widgets.noninteractive.bg_stroke,
+                        visuals.weak_text_color(),
+                    )
+                };
+                ui.painter().circle(center, radius, fill, stroke);
+                let symbol = if i < current {
+                    "✔".to_owned()
+                } else {
+                    (i + 1).to_string()
+                };
+                ui.painter().text(
+                    center,
+                    Align2::CENTER_CENTER,
+                    symbol,
+                    TextStyle::Button.resolve(ui.style()),
+                    text_color,
+                );
+
+                let label_color = if i == current {
+                    visuals.strong_text_color()
+                } else if i < current {
+                    visuals.text_color()
+                } else {
+                    visuals.weak_text_color()
+                };
+                let label_pos = pos2(
+                    center.x - 0.5 * galley.size().x,
+                    rect.top() + 2.0 * radius + spacing,
+                );
+                ui.painter().galley(label_pos, galley, label_color);
+            }
+        }
+
+        response
+    }
+}
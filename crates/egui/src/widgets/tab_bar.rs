@@ -0,0 +1,376 @@
+use std::sync::Arc;
+
+use crate::{
+    Align2, CornerRadius, Galley, Id, PointerButton, Rect, Response, ScrollArea, Sense, Stroke,
+    TextStyle, TextWrapMode, Ui, Vec2, WidgetInfo, WidgetText, WidgetType, pos2, vec2,
+};
+
+/// A row of tabs, one for each item in a `Vec`, for simple tabbed and multi-document interfaces.
+///
+/// * Click a tab to select it.
+/// * Tabs can be closed with their close button, or with a middle click.
+/// * Tabs can be reordered by dragging them.
+/// * If there are too many tabs to fit, the bar scrolls horizontally (e.g. with the mouse wheel).
+///
+/// The tab bar only shows the tabs; show the contents of the selected tab yourself.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut documents = vec!["a.txt".to_owned(), "b.txt".to_owned()];
+/// # let mut selected = 0;
+/// let response = egui::TabBar::new("documents", &mut documents, &mut selected)
+///     .show(ui, |document| document.as_str().into());
+///
+/// for closed in response.closed {
+///     // ask to save `closed`…
+/// }
+///
+/// if let Some(document) = documents.get(selected) {
+///     ui.label(format!("Editing {document}"));
+/// }
+/// # });
+/// ```
+#[must_use = "You should call .show()"]
+pub struct TabBar<'a, T> {
+    id_salt: Id,
+    tabs: &'a mut Vec<T>,
+    selected: &'a mut usize,
+    closable: bool,
+    reorderable: bool,
+}
+
+/// The result of showing a [`TabBar`].
+pub struct TabBarResponse<T> {
+    /// The response of the whole tab bar.
+    ///
+    /// [`Response::changed`] is set if a tab was selected, closed, or moved.
+    pub response: Response,
+
+    /// A different tab was selected by the user.
+    pub selection_changed: bool,
+
+    /// The tabs the user closed this pass, which have been removed from the `Vec`.
+    pub closed: Vec<T>,
+
+    /// The user moved a tab.
+    pub reordered: bool,
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+struct State {
+    /// The selected tab last pass, to scroll to tabs selected from the outside.
+    selected: Option<usize>,
+}
+
+impl<'a, T> TabBar<'a, T> {
+    /// `selected` is the index of the selected tab in `tabs`.
+    pub fn new(
+        id_salt: impl std::hash::Hash,
+        tabs: &'a mut Vec<T>,
+        selected: &'a mut usize,
+    ) -> Self {
+        Self {
+            id_salt: Id::new(id_salt),
+            tabs,
+            selected,
+            closable: true,
+            reorderable: true,
+        }
+    }
+
+    /// Show a close button on each tab, and close tabs on middle click.
+    ///
+    /// Default: `true`.
+    #[inline]
+    pub fn closable(mut self, closable: bool) -> Self {
+        self.closable = closable;
+        self
+    }
+
+    /// Allow reordering the tabs by dragging them.
+    ///
+    /// Default: `true`.
+    #[inline]
+    pub fn reorderable(mut self, reorderable: bool) -> Self {
+        self.reorderable = reorderable;
+        self
+    }
+
+    /// Show the tabs, using `title` for the text of each.
+    pub fn show(self, ui: &mut Ui, mut title: impl FnMut(&T) -> WidgetText) -> TabBarResponse<T> {
+        let Self {
+            id_salt,
+            tabs,
+            selected,
+            closable,
+            reorderable,
+        } = self;
+
+        let id = ui.make_persistent_id(id_salt);
+        let mut state: State = ui.data_mut(|d| d.get_temp(id)).unwrap_or_default();
+        if !tabs.is_empty() {
+            *selected = (*selected).min(tabs.len() - 1);
+        }
+        let scroll_to_selected = state.selected != Some(*selected);
+
+        let mut selection_changed = false;
+        let mut reordered = false;
+        let mut to_close = None;
+
+        let output = ui.scope(|ui| {
+            // Scroll the bar with a normal mouse wheel:
+            ui.style_mut().always_scroll_the_only_direction = true;
+
+            ScrollArea::horizontal()
+                .id_salt(id)
+                .auto_shrink([false, true])
+                .show(ui, |ui| {
+                    ui.horizontal(|ui| {
+                        ui.spacing_mut().item_spacing.x = 0.0;
+                        let padding = ui.spacing().button_padding;
+                        let close_size = ui.spacing().icon_width;
+                        let mut tab_rects = Vec::with_capacity(tabs.len());
+                        let mut dragged = None;
+
+                        for (i, tab) in tabs.iter().enumerate() {
+                            let tab_id = id.with(("tab", i));
+                            let galley = title(tab).into_galley(
+                                ui,
+                                Some(TextWrapMode::Extend),
+                                f32::INFINITY,
+                                TextStyle::Button,
+                            );
+                            let close_width = if closable {
+                                padding.x + close_size
+                            } else {
+                                0.0
+                            };
+                            let size = vec2(
+                                padding.x + galley.size().x + close_width + padding.x,
+                                (galley.size().y + 2.0 * padding.y)
+                                    .max(ui.spacing().interact_size.y),
+                            );
+                            let (_, rect) = ui.allocate_space(size);
+                            let sense = if reorderable {
+                                Sense::click_and_drag()
+                            } else {
+                                Sense::click()
+                            };
+                            let response = ui.interact(rect, tab_id, sense);
+                            let is_selected = i == *selected;
+                            response.widget_info(|| {
+                                WidgetInfo::selected(
+                                    WidgetType::Tab,
+                                    ui.is_enabled(),
+                                    is_selected,
+                                    galley.text(),
+                                )
+                            });
+
+                            let close_response = closable.then(|| {
+                                let close_rect = Rect::from_center_size(
+                                    pos2(
+                                        rect.right() - padding.x - 0.5 * close_size,
+                                        rect.center().y,
+                                    ),
+                                    Vec2::splat(close_size),
+                                );
+                                let close_response =
+                                    ui.interact(close_rect, tab_id.with("close"), Sense::click());
+                                close_response.widget_info(|| {
+                                    WidgetInfo::labeled(
+                                        WidgetType::Button,
+                                        ui.is_enabled(),
+                                        format!("Close {}", galley.text()),
+                                    )
+                                });
+                                close_response
+                            });
+
+                            if close_response.as_ref().is_some_and(Response::clicked)
+                                || (closable && response.clicked_by(PointerButton::Middle))
+                            {
+                                to_close = Some(i);
+                            } else if response.clicked() && !is_selected {
+                                *selected = i;
+                                selection_changed = true;
+                            }
+                            if response.dragged() {
+                                dragged = Some(i);
+                            }
+                            if is_selected && (scroll_to_selected || selection_changed) {
+                                response.scroll_to_me(None);
+                            }
+
+                            if ui.is_rect_visible(rect) {
+                                paint_tab(
+                                    ui,
+                                    &response,
+                                    close_response.as_ref(),
+                                    galley,
+                                    is_selected,
+                                );
+                            }
+
+                            tab_rects.push(rect);
+                        }
+
+                        // Swap the dragged tab with its neighbor once the pointer passes its middle:
+                        if let (Some(i), Some(pointer)) = (dragged, ui.ctx().pointer_interact_pos())
+                        {
+                            let neighbor = if 0 < i && pointer.x < tab_rects[i - 1].center().x {
+                                Some(i - 1)
+                            } else if i + 1 < tabs.len() && tab_rects[i + 1].center().x < pointer.x
+                            {
+                                Some(i + 1)
+                            } else {
+                                None
+                            };
+                            if let Some(j) = neighbor {
+                                tabs.swap(i, j);
+                                if *selected == i {
+                                    *selected = j;
+                                } else if *selected == j {
+                                    *selected = i;
+                                }
+                                // Keep dragging the same tab:
+                                ui.ctx().set_dragged_id(id.with(("tab", j)));
+                                reordered = true;
+                            }
+                        }
+                    });
+                })
+        });
+
+        let mut response = output.response;
+        let bar_rect = output.inner.inner_rect;
+        ui.painter().hline(
+            bar_rect.x_range(),
+            bar_rect.bottom(),
+            Stroke::new(1.0, ui.visuals().widgets.noninteractive.bg_stroke.color),
+        );
+
+        let mut closed = Vec::new();
+        if let Some(i) = to_close {
+            closed.push(tabs.remove(i));
+            if i < *selected || (i == *selected && *selected == tabs.len() && 0 < i) {
+                *selected -= 1;
+            }
+        }
+
+        if selection_changed || reordered || !closed.is_empty() {
+            response.mark_changed();
+            ui.ctx().request_repaint();
+        }
+
+        state.selected = Some(*selected);
+        ui.data_mut(|d| d.insert_temp(id, state));
+
+        TabBarResponse {
+            response,
+            selection_changed,
+            closed,
+            reordered,
+        }
+    }
+}
+
+fn paint_tab(
+    ui: &Ui,
+    response: &Response,
+    close_response: Option<&Response>,
+    galley: Arc<Galley>,
+    is_selected: bool,
+) {
+    let rect = response.rect;
+    let visuals = ui.style().interact_selectable(response, is_selected);
+    let radius = ui.visuals().widgets.inactive.corner_radius;
+    let corner_radius = CornerRadius {
+        nw: radius.nw,
+        ne: radius.ne,
+        sw: 0,
+        se: 0,
+    };
+    if is_selected || response.hovered() || response.dragged() {
+        ui.painter()
+            .rect_filled(rect, corner_radius, visuals.weak_bg_fill);
+    }
+
+    let text_color = if is_selected {
+        ui.visuals().strong_text_color()
+    } else {
+        visuals.text_color()
+    };
+    let text_rect = rect.shrink2(vec2(ui.spacing().button_padding.x, 0.0));
+    let text_pos = Align2::LEFT_CENTER
+        .align_size_within_rect(galley.size(), text_rect)
+        .min;
+    ui.painter().galley(text_pos, galley, text_color);
+
+    if let Some(close_response) = close_response {
+        // Like the close button of a `Window`, but only shown on the selected or hovered tab:
+        if is_selected || response.hovered() || close_response.hovered() {
+            let stroke = ui.style().interact(close_response).fg_stroke;
+            let cross = close_response.rect.shrink(3.0);
+            ui.painter()
+                .line_segment([cross.left_top(), cross.right_bottom()], stroke);
+            ui.painter()
+                .line_segment([cross.right_top(), cross.left_bottom()], stroke);
+        }
+    }
+
+    if is_selected {
+        let underline = Rect::from_min_max(pos2(rect.left(), rect.bottom() - 2.0), rect.max);
+        ui.painter()
+            .rect_filled(underline, 0.0, ui.visuals().selection.bg_fill);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Context, Event, Modifiers, Pos2, RawInput};
+
+    #[test]
+    fn test_close_tab() {
+        let ctx = Context::default();
+        let mut tabs = vec!["one", "two", "three"];
+        let mut selected = 2;
+        let mut run = |events: Vec<Event>| {
+            let input = RawInput {
+                screen_rect: Some(Rect::from_min_size(Pos2::ZERO, vec2(400.0, 300.0))),
+                events,
+                ..Default::default()
+            };
+            let mut closed = Vec::new();
+            let mut close_rect = Rect::NOTHING;
+            let _ = ctx.run(input, |ctx| {
+                crate::CentralPanel::default().show(ctx, |ui| {
+                    let id = ui.make_persistent_id(Id::new("tabs"));
+                    closed = TabBar::new("tabs", &mut tabs, &mut selected)
+                        .show(ui, |tab| (*tab).into())
+                        .closed;
+                    close_rect = ctx
+                        .read_response(id.with(("tab", 2)).with("close"))
+                        .map_or(Rect::NOTHING, |r| r.rect);
+                });
+            });
+            (closed, close_rect)
+        };
+
+        let (_, close_rect) = run(vec![]);
+        let pos = close_rect.center();
+        let button = |pressed| Event::PointerButton {
+            pos,
+            button: PointerButton::Primary,
+            pressed,
+            modifiers: Modifiers::NONE,
+        };
+        run(vec![Event::PointerMoved(pos)]);
+        run(vec![button(true)]);
+        let (closed, _) = run(vec![button(false)]);
+        assert_eq!(closed, vec!["three"]);
+        assert_eq!(tabs, vec!["one", "two"]);
+        assert_eq!(selected, 1);
+    }
+}
@@ -1,8 +1,8 @@
-use std::sync::Arc;
+use std::{ops::Range, sync::Arc};
 
 use emath::{Rect, TSTransform};
 use epaint::{
-    StrokeKind,
+    Stroke, StrokeKind,
     text::{Galley, LayoutJob, cursor::CCursor},
 };
 
@@ -17,7 +17,11 @@ use crate::{
     vec2,
 };
 
-use super::{TextEditOutput, TextEditState};
+use super::{
+    TextEditOutput, TextEditState,
+    find_replace::{FindAction, FindState, find_bar},
+    whitespace::paint_whitespace,
+};
 
 type LayouterFn<'t> = &'t mut dyn FnMut(&Ui, &dyn TextBuffer, f32) -> Arc<Galley>;
 
@@ -75,6 +79,7 @@ pub struct TextEdit<'t> {
     text_color: Option<Color32>,
     layouter: Option<LayouterFn<'t>>,
     password: bool,
+    reveal_password: bool,
     frame: bool,
     margin: Margin,
     multiline: bool,
@@ -89,6 +94,10 @@ pub struct TextEdit<'t> {
     char_limit: usize,
     return_key: Option<KeyboardShortcut>,
     background_color: Option<Color32>,
+    find_replace: bool,
+    show_whitespace: bool,
+    show_wrap_markers: bool,
+    column_guide: Option<usize>,
 }
 
 impl WidgetWithState for TextEdit<'_> {
@@ -128,6 +137,7 @@ impl<'t> TextEdit<'t> {
             text_color: None,
             layouter: None,
             password: false,
+            reveal_password: false,
             frame: true,
             margin: Margin::symmetric(4, 2),
             multiline: true,
@@ -148,6 +158,10 @@ impl<'t> TextEdit<'t> {
             char_limit: usize::MAX,
             return_key: Some(KeyboardShortcut::new(Modifiers::NONE, Key::Enter)),
             background_color: None,
+            find_replace: false,
+            show_whitespace: false,
+            show_wrap_markers: false,
+            column_guide: None,
         }
     }
 
@@ -223,12 +237,21 @@ impl<'t> TextEdit<'t> {
     }
 
     /// If true, hide the letters from view and prevent copying from the field.
+    ///
+    /// The text is also kept out of the undo history.
     #[inline]
     pub fn password(mut self, password: bool) -> Self {
         self.password = password;
         self
     }
 
+    /// Show the letters of a [`Self::password`], while still preventing copying.
+    #[inline]
+    pub(crate) fn reveal_password(mut self, reveal_password: bool) -> Self {
+        self.reveal_password = reveal_password;
+        self
+    }
+
     /// Pick a [`crate::FontId`] or [`TextStyle`].
     #[inline]
     pub fn font(mut self, font_selection: impl Into<FontSelection>) -> Self {
@@ -396,6 +419,49 @@ impl<'t> TextEdit<'t> {
         self.return_key = return_key.into();
         self
     }
+
+    /// Let the user search the text with Ctrl+F (⌘F on Mac), and search and replace with Ctrl+H.
+    ///
+    /// This shows a bar in the top right corner, and highlights all matches.
+    /// Enter and Shift+Enter go to the next and previous match, and Escape closes the bar.
+    /// With the `regex` feature, the query can also be a regular expression,
+    /// and the replacement can refer to its groups with `$1`, `${name}` etc.
+    ///
+    /// Only for multiline text edits. Default: `false`.
+    #[inline]
+    pub fn find_replace(mut self, find_replace: bool) -> Self {
+        self.find_replace = find_replace;
+        self
+    }
+
+    /// Show spaces as middle dots and tabs as arrows.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn show_whitespace(mut self, show_whitespace: bool) -> Self {
+        self.show_whitespace = show_whitespace;
+        self
+    }
+
+    /// Show a small return arrow at the end of rows that continue on the next row because of wrapping.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn show_wrap_markers(mut self, show_wrap_markers: bool) -> Self {
+        self.show_wrap_markers = show_wrap_markers;
+        self
+    }
+
+    /// Show a vertical guide line after this many columns, e.g. `Some(100)`.
+    ///
+    /// The column width is the width of a space, so this is mostly useful with a monospace font.
+    ///
+    /// Default: `None`.
+    #[inline]
+    pub fn column_guide(mut self, columns: impl Into<Option<usize>>) -> Self {
+        self.column_guide = columns.into();
+        self
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -479,6 +545,7 @@ impl TextEdit<'_> {
             text_color,
             layouter,
             password,
+            reveal_password,
             frame: _,
             margin,
             multiline,
@@ -493,6 +560,10 @@ impl TextEdit<'_> {
             char_limit,
             return_key,
             background_color: _,
+            find_replace,
+            show_whitespace,
+            show_wrap_markers,
+            column_guide,
         } = self;
 
         let text_color = text_color
@@ -516,7 +587,7 @@ impl TextEdit<'_> {
 
         let font_id_clone = font_id.clone();
         let mut default_layouter = move |ui: &Ui, text: &dyn TextBuffer, wrap_width: f32| {
-            let text = mask_if_password(password, text.as_str());
+            let text = mask_if_password(password && !reveal_password, text.as_str());
             let layout_job = if multiline {
                 LayoutJob::simple(text, font_id_clone.clone(), text_color, wrap_width)
             } else {
@@ -620,6 +691,29 @@ impl TextEdit<'_> {
         if interactive && ui.memory(|mem| mem.has_focus(id)) {
             ui.memory_mut(|mem| mem.set_focus_lock_filter(id, event_filter));
 
+            if find_replace && multiline {
+                let (find, replace) = ui.input_mut(|i| {
+                    (
+                        i.consume_key(Modifiers::COMMAND, Key::F),
+                        i.consume_key(Modifiers::COMMAND, Key::H),
+                    )
+                });
+                if find || replace {
+                    let selected = state
+                        .cursor
+                        .char_range()
+                        .map(|range| range.slice_str(text.as_str()).to_owned());
+                    let find_state = state.find.get_or_insert_with(Default::default);
+                    find_state.replace |= replace;
+                    find_state.focus_query = true;
+                    // Search for the selected text:
+                    if let Some(selected) = selected.filter(|s| !s.is_empty() && !s.contains('\n'))
+                    {
+                        find_state.query = selected;
+                    }
+                }
+            }
+
             let default_cursor_range = if cursor_at_end {
                 CCursorRange::one(galley.end())
             } else {
@@ -682,6 +776,81 @@ impl TextEdit<'_> {
             state.text_offset = align_offset;
         }
 
+        let mut find_matches: Arc<[Range<usize>]> = Arc::from([]);
+        let mut current_match = None;
+        if let Some(mut find_state) = state.find.take().filter(|_| find_replace && multiline) {
+            let mut matches = find_state.matches(text.as_str());
+            let action = find_bar(
+                ui,
+                id.with("find"),
+                rect.intersect(ui.clip_rect()),
+                &mut find_state,
+                matches.len(),
+                text.is_mutable(),
+            );
+            let cursor_index = state
+                .cursor
+                .char_range()
+                .map_or(0, |range| range.sorted_cursors()[0].index);
+            let mut text_changed = false;
+            let mut select = false;
+            match action {
+                Some(FindAction::Search) => {
+                    matches = find_state.matches(text.as_str());
+                    find_state.current = FindState::match_at_or_after(&matches, cursor_index);
+                    select = true;
+                }
+                Some(FindAction::Next) if !matches.is_empty() => {
+                    find_state.current = (find_state.current + 1) % matches.len();
+                    select = true;
+                }
+                Some(FindAction::Previous) if !matches.is_empty() => {
+                    find_state.current = (find_state.current + matches.len() - 1) % matches.len();
+                    select = true;
+                }
+                Some(FindAction::Replace) => {
+                    if let Some(range) = matches.get(find_state.current) {
+                        let end = find_state.replace_match(text, range.clone());
+                        matches = find_state.matches(text.as_str());
+                        find_state.current = FindState::match_at_or_after(&matches, end);
+                        text_changed = true;
+                        select = true;
+                    }
+                }
+                Some(FindAction::ReplaceAll) => {
+                    text_changed = 0 < find_state.replace_all(text);
+                    matches = find_state.matches(text.as_str());
+                    find_state.current = 0;
+                }
+                Some(FindAction::Close) => {
+                    ui.memory_mut(|mem| mem.request_focus(id));
+                }
+                Some(FindAction::Next | FindAction::Previous) | None => {}
+            }
+            find_state.current = find_state.current.min(matches.len().saturating_sub(1));
+
+            if text_changed {
+                galley = layouter(ui, text, wrap_width);
+                response.mark_changed();
+            }
+            if let Some(range) = matches.get(find_state.current).filter(|_| select) {
+                state.cursor.set_char_range(Some(CCursorRange::two(
+                    CCursor::new(range.start),
+                    CCursor::new(range.end),
+                )));
+                let match_rect = galley
+                    .pos_from_cursor(CCursor::new(range.start))
+                    .translate(galley_pos.to_vec2());
+                ui.scroll_to_rect(match_rect.expand(row_height), None);
+            }
+
+            if action != Some(FindAction::Close) {
+                current_match = Some(find_state.current);
+                find_matches = matches;
+                state.find = Some(find_state);
+            }
+        }
+
         let selection_changed = if let (Some(cursor_range), Some(prev_cursor_range)) =
             (cursor_range, prev_cursor_range)
         {
@@ -693,7 +862,7 @@ impl TextEdit<'_> {
         if ui.is_rect_visible(rect) {
             if text.as_str().is_empty() && !hint_text.is_empty() {
                 let hint_text_color = ui.visuals().weak_text_color();
-                let hint_text_font_id = hint_text_font.unwrap_or(font_id.into());
+                let hint_text_font_id = hint_text_font.unwrap_or(font_id.clone().into());
                 let galley = if multiline {
                     hint_text.into_galley(
                         ui,
@@ -718,6 +887,22 @@ impl TextEdit<'_> {
 
             let has_focus = ui.memory(|mem| mem.has_focus(id));
 
+            if !find_matches.is_empty() {
+                // Highlight all matches, and the current one like a selection:
+                let mut visuals = ui.visuals().clone();
+                visuals.selection.bg_fill = visuals.selection.bg_fill.gamma_multiply(0.5);
+                for (i, range) in find_matches.iter().enumerate() {
+                    let current = Some(i) == current_match;
+                    if current && has_focus {
+                        continue; // Painted as the selection below
+                    }
+                    let range =
+                        CCursorRange::two(CCursor::new(range.start), CCursor::new(range.end));
+                    let visuals = if current { ui.visuals() } else { &visuals };
+                    paint_text_selection(&mut galley, visuals, &range, None);
+                }
+            }
+
             if has_focus {
                 if let Some(cursor_range) = state.cursor.range(&galley) {
                     // Add text selection rectangles to the galley:
@@ -738,15 +923,36 @@ impl TextEdit<'_> {
                 }
             }
 
+            if let Some(columns) = column_guide {
+                let column_width = ui.fonts(|f| f.glyph_width(&font_id, ' '));
+                let x = galley_pos.x + columns as f32 * column_width;
+                let color = ui.visuals().widgets.noninteractive.bg_stroke.color;
+                painter.vline(x, rect.y_range(), Stroke::new(1.0, color));
+            }
+
             painter.galley(galley_pos, galley.clone(), text_color);
 
+            if show_whitespace || show_wrap_markers {
+                paint_whitespace(
+                    &ui.painter_at(outer_rect),
+                    galley_pos,
+                    &galley,
+                    ui.visuals().weak_text_color().gamma_multiply(0.5),
+                    show_whitespace,
+                    show_wrap_markers,
+                );
+            }
+
             if has_focus {
                 if let Some(cursor_range) = state.cursor.range(&galley) {
                     let primary_cursor_rect =
                         cursor_rect(&galley, &cursor_range.primary, row_height)
                             .translate(galley_pos.to_vec2());
 
-                    if response.changed() || selection_changed {
+                    if ui.ctx().is_being_dragged(response.id) {
+                        // Drag-selecting past the edge of the view:
+                        ui.auto_scroll_towards_pointer();
+                    } else if response.changed() || selection_changed {
                         // Scroll to keep primary cursor in view:
                         ui.scroll_to_rect(primary_cursor_rect + margin, None);
                     }
@@ -901,10 +1107,13 @@ fn events(
 
     // We feed state to the undoer both before and after handling input
     // so that the undoer creates automatic saves even when there are no events for a while.
-    state.undoer.lock().feed_state(
-        ui.input(|i| i.time),
-        &(cursor_range, text.as_str().to_owned()),
-    );
+    // Passwords are never kept around in the undo history.
+    if !password {
+        state.undoer.lock().feed_state(
+            ui.input(|i| i.time),
+            &(cursor_range, text.as_str().to_owned()),
+        );
+    }
 
     let copy_if_not_password = |ui: &Ui, text: String| {
         if !password {
@@ -1107,10 +1316,12 @@ fn events(
 
     state.cursor.set_char_range(Some(cursor_range));
 
-    state.undoer.lock().feed_state(
-        ui.input(|i| i.time),
-        &(cursor_range, text.as_str().to_owned()),
-    );
+    if !password {
+        state.undoer.lock().feed_state(
+            ui.input(|i| i.time),
+            &(cursor_range, text.as_str().to_owned()),
+        );
+    }
 
     (any_change, cursor_range)
 }
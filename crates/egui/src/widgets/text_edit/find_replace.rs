@@ -0,0 +1,501 @@
+use std::{borrow::Cow, ops::Range, sync::Arc};
+
+use crate::{Align2, Area, Button, Frame, Id, Key, Order, Rect, TextBuffer, TextEdit, Ui, vec2};
+
+/// The state of the find-and-replace bar of a [`TextEdit`], see [`TextEdit::find_replace`].
+#[derive(Clone, Debug, Default)]
+pub(crate) struct FindState {
+    pub query: String,
+    pub replacement: String,
+
+    /// Is the replace field shown?
+    pub replace: bool,
+
+    pub case_sensitive: bool,
+    pub whole_word: bool,
+
+    /// Is the query a regular expression? Only with the `regex` feature.
+    pub regex: bool,
+
+    /// Index of the selected match.
+    pub current: usize,
+
+    /// Move the keyboard focus to the query this frame.
+    pub focus_query: bool,
+
+    /// The last search, reused until the text, the query or the options change.
+    search: Option<Search>,
+}
+
+/// The result of searching the text, see [`FindState::matches`].
+#[derive(Clone, Debug)]
+struct Search {
+    /// Hash of the text, the query and the options that were searched with.
+    hash: u64,
+
+    /// The char ranges of the matches.
+    matches: Arc<[Range<usize>]>,
+
+    /// Why the query is invalid, if it is.
+    error: Option<String>,
+
+    #[cfg(feature = "regex")]
+    regex: Option<regex::Regex>,
+}
+
+/// Something the user did in the find-and-replace bar.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum FindAction {
+    /// The query or the options changed.
+    Search,
+    Next,
+    Previous,
+    Replace,
+    ReplaceAll,
+    Close,
+}
+
+impl FindState {
+    /// The char ranges of all matches of the query in the text.
+    ///
+    /// Only searches again if the text, the query or the options changed since last time.
+    pub fn matches(&mut self, text: &str) -> Arc<[Range<usize>]> {
+        let hash = crate::util::hash((
+            text,
+            &self.query,
+            self.case_sensitive,
+            self.whole_word,
+            self.regex,
+        ));
+        let search = match self.search.take() {
+            Some(search) if search.hash == hash => search,
+            _ => self.search(text, hash),
+        };
+        let matches = search.matches.clone();
+        self.search = Some(search);
+        matches
+    }
+
+    /// Why the query is invalid, if it is, e.g. a regular expression that doesn't parse.
+    pub fn error(&self) -> Option<&str> {
+        self.search.as_ref()?.error.as_deref()
+    }
+
+    fn search(&self, text: &str, hash: u64) -> Search {
+        #[cfg(feature = "regex")]
+        if self.regex && !self.query.is_empty() {
+            let regex = regex::RegexBuilder::new(&self.query)
+                .case_insensitive(!self.case_sensitive)
+                .build();
+            return match regex {
+                Ok(regex) => {
+                    let byte_ranges = regex.find_iter(text).map(|m| m.range()).filter(|range| {
+                        !range.is_empty() && (!self.whole_word || is_whole_word(text, range))
+                    });
+                    Search {
+                        hash,
+                        matches: char_ranges(text, byte_ranges).into(),
+                        error: None,
+                        regex: Some(regex),
+                    }
+                }
+                Err(err) => Search {
+                    hash,
+                    matches: Arc::from([]),
+                    error: Some(err.to_string()),
+                    regex: None,
+                },
+            };
+        }
+
+        Search {
+            hash,
+            matches: find_matches(text, &self.query, self.case_sensitive, self.whole_word).into(),
+            error: None,
+            #[cfg(feature = "regex")]
+            regex: None,
+        }
+    }
+
+    /// The match at or after this char index, wrapping around.
+    pub fn match_at_or_after(matches: &[Range<usize>], char_index: usize) -> usize {
+        matches
+            .iter()
+            .position(|m| char_index <= m.start)
+            .unwrap_or(0)
+    }
+
+    /// Replace one match with [`Self::replacement`]. Returns the char index after the replacement.
+    ///
+    /// Call [`Self::matches`] first.
+    pub fn replace_match(&self, text: &mut dyn TextBuffer, range: Range<usize>) -> usize {
+        let byte_index = text.byte_index_from_char_index(range.start);
+        let replacement = self.expand_replacement(text.as_str(), byte_index);
+        replace_range(text, range, &replacement)
+    }
+
+    /// Replace all matches with [`Self::replacement`]. Returns how many matches were replaced.
+    pub fn replace_all(&mut self, text: &mut dyn TextBuffer) -> usize {
+        let matches = self.matches(text.as_str());
+
+        // Expand all the replacements before changing the text:
+        let mut byte_indices = text.as_str().char_indices().map(|(i, _)| i).enumerate();
+        let replacements: Vec<String> = matches
+            .iter()
+            .map(|range| {
+                let byte_index = byte_indices
+                    .find(|(char_index, _)| *char_index == range.start)
+                    .map_or(text.as_str().len(), |(_, byte_index)| byte_index);
+                self.expand_replacement(text.as_str(), byte_index)
+            })
+            .collect();
+
+        // Back to front, so the char indices of the remaining matches stay valid:
+        for (range, replacement) in matches.iter().zip(&replacements).rev() {
+            replace_range(text, range.clone(), replacement);
+        }
+        matches.len()
+    }
+
+    /// [`Self::replacement`], with `$1`, `${name}` etc. expanded to the groups of the regex match
+    /// at this byte index, when searching with a regular expression.
+    fn expand_replacement(&self, text: &str, byte_index: usize) -> String {
+        #[cfg(feature = "regex")]
+        if let Some(captures) = self
+            .search
+            .as_ref()
+            .and_then(|search| search.regex.as_ref())
+            .and_then(|regex| regex.captures_at(text, byte_index))
+        {
+            let mut replacement = String::new();
+            captures.expand(&self.replacement, &mut replacement);
+            return replacement;
+        }
+        #[cfg(not(feature = "regex"))]
+        let _ = (text, byte_index);
+        self.replacement.clone()
+    }
+}
+
+/// Returns the char index after the replacement.
+fn replace_range(text: &mut dyn TextBuffer, range: Range<usize>, replacement: &str) -> usize {
+    let start = range.start;
+    text.delete_char_range(range);
+    start + text.insert_text(replacement, start)
+}
+
+/// The char ranges of all non-overlapping matches of `query` in `text`.
+fn find_matches(
+    text: &str,
+    query: &str,
+    case_sensitive: bool,
+    whole_word: bool,
+) -> Vec<Range<usize>> {
+    if query.is_empty() {
+        return vec![];
+    }
+
+    // Folding one char at a time keeps the char indices the same:
+    let fold = |s: &str| -> String {
+        s.chars()
+            .map(|c| c.to_lowercase().next().unwrap_or(c))
+            .collect()
+    };
+    let (text, query): (Cow<'_, str>, Cow<'_, str>) = if case_sensitive {
+        (text.into(), query.into())
+    } else {
+        (fold(text).into(), fold(query).into())
+    };
+
+    let mut byte_ranges = vec![];
+    let mut start = 0;
+    while let Some(offset) = text[start..].find(&*query) {
+        let range = start + offset..start + offset + query.len();
+        if whole_word && !is_whole_word(&text, &range) {
+            // Try again from the next char:
+            start = range.start + text[range.start..].chars().next().map_or(1, char::len_utf8);
+        } else {
+            start = range.end;
+            byte_ranges.push(range);
+        }
+    }
+    char_ranges(&text, byte_ranges)
+}
+
+/// Is this byte range of `text` neither preceded nor followed by a word character?
+fn is_whole_word(text: &str, range: &Range<usize>) -> bool {
+    let is_word = |c: Option<char>| c.is_some_and(|c| c.is_alphanumeric() || c == '_');
+    !is_word(text[..range.start].chars().next_back()) && !is_word(text[range.end..].chars().next())
+}
+
+/// Turn sorted, non-overlapping byte ranges of `text` into char ranges, in a single pass.
+fn char_ranges(
+    text: &str,
+    byte_ranges: impl IntoIterator<Item = Range<usize>>,
+) -> Vec<Range<usize>> {
+    let mut byte_index = 0;
+    let mut char_index = 0;
+    let mut to_char_index = |i: usize| {
+        char_index += text[byte_index..i].chars().count();
+        byte_index = i;
+        char_index
+    };
+    byte_ranges
+        .into_iter()
+        .map(|range| to_char_index(range.start)..to_char_index(range.end))
+        .collect()
+}
+
+/// Show the find-and-replace bar in the top right corner of `rect`.
+pub(crate) fn find_bar(
+    ui: &Ui,
+    id: Id,
+    rect: Rect,
+    state: &mut FindState,
+    num_matches: usize,
+    is_mutable: bool,
+) -> Option<FindAction> {
+    let query_id = id.with("query");
+    let mut action = None;
+
+    Area::new(id)
+        .order(Order::Foreground)
+        .pivot(Align2::RIGHT_TOP)
+        .fixed_pos(rect.right_top() + vec2(-4.0, 4.0))
+        .show(ui.ctx(), |ui| {
+            Frame::popup(ui.style()).show(ui, |ui| {
+                ui.horizontal(|ui| {
+                    let response = ui.add(
+                        TextEdit::singleline(&mut state.query)
+                            .id(query_id)
+                            .hint_text("Find")
+                            .desired_width(140.0),
+                    );
+                    if state.focus_query {
+                        // Keep asking until it sticks, since the bar is invisible the first frame:
+                        if response.has_focus() {
+                            state.focus_query = false;
+                        } else {
+                            response.request_focus();
+                        }
+                    }
+                    if response.changed() {
+                        action = Some(FindAction::Search);
+                    }
+                    if response.lost_focus() {
+                        let (enter, shift, escape) = ui.input(|i| {
+                            (
+                                i.key_pressed(Key::Enter),
+                                i.modifiers.shift,
+                                i.key_pressed(Key::Escape),
+                            )
+                        });
+                        if escape {
+                            action = Some(FindAction::Close);
+                        } else if enter {
+                            action = Some(if shift {
+                                FindAction::Previous
+                            } else {
+                                FindAction::Next
+                            });
+                            ui.memory_mut(|mem| mem.request_focus(query_id));
+                        }
+                    }
+
+                    #[cfg_attr(not(feature = "regex"), expect(unused_mut))]
+                    let mut options = vec![
+                        (&mut state.case_sensitive, "Aa", "Match case"),
+                        (&mut state.whole_word, "W", "Match whole words"),
+                    ];
+                    #[cfg(feature = "regex")]
+                    options.push((&mut state.regex, ".*", "Use regular expression"));
+                    for (value, text, hover_text) in options {
+                        if ui
+                            .add(Button::new(text).selected(*value))
+                            .on_hover_text(hover_text)
+                            .clicked()
+                        {
+                            *value = !*value;
+                            action = Some(FindAction::Search);
+                        }
+                    }
+
+                    if let Some(error) = state.error() {
+                        ui.colored_label(ui.visuals().error_fg_color, "Invalid query")
+                            .on_hover_text(error);
+                    } else if num_matches == 0 {
+                        if !state.query.is_empty() {
+                            ui.weak("No results");
+                        }
+                    } else {
+                        ui.weak(format!(
+                            "{} of {num_matches}",
+                            state.current.min(num_matches - 1) + 1
+                        ));
+                    }
+
+                    ui.add_enabled_ui(0 < num_matches, |ui| {
+                        if ui.button("⏶").on_hover_text("Previous match").clicked() {
+                            action = Some(FindAction::Previous);
+                        }
+                        if ui.button("⏷").on_hover_text("Next match").clicked() {
+                            action = Some(FindAction::Next);
+                        }
+                    });
+                    if ui.button("🗙").on_hover_text("Close").clicked() {
+                        action = Some(FindAction::Close);
+                    }
+                });
+
+                if state.replace && is_mutable {
+                    ui.horizontal(|ui| {
+                        ui.add(
+                            TextEdit::singleline(&mut state.replacement)
+                                .id(id.with("replacement"))
+                                .hint_text("Replace")
+                                .desired_width(140.0),
+                        );
+                        ui.add_enabled_ui(0 < num_matches, |ui| {
+                            if ui.button("Replace").clicked() {
+                                action = Some(FindAction::Replace);
+                            }
+                            if ui.button("Replace all").clicked() {
+                                action = Some(FindAction::ReplaceAll);
+                            }
+                        });
+                    });
+                }
+            });
+        });
+
+    action
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_and_replace() {
+        assert_eq!(find_matches("aXa xa", "xa", false, false), [1..3, 4..6]);
+        assert_eq!(find_matches("xa aXa xa", "xa", true, false), [0..2, 7..9]);
+        assert_eq!(find_matches("xa aXa Xa", "xa", false, true), [0..2, 7..9]);
+        assert_eq!(find_matches("aaaa", "aa", true, false), [0..2, 2..4]);
+        assert_eq!(find_matches("åäö ÅÄÖ", "äö", false, false), [1..3, 5..7]);
+        assert!(find_matches("abc", "", false, false).is_empty());
+
+        let mut state = FindState {
+            query: "cat".to_owned(),
+            replacement: "dog".to_owned(),
+            whole_word: true,
+            ..Default::default()
+        };
+        let mut text = "cat category cat".to_owned();
+        assert_eq!(state.replace_all(&mut text), 2);
+        assert_eq!(text, "dog category dog");
+    }
+
+    #[test]
+    #[expect(clippy::single_range_in_vec_init)]
+    fn test_matches_are_cached() {
+        let mut state = FindState {
+            query: "a".to_owned(),
+            ..Default::default()
+        };
+        let matches = state.matches("a b a");
+        assert_eq!(*matches, [0..1, 4..5]);
+        assert!(
+            Arc::ptr_eq(&matches, &state.matches("a b a")),
+            "Should not search again when nothing changed"
+        );
+        assert_eq!(*state.matches("a b"), [0..1]);
+
+        state.query = "b".to_owned();
+        assert_eq!(*state.matches("a b"), [2..3]);
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    #[expect(clippy::single_range_in_vec_init)]
+    fn test_regex() {
+        let mut state = FindState {
+            query: r"(\w+)@(\w+)".to_owned(),
+            replacement: "$2 at $1".to_owned(),
+            regex: true,
+            ..Default::default()
+        };
+        let mut text = "å@b, c@d".to_owned();
+        assert_eq!(*state.matches(&text), [0..3, 5..8]);
+        assert_eq!(state.replace_all(&mut text), 2);
+        assert_eq!(text, "b at å, d at c");
+
+        state.query = "A+".to_owned();
+        assert_eq!(*state.matches("aa Aa"), [0..2, 3..5]);
+        state.case_sensitive = true;
+        assert_eq!(*state.matches("aa Aa"), [3..4]);
+
+        state.query = "(".to_owned();
+        assert!(state.matches("(").is_empty());
+        assert!(state.error().is_some());
+    }
+
+    #[test]
+    fn test_find_bar() {
+        use crate::{CentralPanel, Context, Event, Modifiers, RawInput, text::CCursorRange};
+
+        let ctx = Context::default();
+        let id = Id::new("text_edit");
+        let mut text = "one two one".to_owned();
+        let mut run = |events: Vec<Event>| {
+            let input = RawInput {
+                events,
+                ..Default::default()
+            };
+            let _ = ctx.run(input, |ctx| {
+                CentralPanel::default().show(ctx, |ui| {
+                    TextEdit::multiline(&mut text)
+                        .id(id)
+                        .find_replace(true)
+                        .show(ui);
+                });
+            });
+            TextEdit::load_state(&ctx, id).unwrap()
+        };
+        let key = |key, modifiers| Event::Key {
+            key,
+            physical_key: None,
+            pressed: true,
+            repeat: false,
+            modifiers,
+        };
+        let selection = |state: &crate::text_edit::TextEditState| {
+            state
+                .cursor
+                .char_range()
+                .map(|range: CCursorRange| range.as_sorted_char_range())
+        };
+
+        run(vec![]);
+        ctx.memory_mut(|mem| mem.request_focus(id));
+        run(vec![]);
+        run(vec![key(Key::F, Modifiers::COMMAND)]);
+        run(vec![]);
+        let state = run(vec![Event::Text("one".to_owned())]);
+        assert_eq!(state.find.as_ref().unwrap().query, "one");
+        assert_eq!(
+            selection(&state),
+            Some(0..3),
+            "Should select the first match"
+        );
+
+        let state = run(vec![key(Key::Enter, Modifiers::NONE)]);
+        assert_eq!(
+            selection(&state),
+            Some(8..11),
+            "Should select the next match"
+        );
+
+        let state = run(vec![key(Key::Escape, Modifiers::NONE)]);
+        assert!(state.find.is_none());
+    }
+}
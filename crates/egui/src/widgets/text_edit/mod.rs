@@ -1,7 +1,9 @@
 mod builder;
+mod find_replace;
 mod output;
 mod state;
 mod text_buffer;
+mod whitespace;
 
 pub use {
     crate::text_selection::TextCursorState, builder::TextEdit, output::TextEditOutput,
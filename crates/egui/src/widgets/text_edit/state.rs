@@ -58,6 +58,10 @@ pub struct TextEditState {
     /// Used to pause the cursor animation when typing.
     #[cfg_attr(feature = "serde", serde(skip))]
     pub(crate) last_interaction_time: f64,
+
+    /// The find-and-replace bar, if open. See [`crate::TextEdit::find_replace`].
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) find: Option<super::find_replace::FindState>,
 }
 
 impl TextEditState {
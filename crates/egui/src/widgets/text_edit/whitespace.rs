@@ -0,0 +1,83 @@
+use epaint::{Galley, Stroke};
+
+use crate::{Color32, NumExt as _, Painter, Pos2, pos2, vec2};
+
+/// Paint visible whitespace and/or soft-wrap markers on top of a galley painted at `galley_pos`.
+///
+/// Spaces become middle dots and tabs arrows.
+/// Rows that continue on the next row because of wrapping get a return arrow at their end.
+pub(crate) fn paint_whitespace(
+    painter: &Painter,
+    galley_pos: Pos2,
+    galley: &Galley,
+    color: Color32,
+    show_whitespace: bool,
+    show_wrap_markers: bool,
+) {
+    let clip_rect = painter.clip_rect();
+    let stroke = Stroke::new(1.0, color);
+
+    for (i, row) in galley.rows.iter().enumerate() {
+        let row_rect = row.rect().translate(galley_pos.to_vec2());
+        if row_rect.bottom() < clip_rect.top() {
+            continue;
+        }
+        if clip_rect.bottom() < row_rect.top() {
+            break;
+        }
+
+        if show_whitespace {
+            for glyph in &row.glyphs {
+                let rect = glyph.logical_rect().translate(row_rect.min.to_vec2());
+                match glyph.chr {
+                    ' ' | '\u{a0}' => {
+                        let radius = (0.06 * glyph.font_height).at_least(1.0);
+                        painter.circle_filled(rect.center(), radius, color);
+                    }
+                    '\t' => {
+                        let inset = 0.15 * rect.width().at_most(glyph.font_height);
+                        let from = rect.left_center() + vec2(inset, 0.0);
+                        let to = rect.right_center() - vec2(inset, 0.0);
+                        painter.arrow(from, to - from, stroke);
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        let is_wrapped = !row.ends_with_newline && i + 1 < galley.rows.len();
+        if show_wrap_markers && is_wrapped {
+            // A small ↵ just after the end of the row:
+            let size = 0.4 * row_rect.height();
+            let right = row_rect.right() + 2.0 + size;
+            let y = row_rect.center().y;
+            painter.line_segment([pos2(right, y - 0.5 * size), pos2(right, y)], stroke);
+            painter.arrow(pos2(right, y), vec2(-size, 0.0), stroke);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{CentralPanel, Context, RawInput, Shape, TextEdit};
+
+    #[test]
+    fn test_show_whitespace() {
+        let count_circles = |show_whitespace| {
+            let ctx = Context::default();
+            let output = ctx.run(RawInput::default(), |ctx| {
+                CentralPanel::default().show(ctx, |ui| {
+                    let mut text = "one two  three\tfour";
+                    ui.add(TextEdit::multiline(&mut text).show_whitespace(show_whitespace));
+                });
+            });
+            output
+                .shapes
+                .iter()
+                .filter(|clipped| matches!(clipped.shape, Shape::Circle(_)))
+                .count()
+        };
+        assert_eq!(count_circles(false), 0);
+        assert_eq!(count_circles(true), 3, "One dot per space");
+    }
+}
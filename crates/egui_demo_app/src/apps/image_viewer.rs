@@ -81,6 +81,13 @@ impl eframe::App for ImageViewer {
             ui.add(Slider::new(&mut self.image_options.uv.max.x, 0.0..=1.0).text("max x"));
             ui.add(Slider::new(&mut self.image_options.uv.max.y, 0.0..=1.0).text("max y"));
 
+            // flip
+            ui.add_space(2.0);
+            ui.horizontal(|ui| {
+                ui.checkbox(&mut self.image_options.flip.x, "Flip x");
+                ui.checkbox(&mut self.image_options.flip.y, "Flip y");
+            });
+
             // rotation
             ui.add_space(2.0);
             let had_rotation = self.image_options.rotation.is_some();
@@ -119,6 +126,7 @@ impl eframe::App for ImageViewer {
                 ui.color_edit_button_srgba(&mut self.image_options.tint);
                 ui.label("Tint");
             });
+            ui.add(Slider::new(&mut self.image_options.opacity, 0.0..=1.0).text("Opacity"));
 
             // fit
             ui.add_space(10.0);
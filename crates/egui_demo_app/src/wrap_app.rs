@@ -511,6 +511,8 @@ impl WrapApp {
                         }
                         if let Some(bytes) = &file.bytes {
                             additional_info.push(format!("{} bytes", bytes.len()));
+                        } else if let Some(size) = file.size {
+                            additional_info.push(format!("{size} bytes, not loaded"));
                         }
                         if !additional_info.is_empty() {
                             info += &format!(" ({})", additional_info.join(", "));
@@ -95,6 +95,7 @@ impl crate::View for CodeEditor {
                     .code_editor()
                     .desired_rows(10)
                     .lock_focus(true)
+                    .find_replace(true)
                     .desired_width(f32::INFINITY)
                     .layouter(&mut layouter),
             );
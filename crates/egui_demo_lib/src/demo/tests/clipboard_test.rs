@@ -1,11 +1,13 @@
 pub struct ClipboardTest {
     text: String,
+    pasted_image: Option<egui::TextureHandle>,
 }
 
 impl Default for ClipboardTest {
     fn default() -> Self {
         Self {
             text: "Example text you can copy-and-paste".to_owned(),
+            pasted_image: None,
         }
     }
 }
@@ -38,24 +40,21 @@ impl crate::View for ClipboardTest {
             })
             .inner;
 
-        if !cfg!(target_arch = "wasm32") {
-            // These commands are not yet implemented on web
-            ui.horizontal(|ui| {
-                for (name, cmd) in [
-                    ("Copy", egui::ViewportCommand::RequestCopy),
-                    ("Cut", egui::ViewportCommand::RequestCut),
-                    ("Paste", egui::ViewportCommand::RequestPaste),
-                ] {
-                    if ui.button(name).clicked() {
-                        // Next frame we should get a copy/cut/paste-event…
-                        ui.ctx().send_viewport_cmd(cmd);
+        ui.horizontal(|ui| {
+            for (name, cmd) in [
+                ("Copy", egui::ViewportCommand::RequestCopy),
+                ("Cut", egui::ViewportCommand::RequestCut),
+                ("Paste", egui::ViewportCommand::RequestPaste),
+            ] {
+                if ui.button(name).clicked() {
+                    // Next frame we should get a copy/cut/paste-event…
+                    ui.ctx().send_viewport_cmd(cmd);
 
-                        // …that should en up here:
-                        text_edit_response.request_focus();
-                    }
+                    // …that should en up here:
+                    text_edit_response.request_focus();
                 }
-            });
-        }
+            }
+        });
 
         ui.separator();
 
@@ -74,6 +73,27 @@ impl crate::View for ClipboardTest {
             }
         });
 
+        let pasted_image = ui.input(|i| {
+            i.events.iter().rev().find_map(|event| match event {
+                egui::Event::PasteImage(image) => Some(image.clone()),
+                _ => None,
+            })
+        });
+        if let Some(image) = pasted_image {
+            self.pasted_image = Some(ui.ctx().load_texture(
+                "pasted_image",
+                (*image).clone(),
+                Default::default(),
+            ));
+        }
+
+        if cfg!(target_arch = "wasm32") {
+            ui.label("On the web you can also paste images:");
+            if let Some(texture) = &self.pasted_image {
+                ui.add(egui::Image::new(texture).max_height(128.0));
+            }
+        }
+
         ui.vertical_centered_justified(|ui| {
             ui.add(crate::egui_github_link_file!());
         });
@@ -0,0 +1,649 @@
+//! Widgets for audio tools: [`Waveform`] and [`Spectrogram`].
+//!
+//! Both show a time range given by an [`AudioView`], which you own.
+//! Pass the same [`AudioView`] to several widgets to keep them in sync,
+//! e.g. a waveform above its spectrogram.
+//!
+//! * Zoom with ctrl/cmd + scroll (or pinch), around the mouse cursor
+//! * Pan by scrolling
+//! * Drag to select a region
+//! * Click to e.g. move the playhead (see [`AudioResponse::clicked_time`])
+//!
+//! ```
+//! # egui::__run_test_ui(|ui| {
+//! use egui_extras::audio::{AudioView, Waveform};
+//!
+//! let sample_rate = 48_000.0;
+//! let left: Vec<f32> = (0..48_000).map(|i| (i as f32 * 0.05).sin()).collect();
+//! let right: Vec<f32> = left.iter().map(|s| 0.5 * s).collect();
+//!
+//! # let mut view = AudioView::default();
+//! # let mut playhead = 0.0;
+//! let response = Waveform::new(&[&left, &right], sample_rate)
+//!     .playhead(playhead)
+//!     .show(ui, &mut view);
+//! if let Some(time) = response.clicked_time {
+//!     playhead = time;
+//! }
+//! if response.selection_changed {
+//!     println!("Selected {:?}", view.selection);
+//! }
+//! # });
+//! ```
+
+use std::ops::RangeInclusive;
+
+use egui::{
+    Color32, ColorImage, Mesh, Painter, Pos2, Rect, Response, Sense, Stroke, TextureHandle,
+    TextureOptions, Ui, lerp, pos2, remap, vec2,
+};
+
+/// The time range shown by audio widgets, and the selected region.
+///
+/// Store this in your app state between frames.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct AudioView {
+    /// Time at the left edge, in seconds.
+    pub start: f64,
+
+    /// How many seconds are visible.
+    ///
+    /// If this is zero (the default), the whole clip is shown.
+    pub duration: f64,
+
+    /// The selected region, in seconds.
+    pub selection: Option<RangeInclusive<f64>>,
+}
+
+impl AudioView {
+    /// Show the whole clip of the given length.
+    pub fn fit(&mut self, total_duration: f64) {
+        self.start = 0.0;
+        self.duration = total_duration;
+    }
+
+    /// The time at the given x coordinate of `rect`.
+    pub fn time_at(&self, rect: Rect, x: f32) -> f64 {
+        remap(
+            x as f64,
+            rect.left() as f64..=rect.right() as f64,
+            self.start..=self.start + self.duration,
+        )
+    }
+
+    /// The x coordinate of the given time in `rect`.
+    pub fn x_at(&self, rect: Rect, time: f64) -> f32 {
+        remap(
+            time,
+            self.start..=self.start + self.duration,
+            rect.left() as f64..=rect.right() as f64,
+        ) as f32
+    }
+
+    fn is_valid(&self) -> bool {
+        self.start.is_finite() && self.duration.is_finite() && 0.0 < self.duration
+    }
+}
+
+/// The result of showing a [`Waveform`] or [`Spectrogram`].
+pub struct AudioResponse {
+    /// The response of the whole widget.
+    pub response: Response,
+
+    /// The time (in seconds) where the user clicked, if they did.
+    pub clicked_time: Option<f64>,
+
+    /// The user changed [`AudioView::selection`] (by dragging, or by clicking to clear it).
+    pub selection_changed: bool,
+
+    /// The user finished dragging out a new selection this frame.
+    pub selection_finished: bool,
+}
+
+/// Shared by all the audio widgets: zooming, panning and selecting.
+fn interact(
+    ui: &Ui,
+    rect: Rect,
+    response: Response,
+    view: &mut AudioView,
+    total_duration: f64,
+) -> AudioResponse {
+    if !view.is_valid() {
+        view.fit(total_duration.max(f64::EPSILON));
+    }
+
+    if let Some(hover_pos) = response.hover_pos() {
+        let (zoom, scroll) = ui.input(|i| (i.zoom_delta(), i.smooth_scroll_delta));
+        let seconds_per_point = view.duration / rect.width().max(1.0) as f64;
+        view.start -= (scroll.x + scroll.y) as f64 * seconds_per_point;
+        if zoom != 1.0 {
+            let time_at_pointer = view.time_at(rect, hover_pos.x);
+            let min_duration = total_duration.max(f64::EPSILON) * 1e-6;
+            view.duration = (view.duration / zoom as f64).max(min_duration);
+            view.start = time_at_pointer
+                - (hover_pos.x - rect.left()) as f64 / rect.width().max(1.0) as f64 * view.duration;
+        }
+    }
+
+    let mut selection_changed = false;
+    let mut selection_finished = false;
+    let anchor_id = response.id.with("selection_anchor");
+    if response.drag_started_by(egui::PointerButton::Primary) {
+        if let Some(pos) = response.interact_pointer_pos() {
+            let anchor = view.time_at(rect, pos.x).clamp(0.0, total_duration);
+            ui.data_mut(|d| d.insert_temp(anchor_id, anchor));
+        }
+    }
+    if response.dragged_by(egui::PointerButton::Primary) {
+        let anchor = ui.data(|d| d.get_temp::<f64>(anchor_id));
+        if let (Some(anchor), Some(pos)) = (anchor, response.interact_pointer_pos()) {
+            let time = view.time_at(rect, pos.x).clamp(0.0, total_duration);
+            let selection = anchor.min(time)..=anchor.max(time);
+            if view.selection.as_ref() != Some(&selection) {
+                view.selection = Some(selection);
+                selection_changed = true;
+            }
+        }
+    }
+    if response.drag_stopped_by(egui::PointerButton::Primary) {
+        ui.data_mut(|d| d.remove::<f64>(anchor_id));
+        selection_finished = view.selection.is_some();
+    }
+
+    let mut clicked_time = None;
+    if response.clicked() {
+        if let Some(pos) = response.interact_pointer_pos() {
+            clicked_time = Some(view.time_at(rect, pos.x).clamp(0.0, total_duration));
+        }
+        if view.selection.take().is_some() {
+            selection_changed = true;
+        }
+    }
+
+    AudioResponse {
+        response,
+        clicked_time,
+        selection_changed,
+        selection_finished,
+    }
+}
+
+/// Paint the selection and the playhead on top of the widget.
+fn paint_overlay(ui: &Ui, painter: &Painter, rect: Rect, view: &AudioView, playhead: Option<f64>) {
+    let visuals = ui.visuals();
+
+    if let Some(selection) = &view.selection {
+        let left = view.x_at(rect, *selection.start()).max(rect.left());
+        let right = view.x_at(rect, *selection.end()).min(rect.right());
+        if left <= right {
+            let selection_rect = Rect::from_x_y_ranges(left..=right, rect.y_range());
+            painter.rect_filled(
+                selection_rect,
+                0.0,
+                visuals.selection.bg_fill.gamma_multiply(0.4),
+            );
+            painter.vline(left, rect.y_range(), visuals.selection.stroke);
+            painter.vline(right, rect.y_range(), visuals.selection.stroke);
+        }
+    }
+
+    if let Some(playhead) = playhead {
+        let x = view.x_at(rect, playhead);
+        if rect.x_range().contains(x) {
+            painter.vline(x, rect.y_range(), Stroke::new(1.5, visuals.warn_fg_color));
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// Shows PCM samples, one lane per channel.
+///
+/// When zoomed out, the samples under each physical pixel are reduced to their minimum and maximum.
+/// When zoomed in far enough, the individual samples are connected by lines.
+///
+/// See the [module-level docs](self) for an example.
+#[must_use = "You should call .show()"]
+pub struct Waveform<'a> {
+    channels: &'a [&'a [f32]],
+    sample_rate: f32,
+    playhead: Option<f64>,
+    height: Option<f32>,
+    color: Option<Color32>,
+}
+
+impl<'a> Waveform<'a> {
+    /// One slice of samples per channel, nominally in `-1.0..=1.0`.
+    pub fn new(channels: &'a [&'a [f32]], sample_rate: f32) -> Self {
+        Self {
+            channels,
+            sample_rate,
+            playhead: None,
+            height: None,
+            color: None,
+        }
+    }
+
+    /// Show a playhead marker at this time, in seconds.
+    #[inline]
+    pub fn playhead(mut self, seconds: f64) -> Self {
+        self.playhead = Some(seconds);
+        self
+    }
+
+    /// Total height of the widget.
+    ///
+    /// Default: 64 points per channel.
+    #[inline]
+    pub fn height(mut self, height: f32) -> Self {
+        self.height = Some(height);
+        self
+    }
+
+    /// Color of the waveform.
+    ///
+    /// Default: [`egui::style::Visuals::text_color`].
+    #[inline]
+    pub fn color(mut self, color: Color32) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// Show the waveform, using the full available width.
+    pub fn show(self, ui: &mut Ui, view: &mut AudioView) -> AudioResponse {
+        let Self {
+            channels,
+            sample_rate,
+            playhead,
+            height,
+            color,
+        } = self;
+
+        let num_channels = channels.len().max(1);
+        let height = height.unwrap_or(64.0 * num_channels as f32);
+        let (rect, response) =
+            ui.allocate_exact_size(vec2(ui.available_width(), height), Sense::click_and_drag());
+
+        let num_samples = channels.iter().map(|c| c.len()).max().unwrap_or(0);
+        let sample_rate = sample_rate.max(f32::EPSILON) as f64;
+        let total_duration = num_samples as f64 / sample_rate;
+        let response = interact(ui, rect, response, view, total_duration);
+
+        if ui.is_rect_visible(rect) {
+            let visuals = ui.visuals();
+            let painter = ui.painter_at(rect);
+            painter.rect_filled(rect, 0.0, visuals.extreme_bg_color);
+            let color = color.unwrap_or_else(|| visuals.text_color());
+
+            let lane_height = rect.height() / num_channels as f32;
+            for (i, samples) in channels.iter().enumerate() {
+                let lane = Rect::from_min_size(
+                    rect.min + vec2(0.0, i as f32 * lane_height),
+                    vec2(rect.width(), lane_height),
+                );
+                if 0 < i {
+                    painter.hline(
+                        rect.x_range(),
+                        lane.top(),
+                        visuals.widgets.noninteractive.bg_stroke,
+                    );
+                }
+                painter.hline(
+                    rect.x_range(),
+                    lane.center().y,
+                    Stroke::new(1.0, color.gamma_multiply(0.2)),
+                );
+                paint_channel(ui, &painter, lane, samples, sample_rate, view, color);
+            }
+
+            paint_overlay(ui, &painter, rect, view, playhead);
+        }
+
+        response
+    }
+}
+
+fn paint_channel(
+    ui: &Ui,
+    painter: &Painter,
+    lane: Rect,
+    samples: &[f32],
+    sample_rate: f64,
+    view: &AudioView,
+    color: Color32,
+) {
+    if samples.is_empty() {
+        return;
+    }
+    let y_at = |sample: f32| {
+        lerp(
+            lane.bottom()..=lane.top(),
+            remap(sample.clamp(-1.0, 1.0), -1.0..=1.0, 0.0..=1.0),
+        )
+    };
+    let sample_at = |time: f64| time * sample_rate;
+
+    let pixels_per_point = ui.pixels_per_point();
+    let num_columns = (lane.width() * pixels_per_point).ceil().max(1.0) as usize;
+    let samples_per_column = view.duration * sample_rate / num_columns as f64;
+
+    if samples_per_column < 1.0 {
+        // Zoomed in: draw a line through the samples.
+        let first = sample_at(view.start).floor().max(0.0) as usize;
+        let last = (sample_at(view.start + view.duration).ceil() as usize + 1).min(samples.len());
+        let points: Vec<Pos2> = (first..last)
+            .map(|i| pos2(view.x_at(lane, i as f64 / sample_rate), y_at(samples[i])))
+            .collect();
+        if samples_per_column < 0.2 {
+            for point in &points {
+                painter.circle_filled(*point, 1.5, color);
+            }
+        }
+        painter.line(points, Stroke::new(1.0, color));
+        return;
+    }
+
+    // Zoomed out: one min/max bar per physical pixel.
+    let column_width = 1.0 / pixels_per_point;
+    let mut mesh = Mesh::default();
+    for column in 0..num_columns {
+        let begin = sample_at(view.start) + column as f64 * samples_per_column;
+        let end = begin + samples_per_column;
+        let begin = (begin.max(0.0) as usize).min(samples.len());
+        let end = (end.ceil().max(0.0) as usize).min(samples.len());
+        if begin >= end {
+            continue;
+        }
+        let (min, max) = samples[begin..end]
+            .iter()
+            .fold((f32::INFINITY, f32::NEG_INFINITY), |(min, max), &s| {
+                (min.min(s), max.max(s))
+            });
+        let x = lane.left() + column as f32 * column_width;
+        let top = y_at(max);
+        let bottom = y_at(min).max(top + column_width);
+        mesh.add_colored_rect(
+            Rect::from_x_y_ranges(x..=x + column_width, top..=bottom),
+            color,
+        );
+    }
+    painter.add(mesh);
+}
+
+// ----------------------------------------------------------------------------
+
+/// Maps a normalized magnitude in `0.0..=1.0` to a color.
+pub type Colormap = fn(f32) -> Color32;
+
+/// A perceptually ordered colormap going from black over purple and orange to light yellow.
+pub fn magma(t: f32) -> Color32 {
+    const STOPS: [[u8; 3]; 5] = [
+        [0, 0, 4],
+        [81, 18, 124],
+        [183, 55, 121],
+        [252, 137, 97],
+        [252, 253, 191],
+    ];
+    let t = t.clamp(0.0, 1.0) * (STOPS.len() - 1) as f32;
+    let i = (t.floor() as usize).min(STOPS.len() - 2);
+    let f = t - i as f32;
+    let [r, g, b] =
+        std::array::from_fn(|c| lerp(STOPS[i][c] as f32..=STOPS[i + 1][c] as f32, f).round() as u8);
+    Color32::from_rgb(r, g, b)
+}
+
+/// Shows a 2D array of magnitudes as an image, with time going right and frequency going up.
+///
+/// You compute the magnitudes (e.g. with an FFT), and this widget shows them.
+/// The magnitudes are re-rendered to a texture whenever they or the [`AudioView`] changes.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui_extras::audio::{AudioView, Spectrogram};
+///
+/// let num_bins = 256;
+/// let magnitudes = vec![0.5; 100 * num_bins]; // 100 frames
+/// # let mut view = AudioView::default();
+/// Spectrogram::new("spectrogram", &magnitudes, num_bins, 0.01).show(ui, &mut view);
+/// # });
+/// ```
+#[must_use = "You should call .show()"]
+pub struct Spectrogram<'a> {
+    id: egui::Id,
+    magnitudes: &'a [f32],
+    num_bins: usize,
+    frame_duration: f64,
+    range: Option<RangeInclusive<f32>>,
+    colormap: Colormap,
+    playhead: Option<f64>,
+    height: f32,
+}
+
+impl<'a> Spectrogram<'a> {
+    /// `magnitudes` is laid out frame by frame, i.e. `magnitudes[frame * num_bins + bin]`,
+    /// with the lowest frequency first in each frame.
+    ///
+    /// `frame_duration` is the time between two frames, in seconds.
+    ///
+    /// The `id_salt` must be unique, and is used for caching the texture.
+    pub fn new(
+        id_salt: impl std::hash::Hash,
+        magnitudes: &'a [f32],
+        num_bins: usize,
+        frame_duration: f64,
+    ) -> Self {
+        Self {
+            id: egui::Id::new(id_salt),
+            magnitudes,
+            num_bins,
+            frame_duration,
+            range: None,
+            colormap: magma,
+            playhead: None,
+            height: 128.0,
+        }
+    }
+
+    /// Which magnitudes map to the ends of the colormap.
+    ///
+    /// Default: from zero to the largest magnitude.
+    #[inline]
+    pub fn range(mut self, range: RangeInclusive<f32>) -> Self {
+        self.range = Some(range);
+        self
+    }
+
+    /// Default: [`magma`].
+    #[inline]
+    pub fn colormap(mut self, colormap: Colormap) -> Self {
+        self.colormap = colormap;
+        self
+    }
+
+    /// Show a playhead marker at this time, in seconds.
+    #[inline]
+    pub fn playhead(mut self, seconds: f64) -> Self {
+        self.playhead = Some(seconds);
+        self
+    }
+
+    /// Default: 128.
+    #[inline]
+    pub fn height(mut self, height: f32) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Show the spectrogram, using the full available width.
+    pub fn show(self, ui: &mut Ui, view: &mut AudioView) -> AudioResponse {
+        let Self {
+            id,
+            magnitudes,
+            num_bins,
+            frame_duration,
+            range,
+            colormap,
+            playhead,
+            height,
+        } = self;
+
+        let (rect, response) =
+            ui.allocate_exact_size(vec2(ui.available_width(), height), Sense::click_and_drag());
+
+        let num_bins = num_bins.max(1);
+        let num_frames = magnitudes.len() / num_bins;
+        let frame_duration = frame_duration.max(f64::EPSILON);
+        let total_duration = num_frames as f64 * frame_duration;
+        let response = interact(ui, rect, response, view, total_duration);
+
+        if !ui.is_rect_visible(rect) {
+            return response;
+        }
+
+        let painter = ui.painter_at(rect);
+        painter.rect_filled(rect, 0.0, colormap(0.0));
+
+        let pixels_per_point = ui.pixels_per_point();
+        let max_side = ui.input(|i| i.max_texture_side);
+        let size = [
+            ((rect.width() * pixels_per_point).ceil() as usize).clamp(1, max_side),
+            ((rect.height() * pixels_per_point).ceil() as usize).clamp(1, num_bins.min(max_side)),
+        ];
+        let range =
+            range.unwrap_or_else(|| 0.0..=magnitudes.iter().copied().fold(f32::EPSILON, f32::max));
+
+        let key = egui::util::hash((
+            HashBits(magnitudes),
+            num_bins,
+            size,
+            view.start.to_bits(),
+            view.duration.to_bits(),
+            range.start().to_bits(),
+            range.end().to_bits(),
+            colormap,
+        ));
+        let cache_id = id.with("texture");
+        let cached: Option<(u64, TextureHandle)> = ui.data(|d| d.get_temp(cache_id));
+        let texture = match cached {
+            Some((cached_key, texture)) if cached_key == key => texture,
+            _ => {
+                let image = render_spectrogram(
+                    magnitudes,
+                    num_bins,
+                    frame_duration,
+                    view,
+                    size,
+                    &range,
+                    colormap,
+                );
+                let texture = ui.ctx().load_texture(
+                    format!("egui_extras::audio::Spectrogram {id:?}"),
+                    image,
+                    TextureOptions::LINEAR,
+                );
+                ui.data_mut(|d| d.insert_temp(cache_id, (key, texture.clone())));
+                texture
+            }
+        };
+
+        painter.image(
+            texture.id(),
+            rect,
+            Rect::from_min_max(Pos2::ZERO, pos2(1.0, 1.0)),
+            Color32::WHITE,
+        );
+        paint_overlay(ui, &painter, rect, view, playhead);
+
+        response
+    }
+}
+
+/// Hashes floats by their bits, to notice when the magnitudes change.
+struct HashBits<'a>(&'a [f32]);
+
+impl std::hash::Hash for HashBits<'_> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.0.len().hash(state);
+        for value in self.0 {
+            value.to_bits().hash(state);
+        }
+    }
+}
+
+/// Render the visible part of the spectrogram, taking the maximum of all magnitudes under each pixel.
+fn render_spectrogram(
+    magnitudes: &[f32],
+    num_bins: usize,
+    frame_duration: f64,
+    view: &AudioView,
+    [width, height]: [usize; 2],
+    range: &RangeInclusive<f32>,
+    colormap: Colormap,
+) -> ColorImage {
+    let num_frames = magnitudes.len() / num_bins;
+    let frames_per_column = view.duration / frame_duration / width as f64;
+    let bins_per_row = num_bins as f64 / height as f64;
+    let span = (range.end() - range.start()).max(f32::EPSILON);
+
+    let mut pixels = vec![Color32::TRANSPARENT; width * height];
+    for column in 0..width {
+        let first = view.start / frame_duration + column as f64 * frames_per_column;
+        let begin = first.floor().max(0.0) as usize;
+        let end = ((first + frames_per_column).ceil().max(0.0) as usize)
+            .max(begin + 1)
+            .min(num_frames);
+        if begin >= end {
+            continue;
+        }
+        for row in 0..height {
+            // Row 0 is at the top, i.e. the highest frequency:
+            let bin_begin = ((height - 1 - row) as f64 * bins_per_row) as usize;
+            let bin_end = (((height - row) as f64 * bins_per_row).ceil() as usize)
+                .max(bin_begin + 1)
+                .min(num_bins);
+            let mut magnitude = f32::NEG_INFINITY;
+            for frame in begin..end {
+                let bins = &magnitudes[frame * num_bins..][bin_begin..bin_end];
+                magnitude = bins.iter().copied().fold(magnitude, f32::max);
+            }
+            pixels[row * width + column] = colormap((magnitude - range.start()) / span);
+        }
+    }
+
+    ColorImage::new([width, height], pixels)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use egui::Vec2;
+
+    #[test]
+    fn spectrogram_orientation() {
+        // Two frames of two bins. Only the high bin of the second frame is loud:
+        let magnitudes = [0.0, 0.0, 0.0, 1.0];
+        let view = AudioView {
+            start: 0.0,
+            duration: 2.0,
+            selection: None,
+        };
+        let image = render_spectrogram(&magnitudes, 2, 1.0, &view, [2, 2], &(0.0..=1.0), magma);
+        assert_eq!(image[(1, 0)], magma(1.0), "top right should be loud");
+        assert_eq!(image[(0, 0)], magma(0.0));
+        assert_eq!(image[(1, 1)], magma(0.0));
+    }
+
+    #[test]
+    fn view_roundtrip() {
+        let view = AudioView {
+            start: 1.0,
+            duration: 4.0,
+            selection: None,
+        };
+        let rect = Rect::from_min_size(pos2(10.0, 0.0), Vec2::splat(100.0));
+        assert_eq!(view.time_at(rect, 10.0), 1.0);
+        assert_eq!(view.time_at(rect, 110.0), 5.0);
+        assert_eq!(view.x_at(rect, 3.0), 60.0);
+    }
+}
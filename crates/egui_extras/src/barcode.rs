@@ -0,0 +1,894 @@
+//! QR codes and Code 128 barcodes, painted directly as shapes (no texture needed).
+
+use std::sync::Arc;
+
+use egui::{Color32, Mesh, Rect, Response, Sense, Ui, Vec2, Widget, WidgetInfo, WidgetType, vec2};
+
+/// How much of a [`QrCode`] can be damaged (or covered by a logo) and still be read.
+///
+/// Higher levels make the code larger, for the same data.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum QrErrorCorrection {
+    /// About 7% can be restored.
+    Low,
+
+    /// About 15% can be restored.
+    #[default]
+    Medium,
+
+    /// About 25% can be restored.
+    Quartile,
+
+    /// About 30% can be restored.
+    High,
+}
+
+impl QrErrorCorrection {
+    fn index(self) -> usize {
+        match self {
+            Self::Low => 0,
+            Self::Medium => 1,
+            Self::Quartile => 2,
+            Self::High => 3,
+        }
+    }
+
+    fn format_bits(self) -> u32 {
+        match self {
+            Self::Low => 1,
+            Self::Medium => 0,
+            Self::Quartile => 3,
+            Self::High => 2,
+        }
+    }
+}
+
+/// The modules ("pixels") of a QR code, encoding some bytes (e.g. UTF-8 text).
+///
+/// The smallest version (size) that fits the data is picked.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct QrCode {
+    size: usize,
+    modules: Vec<bool>,
+}
+
+impl QrCode {
+    /// Returns `None` if there is too much data for a QR code
+    /// (about 2300 bytes at [`QrErrorCorrection::Medium`]).
+    pub fn encode(data: &[u8], error_correction: QrErrorCorrection) -> Option<Self> {
+        let ecl = error_correction;
+        let version = (1..=40).find(|&version| {
+            let count_bits = if version <= 9 { 8 } else { 16 };
+            data.len() < 1 << count_bits
+                && 4 + count_bits + 8 * data.len() <= 8 * num_data_codewords(version, ecl)
+        })?;
+
+        // Byte mode, the length, the data, a terminator, and then padding:
+        let capacity = 8 * num_data_codewords(version, ecl);
+        let mut bits = BitBuffer::default();
+        bits.push(0b0100, 4);
+        bits.push(data.len() as u32, if version <= 9 { 8 } else { 16 });
+        for &byte in data {
+            bits.push(byte.into(), 8);
+        }
+        bits.push(0, (capacity - bits.len).min(4));
+        bits.push(0, (8 - bits.len % 8) % 8);
+        for &pad in [0xEC, 0x11].iter().cycle() {
+            if bits.len == capacity {
+                break;
+            }
+            bits.push(pad, 8);
+        }
+
+        let codewords = add_error_correction(&bits.bytes, version, ecl);
+
+        let mut builder = Builder::new(version);
+        builder.draw_function_patterns(ecl);
+        builder.draw_codewords(&codewords);
+
+        let best_mask = (0..8)
+            .min_by_key(|&mask| {
+                builder.apply_mask(mask);
+                builder.draw_format_bits(ecl, mask);
+                let penalty = builder.penalty();
+                builder.apply_mask(mask); // undo
+                penalty
+            })
+            .unwrap_or_default();
+        builder.apply_mask(best_mask);
+        builder.draw_format_bits(ecl, best_mask);
+
+        Some(Self {
+            size: builder.size,
+            modules: builder.modules,
+        })
+    }
+
+    /// The width and height, in modules (not counting the quiet zone).
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Is the module at the given column and row dark?
+    pub fn is_dark(&self, x: usize, y: usize) -> bool {
+        self.modules[y * self.size + x]
+    }
+}
+
+#[derive(Default)]
+struct BitBuffer {
+    bytes: Vec<u8>,
+    len: usize,
+}
+
+impl BitBuffer {
+    /// Append the lowest `count` bits of `value`, most significant first.
+    fn push(&mut self, value: u32, count: usize) {
+        for i in (0..count).rev() {
+            if self.len % 8 == 0 {
+                self.bytes.push(0);
+            }
+            if (value >> i) & 1 == 1 {
+                *self.bytes.last_mut().expect("just pushed") |= 0x80 >> (self.len % 8);
+            }
+            self.len += 1;
+        }
+    }
+}
+
+/// Error correction codewords per block, by level and version.
+const ECC_CODEWORDS_PER_BLOCK: [[u8; 41]; 4] = [
+    [
+        0, 7, 10, 15, 20, 26, 18, 20, 24, 30, 18, 20, 24, 26, 30, 22, 24, 28, 30, 28, 28, 28, 28,
+        30, 30, 26, 28, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30,
+    ],
+    [
+        0, 10, 16, 26, 18, 24, 16, 18, 22, 22, 26, 30, 22, 22, 24, 24, 28, 28, 26, 26, 26, 26, 28,
+        28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28,
+    ],
+    [
+        0, 13, 22, 18, 26, 18, 24, 18, 22, 20, 24, 28, 26, 24, 20, 30, 24, 28, 28, 26, 30, 28, 30,
+        30, 30, 30, 28, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30,
+    ],
+    [
+        0, 17, 28, 22, 16, 22, 28, 26, 26, 24, 28, 24, 28, 22, 24, 24, 30, 28, 28, 26, 28, 30, 24,
+        30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30,
+    ],
+];
+
+/// Error correction blocks, by level and version.
+const NUM_ERROR_CORRECTION_BLOCKS: [[u8; 41]; 4] = [
+    [
+        0, 1, 1, 1, 1, 1, 2, 2, 2, 2, 4, 4, 4, 4, 4, 6, 6, 6, 6, 7, 8, 8, 9, 9, 10, 12, 12, 12, 13,
+        14, 15, 16, 17, 18, 19, 19, 20, 21, 22, 24, 25,
+    ],
+    [
+        0, 1, 1, 1, 2, 2, 4, 4, 4, 5, 5, 5, 8, 9, 9, 10, 10, 11, 13, 14, 16, 17, 17, 18, 20, 21,
+        23, 25, 26, 28, 29, 31, 33, 35, 37, 38, 40, 43, 45, 47, 49,
+    ],
+    [
+        0, 1, 1, 2, 2, 4, 4, 6, 6, 8, 8, 8, 10, 12, 16, 12, 17, 16, 18, 21, 20, 23, 23, 25, 27, 29,
+        34, 34, 35, 38, 40, 43, 45, 48, 51, 53, 56, 59, 62, 65, 68,
+    ],
+    [
+        0, 1, 1, 2, 4, 4, 4, 5, 6, 8, 8, 11, 11, 16, 16, 18, 16, 19, 21, 25, 25, 25, 34, 30, 32,
+        35, 37, 40, 42, 45, 48, 51, 54, 57, 60, 63, 66, 70, 74, 77, 81,
+    ],
+];
+
+/// The number of modules that hold data or error correction, i.e. that aren't function patterns.
+fn num_raw_data_modules(version: usize) -> usize {
+    let mut result = (16 * version + 128) * version + 64;
+    if 2 <= version {
+        let num_align = version / 7 + 2;
+        result -= (25 * num_align - 10) * num_align - 55;
+        if 7 <= version {
+            result -= 36;
+        }
+    }
+    result
+}
+
+fn num_data_codewords(version: usize, ecl: QrErrorCorrection) -> usize {
+    num_raw_data_modules(version) / 8
+        - ECC_CODEWORDS_PER_BLOCK[ecl.index()][version] as usize
+            * NUM_ERROR_CORRECTION_BLOCKS[ecl.index()][version] as usize
+}
+
+/// Split the data into blocks, append the error correction to each, and interleave them.
+fn add_error_correction(data: &[u8], version: usize, ecl: QrErrorCorrection) -> Vec<u8> {
+    let num_blocks = NUM_ERROR_CORRECTION_BLOCKS[ecl.index()][version] as usize;
+    let ecc_len = ECC_CODEWORDS_PER_BLOCK[ecl.index()][version] as usize;
+    let raw_codewords = num_raw_data_modules(version) / 8;
+    let num_short_blocks = num_blocks - raw_codewords % num_blocks;
+    let short_block_len = raw_codewords / num_blocks;
+    let divisor = reed_solomon_divisor(ecc_len);
+
+    let mut blocks = Vec::with_capacity(num_blocks);
+    let mut start = 0;
+    for i in 0..num_blocks {
+        let len = short_block_len - ecc_len + usize::from(num_short_blocks <= i);
+        let mut block = data[start..start + len].to_vec();
+        start += len;
+        let ecc = reed_solomon_remainder(&block, &divisor);
+        if i < num_short_blocks {
+            block.push(0); // placeholder, skipped when interleaving
+        }
+        block.extend(ecc);
+        blocks.push(block);
+    }
+
+    let mut result = Vec::with_capacity(raw_codewords);
+    for i in 0..blocks[0].len() {
+        for (j, block) in blocks.iter().enumerate() {
+            if i != short_block_len - ecc_len || num_short_blocks <= j {
+                result.push(block[i]);
+            }
+        }
+    }
+    result
+}
+
+/// Multiply in GF(2^8), modulo `x^8 + x^4 + x^3 + x^2 + 1`.
+fn gf_mul(x: u8, y: u8) -> u8 {
+    let mut z: u32 = 0;
+    for i in (0..8).rev() {
+        z = (z << 1) ^ ((z >> 7) * 0x11D);
+        z ^= u32::from((y >> i) & 1) * u32::from(x);
+    }
+    z as u8
+}
+
+/// The generator polynomial of the given degree, without its leading term.
+fn reed_solomon_divisor(degree: usize) -> Vec<u8> {
+    let mut result = vec![0; degree];
+    result[degree - 1] = 1;
+    let mut root = 1;
+    for _ in 0..degree {
+        for j in 0..degree {
+            result[j] = gf_mul(result[j], root);
+            if j + 1 < degree {
+                result[j] ^= result[j + 1];
+            }
+        }
+        root = gf_mul(root, 0x02);
+    }
+    result
+}
+
+fn reed_solomon_remainder(data: &[u8], divisor: &[u8]) -> Vec<u8> {
+    let mut result = vec![0; divisor.len()];
+    for &byte in data {
+        let factor = byte ^ result.remove(0);
+        result.push(0);
+        for (x, &y) in result.iter_mut().zip(divisor) {
+            *x ^= gf_mul(y, factor);
+        }
+    }
+    result
+}
+
+/// Builds up the modules of a [`QrCode`].
+struct Builder {
+    version: usize,
+    size: usize,
+    modules: Vec<bool>,
+
+    /// Modules of the finder, timing, alignment, format and version patterns,
+    /// which don't hold data and aren't masked.
+    is_function: Vec<bool>,
+}
+
+impl Builder {
+    fn new(version: usize) -> Self {
+        let size = 4 * version + 17;
+        Self {
+            version,
+            size,
+            modules: vec![false; size * size],
+            is_function: vec![false; size * size],
+        }
+    }
+
+    fn set_function(&mut self, x: usize, y: usize, dark: bool) {
+        self.modules[y * self.size + x] = dark;
+        self.is_function[y * self.size + x] = true;
+    }
+
+    fn draw_function_patterns(&mut self, ecl: QrErrorCorrection) {
+        let size = self.size;
+        for i in 0..size {
+            self.set_function(6, i, i % 2 == 0);
+            self.set_function(i, 6, i % 2 == 0);
+        }
+
+        for (x, y) in [(3, 3), (size - 4, 3), (3, size - 4)] {
+            for dy in -4_i32..=4 {
+                for dx in -4_i32..=4 {
+                    let (xx, yy) = (x as i32 + dx, y as i32 + dy);
+                    if (0..size as i32).contains(&xx) && (0..size as i32).contains(&yy) {
+                        let distance = dx.abs().max(dy.abs());
+                        self.set_function(xx as usize, yy as usize, distance != 2 && distance != 4);
+                    }
+                }
+            }
+        }
+
+        let positions = self.alignment_pattern_positions();
+        let n = positions.len();
+        for (i, &x) in positions.iter().enumerate() {
+            for (j, &y) in positions.iter().enumerate() {
+                // Skip the three that overlap the finder patterns:
+                if (i == 0 && (j == 0 || j == n - 1)) || (i == n - 1 && j == 0) {
+                    continue;
+                }
+                for dy in -2_i32..=2 {
+                    for dx in -2_i32..=2 {
+                        let dark = dx.abs().max(dy.abs()) != 1;
+                        self.set_function((x as i32 + dx) as usize, (y as i32 + dy) as usize, dark);
+                    }
+                }
+            }
+        }
+
+        // Reserve the format bits, to be drawn once the mask is picked:
+        self.draw_format_bits(ecl, 0);
+        self.draw_version();
+    }
+
+    fn alignment_pattern_positions(&self) -> Vec<usize> {
+        if self.version == 1 {
+            return vec![];
+        }
+        let num_align = self.version / 7 + 2;
+        let step = (self.version * 8 + num_align * 3 + 5) / (num_align * 4 - 4) * 2;
+        let mut result: Vec<usize> = (0..num_align - 1)
+            .map(|i| self.size - 7 - i * step)
+            .collect();
+        result.push(6);
+        result.reverse();
+        result
+    }
+
+    fn draw_format_bits(&mut self, ecl: QrErrorCorrection, mask: u32) {
+        let data = (ecl.format_bits() << 3) | mask;
+        let mut rem = data;
+        for _ in 0..10 {
+            rem = (rem << 1) ^ ((rem >> 9) * 0x537);
+        }
+        let bits = ((data << 10) | rem) ^ 0x5412;
+        let bit = |i: usize| (bits >> i) & 1 == 1;
+
+        let size = self.size;
+        for i in 0..6 {
+            self.set_function(8, i, bit(i));
+        }
+        self.set_function(8, 7, bit(6));
+        self.set_function(8, 8, bit(7));
+        self.set_function(7, 8, bit(8));
+        for i in 9..15 {
+            self.set_function(14 - i, 8, bit(i));
+        }
+
+        for i in 0..8 {
+            self.set_function(size - 1 - i, 8, bit(i));
+        }
+        for i in 8..15 {
+            self.set_function(8, size - 15 + i, bit(i));
+        }
+        self.set_function(8, size - 8, true);
+    }
+
+    fn draw_version(&mut self) {
+        if self.version < 7 {
+            return;
+        }
+        let mut rem = self.version as u32;
+        for _ in 0..12 {
+            rem = (rem << 1) ^ ((rem >> 11) * 0x1F25);
+        }
+        let bits = ((self.version as u32) << 12) | rem;
+        for i in 0..18 {
+            let dark = (bits >> i) & 1 == 1;
+            let (a, b) = (self.size - 11 + i % 3, i / 3);
+            self.set_function(a, b, dark);
+            self.set_function(b, a, dark);
+        }
+    }
+
+    /// Fill in the data in a zigzag, two columns at a time, from the bottom right.
+    fn draw_codewords(&mut self, codewords: &[u8]) {
+        let size = self.size;
+        let mut i = 0;
+        let mut right = size - 1;
+        while 1 <= right {
+            if right == 6 {
+                right = 5; // skip the vertical timing pattern
+            }
+            for vert in 0..size {
+                for j in 0..2 {
+                    let x = right - j;
+                    let upward = (right + 1) & 2 == 0;
+                    let y = if upward { size - 1 - vert } else { vert };
+                    if !self.is_function[y * size + x] && i < 8 * codewords.len() {
+                        self.modules[y * size + x] = (codewords[i / 8] >> (7 - i % 8)) & 1 == 1;
+                        i += 1;
+                    }
+                }
+            }
+            if right < 2 {
+                break;
+            }
+            right -= 2;
+        }
+    }
+
+    /// Flip the data modules with the given mask pattern. Applying it twice undoes it.
+    fn apply_mask(&mut self, mask: u32) {
+        for y in 0..self.size {
+            for x in 0..self.size {
+                let invert = match mask {
+                    0 => (x + y) % 2 == 0,
+                    1 => y % 2 == 0,
+                    2 => x % 3 == 0,
+                    3 => (x + y) % 3 == 0,
+                    4 => (x / 3 + y / 2) % 2 == 0,
+                    5 => x * y % 2 + x * y % 3 == 0,
+                    6 => (x * y % 2 + x * y % 3) % 2 == 0,
+                    _ => ((x + y) % 2 + x * y % 3) % 2 == 0,
+                };
+                let index = y * self.size + x;
+                self.modules[index] ^= invert && !self.is_function[index];
+            }
+        }
+    }
+
+    /// How hard the code is to scan: long runs, blocks, look-alike finder patterns and imbalance.
+    fn penalty(&self) -> usize {
+        let size = self.size;
+        let dark = |x: usize, y: usize| self.modules[y * size + x];
+        let mut penalty = 0;
+
+        for transposed in [false, true] {
+            let at = |a: usize, b: usize| if transposed { dark(b, a) } else { dark(a, b) };
+            for b in 0..size {
+                let line: Vec<bool> = (0..size).map(|a| at(a, b)).collect();
+
+                let mut run = 1;
+                for a in 1..=size {
+                    if a < size && line[a] == line[a - 1] {
+                        run += 1;
+                    } else {
+                        if 5 <= run {
+                            penalty += run - 2;
+                        }
+                        run = 1;
+                    }
+                }
+
+                const FINDER: [bool; 11] = [
+                    true, false, true, true, true, false, true, false, false, false, false,
+                ];
+                for window in line.windows(11) {
+                    if window == FINDER || window.iter().rev().eq(FINDER.iter()) {
+                        penalty += 40;
+                    }
+                }
+            }
+        }
+
+        for y in 1..size {
+            for x in 1..size {
+                let color = dark(x, y);
+                if color == dark(x - 1, y) && color == dark(x, y - 1) && color == dark(x - 1, y - 1)
+                {
+                    penalty += 3;
+                }
+            }
+        }
+
+        let total = size * size;
+        let num_dark = self.modules.iter().filter(|&&dark| dark).count();
+        let imbalance = (20 * num_dark).abs_diff(10 * total);
+        penalty += 10 * (imbalance.div_ceil(total)).saturating_sub(1);
+
+        penalty
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// Bar widths for the symbols of Code 128. The last one, the stop symbol, has an extra bar.
+const CODE128_PATTERNS: [&[u8]; 107] = [
+    b"212222", b"222122", b"222221", b"121223", b"121322", b"131222", b"122213", b"122312",
+    b"132212", b"221213", b"221312", b"231212", b"112232", b"122132", b"122231", b"113222",
+    b"123122", b"123221", b"223211", b"221132", b"221231", b"213212", b"223112", b"312131",
+    b"311222", b"321122", b"321221", b"312212", b"322112", b"322211", b"212123", b"212321",
+    b"232121", b"111323", b"131123", b"131321", b"112313", b"132113", b"132311", b"211313",
+    b"231113", b"231311", b"112133", b"112331", b"132131", b"113123", b"113321", b"133121",
+    b"313121", b"211331", b"231131", b"213113", b"213311", b"213131", b"311123", b"311321",
+    b"331121", b"312113", b"312311", b"332111", b"314111", b"221411", b"431111", b"111224",
+    b"111422", b"121124", b"121421", b"141122", b"141221", b"112214", b"112412", b"122114",
+    b"122411", b"142112", b"142211", b"241211", b"221114", b"413111", b"241112", b"134111",
+    b"111242", b"121142", b"121241", b"114212", b"124112", b"124211", b"411212", b"421112",
+    b"421211", b"212141", b"214121", b"412121", b"111143", b"111341", b"131141", b"114113",
+    b"114311", b"411113", b"411311", b"113141", b"114131", b"311141", b"411131", b"211412",
+    b"211214", b"211232", b"2331112",
+];
+
+const CODE128_START_B: usize = 104;
+const CODE128_STOP: usize = 106;
+
+/// The modules of a Code 128 barcode (using code set B), `true` for bars.
+///
+/// Returns `None` if the text contains anything but printable ASCII.
+fn code128_modules(text: &str) -> Option<Vec<bool>> {
+    let values = text
+        .bytes()
+        .map(|byte| {
+            (b' '..=b'~')
+                .contains(&byte)
+                .then(|| usize::from(byte - b' '))
+        })
+        .collect::<Option<Vec<_>>>()?;
+    let checksum = values
+        .iter()
+        .enumerate()
+        .fold(CODE128_START_B, |sum, (i, value)| sum + (i + 1) * value)
+        % 103;
+
+    let symbols = std::iter::once(CODE128_START_B)
+        .chain(values)
+        .chain([checksum, CODE128_STOP]);
+    let mut modules = Vec::new();
+    for symbol in symbols {
+        for (i, width) in CODE128_PATTERNS[symbol].iter().enumerate() {
+            let is_bar = i % 2 == 0;
+            modules.extend(std::iter::repeat_n(is_bar, usize::from(width - b'0')));
+        }
+    }
+    Some(modules)
+}
+
+// ----------------------------------------------------------------------------
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+enum Kind {
+    Qr(QrErrorCorrection),
+    Code128,
+}
+
+/// What gets painted: a grid of `width` modules per row (one row for 1D barcodes).
+#[derive(Clone, Debug)]
+struct Modules {
+    width: usize,
+    dark: Vec<bool>,
+}
+
+#[derive(Default)]
+struct Encoder;
+
+impl egui::cache::ComputerMut<(Kind, &str), Option<Arc<Modules>>> for Encoder {
+    fn compute(&mut self, (kind, text): (Kind, &str)) -> Option<Arc<Modules>> {
+        let modules = match kind {
+            Kind::Qr(error_correction) => {
+                let code = QrCode::encode(text.as_bytes(), error_correction)?;
+                Modules {
+                    width: code.size,
+                    dark: code.modules,
+                }
+            }
+            Kind::Code128 => {
+                let dark = code128_modules(text)?;
+                Modules {
+                    width: dark.len(),
+                    dark,
+                }
+            }
+        };
+        Some(Arc::new(modules))
+    }
+}
+
+type EncoderCache = egui::cache::FrameCache<Option<Arc<Modules>>, Encoder>;
+
+/// Shows a QR code or barcode of some text, painted as rectangles.
+///
+/// The encoded code is cached, so this is cheap to show every frame.
+/// If the text can't be encoded (too long, or not ASCII for Code 128), an error is shown instead.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui_extras::barcode::{Barcode, QrErrorCorrection};
+///
+/// ui.add(Barcode::qr("https://www.egui.rs").error_correction(QrErrorCorrection::High));
+/// ui.add(Barcode::code128("ABC-1234").height(40.0));
+/// # });
+/// ```
+#[doc(alias = "qr")]
+#[must_use = "You should put this widget in a ui with `ui.add(widget);`"]
+pub struct Barcode<'a> {
+    text: &'a str,
+    kind: Kind,
+    module_size: f32,
+    quiet_zone: usize,
+    height: f32,
+    dark_color: Color32,
+    light_color: Color32,
+}
+
+impl<'a> Barcode<'a> {
+    /// A QR code of the given text, encoded as UTF-8.
+    pub fn qr(text: &'a str) -> Self {
+        Self {
+            text,
+            kind: Kind::Qr(QrErrorCorrection::default()),
+            module_size: 4.0,
+            quiet_zone: 4,
+            height: 0.0,
+            dark_color: Color32::BLACK,
+            light_color: Color32::WHITE,
+        }
+    }
+
+    /// A Code 128 barcode of the given text, which must be printable ASCII.
+    pub fn code128(text: &'a str) -> Self {
+        Self {
+            text,
+            kind: Kind::Code128,
+            module_size: 2.0,
+            quiet_zone: 10,
+            height: 48.0,
+            dark_color: Color32::BLACK,
+            light_color: Color32::WHITE,
+        }
+    }
+
+    /// How much of a QR code can be damaged and still be read. Ignored for barcodes.
+    ///
+    /// Default: [`QrErrorCorrection::Medium`].
+    #[inline]
+    pub fn error_correction(mut self, error_correction: QrErrorCorrection) -> Self {
+        if let Kind::Qr(ecl) = &mut self.kind {
+            *ecl = error_correction;
+        }
+        self
+    }
+
+    /// The size of each module (square of a QR code, or narrowest bar), in points.
+    ///
+    /// Default: 4 for QR codes, 2 for barcodes.
+    #[inline]
+    pub fn module_size(mut self, module_size: f32) -> Self {
+        self.module_size = module_size;
+        self
+    }
+
+    /// The size of the empty margin around the code, in modules.
+    ///
+    /// Scanners need this to find the code, so only make it smaller if it's on a light background.
+    ///
+    /// Default: 4 for QR codes, 10 for barcodes (as the standards require).
+    #[inline]
+    pub fn quiet_zone(mut self, quiet_zone: usize) -> Self {
+        self.quiet_zone = quiet_zone;
+        self
+    }
+
+    /// The height of the bars of a barcode, in points. Ignored for QR codes.
+    ///
+    /// Default: 48.
+    #[inline]
+    pub fn height(mut self, height: f32) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// The colors of the code. Keep it dark on light, since many scanners can't read it inverted.
+    ///
+    /// Default: black on white.
+    #[inline]
+    pub fn colors(mut self, dark: Color32, light: Color32) -> Self {
+        self.dark_color = dark;
+        self.light_color = light;
+        self
+    }
+}
+
+impl Widget for Barcode<'_> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Self {
+            text,
+            kind,
+            module_size,
+            quiet_zone,
+            height,
+            dark_color,
+            light_color,
+        } = self;
+
+        let modules = ui.memory_mut(|m| m.caches.cache::<EncoderCache>().get((kind, text)));
+        let Some(modules) = modules else {
+            let error = match kind {
+                Kind::Qr(_) => "Too much data for a QR code",
+                Kind::Code128 => "Code 128 only supports printable ASCII",
+            };
+            return ui.colored_label(ui.visuals().error_fg_color, error);
+        };
+
+        let columns = modules.width;
+        let rows = modules.dark.len() / columns.max(1);
+        let margin = quiet_zone as f32 * module_size;
+        let code_size = match kind {
+            Kind::Qr(_) => Vec2::splat(columns as f32 * module_size),
+            Kind::Code128 => vec2(columns as f32 * module_size, height),
+        };
+        let margin = match kind {
+            Kind::Qr(_) => Vec2::splat(margin),
+            Kind::Code128 => vec2(margin, 0.0),
+        };
+
+        let (rect, response) = ui.allocate_exact_size(code_size + 2.0 * margin, Sense::hover());
+        response.widget_info(|| WidgetInfo::labeled(WidgetType::Image, ui.is_enabled(), text));
+
+        if ui.is_rect_visible(rect) {
+            ui.painter().rect_filled(rect, 0.0, light_color);
+
+            // One rectangle per horizontal run of dark modules:
+            let origin = rect.min + margin;
+            let module = vec2(module_size, code_size.y / rows.max(1) as f32);
+            let mut mesh = Mesh::default();
+            for (y, row) in modules.dark.chunks_exact(columns.max(1)).enumerate() {
+                let mut x = 0;
+                while x < columns {
+                    if row[x] {
+                        let start = x;
+                        while x < columns && row[x] {
+                            x += 1;
+                        }
+                        let min = origin + vec2(start as f32, y as f32) * module;
+                        let max = origin + vec2(x as f32, (y + 1) as f32) * module;
+                        mesh.add_colored_rect(Rect::from_min_max(min, max), dark_color);
+                    }
+                    x += 1;
+                }
+            }
+            ui.painter().add(mesh);
+        }
+
+        response
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn qr_capacity() {
+        assert_eq!(num_data_codewords(1, QrErrorCorrection::Medium), 16);
+        assert_eq!(num_data_codewords(1, QrErrorCorrection::High), 9);
+        assert_eq!(num_data_codewords(40, QrErrorCorrection::Low), 2956);
+        assert_eq!(num_data_codewords(40, QrErrorCorrection::High), 1276);
+
+        let code = QrCode::encode(b"egui", QrErrorCorrection::Medium).unwrap();
+        assert_eq!(code.size(), 21);
+        assert!(code.is_dark(8, code.size() - 8), "The dark module");
+        let big = QrCode::encode(&[b'x'; 1000], QrErrorCorrection::Low).unwrap();
+        assert_eq!(big.size(), 4 * 22 + 17);
+        assert!(QrCode::encode(&[b'x'; 3000], QrErrorCorrection::Low).is_none());
+    }
+
+    /// Compares against <https://www.nayuki.io/page/qr-code-generator-library>,
+    /// with the same error correction level and no boosting of it.
+    #[test]
+    fn qr_matches_reference_encoder() {
+        let modules = |code: &QrCode| {
+            (0..code.size())
+                .map(|y| {
+                    (0..code.size())
+                        .map(|x| if code.is_dark(x, y) { '#' } else { '.' })
+                        .collect::<String>()
+                })
+                .collect::<Vec<_>>()
+        };
+        let reference = |text: &str| {
+            text.split_whitespace()
+                .map(str::to_owned)
+                .collect::<Vec<_>>()
+        };
+
+        // Version 1, mask 3:
+        let code = QrCode::encode(b"egui", QrErrorCorrection::Medium).unwrap();
+        let expected = "
+            #######.#.....#######
+            #.....#.####..#.....#
+            #.###.#..#..#.#.###.#
+            #.###.#.#.##..#.###.#
+            #.###.#...#...#.###.#
+            #.....#..#....#.....#
+            #######.#.#.#.#######
+            ........##...........
+            #.##.###.##...#..#.##
+            ##..#..#.#..#..#.##.#
+            .#.##.###.#.#####.###
+            .#..##..#.#.##...#...
+            ##...####.##.#..##.#.
+            ........#....#.#.....
+            #######.###..##.##...
+            #.....#.#..####..##..
+            #.###.#...##..#..####
+            #.###.#.#....#..#.##.
+            #.###.#.####.#..###..
+            #.....#....##.#.##..#
+            #######.#..##..#.....
+        ";
+        assert_eq!(modules(&code), reference(expected));
+
+        // Version 2 (with an alignment pattern), mask 1:
+        let code = QrCode::encode(b"Hello, egui!", QrErrorCorrection::High).unwrap();
+        let expected = "
+            #######...#####.#.#######
+            #.....#.#####.#...#.....#
+            #.###.#.#..#...#..#.###.#
+            #.###.#.########..#.###.#
+            #.###.#.#..#####..#.###.#
+            #.....#.#.###..##.#.....#
+            #######.#.#.#.#.#.#######
+            .........###.##.#........
+            ..#..#####.###...#.#####.
+            #.###..########.####.##.#
+            .##...##.##.#####.#..##.#
+            ..##...##.#.....##.#.#.#.
+            #....###.##.###...##.#..#
+            .#...#.....#......##.##.#
+            ####.###.#.##.##.#..###.#
+            ....#..#....###..#.#.#...
+            ####..##..#.##.#######.#.
+            ........#..#.#..#...###..
+            #######.#..##.###.#.#...#
+            #.....#.#.####.##...#....
+            #.###.#..#.####.######...
+            #.###.#..#..###.#...###..
+            #.###.#.##.##....#..#####
+            #.....#..#..###..........
+            #######..###.####.####..#
+        ";
+        assert_eq!(modules(&code), reference(expected));
+    }
+
+    #[test]
+    fn reed_solomon_codewords_are_divisible() {
+        // Evaluating a valid codeword at the roots of the generator gives zero:
+        let data = b"Hello, world! 123";
+        let divisor = reed_solomon_divisor(10);
+        let codeword: Vec<u8> = data
+            .iter()
+            .copied()
+            .chain(reed_solomon_remainder(data, &divisor))
+            .collect();
+        let mut root = 1;
+        for _ in 0..10 {
+            let value = codeword.iter().fold(0, |sum, &c| gf_mul(sum, root) ^ c);
+            assert_eq!(value, 0);
+            root = gf_mul(root, 0x02);
+        }
+    }
+
+    #[test]
+    fn code128() {
+        for (i, pattern) in CODE128_PATTERNS.iter().enumerate() {
+            let width: u8 = pattern.iter().map(|w| w - b'0').sum();
+            assert_eq!(width, if i == CODE128_STOP { 13 } else { 11 });
+        }
+        let mut unique = CODE128_PATTERNS.to_vec();
+        unique.sort_unstable();
+        unique.dedup();
+        assert_eq!(unique.len(), CODE128_PATTERNS.len());
+
+        // Start, one symbol per character, checksum, stop:
+        assert_eq!(code128_modules("egui").unwrap().len(), 11 * 6 + 13);
+        assert!(code128_modules("ünicode").is_none());
+    }
+}
@@ -0,0 +1,62 @@
+//! Make HTTP requests, natively and on the web, using [`ehttp`].
+//!
+//! This is what the `http(s)://` image loader uses (see [`crate::install_image_loaders`]).
+//! The same code works on all platforms, and egui repaints when the response arrives.
+//!
+//! ```
+//! # egui::__run_test_ui(|ui| {
+//! use egui_extras::fetch::{Request, fetch_task};
+//!
+//! # let mut task = None;
+//! if ui.button("Fetch").clicked() {
+//!     task = Some(fetch_task(ui.ctx(), Request::get("https://example.com")));
+//! }
+//! if let Some(task) = &task {
+//!     task.show_when_ready(ui, |ui, response| match response {
+//!         Ok(response) => ui.label(response.text().unwrap_or("<binary>")),
+//!         Err(err) => ui.colored_label(ui.visuals().error_fg_color, err),
+//!     });
+//! }
+//! # });
+//! ```
+
+use egui::Context;
+
+pub use ehttp::{Headers, Request, Response};
+
+use crate::futures::TaskHandle;
+
+/// Perform the request in the background, and call `on_done` with the response.
+///
+/// A repaint is requested after `on_done` has been called.
+///
+/// Note that a response with an error status, e.g. 404, is still an `Ok`.
+/// Check [`Response::ok`] for that.
+pub fn fetch(
+    ctx: &Context,
+    request: Request,
+    on_done: impl FnOnce(Result<Response, String>) + Send + 'static,
+) {
+    let ctx = ctx.clone();
+    ehttp::fetch(request, move |response| {
+        on_done(response);
+        ctx.request_repaint();
+    });
+}
+
+/// Like [`fetch`], but gives you a [`TaskHandle`] to poll in the UI.
+pub fn fetch_task(ctx: &Context, request: Request) -> TaskHandle<Result<Response, String>> {
+    let (sender, handle) = TaskHandle::new(ctx);
+    fetch(ctx, request, move |response| sender.send(response));
+    handle
+}
+
+#[test]
+fn test_fetch_invalid_url() {
+    let ctx = Context::default();
+    let task = fetch_task(&ctx, Request::get("not a url"));
+    while !task.is_ready() {
+        std::thread::yield_now();
+    }
+    assert!(matches!(task.ready(), Some(Err(_))));
+}
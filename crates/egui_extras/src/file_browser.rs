@@ -0,0 +1,877 @@
+//! A file and folder browser panel.
+//!
+//! The browser talks to the file system through the [`FileSystem`] trait,
+//! so you can use it with the real file system ([`NativeFileSystem`]),
+//! with a virtual one ([`MemoryFileSystem`], e.g. on the web),
+//! or with your own (e.g. files in an archive or on a server).
+//!
+//! ```
+//! # egui::__run_test_ui(|ui| {
+//! use egui_extras::file_browser::{FileBrowser, MemoryFileSystem};
+//!
+//! let mut fs = MemoryFileSystem::default();
+//! fs.add_file("/images/cat.png", 1024);
+//! fs.add_file("/notes.txt", 12);
+//!
+//! let mut browser = FileBrowser::new("/").extensions(["png", "jpg"]);
+//! let response = browser.ui(ui, &mut fs);
+//! if let Some(path) = response.picked {
+//!     println!("Open {}", path.display());
+//! }
+//! # });
+//! ```
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    io,
+    path::{Path, PathBuf},
+};
+
+use egui::{NumExt as _, RichText, Ui};
+
+use crate::{Column, Size, StripBuilder, TableBuilder};
+
+/// One entry in a directory.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DirEntry {
+    /// Full path of the entry.
+    pub path: PathBuf,
+
+    /// Is this a directory (or a file)?
+    pub is_dir: bool,
+
+    /// Size in bytes, if known. Usually `None` for directories.
+    pub size: Option<u64>,
+}
+
+impl DirEntry {
+    /// The last component of the path.
+    pub fn name(&self) -> String {
+        file_name(&self.path)
+    }
+
+    /// The lowercase file extension, if any.
+    pub fn extension(&self) -> Option<String> {
+        self.path
+            .extension()
+            .map(|ext| ext.to_string_lossy().to_lowercase())
+    }
+}
+
+/// What the [`FileBrowser`] needs from a file system.
+///
+/// # Errors
+/// All methods report failures as [`io::Error`]s, which are shown in the browser.
+#[expect(clippy::missing_errors_doc)]
+pub trait FileSystem {
+    /// List everything in a directory. The order does not matter.
+    fn read_dir(&self, path: &Path) -> io::Result<Vec<DirEntry>>;
+
+    /// Create a new, empty directory.
+    fn create_dir(&mut self, path: &Path) -> io::Result<()>;
+
+    /// Rename (move) a file or directory.
+    fn rename(&mut self, from: &Path, to: &Path) -> io::Result<()>;
+}
+
+/// The file system of the computer, using [`std::fs`].
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct NativeFileSystem;
+
+#[cfg(not(target_arch = "wasm32"))]
+impl FileSystem for NativeFileSystem {
+    fn read_dir(&self, path: &Path) -> io::Result<Vec<DirEntry>> {
+        let mut entries = Vec::new();
+        for entry in std::fs::read_dir(path)? {
+            let entry = entry?;
+            let metadata = entry.metadata()?;
+            entries.push(DirEntry {
+                path: entry.path(),
+                is_dir: metadata.is_dir(),
+                size: metadata.is_file().then_some(metadata.len()),
+            });
+        }
+        Ok(entries)
+    }
+
+    fn create_dir(&mut self, path: &Path) -> io::Result<()> {
+        std::fs::create_dir(path)
+    }
+
+    fn rename(&mut self, from: &Path, to: &Path) -> io::Result<()> {
+        std::fs::rename(from, to)
+    }
+}
+
+/// A virtual file system kept in memory.
+///
+/// Useful on the web, in tests, or to present something that isn't really a file system.
+/// The root is `/`.
+#[derive(Clone, Debug, Default)]
+pub struct MemoryFileSystem {
+    /// Path -> size in bytes, or `None` for directories.
+    entries: BTreeMap<PathBuf, Option<u64>>,
+}
+
+impl MemoryFileSystem {
+    /// Add a file of the given size, creating any missing parent directories.
+    pub fn add_file(&mut self, path: impl Into<PathBuf>, size: u64) {
+        let path = path.into();
+        self.add_parents(&path);
+        self.entries.insert(path, Some(size));
+    }
+
+    /// Add a directory, creating any missing parent directories.
+    pub fn add_dir(&mut self, path: impl Into<PathBuf>) {
+        let path = path.into();
+        self.add_parents(&path);
+        self.entries.insert(path, None);
+    }
+
+    fn add_parents(&mut self, path: &Path) {
+        for parent in path.ancestors().skip(1) {
+            if parent.parent().is_some() {
+                self.entries.entry(parent.to_owned()).or_insert(None);
+            }
+        }
+    }
+
+    fn is_dir(&self, path: &Path) -> bool {
+        path.parent().is_none() || self.entries.get(path) == Some(&None)
+    }
+}
+
+impl FileSystem for MemoryFileSystem {
+    fn read_dir(&self, path: &Path) -> io::Result<Vec<DirEntry>> {
+        if !self.is_dir(path) {
+            return Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("No such directory: {}", path.display()),
+            ));
+        }
+        Ok(self
+            .entries
+            .iter()
+            .filter(|(entry, _)| entry.parent() == Some(path))
+            .map(|(entry, size)| DirEntry {
+                path: entry.clone(),
+                is_dir: size.is_none(),
+                size: *size,
+            })
+            .collect())
+    }
+
+    fn create_dir(&mut self, path: &Path) -> io::Result<()> {
+        if self.entries.contains_key(path) {
+            return Err(io::Error::new(
+                io::ErrorKind::AlreadyExists,
+                format!("{} already exists", path.display()),
+            ));
+        }
+        if !path.parent().is_some_and(|parent| self.is_dir(parent)) {
+            return Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("No parent directory for {}", path.display()),
+            ));
+        }
+        self.entries.insert(path.to_owned(), None);
+        Ok(())
+    }
+
+    fn rename(&mut self, from: &Path, to: &Path) -> io::Result<()> {
+        if !self.entries.contains_key(from) {
+            return Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("No such file: {}", from.display()),
+            ));
+        }
+        if self.entries.contains_key(to) {
+            return Err(io::Error::new(
+                io::ErrorKind::AlreadyExists,
+                format!("{} already exists", to.display()),
+            ));
+        }
+
+        // Move the entry and everything inside it:
+        let moved: Vec<PathBuf> = self
+            .entries
+            .keys()
+            .filter(|path| path.starts_with(from))
+            .cloned()
+            .collect();
+        for old in moved {
+            if let Some(size) = self.entries.remove(&old) {
+                let suffix = old.strip_prefix(from).unwrap_or(&old);
+                let new = if suffix.as_os_str().is_empty() {
+                    to.to_owned()
+                } else {
+                    to.join(suffix)
+                };
+                self.entries.insert(new, size);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// What to sort the file list by. Directories are always listed first.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum SortBy {
+    #[default]
+    Name,
+    Extension,
+    Size,
+}
+
+/// What happened in [`FileBrowser::ui`] this frame.
+#[derive(Clone, Debug, Default)]
+pub struct FileBrowserResponse {
+    /// A file was double-clicked (or Enter was pressed on it while it had keyboard focus).
+    pub picked: Option<PathBuf>,
+
+    /// The selected entry changed.
+    pub selection_changed: bool,
+
+    /// The current directory changed.
+    pub directory_changed: bool,
+}
+
+/// An action that is being edited by the user.
+#[derive(Clone, Debug)]
+enum Edit {
+    NewFolder { name: String },
+    Rename { from: PathBuf, name: String },
+}
+
+/// A file browser: a directory tree, a sortable file list and a breadcrumb path bar.
+///
+/// Keep this around between frames and call [`Self::ui`] each frame.
+pub struct FileBrowser {
+    root: PathBuf,
+    current_dir: PathBuf,
+    selected: Option<PathBuf>,
+
+    sort_by: SortBy,
+    ascending: bool,
+    extensions: Vec<String>,
+    show_hidden: bool,
+    show_tree: bool,
+    allow_edits: bool,
+
+    /// Cached listing of [`Self::current_dir`].
+    entries: Option<Result<Vec<DirEntry>, String>>,
+
+    /// Cached sub-directories for the tree.
+    tree_children: BTreeMap<PathBuf, Vec<PathBuf>>,
+    expanded: BTreeSet<PathBuf>,
+
+    edit: Option<Edit>,
+
+    /// The [`Self::edit`] started this frame, so its text field should get keyboard focus.
+    edit_just_started: bool,
+
+    error: Option<String>,
+}
+
+impl FileBrowser {
+    /// Start browsing at `root`.
+    ///
+    /// The directory tree is rooted here.
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        let root = root.into();
+        Self {
+            current_dir: root.clone(),
+            expanded: std::iter::once(root.clone()).collect(),
+            root,
+            selected: None,
+            sort_by: SortBy::default(),
+            ascending: true,
+            extensions: Vec::new(),
+            show_hidden: false,
+            show_tree: true,
+            allow_edits: true,
+            entries: None,
+            tree_children: Default::default(),
+            edit: None,
+            edit_just_started: false,
+            error: None,
+        }
+    }
+
+    /// Only show files with one of these extensions (case-insensitive).
+    ///
+    /// Directories are always shown. An empty list shows all files.
+    #[inline]
+    pub fn extensions(mut self, extensions: impl IntoIterator<Item = impl ToString>) -> Self {
+        self.extensions = extensions
+            .into_iter()
+            .map(|ext| ext.to_string().trim_start_matches('.').to_lowercase())
+            .collect();
+        self.entries = None;
+        self
+    }
+
+    /// Show files and directories whose name starts with a `.`.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn show_hidden(mut self, show_hidden: bool) -> Self {
+        self.show_hidden = show_hidden;
+        self
+    }
+
+    /// Show the directory tree to the left of the file list.
+    ///
+    /// Default: `true`.
+    #[inline]
+    pub fn show_tree(mut self, show_tree: bool) -> Self {
+        self.show_tree = show_tree;
+        self
+    }
+
+    /// Allow creating and renaming folders and files.
+    ///
+    /// Default: `true`.
+    #[inline]
+    pub fn allow_edits(mut self, allow_edits: bool) -> Self {
+        self.allow_edits = allow_edits;
+        self
+    }
+
+    /// The directory whose contents are shown.
+    pub fn current_dir(&self) -> &Path {
+        &self.current_dir
+    }
+
+    /// Navigate to another directory.
+    pub fn set_current_dir(&mut self, dir: impl Into<PathBuf>) {
+        let dir = dir.into();
+        for ancestor in dir.ancestors() {
+            self.expanded.insert(ancestor.to_owned());
+        }
+        self.current_dir = dir;
+        self.selected = None;
+        self.edit = None;
+        self.entries = None;
+    }
+
+    /// The selected file or directory, if any.
+    pub fn selected(&self) -> Option<&Path> {
+        self.selected.as_deref()
+    }
+
+    /// Re-read everything from the file system on the next frame.
+    pub fn refresh(&mut self) {
+        self.entries = None;
+        self.tree_children.clear();
+    }
+
+    pub fn ui(&mut self, ui: &mut Ui, fs: &mut dyn FileSystem) -> FileBrowserResponse {
+        let mut response = FileBrowserResponse::default();
+
+        if self.entries.is_none() {
+            self.entries = Some(self.read_current_dir(fs));
+        }
+
+        self.path_bar_ui(ui, &mut response);
+        ui.separator();
+
+        let bottom_height = ui.spacing().interact_size.y + ui.spacing().item_spacing.y;
+        let tree_width = (ui.available_width() * 0.3).at_most(250.0);
+        let content_height = (ui.available_height() - bottom_height - 8.0).at_least(100.0);
+
+        ui.allocate_ui(egui::vec2(ui.available_width(), content_height), |ui| {
+            if self.show_tree {
+                StripBuilder::new(ui)
+                    .size(Size::exact(tree_width))
+                    .size(Size::remainder())
+                    .horizontal(|mut strip| {
+                        strip.cell(|ui| {
+                            egui::ScrollArea::both()
+                                .id_salt("file_browser_tree")
+                                .auto_shrink(false)
+                                .show(ui, |ui| {
+                                    let root = self.root.clone();
+                                    self.tree_ui(ui, fs, &root, &mut response);
+                                });
+                        });
+                        strip.cell(|ui| self.list_ui(ui, &mut response));
+                    });
+            } else {
+                self.list_ui(ui, &mut response);
+            }
+        });
+
+        ui.separator();
+        self.actions_ui(ui, fs, &mut response);
+
+        if response.directory_changed {
+            self.entries = None;
+        }
+
+        response
+    }
+
+    fn read_current_dir(&self, fs: &dyn FileSystem) -> Result<Vec<DirEntry>, String> {
+        let mut entries = fs
+            .read_dir(&self.current_dir)
+            .map_err(|err| err.to_string())?;
+        entries.retain(|entry| {
+            let name = entry.name();
+            if !self.show_hidden && name.starts_with('.') {
+                return false;
+            }
+            entry.is_dir
+                || self.extensions.is_empty()
+                || entry
+                    .extension()
+                    .is_some_and(|ext| self.extensions.contains(&ext))
+        });
+        self.sort(&mut entries);
+        Ok(entries)
+    }
+
+    fn sort(&self, entries: &mut [DirEntry]) {
+        entries.sort_by(|a, b| {
+            let ordering = match self.sort_by {
+                SortBy::Name => a.name().to_lowercase().cmp(&b.name().to_lowercase()),
+                SortBy::Extension => a
+                    .extension()
+                    .cmp(&b.extension())
+                    .then_with(|| a.name().to_lowercase().cmp(&b.name().to_lowercase())),
+                SortBy::Size => a.size.cmp(&b.size),
+            };
+            let ordering = if self.ascending {
+                ordering
+            } else {
+                ordering.reverse()
+            };
+            b.is_dir.cmp(&a.is_dir).then(ordering)
+        });
+    }
+
+    fn navigate(&mut self, dir: PathBuf, response: &mut FileBrowserResponse) {
+        if dir != self.current_dir {
+            self.set_current_dir(dir);
+            response.directory_changed = true;
+        }
+    }
+
+    fn path_bar_ui(&mut self, ui: &mut Ui, response: &mut FileBrowserResponse) {
+        ui.horizontal(|ui| {
+            let parent = self.current_dir.parent().map(Path::to_owned);
+            if ui
+                .add_enabled(parent.is_some(), egui::Button::new("⬆"))
+                .on_hover_text("Parent directory")
+                .clicked()
+            {
+                if let Some(parent) = parent {
+                    self.navigate(parent, response);
+                }
+            }
+            if ui.button("⟳").on_hover_text("Refresh").clicked() {
+                self.refresh();
+            }
+
+            ui.separator();
+
+            let ancestors: Vec<PathBuf> =
+                self.current_dir.ancestors().map(Path::to_owned).collect();
+            for (i, dir) in ancestors.into_iter().rev().enumerate() {
+                if 0 < i {
+                    ui.weak("⏵");
+                }
+                let is_current = dir == self.current_dir;
+                if ui.selectable_label(is_current, file_name(&dir)).clicked() {
+                    self.navigate(dir, response);
+                }
+            }
+        });
+    }
+
+    fn tree_ui(
+        &mut self,
+        ui: &mut Ui,
+        fs: &dyn FileSystem,
+        dir: &Path,
+        response: &mut FileBrowserResponse,
+    ) {
+        let id = ui.make_persistent_id(("file_browser_tree", dir));
+        let is_expanded = self.expanded.contains(dir);
+        let mut state =
+            egui::collapsing_header::CollapsingState::load_with_default_open(ui.ctx(), id, false);
+        state.set_open(is_expanded);
+
+        let header = state.show_header(ui, |ui| {
+            let is_current = dir == self.current_dir;
+            let icon = if is_expanded { "🗁" } else { "🗀" };
+            if ui
+                .selectable_label(is_current, format!("{icon} {}", file_name(dir)))
+                .clicked()
+            {
+                self.navigate(dir.to_owned(), response);
+            }
+        });
+
+        if header.is_open() != is_expanded {
+            if header.is_open() {
+                self.expanded.insert(dir.to_owned());
+            } else {
+                self.expanded.remove(dir);
+            }
+        }
+
+        header.body(|ui| {
+            if !self.tree_children.contains_key(dir) {
+                let mut children: Vec<PathBuf> = fs
+                    .read_dir(dir)
+                    .unwrap_or_default()
+                    .into_iter()
+                    .filter(|entry| {
+                        entry.is_dir && (self.show_hidden || !entry.name().starts_with('.'))
+                    })
+                    .map(|entry| entry.path)
+                    .collect();
+                children.sort_by_key(|path| file_name(path).to_lowercase());
+                self.tree_children.insert(dir.to_owned(), children);
+            }
+            let children = self.tree_children.get(dir).cloned().unwrap_or_default();
+            for child in children {
+                self.tree_ui(ui, fs, &child, response);
+            }
+        });
+    }
+
+    fn list_ui(&mut self, ui: &mut Ui, response: &mut FileBrowserResponse) {
+        let entries = match &self.entries {
+            Some(Ok(entries)) => entries.clone(),
+            Some(Err(err)) => {
+                ui.colored_label(ui.visuals().error_fg_color, err);
+                return;
+            }
+            None => return,
+        };
+
+        let row_height = ui.text_style_height(&egui::TextStyle::Body);
+        let mut sort_changed = false;
+
+        TableBuilder::new(ui)
+            .id_salt("file_browser_list")
+            .striped(true)
+            .sense(egui::Sense::click())
+            .cell_layout(egui::Layout::left_to_right(egui::Align::Center))
+            .column(Column::remainder().at_least(100.0).clip(true))
+            .column(Column::auto().at_least(50.0))
+            .column(Column::auto().at_least(70.0))
+            .auto_shrink(false)
+            .header(row_height + 4.0, |mut header| {
+                for (label, sort_by) in [
+                    ("Name", SortBy::Name),
+                    ("Type", SortBy::Extension),
+                    ("Size", SortBy::Size),
+                ] {
+                    header.col(|ui| {
+                        let arrow = if self.sort_by != sort_by {
+                            ""
+                        } else if self.ascending {
+                            " ⏶"
+                        } else {
+                            " ⏷"
+                        };
+                        if ui
+                            .selectable_label(
+                                self.sort_by == sort_by,
+                                RichText::new(format!("{label}{arrow}")).strong(),
+                            )
+                            .clicked()
+                        {
+                            if self.sort_by == sort_by {
+                                self.ascending = !self.ascending;
+                            } else {
+                                self.sort_by = sort_by;
+                                self.ascending = true;
+                            }
+                            sort_changed = true;
+                        }
+                    });
+                }
+            })
+            .body(|body| {
+                body.rows(row_height, entries.len(), |mut row| {
+                    let entry = &entries[row.index()];
+                    row.set_selected(self.selected.as_ref() == Some(&entry.path));
+
+                    row.col(|ui| {
+                        let icon = if entry.is_dir {
+                            "🗀"
+                        } else {
+                            file_icon(entry)
+                        };
+                        ui.add(
+                            egui::Label::new(format!("{icon} {}", entry.name())).selectable(false),
+                        );
+                    });
+                    row.col(|ui| {
+                        let typ = if entry.is_dir {
+                            "Folder".to_owned()
+                        } else {
+                            entry.extension().unwrap_or_default()
+                        };
+                        ui.add(egui::Label::new(typ).selectable(false));
+                    });
+                    row.col(|ui| {
+                        if let Some(size) = entry.size {
+                            ui.add(egui::Label::new(format_size(size)).selectable(false));
+                        }
+                    });
+
+                    let row_response = row.response();
+                    if row_response.clicked() {
+                        // So that Enter opens it, but only while the list has focus:
+                        row_response.request_focus();
+                        if self.selected.as_ref() != Some(&entry.path) {
+                            self.selected = Some(entry.path.clone());
+                            response.selection_changed = true;
+                        }
+                    }
+                    let enter = row_response.has_focus()
+                        && row_response.ctx.input(|i| i.key_pressed(egui::Key::Enter));
+                    if row_response.double_clicked() || enter {
+                        if entry.is_dir {
+                            self.navigate(entry.path.clone(), response);
+                        } else {
+                            response.picked = Some(entry.path.clone());
+                        }
+                    }
+                });
+            });
+
+        if sort_changed {
+            if let Some(Ok(entries)) = &mut self.entries {
+                let mut sorted = std::mem::take(entries);
+                self.sort(&mut sorted);
+                self.entries = Some(Ok(sorted));
+            }
+        }
+    }
+
+    fn actions_ui(
+        &mut self,
+        ui: &mut Ui,
+        fs: &mut dyn FileSystem,
+        response: &mut FileBrowserResponse,
+    ) {
+        ui.horizontal(|ui| {
+            if let Some(edit) = &mut self.edit {
+                let (label, name) = match edit {
+                    Edit::NewFolder { name } => ("New folder:", name),
+                    Edit::Rename { name, .. } => ("Rename to:", name),
+                };
+                ui.label(label);
+                let text_response = ui.text_edit_singleline(name);
+                if std::mem::take(&mut self.edit_just_started) {
+                    text_response.request_focus();
+                }
+                let submit =
+                    text_response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+                let cancel =
+                    text_response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Escape));
+
+                if ui.button("OK").clicked() || submit {
+                    self.apply_edit(fs, response);
+                } else if ui.button("Cancel").clicked() || cancel {
+                    self.edit = None;
+                }
+            } else if self.allow_edits {
+                if ui.button("🗁 New folder").clicked() {
+                    self.edit = Some(Edit::NewFolder {
+                        name: "New folder".to_owned(),
+                    });
+                    self.edit_just_started = true;
+                    self.error = None;
+                }
+                if let Some(selected) = &self.selected {
+                    if ui.button("Rename").clicked() {
+                        self.edit = Some(Edit::Rename {
+                            from: selected.clone(),
+                            name: file_name(selected),
+                        });
+                        self.edit_just_started = true;
+                        self.error = None;
+                    }
+                }
+            }
+
+            if let Some(error) = &self.error {
+                ui.colored_label(ui.visuals().error_fg_color, error);
+            }
+        });
+    }
+
+    fn apply_edit(&mut self, fs: &mut dyn FileSystem, response: &mut FileBrowserResponse) {
+        let Some(edit) = self.edit.take() else {
+            return;
+        };
+        let result = match &edit {
+            Edit::NewFolder { name } => valid_name(name).and_then(|name| {
+                let path = self.current_dir.join(name);
+                fs.create_dir(&path)
+                    .map(|()| path)
+                    .map_err(|err| err.to_string())
+            }),
+            Edit::Rename { from, name } => valid_name(name).and_then(|name| {
+                let to = from.with_file_name(name);
+                fs.rename(from, &to)
+                    .map(|()| to)
+                    .map_err(|err| err.to_string())
+            }),
+        };
+        match result {
+            Ok(path) => {
+                self.refresh();
+                self.selected = Some(path);
+                response.selection_changed = true;
+                self.error = None;
+            }
+            Err(err) => {
+                self.error = Some(err);
+                self.edit = Some(edit);
+            }
+        }
+    }
+}
+
+/// The trimmed `name`, if it names a single entry in a directory.
+///
+/// This rejects anything that would end up outside of the directory, like `..` or `a/b`.
+fn valid_name(name: &str) -> Result<&str, String> {
+    let name = name.trim();
+    let mut components = Path::new(name).components();
+    let single = matches!(
+        (components.next(), components.next()),
+        (Some(std::path::Component::Normal(_)), None)
+    );
+    if single && !name.contains(['/', '\\']) {
+        Ok(name)
+    } else if name.is_empty() {
+        Err("The name can't be empty".to_owned())
+    } else {
+        Err(format!("Invalid name: {name:?}"))
+    }
+}
+
+fn file_name(path: &Path) -> String {
+    path.file_name().map_or_else(
+        || path.display().to_string(),
+        |name| name.to_string_lossy().into_owned(),
+    )
+}
+
+fn file_icon(entry: &DirEntry) -> &'static str {
+    match entry.extension().as_deref() {
+        Some("png" | "jpg" | "jpeg" | "gif" | "bmp" | "webp" | "svg") => "🖼",
+        Some("txt" | "md" | "rs" | "toml" | "json" | "ron" | "csv") => "🖹",
+        _ => "🗋",
+    }
+}
+
+fn format_size(size: u64) -> String {
+    const KIB: f64 = 1024.0;
+    let size_f = size as f64;
+    if size_f < KIB {
+        format!("{size} B")
+    } else if size_f < KIB * KIB {
+        format!("{:.1} KiB", size_f / KIB)
+    } else if size_f < KIB * KIB * KIB {
+        format!("{:.1} MiB", size_f / (KIB * KIB))
+    } else {
+        format!("{:.1} GiB", size_f / (KIB * KIB * KIB))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn memory_file_system() {
+        let mut fs = MemoryFileSystem::default();
+        fs.add_file("/a/b/c.txt", 3);
+        fs.add_dir("/d");
+
+        let root = fs.read_dir(Path::new("/")).unwrap();
+        assert_eq!(root.len(), 2);
+        assert!(root.iter().all(|entry| entry.is_dir));
+
+        fs.rename(Path::new("/a"), Path::new("/x")).unwrap();
+        let moved = fs.read_dir(Path::new("/x/b")).unwrap();
+        assert_eq!(moved[0].path, Path::new("/x/b/c.txt"));
+        assert_eq!(moved[0].size, Some(3));
+
+        fs.create_dir(Path::new("/x/new")).unwrap();
+        assert!(fs.create_dir(Path::new("/x/new")).is_err());
+        assert!(fs.create_dir(Path::new("/nope/new")).is_err());
+    }
+
+    #[test]
+    fn edits_stay_in_the_directory() {
+        assert_eq!(valid_name("  new  "), Ok("new"));
+        assert_eq!(valid_name(".hidden"), Ok(".hidden"));
+        for name in ["", "  ", ".", "..", "a/b", "a\\b", "/abs", "../up"] {
+            assert!(valid_name(name).is_err(), "{name:?}");
+        }
+
+        let mut fs = MemoryFileSystem::default();
+        fs.add_file("/dir/a.txt", 1);
+        let mut browser = FileBrowser::new("/dir");
+        let mut response = FileBrowserResponse::default();
+
+        browser.edit = Some(Edit::NewFolder {
+            name: "../escaped".to_owned(),
+        });
+        browser.apply_edit(&mut fs, &mut response);
+        assert!(browser.error.is_some());
+        assert!(browser.edit.is_some());
+
+        browser.edit = Some(Edit::Rename {
+            from: PathBuf::from("/dir/a.txt"),
+            name: "..".to_owned(),
+        });
+        browser.apply_edit(&mut fs, &mut response);
+        assert!(browser.error.is_some());
+        assert_eq!(fs.read_dir(Path::new("/dir")).unwrap().len(), 1);
+        assert_eq!(fs.read_dir(Path::new("/")).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn filter_and_sort() {
+        let mut fs = MemoryFileSystem::default();
+        fs.add_file("/b.png", 10);
+        fs.add_file("/a.PNG", 20);
+        fs.add_file("/c.txt", 30);
+        fs.add_file("/.hidden.png", 1);
+        fs.add_dir("/z");
+
+        let browser = FileBrowser::new("/").extensions(["png"]);
+        let names: Vec<String> = browser
+            .read_current_dir(&fs)
+            .unwrap()
+            .iter()
+            .map(DirEntry::name)
+            .collect();
+        assert_eq!(names, ["z", "a.PNG", "b.png"]);
+
+        fs.add_file("/B.txt", 40);
+        let mut browser = FileBrowser::new("/");
+        browser.sort_by = SortBy::Extension;
+        let names: Vec<String> = browser
+            .read_current_dir(&fs)
+            .unwrap()
+            .iter()
+            .map(DirEntry::name)
+            .collect();
+        assert_eq!(names, ["z", "a.PNG", "b.png", "B.txt", "c.txt"]);
+    }
+}
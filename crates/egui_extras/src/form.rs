@@ -0,0 +1,302 @@
+//! Forms with labeled, validated fields, for CRUD-style apps.
+
+use egui::{Button, Grid, Id, Key, Rect, Response, RichText, Ui, WidgetText};
+
+/// The result of validating a single field: `Err` holds the message shown to the user.
+pub type Validation = Result<(), String>;
+
+/// Lays out labeled fields in a grid, shows validation errors, and handles submitting.
+///
+/// * Errors are shown (in red, below the field, which gets a red outline) once the user
+///   has edited the field, or tried to submit the form.
+/// * Submitting is blocked while any field is invalid.
+///   Instead, the first invalid field is focused (and scrolled into view).
+/// * Pressing enter in a single-line field submits the form.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut name = String::new();
+/// # let mut age = 18;
+/// use egui_extras::form::Form;
+///
+/// let response = Form::new("sign_up").show(ui, |form| {
+///     let name_ok = if name.trim().is_empty() {
+///         Err("Please enter your name".to_owned())
+///     } else {
+///         Ok(())
+///     };
+///     form.field("Name", name_ok, |ui| ui.text_edit_singleline(&mut name));
+///
+///     let age_ok = if age < 18 { Err("Must be 18 or older".to_owned()) } else { Ok(()) };
+///     form.field("Age", age_ok, |ui| ui.add(egui::DragValue::new(&mut age)));
+///
+///     form.submit_button("Sign up");
+/// });
+///
+/// if response.submitted {
+///     // every field is valid
+/// }
+/// # });
+/// ```
+#[must_use = "You should call .show()"]
+pub struct Form {
+    id: Id,
+    submit_on_enter: bool,
+}
+
+/// The result of showing a [`Form`].
+pub struct FormResponse<R> {
+    /// What the closure returned.
+    pub inner: R,
+
+    /// The response of the whole form.
+    pub response: Response,
+
+    /// The form was submitted this pass, and all fields are valid.
+    pub submitted: bool,
+
+    /// Are all fields valid?
+    pub is_valid: bool,
+}
+
+#[derive(Clone, Debug, Default)]
+struct State {
+    /// Show all errors, even for untouched fields, since the user tried to submit.
+    submit_attempted: bool,
+
+    /// The fields the user has edited.
+    touched: Vec<Id>,
+}
+
+/// Adds fields to a [`Form`]. Passed to the closure of [`Form::show`].
+pub struct FormUi<'a> {
+    ui: &'a mut Ui,
+    state: State,
+
+    /// The first invalid field, to focus on a failed submit.
+    first_invalid: Option<(Id, Rect)>,
+    num_invalid: usize,
+    submit: bool,
+    submit_on_enter: bool,
+}
+
+impl Form {
+    /// The `id_salt` must be unique, and is used to remember which fields have been edited.
+    pub fn new(id_salt: impl std::hash::Hash) -> Self {
+        Self {
+            id: Id::new(id_salt),
+            submit_on_enter: true,
+        }
+    }
+
+    /// Submit the form when enter is pressed in a single-line field.
+    ///
+    /// Default: `true`.
+    #[inline]
+    pub fn submit_on_enter(mut self, submit_on_enter: bool) -> Self {
+        self.submit_on_enter = submit_on_enter;
+        self
+    }
+
+    /// Forget which fields have been edited, e.g. after the form was submitted and cleared.
+    pub fn reset(ctx: &egui::Context, id_salt: impl std::hash::Hash) {
+        ctx.data_mut(|d| d.remove::<State>(Id::new(id_salt)));
+    }
+
+    pub fn show<R>(
+        self,
+        ui: &mut Ui,
+        add_contents: impl FnOnce(&mut FormUi<'_>) -> R,
+    ) -> FormResponse<R> {
+        let Self {
+            id,
+            submit_on_enter,
+        } = self;
+
+        let state: State = ui.data_mut(|d| d.get_temp(id)).unwrap_or_default();
+
+        let egui::InnerResponse { inner, response } =
+            Grid::new(id.with("grid")).num_columns(2).show(ui, |ui| {
+                let mut form = FormUi {
+                    ui,
+                    state,
+                    first_invalid: None,
+                    num_invalid: 0,
+                    submit: false,
+                    submit_on_enter,
+                };
+                let inner = add_contents(&mut form);
+                let FormUi {
+                    state,
+                    first_invalid,
+                    num_invalid,
+                    submit,
+                    ..
+                } = form;
+                (inner, state, first_invalid, num_invalid, submit)
+            });
+        let (inner, mut state, first_invalid, num_invalid, submit) = inner;
+
+        let is_valid = num_invalid == 0;
+        let mut submitted = false;
+        if submit {
+            if let Some((field_id, rect)) = first_invalid {
+                state.submit_attempted = true;
+                ui.memory_mut(|m| m.request_focus(field_id));
+                ui.scroll_to_rect(rect, None);
+                ui.ctx().request_repaint();
+            } else {
+                submitted = true;
+            }
+        }
+
+        ui.data_mut(|d| d.insert_temp(id, state));
+
+        FormResponse {
+            inner,
+            response,
+            submitted,
+            is_valid,
+        }
+    }
+}
+
+impl FormUi<'_> {
+    /// The [`Ui`] of the form grid, e.g. for adding your own rows.
+    ///
+    /// Remember to call [`Ui::end_row`] after each row.
+    pub fn ui(&mut self) -> &mut Ui {
+        self.ui
+    }
+
+    /// Add a row with a label and a field that needs no validation.
+    pub fn row(
+        &mut self,
+        label: impl Into<WidgetText>,
+        add_field: impl FnOnce(&mut Ui) -> Response,
+    ) -> Response {
+        self.field(label, Ok(()), add_field)
+    }
+
+    /// Add a row with a label and a field.
+    ///
+    /// `validation` is the result of validating the current value of the field.
+    /// `add_field` should add a single widget (e.g. a [`egui::TextEdit`]) and return its response.
+    pub fn field(
+        &mut self,
+        label: impl Into<WidgetText>,
+        validation: Validation,
+        add_field: impl FnOnce(&mut Ui) -> Response,
+    ) -> Response {
+        let label_response = self.ui.label(label);
+        let response = add_field(self.ui).labelled_by(label_response.id);
+        self.ui.end_row();
+
+        if response.changed() || response.lost_focus() {
+            if !self.state.touched.contains(&response.id) {
+                self.state.touched.push(response.id);
+            }
+            // The validation was for the old value:
+            self.ui.ctx().request_repaint();
+        }
+
+        if self.submit_on_enter
+            && response.lost_focus()
+            && self.ui.input(|i| i.key_pressed(Key::Enter))
+        {
+            self.submit = true;
+        }
+
+        if let Err(error) = validation {
+            self.num_invalid += 1;
+            if self.first_invalid.is_none() {
+                self.first_invalid = Some((response.id, response.rect));
+            }
+
+            let show_error =
+                self.state.submit_attempted || self.state.touched.contains(&response.id);
+            if show_error {
+                let color = self.ui.visuals().error_fg_color;
+                self.ui.painter().rect_stroke(
+                    response.rect.expand(1.0),
+                    self.ui.visuals().widgets.inactive.corner_radius,
+                    (1.0, color),
+                    egui::StrokeKind::Outside,
+                );
+
+                self.ui.label("");
+                self.ui.label(RichText::new(error).small().color(color));
+                self.ui.end_row();
+            }
+        }
+
+        response
+    }
+
+    /// Add a submit button below the fields.
+    ///
+    /// Clicking it submits the form (see [`FormResponse::submitted`]),
+    /// unless a field is invalid.
+    pub fn submit_button(&mut self, text: impl Into<WidgetText>) -> Response {
+        self.ui.label("");
+        let response = self.ui.add(Button::new(text));
+        self.ui.end_row();
+        if response.clicked() {
+            self.submit();
+        }
+        response
+    }
+
+    /// Try to submit the form, e.g. from a button of your own.
+    pub fn submit(&mut self) {
+        self.submit = true;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn failed_submit_focuses_first_invalid_field() {
+        let ctx = egui::Context::default();
+        let run = |name: &mut String, email: &mut String, submit: bool| {
+            let mut result = (false, Id::NULL);
+            let _ = ctx.run(Default::default(), |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    let response = Form::new("form").show(ui, |form| {
+                        let required = |s: &str| {
+                            if s.is_empty() {
+                                Err("Required".to_owned())
+                            } else {
+                                Ok(())
+                            }
+                        };
+                        form.field("Name", required(name), |ui| ui.text_edit_singleline(name));
+                        let email_response = form.field("Email", required(email), |ui| {
+                            ui.text_edit_singleline(email)
+                        });
+                        if submit {
+                            form.submit();
+                        }
+                        email_response.id
+                    });
+                    result = (response.submitted, response.inner);
+                });
+            });
+            result
+        };
+
+        let mut name = String::new();
+        let mut email = String::new();
+        run(&mut name, &mut email, false);
+        name = "Ferris".to_owned();
+        let (submitted, email_id) = run(&mut name, &mut email, true);
+        assert!(!submitted);
+        run(&mut name, &mut email, false);
+        assert!(ctx.memory(|m| m.has_focus(email_id)));
+
+        email = "ferris@example.com".to_owned();
+        assert!(run(&mut name, &mut email, true).0);
+    }
+}
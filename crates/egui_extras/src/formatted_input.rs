@@ -0,0 +1,564 @@
+//! Text fields for values with a fixed format, like phone numbers, IP addresses and color codes.
+//!
+//! The value is kept normalized (e.g. just the digits of a phone number),
+//! while the field shows it formatted (e.g. `(555) 123-4567`).
+//! Whatever is typed or pasted is normalized, so `555.123.4567` and `+1 (555) 123 4567` both work,
+//! and the cursor stays where the user expects it while separators come and go.
+//!
+//! Use [`InputFormat::validate`] to check the value, e.g. with a [`crate::form::Form`].
+
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use egui::{
+    Id, Response, TextEdit, Ui, Widget,
+    text::{CCursor, CCursorRange},
+};
+
+use crate::form::Validation;
+
+/// How to normalize, format and validate the value of a [`FormattedInput`].
+pub trait InputFormat {
+    /// Keep only what is part of the value, e.g. the digits of a phone number.
+    ///
+    /// This is applied to everything typed or pasted, so it should be lenient,
+    /// and it must be idempotent: `normalize(format(normalize(text))) == normalize(text)`.
+    fn normalize(&self, text: &str) -> String;
+
+    /// How to show a normalized value, e.g. with separators between groups of digits.
+    fn format(&self, value: &str) -> String {
+        value.to_owned()
+    }
+
+    /// Is the (normalized) value complete and valid?
+    ///
+    /// # Errors
+    /// What to tell the user is wrong with the value.
+    fn validate(&self, value: &str) -> Validation;
+
+    /// An example value, shown when the field is empty.
+    fn hint(&self) -> Option<String> {
+        None
+    }
+}
+
+/// What a slot of an [`InputMask`] accepts.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Slot {
+    Digit,
+    Letter,
+    HexDigit,
+    AlphaNumeric,
+    Literal(char),
+}
+
+impl Slot {
+    fn accepts(self, c: char) -> bool {
+        match self {
+            Self::Digit => c.is_ascii_digit(),
+            Self::Letter => c.is_alphabetic(),
+            Self::HexDigit => c.is_ascii_hexdigit(),
+            Self::AlphaNumeric => c.is_alphanumeric(),
+            Self::Literal(_) => false,
+        }
+    }
+}
+
+/// A fixed pattern, like `(###) ###-####` for North American phone numbers.
+///
+/// * `#` is a digit
+/// * `A` is a letter
+/// * `X` is a hexadecimal digit
+/// * `*` is a letter or a digit
+/// * anything else is shown as is, but isn't part of the value
+///
+/// Separators are only shown once the user has typed past them.
+/// The value is valid once all slots are filled.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InputMask {
+    slots: Vec<Slot>,
+}
+
+impl InputMask {
+    pub fn new(pattern: &str) -> Self {
+        let slots = pattern
+            .chars()
+            .map(|c| match c {
+                '#' => Slot::Digit,
+                'A' => Slot::Letter,
+                'X' => Slot::HexDigit,
+                '*' => Slot::AlphaNumeric,
+                c => Slot::Literal(c),
+            })
+            .collect();
+        Self { slots }
+    }
+
+    fn num_slots(&self) -> usize {
+        self.slots
+            .iter()
+            .filter(|slot| !matches!(slot, Slot::Literal(_)))
+            .count()
+    }
+}
+
+impl InputFormat for InputMask {
+    fn normalize(&self, text: &str) -> String {
+        let mut chars = text.chars();
+        let mut value = String::new();
+        for slot in &self.slots {
+            if matches!(slot, Slot::Literal(_)) {
+                continue;
+            }
+            match chars.find(|&c| slot.accepts(c)) {
+                Some(c) => value.push(c),
+                None => break,
+            }
+        }
+        value
+    }
+
+    fn format(&self, value: &str) -> String {
+        let mut chars = value.chars().peekable();
+        let mut text = String::new();
+        let mut literals = String::new();
+        for slot in &self.slots {
+            if chars.peek().is_none() {
+                break;
+            }
+            if let Slot::Literal(c) = slot {
+                literals.push(*c);
+            } else if let Some(c) = chars.next() {
+                text.push_str(&std::mem::take(&mut literals));
+                text.push(c);
+            }
+        }
+        text
+    }
+
+    fn validate(&self, value: &str) -> Validation {
+        let missing = self.num_slots() - value.chars().count().min(self.num_slots());
+        if missing == 0 {
+            Ok(())
+        } else {
+            Err(format!("{missing} more characters needed"))
+        }
+    }
+}
+
+/// A phone number: an optional leading `+` and up to 15 digits.
+///
+/// For readability the digits are shown in groups of three, with the last group of up to four,
+/// e.g. `+46 701 234 567`. Use an [`InputMask`] to follow the rules of a specific country.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct PhoneNumber;
+
+impl InputFormat for PhoneNumber {
+    fn normalize(&self, text: &str) -> String {
+        let plus = text.trim_start().starts_with('+');
+        let digits = text.chars().filter(char::is_ascii_digit).take(15);
+        if plus {
+            std::iter::once('+').chain(digits).collect()
+        } else {
+            digits.collect()
+        }
+    }
+
+    fn format(&self, value: &str) -> String {
+        let (plus, digits) = match value.strip_prefix('+') {
+            Some(digits) => ("+", digits),
+            None => ("", value),
+        };
+        let mut groups = Vec::new();
+        let mut rest = digits;
+        while 4 < rest.len() {
+            let (group, tail) = rest.split_at(3);
+            groups.push(group);
+            rest = tail;
+        }
+        if !rest.is_empty() {
+            groups.push(rest);
+        }
+        format!("{plus}{}", groups.join(" "))
+    }
+
+    fn validate(&self, value: &str) -> Validation {
+        let num_digits = value.trim_start_matches('+').len();
+        if num_digits < 7 {
+            Err("Phone number is too short".to_owned())
+        } else {
+            Ok(())
+        }
+    }
+
+    fn hint(&self) -> Option<String> {
+        Some("+1 555 123 4567".to_owned())
+    }
+}
+
+/// A payment card number, in groups of four digits (or 4-6-5 for American Express).
+///
+/// Valid when it has 13 to 19 digits and passes the Luhn checksum.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CreditCard;
+
+impl CreditCard {
+    fn is_amex(value: &str) -> bool {
+        value.starts_with("34") || value.starts_with("37")
+    }
+
+    /// The Luhn checksum, which catches all single-digit typos and most swapped digits.
+    pub fn luhn(digits: &str) -> bool {
+        let sum: u32 = digits
+            .chars()
+            .rev()
+            .filter_map(|c| c.to_digit(10))
+            .enumerate()
+            .map(|(i, digit)| match (i % 2 == 1, 2 * digit) {
+                (true, doubled) if 9 < doubled => doubled - 9,
+                (true, doubled) => doubled,
+                (false, _) => digit,
+            })
+            .sum();
+        sum % 10 == 0
+    }
+}
+
+impl InputFormat for CreditCard {
+    fn normalize(&self, text: &str) -> String {
+        let value: String = text.chars().filter(char::is_ascii_digit).take(19).collect();
+        if Self::is_amex(&value) {
+            value.chars().take(15).collect()
+        } else {
+            value
+        }
+    }
+
+    fn format(&self, value: &str) -> String {
+        let groups: &[usize] = if Self::is_amex(value) {
+            &[4, 6, 5]
+        } else {
+            &[4, 4, 4, 4, 3]
+        };
+        let mut text = String::new();
+        let mut rest = value;
+        for &len in groups {
+            if rest.is_empty() {
+                break;
+            }
+            let (group, tail) = rest.split_at(len.min(rest.len()));
+            if !text.is_empty() {
+                text.push(' ');
+            }
+            text.push_str(group);
+            rest = tail;
+        }
+        text
+    }
+
+    fn validate(&self, value: &str) -> Validation {
+        if value.len() < 13 {
+            Err("Card number is too short".to_owned())
+        } else if !Self::luhn(value) {
+            Err("Card number is not valid".to_owned())
+        } else {
+            Ok(())
+        }
+    }
+
+    fn hint(&self) -> Option<String> {
+        Some("1234 5678 9012 3456".to_owned())
+    }
+}
+
+/// An IPv4 address, like `192.168.0.1`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Ipv4Address;
+
+impl InputFormat for Ipv4Address {
+    fn normalize(&self, text: &str) -> String {
+        // Also accept e.g. "192, 168, 0, 1", by treating anything else as a single separator:
+        let mut value = String::new();
+        for c in text.trim().chars() {
+            if c.is_ascii_digit() {
+                value.push(c);
+            } else if !value.is_empty() && !value.ends_with('.') {
+                value.push('.');
+            }
+        }
+        value.chars().take(15).collect()
+    }
+
+    fn validate(&self, value: &str) -> Validation {
+        value
+            .parse::<Ipv4Addr>()
+            .map(|_| ())
+            .map_err(|_err| "Not a valid IPv4 address".to_owned())
+    }
+
+    fn hint(&self) -> Option<String> {
+        Some("192.168.0.1".to_owned())
+    }
+}
+
+/// An IPv6 address, like `2001:db8::1`. Hexadecimal digits are shown in lowercase.
+///
+/// An IPv4-style tail (`::ffff:192.168.0.1`) is allowed.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Ipv6Address;
+
+impl InputFormat for Ipv6Address {
+    fn normalize(&self, text: &str) -> String {
+        // Also accept an address copied from a URL, e.g. "[2001:db8::1]":
+        text.chars()
+            .filter(|&c| c.is_ascii_hexdigit() || c == ':' || c == '.')
+            .map(|c| c.to_ascii_lowercase())
+            .take(45)
+            .collect()
+    }
+
+    fn validate(&self, value: &str) -> Validation {
+        value
+            .parse::<Ipv6Addr>()
+            .map(|_| ())
+            .map_err(|_err| "Not a valid IPv6 address".to_owned())
+    }
+
+    fn hint(&self) -> Option<String> {
+        Some("2001:db8::1".to_owned())
+    }
+}
+
+/// A hex color code, like `#FF8800`.
+///
+/// The value is the uppercase hexadecimal digits, without the `#`:
+/// 3 (`RGB`), 6 (`RRGGBB`) or 8 (`RRGGBBAA`) of them.
+/// Use [`egui::Color32::from_hex`] (with a `#` in front) to get the color.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct HexColor;
+
+impl InputFormat for HexColor {
+    fn normalize(&self, text: &str) -> String {
+        // Also accept e.g. "0xff8800":
+        let text = text.trim();
+        let text = text
+            .strip_prefix("0x")
+            .or_else(|| text.strip_prefix("0X"))
+            .unwrap_or(text);
+        text.chars()
+            .filter(char::is_ascii_hexdigit)
+            .map(|c| c.to_ascii_uppercase())
+            .take(8)
+            .collect()
+    }
+
+    fn format(&self, value: &str) -> String {
+        format!("#{value}")
+    }
+
+    fn validate(&self, value: &str) -> Validation {
+        if matches!(value.len(), 3 | 6 | 8) {
+            Ok(())
+        } else {
+            Err("Use 3, 6 or 8 hexadecimal digits".to_owned())
+        }
+    }
+
+    fn hint(&self) -> Option<String> {
+        Some("#FF8800".to_owned())
+    }
+}
+
+/// A single-line [`TextEdit`] for a value with a fixed format, see the [module docs](self).
+///
+/// `value` is the normalized value, e.g. `5551234567` for a phone number shown as `555 123 4567`.
+/// An invalid value gets a red outline when the field isn't being edited;
+/// use [`InputFormat::validate`] to show the reason.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut ip = String::new();
+/// use egui_extras::formatted_input::{FormattedInput, Ipv4Address};
+///
+/// ui.add(FormattedInput::new(&mut ip, Ipv4Address));
+/// # });
+/// ```
+#[doc(alias = "mask")]
+#[must_use = "You should put this widget in a ui with `ui.add(widget);`"]
+pub struct FormattedInput<'a, F> {
+    value: &'a mut String,
+    format: F,
+    id_salt: Option<Id>,
+    desired_width: Option<f32>,
+    outline_invalid: bool,
+}
+
+impl<'a, F: InputFormat> FormattedInput<'a, F> {
+    pub fn new(value: &'a mut String, format: F) -> Self {
+        Self {
+            value,
+            format,
+            id_salt: None,
+            desired_width: None,
+            outline_invalid: true,
+        }
+    }
+
+    /// Use this if the id of the field isn't unique, or changes from pass to pass.
+    #[inline]
+    pub fn id_salt(mut self, id_salt: impl std::hash::Hash) -> Self {
+        self.id_salt = Some(Id::new(id_salt));
+        self
+    }
+
+    /// Default: [`egui::style::Spacing::text_edit_width`].
+    #[inline]
+    pub fn desired_width(mut self, desired_width: f32) -> Self {
+        self.desired_width = Some(desired_width);
+        self
+    }
+
+    /// Outline the field in red when the value is invalid, and the field isn't focused or empty.
+    ///
+    /// Turn this off when e.g. a [`crate::form::Form`] already shows the errors.
+    ///
+    /// Default: `true`.
+    #[inline]
+    pub fn outline_invalid(mut self, outline_invalid: bool) -> Self {
+        self.outline_invalid = outline_invalid;
+        self
+    }
+}
+
+impl<F: InputFormat> Widget for FormattedInput<'_, F> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Self {
+            value,
+            format,
+            id_salt,
+            desired_width,
+            outline_invalid,
+        } = self;
+
+        let id = id_salt.map_or_else(
+            || ui.next_auto_id(),
+            |id_salt| ui.make_persistent_id(id_salt),
+        );
+
+        let mut text = format.format(value);
+        let mut text_edit = TextEdit::singleline(&mut text)
+            .id(id)
+            .desired_width(desired_width.unwrap_or(ui.spacing().text_edit_width));
+        if let Some(hint) = format.hint() {
+            text_edit = text_edit.hint_text(hint);
+        }
+        let mut output = text_edit.show(ui);
+        let response = output.response;
+
+        if response.changed() {
+            let new_value = format.normalize(&text);
+            let formatted = format.format(&new_value);
+
+            // Keep the cursor after the same number of value characters:
+            if let Some(range) = output.cursor_range {
+                let in_value = |text: &str, index: usize| {
+                    let prefix: String = text.chars().take(index).collect();
+                    format.normalize(&prefix).chars().count()
+                };
+                let target = in_value(&text, range.primary.index);
+                let index = (0..=formatted.chars().count())
+                    .find(|&i| target <= in_value(&formatted, i))
+                    .unwrap_or_else(|| formatted.chars().count());
+                output
+                    .state
+                    .cursor
+                    .set_char_range(Some(CCursorRange::one(CCursor::new(index))));
+                output.state.store(ui.ctx(), id);
+            }
+
+            *value = new_value;
+        }
+
+        let is_invalid = !value.is_empty() && format.validate(value).is_err();
+        if outline_invalid && is_invalid && !response.has_focus() {
+            let stroke = egui::Stroke::new(1.0, ui.visuals().error_fg_color);
+            ui.painter().rect_stroke(
+                response.rect,
+                ui.visuals().widgets.inactive.corner_radius,
+                stroke,
+                egui::StrokeKind::Inside,
+            );
+        }
+
+        response
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats() {
+        let mask = InputMask::new("(###) ###-####");
+        assert_eq!(mask.normalize("555.123.45678"), "5551234567");
+        assert_eq!(mask.format("5551"), "(555) 1");
+        assert_eq!(mask.format("555"), "(555");
+        assert!(mask.validate("555123").is_err());
+        assert!(mask.validate("5551234567").is_ok());
+
+        assert_eq!(PhoneNumber.normalize(" +1 (555) 123-4567"), "+15551234567");
+        assert_eq!(PhoneNumber.format("+15551234567"), "+155 512 345 67");
+        assert_eq!(PhoneNumber.format("5551234567"), "555 123 4567");
+
+        assert_eq!(CreditCard.format("4111111111111111"), "4111 1111 1111 1111");
+        assert_eq!(CreditCard.format("378282246310005"), "3782 822463 10005");
+        assert!(CreditCard.validate("4111111111111111").is_ok());
+        assert!(CreditCard.validate("4111111111111112").is_err());
+
+        assert!(
+            Ipv4Address
+                .validate(&Ipv4Address.normalize("10, 0, 0, 1"))
+                .is_ok()
+        );
+        assert!(Ipv4Address.validate("256.0.0.1").is_err());
+        assert_eq!(Ipv6Address.normalize("[2001:DB8::1]"), "2001:db8::1");
+        assert!(Ipv6Address.validate("2001:db8::1").is_ok());
+
+        assert_eq!(HexColor.normalize("0xff8800"), "FF8800");
+        assert_eq!(HexColor.format("FF8800"), "#FF8800");
+        assert!(HexColor.validate("FF88").is_err());
+    }
+
+    #[test]
+    fn typing_keeps_cursor() {
+        let ctx = egui::Context::default();
+        let mut value = String::new();
+        let run = |value: &mut String, events: Vec<egui::Event>| {
+            let input = egui::RawInput {
+                events,
+                ..Default::default()
+            };
+            let mut id = Id::NULL;
+            let _ = ctx.run(input, |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    let mask = InputMask::new("(###) ###-####");
+                    id = ui.add(FormattedInput::new(value, mask).id_salt("phone")).id;
+                    ui.memory_mut(|m| m.request_focus(id));
+                });
+            });
+            TextEdit::load_state(&ctx, id)
+                .and_then(|state| state.cursor.char_range())
+                .map(|range| range.primary.index)
+        };
+
+        run(&mut value, vec![]);
+        run(&mut value, vec![]);
+        for c in "5551".chars() {
+            run(&mut value, vec![egui::Event::Text(c.to_string())]);
+        }
+        assert_eq!(value, "5551");
+        let cursor = run(&mut value, vec![egui::Event::Paste("23-4567".to_owned())]);
+        assert_eq!(value, "5551234567");
+        assert_eq!(cursor, Some("(555) 123-4567".len()));
+    }
+}
@@ -0,0 +1,297 @@
+//! Run background work and show its result in the UI when it is done.
+//!
+//! A [`TaskHandle`] is the UI side of some background work.
+//! Store it in your app state, and each frame poll it with [`TaskHandle::ready`]
+//! or [`TaskHandle::show_when_ready`].
+//! egui repaints when the work is done, and whenever it reports progress,
+//! so you don't need to poll continuously.
+//!
+//! egui does not come with an async runtime.
+//! Instead, [`TaskHandle::from_future`] gives you a future to spawn on whatever executor you use,
+//! e.g. `tokio::spawn` natively or `wasm_bindgen_futures::spawn_local` on the web.
+//! For blocking work there is [`TaskHandle::spawn_thread`],
+//! and for anything else [`TaskHandle::new`] gives you a [`TaskSender`].
+//!
+//! ```
+//! # egui::__run_test_ui(|ui| {
+//! use egui_extras::futures::TaskHandle;
+//!
+//! # let mut download: Option<TaskHandle<String>> = None;
+//! if ui.button("Download").clicked() {
+//!     let (task, handle) = TaskHandle::from_future(ui.ctx(), |progress| async move {
+//!         progress.set(0.5);
+//!         // … await something here …
+//!         "Hello!".to_owned()
+//!     });
+//!     # let spawn = |_| {};
+//!     spawn(task); // e.g. `tokio::spawn(task)`
+//!     download = Some(handle);
+//! }
+//!
+//! if let Some(download) = &download {
+//!     download.show_when_ready(ui, |ui, text| ui.label(text));
+//! }
+//! # });
+//! ```
+
+use std::{
+    future::Future,
+    sync::{Arc, OnceLock},
+    task::{Poll, Waker},
+};
+
+use egui::{Context, Ui, mutex::Mutex};
+
+#[derive(Default)]
+struct State {
+    /// In the range 0-1.
+    progress: Option<f32>,
+    cancelled: bool,
+
+    /// Woken on cancellation, so that a future from [`TaskHandle::from_future`] is dropped.
+    waker: Option<Waker>,
+}
+
+/// Shared by both sides of a task.
+struct Control {
+    ctx: Context,
+    state: Mutex<State>,
+}
+
+/// Lets a task report its progress, and check if it has been cancelled.
+///
+/// This is cheap to clone.
+#[derive(Clone)]
+pub struct Progress {
+    control: Arc<Control>,
+}
+
+impl Progress {
+    /// Report how far along the task is, from 0 to 1.
+    ///
+    /// This requests a repaint, so that the UI can show the progress.
+    pub fn set(&self, fraction: f32) {
+        self.control.state.lock().progress = Some(fraction.clamp(0.0, 1.0));
+        self.control.ctx.request_repaint();
+    }
+
+    /// Has [`TaskHandle::cancel`] been called?
+    ///
+    /// Long running tasks should check this now and then, and stop early if it is set.
+    pub fn is_cancelled(&self) -> bool {
+        self.control.state.lock().cancelled
+    }
+}
+
+/// The task side of a [`TaskHandle`], see [`TaskHandle::new`].
+pub struct TaskSender<T> {
+    progress: Progress,
+    result: Arc<OnceLock<T>>,
+}
+
+impl<T> TaskSender<T> {
+    /// Report progress, or check for cancellation.
+    pub fn progress(&self) -> &Progress {
+        &self.progress
+    }
+
+    /// Finish the task, and request a repaint so that the UI can show the result.
+    pub fn send(self, value: T) {
+        self.result.set(value).ok();
+        self.progress.control.ctx.request_repaint();
+    }
+}
+
+/// Some background work that will eventually produce a `T`.
+///
+/// See the [module docs](self) for how to create one.
+///
+/// Dropping the handle does not stop the work; use [`Self::cancel`] for that.
+pub struct TaskHandle<T> {
+    control: Arc<Control>,
+    result: Arc<OnceLock<T>>,
+}
+
+impl<T> TaskHandle<T> {
+    /// Create a task that is finished by calling [`TaskSender::send`], e.g. from a callback.
+    pub fn new(ctx: &Context) -> (TaskSender<T>, Self) {
+        let control = Arc::new(Control {
+            ctx: ctx.clone(),
+            state: Default::default(),
+        });
+        let result = Arc::new(OnceLock::new());
+        let sender = TaskSender {
+            progress: Progress {
+                control: control.clone(),
+            },
+            result: result.clone(),
+        };
+        (sender, Self { control, result })
+    }
+
+    /// Create a task from a future.
+    ///
+    /// It is up to you to spawn the returned future on an executor.
+    /// When the task is cancelled, the future you gave is dropped the next time the executor polls it.
+    pub fn from_future<F>(
+        ctx: &Context,
+        make_future: impl FnOnce(Progress) -> F,
+    ) -> (impl Future<Output = ()>, Self)
+    where
+        F: Future<Output = T>,
+    {
+        let (sender, handle) = Self::new(ctx);
+        let future = make_future(sender.progress.clone());
+
+        let task = async move {
+            let mut future = std::pin::pin!(future);
+            let output = std::future::poll_fn(|cx| {
+                {
+                    let mut state = sender.progress.control.state.lock();
+                    if state.cancelled {
+                        return Poll::Ready(None);
+                    }
+                    state.waker = Some(cx.waker().clone());
+                }
+                future.as_mut().poll(cx).map(Some)
+            })
+            .await;
+
+            if let Some(output) = output {
+                sender.send(output);
+            }
+        };
+
+        (task, handle)
+    }
+
+    /// Run blocking work on a new thread with the given name.
+    ///
+    /// # Errors
+    /// Fails if the thread could not be created.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn spawn_thread(
+        ctx: &Context,
+        name: impl Into<String>,
+        run: impl FnOnce(&Progress) -> T + Send + 'static,
+    ) -> std::io::Result<Self>
+    where
+        T: Send + Sync + 'static,
+    {
+        let (sender, handle) = Self::new(ctx);
+        std::thread::Builder::new()
+            .name(name.into())
+            .spawn(move || {
+                let value = run(sender.progress());
+                sender.send(value);
+            })?;
+        Ok(handle)
+    }
+
+    /// The result, if the task is done.
+    pub fn ready(&self) -> Option<&T> {
+        self.result.get()
+    }
+
+    pub fn is_ready(&self) -> bool {
+        self.ready().is_some()
+    }
+
+    /// The last reported progress, from 0 to 1, if any.
+    ///
+    /// See [`Progress::set`].
+    pub fn progress(&self) -> Option<f32> {
+        self.control.state.lock().progress
+    }
+
+    /// Ask the task to stop.
+    ///
+    /// Tasks from [`Self::from_future`] stop at their next `.await`,
+    /// others only when they check [`Progress::is_cancelled`].
+    pub fn cancel(&self) {
+        let waker = {
+            let mut state = self.control.state.lock();
+            state.cancelled = true;
+            state.waker.take()
+        };
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.control.state.lock().cancelled
+    }
+
+    /// Take the result out of the handle, if the task is done.
+    ///
+    /// # Errors
+    /// Gives back the handle if the task is not done yet.
+    pub fn try_take(self) -> Result<T, Self> {
+        let Self { control, result } = self;
+        match Arc::try_unwrap(result) {
+            Ok(result) => result.into_inner().ok_or_else(|| Self {
+                control,
+                result: Default::default(),
+            }),
+            Err(result) => Err(Self { control, result }),
+        }
+    }
+
+    /// Show the result with `add_contents` when it is ready,
+    /// and until then a progress bar or a spinner.
+    pub fn show_when_ready<R>(
+        &self,
+        ui: &mut Ui,
+        add_contents: impl FnOnce(&mut Ui, &T) -> R,
+    ) -> Option<R> {
+        if let Some(value) = self.ready() {
+            return Some(add_contents(ui, value));
+        }
+
+        if self.is_cancelled() {
+            ui.weak("Cancelled");
+        } else if let Some(progress) = self.progress() {
+            ui.add(egui::ProgressBar::new(progress).show_percentage());
+        } else {
+            ui.spinner();
+        }
+        None
+    }
+}
+
+#[test]
+fn test_task_handle() {
+    let ctx = Context::default();
+    let mut cx = std::task::Context::from_waker(Waker::noop());
+
+    let (task, handle) = TaskHandle::from_future(&ctx, |progress| async move {
+        progress.set(0.5);
+        42
+    });
+    assert!(!handle.is_ready());
+    assert!(std::pin::pin!(task).poll(&mut cx).is_ready());
+    assert_eq!(handle.progress(), Some(0.5));
+    assert_eq!(handle.ready(), Some(&42));
+    assert_eq!(handle.try_take().ok(), Some(42));
+
+    let (task, handle) = TaskHandle::<()>::from_future(&ctx, |_| std::future::pending::<()>());
+    let mut task = std::pin::pin!(task);
+    assert!(task.as_mut().poll(&mut cx).is_pending());
+    handle.cancel();
+    assert!(task.as_mut().poll(&mut cx).is_ready(), "Cancelled");
+    assert!(handle.is_cancelled() && !handle.is_ready());
+
+    let handle = TaskHandle::spawn_thread(&ctx, "test_task", |progress| {
+        while !progress.is_cancelled() {
+            std::thread::yield_now();
+        }
+        "stopped"
+    })
+    .unwrap();
+    handle.cancel();
+    while !handle.is_ready() {
+        std::thread::yield_now();
+    }
+    assert_eq!(handle.ready(), Some(&"stopped"));
+}
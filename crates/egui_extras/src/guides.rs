@@ -0,0 +1,462 @@
+//! Rulers, draggable guide lines and snapping, for design and diagram tools.
+//!
+//! Everything works in canvas coordinates, with a [`TSTransform`] from the canvas to the screen,
+//! e.g. [`egui::Scene::to_global`] or just a translation to where the canvas is shown.
+//!
+//! ```
+//! # egui::__run_test_ui(|ui| {
+//! use egui_extras::guides::{Guide, Rulers, snap_rect};
+//!
+//! # let mut guides = vec![Guide::vertical(100.0)];
+//! # let mut card = egui::Rect::from_min_size(egui::pos2(20.0, 20.0), egui::vec2(80.0, 40.0));
+//! let (region, response) = ui.allocate_exact_size(egui::vec2(400.0, 300.0), egui::Sense::hover());
+//! let to_screen = egui::emath::TSTransform::from_translation(region.min.to_vec2());
+//!
+//! // Drag the card around, snapping it to the guides:
+//! let card_response = ui.interact(to_screen * card, response.id.with("card"), egui::Sense::drag());
+//! if card_response.dragged() {
+//!     let moved = card.translate(card_response.drag_delta() / to_screen.scaling);
+//!     let snap = snap_rect(moved, &guides, &[], 6.0 / to_screen.scaling);
+//!     card = snap.rect;
+//!     snap.paint(&ui.painter_at(region), to_screen, ui.visuals().warn_fg_color);
+//! }
+//! ui.painter().rect_filled(to_screen * card, 4.0, ui.visuals().widgets.inactive.bg_fill);
+//!
+//! // Drag from the rulers to add guides, and back onto them to remove them:
+//! Rulers::new(region, to_screen).show(ui, &mut guides);
+//! # });
+//! ```
+
+use egui::{
+    Align2, Color32, CursorIcon, FontId, Id, Painter, Pos2, Rect, Response, Sense, Shape, Stroke,
+    Ui, emath::TSTransform, epaint::TextShape, pos2,
+};
+
+/// Which way a [`Guide`] goes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum GuideAxis {
+    /// A vertical line at an x coordinate.
+    Vertical,
+
+    /// A horizontal line at a y coordinate.
+    Horizontal,
+}
+
+/// A guide line across the canvas, in canvas coordinates.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Guide {
+    pub axis: GuideAxis,
+
+    /// The x coordinate of a vertical guide, or the y coordinate of a horizontal one.
+    pub position: f32,
+}
+
+impl Guide {
+    /// A vertical line at this x coordinate.
+    pub fn vertical(x: f32) -> Self {
+        Self {
+            axis: GuideAxis::Vertical,
+            position: x,
+        }
+    }
+
+    /// A horizontal line at this y coordinate.
+    pub fn horizontal(y: f32) -> Self {
+        Self {
+            axis: GuideAxis::Horizontal,
+            position: y,
+        }
+    }
+}
+
+/// Rulers along the top and left edges of a region of the screen,
+/// and the [`Guide`]s across it.
+///
+/// Drag from a ruler to add a guide, drag a guide to move it,
+/// and drag it back onto its ruler to remove it.
+///
+/// Show this after the canvas, so that it is on top of it.
+#[must_use = "You should call .show()"]
+pub struct Rulers {
+    region: Rect,
+    to_screen: TSTransform,
+    size: f32,
+    guide_color: Option<Color32>,
+    id_salt: Option<Id>,
+}
+
+impl Rulers {
+    /// Rulers for the canvas shown in `region`, with `to_screen` going from canvas to screen coordinates.
+    pub fn new(region: Rect, to_screen: TSTransform) -> Self {
+        Self {
+            region,
+            to_screen,
+            size: 16.0,
+            guide_color: None,
+            id_salt: None,
+        }
+    }
+
+    /// How thick the rulers are, in points.
+    ///
+    /// Default: 16.
+    #[inline]
+    pub fn size(mut self, size: f32) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// The color of the guide lines.
+    ///
+    /// Default: the selection color.
+    #[inline]
+    pub fn guide_color(mut self, color: Color32) -> Self {
+        self.guide_color = Some(color);
+        self
+    }
+
+    #[inline]
+    pub fn id_salt(mut self, id_salt: impl std::hash::Hash) -> Self {
+        self.id_salt = Some(Id::new(id_salt));
+        self
+    }
+
+    /// Show the rulers and guides.
+    ///
+    /// The response is [`Response::changed`] when a guide was added, moved or removed.
+    pub fn show(self, ui: &Ui, guides: &mut Vec<Guide>) -> Response {
+        let Self {
+            region,
+            to_screen,
+            size,
+            guide_color,
+            id_salt,
+        } = self;
+        let id = ui.make_persistent_id(id_salt.unwrap_or_else(|| Id::new("rulers")));
+        let guide_color = guide_color.unwrap_or(ui.visuals().selection.bg_fill);
+        let to_canvas = to_screen.inverse();
+
+        let top = Rect::from_x_y_ranges(region.x_range(), region.top()..=region.top() + size);
+        let left = Rect::from_x_y_ranges(region.left()..=region.left() + size, region.y_range());
+        let ruler_for = |axis| match axis {
+            GuideAxis::Horizontal => top,
+            GuideAxis::Vertical => left,
+        };
+        let screen_position = |guide: &Guide| match guide.axis {
+            GuideAxis::Vertical => (to_screen * pos2(guide.position, 0.0)).x,
+            GuideAxis::Horizontal => (to_screen * pos2(0.0, guide.position)).y,
+        };
+        let canvas_position = |axis, pos: Pos2| {
+            let pos = to_canvas * pos;
+            match axis {
+                GuideAxis::Vertical => pos.x,
+                GuideAxis::Horizontal => pos.y,
+            }
+        };
+
+        let mut response = ui.interact(region, id, Sense::hover());
+        let pointer = ui.input(|i| i.pointer.latest_pos());
+
+        // Drag existing guides:
+        let mut remove = None;
+        let mut hovered_guide = None;
+        for (i, guide) in guides.iter_mut().enumerate() {
+            let position = screen_position(guide);
+            let (rect, cursor) = match guide.axis {
+                GuideAxis::Vertical => (
+                    Rect::from_x_y_ranges(position - 3.0..=position + 3.0, region.y_range()),
+                    CursorIcon::ResizeColumn,
+                ),
+                GuideAxis::Horizontal => (
+                    Rect::from_x_y_ranges(region.x_range(), position - 3.0..=position + 3.0),
+                    CursorIcon::ResizeRow,
+                ),
+            };
+            if !region.intersects(rect) {
+                continue;
+            }
+            let guide_response = ui
+                .interact(rect.intersect(region), id.with(("guide", i)), Sense::drag())
+                .on_hover_cursor(cursor);
+            if guide_response.hovered() || guide_response.dragged() {
+                hovered_guide = Some(i);
+            }
+            if guide_response.dragged() {
+                if let Some(pointer) = pointer {
+                    guide.position = canvas_position(guide.axis, pointer);
+                    response.mark_changed();
+                }
+            }
+            if guide_response.drag_stopped()
+                && pointer.is_none_or(|p| ruler_for(guide.axis).contains(p) || !region.contains(p))
+            {
+                remove = Some(i);
+            }
+        }
+        if let Some(i) = remove {
+            guides.remove(i);
+            response.mark_changed();
+        }
+
+        // Drag new guides out of the rulers:
+        for (ruler, axis, cursor) in [
+            (top, GuideAxis::Horizontal, CursorIcon::ResizeRow),
+            (left, GuideAxis::Vertical, CursorIcon::ResizeColumn),
+        ] {
+            let ruler_id = id.with(axis);
+            let ruler_response = ui
+                .interact(ruler, ruler_id, Sense::drag())
+                .on_hover_cursor(cursor);
+            if ruler_response.drag_started() {
+                if let Some(pointer) = pointer {
+                    guides.push(Guide {
+                        axis,
+                        position: canvas_position(axis, pointer),
+                    });
+                    ui.data_mut(|d| d.insert_temp(ruler_id, guides.len() - 1));
+                    response.mark_changed();
+                }
+            }
+            let new_guide: Option<usize> = ui.data(|d| d.get_temp(ruler_id));
+            let Some(index) = new_guide.filter(|&index| index < guides.len()) else {
+                continue;
+            };
+            hovered_guide = Some(index);
+            if ruler_response.dragged() {
+                if let Some(pointer) = pointer {
+                    guides[index].position = canvas_position(axis, pointer);
+                    response.mark_changed();
+                }
+            } else {
+                ui.data_mut(|d| d.remove::<usize>(ruler_id));
+                if pointer.is_none_or(|p| ruler.contains(p) || !region.contains(p)) {
+                    guides.remove(index);
+                    response.mark_changed();
+                }
+            }
+        }
+
+        // Paint:
+        let painter = ui.painter_at(region);
+        for (i, guide) in guides.iter().enumerate() {
+            let width = if hovered_guide == Some(i) { 2.0 } else { 1.0 };
+            let stroke = Stroke::new(width, guide_color);
+            let position = screen_position(guide);
+            match guide.axis {
+                GuideAxis::Vertical => painter.vline(position, region.y_range(), stroke),
+                GuideAxis::Horizontal => painter.hline(region.x_range(), position, stroke),
+            };
+        }
+        paint_rulers(&painter, top, left, to_screen, size);
+
+        response
+    }
+}
+
+fn paint_rulers(painter: &Painter, top: Rect, left: Rect, to_screen: TSTransform, size: f32) {
+    let visuals = painter.ctx().style().visuals.clone();
+    let stroke = visuals.widgets.noninteractive.fg_stroke;
+    let font_id = FontId::monospace(0.6 * size);
+    let step = ruler_step(to_screen.scaling, 64.0);
+    if !step.is_finite() {
+        return;
+    }
+    let to_canvas = to_screen.inverse();
+
+    painter.rect_filled(top, 0.0, visuals.faint_bg_color);
+    painter.rect_filled(left, 0.0, visuals.faint_bg_color);
+    painter.hline(
+        top.x_range(),
+        top.bottom(),
+        visuals.widgets.noninteractive.bg_stroke,
+    );
+    painter.vline(
+        left.right(),
+        left.y_range(),
+        visuals.widgets.noninteractive.bg_stroke,
+    );
+
+    let minor = step / 5.0;
+    let visible = to_canvas * Rect::from_min_max(left.right_top(), top.right_bottom());
+    for i in (visible.left() / minor).floor() as i64..=(visible.right() / minor).ceil() as i64 {
+        let value = i as f32 * minor;
+        let x = (to_screen * pos2(value, 0.0)).x;
+        if x < left.right() {
+            continue;
+        }
+        let is_major = i.rem_euclid(5) == 0;
+        let length = if is_major { 0.5 } else { 0.25 } * size;
+        painter.vline(x, top.bottom() - length..=top.bottom(), stroke);
+        if is_major {
+            painter.text(
+                pos2(x + 2.0, top.top()),
+                Align2::LEFT_TOP,
+                format_value(value),
+                font_id.clone(),
+                stroke.color,
+            );
+        }
+    }
+
+    let visible = to_canvas * Rect::from_min_max(left.left_top(), left.right_bottom());
+    for i in (visible.top() / minor).floor() as i64..=(visible.bottom() / minor).ceil() as i64 {
+        let value = i as f32 * minor;
+        let y = (to_screen * pos2(0.0, value)).y;
+        if y < top.bottom() {
+            continue;
+        }
+        let is_major = i.rem_euclid(5) == 0;
+        let length = if is_major { 0.5 } else { 0.25 } * size;
+        painter.hline(left.right() - length..=left.right(), y, stroke);
+        if is_major {
+            // Read from bottom to top, like on a paper ruler:
+            let galley = painter.layout_no_wrap(format_value(value), font_id.clone(), stroke.color);
+            painter.add(
+                TextShape::new(pos2(left.left(), y - 2.0), galley, stroke.color)
+                    .with_angle(-std::f32::consts::FRAC_PI_2),
+            );
+        }
+    }
+
+    // The corner where the rulers meet:
+    painter.rect_filled(
+        Rect::from_min_max(left.left_top(), pos2(left.right(), top.bottom())),
+        0.0,
+        visuals.faint_bg_color,
+    );
+}
+
+fn format_value(value: f32) -> String {
+    egui::emath::format_with_decimals_in_range(value as f64, 0..=3)
+}
+
+/// The distance between labelled ruler ticks in canvas units:
+/// 1, 2 or 5 times a power of ten, at least `min_spacing_on_screen` points apart on screen.
+fn ruler_step(scaling: f32, min_spacing_on_screen: f32) -> f32 {
+    if !(0.0 < scaling && scaling.is_finite()) {
+        return f32::INFINITY;
+    }
+    let min_step = min_spacing_on_screen / scaling;
+    let power = 10_f32.powf(min_step.log10().floor());
+    [1.0, 2.0, 5.0, 10.0]
+        .into_iter()
+        .map(|factor| factor * power)
+        .find(|&step| min_step <= step)
+        .unwrap_or(10.0 * power)
+}
+
+/// The result of [`snap_rect`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Snap {
+    /// The rectangle, moved so it lines up with what it snapped to.
+    pub rect: Rect,
+
+    /// The x coordinate of the vertical line the rectangle snapped to, if any.
+    pub x: Option<f32>,
+
+    /// The y coordinate of the horizontal line the rectangle snapped to, if any.
+    pub y: Option<f32>,
+}
+
+impl Snap {
+    /// Show the lines that the rectangle snapped to, across the clip rect of the painter.
+    pub fn paint(&self, painter: &Painter, to_screen: TSTransform, color: Color32) {
+        let clip_rect = painter.clip_rect();
+        let stroke = Stroke::new(1.0, color);
+        let mut shapes = vec![];
+        if let Some(x) = self.x {
+            let x = (to_screen * pos2(x, 0.0)).x;
+            shapes.extend(Shape::dashed_line(
+                &[pos2(x, clip_rect.top()), pos2(x, clip_rect.bottom())],
+                stroke,
+                4.0,
+                4.0,
+            ));
+        }
+        if let Some(y) = self.y {
+            let y = (to_screen * pos2(0.0, y)).y;
+            shapes.extend(Shape::dashed_line(
+                &[pos2(clip_rect.left(), y), pos2(clip_rect.right(), y)],
+                stroke,
+                4.0,
+                4.0,
+            ));
+        }
+        painter.extend(shapes);
+    }
+}
+
+/// Move `rect` so that one of its edges or its center lines up with a guide,
+/// or with an edge or the center of one of the `others`, if any is within `threshold`.
+///
+/// Horizontal and vertical snapping are independent, and the closest candidate wins.
+/// Everything is in canvas coordinates.
+pub fn snap_rect(rect: Rect, guides: &[Guide], others: &[Rect], threshold: f32) -> Snap {
+    let snap_axis = |axis, anchors: [f32; 3], other_lines: &dyn Fn(&Rect) -> [f32; 3]| {
+        let candidates = guides
+            .iter()
+            .filter(|guide| guide.axis == axis)
+            .map(|guide| guide.position)
+            .chain(others.iter().flat_map(other_lines));
+        let mut best: Option<(f32, f32)> = None; // (offset, line)
+        for line in candidates {
+            for anchor in anchors {
+                let offset = line - anchor;
+                if offset.abs() <= threshold
+                    && best.is_none_or(|(best, _)| offset.abs() < best.abs())
+                {
+                    best = Some((offset, line));
+                }
+            }
+        }
+        best
+    };
+
+    let x = snap_axis(
+        GuideAxis::Vertical,
+        [rect.left(), rect.center().x, rect.right()],
+        &|other| [other.left(), other.center().x, other.right()],
+    );
+    let y = snap_axis(
+        GuideAxis::Horizontal,
+        [rect.top(), rect.center().y, rect.bottom()],
+        &|other| [other.top(), other.center().y, other.bottom()],
+    );
+
+    let offset = egui::vec2(x.map_or(0.0, |x| x.0), y.map_or(0.0, |y| y.0));
+    Snap {
+        rect: rect.translate(offset),
+        x: x.map(|x| x.1),
+        y: y.map(|y| y.1),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_snapping() {
+        let rect = Rect::from_min_size(pos2(12.0, 50.0), egui::vec2(20.0, 10.0));
+        let guides = [Guide::vertical(10.0), Guide::horizontal(100.0)];
+
+        // The left edge snaps to the vertical guide, the horizontal one is too far away:
+        let snap = snap_rect(rect, &guides, &[], 5.0);
+        assert_eq!(snap.rect.min, pos2(10.0, 50.0));
+        assert_eq!((snap.x, snap.y), (Some(10.0), None));
+
+        // The center snaps to the center of another rect:
+        let other = Rect::from_min_size(pos2(200.0, 42.0), egui::vec2(10.0, 30.0));
+        let snap = snap_rect(rect, &[], &[other], 5.0);
+        assert_eq!(snap.rect.center().y, 57.0);
+        assert_eq!(snap.y, Some(57.0));
+
+        assert_eq!(snap_rect(rect, &guides, &[], 1.0).rect, rect);
+
+        assert_eq!(ruler_step(1.0, 64.0), 100.0);
+        assert_eq!(ruler_step(2.0, 64.0), 50.0);
+        assert_eq!(ruler_step(10.0, 64.0), 10.0);
+    }
+}
@@ -37,6 +37,56 @@ pub fn load_image_bytes(image_bytes: &[u8]) -> Result<egui::ColorImage, egui::lo
     ))
 }
 
+/// Encode an image as a PNG file, e.g. for saving a screenshot.
+///
+/// Requires the "image" feature, and the "png" feature of the `image` crate.
+///
+/// # Errors
+/// If the PNG encoder is not enabled.
+#[cfg(feature = "image")]
+pub fn encode_png(image: &egui::ColorImage) -> Result<Vec<u8>, image::ImageError> {
+    encode_image(image, image::ImageFormat::Png)
+}
+
+/// Encode an image as a JPEG file.
+///
+/// JPEG has no alpha channel, so the image is drawn onto black first.
+///
+/// Requires the "image" feature, and the "jpeg" feature of the `image` crate.
+///
+/// # Errors
+/// If the JPEG encoder is not enabled.
+#[cfg(feature = "image")]
+pub fn encode_jpeg(image: &egui::ColorImage) -> Result<Vec<u8>, image::ImageError> {
+    encode_image(image, image::ImageFormat::Jpeg)
+}
+
+#[cfg(feature = "image")]
+fn encode_image(
+    image: &egui::ColorImage,
+    format: image::ImageFormat,
+) -> Result<Vec<u8>, image::ImageError> {
+    profiling::function_scope!();
+    let [width, height] = [image.width() as u32, image.height() as u32];
+    let image = if format == image::ImageFormat::Jpeg {
+        // Premultiplied colors are what you get when drawing onto black:
+        let rgb = image
+            .pixels
+            .iter()
+            .flat_map(|color| [color.r(), color.g(), color.b()])
+            .collect();
+        image::RgbImage::from_raw(width, height, rgb).map(image::DynamicImage::from)
+    } else {
+        image::RgbaImage::from_raw(width, height, image.to_rgba_unmultiplied())
+            .map(image::DynamicImage::from)
+    };
+    let image = image.expect("The size of a ColorImage should match its pixels");
+
+    let mut bytes = std::io::Cursor::new(Vec::new());
+    image.write_to(&mut bytes, format)?;
+    Ok(bytes.into_inner())
+}
+
 /// Load an SVG and rasterize it into an egui image.
 ///
 /// Requires the "svg" feature.
@@ -115,3 +165,15 @@ pub fn load_svg_bytes_with_size(
 
     Ok(image)
 }
+
+#[cfg(all(test, feature = "image"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn png_round_trip() {
+        let image = egui::ColorImage::example();
+        let png = encode_png(&image).unwrap();
+        assert_eq!(load_image_bytes(&png).unwrap(), image);
+    }
+}
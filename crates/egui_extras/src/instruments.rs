@@ -0,0 +1,532 @@
+//! Widgets for dashboards and hardware frontends: status [`Led`]s,
+//! [`SevenSegment`] displays, and level [`Meter`]s with peak hold.
+
+use egui::{
+    Color32, Pos2, Rect, Response, Sense, Shape, Stroke, Ui, Vec2, Widget, WidgetInfo, WidgetType,
+    pos2, vec2,
+};
+
+/// A round status light.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let connected = true;
+/// use egui_extras::instruments::Led;
+///
+/// ui.horizontal(|ui| {
+///     ui.add(Led::new(connected).blink(1.0));
+///     ui.label("Connected");
+/// });
+/// # });
+/// ```
+#[must_use = "You should put this widget in a ui with `ui.add(widget);`"]
+pub struct Led {
+    on: bool,
+    color: Color32,
+    blink_period: Option<f32>,
+    diameter: Option<f32>,
+}
+
+impl Led {
+    pub fn new(on: bool) -> Self {
+        Self {
+            on,
+            color: Color32::from_rgb(0, 200, 60),
+            blink_period: None,
+            diameter: None,
+        }
+    }
+
+    /// The color when on. When off, a dim version of it is shown.
+    ///
+    /// Default: green.
+    #[inline]
+    pub fn color(mut self, color: Color32) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Blink while on, with the given period in seconds.
+    #[inline]
+    pub fn blink(mut self, period: f32) -> Self {
+        self.blink_period = Some(period);
+        self
+    }
+
+    /// Default: the height of a line of text.
+    #[inline]
+    pub fn diameter(mut self, diameter: f32) -> Self {
+        self.diameter = Some(diameter);
+        self
+    }
+}
+
+impl Widget for Led {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Self {
+            on,
+            color,
+            blink_period,
+            diameter,
+        } = self;
+
+        let diameter = diameter.unwrap_or_else(|| ui.text_style_height(&egui::TextStyle::Body));
+        let (rect, response) = ui.allocate_exact_size(Vec2::splat(diameter), Sense::hover());
+        response.widget_info(|| WidgetInfo::selected(WidgetType::Other, ui.is_enabled(), on, ""));
+
+        let mut lit = on;
+        if let Some(period) = blink_period.filter(|&period| on && 0.0 < period) {
+            let half_period = 0.5 * period as f64;
+            let time = ui.input(|i| i.time);
+            lit = (time / half_period) as i64 % 2 == 0;
+            let next_toggle = half_period - time % half_period;
+            ui.ctx()
+                .request_repaint_after_secs(next_toggle as f32 + f32::EPSILON);
+        }
+
+        if ui.is_rect_visible(rect) {
+            let center = rect.center();
+            let radius = 0.5 * diameter;
+            let fill = if lit {
+                color
+            } else {
+                color.gamma_multiply(0.25)
+            };
+            let painter = ui.painter();
+            painter.circle(
+                center,
+                radius - 0.5,
+                fill,
+                ui.visuals().widgets.noninteractive.fg_stroke,
+            );
+            if lit {
+                // A small reflection, so it looks like it's glowing:
+                let highlight = Color32::from_white_alpha(100);
+                painter.circle_filled(center - Vec2::splat(0.3 * radius), 0.3 * radius, highlight);
+            }
+        }
+
+        response
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// Which segments to light for a character: bit 0 to 6 are segments `a` to `g`.
+///
+/// ```text
+///  aaa
+/// f   b
+///  ggg
+/// e   c
+///  ddd
+/// ```
+fn segments_of(c: char) -> u8 {
+    match c.to_ascii_uppercase() {
+        '0' | 'O' => 0b011_1111,
+        '1' => 0b000_0110,
+        '2' => 0b101_1011,
+        '3' => 0b100_1111,
+        '4' => 0b110_0110,
+        '5' | 'S' => 0b110_1101,
+        '6' => 0b111_1101,
+        '7' => 0b000_0111,
+        '8' => 0b111_1111,
+        '9' => 0b110_1111,
+        'A' => 0b111_0111,
+        'B' => 0b111_1100,
+        'C' => 0b011_1001,
+        'D' => 0b101_1110,
+        'E' => 0b111_1001,
+        'F' => 0b111_0001,
+        'H' => 0b111_0110,
+        'L' => 0b011_1000,
+        'P' => 0b111_0011,
+        'U' => 0b011_1110,
+        '-' => 0b100_0000,
+        '_' => 0b000_1000,
+        _ => 0,
+    }
+}
+
+/// A seven-segment display, like on a digital clock or multimeter.
+///
+/// Shows digits, `-`, `_`, and the letters that can be shown with seven segments
+/// (`A–F`, `H`, `L`, `O`, `P`, `S`, `U`). A `.`, `,` or `:` lights the decimal point of the digit before it.
+/// Other characters are shown as all segments off.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui_extras::instruments::SevenSegment;
+///
+/// ui.add(SevenSegment::new(&format!("{:.1}", 23.45)).num_digits(5));
+/// # });
+/// ```
+#[must_use = "You should put this widget in a ui with `ui.add(widget);`"]
+pub struct SevenSegment<'a> {
+    text: &'a str,
+    num_digits: usize,
+    digit_height: f32,
+    color: Color32,
+}
+
+impl<'a> SevenSegment<'a> {
+    pub fn new(text: &'a str) -> Self {
+        Self {
+            text,
+            num_digits: 0,
+            digit_height: 24.0,
+            color: Color32::from_rgb(255, 60, 40),
+        }
+    }
+
+    /// Always show at least this many digits, padding with unlit digits on the left.
+    ///
+    /// Default: 0, i.e. as many digits as in the text.
+    #[inline]
+    pub fn num_digits(mut self, num_digits: usize) -> Self {
+        self.num_digits = num_digits;
+        self
+    }
+
+    /// Default: 24.
+    #[inline]
+    pub fn digit_height(mut self, digit_height: f32) -> Self {
+        self.digit_height = digit_height;
+        self
+    }
+
+    /// The color of lit segments. Unlit segments are shown faintly.
+    ///
+    /// Default: red.
+    #[inline]
+    pub fn color(mut self, color: Color32) -> Self {
+        self.color = color;
+        self
+    }
+}
+
+impl Widget for SevenSegment<'_> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Self {
+            text,
+            num_digits,
+            digit_height,
+            color,
+        } = self;
+
+        // The segments of each digit, and whether its decimal point is lit:
+        let mut digits: Vec<(u8, bool)> = Vec::new();
+        for c in text.chars() {
+            match c {
+                '.' | ',' | ':' => match digits.last_mut() {
+                    Some((_, point)) if !*point => *point = true,
+                    _ => digits.push((0, true)),
+                },
+                c => digits.push((segments_of(c), false)),
+            }
+        }
+        let padding = num_digits.saturating_sub(digits.len());
+        digits.splice(0..0, std::iter::repeat_n((0, false), padding));
+
+        let digit_width = 0.6 * digit_height;
+        let spacing = 0.25 * digit_height;
+        let width = digits.len() as f32 * (digit_width + spacing);
+        let (rect, response) = ui.allocate_exact_size(vec2(width, digit_height), Sense::hover());
+        response.widget_info(|| WidgetInfo::labeled(WidgetType::Label, ui.is_enabled(), text));
+
+        if ui.is_rect_visible(rect) {
+            let unlit = color.gamma_multiply(0.1);
+            let mut shapes = Vec::new();
+            for (i, (segments, point)) in digits.into_iter().enumerate() {
+                let min = rect.min + vec2(i as f32 * (digit_width + spacing), 0.0);
+                let digit_rect = Rect::from_min_size(min, vec2(digit_width, digit_height));
+                for (segment, polygon) in segment_polygons(digit_rect).into_iter().enumerate() {
+                    let lit = segments & (1 << segment) != 0;
+                    let fill = if lit { color } else { unlit };
+                    shapes.push(Shape::convex_polygon(polygon, fill, Stroke::NONE));
+                }
+                let thickness = 0.12 * digit_height;
+                let point_center = pos2(
+                    digit_rect.right() + 0.5 * spacing,
+                    digit_rect.bottom() - 0.5 * thickness,
+                );
+                let fill = if point { color } else { unlit };
+                shapes.push(Shape::circle_filled(point_center, 0.5 * thickness, fill));
+            }
+            ui.painter().extend(shapes);
+        }
+
+        response
+    }
+}
+
+/// The seven segments `a` to `g` of a digit, as hexagons with pointy ends.
+fn segment_polygons(rect: Rect) -> [Vec<Pos2>; 7] {
+    let t = 0.12 * rect.height(); // thickness
+    let gap = 0.1 * t;
+
+    // The center lines of the segments:
+    let (left, right) = (rect.left() + 0.5 * t, rect.right() - 0.5 * t);
+    let (top, middle, bottom) = (
+        rect.top() + 0.5 * t,
+        rect.center().y,
+        rect.bottom() - 0.5 * t,
+    );
+
+    let horizontal = |y: f32| {
+        let (left, right) = (left + gap, right - gap);
+        vec![
+            pos2(left, y),
+            pos2(left + 0.5 * t, y - 0.5 * t),
+            pos2(right - 0.5 * t, y - 0.5 * t),
+            pos2(right, y),
+            pos2(right - 0.5 * t, y + 0.5 * t),
+            pos2(left + 0.5 * t, y + 0.5 * t),
+        ]
+    };
+    let vertical = |x: f32, top: f32, bottom: f32| {
+        let (top, bottom) = (top + gap, bottom - gap);
+        vec![
+            pos2(x, top),
+            pos2(x + 0.5 * t, top + 0.5 * t),
+            pos2(x + 0.5 * t, bottom - 0.5 * t),
+            pos2(x, bottom),
+            pos2(x - 0.5 * t, bottom - 0.5 * t),
+            pos2(x - 0.5 * t, top + 0.5 * t),
+        ]
+    };
+    [
+        horizontal(top),
+        vertical(right, top, middle),
+        vertical(right, middle, bottom),
+        horizontal(bottom),
+        vertical(left, middle, bottom),
+        vertical(left, top, middle),
+        horizontal(middle),
+    ]
+}
+
+// ----------------------------------------------------------------------------
+
+#[derive(Clone, Copy, Debug, Default)]
+struct PeakState {
+    peak: f32,
+
+    /// When the peak was reached.
+    time: f64,
+}
+
+/// A level meter (e.g. a VU meter), going from green to yellow to red,
+/// optionally holding the peak for a while.
+///
+/// The value goes from 0 to 1.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let level = 0.7;
+/// use egui_extras::instruments::Meter;
+///
+/// ui.add(Meter::new(level).peak_hold(1.5));
+/// # });
+/// ```
+#[must_use = "You should put this widget in a ui with `ui.add(widget);`"]
+pub struct Meter {
+    value: f32,
+    vertical: bool,
+    length: Option<f32>,
+    thickness: Option<f32>,
+    peak_hold: Option<f32>,
+    warning_level: f32,
+    danger_level: f32,
+}
+
+impl Meter {
+    pub fn new(value: f32) -> Self {
+        Self {
+            value,
+            vertical: false,
+            length: None,
+            thickness: None,
+            peak_hold: None,
+            warning_level: 0.7,
+            danger_level: 0.9,
+        }
+    }
+
+    /// Fill from the bottom up, instead of from the left.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn vertical(mut self, vertical: bool) -> Self {
+        self.vertical = vertical;
+        self
+    }
+
+    /// The size along the direction of the value.
+    ///
+    /// Default: [`egui::style::Spacing::slider_width`].
+    #[inline]
+    pub fn length(mut self, length: f32) -> Self {
+        self.length = Some(length);
+        self
+    }
+
+    /// Default: half the height of [`egui::style::Spacing::interact_size`].
+    #[inline]
+    pub fn thickness(mut self, thickness: f32) -> Self {
+        self.thickness = Some(thickness);
+        self
+    }
+
+    /// Show a line at the highest recent value, for this many seconds.
+    ///
+    /// The peak is remembered using the [`egui::Id`] of the widget, so the meter needs
+    /// to be in the same place from one pass to the next.
+    #[inline]
+    pub fn peak_hold(mut self, seconds: f32) -> Self {
+        self.peak_hold = Some(seconds);
+        self
+    }
+
+    /// Above these levels the meter is yellow and red, respectively.
+    ///
+    /// Default: 0.7 and 0.9.
+    #[inline]
+    pub fn levels(mut self, warning: f32, danger: f32) -> Self {
+        self.warning_level = warning;
+        self.danger_level = danger;
+        self
+    }
+}
+
+impl Widget for Meter {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Self {
+            value,
+            vertical,
+            length,
+            thickness,
+            peak_hold,
+            warning_level,
+            danger_level,
+        } = self;
+
+        let value = if value.is_nan() {
+            0.0
+        } else {
+            value.clamp(0.0, 1.0)
+        };
+        let length = length.unwrap_or(ui.spacing().slider_width);
+        let thickness = thickness.unwrap_or(0.5 * ui.spacing().interact_size.y);
+        let size = if vertical {
+            vec2(thickness, length)
+        } else {
+            vec2(length, thickness)
+        };
+        let (rect, response) = ui.allocate_exact_size(size, Sense::hover());
+        response.widget_info(|| {
+            let mut info = WidgetInfo::new(WidgetType::ProgressIndicator);
+            info.value = Some(value.into());
+            info
+        });
+
+        let peak = peak_hold.map(|hold| {
+            let now = ui.input(|i| i.time);
+            let mut state: PeakState = ui.data(|d| d.get_temp(response.id)).unwrap_or_default();
+            let expired = state.time + hold as f64 <= now;
+            if state.peak <= value || expired {
+                state = PeakState {
+                    peak: value,
+                    time: now,
+                };
+            }
+            ui.data_mut(|d| d.insert_temp(response.id, state));
+            if state.peak > value {
+                let remaining = state.time + hold as f64 - now;
+                ui.ctx().request_repaint_after_secs(remaining as f32);
+            }
+            state.peak
+        });
+
+        if ui.is_rect_visible(rect) {
+            let visuals = ui.visuals();
+            let painter = ui.painter();
+            painter.rect_filled(rect, 0.0, visuals.extreme_bg_color);
+
+            // The part of the meter between two values:
+            let part = |from: f32, to: f32| {
+                if vertical {
+                    Rect::from_x_y_ranges(
+                        rect.x_range(),
+                        egui::lerp(rect.bottom()..=rect.top(), to)
+                            ..=egui::lerp(rect.bottom()..=rect.top(), from),
+                    )
+                } else {
+                    Rect::from_x_y_ranges(
+                        egui::lerp(rect.x_range(), from)..=egui::lerp(rect.x_range(), to),
+                        rect.y_range(),
+                    )
+                }
+            };
+            let zones = [
+                (0.0, warning_level, Color32::from_rgb(0, 200, 60)),
+                (warning_level, danger_level, Color32::from_rgb(230, 200, 0)),
+                (danger_level, 1.0, Color32::from_rgb(230, 40, 30)),
+            ];
+            let color_at = |level: f32| {
+                zones
+                    .iter()
+                    .find(|(_, to, _)| level <= *to)
+                    .map_or(zones[2].2, |zone| zone.2)
+            };
+            for (from, to, color) in zones {
+                if from < value {
+                    painter.rect_filled(part(from, to.min(value)), 0.0, color);
+                }
+            }
+
+            if let Some(peak) = peak.filter(|&peak| 0.0 < peak) {
+                let line = part(peak, peak).expand(1.0).intersect(rect);
+                painter.rect_filled(line, 0.0, color_at(peak));
+            }
+
+            painter.rect_stroke(
+                rect,
+                0.0,
+                visuals.widgets.noninteractive.bg_stroke,
+                egui::StrokeKind::Inside,
+            );
+        }
+
+        response
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn meter_holds_peak() {
+        let ctx = egui::Context::default();
+        let run = |time: f64, value: f32| {
+            let input = egui::RawInput {
+                time: Some(time),
+                ..Default::default()
+            };
+            let mut id = egui::Id::NULL;
+            let _ = ctx.run(input, |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    id = ui.add(Meter::new(value).peak_hold(1.0)).id;
+                });
+            });
+            ctx.data(|d| d.get_temp::<PeakState>(id)).unwrap().peak
+        };
+
+        assert_eq!(run(0.0, 0.8), 0.8);
+        assert_eq!(run(0.5, 0.3), 0.8, "Still holding the peak");
+        assert_eq!(run(1.5, 0.3), 0.3, "The hold expired");
+        assert_eq!(run(1.6, 0.5), 0.5);
+    }
+}
@@ -0,0 +1,199 @@
+//! A virtual joystick, e.g. for teleoperation panels and on-screen game controls.
+
+use egui::{Response, Sense, Ui, Vec2, Widget, WidgetInfo, WidgetType};
+
+/// A round pad with a thumb that can be dragged in any direction.
+///
+/// The value is a [`Vec2`] with a length of at most 1, with `y` pointing down like in the rest of egui
+/// (so use `-value.y` for "forward").
+/// [`Response::changed`] is set whenever the value changes.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut direction = egui::Vec2::ZERO;
+/// use egui_extras::joystick::Joystick;
+///
+/// ui.add(Joystick::new(&mut direction).deadzone(0.15));
+/// let (turn, forward) = (direction.x, -direction.y);
+/// # });
+/// ```
+#[doc(alias = "gamepad")]
+#[doc(alias = "thumbstick")]
+#[must_use = "You should put this widget in a ui with `ui.add(widget);`"]
+pub struct Joystick<'a> {
+    value: &'a mut Vec2,
+    diameter: f32,
+    deadzone: f32,
+    spring_back: bool,
+}
+
+impl<'a> Joystick<'a> {
+    pub fn new(value: &'a mut Vec2) -> Self {
+        Self {
+            value,
+            diameter: 100.0,
+            deadzone: 0.1,
+            spring_back: true,
+        }
+    }
+
+    /// Default: 100.
+    #[inline]
+    pub fn diameter(mut self, diameter: f32) -> Self {
+        self.diameter = diameter;
+        self
+    }
+
+    /// Offsets smaller than this (as a fraction of the radius) count as zero.
+    ///
+    /// The rest of the range is scaled, so the value still goes smoothly from 0 to 1.
+    ///
+    /// Default: 0.1.
+    #[inline]
+    pub fn deadzone(mut self, deadzone: f32) -> Self {
+        self.deadzone = deadzone.clamp(0.0, 0.99);
+        self
+    }
+
+    /// Return to the center when released. If `false`, the thumb stays where it was let go.
+    ///
+    /// Default: `true`.
+    #[inline]
+    pub fn spring_back(mut self, spring_back: bool) -> Self {
+        self.spring_back = spring_back;
+        self
+    }
+}
+
+impl Widget for Joystick<'_> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Self {
+            value,
+            diameter,
+            deadzone,
+            spring_back,
+        } = self;
+
+        let (rect, mut response) =
+            ui.allocate_exact_size(Vec2::splat(diameter), Sense::click_and_drag());
+        let radius = 0.5 * diameter;
+        let thumb_radius = 0.3 * radius;
+        // How far the center of the thumb can move:
+        let reach = radius - thumb_radius;
+
+        let old_value = *value;
+        let is_dragged = response.is_pointer_button_down_on();
+        if let Some(pointer) = response.interact_pointer_pos().filter(|_| is_dragged) {
+            let offset = pointer - rect.center();
+            let length = (offset.length() / reach).min(1.0);
+            *value = if length <= deadzone {
+                Vec2::ZERO
+            } else {
+                offset.normalized() * (length - deadzone) / (1.0 - deadzone)
+            };
+        } else if spring_back {
+            *value = Vec2::ZERO;
+        }
+
+        // Where the thumb is, undoing the deadzone scaling:
+        let length = value.length().min(1.0);
+        let offset = if length == 0.0 {
+            Vec2::ZERO
+        } else {
+            value.normalized() * (deadzone + length * (1.0 - deadzone)) * reach
+        };
+
+        if *value != old_value {
+            response.mark_changed();
+        }
+        response.widget_info(|| {
+            WidgetInfo::labeled(
+                WidgetType::Other,
+                ui.is_enabled(),
+                format!("Joystick at {:.2}, {:.2}", value.x, value.y),
+            )
+        });
+
+        if ui.is_rect_visible(rect) {
+            // Animate the spring-back:
+            let animation_time = ui.style().animation_time;
+            let thumb_offset = Vec2::new(
+                ui.ctx().animate_value_with_time(
+                    response.id.with("x"),
+                    offset.x,
+                    if is_dragged { 0.0 } else { animation_time },
+                ),
+                ui.ctx().animate_value_with_time(
+                    response.id.with("y"),
+                    offset.y,
+                    if is_dragged { 0.0 } else { animation_time },
+                ),
+            );
+
+            let visuals = ui.style().interact(&response);
+            let painter = ui.painter();
+            let center = rect.center();
+            painter.circle(
+                center,
+                radius - visuals.bg_stroke.width,
+                ui.visuals().extreme_bg_color,
+                visuals.bg_stroke,
+            );
+            if 0.0 < deadzone {
+                let stroke = ui.visuals().widgets.noninteractive.bg_stroke;
+                painter.circle_stroke(center, deadzone * reach, stroke);
+            }
+            painter.circle(
+                center + thumb_offset,
+                thumb_radius,
+                visuals.bg_fill,
+                visuals.fg_stroke,
+            );
+        }
+
+        response
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn drag_and_spring_back() {
+        let ctx = egui::Context::default();
+        let mut value = Vec2::ZERO;
+        let run = |value: &mut Vec2, events: Vec<egui::Event>| {
+            let input = egui::RawInput {
+                events,
+                ..Default::default()
+            };
+            let mut rect = egui::Rect::NOTHING;
+            let _ = ctx.run(input, |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    rect = ui.add(Joystick::new(value)).rect;
+                });
+            });
+            rect
+        };
+        let button = |pos, pressed| egui::Event::PointerButton {
+            pos,
+            button: egui::PointerButton::Primary,
+            pressed,
+            modifiers: Default::default(),
+        };
+
+        let rect = run(&mut value, vec![]);
+        let center = rect.center();
+        run(
+            &mut value,
+            vec![egui::Event::PointerMoved(center), button(center, true)],
+        );
+        let far_right = center + egui::vec2(1000.0, 0.0);
+        run(&mut value, vec![egui::Event::PointerMoved(far_right)]);
+        assert!((value - egui::vec2(1.0, 0.0)).length() < 1e-4, "{value:?}");
+
+        run(&mut value, vec![button(far_right, false)]);
+        assert_eq!(value, Vec2::ZERO);
+    }
+}
@@ -0,0 +1,390 @@
+//! A board of cards in columns that can be dragged between them, like a kanban board.
+
+use std::collections::BTreeSet;
+
+use egui::{
+    Frame, Id, ScrollArea, Sense, Ui, epaint::TextShape, pos2, style::ScrollAnimation, vec2,
+};
+
+/// The data shown in a [`Board`].
+///
+/// Cards are identified by their column and their position in it.
+pub trait BoardModel {
+    fn num_columns(&self) -> usize;
+
+    /// Shown in the header of the column.
+    fn column_title(&self, column: usize) -> String;
+
+    fn num_cards(&self, column: usize) -> usize;
+
+    /// Show the contents of a card. The frame around it is added by the [`Board`].
+    fn card_ui(&mut self, ui: &mut Ui, column: usize, index: usize);
+
+    /// The user dropped a card somewhere else: move it in your data.
+    fn move_card(&mut self, card_move: CardMove);
+
+    /// Can the card be dropped there? E.g. to limit the number of cards in a column.
+    ///
+    /// Default: always `true`.
+    fn can_move(&self, card_move: CardMove) -> bool {
+        let _ = card_move;
+        true
+    }
+}
+
+/// A card dropped by the user in a [`Board`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CardMove {
+    pub from_column: usize,
+    pub from_index: usize,
+    pub to_column: usize,
+
+    /// Where the card should be in the new column, once moved
+    /// (i.e. counting as if it had already been removed from where it was).
+    pub to_index: usize,
+}
+
+impl CardMove {
+    /// Make the move in a `Vec` of columns of cards.
+    pub fn apply<T>(self, columns: &mut [Vec<T>]) {
+        let card = columns[self.from_column].remove(self.from_index);
+        let column = &mut columns[self.to_column];
+        column.insert(self.to_index.min(column.len()), card);
+    }
+}
+
+/// The drag-and-drop payload of a card, which only this board accepts.
+#[derive(Clone, Copy)]
+struct DraggedCard {
+    board: Id,
+    column: usize,
+    index: usize,
+}
+
+/// Cards in columns, which can be dragged within and between the columns.
+///
+/// * Dragging a card near the top or bottom of a column scrolls it.
+/// * Columns can be collapsed into a narrow strip, which cards can still be dropped on.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui_extras::kanban::{Board, BoardModel, CardMove};
+///
+/// struct Tasks(Vec<(String, Vec<String>)>);
+///
+/// impl BoardModel for Tasks {
+///     fn num_columns(&self) -> usize { self.0.len() }
+///     fn column_title(&self, column: usize) -> String { self.0[column].0.clone() }
+///     fn num_cards(&self, column: usize) -> usize { self.0[column].1.len() }
+///     fn card_ui(&mut self, ui: &mut egui::Ui, column: usize, index: usize) {
+///         ui.label(&self.0[column].1[index]);
+///     }
+///     fn move_card(&mut self, m: CardMove) {
+///         let card = self.0[m.from_column].1.remove(m.from_index);
+///         self.0[m.to_column].1.insert(m.to_index, card);
+///     }
+/// }
+///
+/// let mut tasks = Tasks(vec![
+///     ("To do".to_owned(), vec!["Write docs".to_owned()]),
+///     ("Done".to_owned(), vec![]),
+/// ]);
+/// Board::new("tasks").show(ui, &mut tasks);
+/// # });
+/// ```
+#[doc(alias = "kanban")]
+#[must_use = "You should call .show()"]
+pub struct Board {
+    id: Id,
+    column_width: f32,
+}
+
+#[derive(Clone, Default)]
+struct State {
+    collapsed: BTreeSet<usize>,
+}
+
+/// How close to the edge of a column (in points) dragging a card starts scrolling it.
+const SCROLL_MARGIN: f32 = 32.0;
+
+/// How fast a column scrolls when dragging a card at its very edge, in points per second.
+const SCROLL_SPEED: f32 = 600.0;
+
+impl Board {
+    /// The `id_salt` must be unique, and is used to remember scroll positions and collapsed columns.
+    pub fn new(id_salt: impl std::hash::Hash) -> Self {
+        Self {
+            id: Id::new(id_salt),
+            column_width: 250.0,
+        }
+    }
+
+    /// The width of an expanded column.
+    ///
+    /// Default: 250.
+    #[inline]
+    pub fn column_width(mut self, column_width: f32) -> Self {
+        self.column_width = column_width;
+        self
+    }
+
+    /// Show the board, filling the available space.
+    ///
+    /// Returns the move made by the user this pass, after passing it to [`BoardModel::move_card`].
+    pub fn show(self, ui: &mut Ui, model: &mut dyn BoardModel) -> Option<CardMove> {
+        let Self { id, column_width } = self;
+        let mut state: State = ui.data(|d| d.get_temp(id)).unwrap_or_default();
+        let dragged = egui::DragAndDrop::payload::<DraggedCard>(ui.ctx())
+            .filter(|card| card.board == id)
+            .map(|card| *card);
+        let mut dropped = None;
+
+        ScrollArea::horizontal()
+            .id_salt(id.with("board"))
+            .auto_shrink(false)
+            .show(ui, |ui| {
+                ui.horizontal_top(|ui| {
+                    for column in 0..model.num_columns() {
+                        let collapsed = state.collapsed.contains(&column);
+                        let mut frame = Frame::group(ui.style()).begin(ui);
+                        let (toggle, insert_at) = frame
+                            .content_ui
+                            .vertical(|ui| {
+                                ui.set_min_height(ui.available_height());
+                                if collapsed {
+                                    ui.set_width(ui.spacing().interact_size.y);
+                                    (column_collapsed_ui(ui, model, column), None)
+                                } else {
+                                    ui.set_width(column_width);
+                                    let toggle = ui
+                                        .horizontal(|ui| {
+                                            let clicked = ui
+                                                .small_button("⏴")
+                                                .on_hover_text("Collapse")
+                                                .clicked();
+                                            ui.strong(model.column_title(column));
+                                            ui.weak(model.num_cards(column).to_string());
+                                            clicked
+                                        })
+                                        .inner;
+                                    (toggle, cards_ui(ui, id, model, column, dragged))
+                                }
+                            })
+                            .inner;
+                        let response = frame.allocate_space(ui);
+
+                        if let Some(card) = dragged.filter(|_| response.contains_pointer()) {
+                            let to_index = insert_at.unwrap_or(model.num_cards(column));
+                            let to_index = if card.column == column && card.index < to_index {
+                                to_index - 1
+                            } else {
+                                to_index
+                            };
+                            let card_move = CardMove {
+                                from_column: card.column,
+                                from_index: card.index,
+                                to_column: column,
+                                to_index,
+                            };
+                            let is_noop = card.column == column && card.index == to_index;
+                            if model.can_move(card_move) {
+                                frame.frame.stroke = ui.visuals().widgets.active.bg_stroke;
+                                if !is_noop
+                                    && response.dnd_release_payload::<DraggedCard>().is_some()
+                                {
+                                    dropped = Some(card_move);
+                                }
+                            }
+                        }
+                        frame.paint(ui);
+
+                        if toggle && !state.collapsed.remove(&column) {
+                            state.collapsed.insert(column);
+                        }
+                    }
+                });
+            });
+
+        if let Some(card_move) = dropped {
+            model.move_card(card_move);
+        }
+        ui.data_mut(|d| d.insert_temp(id, state));
+        dropped
+    }
+}
+
+/// The narrow strip of a collapsed column. Returns `true` if the expand button was clicked.
+fn column_collapsed_ui(ui: &mut Ui, model: &dyn BoardModel, column: usize) -> bool {
+    let clicked = ui.small_button("⏵").on_hover_text("Expand").clicked();
+    ui.weak(model.num_cards(column).to_string());
+
+    // The title, going down:
+    let galley = ui.painter().layout_no_wrap(
+        model.column_title(column),
+        egui::TextStyle::Body.resolve(ui.style()),
+        ui.visuals().strong_text_color(),
+    );
+    let (rect, _) = ui.allocate_exact_size(vec2(galley.size().y, galley.size().x), Sense::hover());
+    let pos = pos2(rect.right(), rect.top());
+    ui.painter().add(
+        TextShape::new(pos, galley, ui.visuals().text_color())
+            .with_angle(std::f32::consts::FRAC_PI_2),
+    );
+    clicked
+}
+
+/// The cards of an expanded column.
+///
+/// Returns where a card dragged over the column would be inserted.
+fn cards_ui(
+    ui: &mut Ui,
+    board: Id,
+    model: &mut dyn BoardModel,
+    column: usize,
+    dragged: Option<DraggedCard>,
+) -> Option<usize> {
+    ScrollArea::vertical()
+        .id_salt(board.with(("column", column)))
+        .auto_shrink(false)
+        .show(ui, |ui| {
+            ui.set_width(ui.available_width());
+            let spacing = ui.spacing().item_spacing.y;
+            let pointer = ui.ctx().pointer_hover_pos();
+            let view = ui.clip_rect();
+            let hovered = pointer.filter(|pos| dragged.is_some() && view.contains(*pos));
+
+            let mut insert_at = 0;
+            let mut line_y = ui.cursor().top();
+            for index in 0..model.num_cards(column) {
+                let card_id = board.with(("card", column, index));
+                let payload = DraggedCard {
+                    board,
+                    column,
+                    index,
+                };
+                let rect = ui
+                    .dnd_drag_source(card_id, payload, |ui| {
+                        Frame::group(ui.style())
+                            .fill(ui.visuals().window_fill)
+                            .show(ui, |ui| {
+                                ui.set_width(ui.available_width());
+                                model.card_ui(ui, column, index);
+                            });
+                    })
+                    .response
+                    .rect;
+                if hovered.is_some_and(|pos| rect.center().y < pos.y) {
+                    insert_at = index + 1;
+                    line_y = rect.bottom() + 0.5 * spacing;
+                }
+            }
+
+            let pos = hovered?;
+            if insert_at == 0 {
+                line_y = ui.min_rect().top() - 0.5 * spacing;
+            }
+            let stroke = ui.visuals().selection.stroke;
+            ui.painter().hline(ui.min_rect().x_range(), line_y, stroke);
+
+            // Scroll when dragging near the top or bottom:
+            let dt = ui.input(|i| i.stable_dt).min(0.1);
+            let speed = |distance: f32| SCROLL_SPEED * (1.0 - distance / SCROLL_MARGIN).max(0.0);
+            let delta = speed(pos.y - view.top()) - speed(view.bottom() - pos.y);
+            if delta != 0.0 {
+                ui.scroll_with_delta_animation(vec2(0.0, delta * dt), ScrollAnimation::none());
+                ui.ctx().request_repaint();
+            }
+            Some(insert_at)
+        })
+        .inner
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use egui::Rect;
+
+    struct Model {
+        columns: Vec<Vec<&'static str>>,
+        rects: Vec<Rect>,
+    }
+
+    impl BoardModel for Model {
+        fn num_columns(&self) -> usize {
+            self.columns.len()
+        }
+
+        fn column_title(&self, column: usize) -> String {
+            format!("Column {column}")
+        }
+
+        fn num_cards(&self, column: usize) -> usize {
+            self.columns[column].len()
+        }
+
+        fn card_ui(&mut self, ui: &mut Ui, _column: usize, _index: usize) {
+            // No fonts in the tests, so give the cards a size:
+            let (rect, _) = ui.allocate_exact_size(vec2(50.0, 20.0), Sense::hover());
+            self.rects.push(rect);
+        }
+
+        fn move_card(&mut self, card_move: CardMove) {
+            card_move.apply(&mut self.columns);
+        }
+    }
+
+    #[test]
+    fn drag_card_to_other_column() {
+        let ctx = egui::Context::default();
+        let mut model = Model {
+            columns: vec![vec!["a", "b"], vec!["c"]],
+            rects: Vec::new(),
+        };
+        let run = |model: &mut Model, events: Vec<egui::Event>| {
+            let input = egui::RawInput {
+                events,
+                screen_rect: Some(Rect::from_min_size(egui::Pos2::ZERO, vec2(800.0, 600.0))),
+                ..Default::default()
+            };
+            model.rects.clear();
+            let mut card_move = None;
+            let _ = ctx.run(input, |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    card_move = Board::new("board").show(ui, model);
+                });
+            });
+            card_move
+        };
+        let button = |pos, pressed| egui::Event::PointerButton {
+            pos,
+            button: egui::PointerButton::Primary,
+            pressed,
+            modifiers: Default::default(),
+        };
+
+        run(&mut model, vec![]);
+        run(&mut model, vec![]);
+        let [a, _, c] = model.rects[..] else {
+            panic!("Expected three cards");
+        };
+        // Drop "a" below "c":
+        let (start, end) = (a.center(), c.center() + vec2(0.0, c.height()));
+        run(
+            &mut model,
+            vec![egui::Event::PointerMoved(start), button(start, true)],
+        );
+        run(&mut model, vec![egui::Event::PointerMoved(end)]);
+        run(&mut model, vec![egui::Event::PointerMoved(end)]);
+        let card_move = run(&mut model, vec![button(end, false)]);
+        assert_eq!(
+            card_move,
+            Some(CardMove {
+                from_column: 0,
+                from_index: 0,
+                to_column: 1,
+                to_index: 1,
+            })
+        );
+        assert_eq!(model.columns, vec![vec!["b"], vec!["c", "a"]]);
+    }
+}
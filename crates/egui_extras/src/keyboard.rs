@@ -0,0 +1,712 @@
+//! An on-screen keyboard, for touch screens where there is no OS keyboard,
+//! e.g. kiosks and embedded devices.
+//!
+//! The keyboard sends [`egui::Event::Text`] and [`egui::Event::Key`] to whatever widget
+//! had keyboard focus (usually a [`egui::TextEdit`]), just like a physical keyboard would.
+//! Pressing a key does not steal the focus from that widget.
+//!
+//! ```
+//! # egui::__run_test_ui(|ui| {
+//! # let mut text = String::new();
+//! use egui_extras::keyboard::OnScreenKeyboard;
+//!
+//! ui.text_edit_singleline(&mut text);
+//! OnScreenKeyboard::new("keyboard").show(ui);
+//! # });
+//! ```
+//!
+//! Hold down a key to see its alternates (e.g. `é` and `è` on the `e` key).
+//! Use [`OnScreenKeyboard::layouts`] to provide your own [`KeyboardLayout`]s.
+
+use std::sync::Arc;
+
+use egui::{
+    Align2, Event, Id, Key, Modifiers, Order, Pos2, Rect, Response, Sense, TextStyle, Ui, Vec2,
+    mutex::Mutex, vec2,
+};
+
+/// What happens when a [`VirtualKey`] is pressed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum KeyAction {
+    /// Type some text. Letters are upper-cased while shift is active.
+    Text(String),
+
+    /// Press and release a key, e.g. [`Key::Backspace`] or [`Key::Enter`].
+    ///
+    /// Holding it down repeats it.
+    Key(Key),
+
+    /// Toggle shift. Pressing it twice in quick succession locks it (caps lock).
+    Shift,
+
+    /// Switch to the [`KeyboardLayout`] with the given name.
+    SwitchLayout(String),
+}
+
+/// A key on a [`KeyboardLayout`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct VirtualKey {
+    /// Shown on the key.
+    pub label: String,
+
+    /// What the key does.
+    pub action: KeyAction,
+
+    /// Shown when the key is held down. Releasing the pointer over one types it.
+    pub alternates: Vec<String>,
+
+    /// Width relative to a normal key.
+    pub width: f32,
+}
+
+impl VirtualKey {
+    /// A key that types `text`, and shows `text` as its label.
+    pub fn text(text: impl Into<String>) -> Self {
+        let text = text.into();
+        Self {
+            label: text.clone(),
+            action: KeyAction::Text(text),
+            alternates: Vec::new(),
+            width: 1.0,
+        }
+    }
+
+    /// A key that presses a [`Key`].
+    pub fn key(label: impl Into<String>, key: Key) -> Self {
+        Self {
+            label: label.into(),
+            action: KeyAction::Key(key),
+            alternates: Vec::new(),
+            width: 1.0,
+        }
+    }
+
+    /// The shift key.
+    pub fn shift() -> Self {
+        Self {
+            label: "⬆".to_owned(),
+            action: KeyAction::Shift,
+            alternates: Vec::new(),
+            width: 1.5,
+        }
+    }
+
+    /// A key that switches to the layout with the given name.
+    pub fn switch_layout(label: impl Into<String>, layout: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            action: KeyAction::SwitchLayout(layout.into()),
+            alternates: Vec::new(),
+            width: 1.5,
+        }
+    }
+
+    /// Characters to show when the key is held down.
+    #[inline]
+    pub fn alternates(mut self, alternates: &str) -> Self {
+        self.alternates = alternates.chars().map(String::from).collect();
+        self
+    }
+
+    /// Width relative to a normal key.
+    #[inline]
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = width;
+        self
+    }
+
+    fn label(&self, shift: bool) -> String {
+        match &self.action {
+            KeyAction::Text(_) if shift => self.label.to_uppercase(),
+            _ => self.label.clone(),
+        }
+    }
+}
+
+/// A named set of rows of [`VirtualKey`]s.
+#[derive(Clone, Debug, PartialEq)]
+pub struct KeyboardLayout {
+    /// Used by [`KeyAction::SwitchLayout`].
+    pub name: String,
+
+    /// From top to bottom.
+    pub rows: Vec<Vec<VirtualKey>>,
+}
+
+impl KeyboardLayout {
+    /// The name of [`Self::qwerty`].
+    pub const QWERTY: &'static str = "qwerty";
+
+    /// The name of [`Self::symbols`].
+    pub const SYMBOLS: &'static str = "symbols";
+
+    /// The name of [`Self::numeric`].
+    pub const NUMERIC: &'static str = "numeric";
+
+    /// A QWERTY layout, with a key to go to [`Self::symbols`].
+    pub fn qwerty() -> Self {
+        fn letters(row: &str) -> Vec<VirtualKey> {
+            row.chars()
+                .map(|c| {
+                    let key = VirtualKey::text(c);
+                    match c {
+                        'a' => key.alternates("àáâäæãå"),
+                        'c' => key.alternates("çć"),
+                        'e' => key.alternates("èéêë"),
+                        'i' => key.alternates("ìíîï"),
+                        'n' => key.alternates("ñ"),
+                        'o' => key.alternates("òóôöõø"),
+                        's' => key.alternates("ßś"),
+                        'u' => key.alternates("ùúûü"),
+                        'y' => key.alternates("ýÿ"),
+                        _ => key,
+                    }
+                })
+                .collect()
+        }
+
+        let mut bottom = letters("zxcvbnm");
+        bottom.insert(0, VirtualKey::shift());
+        bottom.push(VirtualKey::key("⬅", Key::Backspace).width(1.5));
+
+        Self {
+            name: Self::QWERTY.to_owned(),
+            rows: vec![
+                letters("qwertyuiop"),
+                letters("asdfghjkl"),
+                bottom,
+                vec![
+                    VirtualKey::switch_layout("?123", Self::SYMBOLS),
+                    VirtualKey::text(",").alternates(";:"),
+                    VirtualKey::text(" ").width(5.0),
+                    VirtualKey::text(".").alternates("!?…"),
+                    VirtualKey::key("⮨", Key::Enter).width(1.5),
+                ],
+            ],
+        }
+    }
+
+    /// Digits and punctuation, with a key to go back to [`Self::qwerty`].
+    pub fn symbols() -> Self {
+        fn keys(row: &str) -> Vec<VirtualKey> {
+            row.chars().map(VirtualKey::text).collect()
+        }
+
+        let mut third = keys("*\"':;!?");
+        third.insert(0, VirtualKey::text("=").width(1.5));
+        third.push(VirtualKey::key("⬅", Key::Backspace).width(1.5));
+
+        Self {
+            name: Self::SYMBOLS.to_owned(),
+            rows: vec![
+                keys("1234567890"),
+                keys("@#$%&-+()/"),
+                third,
+                vec![
+                    VirtualKey::switch_layout("ABC", Self::QWERTY),
+                    VirtualKey::text(","),
+                    VirtualKey::text(" ").width(5.0),
+                    VirtualKey::text("."),
+                    VirtualKey::key("⮨", Key::Enter).width(1.5),
+                ],
+            ],
+        }
+    }
+
+    /// A numeric keypad.
+    pub fn numeric() -> Self {
+        fn keys(row: &str) -> Vec<VirtualKey> {
+            row.chars().map(VirtualKey::text).collect()
+        }
+
+        Self {
+            name: Self::NUMERIC.to_owned(),
+            rows: vec![
+                keys("789"),
+                keys("456"),
+                keys("123"),
+                vec![
+                    VirtualKey::text("-").alternates("+"),
+                    VirtualKey::text("0"),
+                    VirtualKey::text(".").alternates(","),
+                ],
+                vec![
+                    VirtualKey::key("⬅", Key::Backspace),
+                    VirtualKey::key("⮨", Key::Enter).width(2.0),
+                ],
+            ],
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+enum Shift {
+    #[default]
+    Off,
+    Once,
+    Locked,
+}
+
+#[derive(Clone, Debug, Default)]
+struct State {
+    layout: Option<String>,
+    shift: Shift,
+    last_shift_press: Option<f64>,
+
+    /// The widget that gets the keystrokes.
+    target: Option<Id>,
+
+    /// `(row, column)` of the key being held down, and since when.
+    held: Option<((usize, usize), f64)>,
+
+    /// Alternates were shown for the [`Self::held`] key, so releasing it should not type it.
+    showing_alternates: bool,
+
+    next_repeat: f64,
+}
+
+impl State {
+    fn load(ctx: &egui::Context, id: Id) -> Self {
+        ctx.data_mut(|d| d.get_temp(id)).unwrap_or_default()
+    }
+
+    fn store(self, ctx: &egui::Context, id: Id) {
+        ctx.data_mut(|d| d.insert_temp(id, self));
+    }
+}
+
+/// Events waiting to be delivered at the start of the next pass.
+#[derive(Clone, Default)]
+struct PendingEvents(Arc<Mutex<Vec<Event>>>);
+
+fn pending_events(ctx: &egui::Context) -> PendingEvents {
+    let id = Id::new("egui_extras::keyboard::pending_events");
+    if let Some(pending) = ctx.data(|d| d.get_temp::<PendingEvents>(id)) {
+        return pending;
+    }
+
+    let pending = PendingEvents::default();
+    ctx.data_mut(|d| d.insert_temp(id, pending.clone()));
+    let queue = pending.0.clone();
+    ctx.on_begin_pass(
+        "egui_extras::keyboard",
+        Arc::new(move |ctx| {
+            let events = std::mem::take(&mut *queue.lock());
+            if !events.is_empty() {
+                ctx.input_mut(|i| i.events.extend(events));
+            }
+        }),
+    );
+    pending
+}
+
+/// An on-screen keyboard.
+///
+/// See the [module-level docs](self) for an example.
+#[must_use = "You should call .show()"]
+pub struct OnScreenKeyboard {
+    id: Id,
+    layouts: Vec<KeyboardLayout>,
+    key_height: Option<f32>,
+    long_press_time: f64,
+    repeat_interval: f64,
+}
+
+impl OnScreenKeyboard {
+    /// The `id_salt` must be unique, and is used to remember the active layout and shift state.
+    ///
+    /// By default you get [`KeyboardLayout::qwerty`] and [`KeyboardLayout::symbols`].
+    pub fn new(id_salt: impl std::hash::Hash) -> Self {
+        Self {
+            id: Id::new(id_salt),
+            layouts: vec![KeyboardLayout::qwerty(), KeyboardLayout::symbols()],
+            key_height: None,
+            long_press_time: 0.5,
+            repeat_interval: 0.05,
+        }
+    }
+
+    /// Use these layouts instead of the default ones.
+    ///
+    /// The first one is shown initially.
+    #[inline]
+    pub fn layouts(mut self, layouts: Vec<KeyboardLayout>) -> Self {
+        self.layouts = layouts;
+        self
+    }
+
+    /// A numeric keypad only, e.g. for entering a PIN.
+    #[inline]
+    pub fn numeric(self) -> Self {
+        self.layouts(vec![KeyboardLayout::numeric()])
+    }
+
+    /// The height of each key.
+    ///
+    /// Default: three times [`egui::style::Spacing::interact_size`].
+    #[inline]
+    pub fn key_height(mut self, key_height: f32) -> Self {
+        self.key_height = Some(key_height);
+        self
+    }
+
+    /// How long (in seconds) to hold down a key to show its alternates, or to start repeating it.
+    ///
+    /// Default: 0.5 seconds.
+    #[inline]
+    pub fn long_press_time(mut self, seconds: f64) -> Self {
+        self.long_press_time = seconds;
+        self
+    }
+
+    /// Switch to the layout with the given name.
+    pub fn set_layout(ctx: &egui::Context, id_salt: impl std::hash::Hash, name: &str) {
+        let id = Id::new(id_salt);
+        let mut state = State::load(ctx, id);
+        state.layout = Some(name.to_owned());
+        state.store(ctx, id);
+    }
+
+    /// Show the keyboard, using the full available width.
+    pub fn show(self, ui: &mut Ui) -> Response {
+        let Self {
+            id,
+            layouts,
+            key_height,
+            long_press_time,
+            repeat_interval,
+        } = self;
+
+        let ctx = ui.ctx().clone();
+        let mut state = State::load(&ctx, id);
+
+        if let Some(focused) = ctx.memory(|mem| mem.focused()) {
+            state.target = Some(focused);
+        }
+
+        let Some(layout) = layouts
+            .iter()
+            .find(|layout| Some(&layout.name) == state.layout.as_ref())
+            .or_else(|| layouts.first())
+        else {
+            return ui.allocate_response(Vec2::ZERO, Sense::hover());
+        };
+
+        let spacing = ui.spacing().item_spacing.x.min(ui.spacing().item_spacing.y);
+        let key_height = key_height.unwrap_or(3.0 * ui.spacing().interact_size.y);
+        let widest_row = layout
+            .rows
+            .iter()
+            .map(|row| row.iter().map(|key| key.width).sum::<f32>())
+            .fold(1.0, f32::max);
+        let unit = (ui.available_width() + spacing) / widest_row;
+        let num_rows = layout.rows.len() as f32;
+        let size = vec2(
+            ui.available_width(),
+            num_rows * (key_height + spacing) - spacing,
+        );
+        let (rect, response) = ui.allocate_exact_size(size, Sense::hover());
+
+        let now = ui.input(|i| i.time);
+        let shift = state.shift != Shift::Off;
+        let pointer_pos = ui.input(|i| i.pointer.latest_pos());
+        let pointer_released = ui.input(|i| i.pointer.any_released());
+        let mut pressed: Option<(&VirtualKey, Option<String>)> = None;
+        let mut held_now = None;
+
+        for (row_index, row) in layout.rows.iter().enumerate() {
+            let row_width = row.iter().map(|key| key.width).sum::<f32>() * unit - spacing;
+            let mut x = rect.center().x - row_width / 2.0;
+            let y = rect.top() + row_index as f32 * (key_height + spacing);
+
+            for (column, key) in row.iter().enumerate() {
+                let key_rect = Rect::from_min_size(
+                    Pos2::new(x, y),
+                    vec2(key.width * unit - spacing, key_height),
+                );
+                x += key.width * unit;
+
+                let key_id = id.with((row_index, column));
+                // Not focusable, so that pressing a key doesn't steal focus from the target.
+                let key_response = ui.interact(key_rect, key_id, Sense::CLICK);
+
+                let is_held = key_response.is_pointer_button_down_on();
+                if is_held {
+                    held_now = Some((row_index, column));
+                    if state.held.map(|(pos, _)| pos) != Some((row_index, column)) {
+                        state.held = Some(((row_index, column), now));
+                        state.showing_alternates = false;
+                        state.next_repeat = now + long_press_time;
+                    }
+                }
+                let held_for = state
+                    .held
+                    .filter(|(pos, _)| *pos == (row_index, column))
+                    .map_or(0.0, |(_, since)| now - since);
+
+                if is_held && long_press_time <= held_for && !key.alternates.is_empty() {
+                    state.showing_alternates = true;
+                }
+
+                if state.showing_alternates
+                    && state.held.map(|(pos, _)| pos) == Some((row_index, column))
+                {
+                    let chosen = show_alternates(ui, key_id, key_rect, key, shift, pointer_pos);
+                    if pointer_released {
+                        if let Some(chosen) = chosen {
+                            pressed = Some((key, Some(chosen)));
+                        }
+                    }
+                } else if is_held && matches!(key.action, KeyAction::Key(_)) {
+                    if state.next_repeat <= now {
+                        pressed = Some((key, None));
+                        state.next_repeat = now + repeat_interval;
+                    }
+                    ui.ctx().request_repaint();
+                } else if key_response.clicked()
+                    && !(matches!(key.action, KeyAction::Key(_)) && long_press_time <= held_for)
+                {
+                    pressed = Some((key, None));
+                } else if is_held && !key.alternates.is_empty() {
+                    ui.ctx()
+                        .request_repaint_after_secs((long_press_time - held_for) as f32);
+                }
+
+                paint_key(ui, &key_response, key_rect, key, state.shift);
+            }
+        }
+
+        if held_now.is_none() {
+            state.held = None;
+            state.showing_alternates = false;
+        }
+
+        if held_now.is_some() || pressed.is_some() {
+            // Pressing a key made egui take the focus away from the target. Give it back:
+            if let Some(target) = state.target {
+                ctx.memory_mut(|mem| mem.request_focus(target));
+            }
+        }
+
+        if let Some((key, alternate)) = pressed {
+            press(&ctx, &mut state, key, alternate.as_deref(), now);
+        }
+
+        state.store(&ctx, id);
+        response
+    }
+}
+
+/// `alternate` is the chosen one of the [`VirtualKey::alternates`], if any.
+fn press(
+    ctx: &egui::Context,
+    state: &mut State,
+    key: &VirtualKey,
+    alternate: Option<&str>,
+    now: f64,
+) {
+    let modifiers = if state.shift == Shift::Off {
+        Modifiers::NONE
+    } else {
+        Modifiers::SHIFT
+    };
+
+    let mut events = Vec::new();
+    match &key.action {
+        KeyAction::Text(text) => {
+            let text = alternate.unwrap_or(text);
+            let text = if state.shift == Shift::Off {
+                text.to_owned()
+            } else {
+                text.to_uppercase()
+            };
+            events.push(Event::Text(text));
+            if state.shift == Shift::Once {
+                state.shift = Shift::Off;
+            }
+        }
+        KeyAction::Key(key) => {
+            for pressed in [true, false] {
+                events.push(Event::Key {
+                    key: *key,
+                    physical_key: None,
+                    pressed,
+                    repeat: false,
+                    modifiers,
+                });
+            }
+        }
+        KeyAction::Shift => {
+            let double_tap = state.last_shift_press.is_some_and(|last| now - last < 0.3);
+            state.shift = match state.shift {
+                Shift::Off => Shift::Once,
+                Shift::Once if double_tap => Shift::Locked,
+                Shift::Once | Shift::Locked => Shift::Off,
+            };
+            state.last_shift_press = Some(now);
+        }
+        KeyAction::SwitchLayout(name) => {
+            state.layout = Some(name.clone());
+            state.shift = Shift::Off;
+        }
+    }
+
+    if !events.is_empty() {
+        pending_events(ctx).0.lock().extend(events);
+        ctx.request_repaint();
+    }
+}
+
+/// Returns the alternate under the pointer, if any.
+fn show_alternates(
+    ui: &Ui,
+    id: Id,
+    key_rect: Rect,
+    key: &VirtualKey,
+    shift: bool,
+    pointer_pos: Option<Pos2>,
+) -> Option<String> {
+    let painter = ui
+        .ctx()
+        .layer_painter(egui::LayerId::new(Order::Tooltip, id.with("alternates")));
+    let visuals = ui.visuals();
+    let size = key_rect.size() * vec2(1.0, 0.8);
+    let total_width = size.x * key.alternates.len() as f32;
+    let left = (key_rect.center().x - total_width / 2.0)
+        .max(ui.clip_rect().left())
+        .min(ui.clip_rect().right() - total_width);
+    let popup_rect = Rect::from_min_size(
+        Pos2::new(left, key_rect.top() - size.y - ui.spacing().item_spacing.y),
+        vec2(total_width, size.y),
+    );
+
+    let window = &visuals.window_stroke;
+    painter.rect(
+        popup_rect.expand(2.0),
+        visuals.window_corner_radius,
+        visuals.window_fill,
+        *window,
+        egui::StrokeKind::Outside,
+    );
+
+    let mut chosen = None;
+    for (i, alternate) in key.alternates.iter().enumerate() {
+        let rect = Rect::from_min_size(
+            Pos2::new(popup_rect.left() + i as f32 * size.x, popup_rect.top()),
+            size,
+        );
+        let is_hovered = pointer_pos.is_some_and(|pos| rect.contains(pos));
+        let text_color = if is_hovered {
+            painter.rect_filled(
+                rect,
+                visuals.widgets.active.corner_radius,
+                visuals.selection.bg_fill,
+            );
+            visuals.selection.stroke.color
+        } else {
+            visuals.text_color()
+        };
+        if is_hovered {
+            chosen = Some(alternate.clone());
+        }
+        let text = if shift {
+            alternate.to_uppercase()
+        } else {
+            alternate.clone()
+        };
+        painter.text(
+            rect.center(),
+            Align2::CENTER_CENTER,
+            text,
+            TextStyle::Button.resolve(ui.style()),
+            text_color,
+        );
+    }
+    chosen
+}
+
+fn paint_key(ui: &Ui, response: &Response, rect: Rect, key: &VirtualKey, shift: Shift) {
+    if !ui.is_rect_visible(rect) {
+        return;
+    }
+    let is_active = key.action == KeyAction::Shift && shift != Shift::Off;
+    let visuals = ui.style().interact_selectable(response, is_active);
+    let is_special = !matches!(key.action, KeyAction::Text(_));
+    let fill = if is_special && !is_active {
+        visuals.bg_fill
+    } else {
+        visuals.weak_bg_fill
+    };
+    ui.painter().rect(
+        rect,
+        visuals.corner_radius,
+        fill,
+        visuals.bg_stroke,
+        egui::StrokeKind::Inside,
+    );
+    ui.painter().text(
+        rect.center(),
+        Align2::CENTER_CENTER,
+        key.label(shift != Shift::Off),
+        TextStyle::Button.resolve(ui.style()),
+        visuals.text_color(),
+    );
+    if !key.alternates.is_empty() {
+        let dot = rect.right_top() + vec2(-4.0, 4.0);
+        ui.painter()
+            .circle_filled(dot, 1.5, visuals.text_color().gamma_multiply(0.5));
+    }
+
+    if key.action == KeyAction::Shift && shift == Shift::Locked {
+        // Caps lock:
+        let y = rect.bottom() - 0.25 * rect.height();
+        let x = rect.center().x;
+        ui.painter().hline(x - 4.0..=x + 4.0, y, visuals.fg_stroke);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn typing_into_focused_text_edit() {
+        let ctx = egui::Context::default();
+        let mut text = String::new();
+
+        let mut run = |events: Vec<Event>| {
+            let input = egui::RawInput {
+                screen_rect: Some(Rect::from_min_size(Pos2::ZERO, vec2(800.0, 600.0))),
+                events,
+                ..Default::default()
+            };
+            let mut keyboard_rect = Rect::NOTHING;
+            let _ = ctx.run(input, |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    let response = ui.text_edit_singleline(&mut text);
+                    if ctx.cumulative_pass_nr() == 0 {
+                        response.request_focus();
+                    }
+                    keyboard_rect = OnScreenKeyboard::new("keyboard").show(ui).rect;
+                });
+            });
+            keyboard_rect
+        };
+
+        let keyboard_rect = run(vec![]);
+
+        // The top left key is `q`:
+        let pos = keyboard_rect.left_top() + vec2(5.0, 5.0);
+        let button = |pressed| Event::PointerButton {
+            pos,
+            button: egui::PointerButton::Primary,
+            pressed,
+            modifiers: Modifiers::NONE,
+        };
+        run(vec![Event::PointerMoved(pos), button(true)]);
+        run(vec![button(false)]);
+        run(vec![]);
+
+        assert_eq!(text, "q");
+    }
+}
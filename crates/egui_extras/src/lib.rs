@@ -12,19 +12,47 @@
 #[cfg(feature = "chrono")]
 mod datepicker;
 
+pub mod audio;
+pub mod barcode;
+#[cfg(feature = "http")]
+pub mod fetch;
+pub mod file_browser;
+pub mod form;
+pub mod formatted_input;
+pub mod futures;
+pub mod guides;
+pub mod instruments;
+pub mod joystick;
+pub mod kanban;
+pub mod list_view;
 pub mod syntax_highlighting;
+pub mod table_of_contents;
 
 #[doc(hidden)]
 pub mod image;
+pub mod keyboard;
 mod layout;
 mod loaders;
+pub mod message_list;
+pub mod minimap;
+pub mod node_graph;
+pub mod pagination;
+#[cfg(feature = "recorder")]
+pub mod recorder;
+#[cfg(feature = "remote")]
+pub mod remote;
 mod sizing;
+pub mod sparkline;
 mod strip;
 mod table;
+pub mod timeline;
+pub mod tour;
 
 #[cfg(feature = "chrono")]
 pub use crate::datepicker::DatePickerButton;
 
+#[cfg(feature = "image")]
+pub use crate::image::{encode_jpeg, encode_png};
 pub(crate) use crate::layout::StripLayout;
 pub use crate::sizing::Size;
 pub use crate::strip::*;
@@ -0,0 +1,574 @@
+//! A list of items with an icon, a name and detail columns, like in a file manager or a playlist.
+
+use std::collections::BTreeSet;
+
+use egui::{
+    Align2, EventFilter, Id, Key, Modifiers, Rect, Response, Sense, TextStyle, Ui, Vec2,
+    WidgetInfo, WidgetType, pos2, vec2,
+};
+
+/// One row of a [`ListView`].
+#[derive(Clone, Debug, Default)]
+pub struct ListItem {
+    icon: Option<String>,
+    text: String,
+    details: Vec<String>,
+}
+
+impl ListItem {
+    /// The text is shown in the main column, and is what type-to-search looks at.
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            icon: None,
+            text: text.into(),
+            details: Vec::new(),
+        }
+    }
+
+    /// Shown in front of the text, e.g. an emoji like `"📁"`.
+    #[inline]
+    pub fn icon(mut self, icon: impl Into<String>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    /// The text of the next detail column, see [`ListView::column`].
+    #[inline]
+    pub fn detail(mut self, detail: impl Into<String>) -> Self {
+        self.details.push(detail.into());
+        self
+    }
+}
+
+/// A virtualized list with multi-selection and type-to-search.
+///
+/// A lighter-weight alternative to [`crate::TableBuilder`] when every row looks the same:
+/// an icon and a text, followed by a few detail columns.
+///
+/// * Click to select, ctrl/cmd-click to toggle, shift-click to select a range.
+/// * Drag on the list to select the rows inside a box (hold ctrl/cmd to add to the selection).
+/// * With the list focused: the arrow keys, home and end move the selection (shift extends it),
+///   ctrl/cmd+A selects all, and typing jumps to the first item starting with what was typed.
+/// * Double-click or enter activates an item, see [`ListViewResponse::activated`].
+///
+/// Only the rows in view are shown, so it handles very long lists.
+/// `item` is called for them, and for every item while searching.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let files = vec![("src", true, ""), ("Cargo.toml", false, "1 KB")];
+/// # let mut selection = std::collections::BTreeSet::new();
+/// use egui_extras::list_view::{ListItem, ListView};
+///
+/// let response = ListView::new("files")
+///     .main_column("Name")
+///     .column("Size", 80.0)
+///     .show(ui, files.len(), &mut selection, |i| {
+///         let (name, is_dir, size) = files[i];
+///         ListItem::new(name)
+///             .icon(if is_dir { "📁" } else { "🗋" })
+///             .detail(size)
+///     });
+/// if let Some(i) = response.activated {
+///     // open files[i]
+/// }
+/// # });
+/// ```
+#[doc(alias = "ListBox")]
+#[must_use = "You should call .show()"]
+pub struct ListView {
+    id: Id,
+    main_column: String,
+    columns: Vec<(String, f32)>,
+    row_height: Option<f32>,
+    multi_select: bool,
+}
+
+/// The result of showing a [`ListView`].
+pub struct ListViewResponse {
+    /// The response of the list itself, which is what gets focused.
+    pub response: Response,
+
+    /// The selection was changed by the user this pass.
+    pub selection_changed: bool,
+
+    /// The item that was double-clicked, or had enter pressed on it.
+    pub activated: Option<usize>,
+}
+
+#[derive(Clone, Default)]
+struct State {
+    /// Where shift-click and shift-arrows extend the selection from.
+    anchor: Option<usize>,
+
+    /// The item moved with the arrow keys.
+    cursor: Option<usize>,
+
+    /// What has been typed so far, and when.
+    search: String,
+    last_typed: f64,
+
+    /// Where the box select started, relative to the top-left of the list contents,
+    /// and the selection before it.
+    band: Option<(Vec2, BTreeSet<usize>)>,
+}
+
+/// Typing after this many seconds starts a new search.
+const SEARCH_TIMEOUT: f64 = 1.0;
+
+impl ListView {
+    /// The `id_salt` must be unique, and is used to remember the scroll position and selection anchor.
+    pub fn new(id_salt: impl std::hash::Hash) -> Self {
+        Self {
+            id: Id::new(id_salt),
+            main_column: String::new(),
+            columns: Vec::new(),
+            row_height: None,
+            multi_select: true,
+        }
+    }
+
+    /// The header of the main column, with the icon and the text.
+    ///
+    /// A header row is shown if any column has a title.
+    #[inline]
+    pub fn main_column(mut self, title: impl Into<String>) -> Self {
+        self.main_column = title.into();
+        self
+    }
+
+    /// Add a detail column, to the right of the main column, which gets the remaining width.
+    ///
+    /// The texts come from [`ListItem::detail`], in the same order.
+    #[inline]
+    pub fn column(mut self, title: impl Into<String>, width: f32) -> Self {
+        self.columns.push((title.into(), width));
+        self
+    }
+
+    /// Default: [`egui::style::Spacing::interact_size`]`.y`.
+    #[inline]
+    pub fn row_height(mut self, row_height: f32) -> Self {
+        self.row_height = Some(row_height);
+        self
+    }
+
+    /// Allow selecting more than one item.
+    ///
+    /// Default: `true`.
+    #[inline]
+    pub fn multi_select(mut self, multi_select: bool) -> Self {
+        self.multi_select = multi_select;
+        self
+    }
+
+    /// Show the list, filling the available space.
+    pub fn show(
+        self,
+        ui: &mut Ui,
+        num_items: usize,
+        selection: &mut BTreeSet<usize>,
+        item: impl Fn(usize) -> ListItem,
+    ) -> ListViewResponse {
+        let Self {
+            id,
+            main_column,
+            columns,
+            row_height,
+            multi_select,
+        } = self;
+
+        let mut state: State = ui.data(|d| d.get_temp(id)).unwrap_or_default();
+        let row_height = row_height.unwrap_or(ui.spacing().interact_size.y);
+        let padding = ui.spacing().button_padding.x;
+        let details_width: f32 = columns.iter().map(|(_, width)| width).sum();
+        let old_selection = selection.clone();
+        let mut activated = None;
+
+        let column_x = |left: f32, width: f32| {
+            let mut x = left + (width - details_width).max(0.0);
+            let mut xs = vec![left];
+            for (_, width) in &columns {
+                xs.push(x);
+                x += width;
+            }
+            xs
+        };
+
+        if !main_column.is_empty() || columns.iter().any(|(title, _)| !title.is_empty()) {
+            let (rect, _) =
+                ui.allocate_exact_size(vec2(ui.available_width(), row_height), Sense::hover());
+            let painter = ui.painter_at(rect);
+            let xs = column_x(rect.left(), rect.width());
+            let titles =
+                std::iter::once(&main_column).chain(columns.iter().map(|(title, _)| title));
+            for (x, title) in xs.iter().zip(titles) {
+                painter.text(
+                    pos2(x + padding, rect.center().y),
+                    Align2::LEFT_CENTER,
+                    title,
+                    TextStyle::Body.resolve(ui.style()),
+                    ui.visuals().strong_text_color(),
+                );
+            }
+            let stroke = ui.visuals().widgets.noninteractive.bg_stroke;
+            painter.hline(rect.x_range(), rect.bottom() - 0.5 * stroke.width, stroke);
+        }
+
+        let output = egui::ScrollArea::vertical()
+            .id_salt(id.with("scroll_area"))
+            .auto_shrink(false)
+            .scroll_source(egui::scroll_area::ScrollSource {
+                drag: false,
+                ..Default::default()
+            })
+            .show_viewport(ui, |ui, viewport| {
+                let origin = ui.max_rect().min;
+                let width = ui.available_width();
+                let content_rect =
+                    Rect::from_min_size(origin, vec2(width, row_height * num_items as f32));
+                ui.set_height(content_rect.height());
+                let visible_rect = viewport.translate(origin.to_vec2());
+                let response = ui.interact(visible_rect, id, Sense::click_and_drag());
+                response.widget_info(|| {
+                    WidgetInfo::labeled(WidgetType::Other, ui.is_enabled(), "List")
+                });
+
+                let row_at = |y: f32| {
+                    let row = ((y - origin.y) / row_height).floor();
+                    (0.0 <= row && (row as usize) < num_items).then_some(row as usize)
+                };
+                let row_rect = |row: usize| {
+                    Rect::from_min_size(
+                        origin + vec2(0.0, row as f32 * row_height),
+                        vec2(width, row_height),
+                    )
+                };
+                let modifiers = ui.input(|i| i.modifiers);
+                let mut moved_cursor = false;
+
+                // Mouse:
+                if response.clicked() || response.double_clicked() {
+                    response.request_focus();
+                    let row = response
+                        .interact_pointer_pos()
+                        .and_then(|pos| row_at(pos.y));
+                    select(selection, &mut state, row, modifiers, multi_select);
+                    if response.double_clicked() {
+                        activated = row;
+                    }
+                }
+                if multi_select {
+                    if response.drag_started() {
+                        response.request_focus();
+                        if let Some(pos) = ui.input(|i| i.pointer.press_origin()) {
+                            let base = if modifiers.command {
+                                selection.clone()
+                            } else {
+                                BTreeSet::new()
+                            };
+                            state.band = Some((pos - origin, base));
+                        }
+                    }
+                    if let (Some((start, base)), Some(pos)) =
+                        (&state.band, response.interact_pointer_pos())
+                    {
+                        let band = Rect::from_two_pos(origin + *start, pos);
+                        let first = ((band.top() - origin.y) / row_height).floor().max(0.0);
+                        let last = ((band.bottom() - origin.y) / row_height).ceil();
+                        let rows = (first as usize)..(last as usize).min(num_items);
+                        *selection = base.iter().copied().chain(rows).collect();
+
+                        let visuals = &ui.visuals().selection;
+                        ui.painter().rect(
+                            band,
+                            0.0,
+                            visuals.bg_fill.gamma_multiply(0.3),
+                            visuals.stroke,
+                            egui::StrokeKind::Inside,
+                        );
+                        // Scroll when dragging past the top or bottom:
+                        ui.scroll_to_rect(Rect::from_center_size(pos, Vec2::splat(1.0)), None);
+                    }
+                    if !response.dragged() {
+                        state.band = None;
+                    }
+                }
+
+                // Keyboard:
+                if response.has_focus() && 0 < num_items {
+                    ui.memory_mut(|m| {
+                        m.set_focus_lock_filter(
+                            id,
+                            EventFilter {
+                                vertical_arrows: true,
+                                ..Default::default()
+                            },
+                        );
+                    });
+                    let (keys, typed, time) = ui.input(|i| {
+                        let keys = [
+                            Key::ArrowUp,
+                            Key::ArrowDown,
+                            Key::Home,
+                            Key::End,
+                            Key::Enter,
+                        ]
+                        .map(|key| i.key_pressed(key));
+                        let typed: String = i
+                            .events
+                            .iter()
+                            .filter_map(|event| match event {
+                                egui::Event::Text(text) => Some(text.as_str()),
+                                _ => None,
+                            })
+                            .collect();
+                        (keys, typed, i.time)
+                    });
+                    let [up, down, home, end, enter] = keys;
+                    let last = num_items - 1;
+                    let cursor = state.cursor.map(|cursor| cursor.min(last));
+                    let target = if up {
+                        Some(cursor.map_or(last, |cursor| cursor.saturating_sub(1)))
+                    } else if down {
+                        Some(cursor.map_or(0, |cursor| (cursor + 1).min(last)))
+                    } else if home {
+                        Some(0)
+                    } else if end {
+                        Some(last)
+                    } else {
+                        None
+                    };
+                    if let Some(target) = target {
+                        let modifiers = Modifiers {
+                            command: false,
+                            ..modifiers
+                        };
+                        select(selection, &mut state, Some(target), modifiers, multi_select);
+                        moved_cursor = true;
+                    }
+                    if enter {
+                        activated = cursor;
+                    }
+
+                    if multi_select && ui.input_mut(|i| i.consume_key(Modifiers::COMMAND, Key::A)) {
+                        *selection = (0..num_items).collect();
+                    } else if !typed.is_empty() && !modifiers.command {
+                        if SEARCH_TIMEOUT < time - state.last_typed {
+                            state.search.clear();
+                        }
+                        state.search.push_str(&typed.to_lowercase());
+                        state.last_typed = time;
+                        // Typing the same letter again goes to the next match:
+                        let repeated = state.search.chars().all(|c| typed.starts_with(c))
+                            && 1 < state.search.chars().count();
+                        if repeated {
+                            state.search = typed.to_lowercase();
+                        }
+                        let start = cursor.map_or(0, |cursor| cursor + usize::from(repeated));
+                        let found = (start..num_items)
+                            .chain(0..start)
+                            .find(|&i| item(i).text.to_lowercase().starts_with(&state.search));
+                        if found.is_some() {
+                            select(selection, &mut state, found, Modifiers::NONE, multi_select);
+                            moved_cursor = true;
+                        }
+                    }
+                }
+                if moved_cursor {
+                    if let Some(cursor) = state.cursor {
+                        ui.scroll_to_rect(row_rect(cursor), None);
+                    }
+                }
+                // Paint the rows in view:
+                let hovered_row = response
+                    .hover_pos()
+                    .filter(|_| state.band.is_none())
+                    .and_then(|pos| row_at(pos.y));
+                let font_id = TextStyle::Body.resolve(ui.style());
+                let first = (viewport.top() / row_height).floor().max(0.0) as usize;
+                let last = ((viewport.bottom() / row_height).ceil() as usize).min(num_items);
+                for row in first..last {
+                    let rect = row_rect(row);
+                    let is_selected = selection.contains(&row);
+                    let visuals = ui.visuals();
+                    if is_selected {
+                        ui.painter()
+                            .rect_filled(rect, 0.0, visuals.selection.bg_fill);
+                    } else if hovered_row == Some(row) {
+                        ui.painter()
+                            .rect_filled(rect, 0.0, visuals.widgets.hovered.weak_bg_fill);
+                    }
+                    if response.has_focus() && state.cursor == Some(row) {
+                        ui.painter().rect_stroke(
+                            rect,
+                            0.0,
+                            visuals.selection.stroke,
+                            egui::StrokeKind::Inside,
+                        );
+                    }
+
+                    let text_color = if is_selected {
+                        visuals.selection.stroke.color
+                    } else {
+                        visuals.text_color()
+                    };
+                    let ListItem {
+                        icon,
+                        text,
+                        details,
+                    } = item(row);
+                    let xs = column_x(rect.left(), rect.width());
+                    // Paint a text in a column, after `indent`:
+                    let cell = |column: usize, indent: f32, text: &str, color| {
+                        let right = xs.get(column + 1).copied().unwrap_or(rect.right());
+                        let cell = Rect::from_x_y_ranges(xs[column]..=right, rect.y_range());
+                        ui.painter_at(cell.shrink2(vec2(padding, 0.0))).text(
+                            pos2(cell.left() + padding + indent, cell.center().y),
+                            Align2::LEFT_CENTER,
+                            text,
+                            font_id.clone(),
+                            color,
+                        )
+                    };
+                    let indent = icon.map_or(0.0, |icon| {
+                        cell(0, 0.0, &icon, text_color).width() + padding
+                    });
+                    cell(0, indent, &text, text_color);
+                    let detail_color = if is_selected {
+                        text_color
+                    } else {
+                        visuals.weak_text_color()
+                    };
+                    for (i, detail) in details.iter().enumerate().take(columns.len()) {
+                        cell(i + 1, 0.0, detail, detail_color);
+                    }
+                }
+
+                response
+            });
+
+        ui.data_mut(|d| d.insert_temp(id, state));
+
+        let mut response = output.inner;
+        let selection_changed = *selection != old_selection;
+        if selection_changed {
+            response.mark_changed();
+        }
+        ListViewResponse {
+            response,
+            selection_changed,
+            activated,
+        }
+    }
+}
+
+/// Select a row (or nothing, for `None`) the way clicking it with these modifiers would.
+fn select(
+    selection: &mut BTreeSet<usize>,
+    state: &mut State,
+    row: Option<usize>,
+    modifiers: Modifiers,
+    multi_select: bool,
+) {
+    let Some(row) = row else {
+        if !modifiers.command && !modifiers.shift {
+            selection.clear();
+        }
+        return;
+    };
+    state.cursor = Some(row);
+    match state.anchor {
+        Some(anchor) if multi_select && modifiers.shift => {
+            if !modifiers.command {
+                selection.clear();
+            }
+            selection.extend(anchor.min(row)..=anchor.max(row));
+        }
+        _ if multi_select && modifiers.command => {
+            if !selection.remove(&row) {
+                selection.insert(row);
+            }
+            state.anchor = Some(row);
+        }
+        _ => {
+            selection.clear();
+            selection.insert(row);
+            state.anchor = Some(row);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn search_and_select() {
+        let fruit = ["apple", "banana", "blueberry", "cherry", "date"];
+        let ctx = egui::Context::default();
+        let mut selection = BTreeSet::new();
+        let run = |selection: &mut BTreeSet<usize>, events: Vec<egui::Event>| {
+            let modifiers = events.iter().find_map(|event| match event {
+                egui::Event::Key { modifiers, .. } => Some(*modifiers),
+                _ => None,
+            });
+            let input = egui::RawInput {
+                events,
+                modifiers: modifiers.unwrap_or_default(),
+                ..Default::default()
+            };
+            let mut rect = Rect::NOTHING;
+            let _ = ctx.run(input, |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    let response = ListView::new("fruit")
+                        .show(ui, fruit.len(), selection, |i| ListItem::new(fruit[i]))
+                        .response;
+                    response.request_focus();
+                    rect = response.rect;
+                });
+            });
+            rect
+        };
+        let key = |key, modifiers| egui::Event::Key {
+            key,
+            physical_key: None,
+            pressed: true,
+            repeat: false,
+            modifiers,
+        };
+
+        run(&mut selection, vec![]);
+        run(&mut selection, vec![]);
+        run(&mut selection, vec![egui::Event::Text("b".to_owned())]);
+        assert_eq!(selection, BTreeSet::from([1]));
+        run(&mut selection, vec![egui::Event::Text("b".to_owned())]);
+        assert_eq!(
+            selection,
+            BTreeSet::from([2]),
+            "Typing 'b' again goes to the next match"
+        );
+
+        run(&mut selection, vec![key(Key::ArrowDown, Modifiers::SHIFT)]);
+        assert_eq!(selection, BTreeSet::from([2, 3]));
+
+        // Box select the first two rows:
+        let rect = run(&mut selection, vec![]);
+        let start = rect.left_top() + vec2(10.0, 2.0);
+        let end = start + vec2(50.0, 20.0);
+        let button = |pos, pressed| egui::Event::PointerButton {
+            pos,
+            button: egui::PointerButton::Primary,
+            pressed,
+            modifiers: Modifiers::NONE,
+        };
+        run(
+            &mut selection,
+            vec![egui::Event::PointerMoved(start), button(start, true)],
+        );
+        run(&mut selection, vec![egui::Event::PointerMoved(end)]);
+        run(&mut selection, vec![egui::Event::PointerMoved(end)]);
+        run(&mut selection, vec![button(end, false)]);
+        assert_eq!(selection, BTreeSet::from([0, 1]));
+    }
+}
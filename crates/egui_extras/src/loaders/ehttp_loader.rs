@@ -82,8 +82,7 @@ impl BytesLoader for EhttpLoader {
             cache.insert(uri.clone(), Poll::Pending);
             drop(cache);
 
-            ehttp::fetch(ehttp::Request::get(uri.clone()), {
-                let ctx = ctx.clone();
+            crate::fetch::fetch(ctx, crate::fetch::Request::get(uri.clone()), {
                 let cache = self.cache.clone();
                 move |response| {
                     let result = match response {
@@ -100,7 +99,6 @@ impl BytesLoader for EhttpLoader {
                     {
                         let entry = entry.get_mut();
                         *entry = Poll::Ready(result);
-                        ctx.request_repaint();
                         log::trace!("Finished loading {uri:?}");
                     } else {
                         log::trace!(
@@ -5,20 +5,242 @@ use egui::{
     mutex::Mutex,
 };
 use image::ImageFormat;
-use std::{mem::size_of, path::Path, sync::Arc, task::Poll};
+use std::{mem::size_of, path::Path, sync::Arc};
 
-#[cfg(not(target_arch = "wasm32"))]
-use std::thread;
+#[cfg(target_arch = "wasm32")]
+mod web_decoder;
+
+#[derive(Clone)]
+enum Entry {
+    /// Still decoding, with a low-resolution preview once there is one.
+    Pending(Option<Arc<ColorImage>>),
+    Ready(Result<Arc<ColorImage>, String>),
+}
 
-type Entry = Poll<Result<Arc<ColorImage>, String>>;
+type Cache = Arc<Mutex<HashMap<String, Entry>>>;
 
+/// With the `jpeg_previews` feature, JPEGs more than twice this size (in either dimension)
+/// first get a preview of about this size, shown until the full image is ready.
+#[cfg(feature = "jpeg_previews")]
+const PREVIEW_SIZE: u32 = 256;
+
+/// Images waiting to be decoded on the UI thread.
+#[cfg(target_arch = "wasm32")]
+type Queue = Arc<Mutex<std::collections::VecDeque<(String, Bytes)>>>;
+
+/// Decodes images off the UI thread.
+///
+/// On native, images are decoded with the [`image`] crate by a small pool of background threads.
+/// On the web, they are decoded by the browser in a web worker.
+/// Formats the browser can't decode fall back to the [`image`] crate on the UI thread,
+/// at most one image per pass.
+///
+/// With the `jpeg_previews` feature, large JPEGs are first decoded at a reduced scale,
+/// which is much quicker, and shown at that resolution until the full image is ready.
+/// On native this uses [`jpeg-decoder`](https://docs.rs/jpeg-decoder), on the web the browser.
 #[derive(Default)]
 pub struct ImageCrateLoader {
-    cache: Arc<Mutex<HashMap<String, Entry>>>,
+    cache: Cache,
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pool: DecodePool,
+
+    #[cfg(target_arch = "wasm32")]
+    queue: Queue,
+
+    /// The pass during which we last decoded an image, plus one.
+    #[cfg(target_arch = "wasm32")]
+    last_decode_pass: std::sync::atomic::AtomicU64,
 }
 
 impl ImageCrateLoader {
     pub const ID: &'static str = egui::generate_loader_id!(ImageCrateLoader);
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn start_decoding(&self, ctx: &egui::Context, uri: &str, bytes: &Bytes) {
+        let uri = uri.to_owned();
+        self.cache.lock().insert(uri.clone(), Entry::Pending(None));
+
+        let ctx = ctx.clone();
+        let cache = self.cache.clone();
+        let bytes = bytes.clone();
+        self.pool.execute(move || {
+            #[cfg(feature = "jpeg_previews")]
+            if let Some(preview) = jpeg_preview(&bytes) {
+                update_entry(&ctx, &cache, &uri, Entry::Pending(Some(Arc::new(preview))));
+            }
+            decode_into_cache(&ctx, &cache, &uri, &bytes);
+        });
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    fn start_decoding(&self, ctx: &egui::Context, uri: &str, bytes: &Bytes) {
+        self.cache
+            .lock()
+            .insert(uri.to_owned(), Entry::Pending(None));
+        let job = web_decoder::Job {
+            ctx: ctx.clone(),
+            cache: self.cache.clone(),
+            fallback: self.queue.clone(),
+            uri: uri.to_owned(),
+            bytes: bytes.clone(),
+        };
+        #[cfg(feature = "jpeg_previews")]
+        let preview = jpeg_preview_size(bytes);
+        #[cfg(not(feature = "jpeg_previews"))]
+        let preview = None;
+        if let Err(job) = web_decoder::decode(job, preview) {
+            self.queue.lock().push_back((job.uri, job.bytes));
+            ctx.request_repaint();
+        }
+    }
+
+    /// Decode the next image the browser couldn't decode, unless we already did so this pass.
+    #[cfg(target_arch = "wasm32")]
+    fn decode_next(&self, ctx: &egui::Context) {
+        use std::sync::atomic::Ordering::Relaxed;
+
+        if self.queue.lock().is_empty() {
+            return;
+        }
+        let pass = ctx.cumulative_pass_nr() + 1;
+        if self.last_decode_pass.swap(pass, Relaxed) == pass {
+            return;
+        }
+        let next = self.queue.lock().pop_front();
+        if let Some((uri, bytes)) = next {
+            decode_into_cache(ctx, &self.cache, &uri, &bytes);
+        }
+        if !self.queue.lock().is_empty() {
+            ctx.request_repaint();
+        }
+    }
+}
+
+/// Decode the image with the [`image`] crate and store the result,
+/// unless it was forgotten while we were decoding.
+fn decode_into_cache(ctx: &egui::Context, cache: &Cache, uri: &str, bytes: &Bytes) {
+    log::trace!("ImageLoader - started loading {uri:?}");
+    let result = crate::image::load_image_bytes(bytes)
+        .map(Arc::new)
+        .map_err(|err| err.to_string());
+
+    if update_entry(ctx, cache, uri, Entry::Ready(result)) {
+        log::trace!("ImageLoader - finished loading {uri:?}");
+    } else {
+        log::trace!(
+            "ImageLoader - canceled loading {uri:?}\nNote: This can happen if `forget_image` is called while the image is still loading."
+        );
+    }
+}
+
+/// Decode a large JPEG at a reduced scale, which skips most of the work of decoding it fully.
+///
+/// Returns `None` if `bytes` isn't a JPEG large enough to need a preview.
+#[cfg(all(feature = "jpeg_previews", not(target_arch = "wasm32")))]
+fn jpeg_preview(bytes: &[u8]) -> Option<ColorImage> {
+    profiling::function_scope!();
+    let mut decoder = jpeg_decoder::Decoder::new(bytes);
+    decoder.read_info().ok()?;
+    let info = decoder.info()?;
+    if u32::from(info.width.max(info.height)) <= 2 * PREVIEW_SIZE {
+        return None;
+    }
+
+    // The smallest of 1/8, 1/4 and 1/2 scale that is at least this large:
+    let (width, height) = decoder
+        .scale(PREVIEW_SIZE as u16, PREVIEW_SIZE as u16)
+        .ok()?;
+    let pixels = decoder.decode().ok()?;
+    let size = [width as usize, height as usize];
+    let mut preview = match info.pixel_format {
+        jpeg_decoder::PixelFormat::L8 => ColorImage::from_gray(size, &pixels),
+        jpeg_decoder::PixelFormat::RGB24 => ColorImage::from_rgb(size, &pixels),
+        jpeg_decoder::PixelFormat::L16 | jpeg_decoder::PixelFormat::CMYK32 => return None,
+    };
+    preview.source_size = egui::vec2(info.width as f32, info.height as f32);
+    Some(preview)
+}
+
+/// The size for the browser to decode a preview of a large JPEG at, and the size of the full image,
+/// read from its header.
+///
+/// Returns `None` if `bytes` isn't a JPEG large enough to need a preview.
+#[cfg(all(feature = "jpeg_previews", target_arch = "wasm32"))]
+fn jpeg_preview_size(bytes: &[u8]) -> Option<([u32; 2], [u32; 2])> {
+    let mut decoder = jpeg_decoder::Decoder::new(bytes);
+    decoder.read_info().ok()?;
+    let info = decoder.info()?;
+    let source_size = [u32::from(info.width), u32::from(info.height)];
+    let largest = source_size[0].max(source_size[1]);
+    if largest <= 2 * PREVIEW_SIZE {
+        return None;
+    }
+    let size = source_size.map(|side| (side * PREVIEW_SIZE / largest).max(1));
+    Some((size, source_size))
+}
+
+/// Replace the entry for `uri`, unless it has been forgotten, and repaint.
+///
+/// Returns `false` if it had been forgotten.
+fn update_entry(ctx: &egui::Context, cache: &Cache, uri: &str, new_entry: Entry) -> bool {
+    let updated = match cache.lock().get_mut(uri) {
+        Some(entry) => {
+            *entry = new_entry;
+            true
+        }
+        None => false,
+    };
+    if updated {
+        // Not while holding the cache lock, since `Context::has_pending_images`
+        // locks the cache while holding the context lock:
+        ctx.request_repaint();
+    }
+    updated
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+type Job = Box<dyn FnOnce() + Send>;
+
+/// A few threads, started on first use, that the jobs are handed out to in turn.
+///
+/// This avoids starting a thread for each image when loading many at once.
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Default)]
+struct DecodePool {
+    /// One per thread, and the index of the next one to use.
+    senders: Mutex<(Vec<std::sync::mpsc::Sender<Job>>, usize)>,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl DecodePool {
+    fn execute(&self, job: impl FnOnce() + Send + 'static) {
+        let mut senders = self.senders.lock();
+        let (senders, next) = &mut *senders;
+        if senders.is_empty() {
+            let num_threads = std::thread::available_parallelism()
+                .map_or(1, |n| n.get())
+                .clamp(1, 4);
+            for i in 0..num_threads {
+                let (sender, receiver) = std::sync::mpsc::channel::<Job>();
+                std::thread::Builder::new()
+                    .name(format!("egui_extras::ImageLoader decoder {i}"))
+                    .spawn(move || {
+                        // Stops when the loader (and so the sender) is dropped:
+                        while let Ok(job) = receiver.recv() {
+                            job();
+                        }
+                    })
+                    .expect("failed to spawn thread");
+                senders.push(sender);
+            }
+        }
+
+        *next = (*next + 1) % senders.len();
+        if senders[*next].send(Box::new(job)).is_err() {
+            log::warn!("ImageLoader - a decoder thread has stopped");
+        }
+    }
 }
 
 fn is_supported_uri(uri: &str) -> bool {
@@ -75,74 +297,17 @@ impl ImageLoader for ImageCrateLoader {
             return Err(LoadError::NotSupported);
         }
 
-        #[cfg(not(target_arch = "wasm32"))]
-        #[expect(clippy::unnecessary_wraps)] // needed here to match other return types
-        fn load_image(
-            ctx: &egui::Context,
-            uri: &str,
-            cache: &Arc<Mutex<HashMap<String, Entry>>>,
-            bytes: &Bytes,
-        ) -> ImageLoadResult {
-            let uri = uri.to_owned();
-            cache.lock().insert(uri.clone(), Poll::Pending);
-
-            // Do the image parsing on a bg thread
-            thread::Builder::new()
-                .name(format!("egui_extras::ImageLoader::load({uri:?})"))
-                .spawn({
-                    let ctx = ctx.clone();
-                    let cache = cache.clone();
-
-                    let uri = uri.clone();
-                    let bytes = bytes.clone();
-                    move || {
-                        log::trace!("ImageLoader - started loading {uri:?}");
-                        let result = crate::image::load_image_bytes(&bytes)
-                            .map(Arc::new)
-                            .map_err(|err| err.to_string());
-                        let mut cache = cache.lock();
-
-                        if let std::collections::hash_map::Entry::Occupied(mut entry) = cache.entry(uri.clone()) {
-                            let entry = entry.get_mut();
-                            *entry = Poll::Ready(result);
-                            ctx.request_repaint();
-                            log::trace!("ImageLoader - finished loading {uri:?}");
-                        } else {
-                            log::trace!("ImageLoader - canceled loading {uri:?}\nNote: This can happen if `forget_image` is called while the image is still loading.");
-                        }
-                    }
-                })
-                .expect("failed to spawn thread");
-
-            Ok(ImagePoll::Pending { size: None })
-        }
-
         #[cfg(target_arch = "wasm32")]
-        fn load_image(
-            _ctx: &egui::Context,
-            uri: &str,
-            cache: &Arc<Mutex<HashMap<String, Entry>>>,
-            bytes: &Bytes,
-        ) -> ImageLoadResult {
-            let mut cache_lock = cache.lock();
-            log::trace!("started loading {uri:?}");
-            let result = crate::image::load_image_bytes(bytes)
-                .map(Arc::new)
-                .map_err(|err| err.to_string());
-            log::trace!("finished loading {uri:?}");
-            cache_lock.insert(uri.into(), std::task::Poll::Ready(result.clone()));
-            match result {
-                Ok(image) => Ok(ImagePoll::Ready { image }),
-                Err(err) => Err(LoadError::Loading(err)),
-            }
-        }
+        self.decode_next(ctx);
 
         let entry = self.cache.lock().get(uri).cloned();
         if let Some(entry) = entry {
             match entry {
-                Poll::Ready(Ok(image)) => Ok(ImagePoll::Ready { image }),
-                Poll::Ready(Err(err)) => Err(LoadError::Loading(err)),
-                Poll::Pending => Ok(ImagePoll::Pending { size: None }),
+                Entry::Ready(Ok(image)) => Ok(ImagePoll::Ready { image }),
+                Entry::Ready(Err(err)) => Err(LoadError::Loading(err)),
+                Entry::Pending(preview) => Ok(ImagePoll::Pending {
+                    size: preview.map(|preview| preview.source_size),
+                }),
             }
         } else {
             match ctx.try_load_bytes(uri) {
@@ -155,7 +320,8 @@ impl ImageLoader for ImageCrateLoader {
                             });
                         }
                     }
-                    load_image(ctx, uri, &self.cache, &bytes)
+                    self.start_decoding(ctx, uri, &bytes);
+                    Ok(ImagePoll::Pending { size: None })
                 }
                 Ok(BytesPoll::Pending { size }) => Ok(ImagePoll::Pending { size }),
                 Err(err) => Err(err),
@@ -163,28 +329,43 @@ impl ImageLoader for ImageCrateLoader {
         }
     }
 
+    fn load_preview(&self, uri: &str) -> Option<Arc<ColorImage>> {
+        let uri = decode_animated_image_uri(uri).map_or(uri, |(uri, _frame_index)| uri);
+        match self.cache.lock().get(uri)? {
+            Entry::Pending(preview) => preview.clone(),
+            Entry::Ready(_) => None,
+        }
+    }
+
     fn forget(&self, uri: &str) {
         let _ = self.cache.lock().remove(uri);
     }
 
     fn forget_all(&self) {
         self.cache.lock().clear();
+        #[cfg(target_arch = "wasm32")]
+        self.queue.lock().clear();
     }
 
     fn byte_size(&self) -> usize {
         self.cache
             .lock()
             .values()
-            .map(|result| match result {
-                Poll::Ready(Ok(image)) => image.pixels.len() * size_of::<egui::Color32>(),
-                Poll::Ready(Err(err)) => err.len(),
-                Poll::Pending => 0,
+            .map(|entry| match entry {
+                Entry::Ready(Ok(image)) | Entry::Pending(Some(image)) => {
+                    image.pixels.len() * size_of::<egui::Color32>()
+                }
+                Entry::Ready(Err(err)) => err.len(),
+                Entry::Pending(None) => 0,
             })
             .sum()
     }
 
     fn has_pending(&self) -> bool {
-        self.cache.lock().values().any(|result| result.is_pending())
+        self.cache
+            .lock()
+            .values()
+            .any(|entry| matches!(entry, Entry::Pending(_)))
     }
 }
 
@@ -192,6 +373,52 @@ impl ImageLoader for ImageCrateLoader {
 mod tests {
     use super::*;
 
+    #[cfg(not(target_arch = "wasm32"))]
+    #[test]
+    fn decodes_in_the_background() {
+        let ctx = egui::Context::default();
+        let loader = ImageCrateLoader::default();
+        let uris: Vec<String> = (0..10).map(|i| format!("bytes://image_{i}.png")).collect();
+        for uri in &uris {
+            ctx.include_bytes(uri.clone(), b"not an image".as_slice());
+            assert!(matches!(
+                loader.load(&ctx, uri, SizeHint::default()),
+                Ok(ImagePoll::Pending { .. })
+            ));
+        }
+
+        let start = std::time::Instant::now();
+        while loader.has_pending() {
+            assert!(start.elapsed().as_secs() < 10, "Decoding took too long");
+            std::thread::yield_now();
+        }
+        for uri in &uris {
+            assert!(matches!(
+                loader.load(&ctx, uri, SizeHint::default()),
+                Err(LoadError::Loading(_))
+            ));
+        }
+    }
+
+    #[cfg(all(feature = "jpeg_previews", not(target_arch = "wasm32")))]
+    #[test]
+    fn preview_of_large_jpegs() {
+        let jpeg = |width, height| {
+            let mut bytes = Vec::new();
+            image::DynamicImage::new_rgb8(width, height)
+                .write_to(&mut std::io::Cursor::new(&mut bytes), ImageFormat::Jpeg)
+                .unwrap();
+            bytes
+        };
+
+        assert!(jpeg_preview(&jpeg(300, 200)).is_none());
+        assert!(jpeg_preview(b"not a jpeg").is_none());
+
+        let preview = jpeg_preview(&jpeg(1024, 512)).unwrap();
+        assert_eq!(preview.size, [256, 128]);
+        assert_eq!(preview.source_size, egui::vec2(1024.0, 512.0));
+    }
+
     #[test]
     fn check_support() {
         assert!(is_supported_uri("https://test.png"));
@@ -0,0 +1,240 @@
+//! Decoding images in a web worker, with the browser's own decoders.
+//!
+//! The worker is started from an inline script on first use,
+//! and shared by all [`super::ImageCrateLoader`]s on the page.
+
+use std::{cell::RefCell, sync::Arc};
+
+use ahash::HashMap;
+use egui::{ColorImage, load::Bytes};
+use wasm_bindgen::{JsCast as _, JsValue, closure::Closure};
+
+use super::{Cache, Entry, Queue, update_entry};
+
+/// Decodes with `createImageBitmap`, and reads the pixels back from an `OffscreenCanvas`.
+///
+/// Posts back `{ id, width, height, pixels, done }` (with `pixels` premultiplied like [`egui::Color32`]),
+/// first for the preview (with `done: false` and the full `source_width` and `source_height`)
+/// if asked for one, then for the full image.
+/// Posts `{ id, error }` if the browser can't decode the image.
+const WORKER_SCRIPT: &str = r#"
+function premultiply(pixels) {
+    for (let i = 0; i < pixels.length; i += 4) {
+        const a = pixels[i + 3];
+        if (a !== 255) {
+            pixels[i] = Math.round((pixels[i] * a) / 255);
+            pixels[i + 1] = Math.round((pixels[i + 1] * a) / 255);
+            pixels[i + 2] = Math.round((pixels[i + 2] * a) / 255);
+        }
+    }
+    return pixels;
+}
+
+function read_pixels(bitmap) {
+    const { width, height } = bitmap;
+    const canvas = new OffscreenCanvas(width, height);
+    const ctx = canvas.getContext("2d");
+    ctx.drawImage(bitmap, 0, 0);
+    bitmap.close();
+    return { width, height, pixels: premultiply(ctx.getImageData(0, 0, width, height).data) };
+}
+
+self.onmessage = async (event) => {
+    const { id, bytes, preview } = event.data;
+    const blob = new Blob([bytes]);
+    const options = { premultiplyAlpha: "none", colorSpaceConversion: "none" };
+    if (preview) {
+        try {
+            // Asking for a small bitmap lets the browser decode a JPEG at a reduced scale:
+            const { width, height, pixels } = read_pixels(
+                await createImageBitmap(blob, {
+                    ...options,
+                    resizeWidth: preview.width,
+                    resizeHeight: preview.height,
+                    resizeQuality: "low",
+                }),
+            );
+            self.postMessage(
+                {
+                    id,
+                    width,
+                    height,
+                    source_width: preview.source_width,
+                    source_height: preview.source_height,
+                    pixels,
+                    done: false,
+                },
+                [pixels.buffer],
+            );
+        } catch (error) {
+            // No preview then, but maybe the full image works.
+        }
+    }
+    try {
+        const { width, height, pixels } = read_pixels(await createImageBitmap(blob, options));
+        self.postMessage({ id, width, height, pixels, done: true }, [pixels.buffer]);
+    } catch (error) {
+        self.postMessage({ id, error: String(error) });
+    }
+};
+"#;
+
+/// An image being decoded by the worker.
+pub(super) struct Job {
+    pub ctx: egui::Context,
+    pub cache: Cache,
+
+    /// Where to queue the image if the browser can't decode it.
+    pub fallback: Queue,
+
+    pub uri: String,
+    pub bytes: Bytes,
+}
+
+struct WebDecoder {
+    worker: web_sys::Worker,
+    _on_message: Closure<dyn FnMut(web_sys::MessageEvent)>,
+    jobs: HashMap<u32, Job>,
+    next_id: u32,
+}
+
+thread_local! {
+    static DECODER: RefCell<Option<WebDecoder>> = const { RefCell::new(None) };
+}
+
+/// Send the image to the worker.
+///
+/// With `preview`, the size to first decode a preview at and the size of the full image,
+/// the worker posts a preview before the full image.
+///
+/// Returns the job back if it couldn't be sent, e.g. because the worker couldn't be started.
+pub(super) fn decode(job: Job, preview: Option<([u32; 2], [u32; 2])>) -> Result<(), Job> {
+    DECODER.with_borrow_mut(|decoder| {
+        if decoder.is_none() {
+            match start_worker() {
+                Ok(started) => *decoder = Some(started),
+                Err(err) => {
+                    log::warn!("ImageLoader - failed to start the image decoding worker: {err:?}");
+                    return Err(job);
+                }
+            }
+        }
+        let Some(decoder) = decoder else {
+            return Err(job);
+        };
+
+        let id = decoder.next_id;
+        decoder.next_id = decoder.next_id.wrapping_add(1);
+
+        let message = js_sys::Object::new();
+        let bytes = js_sys::Uint8Array::from(&job.bytes[..]);
+        let sent = js_sys::Reflect::set(&message, &"id".into(), &id.into())
+            .and_then(|_| js_sys::Reflect::set(&message, &"bytes".into(), &bytes))
+            .and_then(|_| match preview {
+                Some((size, source_size)) => {
+                    let preview = js_sys::Object::new();
+                    for (key, value) in [
+                        ("width", size[0]),
+                        ("height", size[1]),
+                        ("source_width", source_size[0]),
+                        ("source_height", source_size[1]),
+                    ] {
+                        js_sys::Reflect::set(&preview, &key.into(), &value.into())?;
+                    }
+                    js_sys::Reflect::set(&message, &"preview".into(), &preview)
+                }
+                None => Ok(true),
+            })
+            .and_then(|_| decoder.worker.post_message(&message));
+        match sent {
+            Ok(()) => {
+                decoder.jobs.insert(id, job);
+                Ok(())
+            }
+            Err(err) => {
+                log::warn!("ImageLoader - failed to send an image to the decoding worker: {err:?}");
+                Err(job)
+            }
+        }
+    })
+}
+
+fn start_worker() -> Result<WebDecoder, JsValue> {
+    let parts = js_sys::Array::of1(&WORKER_SCRIPT.into());
+    let options = web_sys::BlobPropertyBag::new();
+    options.set_type("text/javascript");
+    let blob = web_sys::Blob::new_with_str_sequence_and_options(&parts, &options)?;
+    let url = web_sys::Url::create_object_url_with_blob(&blob)?;
+    let worker = web_sys::Worker::new(&url)?;
+
+    let on_message =
+        Closure::<dyn FnMut(web_sys::MessageEvent)>::new(|event: web_sys::MessageEvent| {
+            on_message(&event.data());
+        });
+    worker.set_onmessage(Some(on_message.as_ref().unchecked_ref()));
+
+    Ok(WebDecoder {
+        worker,
+        _on_message: on_message,
+        jobs: Default::default(),
+        next_id: 0,
+    })
+}
+
+fn on_message(data: &JsValue) {
+    let get = |key: &str| js_sys::Reflect::get(data, &key.into()).ok();
+    let Some(id) = get("id").and_then(|id| id.as_f64()) else {
+        return;
+    };
+    let id = id as u32;
+    let done = get("done").and_then(|done| done.as_bool()) != Some(false);
+
+    if !done {
+        let job = DECODER.with_borrow(|decoder| {
+            let job = decoder.as_ref()?.jobs.get(&id)?;
+            Some((job.ctx.clone(), job.cache.clone(), job.uri.clone()))
+        });
+        if let (Some((ctx, cache, uri)), Some(preview)) = (job, color_image(data)) {
+            update_entry(&ctx, &cache, &uri, Entry::Pending(Some(Arc::new(preview))));
+        }
+        return;
+    }
+
+    let Some(job) = DECODER.with_borrow_mut(|decoder| decoder.as_mut()?.jobs.remove(&id)) else {
+        return;
+    };
+    if let Some(image) = color_image(data) {
+        if update_entry(
+            &job.ctx,
+            &job.cache,
+            &job.uri,
+            Entry::Ready(Ok(Arc::new(image))),
+        ) {
+            log::trace!("ImageLoader - finished loading {:?}", job.uri);
+        }
+    } else {
+        // Maybe the image crate knows this format:
+        log::trace!(
+            "ImageLoader - the browser failed to decode {:?}: {:?}",
+            job.uri,
+            get("error")
+        );
+        job.fallback.lock().push_back((job.uri, job.bytes));
+        job.ctx.request_repaint();
+    }
+}
+
+fn color_image(data: &JsValue) -> Option<ColorImage> {
+    let get = |key: &str| js_sys::Reflect::get(data, &key.into()).ok();
+    let width = get("width")?.as_f64()? as usize;
+    let height = get("height")?.as_f64()? as usize;
+    let pixels: js_sys::Uint8ClampedArray = get("pixels")?.dyn_into().ok()?;
+    let mut image = ColorImage::from_rgba_premultiplied([width, height], &pixels.to_vec());
+    if let (Some(source_width), Some(source_height)) = (
+        get("source_width").and_then(|width| width.as_f64()),
+        get("source_height").and_then(|height| height.as_f64()),
+    ) {
+        image.source_size = egui::vec2(source_width as f32, source_height as f32);
+    }
+    Some(image)
+}
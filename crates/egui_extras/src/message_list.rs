@@ -0,0 +1,238 @@
+//! A scrolling list of messages that sticks to the bottom, for chats and logs.
+
+use egui::{Button, Id, Rect, Ui, scroll_area::ScrollAreaOutput, vec2};
+
+/// A virtualized list of messages of varying height, e.g. a chat or a log.
+///
+/// * When scrolled to the end, new messages keep it scrolled to the end.
+/// * When scrolled up, the position is kept, and a "N new messages" button
+///   scrolls back down.
+/// * Only the messages in view (plus the ones not yet measured) are shown,
+///   so it handles very long lists.
+///
+/// Messages are identified by their index, so only ever append to the list.
+/// If you remove or change messages, call [`MessageList::reset`] so they are re-measured.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let messages = vec![("alice", "Hi!"), ("bob", "Hello")];
+/// use egui_extras::message_list::MessageList;
+///
+/// MessageList::new("chat").show(ui, messages.len(), |ui, index| {
+///     let (author, text) = messages[index];
+///     ui.horizontal_wrapped(|ui| {
+///         ui.strong(author);
+///         ui.label(text);
+///     });
+/// });
+/// # });
+/// ```
+#[doc(alias = "chat")]
+#[doc(alias = "log")]
+#[must_use = "You should call .show()"]
+pub struct MessageList {
+    id: Id,
+    estimated_height: Option<f32>,
+    stick_to_bottom: bool,
+}
+
+/// The result of showing a [`MessageList`].
+pub struct MessageListResponse<R> {
+    /// The output of the inner [`egui::ScrollArea`].
+    pub scroll_area: ScrollAreaOutput<R>,
+
+    /// Is the list scrolled to the end?
+    pub at_bottom: bool,
+
+    /// How many messages have been added since the user scrolled up.
+    pub num_new: usize,
+}
+
+#[derive(Clone, Default)]
+struct State {
+    /// Measured height of each message, or `NaN` if not yet measured.
+    heights: Vec<f32>,
+
+    /// The width the heights were measured at.
+    width: f32,
+
+    /// How many messages the user has seen, i.e. how many there were when last at the bottom.
+    num_seen: Option<usize>,
+}
+
+impl MessageList {
+    /// The `id_salt` must be unique, and is used to remember the scroll position and message heights.
+    pub fn new(id_salt: impl std::hash::Hash) -> Self {
+        Self {
+            id: Id::new(id_salt),
+            estimated_height: None,
+            stick_to_bottom: true,
+        }
+    }
+
+    /// The height of messages that have not been shown yet.
+    ///
+    /// Default: [`egui::style::Spacing::interact_size`]`.y`.
+    #[inline]
+    pub fn estimated_height(mut self, height: f32) -> Self {
+        self.estimated_height = Some(height);
+        self
+    }
+
+    /// Keep scrolled to the bottom when new messages arrive, if already at the bottom.
+    ///
+    /// Default: `true`.
+    #[inline]
+    pub fn stick_to_bottom(mut self, stick: bool) -> Self {
+        self.stick_to_bottom = stick;
+        self
+    }
+
+    /// Forget the measured message heights, e.g. after removing messages.
+    pub fn reset(ctx: &egui::Context, id_salt: impl std::hash::Hash) {
+        ctx.data_mut(|d| d.remove::<State>(Id::new(id_salt)));
+    }
+
+    /// Show the list, filling the available space.
+    ///
+    /// `add_message` is called with the index of each message that needs to be shown.
+    pub fn show<R>(
+        self,
+        ui: &mut Ui,
+        num_messages: usize,
+        mut add_message: impl FnMut(&mut Ui, usize) -> R,
+    ) -> MessageListResponse<Vec<R>> {
+        let Self {
+            id,
+            estimated_height,
+            stick_to_bottom,
+        } = self;
+
+        let mut state: State = ui.data_mut(|d| d.get_temp(id)).unwrap_or_default();
+        let estimated_height = estimated_height.unwrap_or(ui.spacing().interact_size.y);
+        let num_seen = *state.num_seen.get_or_insert(num_messages);
+        state.heights.resize(num_messages, f32::NAN);
+        let scroll_area_id = id.with("scroll_area");
+
+        let output = egui::ScrollArea::vertical()
+            .id_salt(scroll_area_id)
+            .auto_shrink(false)
+            .stick_to_bottom(stick_to_bottom)
+            .show_viewport(ui, |ui, viewport| {
+                let width = ui.available_width();
+                if state.width != width {
+                    // Text wraps differently, so all heights change:
+                    state.heights.fill(f32::NAN);
+                    state.width = width;
+                }
+                let spacing = ui.spacing().item_spacing.y;
+                let height_of = |h: f32| if h.is_nan() { estimated_height } else { h };
+
+                // Skip the messages above the viewport:
+                let mut index = 0;
+                let mut skipped = 0.0;
+                while index < num_messages {
+                    let height = height_of(state.heights[index]) + spacing;
+                    if viewport.top() < skipped + height {
+                        break;
+                    }
+                    skipped += height;
+                    index += 1;
+                }
+                ui.add_space(skipped);
+
+                let mut results = Vec::new();
+                let mut y = skipped;
+                while index < num_messages && y <= viewport.bottom() {
+                    let response = ui.scope(|ui| add_message(ui, index));
+                    let height = response.response.rect.height();
+                    state.heights[index] = height;
+                    results.push(response.inner);
+                    y += height + spacing;
+                    index += 1;
+                }
+
+                // Make room for the messages below the viewport:
+                let below: f32 = state.heights[index..]
+                    .iter()
+                    .map(|&h| height_of(h) + spacing)
+                    .sum();
+                if 0.0 < below {
+                    ui.add_space(below - spacing);
+                }
+
+                results
+            });
+
+        let at_bottom =
+            output.content_size.y <= output.state.offset.y + output.inner_rect.height() + 1.0;
+        if at_bottom {
+            state.num_seen = Some(num_messages);
+        }
+        let num_new = num_messages.saturating_sub(num_seen);
+
+        if !at_bottom && 0 < num_new {
+            let text = if num_new == 1 {
+                "⬇ 1 new message".to_owned()
+            } else {
+                format!("⬇ {num_new} new messages")
+            };
+            let button = Button::new(text).corner_radius(ui.spacing().interact_size.y);
+            let size = vec2(
+                160.0_f32.min(output.inner_rect.width()),
+                ui.spacing().interact_size.y + 4.0,
+            );
+            let rect =
+                Rect::from_center_size(output.inner_rect.center_bottom() - vec2(0.0, size.y), size);
+            if ui.put(rect, button).clicked() {
+                let mut scroll_state = output.state;
+                scroll_state.offset.y = output.content_size.y;
+                scroll_state.store(ui.ctx(), output.id);
+                state.num_seen = Some(num_messages);
+                ui.ctx().request_repaint();
+            }
+        }
+
+        ui.data_mut(|d| d.insert_temp(id, state));
+
+        MessageListResponse {
+            scroll_area: output,
+            at_bottom,
+            num_new,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_shows_visible_messages() {
+        let ctx = egui::Context::default();
+        let mut shown = Vec::new();
+        for _ in 0..3 {
+            shown.clear();
+            let input = egui::RawInput {
+                screen_rect: Some(Rect::from_min_size(
+                    egui::Pos2::ZERO,
+                    egui::Vec2::new(400.0, 300.0),
+                )),
+                ..Default::default()
+            };
+            let _ = ctx.run(input, |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    MessageList::new("log").show(ui, 10_000, |ui, index| {
+                        shown.push(index);
+                        ui.label(format!("Message {index}"));
+                    });
+                });
+            });
+        }
+
+        assert!(!shown.is_empty());
+        assert!(shown.len() < 50, "Showed {} messages", shown.len());
+        // Stuck to the bottom:
+        assert_eq!(shown.last(), Some(&9_999));
+    }
+}
@@ -0,0 +1,303 @@
+//! A scaled-down overview of a large [`egui::ScrollArea`] or custom canvas,
+//! with a draggable rectangle showing the part that is currently in view.
+//!
+//! ```
+//! # egui::__run_test_ui(|ui| {
+//! use egui_extras::minimap::{Minimap, MinimapCapture};
+//!
+//! ui.horizontal(|ui| {
+//!     let capture = MinimapCapture::begin(ui);
+//!     let output = egui::ScrollArea::vertical()
+//!         .max_width(300.0)
+//!         .show(ui, |ui| {
+//!             for i in 0..100 {
+//!                 ui.label(format!("Line {i}"));
+//!             }
+//!         });
+//!     let shapes = capture.end(ui, &output);
+//!
+//!     // Dragging the minimap scrolls the scroll area:
+//!     Minimap::for_scroll_area(&output).shapes(shapes).show(ui);
+//! });
+//! # });
+//! ```
+
+use egui::{
+    Color32, Id, LayerId, Painter, Pos2, Rect, Response, Sense, Shape, Stroke, StrokeKind, Ui,
+    Vec2, emath::RectTransform, emath::TSTransform, layers::ShapeIdx,
+    scroll_area::ScrollAreaOutput,
+};
+
+/// Records the shapes painted by a [`egui::ScrollArea`], so they can be shown in a [`Minimap`].
+///
+/// Only the shapes that were actually painted are captured, which usually means
+/// the part of the content that is currently visible (plus whatever the widgets
+/// painted despite being clipped). For a full overview of large content,
+/// draw a simplified version with [`Minimap::overview`] instead.
+pub struct MinimapCapture {
+    layer_id: LayerId,
+    start: ShapeIdx,
+}
+
+impl MinimapCapture {
+    /// Call this right before showing the [`egui::ScrollArea`].
+    pub fn begin(ui: &Ui) -> Self {
+        let layer_id = ui.layer_id();
+        let start = ui
+            .ctx()
+            .graphics_mut(|graphics| graphics.entry(layer_id).next_idx());
+        Self { layer_id, start }
+    }
+
+    /// Call this right after showing the [`egui::ScrollArea`].
+    ///
+    /// Returns the captured shapes in content coordinates,
+    /// i.e. relative to the top left of the scrolled content.
+    pub fn end<R>(self, ui: &Ui, output: &ScrollAreaOutput<R>) -> Vec<Shape> {
+        let Self { layer_id, start } = self;
+        let content_origin = output.inner_rect.min - output.state.offset;
+        let to_content = TSTransform::from_translation(-content_origin.to_vec2());
+        ui.ctx().graphics(|graphics| {
+            graphics.get(layer_id).map_or_else(Vec::new, |list| {
+                list.all_entries()
+                    .skip(start.0)
+                    .filter(|clipped| !matches!(clipped.shape, Shape::Callback(_)))
+                    .map(|clipped| {
+                        let mut shape = clipped.shape.clone();
+                        shape.transform(to_content);
+                        shape
+                    })
+                    .collect()
+            })
+        })
+    }
+}
+
+/// The result of showing a [`Minimap`].
+pub struct MinimapResponse {
+    /// The response of the whole minimap.
+    pub response: Response,
+
+    /// If the user moved the viewport rectangle, this is the new scroll offset,
+    /// i.e. the top left of the viewport in content coordinates.
+    ///
+    /// When using [`Minimap::for_scroll_area`] this has already been applied to the scroll area.
+    pub scroll_offset: Option<Vec2>,
+}
+
+type OverviewFn<'a> = Box<dyn FnOnce(&Painter, &RectTransform) + 'a>;
+
+/// A scaled-down overview of some large content.
+///
+/// See the [module-level docs](self) for an example.
+#[must_use = "You should call .show()"]
+pub struct Minimap<'a> {
+    content_size: Vec2,
+    viewport: Rect,
+    scroll_area_id: Option<Id>,
+    max_size: Vec2,
+    shapes: Vec<Shape>,
+    overview: Option<OverviewFn<'a>>,
+}
+
+impl<'a> Minimap<'a> {
+    /// A minimap of content of the given size.
+    ///
+    /// `viewport` is the part of the content currently in view, in content coordinates.
+    /// Use [`MinimapResponse::scroll_offset`] to move it when the user drags the minimap.
+    pub fn new(content_size: Vec2, viewport: Rect) -> Self {
+        Self {
+            content_size,
+            viewport,
+            scroll_area_id: None,
+            max_size: Vec2::new(120.0, f32::INFINITY),
+            shapes: Vec::new(),
+            overview: None,
+        }
+    }
+
+    /// A minimap that is kept in sync with the given [`egui::ScrollArea`].
+    ///
+    /// Dragging the minimap scrolls the scroll area.
+    pub fn for_scroll_area<R>(output: &ScrollAreaOutput<R>) -> Self {
+        let viewport = Rect::from_min_size(output.state.offset.to_pos2(), output.inner_rect.size());
+        Self {
+            scroll_area_id: Some(output.id),
+            ..Self::new(output.content_size, viewport)
+        }
+    }
+
+    /// The largest size of the minimap. The content is scaled down uniformly to fit.
+    ///
+    /// Default: 120 points wide, and as tall as the available space.
+    #[inline]
+    pub fn max_size(mut self, max_size: Vec2) -> Self {
+        self.max_size = max_size;
+        self
+    }
+
+    /// Shapes to show, in content coordinates, e.g. from [`MinimapCapture::end`].
+    #[inline]
+    pub fn shapes(mut self, shapes: Vec<Shape>) -> Self {
+        self.shapes = shapes;
+        self
+    }
+
+    /// Draw a simplified overview of the content.
+    ///
+    /// The [`RectTransform`] maps from content coordinates to the minimap on screen.
+    #[inline]
+    pub fn overview(mut self, overview: impl FnOnce(&Painter, &RectTransform) + 'a) -> Self {
+        self.overview = Some(Box::new(overview));
+        self
+    }
+
+    /// Show the minimap.
+    pub fn show(self, ui: &mut Ui) -> MinimapResponse {
+        let Self {
+            content_size,
+            viewport,
+            scroll_area_id,
+            max_size,
+            shapes,
+            overview,
+        } = self;
+
+        let content_size = content_size.max(viewport.size()).max(Vec2::splat(1.0));
+        let max_size = max_size.min(ui.available_size()).max(Vec2::splat(1.0));
+        let scale = (max_size / content_size).min_elem();
+        let (rect, response) =
+            ui.allocate_exact_size(content_size * scale, Sense::click_and_drag());
+
+        let content_rect = Rect::from_min_size(Pos2::ZERO, content_size);
+        let to_screen = RectTransform::from_to(content_rect, rect);
+        let viewport_on_screen = to_screen.transform_rect(viewport);
+
+        // Where on the viewport rectangle the user grabbed it:
+        let grab_id = response.id.with("grab");
+        if response.drag_started() || response.clicked() {
+            let grab = response
+                .interact_pointer_pos()
+                .filter(|pos| viewport_on_screen.contains(*pos))
+                .map_or(viewport_on_screen.size() / 2.0, |pos| {
+                    pos - viewport_on_screen.min
+                });
+            ui.ctx().data_mut(|d| d.insert_temp(grab_id, grab));
+        }
+
+        let mut scroll_offset = None;
+        if response.dragged() || response.clicked() {
+            if let Some(pointer) = response.interact_pointer_pos() {
+                let grab = ui
+                    .ctx()
+                    .data(|d| d.get_temp::<Vec2>(grab_id))
+                    .unwrap_or(viewport_on_screen.size() / 2.0);
+                let max_offset = (content_size - viewport.size()).max(Vec2::ZERO);
+                let offset = to_screen.inverse().transform_pos(pointer - grab).to_vec2();
+                let offset = offset.clamp(Vec2::ZERO, max_offset);
+                if offset != viewport.min.to_vec2() {
+                    scroll_offset = Some(offset);
+                }
+            }
+        }
+        if response.drag_stopped() {
+            ui.ctx().data_mut(|d| d.remove::<Vec2>(grab_id));
+        }
+
+        if let (Some(offset), Some(id)) = (scroll_offset, scroll_area_id) {
+            let mut state = egui::scroll_area::State::load(ui.ctx(), id).unwrap_or_default();
+            state.offset = offset;
+            state.store(ui.ctx(), id);
+            ui.ctx().request_repaint();
+        }
+
+        if ui.is_rect_visible(rect) {
+            let visuals = ui.visuals();
+            let painter = ui.painter_at(rect);
+            painter.rect_filled(rect, 0.0, visuals.extreme_bg_color);
+
+            if !shapes.is_empty() {
+                let to_minimap = TSTransform::new(rect.min.to_vec2(), scale);
+                painter.extend(shapes.into_iter().map(|mut shape| {
+                    shape.transform(to_minimap);
+                    shape
+                }));
+            }
+            if let Some(overview) = overview {
+                overview(&painter, &to_screen);
+            }
+
+            let viewport_on_screen = scroll_offset.map_or(viewport_on_screen, |offset| {
+                to_screen.transform_rect(Rect::from_min_size(offset.to_pos2(), viewport.size()))
+            });
+            let stroke = if response.hovered() || response.dragged() {
+                visuals.widgets.hovered.fg_stroke
+            } else {
+                Stroke::new(1.0, visuals.widgets.inactive.fg_stroke.color)
+            };
+            painter.rect(
+                viewport_on_screen,
+                2.0,
+                Color32::from_gray(128).gamma_multiply(0.15),
+                stroke,
+                StrokeKind::Inside,
+            );
+            painter.rect_stroke(
+                rect,
+                0.0,
+                visuals.widgets.noninteractive.bg_stroke,
+                StrokeKind::Inside,
+            );
+        }
+
+        MinimapResponse {
+            response,
+            scroll_offset,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn click_scrolls_scroll_area() {
+        let ctx = egui::Context::default();
+        let run = |events: Vec<egui::Event>| {
+            let input = egui::RawInput {
+                screen_rect: Some(Rect::from_min_size(Pos2::ZERO, Vec2::new(800.0, 600.0))),
+                events,
+                ..Default::default()
+            };
+            let mut result = None;
+            let _ = ctx.run(input, |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    ui.horizontal_top(|ui| {
+                        let output = egui::ScrollArea::vertical()
+                            .max_width(300.0)
+                            .show(ui, |ui| ui.allocate_space(Vec2::new(300.0, 10_000.0)));
+                        let minimap = Minimap::for_scroll_area(&output).show(ui);
+                        result = Some((output.state.offset, minimap.response.rect));
+                    });
+                });
+            });
+            result.unwrap()
+        };
+
+        let (offset, minimap_rect) = run(vec![]);
+        assert_eq!(offset, Vec2::ZERO);
+
+        let pos = minimap_rect.center_bottom() - Vec2::new(0.0, 1.0);
+        let button = |pressed| egui::Event::PointerButton {
+            pos,
+            button: egui::PointerButton::Primary,
+            pressed,
+            modifiers: Default::default(),
+        };
+        run(vec![egui::Event::PointerMoved(pos), button(true)]);
+        run(vec![button(false)]);
+        let (offset, _) = run(vec![]);
+        assert!(9_000.0 < offset.y, "offset: {offset:?}");
+    }
+}
@@ -0,0 +1,726 @@
+//! A node graph editor: nodes with typed pins, connected by wires.
+//!
+//! This is a foundation for e.g. shader, material or blueprint editors.
+//! The [`Graph`] is your data model (and can be serialized with the `serde` feature),
+//! and the [`NodeGraphEditor`] shows it and lets the user edit it:
+//!
+//! * Pan with the secondary or middle mouse button, zoom with ctrl + scroll (or pinch).
+//! * Drag nodes by their title. Drag on the background to box-select.
+//! * Drag from a pin to another pin of the same type to connect them.
+//!   Drag away from a connected input to disconnect it.
+//! * Click a wire to select it, press Delete to remove the selection.
+//!
+//! ```
+//! # egui::__run_test_ui(|ui| {
+//! use egui_extras::node_graph::{Graph, Node, NodeGraphEditor, Pin};
+//!
+//! let mut graph = Graph::default();
+//! let a = graph.add_node(Node::new("Number", egui::pos2(0.0, 0.0), 1.0_f32).output(Pin::new("value", "f32")));
+//! let b = graph.add_node(Node::new("Print", egui::pos2(200.0, 0.0), 0.0_f32).input(Pin::new("value", "f32")));
+//! graph.connect(a, 0, b, 0);
+//!
+//! let mut editor = NodeGraphEditor::default();
+//! editor.ui(ui, &mut graph, |ui, _id, value| {
+//!     ui.add(egui::DragValue::new(value));
+//! });
+//! # });
+//! ```
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use egui::{
+    Color32, Id, Pos2, Rect, Sense, Shape, Stroke, Ui, UiBuilder, Vec2,
+    emath::GuiRounding as _,
+    epaint::{CubicBezierShape, StrokeKind},
+    pos2, vec2,
+};
+
+/// Identifies a node in a [`Graph`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct NodeId(pub u64);
+
+/// An input or output of a [`Node`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Pin {
+    /// Shown next to the pin.
+    pub name: String,
+
+    /// Only pins of the same type can be connected.
+    pub typ: String,
+}
+
+impl Pin {
+    pub fn new(name: impl Into<String>, typ: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            typ: typ.into(),
+        }
+    }
+
+    /// A color based on the type of the pin.
+    pub fn color(&self) -> Color32 {
+        let hue = (egui::Id::new(&self.typ).value() % 1000) as f32 / 1000.0;
+        egui::ecolor::Hsva::new(hue, 0.6, 0.9, 1.0).into()
+    }
+}
+
+/// A node in a [`Graph`], with your own data `N`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Node<N> {
+    pub title: String,
+
+    /// Top left corner, in graph coordinates.
+    pub pos: Pos2,
+
+    pub inputs: Vec<Pin>,
+    pub outputs: Vec<Pin>,
+
+    /// Your data, shown below the pins.
+    pub data: N,
+}
+
+impl<N> Node<N> {
+    pub fn new(title: impl Into<String>, pos: Pos2, data: N) -> Self {
+        Self {
+            title: title.into(),
+            pos,
+            inputs: Vec::new(),
+            outputs: Vec::new(),
+            data,
+        }
+    }
+
+    /// Add an input pin.
+    #[inline]
+    pub fn input(mut self, pin: Pin) -> Self {
+        self.inputs.push(pin);
+        self
+    }
+
+    /// Add an output pin.
+    #[inline]
+    pub fn output(mut self, pin: Pin) -> Self {
+        self.outputs.push(pin);
+        self
+    }
+}
+
+/// A connection from an output of one node to an input of another.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Wire {
+    pub from_node: NodeId,
+    pub from_output: usize,
+    pub to_node: NodeId,
+    pub to_input: usize,
+}
+
+/// The data model: nodes and the wires between them.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Graph<N> {
+    pub nodes: BTreeMap<NodeId, Node<N>>,
+    pub wires: Vec<Wire>,
+    next_id: u64,
+}
+
+impl<N> Default for Graph<N> {
+    fn default() -> Self {
+        Self {
+            nodes: Default::default(),
+            wires: Default::default(),
+            next_id: 0,
+        }
+    }
+}
+
+impl<N> Graph<N> {
+    pub fn add_node(&mut self, node: Node<N>) -> NodeId {
+        let id = NodeId(self.next_id);
+        self.next_id += 1;
+        self.nodes.insert(id, node);
+        id
+    }
+
+    /// Remove a node and all wires connected to it.
+    pub fn remove_node(&mut self, id: NodeId) -> Option<Node<N>> {
+        self.wires
+            .retain(|wire| wire.from_node != id && wire.to_node != id);
+        self.nodes.remove(&id)
+    }
+
+    /// Can this output be connected to that input?
+    ///
+    /// They must exist, be on different nodes, and have the same type.
+    pub fn can_connect(
+        &self,
+        from_node: NodeId,
+        from_output: usize,
+        to_node: NodeId,
+        to_input: usize,
+    ) -> bool {
+        if from_node == to_node {
+            return false;
+        }
+        let from = self
+            .nodes
+            .get(&from_node)
+            .and_then(|node| node.outputs.get(from_output));
+        let to = self
+            .nodes
+            .get(&to_node)
+            .and_then(|node| node.inputs.get(to_input));
+        matches!((from, to), (Some(from), Some(to)) if from.typ == to.typ)
+    }
+
+    /// Connect an output to an input, replacing any wire already going into that input.
+    ///
+    /// Returns the new wire, or `None` if the pins can't be connected.
+    pub fn connect(
+        &mut self,
+        from_node: NodeId,
+        from_output: usize,
+        to_node: NodeId,
+        to_input: usize,
+    ) -> Option<Wire> {
+        if !self.can_connect(from_node, from_output, to_node, to_input) {
+            return None;
+        }
+        self.wires
+            .retain(|wire| wire.to_node != to_node || wire.to_input != to_input);
+        let wire = Wire {
+            from_node,
+            from_output,
+            to_node,
+            to_input,
+        };
+        self.wires.push(wire);
+        Some(wire)
+    }
+
+    /// Remove a wire. Returns `true` if it existed.
+    pub fn disconnect(&mut self, wire: &Wire) -> bool {
+        let len = self.wires.len();
+        self.wires.retain(|w| w != wire);
+        self.wires.len() != len
+    }
+
+    /// The wire going into the given input, if any.
+    pub fn wire_into(&self, node: NodeId, input: usize) -> Option<Wire> {
+        self.wires
+            .iter()
+            .find(|wire| wire.to_node == node && wire.to_input == input)
+            .copied()
+    }
+}
+
+/// What happened in [`NodeGraphEditor::ui`] this frame.
+#[derive(Clone, Debug, Default)]
+pub struct NodeGraphResponse {
+    /// Wires the user added.
+    pub connected: Vec<Wire>,
+
+    /// Wires the user removed.
+    pub disconnected: Vec<Wire>,
+
+    /// Nodes the user deleted.
+    pub removed_nodes: Vec<NodeId>,
+
+    /// The selected nodes changed.
+    pub selection_changed: bool,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+enum PinRef {
+    Input(NodeId, usize),
+    Output(NodeId, usize),
+}
+
+/// Shows and edits a [`Graph`]. Keep this around between frames.
+#[derive(Clone, Debug)]
+pub struct NodeGraphEditor {
+    id_salt: Id,
+
+    /// The visible part of the graph, in graph coordinates.
+    scene_rect: Rect,
+
+    selected_nodes: BTreeSet<NodeId>,
+    selected_wire: Option<Wire>,
+
+    /// The pin a new wire is being dragged from.
+    dragged_wire: Option<PinRef>,
+
+    /// Where a box selection started, in graph coordinates.
+    box_select_start: Option<Pos2>,
+}
+
+impl Default for NodeGraphEditor {
+    fn default() -> Self {
+        Self {
+            id_salt: Id::new("node_graph"),
+            scene_rect: Rect::ZERO,
+            selected_nodes: Default::default(),
+            selected_wire: None,
+            dragged_wire: None,
+            box_select_start: None,
+        }
+    }
+}
+
+const PIN_RADIUS: f32 = 5.0;
+const WIRE_WIDTH: f32 = 2.0;
+
+impl NodeGraphEditor {
+    /// Needed if you show more than one graph in the same [`Ui`].
+    #[inline]
+    pub fn id_salt(mut self, id_salt: impl std::hash::Hash) -> Self {
+        self.id_salt = Id::new(id_salt);
+        self
+    }
+
+    pub fn selected_nodes(&self) -> &BTreeSet<NodeId> {
+        &self.selected_nodes
+    }
+
+    pub fn select_nodes(&mut self, nodes: impl IntoIterator<Item = NodeId>) {
+        self.selected_nodes = nodes.into_iter().collect();
+    }
+
+    pub fn selected_wire(&self) -> Option<Wire> {
+        self.selected_wire
+    }
+
+    /// Fit the whole graph in view on the next frame.
+    pub fn reset_view(&mut self) {
+        self.scene_rect = Rect::ZERO;
+    }
+
+    /// Show the graph, filling the available space.
+    ///
+    /// `node_ui` is called for each node to show its data below its pins.
+    pub fn ui<N>(
+        &mut self,
+        ui: &mut Ui,
+        graph: &mut Graph<N>,
+        mut node_ui: impl FnMut(&mut Ui, NodeId, &mut N),
+    ) -> NodeGraphResponse {
+        let mut response = NodeGraphResponse::default();
+        let mut scene_rect = self.scene_rect;
+
+        egui::Frame::canvas(ui.style()).show(ui, |ui| {
+            egui::Scene::new()
+                .zoom_range(0.1..=2.0)
+                .drag_pan_buttons(
+                    egui::containers::DragPanButtons::SECONDARY
+                        | egui::containers::DragPanButtons::MIDDLE,
+                )
+                .show(ui, &mut scene_rect, |ui| {
+                    self.graph_ui(ui, graph, &mut node_ui, &mut response);
+                });
+        });
+
+        self.scene_rect = scene_rect;
+        response
+    }
+
+    fn graph_ui<N>(
+        &mut self,
+        ui: &mut Ui,
+        graph: &mut Graph<N>,
+        node_ui: &mut impl FnMut(&mut Ui, NodeId, &mut N),
+        response: &mut NodeGraphResponse,
+    ) {
+        let background = ui.response();
+        let id = ui.id().with(self.id_salt);
+
+        // Wires are painted below the nodes:
+        let wires_shape_idx = ui.painter().add(Shape::Noop);
+
+        let mut pins: BTreeMap<PinRef, (Pos2, Color32)> = Default::default();
+        let mut node_rects: Vec<(NodeId, Rect)> = Vec::new();
+        let mut drag_delta = None;
+
+        for (&node_id, node) in &mut graph.nodes {
+            let is_selected = self.selected_nodes.contains(&node_id);
+            let node_id_egui = id.with(node_id);
+
+            let mut title_bottom = 0.0;
+            let mut pin_rows = Vec::new();
+
+            let frame_rect = ui
+                .scope_builder(
+                    UiBuilder::new()
+                        .id_salt(node_id)
+                        .max_rect(Rect::from_min_size(node.pos, vec2(300.0, 10_000.0))),
+                    |ui| {
+                        let mut frame = egui::Frame::window(ui.style());
+                        if is_selected {
+                            frame.stroke = ui.visuals().selection.stroke;
+                        }
+                        frame
+                            .show(ui, |ui| {
+                                ui.set_min_width(80.0);
+                                ui.strong(&node.title);
+                                title_bottom = ui.min_rect().bottom();
+                                ui.separator();
+
+                                for (i, pin) in node.inputs.iter().enumerate() {
+                                    let rect = ui.label(format!("⏵ {}", pin.name)).rect;
+                                    pin_rows.push((
+                                        PinRef::Input(node_id, i),
+                                        rect.center().y,
+                                        pin.color(),
+                                    ));
+                                }
+                                for (i, pin) in node.outputs.iter().enumerate() {
+                                    let rect = ui.label(format!("{} ⏵", pin.name)).rect;
+                                    pin_rows.push((
+                                        PinRef::Output(node_id, i),
+                                        rect.center().y,
+                                        pin.color(),
+                                    ));
+                                }
+
+                                node_ui(ui, node_id, &mut node.data);
+                            })
+                            .response
+                            .rect
+                    },
+                )
+                .inner;
+
+            // Drag by the title:
+            let title_rect =
+                Rect::from_min_max(frame_rect.min, pos2(frame_rect.max.x, title_bottom));
+            let title_response = ui
+                .interact(
+                    title_rect,
+                    node_id_egui.with("title"),
+                    Sense::click_and_drag(),
+                )
+                .on_hover_cursor(egui::CursorIcon::Grab);
+
+            if title_response.clicked() || title_response.drag_started() {
+                let additive = ui.input(|i| i.modifiers.shift || i.modifiers.command);
+                if additive && title_response.clicked() {
+                    if !self.selected_nodes.remove(&node_id) {
+                        self.selected_nodes.insert(node_id);
+                    }
+                    response.selection_changed = true;
+                } else if !is_selected {
+                    self.selected_nodes = std::iter::once(node_id).collect();
+                    response.selection_changed = true;
+                }
+                self.selected_wire = None;
+            }
+            if title_response.dragged() {
+                drag_delta = Some(title_response.drag_delta());
+            }
+
+            for (pin, y, color) in pin_rows {
+                let x = match pin {
+                    PinRef::Input(..) => frame_rect.left(),
+                    PinRef::Output(..) => frame_rect.right(),
+                };
+                pins.insert(pin, (pos2(x, y), color));
+            }
+            node_rects.push((node_id, frame_rect));
+        }
+
+        // Move all selected nodes together:
+        if let Some(delta) = drag_delta {
+            for node_id in &self.selected_nodes {
+                if let Some(node) = graph.nodes.get_mut(node_id) {
+                    node.pos += delta;
+                }
+            }
+        }
+
+        self.pins_ui(ui, id, graph, &pins, response);
+        let wire_shapes = self.wires_ui(ui, graph, &pins, &background);
+        ui.painter().set(wires_shape_idx, wire_shapes);
+
+        self.box_select_ui(ui, &background, &node_rects, response);
+
+        // Delete the selection:
+        let delete = ui.memory(|mem| mem.focused().is_none())
+            && ui
+                .input(|i| i.key_pressed(egui::Key::Delete) || i.key_pressed(egui::Key::Backspace));
+        if delete && ui.rect_contains_pointer(ui.clip_rect()) {
+            if let Some(wire) = self.selected_wire.take() {
+                if graph.disconnect(&wire) {
+                    response.disconnected.push(wire);
+                }
+            }
+            for node_id in std::mem::take(&mut self.selected_nodes) {
+                response.disconnected.extend(
+                    graph
+                        .wires
+                        .iter()
+                        .filter(|wire| wire.from_node == node_id || wire.to_node == node_id),
+                );
+                if graph.remove_node(node_id).is_some() {
+                    response.removed_nodes.push(node_id);
+                    response.selection_changed = true;
+                }
+            }
+        }
+    }
+
+    fn pins_ui<N>(
+        &mut self,
+        ui: &Ui,
+        id: Id,
+        graph: &mut Graph<N>,
+        pins: &BTreeMap<PinRef, (Pos2, Color32)>,
+        response: &mut NodeGraphResponse,
+    ) {
+        let painter = ui.painter();
+        let mut released_on = None;
+
+        for (&pin, &(pos, color)) in pins {
+            let pin_response = ui.interact(
+                Rect::from_center_size(pos, Vec2::splat(3.0 * PIN_RADIUS)),
+                id.with(pin),
+                Sense::drag(),
+            );
+
+            let connected = match pin {
+                PinRef::Input(node, input) => graph.wire_into(node, input).is_some(),
+                PinRef::Output(node, output) => graph
+                    .wires
+                    .iter()
+                    .any(|wire| wire.from_node == node && wire.from_output == output),
+            };
+            let radius = if pin_response.hovered() {
+                PIN_RADIUS * 1.3
+            } else {
+                PIN_RADIUS
+            };
+            if connected {
+                painter.circle_filled(pos, radius, color);
+            } else {
+                painter.circle(
+                    pos,
+                    radius,
+                    ui.visuals().extreme_bg_color,
+                    Stroke::new(WIRE_WIDTH, color),
+                );
+            }
+
+            if pin_response.drag_started() {
+                // Dragging a connected input picks up its wire:
+                self.dragged_wire = match pin {
+                    PinRef::Input(node, input) => match graph.wire_into(node, input) {
+                        Some(wire) => {
+                            graph.disconnect(&wire);
+                            response.disconnected.push(wire);
+                            Some(PinRef::Output(wire.from_node, wire.from_output))
+                        }
+                        None => Some(pin),
+                    },
+                    PinRef::Output(..) => Some(pin),
+                };
+            }
+            if pin_response.drag_stopped() {
+                released_on = Some(pin_response.interact_pointer_pos());
+            }
+        }
+
+        let Some(from) = self.dragged_wire else {
+            return;
+        };
+        let Some(&(from_pos, color)) = pins.get(&from) else {
+            self.dragged_wire = None;
+            return;
+        };
+
+        let pointer = ui.ctx().pointer_latest_pos().map(|pos| {
+            match ui.ctx().layer_transform_from_global(ui.layer_id()) {
+                Some(from_global) => from_global * pos,
+                None => pos,
+            }
+        });
+
+        if let Some(released_at) = released_on {
+            self.dragged_wire = None;
+            let Some(released_at) = released_at.or(pointer) else {
+                return;
+            };
+            let target = pins
+                .iter()
+                .filter(|(_, (pos, _))| pos.distance(released_at) < 3.0 * PIN_RADIUS)
+                .map(|(pin, _)| *pin)
+                .next();
+            let wire = match (from, target) {
+                (PinRef::Output(from_node, output), Some(PinRef::Input(to_node, input)))
+                | (PinRef::Input(to_node, input), Some(PinRef::Output(from_node, output))) => {
+                    graph.connect(from_node, output, to_node, input)
+                }
+                _ => None,
+            };
+            response.connected.extend(wire);
+        } else if let Some(pointer) = pointer {
+            let (a, b) = match from {
+                PinRef::Output(..) => (from_pos, pointer),
+                PinRef::Input(..) => (pointer, from_pos),
+            };
+            painter.add(wire_shape(a, b, Stroke::new(WIRE_WIDTH, color)));
+        }
+    }
+
+    fn wires_ui<N>(
+        &mut self,
+        ui: &Ui,
+        graph: &Graph<N>,
+        pins: &BTreeMap<PinRef, (Pos2, Color32)>,
+        background: &egui::Response,
+    ) -> Shape {
+        let hover_pos = background.hover_pos();
+        let clicked = background.clicked();
+        let mut hovered_wire = None;
+        let mut shapes = Vec::with_capacity(graph.wires.len());
+
+        for wire in &graph.wires {
+            let (Some(&(from, color)), Some(&(to, _))) = (
+                pins.get(&PinRef::Output(wire.from_node, wire.from_output)),
+                pins.get(&PinRef::Input(wire.to_node, wire.to_input)),
+            ) else {
+                continue;
+            };
+
+            let hovered =
+                hover_pos.is_some_and(|pos| wire_contains(from, to, pos, 2.0 * WIRE_WIDTH));
+            if hovered {
+                hovered_wire = Some(*wire);
+            }
+
+            let mut stroke = Stroke::new(WIRE_WIDTH, color);
+            if self.selected_wire == Some(*wire) {
+                stroke = Stroke::new(2.0 * WIRE_WIDTH, ui.visuals().selection.stroke.color);
+            } else if hovered {
+                stroke.width *= 1.5;
+            }
+            shapes.push(wire_shape(from, to, stroke));
+        }
+
+        if clicked {
+            self.selected_wire = hovered_wire;
+            if hovered_wire.is_some() {
+                self.selected_nodes.clear();
+            }
+        }
+        if hovered_wire.is_some() {
+            ui.ctx().set_cursor_icon(egui::CursorIcon::PointingHand);
+        }
+
+        Shape::Vec(shapes)
+    }
+
+    fn box_select_ui(
+        &mut self,
+        ui: &Ui,
+        background: &egui::Response,
+        node_rects: &[(NodeId, Rect)],
+        response: &mut NodeGraphResponse,
+    ) {
+        if background.drag_started_by(egui::PointerButton::Primary) {
+            self.box_select_start = background.interact_pointer_pos();
+        }
+
+        if background.clicked() && self.selected_wire.is_none() && !self.selected_nodes.is_empty() {
+            self.selected_nodes.clear();
+            response.selection_changed = true;
+        }
+
+        let Some(start) = self.box_select_start else {
+            return;
+        };
+        let Some(current) = background.interact_pointer_pos() else {
+            self.box_select_start = None;
+            return;
+        };
+
+        let selection_rect = Rect::from_two_pos(start, current);
+        let stroke = ui.visuals().selection.stroke;
+        ui.painter().rect(
+            selection_rect.round_ui(),
+            0.0,
+            stroke.color.gamma_multiply(0.1),
+            stroke,
+            StrokeKind::Inside,
+        );
+
+        if background.drag_stopped() {
+            let additive = ui.input(|i| i.modifiers.shift || i.modifiers.command);
+            if !additive {
+                self.selected_nodes.clear();
+            }
+            self.selected_nodes.extend(
+                node_rects
+                    .iter()
+                    .filter(|(_, rect)| selection_rect.intersects(*rect))
+                    .map(|(id, _)| *id),
+            );
+            self.selected_wire = None;
+            self.box_select_start = None;
+            response.selection_changed = true;
+        }
+    }
+}
+
+fn wire_bezier(from: Pos2, to: Pos2, stroke: Stroke) -> CubicBezierShape {
+    let bend = ((to.x - from.x).abs() * 0.5).max(50.0);
+    CubicBezierShape::from_points_stroke(
+        [from, from + vec2(bend, 0.0), to - vec2(bend, 0.0), to],
+        false,
+        Color32::TRANSPARENT,
+        stroke,
+    )
+}
+
+fn wire_shape(from: Pos2, to: Pos2, stroke: Stroke) -> Shape {
+    wire_bezier(from, to, stroke).into()
+}
+
+/// Is `pos` within `max_distance` of the wire from `from` to `to`?
+fn wire_contains(from: Pos2, to: Pos2, pos: Pos2, max_distance: f32) -> bool {
+    wire_bezier(from, to, Stroke::NONE).distance_to_pos(pos) <= max_distance
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn connect_typed_pins() {
+        let mut graph = Graph::default();
+        let a = graph.add_node(
+            Node::new("a", Pos2::ZERO, ())
+                .output(Pin::new("x", "f32"))
+                .output(Pin::new("s", "str")),
+        );
+        let b = graph.add_node(Node::new("b", Pos2::ZERO, ()).input(Pin::new("x", "f32")));
+
+        assert!(graph.connect(a, 1, b, 0).is_none(), "Type mismatch");
+        assert!(graph.connect(a, 0, a, 0).is_none(), "Same node");
+        assert!(graph.connect(a, 0, b, 0).is_some());
+        assert!(graph.connect(a, 0, b, 0).is_some());
+        assert_eq!(graph.wires.len(), 1, "An input only takes one wire");
+
+        graph.remove_node(a);
+        assert!(graph.wires.is_empty());
+    }
+
+    #[test]
+    fn wire_hit_test() {
+        let from = pos2(0.0, 0.0);
+        let to = pos2(200.0, 100.0);
+        assert!(wire_contains(from, to, pos2(100.0, 50.0), 2.0));
+        assert!(wire_contains(from, to, pos2(1.0, 0.5), 2.0));
+        assert!(!wire_contains(from, to, pos2(100.0, 0.0), 2.0));
+    }
+}
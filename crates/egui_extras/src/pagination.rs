@@ -0,0 +1,294 @@
+//! Helpers for long lists: page-by-page with [`Pagination`],
+//! or loading more as the user scrolls with [`InfiniteScroll`].
+
+use egui::{Button, DragValue, Response, Ui, Widget};
+
+/// Page number buttons with previous/next buttons and ellipsis, e.g. `⏴ 1 … 4 5 6 … 20 ⏵`.
+///
+/// Pages are zero-based, but shown one-based.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut page = 0;
+/// use egui_extras::pagination::Pagination;
+///
+/// if ui.add(Pagination::new(&mut page, 20)).changed() {
+///     // fetch the new page…
+/// }
+/// # });
+/// ```
+#[must_use = "You should put this widget in a ui with `ui.add(widget);`"]
+pub struct Pagination<'a> {
+    page: &'a mut usize,
+    num_pages: usize,
+    siblings: usize,
+    jump_to_page: bool,
+}
+
+impl<'a> Pagination<'a> {
+    pub fn new(page: &'a mut usize, num_pages: usize) -> Self {
+        Self {
+            page,
+            num_pages,
+            siblings: 1,
+            jump_to_page: false,
+        }
+    }
+
+    /// How many pages to show on each side of the current page.
+    ///
+    /// Default: 1.
+    #[inline]
+    pub fn siblings(mut self, siblings: usize) -> Self {
+        self.siblings = siblings;
+        self
+    }
+
+    /// Show a field for typing in the page to go to.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn jump_to_page(mut self, jump_to_page: bool) -> Self {
+        self.jump_to_page = jump_to_page;
+        self
+    }
+}
+
+impl Widget for Pagination<'_> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Self {
+            page,
+            num_pages,
+            siblings,
+            jump_to_page,
+        } = self;
+
+        let last_page = num_pages.saturating_sub(1);
+        *page = (*page).min(last_page);
+        let mut new_page = *page;
+
+        let mut response = ui
+            .horizontal(|ui| {
+                if ui
+                    .add_enabled(0 < *page, Button::new("⏴"))
+                    .on_hover_text("Previous page")
+                    .clicked()
+                {
+                    new_page = page.saturating_sub(1);
+                }
+
+                for item in page_items(*page, num_pages, siblings) {
+                    match item {
+                        Some(p) => {
+                            if ui
+                                .selectable_label(p == *page, (p + 1).to_string())
+                                .clicked()
+                            {
+                                new_page = p;
+                            }
+                        }
+                        None => {
+                            ui.weak("…");
+                        }
+                    }
+                }
+
+                if ui
+                    .add_enabled(*page < last_page, Button::new("⏵"))
+                    .on_hover_text("Next page")
+                    .clicked()
+                {
+                    new_page = *page + 1;
+                }
+
+                if jump_to_page && 1 < num_pages {
+                    ui.separator();
+                    ui.label("Go to");
+                    let mut one_based = new_page + 1;
+                    if ui
+                        .add(DragValue::new(&mut one_based).range(1..=num_pages))
+                        .changed()
+                    {
+                        new_page = one_based - 1;
+                    }
+                }
+            })
+            .response;
+
+        if new_page != *page {
+            *page = new_page;
+            response.mark_changed();
+        }
+        response
+    }
+}
+
+/// Which pages to show buttons for. `None` means an ellipsis.
+///
+/// The first and last page are always shown, together with `siblings` pages on each side
+/// of the current one. The total number of items stays the same as the current page moves,
+/// so the buttons don't jump around.
+fn page_items(page: usize, num_pages: usize, siblings: usize) -> Vec<Option<usize>> {
+    // first + last + current + siblings + two ellipses:
+    let max_items = 2 * siblings + 5;
+    if num_pages <= max_items {
+        return (0..num_pages).map(Some).collect();
+    }
+
+    let last = num_pages - 1;
+    // The number of pages shown next to the first or last page, when there is only one ellipsis:
+    let edge = 2 * siblings + 3;
+
+    let mut items = Vec::with_capacity(max_items);
+    if page <= siblings + 2 {
+        items.extend((0..edge).map(Some));
+        items.extend([None, Some(last)]);
+    } else if last - siblings - 2 <= page {
+        items.extend([Some(0), None]);
+        items.extend((num_pages - edge..num_pages).map(Some));
+    } else {
+        items.extend([Some(0), None]);
+        items.extend((page - siblings..=page + siblings).map(Some));
+        items.extend([None, Some(last)]);
+    }
+    items
+}
+
+// ----------------------------------------------------------------------------
+
+/// Load more items when the user scrolls near the end of a [`egui::ScrollArea`].
+///
+/// Show this as the last thing in the scroll area.
+/// While [`Self::loading`] is set, a spinner row is shown instead.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut items: Vec<String> = vec![];
+/// # let mut is_loading = false;
+/// use egui_extras::pagination::InfiniteScroll;
+///
+/// egui::ScrollArea::vertical().show(ui, |ui| {
+///     for item in &items {
+///         ui.label(item);
+///     }
+///     InfiniteScroll::new().loading(is_loading).show(ui, || {
+///         is_loading = true;
+///         // start fetching more items…
+///     });
+/// });
+/// # });
+/// ```
+#[must_use = "You should call .show()"]
+pub struct InfiniteScroll {
+    threshold: f32,
+    loading: bool,
+    has_more: bool,
+}
+
+impl Default for InfiniteScroll {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl InfiniteScroll {
+    pub fn new() -> Self {
+        Self {
+            threshold: 200.0,
+            loading: false,
+            has_more: true,
+        }
+    }
+
+    /// Start loading when the end is this close to coming into view, in points.
+    ///
+    /// Default: 200.
+    #[inline]
+    pub fn threshold(mut self, threshold: f32) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    /// Are more items being loaded right now?
+    ///
+    /// The `load_more` callback is not called while this is set.
+    #[inline]
+    pub fn loading(mut self, loading: bool) -> Self {
+        self.loading = loading;
+        self
+    }
+
+    /// Set this to `false` when there is nothing more to load.
+    ///
+    /// Default: `true`.
+    #[inline]
+    pub fn has_more(mut self, has_more: bool) -> Self {
+        self.has_more = has_more;
+        self
+    }
+
+    /// Calls `load_more` if the end of the contents is near the visible area.
+    ///
+    /// Returns the response of the loading row, if shown.
+    pub fn show(self, ui: &mut Ui, load_more: impl FnOnce()) -> Option<Response> {
+        let Self {
+            threshold,
+            loading,
+            has_more,
+        } = self;
+
+        if !has_more {
+            return None;
+        }
+
+        if loading {
+            return Some(
+                ui.horizontal(|ui| {
+                    ui.spinner();
+                    ui.weak("Loading…");
+                })
+                .response,
+            );
+        }
+
+        let end = ui.cursor().min;
+        let visible = ui.clip_rect();
+        let near_end = if ui.layout().main_dir().is_horizontal() {
+            end.x - visible.right() < threshold
+        } else {
+            end.y - visible.bottom() < threshold
+        };
+        if near_end {
+            load_more();
+            ui.ctx().request_repaint();
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::page_items;
+
+    fn show(page: usize, num_pages: usize) -> String {
+        page_items(page, num_pages, 1)
+            .into_iter()
+            .map(|item| item.map_or("…".to_owned(), |p| (p + 1).to_string()))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    #[test]
+    fn test_page_items() {
+        assert_eq!(show(0, 0), "");
+        assert_eq!(show(0, 5), "1 2 3 4 5");
+        assert_eq!(show(0, 7), "1 2 3 4 5 6 7");
+        assert_eq!(show(0, 20), "1 2 3 4 5 … 20");
+        assert_eq!(show(3, 20), "1 2 3 4 5 … 20");
+        assert_eq!(show(4, 20), "1 … 4 5 6 … 20");
+        assert_eq!(show(10, 20), "1 … 10 11 12 … 20");
+        assert_eq!(show(15, 20), "1 … 15 16 17 … 20");
+        assert_eq!(show(16, 20), "1 … 16 17 18 19 20");
+        assert_eq!(show(19, 20), "1 … 16 17 18 19 20");
+    }
+}
@@ -0,0 +1,366 @@
+//! Record input events and platform commands, and replay them later.
+//!
+//! This is useful for attaching hard-to-reproduce interaction bugs to an issue:
+//! the user records a session with a [`Recorder`], saves it with [`Recording::to_bytes`],
+//! and a maintainer can then step through it with a [`RecordingViewer`]
+//! or feed it back into their app with a [`Replayer`].
+//!
+//! ```
+//! # let ctx = egui::Context::default();
+//! let mut recorder = egui_extras::recorder::Recorder::default();
+//! recorder.start();
+//!
+//! // Each frame:
+//! let input = egui::RawInput::default();
+//! recorder.record_input(&input);
+//! let output = ctx.run(input, |ctx| { /* your ui */ });
+//! recorder.record_output(&output);
+//!
+//! let bytes = recorder.recording().to_bytes();
+//! let recording = egui_extras::recorder::Recording::from_bytes(&bytes).unwrap();
+//! assert_eq!(recording.frames.len(), 1);
+//! ```
+
+use egui::{FullOutput, OutputCommand, RawInput};
+
+/// First bytes of every recording.
+const MAGIC: &[u8; 8] = b"EGUIREC\0";
+
+/// Bump this when the format of [`RecordedFrame`] changes.
+const VERSION: u32 = 1;
+
+/// Everything that happened during one frame.
+#[derive(Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct RecordedFrame {
+    /// The input given to egui this frame.
+    pub input: RawInput,
+
+    /// The commands egui asked the platform to execute this frame.
+    pub commands: Vec<OutputCommand>,
+}
+
+/// A recorded session: a list of frames.
+#[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct Recording {
+    pub frames: Vec<RecordedFrame>,
+}
+
+/// Error when decoding a [`Recording`].
+#[derive(Debug)]
+pub enum RecordingError {
+    /// The data does not start with the expected header.
+    NotARecording,
+
+    /// The recording was made with an incompatible version of `egui_extras`.
+    UnsupportedVersion(u32),
+
+    /// The data was corrupt.
+    Decode(postcard::Error),
+}
+
+impl std::fmt::Display for RecordingError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NotARecording => write!(f, "Not an egui recording"),
+            Self::UnsupportedVersion(version) => write!(
+                f,
+                "Unsupported recording version {version} (expected {VERSION})"
+            ),
+            Self::Decode(err) => write!(f, "Failed to decode recording: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for RecordingError {}
+
+impl Recording {
+    /// Encode as a compact binary blob.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = MAGIC.to_vec();
+        bytes.extend_from_slice(&VERSION.to_le_bytes());
+        postcard::to_extend(&self.frames, bytes).expect("Serializing into a Vec should never fail")
+    }
+
+    /// Decode something encoded with [`Self::to_bytes`].
+    ///
+    /// # Errors
+    /// Fails if the data is not a recording, is corrupt,
+    /// or was made with an incompatible version.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, RecordingError> {
+        let Some(rest) = bytes.strip_prefix(MAGIC.as_slice()) else {
+            return Err(RecordingError::NotARecording);
+        };
+        let Some((version, rest)) = rest.split_first_chunk::<4>() else {
+            return Err(RecordingError::NotARecording);
+        };
+        let version = u32::from_le_bytes(*version);
+        if version != VERSION {
+            return Err(RecordingError::UnsupportedVersion(version));
+        }
+        let frames = postcard::from_bytes(rest).map_err(RecordingError::Decode)?;
+        Ok(Self { frames })
+    }
+
+    /// Total duration of the recording, in seconds.
+    ///
+    /// Based on [`RawInput::time`], so zero if the integration doesn't provide it.
+    pub fn duration(&self) -> f64 {
+        let time = |frame: &RecordedFrame| frame.input.time.unwrap_or_default();
+        match (self.frames.first(), self.frames.last()) {
+            (Some(first), Some(last)) => time(last) - time(first),
+            _ => 0.0,
+        }
+    }
+}
+
+/// Records the input and platform commands of each frame.
+///
+/// Call [`Self::record_input`] with the [`RawInput`] you give to egui,
+/// and [`Self::record_output`] with the resulting [`FullOutput`].
+#[derive(Default)]
+pub struct Recorder {
+    is_recording: bool,
+    recording: Recording,
+}
+
+impl Recorder {
+    /// Start (or resume) recording.
+    pub fn start(&mut self) {
+        self.is_recording = true;
+    }
+
+    /// Stop (pause) recording.
+    pub fn stop(&mut self) {
+        self.is_recording = false;
+    }
+
+    pub fn is_recording(&self) -> bool {
+        self.is_recording
+    }
+
+    /// Throw away everything recorded so far.
+    pub fn clear(&mut self) {
+        self.recording = Default::default();
+    }
+
+    /// Call this with the input you are about to give to egui.
+    pub fn record_input(&mut self, input: &RawInput) {
+        if self.is_recording {
+            self.recording.frames.push(RecordedFrame {
+                input: input.clone(),
+                commands: Vec::new(),
+            });
+        }
+    }
+
+    /// Call this with the output egui produced for the last recorded input.
+    pub fn record_output(&mut self, output: &FullOutput) {
+        if self.is_recording {
+            if let Some(frame) = self.recording.frames.last_mut() {
+                frame
+                    .commands
+                    .extend(output.platform_output.commands.iter().cloned());
+            }
+        }
+    }
+
+    /// Record the input of the current pass from inside your ui code.
+    ///
+    /// This is an alternative to [`Self::record_input`] and [`Self::record_output`]
+    /// when you don't have access to the integration, e.g. in [`eframe`](https://docs.rs/eframe).
+    /// Call it at the end of your ui code so the commands emitted so far are included.
+    pub fn record_pass(&mut self, ctx: &egui::Context) {
+        if self.is_recording {
+            let input = ctx.input(|i| i.raw.clone());
+            let commands = ctx.output(|o| o.commands.clone());
+            self.recording
+                .frames
+                .push(RecordedFrame { input, commands });
+        }
+    }
+
+    pub fn recording(&self) -> &Recording {
+        &self.recording
+    }
+
+    /// Stop recording and return what was recorded.
+    pub fn take_recording(&mut self) -> Recording {
+        self.is_recording = false;
+        std::mem::take(&mut self.recording)
+    }
+}
+
+/// Plays back a [`Recording`], one frame at a time.
+///
+/// Feed the result of [`Self::next_input`] to [`egui::Context::run`] instead of the real input.
+pub struct Replayer {
+    recording: Recording,
+    next_frame: usize,
+}
+
+impl Replayer {
+    pub fn new(recording: Recording) -> Self {
+        Self {
+            recording,
+            next_frame: 0,
+        }
+    }
+
+    /// The input of the next frame, or `None` when the recording has ended.
+    pub fn next_input(&mut self) -> Option<RawInput> {
+        let frame = self.recording.frames.get(self.next_frame)?;
+        self.next_frame += 1;
+        Some(frame.input.clone())
+    }
+
+    /// Index of the next frame to be replayed.
+    pub fn next_frame(&self) -> usize {
+        self.next_frame
+    }
+
+    /// Jump to the given frame.
+    ///
+    /// Note that egui state is not rewound, so you usually want to start over
+    /// with a fresh [`egui::Context`] and replay up to this frame.
+    pub fn seek(&mut self, frame: usize) {
+        self.next_frame = frame.min(self.recording.frames.len());
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.recording.frames.len() <= self.next_frame
+    }
+
+    pub fn recording(&self) -> &Recording {
+        &self.recording
+    }
+}
+
+/// Shows the contents of a [`Recording`], stepping through it frame by frame.
+#[derive(Default)]
+pub struct RecordingViewer {
+    selected_frame: usize,
+}
+
+impl RecordingViewer {
+    /// The frame currently selected in the viewer.
+    pub fn selected_frame(&self) -> usize {
+        self.selected_frame
+    }
+
+    pub fn ui(&mut self, ui: &mut egui::Ui, recording: &Recording) {
+        let num_frames = recording.frames.len();
+        if num_frames == 0 {
+            ui.weak("Empty recording");
+            return;
+        }
+        self.selected_frame = self.selected_frame.min(num_frames - 1);
+
+        ui.horizontal(|ui| {
+            ui.label(format!(
+                "{num_frames} frames, {:.1} s",
+                recording.duration()
+            ));
+            if ui
+                .add_enabled(0 < self.selected_frame, egui::Button::new("⏴"))
+                .clicked()
+            {
+                self.selected_frame -= 1;
+            }
+            ui.add(
+                egui::DragValue::new(&mut self.selected_frame)
+                    .range(0..=num_frames - 1)
+                    .prefix("frame "),
+            );
+            if ui
+                .add_enabled(self.selected_frame + 1 < num_frames, egui::Button::new("⏵"))
+                .clicked()
+            {
+                self.selected_frame += 1;
+            }
+        });
+
+        let frame = &recording.frames[self.selected_frame];
+
+        egui::Grid::new("recorded_frame")
+            .num_columns(2)
+            .show(ui, |ui| {
+                ui.label("Time:");
+                match frame.input.time {
+                    Some(time) => ui.label(format!("{time:.3} s")),
+                    None => ui.weak("unknown"),
+                };
+                ui.end_row();
+
+                ui.label("Screen rect:");
+                ui.label(format!("{:?}", frame.input.screen_rect));
+                ui.end_row();
+
+                ui.label("Modifiers:");
+                ui.label(format!("{:?}", frame.input.modifiers));
+                ui.end_row();
+            });
+
+        ui.separator();
+        ui.strong(format!("Events ({})", frame.input.events.len()));
+        egui::ScrollArea::vertical()
+            .id_salt("recorded_events")
+            .max_height(200.0)
+            .show(ui, |ui| {
+                for event in &frame.input.events {
+                    ui.monospace(format!("{event:?}"));
+                }
+            });
+
+        ui.separator();
+        ui.strong(format!("Commands ({})", frame.commands.len()));
+        for command in &frame.commands {
+            ui.monospace(format!("{command:?}"));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roundtrip() {
+        let mut recorder = Recorder::default();
+        recorder.start();
+
+        let input = RawInput {
+            time: Some(1.5),
+            events: vec![
+                egui::Event::Text("hello".to_owned()),
+                egui::Event::PointerMoved(egui::pos2(10.0, 20.0)),
+            ],
+            ..Default::default()
+        };
+        recorder.record_input(&input);
+
+        let mut output = FullOutput::default();
+        output
+            .platform_output
+            .commands
+            .push(OutputCommand::CopyText("copied".to_owned()));
+        recorder.record_output(&output);
+
+        let recording = recorder.take_recording();
+        let bytes = recording.to_bytes();
+        let decoded = Recording::from_bytes(&bytes).unwrap();
+        assert_eq!(recording, decoded);
+
+        let mut replayer = Replayer::new(decoded);
+        assert_eq!(replayer.next_input(), Some(input));
+        assert_eq!(replayer.next_input(), None);
+        assert!(replayer.is_finished());
+    }
+
+    #[test]
+    fn reject_garbage() {
+        assert!(matches!(
+            Recording::from_bytes(b"not a recording"),
+            Err(RecordingError::NotARecording)
+        ));
+    }
+}
@@ -0,0 +1,180 @@
+//! Stream draw data from a headless egui process to a thin client renderer.
+//!
+//! The server runs egui as usual, turns each [`FullOutput`] into a [`RemoteFrame`]
+//! and [`send`]s it over a socket (or any other [`Write`]).
+//! The client [`receive`]s frames, uploads the textures and paints the meshes,
+//! and can send its [`egui::RawInput`] back the same way.
+//!
+//! This means the process running egui doesn't need a GPU or a window.
+//!
+//! ```
+//! # let ctx = egui::Context::default();
+//! use egui_extras::remote::{RemoteFrame, receive, send};
+//!
+//! // Server:
+//! let output = ctx.run(egui::RawInput::default(), |ctx| {
+//!     egui::CentralPanel::default().show(ctx, |ui| ui.label("Hello from afar"));
+//! });
+//! let mut socket = Vec::new(); // e.g. a `std::net::TcpStream`
+//! send(&mut socket, &RemoteFrame::from_output(&ctx, output)).unwrap();
+//!
+//! // Client:
+//! let frame: RemoteFrame = receive(&mut socket.as_slice()).unwrap();
+//! assert!(!frame.primitives.is_empty());
+//! ```
+//!
+//! Each message is a little-endian `u32` length followed by that many bytes of [`postcard`].
+
+use std::io::{Read, Write};
+
+use egui::{ClippedPrimitive, FullOutput, TexturesDelta, epaint::Primitive};
+
+/// Messages larger than this are rejected by [`receive`], to protect against corrupt streams.
+pub const MAX_MESSAGE_SIZE: u32 = 256 * 1024 * 1024;
+
+/// Everything a client needs to paint one frame.
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct RemoteFrame {
+    /// The scale the [`Self::primitives`] were tessellated at.
+    pub pixels_per_point: f32,
+
+    /// Textures to upload before painting, and to free after.
+    pub textures_delta: TexturesDelta,
+
+    /// What to paint, in order.
+    ///
+    /// Never contains [`Primitive::Callback`], since those can't be sent to another process.
+    pub primitives: Vec<ClippedPrimitive>,
+}
+
+impl RemoteFrame {
+    /// Tessellate the output of a pass.
+    ///
+    /// Any [`egui::PaintCallback`]s are dropped.
+    pub fn from_output(ctx: &egui::Context, output: FullOutput) -> Self {
+        let FullOutput {
+            textures_delta,
+            shapes,
+            pixels_per_point,
+            ..
+        } = output;
+
+        let mut primitives = ctx.tessellate(shapes, pixels_per_point);
+        primitives.retain(|clipped| matches!(clipped.primitive, Primitive::Mesh(_)));
+
+        Self {
+            pixels_per_point,
+            textures_delta,
+            primitives,
+        }
+    }
+}
+
+/// Error when sending or receiving a message.
+#[derive(Debug)]
+pub enum RemoteError {
+    /// Reading or writing the stream failed.
+    Io(std::io::Error),
+
+    /// The message was larger than [`MAX_MESSAGE_SIZE`].
+    TooLarge(u64),
+
+    /// The message could not be encoded or decoded.
+    Codec(postcard::Error),
+}
+
+impl std::fmt::Display for RemoteError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "I/O error: {err}"),
+            Self::TooLarge(size) => write!(
+                f,
+                "Message of {size} bytes is larger than the maximum of {MAX_MESSAGE_SIZE} bytes"
+            ),
+            Self::Codec(err) => write!(f, "Failed to encode or decode message: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for RemoteError {}
+
+impl From<std::io::Error> for RemoteError {
+    fn from(err: std::io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+/// Write one message, e.g. a [`RemoteFrame`] or an [`egui::RawInput`].
+///
+/// # Errors
+/// Fails if the message is too large or the writer fails.
+pub fn send<T: serde::Serialize>(writer: &mut impl Write, message: &T) -> Result<(), RemoteError> {
+    let bytes = postcard::to_stdvec(message).map_err(RemoteError::Codec)?;
+    let len = u32::try_from(bytes.len())
+        .ok()
+        .filter(|&len| len <= MAX_MESSAGE_SIZE)
+        .ok_or(RemoteError::TooLarge(bytes.len() as u64))?;
+    writer.write_all(&len.to_le_bytes())?;
+    writer.write_all(&bytes)?;
+    writer.flush()?;
+    Ok(())
+}
+
+/// Read one message written with [`send`].
+///
+/// Blocks until the whole message has been read.
+///
+/// # Errors
+/// Fails if the reader fails, reaches the end, or the data is corrupt.
+pub fn receive<T: serde::de::DeserializeOwned>(reader: &mut impl Read) -> Result<T, RemoteError> {
+    let mut len = [0; 4];
+    reader.read_exact(&mut len)?;
+    let len = u32::from_le_bytes(len);
+    if MAX_MESSAGE_SIZE < len {
+        return Err(RemoteError::TooLarge(len.into()));
+    }
+    let mut bytes = vec![0; len as usize];
+    reader.read_exact(&mut bytes)?;
+    postcard::from_bytes(&bytes).map_err(RemoteError::Codec)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roundtrip() {
+        let ctx = egui::Context::default();
+        let output = ctx.run(egui::RawInput::default(), |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                ui.label("Hello");
+                ui.painter().add(egui::PaintCallback {
+                    rect: ui.max_rect(),
+                    callback: std::sync::Arc::new(()),
+                });
+            });
+        });
+        let frame = RemoteFrame::from_output(&ctx, output);
+        assert!(!frame.textures_delta.set.is_empty());
+
+        let mut stream = Vec::new();
+        send(&mut stream, &frame).unwrap();
+        send(&mut stream, &egui::RawInput::default()).unwrap();
+
+        let mut reader = stream.as_slice();
+        let received: RemoteFrame = receive(&mut reader).unwrap();
+        assert_eq!(received.primitives.len(), frame.primitives.len());
+        assert_eq!(received.textures_delta, frame.textures_delta);
+        let _input: egui::RawInput = receive(&mut reader).unwrap();
+        assert!(reader.is_empty());
+    }
+
+    #[test]
+    fn reject_huge_message() {
+        let stream = u32::MAX.to_le_bytes();
+        assert!(matches!(
+            receive::<RemoteFrame>(&mut stream.as_slice()),
+            Err(RemoteError::TooLarge(_))
+        ));
+    }
+}
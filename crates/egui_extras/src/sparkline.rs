@@ -0,0 +1,247 @@
+//! Tiny inline charts showing a trend, e.g. in table cells or next to a label.
+
+use egui::{
+    Color32, Pos2, Rangef, Rect, Response, Sense, Shape, Stroke, Ui, Vec2, Widget, WidgetInfo,
+    WidgetType, pos2, vec2,
+};
+
+/// How a [`Sparkline`] shows its values.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SparklineStyle {
+    /// A line through the values.
+    #[default]
+    Line,
+
+    /// One bar per value, from the bottom (or from zero, if the values go below it).
+    Bar,
+}
+
+/// A small chart of some values, without axes, labels, or interaction.
+///
+/// Non-finite values (e.g. `NAN`) are skipped, leaving a gap.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui_extras::sparkline::Sparkline;
+///
+/// let cpu_usage = [0.2, 0.4, 0.35, 0.8, 0.6];
+/// ui.horizontal(|ui| {
+///     ui.label("CPU");
+///     ui.add(Sparkline::new(&cpu_usage).range(0.0..=1.0).last_value_dot(true));
+/// });
+/// # });
+/// ```
+#[must_use = "You should put this widget in a ui with `ui.add(widget);`"]
+pub struct Sparkline<'a> {
+    values: &'a [f32],
+    style: SparklineStyle,
+    size: Option<Vec2>,
+    color: Option<Color32>,
+    range: Option<Rangef>,
+    min_max_markers: bool,
+    last_value_dot: bool,
+}
+
+impl<'a> Sparkline<'a> {
+    pub fn new(values: &'a [f32]) -> Self {
+        Self {
+            values,
+            style: SparklineStyle::default(),
+            size: None,
+            color: None,
+            range: None,
+            min_max_markers: false,
+            last_value_dot: false,
+        }
+    }
+
+    /// Default: [`SparklineStyle::Line`].
+    #[inline]
+    pub fn style(mut self, style: SparklineStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Default: four times as wide as [`egui::style::Spacing::interact_size`] is high, and as high.
+    #[inline]
+    pub fn size(mut self, size: impl Into<Vec2>) -> Self {
+        self.size = Some(size.into());
+        self
+    }
+
+    /// Default: the hyperlink color of the [`egui::Visuals`].
+    #[inline]
+    pub fn color(mut self, color: Color32) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// The values at the bottom and top of the chart, e.g. `0.0..=1.0` for fractions.
+    ///
+    /// Values outside of it are clamped.
+    /// Default: from the smallest to the largest value.
+    #[inline]
+    pub fn range(mut self, range: impl Into<Rangef>) -> Self {
+        self.range = Some(range.into());
+        self
+    }
+
+    /// Mark the smallest and largest values with dots.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn min_max_markers(mut self, min_max_markers: bool) -> Self {
+        self.min_max_markers = min_max_markers;
+        self
+    }
+
+    /// Show a dot at the last value.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn last_value_dot(mut self, last_value_dot: bool) -> Self {
+        self.last_value_dot = last_value_dot;
+        self
+    }
+}
+
+impl Widget for Sparkline<'_> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Self {
+            values,
+            style,
+            size,
+            color,
+            range,
+            min_max_markers,
+            last_value_dot,
+        } = self;
+
+        let height = ui.spacing().interact_size.y;
+        let size = size.unwrap_or(vec2(4.0 * height, height));
+        let (rect, response) = ui.allocate_exact_size(size, Sense::hover());
+
+        let finite = values.iter().copied().filter(|value| value.is_finite());
+        let (min, max) = finite.fold((f32::INFINITY, f32::NEG_INFINITY), |(min, max), value| {
+            (min.min(value), max.max(value))
+        });
+        response.widget_info(|| {
+            let last = values.last().copied().unwrap_or(f32::NAN);
+            WidgetInfo::labeled(
+                WidgetType::Other,
+                ui.is_enabled(),
+                format!("Trend from {min} to {max}, ending at {last}"),
+            )
+        });
+
+        if !ui.is_rect_visible(rect) || min > max {
+            return response;
+        }
+
+        let color = color.unwrap_or(ui.visuals().hyperlink_color);
+        let range = range.unwrap_or(Rangef::new(min, max));
+        // Leave room for the dots:
+        let radius = 2.0;
+        let plot_rect = if style == SparklineStyle::Line {
+            rect.shrink(radius)
+        } else {
+            rect
+        };
+        let to_y = |value: f32| {
+            let t = if range.span() == 0.0 {
+                0.5
+            } else {
+                ((value - range.min) / range.span()).clamp(0.0, 1.0)
+            };
+            egui::lerp(plot_rect.bottom()..=plot_rect.top(), t)
+        };
+        let to_x = |i: usize| match style {
+            SparklineStyle::Line if 1 < values.len() => {
+                egui::lerp(plot_rect.x_range(), i as f32 / (values.len() - 1) as f32)
+            }
+            SparklineStyle::Line => plot_rect.center().x,
+            SparklineStyle::Bar => {
+                plot_rect.left() + (i as f32 + 0.5) * plot_rect.width() / values.len() as f32
+            }
+        };
+        let point = |i: usize| pos2(to_x(i), to_y(values[i]));
+
+        let painter = ui.painter_at(rect);
+        match style {
+            SparklineStyle::Line => {
+                // One line per run of finite values:
+                let stroke = Stroke::new(1.0, color);
+                let mut line: Vec<Pos2> = Vec::new();
+                for (i, value) in values.iter().enumerate() {
+                    if value.is_finite() {
+                        line.push(point(i));
+                    } else if !line.is_empty() {
+                        painter.add(Shape::line(std::mem::take(&mut line), stroke));
+                    }
+                }
+                painter.add(Shape::line(line, stroke));
+            }
+            SparklineStyle::Bar => {
+                let bar_width = (plot_rect.width() / values.len() as f32 - 1.0).max(1.0);
+                let base = to_y(range.clamp(0.0));
+                for (i, value) in values.iter().enumerate() {
+                    if value.is_finite() {
+                        let top = to_y(*value);
+                        let bar = Rect::from_x_y_ranges(
+                            Rangef::point(to_x(i)).expand(0.5 * bar_width),
+                            Rangef::new(top.min(base), top.max(base)).expand(0.25),
+                        );
+                        painter.rect_filled(bar, 0.0, color);
+                    }
+                }
+            }
+        }
+
+        let dot_pos = |i: usize| match style {
+            SparklineStyle::Line => point(i),
+            SparklineStyle::Bar => pos2(to_x(i), to_y(values[i]).min(to_y(range.clamp(0.0)))),
+        };
+        if min_max_markers {
+            let position_of = |target: f32| values.iter().position(|&value| value == target);
+            let marker_color = ui.visuals().strong_text_color();
+            for i in [position_of(min), position_of(max)].into_iter().flatten() {
+                painter.circle_filled(dot_pos(i), radius, marker_color);
+            }
+        }
+        if last_value_dot {
+            if let Some(i) = values.iter().rposition(|value| value.is_finite()) {
+                painter.circle_filled(dot_pos(i), radius, color);
+            }
+        }
+
+        response
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn skips_non_finite_values() {
+        let ctx = egui::Context::default();
+        let mut num_lines = 0;
+        let (mut rect, mut empty_rect) = (Rect::NOTHING, Rect::NOTHING);
+        let output = ctx.run(Default::default(), |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                let values = [1.0, 2.0, f32::NAN, 3.0, 2.0];
+                rect = ui.add(Sparkline::new(&values).size([100.0, 20.0])).rect;
+                empty_rect = ui.add(Sparkline::new(&[])).rect;
+            });
+        });
+        for clipped in output.shapes {
+            if let Shape::Path(path) = clipped.shape {
+                num_lines += 1;
+                assert!(path.points.iter().all(|p| rect.contains(*p)));
+            }
+        }
+        assert_eq!(num_lines, 2, "The NAN should split the line in two");
+        assert_eq!(rect.size(), vec2(100.0, 20.0));
+        assert!(empty_rect.is_positive());
+    }
+}
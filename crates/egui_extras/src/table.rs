@@ -181,6 +181,7 @@ fn to_sizing(columns: &[Column]) -> crate::sizing::Sizing {
 struct TableScrollOptions {
     vscroll: bool,
     drag_to_scroll: bool,
+    auto_scroll_on_drag: bool,
     stick_to_bottom: bool,
     scroll_to_row: Option<(usize, Option<Align>)>,
     scroll_offset_y: Option<f32>,
@@ -196,6 +197,7 @@ impl Default for TableScrollOptions {
         Self {
             vscroll: true,
             drag_to_scroll: true,
+            auto_scroll_on_drag: false,
             stick_to_bottom: false,
             scroll_to_row: None,
             scroll_offset_y: None,
@@ -336,6 +338,18 @@ impl<'a> TableBuilder<'a> {
         self
     }
 
+    /// Scroll towards the pointer when something in the table,
+    /// e.g. a drag-selection of rows, is dragged past the top or bottom of the body.
+    ///
+    /// Give the rows a drag [`Self::sense`] and check [`TableRow::response`] to implement drag-selection.
+    ///
+    /// See [`ScrollArea::auto_scroll_on_drag`] for more.
+    #[inline]
+    pub fn auto_scroll_on_drag(mut self, auto_scroll_on_drag: bool) -> Self {
+        self.scroll_options.auto_scroll_on_drag = auto_scroll_on_drag;
+        self
+    }
+
     /// Should the scroll handle stick to the bottom position even as the content size changes
     /// dynamically? The scroll handle remains stuck until manually changed, and will become stuck
     /// once again when repositioned to the bottom. Default: `false`.
@@ -731,6 +745,7 @@ impl Table<'_> {
         let TableScrollOptions {
             vscroll,
             drag_to_scroll,
+            auto_scroll_on_drag,
             stick_to_bottom,
             scroll_to_row,
             scroll_offset_y,
@@ -749,6 +764,7 @@ impl Table<'_> {
                 drag: drag_to_scroll,
                 ..Default::default()
             })
+            .auto_scroll_on_drag(auto_scroll_on_drag)
             .stick_to_bottom(stick_to_bottom)
             .min_scrolled_height(min_scrolled_height)
             .max_height(max_scroll_height)
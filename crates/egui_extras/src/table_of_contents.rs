@@ -0,0 +1,224 @@
+//! A table of contents for long, scrolling pages, e.g. documentation or settings.
+
+use egui::{Align, Id, Response, RichText, Ui, WidgetText};
+
+#[derive(Clone, Debug)]
+struct Anchor {
+    title: String,
+    level: usize,
+}
+
+#[derive(Clone, Debug, Default)]
+struct State {
+    /// The anchors registered during the last pass.
+    anchors: Vec<Anchor>,
+
+    /// The anchors being registered during this pass.
+    next_anchors: Vec<Anchor>,
+
+    /// The pass the [`Self::next_anchors`] are for.
+    pass_nr: u64,
+
+    /// The section in view.
+    current: Option<usize>,
+
+    /// The section in view, as it is being worked out during this pass.
+    next_current: Option<usize>,
+
+    /// Scroll to this anchor when it is next laid out.
+    scroll_to: Option<usize>,
+}
+
+impl State {
+    /// Uses the anchors from this pass, if they have already been registered.
+    fn latest_current(&self) -> Option<usize> {
+        if self.next_anchors.is_empty() {
+            self.current
+        } else {
+            self.next_current.or(Some(0))
+        }
+    }
+}
+
+/// A list of the headings on a page, highlighting the one currently in view (scroll-spy).
+///
+/// Register the headings while laying out the contents of a [`egui::ScrollArea`]
+/// with [`Self::heading`] (or [`Self::anchor`]), and show the list anywhere with [`Self::show`].
+/// Clicking an entry scrolls smoothly to that heading.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui_extras::table_of_contents::TableOfContents;
+///
+/// let toc = TableOfContents::new("docs");
+/// ui.horizontal_top(|ui| {
+///     ui.vertical(|ui| toc.show(ui));
+///     egui::ScrollArea::vertical().show(ui, |ui| {
+///         toc.heading(ui, "Introduction", 0);
+///         ui.label("…");
+///         toc.heading(ui, "Details", 1);
+///         ui.label("…");
+///     });
+/// });
+/// # });
+/// ```
+///
+/// If the list is shown before the headings, it uses the headings from the previous pass.
+#[derive(Clone, Copy, Debug)]
+pub struct TableOfContents {
+    id: Id,
+}
+
+impl TableOfContents {
+    /// The `id_salt` must be unique, and the same for the headings and the list.
+    pub fn new(id_salt: impl std::hash::Hash) -> Self {
+        Self {
+            id: Id::new(id_salt),
+        }
+    }
+
+    fn state_mut<R>(&self, ui: &Ui, f: impl FnOnce(&mut State) -> R) -> R {
+        let pass_nr = ui.ctx().cumulative_pass_nr();
+        ui.data_mut(|d| {
+            let state = d.get_temp_mut_or_default::<State>(self.id);
+            if state.pass_nr != pass_nr {
+                // A new pass: what was registered during the last one is now complete.
+                if !state.next_anchors.is_empty() {
+                    state.anchors = std::mem::take(&mut state.next_anchors);
+                    state.current = state.next_current.take().or(Some(0));
+                }
+                state.pass_nr = pass_nr;
+            }
+            f(state)
+        })
+    }
+
+    /// Show a heading and register it in the table of contents.
+    ///
+    /// `level` is how deeply nested it is, where `0` is the top level.
+    /// Deeper levels are shown smaller, and indented in the list.
+    pub fn heading(&self, ui: &mut Ui, title: impl Into<String>, level: usize) -> Response {
+        let title = title.into();
+        let text = RichText::new(&title).strong().size(match level {
+            0 => ui.style().text_styles[&egui::TextStyle::Heading].size,
+            1 => 1.25 * ui.style().text_styles[&egui::TextStyle::Body].size,
+            _ => ui.style().text_styles[&egui::TextStyle::Body].size,
+        });
+        let response = ui.label(text);
+        self.anchor(ui, &response, title, level);
+        response
+    }
+
+    /// Register something already shown (e.g. your own heading) as a section.
+    pub fn anchor(&self, ui: &Ui, response: &Response, title: impl Into<String>, level: usize) {
+        // A heading counts as reached a little before it hits the top edge:
+        let threshold = ui.clip_rect().top() + ui.spacing().interact_size.y;
+        let is_above = response.rect.top() <= threshold;
+
+        let scroll_to_me = self.state_mut(ui, |state| {
+            let index = state.next_anchors.len();
+            state.next_anchors.push(Anchor {
+                title: title.into(),
+                level,
+            });
+            if is_above {
+                state.next_current = Some(index);
+            }
+            if state.scroll_to == Some(index) {
+                state.scroll_to = None;
+                true
+            } else {
+                false
+            }
+        });
+
+        if scroll_to_me {
+            response.scroll_to_me(Some(Align::TOP));
+        }
+    }
+
+    /// The index of the section currently in view, if any sections have been registered.
+    pub fn current(&self, ui: &Ui) -> Option<usize> {
+        self.state_mut(ui, |state| state.latest_current())
+    }
+
+    /// Scroll to the section with the given index.
+    pub fn scroll_to(&self, ui: &Ui, index: usize) {
+        self.state_mut(ui, |state| state.scroll_to = Some(index));
+        ui.ctx().request_repaint();
+    }
+
+    /// Show the list of sections.
+    pub fn show(&self, ui: &mut Ui) -> Response {
+        let (anchors, current) = self.state_mut(ui, |state| {
+            let anchors = if state.next_anchors.is_empty() {
+                state.anchors.clone()
+            } else {
+                // The headings came first this pass.
+                state.next_anchors.clone()
+            };
+            (anchors, state.latest_current())
+        });
+
+        ui.vertical(|ui| {
+            for (index, anchor) in anchors.iter().enumerate() {
+                ui.horizontal(|ui| {
+                    ui.add_space(anchor.level as f32 * ui.spacing().indent);
+                    let text = WidgetText::from(anchor.title.as_str());
+                    if ui.selectable_label(current == Some(index), text).clicked() {
+                        self.scroll_to(ui, index);
+                    }
+                });
+            }
+        })
+        .response
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scroll_spy() {
+        let ctx = egui::Context::default();
+        let toc = TableOfContents::new("toc");
+        let run = |frame: usize, scroll_to: Option<usize>| {
+            let input = egui::RawInput {
+                screen_rect: Some(egui::Rect::from_min_size(
+                    egui::Pos2::ZERO,
+                    egui::vec2(400.0, 300.0),
+                )),
+                time: Some(frame as f64),
+                ..Default::default()
+            };
+            let mut current = None;
+            let _ = ctx.run(input, |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    if let Some(index) = scroll_to {
+                        toc.scroll_to(ui, index);
+                    }
+                    egui::ScrollArea::vertical().show(ui, |ui| {
+                        for section in 0..10 {
+                            toc.heading(ui, format!("Section {section}"), 0);
+                            ui.add_space(500.0);
+                        }
+                    });
+                    current = toc.current(ui);
+                });
+            });
+            current
+        };
+
+        for frame in 0..3 {
+            assert_eq!(run(frame, None), Some(0));
+        }
+
+        run(3, Some(2));
+        let mut current = None;
+        for frame in 4..10 {
+            current = run(frame, None);
+        }
+        assert_eq!(current, Some(2));
+    }
+}
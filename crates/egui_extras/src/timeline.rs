@@ -0,0 +1,527 @@
+//! A timeline (Gantt chart): bars on rows along a zoomable time axis.
+//!
+//! Useful for profilers, video editors, schedulers, etc.
+//!
+//! * Zoom with ctrl + scroll (or pinch), pan by dragging the background or scrolling horizontally.
+//! * Drag a bar to move it, drag its edges to resize it.
+//! * Only the visible rows are laid out, so thousands of rows are fine.
+//!
+//! ```
+//! # egui::__run_test_ui(|ui| {
+//! use egui_extras::timeline::{Timeline, TimelineBar};
+//!
+//! let mut bars = vec![
+//!     TimelineBar::new(0, 0.0..=2.0, "Design"),
+//!     TimelineBar::new(1, 1.5..=4.0, "Build"),
+//! ];
+//! let response = Timeline::new("schedule")
+//!     .snap(0.5)
+//!     .show(ui, &mut bars, 2, |row| format!("Track {row}"));
+//! if let Some(bar) = response.changed {
+//!     println!("Bar {bar} was edited");
+//! }
+//! # });
+//! ```
+
+use std::ops::RangeInclusive;
+
+use egui::{
+    Align2, Color32, CursorIcon, Id, NumExt as _, Rect, Response, Sense, Stroke, StrokeKind,
+    TextStyle, Ui, UiBuilder, pos2, vec2,
+};
+
+/// A bar on a [`Timeline`] row.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct TimelineBar {
+    /// Which row the bar is on.
+    pub row: usize,
+
+    pub start: f64,
+    pub end: f64,
+
+    /// Shown inside the bar, if it fits.
+    pub label: String,
+
+    /// Defaults to the selection color of the [`egui::Visuals`].
+    pub color: Option<Color32>,
+}
+
+impl TimelineBar {
+    pub fn new(row: usize, time: RangeInclusive<f64>, label: impl Into<String>) -> Self {
+        Self {
+            row,
+            start: *time.start(),
+            end: *time.end(),
+            label: label.into(),
+            color: None,
+        }
+    }
+
+    #[inline]
+    pub fn color(mut self, color: Color32) -> Self {
+        self.color = Some(color);
+        self
+    }
+}
+
+/// What happened in [`Timeline::show`] this frame.
+pub struct TimelineResponse {
+    /// The response of the whole timeline.
+    pub response: Response,
+
+    /// Index of the bar under the mouse, if any.
+    pub hovered: Option<usize>,
+
+    /// Index of the selected bar, if any.
+    pub selected: Option<usize>,
+
+    /// Index of a bar that was clicked this frame.
+    pub clicked: Option<usize>,
+
+    /// Index of a bar that was moved or resized this frame.
+    pub changed: Option<usize>,
+}
+
+/// The part of the timeline that is being dragged.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum DragMode {
+    Move,
+    Start,
+    End,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct BarDrag {
+    bar: usize,
+    mode: DragMode,
+    start: f64,
+    end: f64,
+}
+
+/// The view of a [`Timeline`], stored in egui memory.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct State {
+    /// Time at the left edge of the bars area.
+    start: f64,
+
+    /// Zoom level.
+    time_per_point: f64,
+
+    selected: Option<usize>,
+    drag: Option<BarDrag>,
+}
+
+/// A horizontally zoomable timeline with draggable and resizable bars on rows.
+pub struct Timeline<'a> {
+    id_salt: Id,
+    row_height: Option<f32>,
+    label_width: f32,
+    initial_view: RangeInclusive<f64>,
+    snap: Option<f64>,
+    editable: bool,
+    time_formatter: Option<Box<dyn 'a + Fn(f64) -> String>>,
+}
+
+impl<'a> Timeline<'a> {
+    pub fn new(id_salt: impl std::hash::Hash) -> Self {
+        Self {
+            id_salt: Id::new(id_salt),
+            row_height: None,
+            label_width: 100.0,
+            initial_view: 0.0..=10.0,
+            snap: None,
+            editable: true,
+            time_formatter: None,
+        }
+    }
+
+    /// Height of each row. Defaults to a bit more than the height of a line of text.
+    #[inline]
+    pub fn row_height(mut self, row_height: f32) -> Self {
+        self.row_height = Some(row_height);
+        self
+    }
+
+    /// Width of the row label column.
+    ///
+    /// Default: `100.0`.
+    #[inline]
+    pub fn label_width(mut self, label_width: f32) -> Self {
+        self.label_width = label_width;
+        self
+    }
+
+    /// The time range that is visible the first time the timeline is shown.
+    ///
+    /// Default: `0.0..=10.0`.
+    #[inline]
+    pub fn initial_view(mut self, time: RangeInclusive<f64>) -> Self {
+        self.initial_view = time;
+        self
+    }
+
+    /// Snap the start and end of bars to multiples of this when dragging.
+    #[inline]
+    pub fn snap(mut self, interval: f64) -> Self {
+        self.snap = (0.0 < interval).then_some(interval);
+        self
+    }
+
+    /// Can the user move and resize bars?
+    ///
+    /// Default: `true`.
+    #[inline]
+    pub fn editable(mut self, editable: bool) -> Self {
+        self.editable = editable;
+        self
+    }
+
+    /// How to show times on the axis and in tooltips.
+    #[inline]
+    pub fn time_formatter(mut self, formatter: impl 'a + Fn(f64) -> String) -> Self {
+        self.time_formatter = Some(Box::new(formatter));
+        self
+    }
+
+    /// Show the timeline, filling the available space.
+    ///
+    /// `row_label` is only called for the visible rows.
+    pub fn show(
+        self,
+        ui: &mut Ui,
+        bars: &mut [TimelineBar],
+        num_rows: usize,
+        row_label: impl Fn(usize) -> String,
+    ) -> TimelineResponse {
+        let Self {
+            id_salt,
+            row_height,
+            label_width,
+            initial_view,
+            snap,
+            editable,
+            time_formatter,
+        } = self;
+
+        let id = ui.make_persistent_id(id_salt);
+        let format_time = |time: f64| match &time_formatter {
+            Some(formatter) => formatter(time),
+            None => egui::emath::format_with_decimals_in_range(time, 0..=3),
+        };
+
+        let row_height = row_height.unwrap_or_else(|| {
+            ui.text_style_height(&TextStyle::Body) + 2.0 * ui.spacing().button_padding.y
+        });
+        let axis_height = ui.text_style_height(&TextStyle::Small) + 8.0;
+
+        let (rect, response) = ui.allocate_exact_size(ui.available_size(), Sense::click_and_drag());
+        let bars_left = rect.left() + label_width;
+        let bars_width = (rect.right() - bars_left).at_least(1.0);
+
+        let mut state = ui
+            .data(|d| d.get_temp::<State>(id))
+            .unwrap_or_else(|| State {
+                start: *initial_view.start(),
+                time_per_point: (initial_view.end() - initial_view.start())
+                    .abs()
+                    .max(f64::EPSILON)
+                    / bars_width as f64,
+                selected: None,
+                drag: None,
+            });
+
+        // Pan and zoom:
+        if response.dragged() && state.drag.is_none() {
+            state.start -= response.drag_delta().x as f64 * state.time_per_point;
+        }
+        if let Some(hover_pos) = response.hover_pos() {
+            let (zoom, scroll_x) = ui.input(|i| (i.zoom_delta(), i.smooth_scroll_delta.x));
+            state.start -= scroll_x as f64 * state.time_per_point;
+            if zoom != 1.0 {
+                let x = (hover_pos.x - bars_left).max(0.0) as f64;
+                let time_at_pointer = state.start + x * state.time_per_point;
+                state.time_per_point /= zoom as f64;
+                state.start = time_at_pointer - x * state.time_per_point;
+            }
+        }
+
+        let view_start = state.start;
+        let time_per_point = state.time_per_point;
+        let to_x = |time: f64| bars_left + ((time - view_start) / time_per_point) as f32;
+        let painter = ui.painter_at(rect);
+        let visuals = ui.visuals().clone();
+
+        // Time axis:
+        let axis_rect = Rect::from_min_max(
+            pos2(bars_left, rect.top()),
+            pos2(rect.right(), rect.top() + axis_height),
+        );
+        let tick = nice_step(time_per_point * 80.0);
+        let first_tick = (view_start / tick).floor() as i64;
+        let last_tick = ((view_start + bars_width as f64 * time_per_point) / tick).ceil() as i64;
+        for i in first_tick..=last_tick {
+            let time = i as f64 * tick;
+            let x = to_x(time);
+            if x < bars_left {
+                continue;
+            }
+            painter.line_segment(
+                [pos2(x, axis_rect.bottom() - 4.0), pos2(x, rect.bottom())],
+                visuals.widgets.noninteractive.bg_stroke,
+            );
+            painter.text(
+                pos2(x + 2.0, axis_rect.top()),
+                Align2::LEFT_TOP,
+                format_time(time),
+                TextStyle::Small.resolve(ui.style()),
+                visuals.weak_text_color(),
+            );
+        }
+
+        let mut hovered = None;
+        let mut clicked = None;
+        let mut changed = None;
+
+        // Rows and bars:
+        let body_rect = Rect::from_min_max(pos2(rect.left(), axis_rect.bottom()), rect.max);
+        let mut body_ui = ui.new_child(UiBuilder::new().max_rect(body_rect));
+        egui::ScrollArea::vertical()
+            .id_salt(id.with("rows"))
+            .auto_shrink(false)
+            .scroll_source(egui::scroll_area::ScrollSource {
+                drag: false,
+                ..Default::default()
+            })
+            .show_rows(&mut body_ui, row_height, num_rows, |ui, row_range| {
+                let mut bars_by_row: Vec<Vec<usize>> = vec![Vec::new(); row_range.len()];
+                for (i, bar) in bars.iter().enumerate() {
+                    if row_range.contains(&bar.row) {
+                        bars_by_row[bar.row - row_range.start].push(i);
+                    }
+                }
+
+                for (row, bar_indices) in row_range.zip(bars_by_row) {
+                    let (row_rect, _) = ui.allocate_exact_size(
+                        vec2(ui.available_width(), row_height),
+                        Sense::hover(),
+                    );
+                    if row % 2 == 1 {
+                        ui.painter()
+                            .rect_filled(row_rect, 0.0, visuals.faint_bg_color);
+                    }
+                    let label_rect =
+                        Rect::from_min_max(row_rect.min, pos2(bars_left, row_rect.max.y));
+                    ui.painter_at(label_rect).text(
+                        label_rect.left_center() + vec2(4.0, 0.0),
+                        Align2::LEFT_CENTER,
+                        row_label(row),
+                        TextStyle::Body.resolve(ui.style()),
+                        visuals.text_color(),
+                    );
+
+                    let bars_clip =
+                        Rect::from_min_max(pos2(bars_left, row_rect.top()), row_rect.max);
+                    for i in bar_indices {
+                        let bar = &mut bars[i];
+                        let x_range = to_x(bar.start)..=to_x(bar.end);
+                        if *x_range.end() < bars_clip.left() || bars_clip.right() < *x_range.start()
+                        {
+                            continue;
+                        }
+                        let bar_rect =
+                            Rect::from_x_y_ranges(x_range, row_rect.y_range().shrink(2.0));
+                        let bar_response = ui.interact(
+                            bar_rect.intersect(bars_clip),
+                            id.with(("bar", i)),
+                            Sense::click_and_drag(),
+                        );
+
+                        let grab = 5.0_f32.at_most(bar_rect.width() / 3.0);
+                        let mode = bar_response.hover_pos().map(|pos| {
+                            if pos.x < bar_rect.left() + grab {
+                                DragMode::Start
+                            } else if bar_rect.right() - grab < pos.x {
+                                DragMode::End
+                            } else {
+                                DragMode::Move
+                            }
+                        });
+
+                        if editable {
+                            match mode {
+                                Some(DragMode::Start | DragMode::End) => {
+                                    ui.ctx().set_cursor_icon(CursorIcon::ResizeHorizontal);
+                                }
+                                Some(DragMode::Move) => {
+                                    ui.ctx().set_cursor_icon(CursorIcon::Grab);
+                                }
+                                None => {}
+                            }
+
+                            if bar_response.drag_started() {
+                                if let Some(mode) = mode {
+                                    state.drag = Some(BarDrag {
+                                        bar: i,
+                                        mode,
+                                        start: bar.start,
+                                        end: bar.end,
+                                    });
+                                }
+                            }
+                            if let Some(drag) = state.drag.filter(|drag| drag.bar == i) {
+                                if bar_response.dragged() {
+                                    let offset = ui.input(|input| {
+                                        match (
+                                            input.pointer.press_origin(),
+                                            input.pointer.latest_pos(),
+                                        ) {
+                                            (Some(origin), Some(pos)) => {
+                                                (pos.x - origin.x) as f64 * time_per_point
+                                            }
+                                            _ => 0.0,
+                                        }
+                                    });
+                                    let snap = |time: f64| match snap {
+                                        Some(interval) => (time / interval).round() * interval,
+                                        None => time,
+                                    };
+                                    let (start, end) = match drag.mode {
+                                        DragMode::Move => {
+                                            let start = snap(drag.start + offset);
+                                            (start, start + drag.end - drag.start)
+                                        }
+                                        DragMode::Start => {
+                                            (snap(drag.start + offset).min(drag.end), drag.end)
+                                        }
+                                        DragMode::End => {
+                                            (drag.start, snap(drag.end + offset).max(drag.start))
+                                        }
+                                    };
+                                    if (start, end) != (bar.start, bar.end) {
+                                        bar.start = start;
+                                        bar.end = end;
+                                        changed = Some(i);
+                                    }
+                                    ui.ctx().set_cursor_icon(if drag.mode == DragMode::Move {
+                                        CursorIcon::Grabbing
+                                    } else {
+                                        CursorIcon::ResizeHorizontal
+                                    });
+                                }
+                            }
+                        }
+
+                        if bar_response.clicked() || bar_response.drag_started() {
+                            state.selected = Some(i);
+                            clicked = bar_response.clicked().then_some(i);
+                        }
+                        if bar_response.hovered() {
+                            hovered = Some(i);
+                        }
+
+                        let is_selected = state.selected == Some(i);
+                        let color = bar.color.unwrap_or(visuals.selection.bg_fill);
+                        let bar_rect = Rect::from_x_y_ranges(
+                            to_x(bar.start)..=to_x(bar.end),
+                            bar_rect.y_range(),
+                        );
+                        let painter = ui.painter_at(bars_clip);
+                        painter.rect(
+                            bar_rect,
+                            visuals.widgets.inactive.corner_radius,
+                            if bar_response.hovered() {
+                                color.gamma_multiply(1.2)
+                            } else {
+                                color
+                            },
+                            if is_selected {
+                                Stroke::new(2.0, visuals.strong_text_color())
+                            } else {
+                                Stroke::NONE
+                            },
+                            StrokeKind::Inside,
+                        );
+                        let galley = ui.painter().layout_no_wrap(
+                            bar.label.clone(),
+                            TextStyle::Body.resolve(ui.style()),
+                            visuals.strong_text_color(),
+                        );
+                        if galley.size().x + 8.0 < bar_rect.width() {
+                            let pos = pos2(
+                                bar_rect.left().max(bars_clip.left()) + 4.0,
+                                bar_rect.center().y - galley.size().y / 2.0,
+                            );
+                            painter.galley(pos, galley, visuals.strong_text_color());
+                        }
+
+                        bar_response.on_hover_ui(|ui| {
+                            ui.strong(&bar.label);
+                            ui.label(format!(
+                                "{} – {}",
+                                format_time(bar.start),
+                                format_time(bar.end)
+                            ));
+                        });
+                    }
+                }
+            });
+
+        // Separate the labels from the bars:
+        ui.painter().vline(
+            bars_left,
+            rect.y_range(),
+            visuals.widgets.noninteractive.bg_stroke,
+        );
+        ui.painter().rect_stroke(
+            rect,
+            0.0,
+            visuals.widgets.noninteractive.bg_stroke,
+            StrokeKind::Inside,
+        );
+
+        if response.clicked() && hovered.is_none() {
+            state.selected = None;
+        }
+        if state.selected.is_some_and(|i| bars.len() <= i) {
+            state.selected = None;
+        }
+        // Even if the dragged bar was scrolled out of view, or removed:
+        if ui.input(|i| i.pointer.any_released()) {
+            state.drag = None;
+        }
+
+        ui.data_mut(|d| d.insert_temp(id, state));
+
+        TimelineResponse {
+            response,
+            hovered,
+            selected: state.selected,
+            clicked,
+            changed,
+        }
+    }
+}
+
+/// The smallest "nice" number (1, 2 or 5 times a power of ten) that is at least `min_step`.
+fn nice_step(min_step: f64) -> f64 {
+    if !(min_step.is_finite() && 0.0 < min_step) {
+        return 1.0;
+    }
+    let magnitude = 10_f64.powf(min_step.log10().floor());
+    [1.0, 2.0, 5.0, 10.0]
+        .into_iter()
+        .map(|factor| factor * magnitude)
+        .find(|&step| min_step <= step)
+        .unwrap_or(10.0 * magnitude)
+}
+
+#[test]
+fn test_nice_step() {
+    let close = |a: f64, b: f64| (a - b).abs() < 1e-9;
+    assert!(close(nice_step(0.7), 1.0));
+    assert!(close(nice_step(1.0), 1.0));
+    assert!(close(nice_step(1.5), 2.0));
+    assert!(close(nice_step(30.0), 50.0));
+    assert!(close(nice_step(0.03), 0.05));
+}
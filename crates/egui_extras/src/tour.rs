@@ -0,0 +1,368 @@
+//! Guided tours that walk the user through the parts of a UI, one widget at a time.
+
+use egui::{
+    Align, Area, Button, Color32, Context, Frame, Id, Key, Layout, Modifiers, Order, Rect, Sense,
+    Vec2, pos2, vec2,
+};
+
+/// One step of a [`Tour`].
+#[derive(Clone, Debug)]
+pub struct TourStep {
+    /// The widget to highlight, e.g. [`egui::Response::id`].
+    pub target: Id,
+
+    pub title: String,
+
+    pub text: String,
+}
+
+/// The result of showing a [`Tour`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct TourResponse {
+    /// The step being shown, if the tour is running.
+    pub step: Option<usize>,
+
+    /// The user went through all the steps this pass.
+    pub finished: bool,
+
+    /// The user skipped the rest of the tour this pass.
+    pub skipped: bool,
+}
+
+#[derive(Clone, Debug, Default)]
+struct State {
+    step: Option<usize>,
+
+    /// The step that has been scrolled to and focused.
+    shown_step: Option<usize>,
+
+    /// What had keyboard focus before the tour started.
+    prev_focus: Option<Id>,
+
+    /// The size of the callout last pass, for placing it above the target.
+    callout_size: Vec2,
+}
+
+/// A guided tour: dims everything except one widget at a time,
+/// with a callout explaining it and buttons to go back and forth.
+///
+/// The steps refer to widgets by their [`Id`]. Each target is scrolled into view when its step
+/// is shown, and stays interactive. If the target isn't shown, the callout is shown
+/// in the middle of the screen instead.
+///
+/// Show the tour _after_ the rest of your UI, so the targets are where they are this pass.
+/// It does nothing until started with [`Tour::start`].
+///
+/// ```
+/// # egui::__run_test_ctx(|ctx| {
+/// use egui_extras::tour::Tour;
+///
+/// let save_button = egui::CentralPanel::default()
+///     .show(ctx, |ui| {
+///         if ui.button("Take the tour").clicked() {
+///             Tour::start(ui.ctx(), "tour");
+///         }
+///         ui.button("Save").id
+///     })
+///     .inner;
+///
+/// Tour::new("tour")
+///     .step(save_button, "Save", "Saves your changes to disk.")
+///     .show(ctx);
+/// # });
+/// ```
+#[doc(alias = "onboarding")]
+#[doc(alias = "spotlight")]
+#[must_use = "You should call .show()"]
+pub struct Tour {
+    id: Id,
+    steps: Vec<TourStep>,
+    backdrop_color: Color32,
+    padding: f32,
+}
+
+impl Tour {
+    /// The `id_salt` must be unique, and the same as for [`Self::start`].
+    pub fn new(id_salt: impl std::hash::Hash) -> Self {
+        Self {
+            id: Id::new(id_salt),
+            steps: Vec::new(),
+            backdrop_color: Color32::from_black_alpha(160),
+            padding: 4.0,
+        }
+    }
+
+    /// Add a step highlighting the widget with the given [`Id`].
+    #[inline]
+    pub fn step(mut self, target: Id, title: impl Into<String>, text: impl Into<String>) -> Self {
+        self.steps.push(TourStep {
+            target,
+            title: title.into(),
+            text: text.into(),
+        });
+        self
+    }
+
+    /// Add several steps.
+    #[inline]
+    pub fn steps(mut self, steps: impl IntoIterator<Item = TourStep>) -> Self {
+        self.steps.extend(steps);
+        self
+    }
+
+    /// The color everything except the target is dimmed with.
+    ///
+    /// Default: `Color32::from_black_alpha(160)`.
+    #[inline]
+    pub fn backdrop_color(mut self, color: Color32) -> Self {
+        self.backdrop_color = color;
+        self
+    }
+
+    /// Space between the target and the edge of the spotlight.
+    ///
+    /// Default: 4.
+    #[inline]
+    pub fn padding(mut self, padding: f32) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Start the tour from the first step.
+    pub fn start(ctx: &Context, id_salt: impl std::hash::Hash) {
+        let prev_focus = ctx.memory(|m| m.focused());
+        ctx.data_mut(|d| {
+            d.insert_temp(
+                Id::new(id_salt),
+                State {
+                    step: Some(0),
+                    prev_focus,
+                    ..Default::default()
+                },
+            );
+        });
+        ctx.request_repaint();
+    }
+
+    /// Stop the tour without finishing it.
+    pub fn stop(ctx: &Context, id_salt: impl std::hash::Hash) {
+        ctx.data_mut(|d| d.remove::<State>(Id::new(id_salt)));
+        ctx.request_repaint();
+    }
+
+    pub fn is_running(ctx: &Context, id_salt: impl std::hash::Hash) -> bool {
+        ctx.data(|d| d.get_temp::<State>(Id::new(id_salt)))
+            .is_some_and(|state| state.step.is_some())
+    }
+
+    pub fn show(self, ctx: &Context) -> TourResponse {
+        let Self {
+            id,
+            steps,
+            backdrop_color,
+            padding,
+        } = self;
+
+        let mut state: State = ctx.data(|d| d.get_temp(id)).unwrap_or_default();
+        let Some(index) = state.step.filter(|&index| index < steps.len()) else {
+            return TourResponse::default();
+        };
+        let step = &steps[index];
+        let is_new_step = state.shown_step != Some(index);
+        if is_new_step {
+            ctx.scroll_to_widget(step.target, Some(Align::Center));
+        }
+
+        let screen = ctx.screen_rect();
+        let hole = ctx.read_response(step.target).map(|response| {
+            let rect = ctx
+                .layer_transform_to_global(response.layer_id)
+                .map_or(response.rect, |transform| transform * response.rect);
+            rect.expand(padding).intersect(screen)
+        });
+
+        let mut next = false;
+        let mut back = false;
+        let mut skip = ctx.input_mut(|i| i.consume_key(Modifiers::NONE, Key::Escape));
+
+        // Block interaction with everything but the target:
+        let backdrop = match hole {
+            Some(hole) => vec![
+                Rect::from_min_max(screen.min, pos2(screen.right(), hole.top())),
+                Rect::from_min_max(pos2(screen.left(), hole.bottom()), screen.max),
+                Rect::from_min_max(
+                    pos2(screen.left(), hole.top()),
+                    pos2(hole.left(), hole.bottom()),
+                ),
+                Rect::from_min_max(
+                    pos2(hole.right(), hole.top()),
+                    pos2(screen.right(), hole.bottom()),
+                ),
+            ],
+            None => vec![screen],
+        };
+        for (i, rect) in backdrop.into_iter().enumerate() {
+            if rect.is_positive() {
+                // One area per side, since an area blocks everything behind it:
+                Area::new(id.with(("backdrop", i)))
+                    .order(Order::Foreground)
+                    .fixed_pos(rect.min)
+                    .constrain(false)
+                    .sense(Sense::CLICK | Sense::DRAG)
+                    .show(ctx, |ui| {
+                        ui.set_min_size(rect.size());
+                        ui.painter().rect_filled(rect, 0.0, backdrop_color);
+                    });
+            }
+        }
+
+        let style = ctx.style();
+        let frame = Frame::popup(&style);
+        let margin = style.spacing.window_margin.leftf();
+        let width = 280.0_f32.min(screen.width() - 2.0 * margin);
+        let height = state.callout_size.y;
+        let mut pos = match hole {
+            Some(hole) if hole.bottom() + margin + height <= screen.bottom() => {
+                pos2(hole.center().x - 0.5 * width, hole.bottom() + margin)
+            }
+            Some(hole) if screen.top() <= hole.top() - margin - height => {
+                pos2(hole.center().x - 0.5 * width, hole.top() - margin - height)
+            }
+            _ => screen.center() - 0.5 * vec2(width, height),
+        };
+        pos.x = pos.x.clamp(
+            screen.left() + margin,
+            (screen.right() - margin - width).max(screen.left()),
+        );
+
+        let callout = Area::new(id)
+            .order(Order::Foreground)
+            .fixed_pos(pos)
+            .movable(false)
+            .show(ctx, |ui| {
+                if let Some(hole) = hole {
+                    ui.painter().rect_stroke(
+                        hole,
+                        ui.visuals().widgets.inactive.corner_radius,
+                        ui.visuals().selection.stroke,
+                        egui::StrokeKind::Outside,
+                    );
+                }
+
+                frame.show(ui, |ui| {
+                    ui.set_width(width - frame.total_margin().sum().x);
+                    ui.strong(&step.title);
+                    ui.label(&step.text);
+                    ui.add_space(ui.spacing().item_spacing.y);
+                    ui.horizontal(|ui| {
+                        ui.weak(format!("{} / {}", index + 1, steps.len()));
+                        ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+                            let is_last = index + 1 == steps.len();
+                            let next_button = ui.button(if is_last { "Done" } else { "Next" });
+                            if is_new_step {
+                                next_button.request_focus();
+                            }
+                            next |= next_button.clicked();
+                            back |= ui.add_enabled(0 < index, Button::new("Back")).clicked();
+                            if !is_last {
+                                skip |= ui.button("Skip").clicked();
+                            }
+                        });
+                    });
+                });
+            })
+            .response;
+        state.callout_size = callout.rect.size();
+        // Clicking the backdrop brings it to the top:
+        ctx.move_to_top(callout.layer_id);
+
+        state.shown_step = Some(index);
+
+        let mut response = TourResponse {
+            step: Some(index),
+            ..Default::default()
+        };
+        if next && index + 1 < steps.len() {
+            state.step = Some(index + 1);
+            response.step = state.step;
+        } else if back && 0 < index {
+            state.step = Some(index - 1);
+            response.step = state.step;
+        } else if next || skip {
+            response.finished = next;
+            response.skipped = skip;
+            response.step = None;
+            state.step = None;
+            if let Some(prev_focus) = state.prev_focus {
+                ctx.memory_mut(|m| m.request_focus(prev_focus));
+            }
+        }
+        if response.step != Some(index) {
+            ctx.request_repaint();
+        }
+
+        ctx.data_mut(|d| d.insert_temp(id, state));
+        response
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_target_is_interactive() {
+        let ctx = Context::default();
+        let run = |target: Id, events: Vec<egui::Event>| {
+            let input = egui::RawInput {
+                screen_rect: Some(Rect::from_min_size(egui::Pos2::ZERO, vec2(400.0, 300.0))),
+                events,
+                ..Default::default()
+            };
+            let mut buttons = [(Id::NULL, Rect::NOTHING, false); 2];
+            let mut tour = TourResponse::default();
+            let _ = ctx.run(input, |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    for (i, label) in ["Highlighted", "Dimmed"].into_iter().enumerate() {
+                        let response = ui.button(label);
+                        buttons[i] = (response.id, response.rect, response.clicked());
+                    }
+                });
+                tour = Tour::new("tour").step(target, "Title", "Text").show(ctx);
+            });
+            (buttons, tour)
+        };
+        let click = |target: Id, pos: egui::Pos2| {
+            let button = |pressed| egui::Event::PointerButton {
+                pos,
+                button: egui::PointerButton::Primary,
+                pressed,
+                modifiers: Modifiers::NONE,
+            };
+            run(target, vec![egui::Event::PointerMoved(pos)]);
+            run(target, vec![button(true)]);
+            let (buttons, _) = run(target, vec![button(false)]);
+            [buttons[0].2, buttons[1].2]
+        };
+
+        let (buttons, tour) = run(Id::NULL, vec![]);
+        assert_eq!(tour.step, None);
+        let [(target, target_rect, _), (_, other_rect, _)] = buttons;
+
+        Tour::start(&ctx, "tour");
+        assert_eq!(run(target, vec![]).1.step, Some(0));
+        assert_eq!(click(target, other_rect.center()), [false, false]);
+        assert_eq!(click(target, target_rect.center()), [true, false]);
+
+        let escape = egui::Event::Key {
+            key: Key::Escape,
+            physical_key: None,
+            pressed: true,
+            repeat: false,
+            modifiers: Modifiers::NONE,
+        };
+        assert!(run(target, vec![escape]).1.skipped);
+        assert!(!Tour::is_running(&ctx, "tour"));
+        assert_eq!(click(target, other_rect.center()), [false, true]);
+    }
+}
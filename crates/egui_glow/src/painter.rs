@@ -5,7 +5,7 @@ use std::{collections::HashMap, sync::Arc};
 
 use egui::{
     emath::Rect,
-    epaint::{Mesh, PaintCallbackInfo, Primitive, Vertex},
+    epaint::{BlendMode, Mesh, PaintCallbackInfo, Primitive, Vertex},
 };
 use glow::HasContext as _;
 use memoffset::offset_of;
@@ -313,15 +313,7 @@ impl Painter {
             self.gl.enable(glow::BLEND);
             self.gl
                 .blend_equation_separate(glow::FUNC_ADD, glow::FUNC_ADD);
-            self.gl.blend_func_separate(
-                // egui outputs colors with premultiplied alpha:
-                glow::ONE,
-                glow::ONE_MINUS_SRC_ALPHA,
-                // Less important, but this is technically the correct alpha blend function
-                // when you want to make use of the framebuffer alpha (for screenshots, compositing, etc).
-                glow::ONE_MINUS_DST_ALPHA,
-                glow::ONE,
-            );
+            set_blend_mode(&self.gl, BlendMode::Normal);
 
             if self.supports_srgb_framebuffer {
                 self.gl.disable(glow::FRAMEBUFFER_SRGB);
@@ -404,15 +396,22 @@ impl Painter {
 
         unsafe { self.prepare_painting(screen_size_px, pixels_per_point) };
 
+        let mut current_blend_mode = BlendMode::Normal;
+
         for egui::ClippedPrimitive {
             clip_rect,
             primitive,
+            blend_mode,
         } in clipped_primitives
         {
             set_clip_rect(&self.gl, screen_size_px, pixels_per_point, *clip_rect);
 
             match primitive {
                 Primitive::Mesh(mesh) => {
+                    if *blend_mode != current_blend_mode {
+                        set_blend_mode(&self.gl, *blend_mode);
+                        current_blend_mode = *blend_mode;
+                    }
                     self.paint_mesh(mesh);
                 }
                 Primitive::Callback(callback) => {
@@ -448,6 +447,7 @@ impl Painter {
 
                         // Restore state:
                         unsafe { self.prepare_painting(screen_size_px, pixels_per_point) };
+                        current_blend_mode = BlendMode::Normal;
                     }
                 }
             }
@@ -757,6 +757,25 @@ impl Drop for Painter {
     }
 }
 
+fn set_blend_mode(gl: &glow::Context, blend_mode: BlendMode) {
+    // egui outputs colors with premultiplied alpha:
+    let (src, dst) = match blend_mode {
+        BlendMode::Normal => (glow::ONE, glow::ONE_MINUS_SRC_ALPHA),
+        BlendMode::Additive => (glow::ONE, glow::ONE),
+        BlendMode::Multiply => (glow::DST_COLOR, glow::ONE_MINUS_SRC_ALPHA),
+    };
+    unsafe {
+        gl.blend_func_separate(
+            src,
+            dst,
+            // Less important, but this is technically the correct alpha blend function
+            // when you want to make use of the framebuffer alpha (for screenshots, compositing, etc).
+            glow::ONE_MINUS_DST_ALPHA,
+            glow::ONE,
+        );
+    }
+}
+
 fn set_clip_rect(
     gl: &glow::Context,
     [width_px, height_px]: [u32; 2],
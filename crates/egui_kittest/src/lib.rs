@@ -25,12 +25,15 @@ pub use kittest;
 use crate::app_kind::AppKind;
 
 pub use builder::*;
+use node::queue_drag;
 pub use node::*;
 pub use renderer::*;
 
 use egui::epaint::{ClippedShape, RectShape};
 use egui::style::ScrollAnimation;
-use egui::{Color32, Key, Modifiers, Pos2, Rect, RepaintCause, Shape, Vec2, ViewportId};
+use egui::{
+    Color32, Key, Modifiers, PointerButton, Pos2, Rect, RepaintCause, Shape, Vec2, ViewportId,
+};
 use kittest::Queryable;
 
 #[derive(Debug, Clone)]
@@ -557,6 +560,44 @@ impl<'a, State> Harness<'a, State> {
         self.key_combination_modifiers(modifiers, &[key]);
     }
 
+    /// Move the pointer to the given position.
+    pub fn hover_at(&self, pos: Pos2) {
+        self.event(egui::Event::PointerMoved(pos));
+    }
+
+    /// Click at the given position with the primary button.
+    pub fn click_at(&self, pos: Pos2) {
+        self.click_button_at(pos, PointerButton::Primary);
+    }
+
+    /// Click at the given position with the given button.
+    pub fn click_button_at(&self, pos: Pos2, button: PointerButton) {
+        self.hover_at(pos);
+        for pressed in [true, false] {
+            self.event(egui::Event::PointerButton {
+                pos,
+                button,
+                pressed,
+                modifiers: Modifiers::default(),
+            });
+        }
+    }
+
+    /// Drag with the primary button from `from` to `to`.
+    ///
+    /// This will generate:
+    /// - Press at `from`
+    /// - Move the pointer to `to`, in a few steps
+    /// - Release at `to`
+    pub fn drag(&self, from: Pos2, to: Pos2) {
+        queue_drag(&self.queued_events, from, to);
+    }
+
+    /// Type some text into the focused widget.
+    pub fn type_text(&self, text: &str) {
+        self.event(egui::Event::Text(text.to_owned()));
+    }
+
     /// Mask something. Useful for snapshot tests.
     ///
     /// Call this _after_ [`Self::run`] and before [`Self::snapshot`].
@@ -566,6 +607,7 @@ impl<'a, State> Harness<'a, State> {
         self.output.shapes.push(ClippedShape {
             clip_rect: Rect::EVERYTHING,
             shape: Shape::Rect(RectShape::filled(rect, 0.0, Color32::MAGENTA)),
+            blend_mode: Default::default(),
         });
     }
 
@@ -578,6 +620,19 @@ impl<'a, State> Harness<'a, State> {
         self.renderer.render(&self.ctx, &self.output)
     }
 
+    /// Render the last output to an [`egui::ColorImage`].
+    ///
+    /// # Errors
+    /// Returns an error if the rendering fails.
+    #[cfg(any(feature = "wgpu", feature = "snapshot"))]
+    pub fn render_color_image(&mut self) -> Result<egui::ColorImage, String> {
+        let image = self.render()?;
+        Ok(egui::ColorImage::from_rgba_unmultiplied(
+            [image.width() as usize, image.height() as usize],
+            image.as_raw(),
+        ))
+    }
+
     /// Get the root viewport output
     fn root_viewport_output(&self) -> &egui::ViewportOutput {
         self.output
@@ -594,6 +649,24 @@ impl<'a, State> Harness<'a, State> {
         }
     }
 
+    /// Find the node of the widget with the given [`egui::Id`].
+    ///
+    /// Only widgets that produce an AccessKit node can be found this way.
+    ///
+    /// # Panics
+    /// Panics if no such node exists.
+    #[track_caller]
+    pub fn get_by_id(&self, id: egui::Id) -> Node<'_> {
+        self.query_by_id(id)
+            .unwrap_or_else(|| panic!("No node found with id {id:?}"))
+    }
+
+    /// Find the node of the widget with the given [`egui::Id`], if any.
+    pub fn query_by_id(&self, id: egui::Id) -> Option<Node<'_>> {
+        let target = id.accesskit_id();
+        self.query_by(move |node| node.id() == target)
+    }
+
     #[deprecated = "Use `Harness::root` instead."]
     pub fn node(&self) -> Node<'_> {
         self.root()
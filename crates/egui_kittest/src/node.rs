@@ -36,6 +36,31 @@ impl<'tree> NodeT<'tree> for Node<'tree> {
     }
 }
 
+/// Queue the events for dragging with the primary button from `from` to `to`.
+pub(crate) fn queue_drag(queue: &EventQueue, from: Pos2, to: Pos2) {
+    // Move in a few steps, so that drag thresholds and velocities behave realistically:
+    const STEPS: usize = 4;
+
+    let mut queue = queue.lock();
+    queue.push(EventType::Event(egui::Event::PointerMoved(from)));
+    queue.push(EventType::Event(egui::Event::PointerButton {
+        pos: from,
+        button: PointerButton::Primary,
+        pressed: true,
+        modifiers: Modifiers::default(),
+    }));
+    for i in 1..=STEPS {
+        let pos = from.lerp(to, i as f32 / STEPS as f32);
+        queue.push(EventType::Event(egui::Event::PointerMoved(pos)));
+    }
+    queue.push(EventType::Event(egui::Event::PointerButton {
+        pos: to,
+        button: PointerButton::Primary,
+        pressed: false,
+        modifiers: Modifiers::default(),
+    }));
+}
+
 impl Node<'_> {
     fn event(&self, event: egui::Event) {
         self.queue.lock().push(EventType::Event(event));
@@ -93,6 +118,11 @@ impl Node<'_> {
         self.modifiers(Modifiers::default());
     }
 
+    /// Drag from the node center to the given position, with the primary button.
+    pub fn drag_to(&self, pos: Pos2) {
+        queue_drag(self.queue, self.rect().center(), pos);
+    }
+
     /// Click the node via accesskit.
     ///
     /// This will trigger a [`accesskit::Action::Click`] action.
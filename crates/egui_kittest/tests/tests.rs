@@ -159,3 +159,46 @@ fn test_masking() {
 
     harness.snapshot("test_masking");
 }
+
+#[test]
+fn test_drag() {
+    let mut harness = Harness::new_ui_state(
+        |ui, value| {
+            ui.add(egui::Slider::new(value, 0.0..=100.0).show_value(false));
+        },
+        0.0_f32,
+    );
+
+    let slider = harness.get_by_role(egui::accesskit::Role::Slider).rect();
+    harness.drag(slider.left_center(), slider.right_center());
+    harness.run();
+
+    assert_eq!(*harness.state(), 100.0, "The slider was not dragged");
+}
+
+#[test]
+fn test_click_at_and_get_by_id() {
+    let button_id = egui::Id::new("my_button");
+    let mut harness = Harness::new_ui_state(
+        |ui, clicked| {
+            let response = ui.add(egui::Button::new("Click me"));
+            ui.ctx().data_mut(|d| d.insert_temp(button_id, response.id));
+            if response.clicked() {
+                *clicked = true;
+            }
+        },
+        false,
+    );
+
+    let id = harness
+        .ctx
+        .data(|d| d.get_temp::<egui::Id>(button_id))
+        .expect("The button should have run");
+    let rect = harness.get_by_id(id).rect();
+    assert_eq!(rect, harness.get_by_label("Click me").rect());
+
+    harness.click_at(rect.center());
+    harness.run();
+
+    assert!(harness.state(), "The button was not clicked");
+}
@@ -1,6 +1,9 @@
 use emath::Vec2;
 
-use crate::{Color32, textures::TextureOptions};
+use crate::{
+    Color32,
+    textures::{TextureFilter, TextureOptions},
+};
 use std::sync::Arc;
 
 /// An image stored in RAM.
@@ -269,6 +272,7 @@ impl ColorImage {
     }
 
     /// Clone a sub-region as a new image.
+    #[doc(alias = "crop")]
     pub fn region_by_pixels(&self, [x, y]: [usize; 2], [w, h]: [usize; 2]) -> Self {
         assert!(
             x + w <= self.width(),
@@ -298,6 +302,122 @@ impl ColorImage {
         );
         Self::new([w, h], pixels)
     }
+
+    /// Scale the image to a new size (in texels).
+    ///
+    /// [`TextureFilter::Nearest`] keeps hard pixel edges, e.g. for pixel art.
+    /// [`TextureFilter::Linear`] interpolates between texels when enlarging,
+    /// and averages all the covered texels when shrinking.
+    ///
+    /// The [`Self::source_size`] is set to the new size.
+    pub fn resized(&self, new_size: [usize; 2], filter: TextureFilter) -> Self {
+        let [src_w, src_h] = self.size;
+        let [w, h] = new_size;
+        if src_w == 0 || src_h == 0 {
+            return Self::filled(new_size, Color32::TRANSPARENT);
+        }
+
+        let scale_x = src_w as f32 / w as f32;
+        let scale_y = src_h as f32 / h as f32;
+        let mut pixels = Vec::with_capacity(w * h);
+        for y in 0..h {
+            for x in 0..w {
+                let color = match filter {
+                    TextureFilter::Nearest => {
+                        let sx = ((x as f32 + 0.5) * scale_x) as usize;
+                        let sy = ((y as f32 + 0.5) * scale_y) as usize;
+                        self[(sx.min(src_w - 1), sy.min(src_h - 1))]
+                    }
+                    TextureFilter::Linear => {
+                        let x_range = (x as f32 * scale_x, (x + 1) as f32 * scale_x);
+                        let y_range = (y as f32 * scale_y, (y + 1) as f32 * scale_y);
+                        self.sample_linear(x_range, y_range)
+                    }
+                };
+                pixels.push(color);
+            }
+        }
+        Self::new(new_size, pixels)
+    }
+
+    /// The color covering the given range of texels: the average of all texels
+    /// in it if it is larger than a texel, or else the bilinear interpolation at its center.
+    fn sample_linear(&self, (x0, x1): (f32, f32), (y0, y1): (f32, f32)) -> Color32 {
+        let [w, h] = self.size;
+        // The texels to blend, with their weights:
+        let weights = |min: f32, max: f32, len: usize| -> Vec<(usize, f32)> {
+            if 1.0 < max - min {
+                let first = min.floor() as usize;
+                let last = (max.ceil() as usize).min(len);
+                (first..last)
+                    .map(|i| {
+                        let overlap = max.min((i + 1) as f32) - min.max(i as f32);
+                        (i, overlap.max(0.0))
+                    })
+                    .collect()
+            } else {
+                let center = (0.5 * (min + max) - 0.5).clamp(0.0, (len - 1) as f32);
+                let i = center.floor() as usize;
+                let t = center - i as f32;
+                vec![(i, 1.0 - t), ((i + 1).min(len - 1), t)]
+            }
+        };
+
+        let mut sum = [0.0_f32; 4];
+        let mut total_weight = 0.0;
+        for (y, wy) in weights(y0, y1, h) {
+            for &(x, wx) in &weights(x0, x1, w) {
+                let weight = wx * wy;
+                let color = self.pixels[y * w + x].to_array();
+                for (sum, c) in sum.iter_mut().zip(color) {
+                    *sum += weight * c as f32;
+                }
+                total_weight += weight;
+            }
+        }
+        let [r, g, b, a] = sum.map(|c| (c / total_weight).round() as u8);
+        Color32::from_rgba_premultiplied(r, g, b, a)
+    }
+
+    /// Mirror the image left-to-right.
+    pub fn flip_horizontally(&mut self) {
+        let w = self.width();
+        if w == 0 {
+            return;
+        }
+        for row in self.pixels.chunks_exact_mut(w) {
+            row.reverse();
+        }
+    }
+
+    /// Mirror the image top-to-bottom.
+    pub fn flip_vertically(&mut self) {
+        let [w, h] = self.size;
+        for y in 0..h / 2 {
+            let (top, bottom) = self.pixels.split_at_mut((h - 1 - y) * w);
+            top[y * w..(y + 1) * w].swap_with_slice(&mut bottom[..w]);
+        }
+    }
+
+    /// The pixels as flat un-multiplied RGBA data, e.g. for saving to an image file.
+    ///
+    /// The inverse of [`Self::from_rgba_unmultiplied`].
+    pub fn to_rgba_unmultiplied(&self) -> Vec<u8> {
+        self.pixels
+            .iter()
+            .flat_map(|color| color.to_srgba_unmultiplied())
+            .collect()
+    }
+
+    /// The pixels as flat premultiplied RGBA data.
+    ///
+    /// The inverse of [`Self::from_rgba_premultiplied`].
+    pub fn to_rgba_premultiplied(&self) -> Vec<u8> {
+        self.pixels
+            .iter()
+            .flat_map(|color| color.to_array())
+            .collect()
+    }
 }
 
 impl std::ops::Index<(usize, usize)> for ColorImage {
@@ -448,3 +568,39 @@ impl ImageDelta {
         self.pos.is_none()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resize_and_flip() {
+        let (r, g, b, w) = (Color32::RED, Color32::GREEN, Color32::BLUE, Color32::WHITE);
+        let mut image = ColorImage::new([2, 2], vec![r, g, b, w]);
+
+        let big = image.resized([4, 4], TextureFilter::Nearest);
+        assert_eq!(big.size, [4, 4]);
+        assert_eq!(big.source_size, Vec2::splat(4.0));
+        assert_eq!(big[(1, 1)], r);
+        assert_eq!(big[(2, 1)], g);
+        assert_eq!(big[(3, 3)], w);
+
+        let small = image.resized([1, 1], TextureFilter::Linear);
+        assert_eq!(small.pixels, vec![Color32::from_rgb(128, 128, 128)]);
+
+        let smooth = image.resized([3, 1], TextureFilter::Linear);
+        assert_eq!(smooth.pixels[1], Color32::from_rgb(128, 128, 128));
+
+        image.flip_horizontally();
+        assert_eq!(image.pixels, vec![g, r, w, b]);
+        image.flip_vertically();
+        assert_eq!(image.pixels, vec![w, b, g, r]);
+
+        let translucent = ColorImage::new([1, 1], vec![Color32::from_white_alpha(128)]);
+        assert_eq!(translucent.to_rgba_unmultiplied(), vec![255, 255, 255, 128]);
+        assert_eq!(
+            translucent.to_rgba_premultiplied(),
+            vec![128, 128, 128, 128]
+        );
+    }
+}
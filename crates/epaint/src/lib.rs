@@ -56,11 +56,11 @@ pub use self::{
     mesh::{Mesh, Mesh16, Vertex},
     shadow::Shadow,
     shapes::{
-        CircleShape, CubicBezierShape, EllipseShape, PaintCallback, PaintCallbackInfo, PathShape,
-        QuadraticBezierShape, RectShape, Shape, TextShape,
+        ArcShape, CircleShape, CubicBezierShape, EllipseShape, PaintCallback, PaintCallbackInfo,
+        PathShape, QuadraticBezierShape, RectShape, Shape, TextShape,
     },
     stats::PaintStats,
-    stroke::{PathStroke, Stroke, StrokeKind},
+    stroke::{LineCap, LineJoin, PathStroke, Stroke, StrokeKind},
     tessellator::{TessellationOptions, Tessellator},
     text::{FontFamily, FontId, Fonts, Galley},
     texture_atlas::TextureAtlas,
@@ -72,7 +72,7 @@ pub use self::{
 #[deprecated = "Renamed to CornerRadius"]
 pub type Rounding = CornerRadius;
 
-pub use ecolor::{Color32, Hsva, HsvaGamma, Rgba};
+pub use ecolor::{Color32, ColorVision, Hsva, HsvaGamma, Rgba};
 pub use emath::{Pos2, Rect, Vec2, pos2, vec2};
 
 #[deprecated = "Use the ahash crate directly."]
@@ -118,6 +118,7 @@ impl Default for TextureId {
 ///
 /// Everything is using logical points.
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct ClippedShape {
     /// Clip / scissor rectangle.
     /// Only show the part of the [`Shape`] that falls within this.
@@ -125,6 +126,10 @@ pub struct ClippedShape {
 
     /// The shape
     pub shape: Shape,
+
+    /// How to combine the shape with what is painted below it.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub blend_mode: BlendMode,
 }
 
 impl ClippedShape {
@@ -133,7 +138,11 @@ impl ClippedShape {
     /// If using a [`PaintCallback`], note that only the rect is scaled as opposed
     /// to other shapes where the stroke is also scaled.
     pub fn transform(&mut self, transform: emath::TSTransform) {
-        let Self { clip_rect, shape } = self;
+        let Self {
+            clip_rect,
+            shape,
+            blend_mode: _,
+        } = self;
         *clip_rect = transform * *clip_rect;
         shape.transform(transform);
     }
@@ -143,6 +152,7 @@ impl ClippedShape {
 ///
 /// Everything is using logical points.
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct ClippedPrimitive {
     /// Clip / scissor rectangle.
     /// Only show the part of the [`Mesh`] that falls within this.
@@ -150,12 +160,42 @@ pub struct ClippedPrimitive {
 
     /// What to paint - either a [`Mesh`] or a [`PaintCallback`].
     pub primitive: Primitive,
+
+    /// How to combine the [`Mesh`] with what is painted below it.
+    ///
+    /// Ignored for [`PaintCallback`]s.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub blend_mode: BlendMode,
+}
+
+/// How the colors of a [`ClippedPrimitive`] are combined with what is already painted.
+///
+/// With `src` being the (premultiplied) color being painted and `dst` the color already there:
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum BlendMode {
+    /// `src + dst * (1 - src.a)`: paint on top of what is there.
+    #[default]
+    Normal,
+
+    /// `src + dst`: brighten what is there, e.g. for glow effects.
+    Additive,
+
+    /// `src * dst + dst * (1 - src.a)`: darken what is there.
+    ///
+    /// Painting white or transparent leaves the colors unchanged.
+    Multiply,
 }
 
 /// A rendering primitive - either a [`Mesh`] or a [`PaintCallback`].
+///
+/// With the `serde` feature, [`Primitive::Callback`] can not be serialized.
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub enum Primitive {
     Mesh(Mesh),
+
+    #[cfg_attr(feature = "serde", serde(skip))]
     Callback(PaintCallback),
 }
 
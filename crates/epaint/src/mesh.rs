@@ -114,6 +114,30 @@ impl Mesh {
         bounds
     }
 
+    /// The distance from `pos` to the closest triangle, or zero if `pos` is inside one.
+    pub fn distance_to_pos(&self, pos: Pos2) -> f32 {
+        let mut distance = f32::INFINITY;
+        for [a, b, c] in self.triangles() {
+            let [a, b, c] = [a, b, c].map(|i| self.vertices[i as usize].pos);
+
+            // A zero-area triangle has nothing inside it, but all its edges would pass the test below:
+            let area = (b - a).x * (c - a).y - (b - a).y * (c - a).x;
+            if area != 0.0 {
+                // Same sign for all three edges means we're inside, regardless of winding order:
+                let cross = |p: Pos2, q: Pos2| (q - p).x * (pos - p).y - (q - p).y * (pos - p).x;
+                let [ab, bc, ca] = [cross(a, b), cross(b, c), cross(c, a)];
+                if (0.0 <= ab && 0.0 <= bc && 0.0 <= ca) || (ab <= 0.0 && bc <= 0.0 && ca <= 0.0) {
+                    return 0.0;
+                }
+            }
+
+            for [p, q] in [[a, b], [b, c], [c, a]] {
+                distance = distance.min(crate::shapes::hit_test::distance_to_segment(pos, p, q));
+            }
+        }
+        distance
+    }
+
     /// Append all the indices and vertices of `other` to `self`.
     ///
     /// Panics when `other` mesh has a different texture.
@@ -1,7 +1,7 @@
 use std::sync::Arc;
 
 use crate::{
-    CircleShape, Color32, ColorMode, CubicBezierShape, EllipseShape, Mesh, PathShape,
+    ArcShape, CircleShape, Color32, ColorMode, CubicBezierShape, EllipseShape, Mesh, PathShape,
     QuadraticBezierShape, RectShape, Shape, TextShape, color,
 };
 
@@ -58,6 +58,15 @@ pub fn adjust_colors(
             fill,
             stroke,
         })
+        | Shape::Arc(ArcShape {
+            center: _,
+            radius: _,
+            inner_radius: _,
+            start_angle: _,
+            end_angle: _,
+            fill,
+            stroke,
+        })
         | Shape::Rect(RectShape {
             rect: _,
             corner_radius: _,
@@ -80,6 +89,7 @@ pub fn adjust_colors(
             override_text_color,
             opacity_factor: _,
             angle: _,
+            path: _,
         }) => {
             adjust_color(&mut underline.color);
             adjust_color(fallback_color);
@@ -0,0 +1,238 @@
+use std::f32::consts::{FRAC_PI_2, TAU};
+
+use crate::*;
+
+/// How to paint a slice of a circle (a pie slice) or of a ring (a donut segment).
+///
+/// Angles are in radians, going clockwise from the positive x axis.
+/// A sweep of a full turn or more paints the whole circle or ring.
+///
+/// Use a filled ring segment for thick arcs, e.g. in gauges,
+/// and pie slices for pie charts.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct ArcShape {
+    pub center: Pos2,
+
+    /// The outer radius.
+    pub radius: f32,
+
+    /// Zero for a pie slice, otherwise the radius of the hole of the ring.
+    pub inner_radius: f32,
+
+    pub start_angle: f32,
+    pub end_angle: f32,
+
+    pub fill: Color32,
+
+    /// The stroke is painted outside of the outline.
+    pub stroke: Stroke,
+}
+
+impl ArcShape {
+    /// A slice of a pie, from `start_angle` to `end_angle` radians clockwise.
+    #[inline]
+    pub fn pie(
+        center: Pos2,
+        radius: f32,
+        start_angle: f32,
+        end_angle: f32,
+        fill_color: impl Into<Color32>,
+        stroke: impl Into<Stroke>,
+    ) -> Self {
+        Self {
+            center,
+            radius,
+            inner_radius: 0.0,
+            start_angle,
+            end_angle,
+            fill: fill_color.into(),
+            stroke: stroke.into(),
+        }
+    }
+
+    /// A segment of a ring between `inner_radius` and `radius`,
+    /// from `start_angle` to `end_angle` radians clockwise.
+    #[inline]
+    pub fn ring(
+        center: Pos2,
+        inner_radius: f32,
+        radius: f32,
+        start_angle: f32,
+        end_angle: f32,
+        fill_color: impl Into<Color32>,
+        stroke: impl Into<Stroke>,
+    ) -> Self {
+        Self {
+            inner_radius,
+            ..Self::pie(center, radius, start_angle, end_angle, fill_color, stroke)
+        }
+    }
+
+    /// The start angle and the sweep, with a positive sweep of at most a full turn.
+    pub(crate) fn start_and_sweep(&self) -> (f32, f32) {
+        let sweep = (self.end_angle - self.start_angle).clamp(-TAU, TAU);
+        if sweep < 0.0 {
+            (self.start_angle + sweep, -sweep)
+        } else {
+            (self.start_angle, sweep)
+        }
+    }
+
+    /// The inner radius, limited to the outer radius.
+    pub(crate) fn clamped_inner_radius(&self) -> f32 {
+        self.inner_radius.clamp(0.0, self.radius.max(0.0))
+    }
+
+    /// The visual bounding rectangle (includes stroke width)
+    pub fn visual_bounding_rect(&self) -> Rect {
+        if (self.fill == Color32::TRANSPARENT && self.stroke.is_empty())
+            || self.radius <= 0.0
+            || self.start_angle == self.end_angle
+        {
+            return Rect::NOTHING;
+        }
+
+        let (start, sweep) = self.start_and_sweep();
+        let inner_radius = self.clamped_inner_radius();
+        let point = |radius: f32, angle: f32| self.center + radius * Vec2::angled(angle);
+
+        let mut rect = Rect::from_two_pos(point(self.radius, start), point(inner_radius, start));
+        rect.extend_with(point(self.radius, start + sweep));
+        rect.extend_with(point(inner_radius, start + sweep));
+
+        // The arc reaches furthest out at the quarter turns it passes:
+        let mut quarter = (start / FRAC_PI_2).ceil() * FRAC_PI_2;
+        while quarter < start + sweep {
+            rect.extend_with(point(self.radius, quarter));
+            quarter += FRAC_PI_2;
+        }
+
+        rect.expand(self.stroke.width)
+    }
+
+    /// The distance from `pos` to the fill or stroke, or zero if `pos` is on the shape.
+    ///
+    /// See [`Shape::distance_to_pos`].
+    pub fn distance_to_pos(&self, pos: Pos2) -> f32 {
+        if self.radius <= 0.0 || self.start_angle == self.end_angle {
+            return f32::INFINITY;
+        }
+
+        let (start, sweep) = self.start_and_sweep();
+        let inner_radius = self.clamped_inner_radius();
+        let offset = pos - self.center;
+        let r = offset.length();
+
+        let edge_distance = |angle: f32| {
+            let dir = Vec2::angled(angle);
+            super::hit_test::distance_to_segment(
+                pos,
+                self.center + inner_radius * dir,
+                self.center + self.radius * dir,
+            )
+        };
+
+        let within_sweep = TAU <= sweep || (offset.angle() - start).rem_euclid(TAU) <= sweep;
+        let signed_distance = if within_sweep {
+            let radial = (r - self.radius).max(inner_radius - r);
+            if radial < 0.0 && sweep < TAU {
+                // Inside: also limited by the straight edges.
+                radial.max(-edge_distance(start).min(edge_distance(start + sweep)))
+            } else {
+                radial
+            }
+        } else {
+            edge_distance(start).min(edge_distance(start + sweep))
+        };
+
+        super::hit_test::distance_from_signed(
+            signed_distance,
+            self.fill != Color32::TRANSPARENT,
+            self.stroke.width,
+            StrokeKind::Outside,
+        )
+    }
+}
+
+impl From<ArcShape> for Shape {
+    #[inline(always)]
+    fn from(shape: ArcShape) -> Self {
+        Self::Arc(shape)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f32::consts::{FRAC_PI_2, PI, TAU};
+
+    use emath::pos2;
+
+    use super::*;
+
+    fn tessellate(shape: ArcShape, feathering: bool) -> Mesh {
+        let options = TessellationOptions {
+            feathering,
+            ..Default::default()
+        };
+        let mut mesh = Mesh::default();
+        Tessellator::new(1.0, options, [1, 1], vec![]).tessellate_arc(shape, &mut mesh);
+        assert!(mesh.is_valid());
+        mesh
+    }
+
+    fn area(mesh: &Mesh) -> f32 {
+        mesh.indices
+            .chunks_exact(3)
+            .map(|t| {
+                let [a, b, c] = [0, 1, 2].map(|i| mesh.vertices[t[i] as usize].pos);
+                let (ab, ac) = (b - a, c - a);
+                0.5 * (ab.x * ac.y - ab.y * ac.x).abs()
+            })
+            .sum()
+    }
+
+    #[test]
+    fn arc_area_and_bounds() {
+        let center = pos2(100.0, 100.0);
+
+        let quarter = ArcShape::pie(center, 10.0, 0.0, FRAC_PI_2, Color32::RED, Stroke::NONE);
+        let expected = PI * 100.0 / 4.0;
+        assert!((area(&tessellate(quarter, false)) - expected).abs() < 0.01 * expected);
+        let rect = quarter.visual_bounding_rect();
+        assert!((rect.min - center).length() < 1e-4);
+        assert!((rect.max - (center + vec2(10.0, 10.0))).length() < 1e-4);
+
+        // Non-convex, counter-clockwise, and with feathering:
+        let ring = ArcShape::ring(
+            center,
+            5.0,
+            10.0,
+            PI,
+            -FRAC_PI_2,
+            Color32::RED,
+            Stroke::NONE,
+        );
+        let expected = 0.75 * PI * (100.0 - 25.0);
+        assert!((area(&tessellate(ring, false)) - expected).abs() < 0.01 * expected);
+        // The feathering adds half a point around the outline:
+        let perimeter = 0.75 * TAU * (10.0 + 5.0) + 2.0 * 5.0;
+        let feathered = expected + 0.5 * perimeter;
+        assert!((area(&tessellate(ring, true)) - feathered).abs() < 0.01 * feathered);
+
+        let donut = ArcShape::ring(center, 5.0, 10.0, 0.0, TAU, Color32::RED, Stroke::NONE);
+        let expected = PI * (100.0 - 25.0);
+        assert!((area(&tessellate(donut, false)) - expected).abs() < 0.01 * expected);
+        let rect = donut.visual_bounding_rect();
+        assert!((rect.size() - vec2(20.0, 20.0)).length() < 1e-4);
+
+        assert!(ring.distance_to_pos(center + vec2(0.0, 7.5)) == 0.0);
+        assert!(!Shape::from(ring).contains(center), "In the hole");
+        assert!(
+            !Shape::from(ring).contains(center + vec2(-5.0, -5.0)),
+            "Outside the sweep"
+        );
+        assert!((ring.distance_to_pos(center + vec2(0.0, -12.0)) - 2.0).abs() < 1e-4);
+        assert!((quarter.distance_to_pos(center + vec2(5.0, -3.0)) - 3.0).abs() < 1e-4);
+    }
+}
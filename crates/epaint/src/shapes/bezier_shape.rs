@@ -139,6 +139,26 @@ impl CubicBezierShape {
         }
     }
 
+    /// The distance from `pos` to the fill or stroke, or zero if `pos` is on the curve.
+    ///
+    /// The curve is flattened to a tenth of a point before measuring.
+    /// See [`Shape::distance_to_pos`].
+    pub fn distance_to_pos(&self, pos: Pos2) -> f32 {
+        // The curve is always within the convex hull of its control points:
+        let bounds = Rect::from_points(&self.points).expand(self.stroke.width);
+        let outside = bounds.distance_to_pos(pos);
+        if 0.0 < outside {
+            return outside;
+        }
+        super::path_shape::path_distance_to_pos(
+            pos,
+            &self.flatten(Some(super::hit_test::FLATTEN_TOLERANCE)),
+            self.closed,
+            self.fill != Color32::TRANSPARENT,
+            &self.stroke,
+        )
+    }
+
     /// split the original cubic curve into a new one within a range.
     pub fn split_range(&self, t_range: Range<f32>) -> Self {
         debug_assert!(
@@ -495,6 +515,26 @@ impl QuadraticBezierShape {
         }
     }
 
+    /// The distance from `pos` to the fill or stroke, or zero if `pos` is on the curve.
+    ///
+    /// The curve is flattened to a tenth of a point before measuring.
+    /// See [`Shape::distance_to_pos`].
+    pub fn distance_to_pos(&self, pos: Pos2) -> f32 {
+        // The curve is always within the convex hull of its control points:
+        let bounds = Rect::from_points(&self.points).expand(self.stroke.width);
+        let outside = bounds.distance_to_pos(pos);
+        if 0.0 < outside {
+            return outside;
+        }
+        super::path_shape::path_distance_to_pos(
+            pos,
+            &self.flatten(Some(super::hit_test::FLATTEN_TOLERANCE)),
+            self.closed,
+            self.fill != Color32::TRANSPARENT,
+            &self.stroke,
+        )
+    }
+
     /// Calculate the point (x,y) at t based on the quadratic Bézier curve equation.
     /// t is in [0.0,1.0]
     /// [Bézier Curve](https://en.wikipedia.org/wiki/B%C3%A9zier_curve#Quadratic_B.C3.A9zier_curves)
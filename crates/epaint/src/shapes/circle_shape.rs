@@ -42,6 +42,18 @@ impl CircleShape {
             )
         }
     }
+
+    /// The distance from `pos` to the fill or stroke, or zero if `pos` is on the circle.
+    ///
+    /// See [`Shape::distance_to_pos`].
+    pub fn distance_to_pos(&self, pos: Pos2) -> f32 {
+        super::hit_test::distance_from_signed(
+            pos.distance(self.center) - self.radius,
+            self.fill != Color32::TRANSPARENT,
+            self.stroke.width,
+            crate::StrokeKind::Middle,
+        )
+    }
 }
 
 impl From<CircleShape> for Shape {
@@ -44,6 +44,19 @@ impl EllipseShape {
             )
         }
     }
+
+    /// The distance from `pos` to the fill or stroke, or zero if `pos` is on the ellipse.
+    ///
+    /// This is approximate away from the ellipse.
+    /// See [`Shape::distance_to_pos`].
+    pub fn distance_to_pos(&self, pos: Pos2) -> f32 {
+        super::hit_test::distance_from_signed(
+            super::hit_test::signed_distance_to_ellipse(pos, self.center, self.radius),
+            self.fill != Color32::TRANSPARENT,
+            self.stroke.width,
+            StrokeKind::Middle,
+        )
+    }
 }
 
 impl From<EllipseShape> for Shape {
@@ -0,0 +1,187 @@
+//! Geometry shared by the `distance_to_pos` functions of the shapes.
+
+use emath::{Pos2, Vec2};
+
+use crate::StrokeKind;
+
+/// Curves are flattened to this precision (in points) before being hit-tested.
+pub(crate) const FLATTEN_TOLERANCE: f32 = 0.1;
+
+/// The distance from `pos` to the line segment between `a` and `b`.
+pub(crate) fn distance_to_segment(pos: Pos2, a: Pos2, b: Pos2) -> f32 {
+    let ab = b - a;
+    let length_sq = ab.length_sq();
+    if length_sq <= 0.0 {
+        return pos.distance(a);
+    }
+    let t = ((pos - a).dot(ab) / length_sq).clamp(0.0, 1.0);
+    pos.distance(a + t * ab)
+}
+
+/// The distance from `pos` to a line through `points`.
+///
+/// If `closed`, the distance is negative inside the polygon (using the even-odd rule).
+pub(crate) fn signed_distance_to_path(pos: Pos2, points: &[Pos2], closed: bool) -> f32 {
+    let Some(&first) = points.first() else {
+        return f32::INFINITY;
+    };
+
+    let mut distance = pos.distance(first);
+    let mut inside = false;
+    let closing = closed.then(|| [first, *points.last().unwrap_or(&first)]);
+    let segments = points
+        .windows(2)
+        .map(|w| [w[0], w[1]])
+        .chain(closing.map(|[first, last]| [last, first]));
+    for [a, b] in segments {
+        distance = distance.min(distance_to_segment(pos, a, b));
+        if closed && ((a.y > pos.y) != (b.y > pos.y)) {
+            let x = a.x + (pos.y - a.y) / (b.y - a.y) * (b.x - a.x);
+            if pos.x < x {
+                inside = !inside;
+            }
+        }
+    }
+
+    if inside { -distance } else { distance }
+}
+
+/// The signed distance from `pos` to an ellipse, negative inside.
+///
+/// This is an approximation that is exact on the ellipse and for circles.
+pub(crate) fn signed_distance_to_ellipse(pos: Pos2, center: Pos2, radius: Vec2) -> f32 {
+    let radius = radius.max(Vec2::splat(f32::EPSILON));
+    let p = pos - center;
+    let k0 = (p / radius).length();
+    let k1 = (p / (radius * radius)).length();
+    if k1 <= 0.0 {
+        -radius.min_elem()
+    } else {
+        k0 * (k0 - 1.0) / k1
+    }
+}
+
+/// The distance from `pos` to the painted parts of a shape,
+/// given the signed distance to its outline (negative inside).
+///
+/// The stroke width always counts, even if the stroke is transparent,
+/// so that a line with [`crate::Stroke::NONE`] can still be hit-tested.
+pub(crate) fn distance_from_signed(
+    signed_distance: f32,
+    filled: bool,
+    stroke_width: f32,
+    stroke_kind: StrokeKind,
+) -> f32 {
+    let (inner, outer) = match stroke_kind {
+        StrokeKind::Inside => (-stroke_width, 0.0),
+        StrokeKind::Middle => (-0.5 * stroke_width, 0.5 * stroke_width),
+        StrokeKind::Outside => (0.0, stroke_width),
+    };
+    let stroke_distance = (inner - signed_distance)
+        .max(signed_distance - outer)
+        .max(0.0);
+    if filled {
+        stroke_distance.min(signed_distance.max(0.0))
+    } else {
+        stroke_distance
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use emath::{Rect, pos2, vec2};
+
+    use crate::{
+        Color32, CornerRadius, CubicBezierShape, Mesh, PathShape, RectShape, Shape, Stroke,
+        StrokeKind,
+    };
+
+    #[test]
+    fn test_shape_distance_to_pos() {
+        let circle = Shape::circle_filled(pos2(0.0, 0.0), 10.0, Color32::RED);
+        assert!(circle.contains(pos2(3.0, 4.0)));
+        assert_eq!(circle.distance_to_pos(pos2(0.0, 15.0)), 5.0);
+
+        let ring = Shape::circle_stroke(pos2(0.0, 0.0), 10.0, Stroke::new(2.0, Color32::RED));
+        assert!(!ring.contains(pos2(0.0, 0.0)), "Not filled");
+        assert!(ring.contains(pos2(10.5, 0.0)));
+        assert_eq!(ring.distance_to_pos(pos2(0.0, 5.0)), 4.0);
+
+        let line = Shape::line_segment([pos2(0.0, 0.0), pos2(10.0, 0.0)], (4.0, Color32::RED));
+        assert!(line.contains(pos2(5.0, 2.0)));
+        assert_eq!(line.distance_to_pos(pos2(13.0, 4.0)), 3.0);
+
+        let triangle = PathShape::convex_polygon(
+            vec![pos2(0.0, 0.0), pos2(10.0, 0.0), pos2(0.0, 10.0)],
+            Color32::RED,
+            Stroke::NONE,
+        );
+        assert!(triangle.distance_to_pos(pos2(2.0, 2.0)) == 0.0);
+        assert_eq!(triangle.distance_to_pos(pos2(-3.0, 5.0)), 3.0);
+
+        let rect = RectShape::new(
+            Rect::from_min_max(pos2(0.0, 0.0), pos2(20.0, 10.0)),
+            CornerRadius::same(5),
+            Color32::RED,
+            Stroke::new(2.0, Color32::BLUE),
+            StrokeKind::Outside,
+        );
+        assert!(rect.distance_to_pos(pos2(10.0, 5.0)) == 0.0);
+        assert!(
+            rect.distance_to_pos(pos2(10.0, 11.5)) == 0.0,
+            "In the stroke"
+        );
+        assert_eq!(rect.distance_to_pos(pos2(10.0, 15.0)), 3.0);
+        // The rounded corner is cut off:
+        assert!(0.0 < rect.distance_to_pos(pos2(-1.5, -1.5)));
+
+        let wire = CubicBezierShape::from_points_stroke(
+            [
+                pos2(0.0, 0.0),
+                pos2(50.0, 0.0),
+                pos2(50.0, 100.0),
+                pos2(100.0, 100.0),
+            ],
+            false,
+            Color32::TRANSPARENT,
+            Stroke::new(2.0, Color32::RED),
+        );
+        assert!(
+            wire.distance_to_pos(pos2(50.0, 50.0)) < 1.0,
+            "Middle of the curve"
+        );
+        assert!(wire.distance_to_pos(pos2(0.0, 100.0)) > 30.0);
+
+        let mut mesh = Mesh::default();
+        mesh.add_colored_rect(
+            Rect::from_min_size(pos2(0.0, 0.0), vec2(10.0, 10.0)),
+            Color32::RED,
+        );
+        let mesh = Shape::mesh(mesh);
+        assert!(mesh.contains(pos2(5.0, 5.0)));
+        assert_eq!(mesh.distance_to_pos(pos2(5.0, 12.0)), 2.0);
+
+        let both = Shape::Vec(vec![circle, mesh]);
+        assert_eq!(both.distance_to_pos(pos2(5.0, 12.0)), 2.0);
+        assert_eq!(Shape::Noop.distance_to_pos(pos2(0.0, 0.0)), f32::INFINITY);
+    }
+
+    #[test]
+    fn test_mesh_with_degenerate_triangle() {
+        let mut mesh = Mesh::default();
+        for pos in [pos2(0.0, 0.0), pos2(10.0, 0.0), pos2(20.0, 0.0)] {
+            mesh.colored_vertex(pos, Color32::RED);
+        }
+        mesh.add_triangle(0, 1, 2);
+        assert_eq!(mesh.distance_to_pos(pos2(5.0, 3.0)), 3.0);
+        assert_eq!(
+            mesh.distance_to_pos(pos2(30.0, 0.0)),
+            10.0,
+            "On the line, but past it"
+        );
+
+        // All corners in the same place:
+        mesh.add_triangle(1, 1, 1);
+        assert_eq!(mesh.distance_to_pos(pos2(10.0, 4.0)), 4.0);
+    }
+}
@@ -1,6 +1,8 @@
+mod arc_shape;
 mod bezier_shape;
 mod circle_shape;
 mod ellipse_shape;
+pub(crate) mod hit_test;
 mod paint_callback;
 mod path_shape;
 mod rect_shape;
@@ -8,6 +10,7 @@ mod shape;
 mod text_shape;
 
 pub use self::{
+    arc_shape::ArcShape,
     bezier_shape::{CubicBezierShape, QuadraticBezierShape},
     circle_shape::CircleShape,
     ellipse_shape::EllipseShape,
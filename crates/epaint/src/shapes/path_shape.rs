@@ -71,6 +71,40 @@ impl PathShape {
             Rect::from_points(&self.points).expand(self.stroke.width / 2.0)
         }
     }
+
+    /// The distance from `pos` to the fill or stroke, or zero if `pos` is on the path.
+    ///
+    /// See [`Shape::distance_to_pos`].
+    pub fn distance_to_pos(&self, pos: Pos2) -> f32 {
+        path_distance_to_pos(
+            pos,
+            &self.points,
+            self.closed,
+            self.fill != Color32::TRANSPARENT,
+            &self.stroke,
+        )
+    }
+}
+
+/// Shared by [`PathShape`] and the flattened bézier curves.
+pub(crate) fn path_distance_to_pos(
+    pos: Pos2,
+    points: &[Pos2],
+    closed: bool,
+    filled: bool,
+    stroke: &PathStroke,
+) -> f32 {
+    let closed = closed && 2 < points.len();
+    super::hit_test::distance_from_signed(
+        super::hit_test::signed_distance_to_path(pos, points, closed),
+        closed && filled,
+        stroke.width,
+        if closed {
+            stroke.kind
+        } else {
+            StrokeKind::Middle
+        },
+    )
 }
 
 impl From<PathShape> for Shape {
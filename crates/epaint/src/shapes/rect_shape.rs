@@ -172,6 +172,38 @@ impl RectShape {
         }
     }
 
+    /// The distance from `pos` to the fill or stroke, or zero if `pos` is on the rectangle.
+    ///
+    /// Takes the rounded corners into account, but not the blur.
+    /// See [`Shape::distance_to_pos`].
+    pub fn distance_to_pos(&self, pos: Pos2) -> f32 {
+        if self.rect.is_negative() {
+            return f32::INFINITY;
+        }
+
+        let center = self.rect.center();
+        let half_size = 0.5 * self.rect.size();
+        let cr = self.corner_radius;
+        let radius = match (center.x < pos.x, center.y < pos.y) {
+            (false, false) => cr.nw,
+            (true, false) => cr.ne,
+            (false, true) => cr.sw,
+            (true, true) => cr.se,
+        };
+        let radius = f32::from(radius).min(half_size.min_elem());
+
+        // Signed distance to a rounded box:
+        let q = (pos - center).abs() - half_size + Vec2::splat(radius);
+        let signed_distance = q.max_elem().min(0.0) + q.max(Vec2::ZERO).length() - radius;
+
+        super::hit_test::distance_from_signed(
+            signed_distance,
+            self.fill != Color32::TRANSPARENT,
+            self.stroke.width,
+            self.stroke_kind,
+        )
+    }
+
     /// The texture to use when painting this rectangle, if any.
     ///
     /// If no texture is set, this will return [`TextureId::default`].
@@ -11,8 +11,8 @@ use crate::{
 };
 
 use super::{
-    CircleShape, CubicBezierShape, EllipseShape, PaintCallback, PathShape, QuadraticBezierShape,
-    RectShape, TextShape,
+    ArcShape, CircleShape, CubicBezierShape, EllipseShape, PaintCallback, PathShape,
+    QuadraticBezierShape, RectShape, TextShape,
 };
 
 /// A paint primitive such as a circle or a piece of text.
@@ -22,8 +22,11 @@ use super::{
 /// but storing them should also be fine with one exception:
 /// [`Shape::Text`] depends on the current `pixels_per_point` (dpi scale)
 /// and so must be recreated every time `pixels_per_point` changes.
+///
+/// With the `serde` feature, [`Shape::Callback`] can not be serialized.
 #[must_use = "Add a Shape to a Painter"]
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub enum Shape {
     /// Paint nothing. This can be useful as a placeholder.
     Noop,
@@ -38,6 +41,9 @@ pub enum Shape {
     /// Ellipse with optional outline and fill.
     Ellipse(EllipseShape),
 
+    /// A pie slice or ring segment with optional outline and fill.
+    Arc(ArcShape),
+
     /// A line between two points.
     LineSegment { points: [Pos2; 2], stroke: Stroke },
 
@@ -67,6 +73,7 @@ pub enum Shape {
     CubicBezier(CubicBezierShape),
 
     /// Backend-specific painting.
+    #[cfg_attr(feature = "serde", serde(skip))]
     Callback(PaintCallback),
 }
 
@@ -369,6 +376,7 @@ impl Shape {
             }
             Self::Circle(circle_shape) => circle_shape.visual_bounding_rect(),
             Self::Ellipse(ellipse_shape) => ellipse_shape.visual_bounding_rect(),
+            Self::Arc(arc_shape) => arc_shape.visual_bounding_rect(),
             Self::LineSegment { points, stroke } => {
                 if stroke.is_empty() {
                     Rect::NOTHING
@@ -385,6 +393,48 @@ impl Shape {
             Self::Callback(custom) => custom.rect,
         }
     }
+
+    /// The distance from `pos` to what this shape paints, or zero if `pos` is on it.
+    ///
+    /// Inside a fill, or within a stroke, the distance is zero.
+    /// The stroke width always counts, even if the stroke is transparent,
+    /// so a curve with [`Stroke::NONE`] is measured from its center line.
+    /// Text and paint callbacks are measured from their bounding rectangles.
+    ///
+    /// Use this to hit-test custom widgets, e.g. `shape.distance_to_pos(pointer) <= 4.0`
+    /// to let the user click a thin wire without perfect aim.
+    pub fn distance_to_pos(&self, pos: Pos2) -> f32 {
+        match self {
+            Self::Noop => f32::INFINITY,
+            Self::Vec(shapes) => shapes
+                .iter()
+                .map(|shape| shape.distance_to_pos(pos))
+                .fold(f32::INFINITY, f32::min),
+            Self::Circle(circle_shape) => circle_shape.distance_to_pos(pos),
+            Self::Ellipse(ellipse_shape) => ellipse_shape.distance_to_pos(pos),
+            Self::Arc(arc_shape) => arc_shape.distance_to_pos(pos),
+            Self::LineSegment { points, stroke } => {
+                (super::hit_test::distance_to_segment(pos, points[0], points[1])
+                    - 0.5 * stroke.width)
+                    .max(0.0)
+            }
+            Self::Path(path_shape) => path_shape.distance_to_pos(pos),
+            Self::Rect(rect_shape) => rect_shape.distance_to_pos(pos),
+            Self::Text(text_shape) => text_shape.distance_to_pos(pos),
+            Self::Mesh(mesh) => mesh.distance_to_pos(pos),
+            Self::QuadraticBezier(bezier) => bezier.distance_to_pos(pos),
+            Self::CubicBezier(bezier) => bezier.distance_to_pos(pos),
+            Self::Callback(custom) => custom.rect.distance_to_pos(pos),
+        }
+    }
+
+    /// Is `pos` inside the fill or stroke of this shape?
+    ///
+    /// See [`Self::distance_to_pos`].
+    #[inline]
+    pub fn contains(&self, pos: Pos2) -> bool {
+        self.distance_to_pos(pos) <= 0.0
+    }
 }
 
 /// ## Inspection and transforms
@@ -438,6 +488,12 @@ impl Shape {
                 ellipse_shape.radius *= transform.scaling;
                 ellipse_shape.stroke.width *= transform.scaling;
             }
+            Self::Arc(arc_shape) => {
+                arc_shape.center = transform * arc_shape.center;
+                arc_shape.radius *= transform.scaling;
+                arc_shape.inner_radius *= transform.scaling;
+                arc_shape.stroke.width *= transform.scaling;
+            }
             Self::LineSegment { points, stroke } => {
                 for p in points {
                     *p = transform * *p;
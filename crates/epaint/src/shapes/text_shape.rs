@@ -39,6 +39,21 @@ pub struct TextShape {
     /// Rotate text by this many radians clockwise.
     /// The pivot is `pos` (the upper left corner of the text).
     pub angle: f32,
+
+    /// If set, the text follows this line instead of a straight one,
+    /// with each glyph rotated to the direction of the line.
+    ///
+    /// The text starts at the first point, and the line goes through
+    /// the vertical center of the first row.
+    /// Past the ends, the text continues straight ahead.
+    ///
+    /// [`Self::pos`] and [`Self::angle`] are ignored, and so are backgrounds,
+    /// underlines and strikethrough in the galley.
+    ///
+    /// See [`Self::on_path`].
+    ///
+    /// This is an `Arc<Vec>` rather than an `Arc<[Pos2]>` to keep [`Shape`] small.
+    pub path: Option<Arc<Vec<Pos2>>>,
 }
 
 impl TextShape {
@@ -55,18 +70,84 @@ impl TextShape {
             override_text_color: None,
             opacity_factor: 1.0,
             angle: 0.0,
+            path: None,
+        }
+    }
+
+    /// Lay out the text along a line, e.g. around a circle or along a flattened bezier curve.
+    ///
+    /// See [`Self::path`].
+    #[inline]
+    pub fn on_path(
+        path: impl Into<Vec<Pos2>>,
+        galley: Arc<Galley>,
+        fallback_color: Color32,
+    ) -> Self {
+        let path = Arc::new(path.into());
+        let pos = path.first().copied().unwrap_or_default();
+        Self {
+            path: Some(path),
+            ..Self::new(pos, galley, fallback_color)
         }
     }
 
     /// The visual bounding rectangle
     #[inline]
     pub fn visual_bounding_rect(&self) -> Rect {
+        if let Some(path) = &self.path {
+            let mut rect = Rect::NOTHING;
+            self.for_each_glyph_on_path(path, |_, _, positions| {
+                for pos in positions {
+                    rect.extend_with(pos);
+                }
+            });
+            return rect;
+        }
+
         self.galley
             .mesh_bounds
             .rotate_bb(emath::Rot2::from_angle(self.angle))
             .translate(self.pos.to_vec2())
     }
 
+    /// Calls `add_glyph` with each row, the index of a glyph quad in that row,
+    /// and where the four vertices of the quad end up along `path`.
+    pub(crate) fn for_each_glyph_on_path(
+        &self,
+        path: &[Pos2],
+        mut add_glyph: impl FnMut(&text::PlacedRow, usize, [Pos2; 4]),
+    ) {
+        let Some(first_row) = self.galley.rows.first() else {
+            return;
+        };
+        let path = TextPath::new(path);
+        let center_y = first_row.pos.y + 0.5 * first_row.size.y;
+        let start_x = self.galley.rect.min.x;
+
+        for row in &self.galley.rows {
+            let vertices = &row.visuals.mesh.vertices[row.visuals.glyph_vertex_range.clone()];
+            for (quad_index, quad) in vertices.chunks_exact(4).enumerate() {
+                let quad = [0, 1, 2, 3].map(|i| row.pos + quad[i].pos.to_vec2());
+                let center_x = quad.iter().map(|p| p.x).sum::<f32>() / 4.0;
+                let (origin, dir) = path.sample(center_x - start_x);
+                let normal = dir.rot90();
+                let positions = quad.map(|p| {
+                    let offset = p - pos2(center_x, center_y);
+                    origin + offset.x * dir + offset.y * normal
+                });
+                add_glyph(row, quad_index, positions);
+            }
+        }
+    }
+
+    /// The distance from `pos` to [`Self::visual_bounding_rect`].
+    ///
+    /// See [`Shape::distance_to_pos`].
+    #[inline]
+    pub fn distance_to_pos(&self, pos: Pos2) -> f32 {
+        self.visual_bounding_rect().distance_to_pos(pos)
+    }
+
     #[inline]
     pub fn with_underline(mut self, underline: Stroke) -> Self {
         self.underline = underline;
@@ -116,10 +197,14 @@ impl TextShape {
             override_text_color: _,
             opacity_factor: _,
             angle: _,
+            path,
         } = self;
 
         *pos = transform * *pos;
         underline.width *= transform.scaling;
+        if let Some(path) = path {
+            *path = Arc::new(path.iter().map(|&p| transform * p).collect());
+        }
 
         let Galley {
             job: _,
@@ -166,6 +251,55 @@ impl TextShape {
     }
 }
 
+/// A polyline that can be sampled at any distance along it.
+struct TextPath<'a> {
+    points: &'a [Pos2],
+
+    /// The distance along the path to each point.
+    distances: Vec<f32>,
+}
+
+impl<'a> TextPath<'a> {
+    fn new(points: &'a [Pos2]) -> Self {
+        let mut distance = 0.0;
+        let distances = std::iter::once(0.0)
+            .chain(points.windows(2).map(|w| {
+                distance += w[0].distance(w[1]);
+                distance
+            }))
+            .collect();
+        Self { points, distances }
+    }
+
+    /// The position at `distance` along the path, and the direction of the path there.
+    fn sample(&self, distance: f32) -> (Pos2, Vec2) {
+        let n = self.points.len();
+        let total = self.distances.last().copied().unwrap_or(0.0);
+        if n < 2 || total <= 0.0 {
+            return (self.points.first().copied().unwrap_or_default(), Vec2::X);
+        }
+
+        // The segment containing `distance`.
+        // Before the start and past the end we continue along the first and last segment.
+        let mut i = self
+            .distances
+            .partition_point(|&d| d <= distance)
+            .clamp(1, n - 1);
+        while self.distances[i] <= self.distances[i - 1] {
+            // Skip segments of zero length at the ends:
+            if distance < 0.0 {
+                i += 1;
+            } else {
+                i -= 1;
+            }
+        }
+
+        let dir = (self.points[i] - self.points[i - 1]).normalized();
+        let pos = self.points[i - 1] + (distance - self.distances[i - 1]) * dir;
+        (pos, dir)
+    }
+}
+
 impl From<TextShape> for Shape {
     #[inline(always)]
     fn from(shape: TextShape) -> Self {
@@ -211,4 +345,67 @@ mod tests {
         assert!(almost_equal(size_orig.x, size_rot.y, 1e-4));
         assert!(almost_equal(size_orig.y, size_rot.x, 1e-4));
     }
+
+    #[test]
+    fn text_on_path() {
+        let fonts = Fonts::new(
+            1.0,
+            1024,
+            AlphaFromCoverage::default(),
+            FontDefinitions::default(),
+        );
+        let galley = fonts.layout_no_wrap(
+            "testing123".to_owned(),
+            FontId::monospace(12.0),
+            Color32::BLACK,
+        );
+        let width = galley.rect.width();
+        let straight = TextShape::new(Pos2::ZERO, galley.clone(), Color32::BLACK)
+            .visual_bounding_rect()
+            .size();
+
+        // Straight down, with a zero-length segment at the start:
+        let down = TextShape::on_path(
+            vec![pos2(0.0, 0.0), pos2(0.0, 0.0), pos2(0.0, 100.0)],
+            galley.clone(),
+            Color32::BLACK,
+        )
+        .visual_bounding_rect();
+        assert!(almost_equal(down.width(), straight.y, 1e-3));
+        assert!(almost_equal(down.height(), straight.x, 1e-3));
+        assert!(down.center().x.abs() < 2.0, "Centered on the path");
+
+        // Past the end of a short path the text continues straight ahead:
+        let short = TextShape::on_path(
+            vec![pos2(0.0, 0.0), pos2(1.0, 0.0)],
+            galley.clone(),
+            Color32::BLACK,
+        )
+        .visual_bounding_rect();
+        assert!(width * 0.8 < short.width());
+
+        // Around a circle, glyphs are rotated to follow it:
+        let radius = 50.0;
+        let circle: Vec<Pos2> = (0..=64)
+            .map(|i| {
+                let angle = i as f32 / 64.0 * std::f32::consts::TAU;
+                pos2(radius * angle.cos(), radius * angle.sin())
+            })
+            .collect();
+        let text = TextShape::on_path(circle, galley.clone(), Color32::BLACK);
+        let rect = text.visual_bounding_rect();
+        assert!(rect.height() < width * 0.9, "Bent, not straight");
+        assert!(rect.max.x <= radius + straight.y);
+
+        let mut mesh = Mesh::default();
+        crate::Tessellator::new(1.0, Default::default(), [1024, 1024], vec![])
+            .tessellate_text(&text, &mut mesh);
+        let num_glyph_vertices: usize = galley
+            .rows
+            .iter()
+            .map(|row| row.visuals.glyph_vertex_range.len())
+            .sum();
+        assert_eq!(mesh.vertices.len(), num_glyph_vertices);
+        assert!(mesh.is_valid());
+    }
 }
@@ -205,6 +205,7 @@ impl PaintStats {
             Shape::Noop
             | Shape::Circle { .. }
             | Shape::Ellipse { .. }
+            | Shape::Arc { .. }
             | Shape::LineSegment { .. }
             | Shape::Rect { .. }
             | Shape::CubicBezier(_)
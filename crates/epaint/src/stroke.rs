@@ -111,6 +111,48 @@ pub enum StrokeKind {
     Outside,
 }
 
+/// How the corners of a stroked path are painted, see [`PathStroke::join`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum LineJoin {
+    /// Extend the edges until they meet in a sharp corner.
+    ///
+    /// Corners that would reach further out than [`Self::MITER_LIMIT`] half stroke widths
+    /// are painted as [`Self::Bevel`] instead.
+    /// Note that [`Path::add_open_points`](crate::tessellator::Path::add_open_points)
+    /// already cuts off corners sharper than a right angle.
+    #[default]
+    Miter,
+
+    /// Round off the outside of the corner with a circular arc.
+    Round,
+
+    /// Cut off the outside of the corner with a straight line.
+    Bevel,
+}
+
+impl LineJoin {
+    /// How far out a [`Self::Miter`] corner may reach, in units of half the stroke width.
+    ///
+    /// The same as the default in SVG, which turns corners sharper than about 29° into bevels.
+    pub const MITER_LIMIT: f32 = 4.0;
+}
+
+/// How the ends of an open stroked path are painted, see [`PathStroke::cap`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum LineCap {
+    /// End the line exactly at its end points.
+    #[default]
+    Butt,
+
+    /// Add a half circle to each end, with the diameter of the stroke width.
+    Round,
+
+    /// Extend each end by half the stroke width.
+    Square,
+}
+
 /// Describes the width and color of paths. The color can either be solid or provided by a callback. For more information, see [`ColorMode`]
 ///
 /// The default stroke is the same as [`Stroke::NONE`].
@@ -120,6 +162,12 @@ pub struct PathStroke {
     pub width: f32,
     pub color: ColorMode,
     pub kind: StrokeKind,
+
+    /// How to paint the corners of the path.
+    pub join: LineJoin,
+
+    /// How to paint the ends of open paths.
+    pub cap: LineCap,
 }
 
 impl Default for PathStroke {
@@ -135,6 +183,8 @@ impl PathStroke {
         width: 0.0,
         color: ColorMode::TRANSPARENT,
         kind: StrokeKind::Middle,
+        join: LineJoin::Miter,
+        cap: LineCap::Butt,
     };
 
     #[inline]
@@ -142,7 +192,7 @@ impl PathStroke {
         Self {
             width: width.into(),
             color: ColorMode::Solid(color.into()),
-            kind: StrokeKind::Middle,
+            ..Self::NONE
         }
     }
 
@@ -157,7 +207,7 @@ impl PathStroke {
         Self {
             width: width.into(),
             color: ColorMode::UV(Arc::new(callback)),
-            kind: StrokeKind::Middle,
+            ..Self::NONE
         }
     }
 
@@ -193,6 +243,18 @@ impl PathStroke {
         }
     }
 
+    /// Set how to paint the corners of the path, see [`LineJoin`].
+    #[inline]
+    pub fn with_join(self, join: LineJoin) -> Self {
+        Self { join, ..self }
+    }
+
+    /// Set how to paint the ends of open paths, see [`LineCap`].
+    #[inline]
+    pub fn with_cap(self, cap: LineCap) -> Self {
+        Self { cap, ..self }
+    }
+
     /// True if width is zero or color is solid and transparent
     #[inline]
     pub fn is_empty(&self) -> bool {
@@ -216,11 +278,7 @@ impl From<Stroke> for PathStroke {
             // Important, since we use the stroke color when doing feathering of the fill!
             Self::NONE
         } else {
-            Self {
-                width: value.width,
-                color: ColorMode::Solid(value.color),
-                kind: StrokeKind::Middle,
-            }
+            Self::new(value.width, value.color)
         }
     }
 }
@@ -8,10 +8,10 @@
 use emath::{GuiRounding as _, NumExt as _, Pos2, Rect, Rot2, Vec2, pos2, remap, vec2};
 
 use crate::{
-    CircleShape, ClippedPrimitive, ClippedShape, Color32, CornerRadiusF32, CubicBezierShape,
-    EllipseShape, Mesh, PathShape, Primitive, QuadraticBezierShape, RectShape, Shape, Stroke,
-    StrokeKind, TextShape, TextureId, Vertex, WHITE_UV, color::ColorMode, emath,
-    stroke::PathStroke, texture_atlas::PreparedDisc,
+    ArcShape, BlendMode, CircleShape, ClippedPrimitive, ClippedShape, Color32, ColorVision,
+    CornerRadiusF32, CubicBezierShape, EllipseShape, LineCap, LineJoin, Mesh, PathShape, Primitive,
+    QuadraticBezierShape, RectShape, Shape, Stroke, StrokeKind, TextShape, TextureId, Vertex,
+    WHITE_UV, color::ColorMode, emath, stroke::PathStroke, texture_atlas::PreparedDisc,
 };
 
 // ----------------------------------------------------------------------------
@@ -720,6 +720,14 @@ pub struct TessellationOptions {
     ///
     /// The default is `false` to save performance.
     pub validate_meshes: bool,
+
+    /// Transform all vertex colors to simulate how they look with color blindness.
+    ///
+    /// Useful for checking that the colors of an app can be told apart by everyone.
+    /// Does not affect the colors of textures, e.g. images.
+    ///
+    /// Default: [`ColorVision::Normal`].
+    pub color_vision: ColorVision,
 }
 
 impl Default for TessellationOptions {
@@ -739,6 +747,7 @@ impl Default for TessellationOptions {
             epsilon: 1.0e-5,
             parallel_tessellation: true,
             validate_meshes: false,
+            color_vision: ColorVision::Normal,
         }
     }
 }
@@ -820,6 +829,66 @@ fn fill_closed_path(feathering: f32, path: &mut [PathPoint], fill_color: Color32
     }
 }
 
+/// Fill an area that need not be convex, given its outline and a triangulation of it.
+///
+/// The outline is one or more closed loops, with normals pointing out of the area.
+/// The `triangles` index into the points of all loops, in order.
+fn fill_triangulated(
+    feathering: f32,
+    loops: &[&[PathPoint]],
+    triangles: &[[u32; 3]],
+    fill_color: Color32,
+    out: &mut Mesh,
+) {
+    if fill_color == Color32::TRANSPARENT {
+        return;
+    }
+
+    let idx = out.vertices.len() as u32;
+
+    if 0.0 < feathering {
+        let num_points: usize = loops.iter().map(|points| points.len()).sum();
+        out.reserve_triangles(triangles.len() + 2 * num_points);
+        out.reserve_vertices(2 * num_points);
+
+        // The feathering, with an inner and outer vertex for each point:
+        let mut first = idx;
+        for points in loops {
+            let n = points.len() as u32;
+            let mut i0 = n.saturating_sub(1);
+            for i1 in 0..n {
+                let p1 = &points[i1 as usize];
+                let dm = 0.5 * feathering * p1.normal;
+                out.colored_vertex(p1.pos - dm, fill_color);
+                out.colored_vertex(p1.pos + dm, Color32::TRANSPARENT);
+            }
+            for i1 in 0..n {
+                out.add_triangle(first + 2 * i1, first + 2 * i0, first + 2 * i0 + 1);
+                out.add_triangle(first + 2 * i0 + 1, first + 2 * i1 + 1, first + 2 * i1);
+                i0 = i1;
+            }
+            first += 2 * n;
+        }
+
+        // The fill, between the inner vertices:
+        for &[a, b, c] in triangles {
+            out.add_triangle(idx + 2 * a, idx + 2 * b, idx + 2 * c);
+        }
+    } else {
+        out.reserve_triangles(triangles.len());
+        for points in loops {
+            out.vertices.extend(points.iter().map(|p| Vertex {
+                pos: p.pos,
+                uv: WHITE_UV,
+                color: fill_color,
+            }));
+        }
+        for &[a, b, c] in triangles {
+            out.add_triangle(idx + a, idx + b, idx + c);
+        }
+    }
+}
+
 /// Like [`fill_closed_path`] but with texturing.
 ///
 /// The `uv_from_pos` is called for each vertex position.
@@ -998,6 +1067,22 @@ fn stroke_and_fill_path(
         ColorMode::UV(fun) => fun(uv_bbox, pos),
     };
 
+    if needs_joins_or_caps(path, path_type, stroke) {
+        if color_fill != Color32::TRANSPARENT {
+            // Fill up to the inner edge of the stroke, and paint the stroke on top:
+            let mut inner: Vec<PathPoint> = path
+                .iter()
+                .map(|point| PathPoint {
+                    pos: point.pos - 0.5 * stroke.width * point.normal,
+                    normal: point.normal,
+                })
+                .collect();
+            fill_closed_path(feathering, &mut inner, color_fill, out);
+        }
+        let color = |pos| get_color(&stroke.color, pos);
+        return stroke_with_joins_and_caps(feathering, path, path_type, stroke, &color, out);
+    }
+
     if 0.0 < feathering {
         let color_outer = Color32::TRANSPARENT;
         let color_middle = &stroke.color;
@@ -1133,11 +1218,10 @@ fn stroke_and_fill_path(
                     //   |    |  que  |    |
                     //   |    |       |    |
 
-                    // (in the future it would be great with an option to add a circular end instead)
+                    // Other line caps are handled by `stroke_with_joins_and_caps`.
 
                     // TODO(emilk): we should probably shrink before adding the line caps,
                     // so that we don't add to the area of the line.
-                    // TODO(emilk): make line caps optional.
 
                     out.reserve_triangles(6 * n as usize + 4);
                     out.reserve_vertices(4 * n as usize);
@@ -1289,6 +1373,219 @@ fn stroke_and_fill_path(
     }
 }
 
+/// Does the path need more than the miter joins and butt caps of [`stroke_and_fill_path`]?
+fn needs_joins_or_caps(path: &[PathPoint], path_type: PathType, stroke: &PathStroke) -> bool {
+    // The normals of the path are already scaled for miter joins,
+    // so their length is how far out the corners reach:
+    let miter_limit_sq = LineJoin::MITER_LIMIT * LineJoin::MITER_LIMIT;
+    stroke.join != LineJoin::Miter
+        || (path_type == PathType::Open && stroke.cap != LineCap::Butt)
+        || path.iter().any(|p| miter_limit_sq < p.normal.length_sq())
+}
+
+/// How many segments to use for a circular arc spanning `angle` radians.
+fn round_segments(angle: f32, radius: f32) -> u32 {
+    ((angle.abs() * radius / 2.0).ceil() as u32).clamp(2, 64)
+}
+
+/// Strokes a path, respecting [`PathStroke::join`] and [`PathStroke::cap`].
+///
+/// The normals of the path are ignored, and recalculated from the points.
+fn stroke_with_joins_and_caps(
+    feathering: f32,
+    path: &[PathPoint],
+    path_type: PathType,
+    stroke: &PathStroke,
+    get_color: &dyn Fn(Pos2) -> Color32,
+    out: &mut Mesh,
+) {
+    use std::f32::consts::{PI, TAU};
+
+    let closed = path_type == PathType::Closed;
+
+    let mut points: Vec<Pos2> = Vec::with_capacity(path.len());
+    for point in path {
+        if points
+            .last()
+            .is_none_or(|last| 1e-8 < last.distance_sq(point.pos))
+        {
+            points.push(point.pos);
+        }
+    }
+    if closed {
+        while 2 < points.len() && points[0].distance_sq(points[points.len() - 1]) <= 1e-8 {
+            points.pop();
+        }
+    }
+    let n = points.len();
+    if n < 2 {
+        return;
+    }
+
+    // Like in `stroke_and_fill_path`, thin lines are painted as a ridge two feather-widths wide:
+    let thin_line = 0.0 < feathering && stroke.width <= 0.9 * feathering;
+    let (inner_rad, outer_rad, opacity) = if thin_line {
+        (0.0, feathering, stroke.width / feathering)
+    } else {
+        (
+            0.5 * (stroke.width - feathering),
+            0.5 * (stroke.width + feathering),
+            1.0,
+        )
+    };
+    let color_outer = Color32::TRANSPARENT;
+    let color = |pos: Pos2| mul_color(get_color(pos), opacity);
+
+    // Each station is a point along the stroke with two offsets,
+    // one to each side, in units of half the stroke width.
+    let mut stations: Vec<(Pos2, Vec2, Vec2)> = Vec::with_capacity(n + 2);
+    for i in 0..n {
+        let p = points[i];
+        let prev = if 0 < i {
+            Some(points[i - 1])
+        } else {
+            closed.then(|| points[n - 1])
+        };
+        let next = if i + 1 < n {
+            Some(points[i + 1])
+        } else {
+            closed.then(|| points[0])
+        };
+
+        let (Some(prev), Some(next)) = (prev, next) else {
+            // An end of an open path:
+            let dir = if let Some(next) = next {
+                (next - p).normalized()
+            } else {
+                (p - prev.unwrap_or(p)).normalized()
+            };
+            let normal = dir.rot90();
+            let p = if stroke.cap == LineCap::Square {
+                let outward = if i == 0 { -dir } else { dir };
+                p + 0.5 * stroke.width * outward
+            } else {
+                p
+            };
+            stations.push((p, normal, -normal));
+            continue;
+        };
+
+        let d0 = (p - prev).normalized();
+        let d1 = (next - p).normalized();
+        let n0 = d0.rot90();
+        let n1 = d1.rot90();
+        let normal = 0.5 * (n0 + n1);
+        let length_sq = normal.length_sq();
+
+        if stroke.join == LineJoin::Miter
+            && 1.0 <= length_sq * LineJoin::MITER_LIMIT * LineJoin::MITER_LIMIT
+        {
+            let miter = normal / length_sq;
+            stations.push((p, miter, -miter));
+            continue;
+        }
+
+        // On the inside of the corner the edges meet, unless that is past the ends of the neighboring
+        // segments, in which case we stop there to avoid spikes:
+        let reach = p.distance(prev).min(p.distance(next)) / outer_rad.max(f32::EPSILON);
+        let plus_is_outside = d1.dot(n0) <= 0.0;
+        let inner = if 1e-6 < length_sq {
+            let inner =
+                normal.normalized() * length_sq.sqrt().recip().min((1.0 + reach * reach).sqrt());
+            if plus_is_outside { -inner } else { inner }
+        } else {
+            // A U-turn
+            -reach * d0
+        };
+
+        // On the outside we go around the corner, through the direction we came from:
+        let mut angle = (n1.angle() - n0.angle() + PI).rem_euclid(TAU) - PI;
+        if (0.0 < Vec2::angled(n0.angle() + 0.5 * angle).dot(d0)) != plus_is_outside {
+            angle -= TAU.copysign(angle);
+        }
+        let segments = if stroke.join == LineJoin::Round {
+            round_segments(angle, outer_rad)
+        } else {
+            1
+        };
+        for k in 0..=segments {
+            let outer = Vec2::angled(n0.angle() + angle * k as f32 / segments as f32);
+            if plus_is_outside {
+                stations.push((p, outer, inner));
+            } else {
+                stations.push((p, inner, -outer));
+            }
+        }
+    }
+
+    let num_stations = stations.len() as u32;
+    out.reserve_vertices(4 * num_stations as usize);
+    out.reserve_triangles(6 * num_stations as usize);
+
+    let idx = out.vertices.len() as u32;
+    for (i, &(p, plus, minus)) in stations.iter().enumerate() {
+        out.colored_vertex(p + plus * outer_rad, color_outer);
+        out.colored_vertex(p + plus * inner_rad, color(p + plus * inner_rad));
+        out.colored_vertex(p + minus * inner_rad, color(p + minus * inner_rad));
+        out.colored_vertex(p + minus * outer_rad, color_outer);
+
+        if 0 < i {
+            let (i0, i1) = (i as u32 - 1, i as u32);
+            for k in 0..3 {
+                out.add_triangle(idx + 4 * i0 + k, idx + 4 * i0 + k + 1, idx + 4 * i1 + k);
+                out.add_triangle(idx + 4 * i0 + k + 1, idx + 4 * i1 + k, idx + 4 * i1 + k + 1);
+            }
+        }
+    }
+
+    if closed {
+        let (i0, i1) = (num_stations - 1, 0);
+        for k in 0..3 {
+            out.add_triangle(idx + 4 * i0 + k, idx + 4 * i0 + k + 1, idx + 4 * i1 + k);
+            out.add_triangle(idx + 4 * i0 + k + 1, idx + 4 * i1 + k, idx + 4 * i1 + k + 1);
+        }
+        return;
+    }
+
+    for (station, back) in [
+        (0, points[0] - points[1]),
+        (num_stations - 1, points[n - 1] - points[n - 2]),
+    ] {
+        let back = back.normalized();
+        let (p, plus, _) = stations[station as usize];
+
+        if stroke.cap == LineCap::Round {
+            // A half circle from one side to the other, around the back:
+            let segments = round_segments(PI, outer_rad);
+            let center = out.vertices.len() as u32;
+            out.colored_vertex(p, color(p));
+            for k in 0..=segments {
+                let (sin, cos) = (PI * k as f32 / segments as f32).sin_cos();
+                let dir = cos * plus + sin * back;
+                out.colored_vertex(p + dir * inner_rad, color(p + dir * inner_rad));
+                out.colored_vertex(p + dir * outer_rad, color_outer);
+                if 0 < k {
+                    let (a, b) = (center + 2 * k - 1, center + 2 * k + 1);
+                    out.add_triangle(center, a, b);
+                    out.add_triangle(a, a + 1, b);
+                    out.add_triangle(a + 1, b, b + 1);
+                }
+            }
+        } else if 0.0 < feathering {
+            // Feather the end by extruding the outer edge backwards:
+            let i = idx + 4 * station;
+            let extrude = back * feathering;
+            let b = out.vertices.len() as u32;
+            out.colored_vertex(out.vertices[i as usize].pos + extrude, color_outer);
+            out.colored_vertex(out.vertices[i as usize + 3].pos + extrude, color_outer);
+            out.add_triangle(i, i + 1, b);
+            out.add_triangle(i + 1, i + 2, b);
+            out.add_triangle(i + 2, b, b + 1);
+            out.add_triangle(i + 2, i + 3, b + 1);
+        }
+    }
+}
+
 fn mul_color(color: Color32, factor: f32) -> Color32 {
     // The fast gamma-space multiply also happens to be perceptually better.
     // Win-win!
@@ -1362,7 +1659,11 @@ impl Tessellator {
         clipped_shape: ClippedShape,
         out_primitives: &mut Vec<ClippedPrimitive>,
     ) {
-        let ClippedShape { clip_rect, shape } = clipped_shape;
+        let ClippedShape {
+            clip_rect,
+            shape,
+            blend_mode,
+        } = clipped_shape;
 
         if !clip_rect.is_positive() {
             return; // skip empty clip rectangles
@@ -1370,7 +1671,14 @@ impl Tessellator {
 
         if let Shape::Vec(shapes) = shape {
             for shape in shapes {
-                self.tessellate_clipped_shape(ClippedShape { clip_rect, shape }, out_primitives);
+                self.tessellate_clipped_shape(
+                    ClippedShape {
+                        clip_rect,
+                        shape,
+                        blend_mode,
+                    },
+                    out_primitives,
+                );
             }
             return;
         }
@@ -1379,6 +1687,7 @@ impl Tessellator {
             out_primitives.push(ClippedPrimitive {
                 clip_rect,
                 primitive: Primitive::Callback(callback),
+                blend_mode,
             });
             return;
         }
@@ -1387,6 +1696,7 @@ impl Tessellator {
             None => true,
             Some(output_clipped_primitive) => {
                 output_clipped_primitive.clip_rect != clip_rect
+                    || output_clipped_primitive.blend_mode != blend_mode
                     || match &output_clipped_primitive.primitive {
                         Primitive::Mesh(output_mesh) => {
                             output_mesh.texture_id != shape.texture_id()
@@ -1400,6 +1710,7 @@ impl Tessellator {
             out_primitives.push(ClippedPrimitive {
                 clip_rect,
                 primitive: Primitive::Mesh(Mesh::default()),
+                blend_mode,
             });
         }
 
@@ -1433,6 +1744,9 @@ impl Tessellator {
             Shape::Ellipse(ellipse) => {
                 self.tessellate_ellipse(ellipse, out);
             }
+            Shape::Arc(arc) => {
+                self.tessellate_arc(arc, out);
+            }
             Shape::Mesh(mesh) => {
                 profiling::scope!("mesh");
 
@@ -1535,6 +1849,124 @@ impl Tessellator {
             .fill_and_stroke(self.feathering, fill, &path_stroke, out);
     }
 
+    /// Tessellate a single [`ArcShape`] into a [`Mesh`].
+    ///
+    /// * `shape`: the pie slice or ring segment to tessellate.
+    /// * `out`: triangles are appended to this.
+    pub fn tessellate_arc(&mut self, shape: ArcShape, out: &mut Mesh) {
+        use std::f32::consts::{FRAC_PI_2, TAU};
+
+        let ArcShape {
+            center,
+            radius,
+            inner_radius: _,
+            start_angle,
+            end_angle,
+            fill,
+            stroke,
+        } = shape;
+
+        if radius <= 0.0 || start_angle == end_angle {
+            return;
+        }
+
+        if self.options.coarse_tessellation_culling
+            && !self.clip_rect.intersects(shape.visual_bounding_rect())
+        {
+            return;
+        }
+
+        let (start, sweep) = shape.start_and_sweep();
+        let inner_radius = shape.clamped_inner_radius();
+        let full_turn = TAU <= sweep;
+
+        if full_turn && inner_radius <= 0.0 {
+            self.tessellate_circle(
+                CircleShape {
+                    center,
+                    radius,
+                    fill,
+                    stroke,
+                },
+                out,
+            );
+            return;
+        }
+
+        // Same density as for ellipses: at least 8 points per quarter turn.
+        let max_radius = (radius * self.pixels_per_point) as u32;
+        let points_per_quarter = u32::max(8, max_radius / 16) as f32;
+        let n = ((sweep / FRAC_PI_2 * points_per_quarter).ceil() as u32).max(1);
+        let point = |radius: f32, i: u32| {
+            center + radius * Vec2::angled(start + sweep * i as f32 / n as f32)
+        };
+
+        let path_stroke = PathStroke::from(stroke).outside();
+
+        if full_turn {
+            // A whole ring is the area between two loops.
+            // The inner loop goes counter-clockwise, so that its normals point into the hole.
+            let outer: Vec<Pos2> = (0..n).map(|i| point(radius, i)).collect();
+            let inner: Vec<Pos2> = (0..n).map(|i| point(inner_radius, (n - i) % n)).collect();
+            let inner_index = |i: u32| n + (n - i % n) % n;
+            let triangles: Vec<[u32; 3]> = (0..n)
+                .flat_map(|i| {
+                    [
+                        [i, (i + 1) % n, inner_index(i)],
+                        [(i + 1) % n, inner_index(i + 1), inner_index(i)],
+                    ]
+                })
+                .collect();
+
+            self.scratchpad_path.clear();
+            self.scratchpad_path.add_line_loop(&outer);
+            let mut inner_path = Path::default();
+            inner_path.add_line_loop(&inner);
+
+            fill_triangulated(
+                self.feathering,
+                &[&self.scratchpad_path.0, &inner_path.0],
+                &triangles,
+                fill,
+                out,
+            );
+            self.scratchpad_path
+                .stroke_closed(self.feathering, &path_stroke, out);
+            inner_path.stroke_closed(self.feathering, &path_stroke, out);
+        } else {
+            // The outline goes clockwise along the outer arc,
+            // then back along the inner arc, or through the center for a pie slice.
+            let mut outline: Vec<Pos2> = (0..=n).map(|i| point(radius, i)).collect();
+            let triangles: Vec<[u32; 3]> = if 0.0 < inner_radius {
+                outline.extend((0..=n).rev().map(|i| point(inner_radius, i)));
+                let inner_index = |i: u32| 2 * n + 1 - i;
+                (0..n)
+                    .flat_map(|i| {
+                        [
+                            [i, i + 1, inner_index(i)],
+                            [i + 1, inner_index(i + 1), inner_index(i)],
+                        ]
+                    })
+                    .collect()
+            } else {
+                outline.push(center);
+                (0..n).map(|i| [n + 1, i, i + 1]).collect()
+            };
+
+            self.scratchpad_path.clear();
+            self.scratchpad_path.add_line_loop(&outline);
+            fill_triangulated(
+                self.feathering,
+                &[&self.scratchpad_path.0],
+                &triangles,
+                fill,
+                out,
+            );
+            self.scratchpad_path
+                .stroke_closed(self.feathering, &path_stroke, out);
+        }
+    }
+
     /// Tessellate a single [`EllipseShape`] into a [`Mesh`].
     ///
     /// * `shape`: the ellipse to tessellate.
@@ -1989,6 +2421,7 @@ impl Tessellator {
             fallback_color,
             opacity_factor,
             angle,
+            path,
         } = text_shape;
 
         if galley.is_empty() {
@@ -2024,6 +2457,49 @@ impl Tessellator {
             1.0 / self.font_tex_size[1] as f32,
         );
 
+        let vertex_color = |is_glyph: bool, mut color: Color32| {
+            if let Some(override_text_color) = override_text_color {
+                // Only override the glyph color (not background color, strike-through color, etc)
+                if is_glyph {
+                    color = *override_text_color;
+                }
+            } else if color == Color32::PLACEHOLDER {
+                color = *fallback_color;
+            }
+
+            if *opacity_factor < 1.0 {
+                color = color.gamma_multiply(*opacity_factor);
+            }
+
+            debug_assert!(
+                color != Color32::PLACEHOLDER,
+                "A placeholder color made it to the tessellator. You forgot to set a fallback color."
+            );
+            color
+        };
+
+        if let Some(path) = path {
+            text_shape.for_each_glyph_on_path(path, |row, quad_index, positions| {
+                let visuals = &row.visuals;
+                let first_vertex = visuals.glyph_vertex_range.start + 4 * quad_index;
+                let first_index = visuals.glyph_index_start + 6 * quad_index;
+                let index_offset = out.vertices.len() as u32 - first_vertex as u32;
+                out.indices.extend(
+                    visuals.mesh.indices[first_index..first_index + 6]
+                        .iter()
+                        .map(|index| index + index_offset),
+                );
+                for (vertex, pos) in visuals.mesh.vertices[first_vertex..].iter().zip(positions) {
+                    out.vertices.push(Vertex {
+                        pos,
+                        uv: (vertex.uv.to_vec2() * uv_normalizer).to_pos2(),
+                        color: vertex_color(true, vertex.color),
+                    });
+                }
+            });
+            return;
+        }
+
         let rotator = Rot2::from_angle(*angle);
 
         for row in &galley.rows {
@@ -2062,22 +2538,9 @@ impl Tessellator {
                     .iter()
                     .enumerate()
                     .map(|(i, vertex)| {
-                        let Vertex { pos, uv, mut color } = *vertex;
-
-                        if let Some(override_text_color) = override_text_color {
-                            // Only override the glyph color (not background color, strike-through color, etc)
-                            if row.visuals.glyph_vertex_range.contains(&i) {
-                                color = *override_text_color;
-                            }
-                        } else if color == Color32::PLACEHOLDER {
-                            color = *fallback_color;
-                        }
-
-                        if *opacity_factor < 1.0 {
-                            color = color.gamma_multiply(*opacity_factor);
-                        }
-
-                        debug_assert!(color != Color32::PLACEHOLDER, "A placeholder color made it to the tessellator. You forgot to set a fallback color.");
+                        let Vertex { pos, uv, color } = *vertex;
+                        let color =
+                            vertex_color(row.visuals.glyph_vertex_range.contains(&i), color);
 
                         let offset = if *angle == 0.0 {
                             pos.to_vec2()
@@ -2236,6 +2699,17 @@ impl Tessellator {
             }
         }
 
+        if self.options.color_vision != ColorVision::Normal {
+            profiling::scope!("color_vision");
+            for clipped_primitive in &mut clipped_primitives {
+                if let Primitive::Mesh(mesh) = &mut clipped_primitive.primitive {
+                    for vertex in &mut mesh.vertices {
+                        vertex.color = self.options.color_vision.simulate(vertex.color);
+                    }
+                }
+            }
+        }
+
         clipped_primitives.retain(|p| {
             p.clip_rect.is_positive()
                 && match &p.primitive {
@@ -2270,7 +2744,10 @@ impl Tessellator {
 
                 Shape::Path(path_shape) => 32 < path_shape.points.len(),
 
-                Shape::QuadraticBezier(_) | Shape::CubicBezier(_) | Shape::Ellipse(_) => true,
+                Shape::QuadraticBezier(_)
+                | Shape::CubicBezier(_)
+                | Shape::Ellipse(_)
+                | Shape::Arc(_) => true,
 
                 Shape::Noop
                 | Shape::Text(_)
@@ -2328,6 +2805,7 @@ impl Tessellator {
                     ClippedPrimitive {
                         clip_rect: Rect::EVERYTHING, // whatever
                         primitive: Primitive::Mesh(clip_rect_mesh),
+                        blend_mode: BlendMode::Normal,
                     },
                 ]
             })
@@ -2356,6 +2834,7 @@ fn test_tessellator() {
     let clipped_shapes = vec![ClippedShape {
         clip_rect: rect,
         shape,
+        blend_mode: BlendMode::Normal,
     }];
 
     let font_tex_size = [1024, 1024]; // unused
@@ -2367,6 +2846,150 @@ fn test_tessellator() {
     assert_eq!(primitives.len(), 2);
 }
 
+#[test]
+fn test_blend_modes() {
+    use crate::*;
+
+    let rect = Rect::from_min_max(pos2(0.0, 0.0), pos2(1.0, 1.0));
+    let mut tessellator = Tessellator::new(1.0, Default::default(), [1024, 1024], vec![]);
+
+    // Shapes with different blend modes can't share a mesh:
+    let circle = Shape::circle_filled(pos2(0.5, 0.5), 0.5, Color32::WHITE);
+    let clipped_shapes = [BlendMode::Normal, BlendMode::Additive, BlendMode::Additive]
+        .map(|blend_mode| ClippedShape {
+            clip_rect: rect,
+            shape: circle.clone(),
+            blend_mode,
+        })
+        .to_vec();
+    let primitives = tessellator.tessellate_shapes(clipped_shapes);
+    let blend_modes: Vec<_> = primitives.iter().map(|p| p.blend_mode).collect();
+    assert_eq!(blend_modes, [BlendMode::Normal, BlendMode::Additive]);
+}
+
+#[test]
+fn test_line_joins_and_caps() {
+    use crate::*;
+
+    fn stroke(points: &[Pos2], stroke: &PathStroke) -> Mesh {
+        let mut mesh = Mesh::default();
+        let mut path = Path::default();
+        path.add_open_points(points);
+        path.stroke_open(0.0, stroke, &mut mesh);
+        assert!(mesh.is_valid());
+        mesh
+    }
+
+    fn area(mesh: &Mesh) -> f32 {
+        mesh.indices
+            .chunks_exact(3)
+            .map(|t| {
+                let [a, b, c] = [0, 1, 2].map(|i| mesh.vertices[t[i] as usize].pos);
+                let (ab, ac) = (b - a, c - a);
+                0.5 * (ab.x * ac.y - ab.y * ac.x).abs()
+            })
+            .sum()
+    }
+
+    let segment = [pos2(0.0, 0.0), pos2(100.0, 0.0)];
+    let wide = PathStroke::new(10.0, Color32::WHITE);
+
+    let butt = stroke(&segment, &wide);
+    assert_eq!(area(&butt), 1000.0);
+
+    let square = stroke(&segment, &wide.clone().with_cap(LineCap::Square));
+    assert_eq!(
+        square.calc_bounds(),
+        Rect::from_min_max(pos2(-5.0, -5.0), pos2(105.0, 5.0))
+    );
+    assert_eq!(area(&square), 1100.0);
+
+    let round = stroke(&segment, &wide.clone().with_cap(LineCap::Round));
+    let circle = 25.0 * std::f32::consts::PI;
+    assert!(
+        (area(&round) - (1000.0 + circle)).abs() < 0.05 * circle,
+        "{}",
+        area(&round)
+    );
+
+    // The triangles must not overlap, so the area is the area of the painted shape:
+    let corner = [pos2(0.0, 0.0), pos2(100.0, 0.0), pos2(100.0, 100.0)];
+    let overlapping = 1975.0;
+
+    let miter = stroke(&corner, &wide);
+    assert!((area(&miter) - (overlapping + 25.0)).abs() < 0.01);
+
+    let bevel = stroke(&corner, &wide.clone().with_join(LineJoin::Bevel));
+    assert!((area(&bevel) - (overlapping + 12.5)).abs() < 0.01);
+
+    let round = stroke(&corner, &wide.clone().with_join(LineJoin::Round));
+    let quarter_circle = 0.25 * 25.0 * std::f32::consts::PI;
+    assert!((area(&round) - (overlapping + quarter_circle)).abs() < 0.05 * quarter_circle);
+    assert!(area(&round) < overlapping + quarter_circle);
+
+    for join in [LineJoin::Miter, LineJoin::Round, LineJoin::Bevel] {
+        for cap in [LineCap::Butt, LineCap::Round, LineCap::Square] {
+            for width in [0.5, 10.0] {
+                let stroke = PathStroke::new(width, Color32::WHITE)
+                    .with_join(join)
+                    .with_cap(cap);
+                let mut mesh = Mesh::default();
+                let mut path = Path::default();
+                path.add_open_points(&corner);
+                path.stroke_open(1.0, &stroke, &mut mesh);
+                assert!(mesh.is_valid());
+                let bounds = mesh.calc_bounds();
+                assert!(
+                    bounds.max.x <= 101.0 + 0.5 * width,
+                    "{join:?} {cap:?}: {bounds:?}"
+                );
+            }
+        }
+    }
+
+    // Filled paths get the joins too, with the fill reaching the inside of the stroke:
+    let fill_and_stroke = |stroke: &PathStroke| {
+        let mut mesh = Mesh::default();
+        let mut path = Path::default();
+        path.add_line_loop(&[
+            pos2(0.0, 0.0),
+            pos2(100.0, 0.0),
+            pos2(100.0, 100.0),
+            pos2(0.0, 100.0),
+        ]);
+        path.fill_and_stroke(0.0, Color32::RED, stroke, &mut mesh);
+        assert!(mesh.is_valid());
+        mesh
+    };
+    let bevel = fill_and_stroke(&wide.clone().with_join(LineJoin::Bevel));
+    assert!(bevel.vertices.iter().any(|v| v.color == Color32::RED));
+    assert!(
+        (area(&bevel) - (110.0 * 110.0 - 4.0 * 12.5)).abs() < 0.1,
+        "{}",
+        area(&bevel)
+    );
+
+    // Sharp corners are beveled instead of reaching far out:
+    let stroke_loop = |points: &[Pos2]| {
+        let mut mesh = Mesh::default();
+        let mut path = Path::default();
+        path.add_line_loop(points);
+        path.stroke_closed(0.0, &wide, &mut mesh);
+        assert!(mesh.is_valid());
+        mesh.calc_bounds()
+    };
+    let spike = stroke_loop(&[pos2(0.0, 0.0), pos2(100.0, 0.0), pos2(0.0, 10.0)]);
+    assert!(spike.max.x < 106.0, "{spike:?}");
+    // …but the corners of an equilateral triangle are within the miter limit:
+    let height = 50.0 * 3.0_f32.sqrt();
+    let triangle = stroke_loop(&[pos2(0.0, 0.0), pos2(100.0, 0.0), pos2(50.0, height)]);
+    let miter = 10.0 * 0.75_f32.sqrt();
+    assert!(
+        (triangle.max.x - (100.0 + miter)).abs() < 0.01,
+        "{triangle:?}"
+    );
+}
+
 #[test]
 fn path_bounding_box() {
     use crate::*;
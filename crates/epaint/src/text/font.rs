@@ -62,6 +62,32 @@ impl Default for GlyphInfo {
 
 // ----------------------------------------------------------------------------
 
+/// Vertical metrics of a font at a specific size.
+///
+/// All values are in points, and rounded to [`emath::GUI_ROUNDING`].
+///
+/// Get these with [`crate::Fonts::font_metrics`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct FontMetrics {
+    /// Height of one row of text, same as [`crate::Fonts::row_height`].
+    pub row_height: f32,
+
+    /// Distance from the top of the row to the baseline.
+    pub ascent: f32,
+
+    /// Distance from the baseline down to the bottom of the lowest glyphs (like "g"),
+    /// as a positive number.
+    pub descent: f32,
+
+    /// Height of lowercase letters above the baseline, measured on the letter "x".
+    ///
+    /// Zero if the font has no "x".
+    pub x_height: f32,
+}
+
+// ----------------------------------------------------------------------------
+
 /// A specific font with a size.
 /// The interface uses points as the unit for everything.
 pub struct FontImpl {
@@ -77,6 +103,8 @@ pub struct FontImpl {
     y_offset_in_points: f32,
 
     ascent: f32,
+    descent: f32,
+    x_height: f32,
     pixels_per_point: f32,
     glyph_info_cache: RwLock<ahash::HashMap<char, GlyphInfo>>, // TODO(emilk): standard Mutex
     atlas: Arc<Mutex<TextureAtlas>>,
@@ -126,6 +154,16 @@ impl FontImpl {
         // Round to closest pixel:
         let y_offset_in_points = (y_offset_points * pixels_per_point).round() / pixels_per_point;
 
+        let x_height = {
+            let scaled = ab_glyph_font.as_scaled(scale_in_pixels as f32);
+            let x = ab_glyph_font.glyph_id('x');
+            ab_glyph_font.outline(x).map_or(0.0, |outline| {
+                // `min.y` is the top (the bounds are y-up), so the height is negative:
+                let height = outline.bounds.height().abs();
+                (height * scaled.scale_factor().vertical / pixels_per_point).round_ui()
+            })
+        };
+
         Self {
             name,
             ab_glyph_font,
@@ -133,6 +171,8 @@ impl FontImpl {
             height_in_points: ascent - descent + line_gap,
             y_offset_in_points,
             ascent: ascent + baseline_offset,
+            descent: -descent - baseline_offset,
+            x_height,
             pixels_per_point,
             glyph_info_cache: Default::default(),
             atlas,
@@ -261,6 +301,16 @@ impl FontImpl {
         self.ascent
     }
 
+    /// The vertical metrics of this font.
+    pub fn metrics(&self) -> FontMetrics {
+        FontMetrics {
+            row_height: self.height_in_points,
+            ascent: self.ascent,
+            descent: self.descent,
+            x_height: self.x_height,
+        }
+    }
+
     fn allocate_glyph(&self, glyph_id: ab_glyph::GlyphId) -> GlyphInfo {
         assert!(glyph_id.0 != 0, "Can't allocate glyph for id 0");
         use ab_glyph::{Font as _, ScaleFont as _};
@@ -423,6 +473,15 @@ impl Font {
         self.row_height
     }
 
+    /// The vertical metrics of the primary font.
+    pub fn metrics(&self) -> FontMetrics {
+        let metrics = self.fonts.first().map(|font| font.metrics());
+        FontMetrics {
+            row_height: self.row_height,
+            ..metrics.unwrap_or_default()
+        }
+    }
+
     pub fn uv_rect(&self, c: char) -> UvRect {
         self.glyph_info_cache
             .get(&c)
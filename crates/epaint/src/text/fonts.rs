@@ -4,11 +4,11 @@ use crate::{
     AlphaFromCoverage, TextureAtlas,
     mutex::{Mutex, MutexGuard},
     text::{
-        Galley, LayoutJob, LayoutSection,
-        font::{Font, FontImpl},
+        Galley, InternedStr, LayoutJob, LayoutSection, TextFormat, TextWrapping,
+        font::{Font, FontImpl, FontMetrics},
     },
 };
-use emath::{NumExt as _, OrderedFloat};
+use emath::{NumExt as _, OrderedFloat, Vec2};
 
 #[cfg(feature = "default_fonts")]
 use epaint_default_fonts::{EMOJI_ICON, HACK_REGULAR, NOTO_EMOJI_REGULAR, UBUNTU_LIGHT};
@@ -552,6 +552,21 @@ impl Fonts {
         self.lock().fonts.row_height(font_id)
     }
 
+    /// The ascent, descent, x-height etc of a font.
+    #[inline]
+    pub fn font_metrics(&self, font_id: &FontId) -> FontMetrics {
+        self.lock().fonts.font(font_id).metrics()
+    }
+
+    /// The size of the given text, laid out on a single line (but breaking at `\n`).
+    ///
+    /// This is the size of the galley that [`Self::layout_no_wrap`] would return.
+    /// The implementation uses memoization, so measuring text before painting it is cheap.
+    pub fn measure_text(&self, text: String, font_id: FontId) -> Vec2 {
+        self.layout_no_wrap(text, font_id, crate::Color32::PLACEHOLDER)
+            .size()
+    }
+
     /// List of all known font families.
     pub fn families(&self) -> Vec<FontFamily> {
         self.lock()
@@ -575,10 +590,34 @@ impl Fonts {
         self.lock().layout_job(job)
     }
 
+    /// Lay out `text` with a single [`TextFormat`], like [`LayoutJob::simple_format`].
+    ///
+    /// Unlike [`Self::layout_job`], this doesn't allocate anything if the galley is already cached.
+    pub fn layout_format(&self, text: &str, format: TextFormat, wrap: TextWrapping) -> Arc<Galley> {
+        let text_hash = crate::util::hash(text);
+        self.lock().layout_format(text_hash, text, format, wrap)
+    }
+
+    /// Like [`Self::layout_format`], but without having to hash the text.
+    pub fn layout_interned(
+        &self,
+        text: &InternedStr,
+        format: TextFormat,
+        wrap: TextWrapping,
+    ) -> Arc<Galley> {
+        self.lock()
+            .layout_format(text.text_hash(), text, format, wrap)
+    }
+
     pub fn num_galleys_in_cache(&self) -> usize {
         self.lock().galley_cache.num_galleys_in_cache()
     }
 
+    /// How the text layout cache was used in the previous pass.
+    pub fn galley_cache_stats(&self) -> GalleyCacheStats {
+        self.lock().galley_cache.last_pass_stats
+    }
+
     /// How full is the font atlas?
     ///
     /// This increases as new fonts and/or glyphs are used,
@@ -640,6 +679,17 @@ impl FontsAndCache {
         self.galley_cache
             .layout(&mut self.fonts, job, allow_split_paragraphs)
     }
+
+    fn layout_format(
+        &mut self,
+        text_hash: u64,
+        text: &str,
+        format: TextFormat,
+        wrap: TextWrapping,
+    ) -> Arc<Galley> {
+        self.galley_cache
+            .layout_format(&mut self.fonts, text_hash, text, format, wrap)
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -758,11 +808,32 @@ struct CachedGalley {
     galley: Arc<Galley>,
 }
 
+/// How the [`Galley`] cache was used during a pass, see [`Fonts::galley_cache_stats`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct GalleyCacheStats {
+    /// Texts that were already laid out, but were given as a [`LayoutJob`]
+    /// that had to be allocated just to be thrown away.
+    pub hits: usize,
+
+    /// Texts that were already laid out, and were looked up without allocating,
+    /// e.g. with [`Fonts::layout_format`].
+    pub allocation_free_hits: usize,
+
+    /// Texts (or paragraphs of texts) that had to be laid out.
+    pub misses: usize,
+
+    /// Number of galleys kept in the cache at the end of the pass.
+    pub num_galleys: usize,
+}
+
 #[derive(Default)]
 struct GalleyCache {
     /// Frame counter used to do garbage collection on the cache
     generation: u32,
     cache: nohash_hasher::IntMap<u64, CachedGalley>,
+
+    current_pass_stats: GalleyCacheStats,
+    last_pass_stats: GalleyCacheStats,
 }
 
 impl GalleyCache {
@@ -802,6 +873,7 @@ impl GalleyCache {
         let galley = match self.cache.entry(hash) {
             std::collections::hash_map::Entry::Occupied(entry) => {
                 // The job was found in cache - no need to re-layout.
+                self.current_pass_stats.hits += 1;
                 let cached = entry.into_mut();
                 cached.last_used = self.generation;
 
@@ -822,6 +894,7 @@ impl GalleyCache {
                 galley
             }
             std::collections::hash_map::Entry::Vacant(entry) => {
+                self.current_pass_stats.misses += 1;
                 let job = Arc::new(job);
                 if allow_split_paragraphs && should_cache_each_paragraph_individually(&job) {
                     let (child_galleys, child_hashes) =
@@ -868,6 +941,44 @@ impl GalleyCache {
         self.layout_internal(fonts, job, allow_split_paragraphs).1
     }
 
+    /// Like [`Self::layout`] with [`LayoutJob::simple_format`],
+    /// but the job is only created if the galley isn't cached already.
+    fn layout_format(
+        &mut self,
+        fonts: &mut FontsImpl,
+        text_hash: u64,
+        text: &str,
+        format: TextFormat,
+        mut wrap: TextWrapping,
+    ) -> Arc<Galley> {
+        if wrap.max_width.is_finite() {
+            // Same protection against rounding errors as in `layout_internal`:
+            wrap.max_width = wrap.max_width.round();
+        }
+
+        // All other parts of the job are given by `LayoutJob::simple_format`:
+        let key = crate::util::hash((text_hash, &format, &wrap));
+
+        if let Some(cached) = self.cache.get_mut(&key) {
+            self.current_pass_stats.allocation_free_hits += 1;
+            cached.last_used = self.generation;
+            return cached.galley.clone();
+        }
+
+        let mut job = LayoutJob::simple_format(text.to_owned(), format);
+        job.wrap = wrap;
+        let galley = self.layout(fonts, job, true);
+        self.cache.insert(
+            key,
+            CachedGalley {
+                last_used: self.generation,
+                children: None,
+                galley: galley.clone(),
+            },
+        );
+        galley
+    }
+
     /// Split on `\n` and lay out (and cache) each paragraph individually.
     fn layout_each_paragraph_individually(
         &mut self,
@@ -983,6 +1094,11 @@ impl GalleyCache {
             cached.last_used == current_generation // only keep those that were used this frame
         });
         self.generation = self.generation.wrapping_add(1);
+
+        self.last_pass_stats = GalleyCacheStats {
+            num_galleys: self.cache.len(),
+            ..std::mem::take(&mut self.current_pass_stats)
+        };
     }
 }
 
@@ -1216,6 +1332,45 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_font_metrics_and_glyph_rects() {
+        let fonts = Fonts::new(
+            1.0,
+            1024,
+            AlphaFromCoverage::default(),
+            FontDefinitions::default(),
+        );
+        let font_id = FontId::proportional(20.0);
+
+        let metrics = fonts.font_metrics(&font_id);
+        assert_eq!(metrics.row_height, fonts.row_height(&font_id));
+        assert!(0.0 < metrics.x_height && metrics.x_height < metrics.ascent);
+        assert!(0.0 < metrics.descent && metrics.ascent + metrics.descent <= metrics.row_height);
+
+        let galley = fonts.layout(
+            "ab\ncd".to_owned(),
+            font_id.clone(),
+            Color32::WHITE,
+            f32::INFINITY,
+        );
+        assert_eq!(
+            fonts.measure_text("ab\ncd".to_owned(), font_id),
+            galley.size()
+        );
+
+        let glyphs: Vec<_> = galley
+            .glyph_rects()
+            .map(|(i, g, rect)| (i, g.chr, rect))
+            .collect();
+        let indices: Vec<_> = glyphs.iter().map(|(i, chr, _)| (*i, *chr)).collect();
+        assert_eq!(indices, [(0, 'a'), (1, 'b'), (3, 'c'), (4, 'd')]);
+        assert!(glyphs[0].2.right() <= glyphs[1].2.left() + 0.01);
+        assert!(
+            glyphs[0].2.bottom() <= glyphs[2].2.top(),
+            "c is on the second row"
+        );
+    }
+
     #[test]
     fn test_intrinsic_size() {
         let pixels_per_point = [1.0, 1.3, 2.0, 0.867];
@@ -1271,4 +1426,30 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_layout_format_cache() {
+        let fonts = Fonts::new(
+            1.0,
+            1024,
+            AlphaFromCoverage::default(),
+            FontDefinitions::default(),
+        );
+        let format = TextFormat::simple(FontId::proportional(14.0), Color32::WHITE);
+        let wrap = TextWrapping::wrap_at_width(100.4);
+        let text = InternedStr::new("Hello world, this text needs to wrap somewhere");
+
+        let first = fonts.layout_interned(&text, format.clone(), wrap.clone());
+        let second = fonts.layout_format(&text, format.clone(), wrap.clone());
+        assert!(Arc::ptr_eq(&first, &second));
+
+        let mut job = LayoutJob::simple_format(text.to_string(), format);
+        job.wrap = TextWrapping::wrap_at_width(100.0);
+        assert_eq!(first.rows.len(), fonts.layout_job(job).rows.len());
+
+        fonts.begin_pass(1.0, 1024, AlphaFromCoverage::default());
+        let stats = fonts.galley_cache_stats();
+        assert_eq!(stats.allocation_free_hits, 1);
+        assert_eq!(stats.misses, 1);
+    }
 }
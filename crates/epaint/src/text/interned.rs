@@ -0,0 +1,187 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, LazyLock, Weak},
+};
+
+use crate::mutex::Mutex;
+
+/// All living [`InternedStr`]s, by hash.
+static INTERNER: LazyLock<Mutex<Interner>> = LazyLock::new(Default::default);
+
+#[derive(Default)]
+struct Interner {
+    strings: HashMap<u64, Weak<Inner>>,
+
+    /// When to next remove the strings that are no longer used.
+    prune_at_len: usize,
+}
+
+impl Interner {
+    fn intern(&mut self, hash: u64, text: &str) -> Arc<Inner> {
+        let new = || {
+            Arc::new(Inner {
+                hash,
+                text: text.into(),
+            })
+        };
+
+        if let Some(existing) = self.strings.get(&hash).and_then(Weak::upgrade) {
+            if *existing.text == *text {
+                return existing;
+            }
+            // A hash collision. Very unlikely, and harmless: we just don't share the memory.
+            return new();
+        }
+
+        let interned = new();
+        self.strings.insert(hash, Arc::downgrade(&interned));
+
+        if self.prune_at_len <= self.strings.len() {
+            self.strings.retain(|_, text| text.strong_count() > 0);
+            self.prune_at_len = 2 * self.strings.len().max(512);
+        }
+
+        interned
+    }
+}
+
+struct Inner {
+    hash: u64,
+    text: Box<str>,
+}
+
+/// An immutable string that is cheap to clone, and knows its own hash.
+///
+/// Equal strings that are alive at the same time share the same memory.
+///
+/// Store these for text that you show every frame, e.g. the labels of a large table.
+/// Showing an [`InternedStr`] neither allocates nor re-hashes the text,
+/// as long as its [`crate::Galley`] is cached (see [`crate::Fonts::layout_format`]).
+///
+/// This is a single pointer, so it doesn't make e.g. `egui::WidgetText` any bigger.
+#[derive(Clone)]
+pub struct InternedStr(Arc<Inner>);
+
+impl InternedStr {
+    pub fn new(text: &str) -> Self {
+        let hash = crate::util::hash(text);
+        Self(INTERNER.lock().intern(hash, text))
+    }
+
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        &self.0.text
+    }
+
+    /// The hash of the text, as given by [`crate::util::hash`].
+    #[inline]
+    pub fn text_hash(&self) -> u64 {
+        self.0.hash
+    }
+
+    /// How many different strings are currently interned.
+    pub fn num_interned() -> usize {
+        INTERNER
+            .lock()
+            .strings
+            .values()
+            .filter(|text| text.strong_count() > 0)
+            .count()
+    }
+}
+
+impl Default for InternedStr {
+    fn default() -> Self {
+        Self::new("")
+    }
+}
+
+impl std::ops::Deref for InternedStr {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl AsRef<str> for InternedStr {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl PartialEq for InternedStr {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+            || (self.text_hash() == other.text_hash() && self.as_str() == other.as_str())
+    }
+}
+
+impl Eq for InternedStr {}
+
+impl std::hash::Hash for InternedStr {
+    #[inline]
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.text_hash().hash(state);
+    }
+}
+
+impl std::fmt::Debug for InternedStr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+impl std::fmt::Display for InternedStr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+impl From<&str> for InternedStr {
+    #[inline]
+    fn from(text: &str) -> Self {
+        Self::new(text)
+    }
+}
+
+impl From<&String> for InternedStr {
+    #[inline]
+    fn from(text: &String) -> Self {
+        Self::new(text)
+    }
+}
+
+impl From<String> for InternedStr {
+    #[inline]
+    fn from(text: String) -> Self {
+        Self::new(&text)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for InternedStr {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.as_str().serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for InternedStr {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer).map(Self::from)
+    }
+}
+
+#[test]
+fn test_interned_str() {
+    let a = InternedStr::new("hello");
+    let b = InternedStr::new(&format!("hel{}", "lo"));
+    assert_eq!(a, b);
+    assert!(Arc::ptr_eq(&a.0, &b.0), "Should share memory");
+    assert_eq!(a.text_hash(), crate::util::hash("hello"));
+    assert_ne!(a, InternedStr::new("world"));
+}
@@ -3,6 +3,7 @@
 pub mod cursor;
 mod font;
 mod fonts;
+mod interned;
 mod text_layout;
 mod text_layout_types;
 
@@ -10,10 +11,12 @@ mod text_layout_types;
 pub const TAB_SIZE: usize = 4;
 
 pub use {
+    font::FontMetrics,
     fonts::{
         FontData, FontDefinitions, FontFamily, FontId, FontInsert, FontPriority, FontTweak, Fonts,
-        FontsImpl, InsertFontFamily,
+        FontsImpl, GalleyCacheStats, InsertFontFamily,
     },
+    interned::InternedStr,
     text_layout::*,
     text_layout_types::*,
 };
@@ -948,6 +948,22 @@ impl Galley {
         self.pos_from_layout_cursor(&self.layout_from_cursor(cursor))
     }
 
+    /// All the glyphs, with the character index of each,
+    /// and its [`Glyph::logical_rect`] relative to the galley.
+    ///
+    /// The baseline of each glyph is at `rect.top() + glyph.font_ascent`.
+    pub fn glyph_rects(&self) -> impl Iterator<Item = (usize, &Glyph, Rect)> + '_ {
+        let mut row_start = 0;
+        self.rows.iter().flat_map(move |row| {
+            let start = row_start;
+            row_start += row.char_count_including_newline();
+            row.glyphs.iter().enumerate().map(move |(column, glyph)| {
+                let rect = glyph.logical_rect().translate(row.pos.to_vec2());
+                (start + column, glyph, rect)
+            })
+        })
+    }
+
     /// Cursor at the given position within the galley.
     ///
     /// A cursor above the galley is considered
@@ -167,7 +167,7 @@ pub struct TextureOptions {
     ///
     /// # Notes
     ///
-    /// - This may not be available on all backends (currently only `egui_glow`).
+    /// - This may not be available on all backends (currently `egui_glow` and `egui-wgpu`).
     pub mipmap_mode: Option<TextureFilter>,
 }
 
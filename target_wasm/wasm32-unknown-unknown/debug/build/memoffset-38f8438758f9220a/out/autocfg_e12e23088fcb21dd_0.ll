@@ -0,0 +1,8 @@
+; ModuleID = 'autocfg_e12e23088fcb21dd_0.45d784d2233c9392-cgu.0'
+source_filename = "autocfg_e12e23088fcb21dd_0.45d784d2233c9392-cgu.0"
+target datalayout = "e-m:e-p:32:32-p10:8:8-p20:8:8-i64:64-n32:64-S128-ni:1:10:20"
+target triple = "wasm32-unknown-unknown"
+
+!llvm.ident = !{!0}
+
+!0 = !{!"rustc version 1.86.0 (05f9846f8 2025-03-31)"}
@@ -0,0 +1,58 @@
+; ModuleID = 'autocfg_9408351cf43cc0c3_1.a71346b56613f8ff-cgu.0'
+source_filename = "autocfg_9408351cf43cc0c3_1.a71346b56613f8ff-cgu.0"
+target datalayout = "e-m:e-p:32:32-p10:8:8-p20:8:8-i64:64-n32:64-S128-ni:1:10:20"
+target triple = "wasm32-unknown-unknown"
+
+@alloc_f93507f8ba4b5780b14b2c2584609be0 = private unnamed_addr constant <{ [8 x i8] }> <{ [8 x i8] c"\00\00\00\00\00\00\F0?" }>, align 8
+@alloc_ef0a1f828f3393ef691f2705e817091c = private unnamed_addr constant <{ [8 x i8] }> <{ [8 x i8] c"\00\00\00\00\00\00\00@" }>, align 8
+
+; core::f64::<impl f64>::total_cmp
+; Function Attrs: inlinehint nounwind
+define internal i8 @"_ZN4core3f6421_$LT$impl$u20$f64$GT$9total_cmp17hfd7e7af50a32316fE"(ptr align 8 %self, ptr align 8 %other) unnamed_addr #0 {
+start:
+  %_6 = alloca [8 x i8], align 8
+  %_3 = alloca [8 x i8], align 8
+  %_5 = load double, ptr %self, align 8
+  %_4 = bitcast double %_5 to i64
+  store i64 %_4, ptr %_3, align 8
+  %_8 = load double, ptr %other, align 8
+  %_7 = bitcast double %_8 to i64
+  store i64 %_7, ptr %_6, align 8
+  %_13 = load i64, ptr %_3, align 8
+  %_12 = ashr i64 %_13, 63
+  %_10 = lshr i64 %_12, 1
+  %0 = load i64, ptr %_3, align 8
+  %1 = xor i64 %0, %_10
+  store i64 %1, ptr %_3, align 8
+  %_18 = load i64, ptr %_6, align 8
+  %_17 = ashr i64 %_18, 63
+  %_15 = lshr i64 %_17, 1
+  %2 = load i64, ptr %_6, align 8
+  %3 = xor i64 %2, %_15
+  store i64 %3, ptr %_6, align 8
+  %_19 = load i64, ptr %_3, align 8
+  %_20 = load i64, ptr %_6, align 8
+  %4 = icmp sgt i64 %_19, %_20
+  %5 = zext i1 %4 to i8
+  %6 = icmp slt i64 %_19, %_20
+  %7 = zext i1 %6 to i8
+  %_0 = sub nsw i8 %5, %7
+  ret i8 %_0
+}
+
+; autocfg_9408351cf43cc0c3_1::probe
+; Function Attrs: nounwind
+define dso_local void @_ZN26autocfg_9408351cf43cc0c3_15probe17h67c91d753aa551cfE() unnamed_addr #1 {
+start:
+; call core::f64::<impl f64>::total_cmp
+  %_1 = call i8 @"_ZN4core3f6421_$LT$impl$u20$f64$GT$9total_cmp17hfd7e7af50a32316fE"(ptr align 8 @alloc_f93507f8ba4b5780b14b2c2584609be0, ptr align 8 @alloc_ef0a1f828f3393ef691f2705e817091c) #2
+  ret void
+}
+
+attributes #0 = { inlinehint nounwind "target-cpu"="generic" }
+attributes #1 = { nounwind "target-cpu"="generic" }
+attributes #2 = { nounwind }
+
+!llvm.ident = !{!0}
+
+!0 = !{!"rustc version 1.86.0 (05f9846f8 2025-03-31)"}
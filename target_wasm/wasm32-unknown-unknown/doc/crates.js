@@ -0,0 +1,2 @@
+window.ALL_CRATES = ["eframe"];
+//{"start":21,"fragment_lengths":[8]}
\ No newline at end of file
@@ -0,0 +1 @@
+window.SIDEBAR_ITEMS = {"constant":["APP_KEY"],"enum":["Error","Renderer","WebGlContextOption","WebRepaintMode","WebStorageBackend"],"mod":["web"],"struct":["CreationContext","Frame","IntegrationInfo","Location","WebInfo","WebOptions"],"trait":["App","Storage"],"type":["AppCreator","Result"]};
\ No newline at end of file
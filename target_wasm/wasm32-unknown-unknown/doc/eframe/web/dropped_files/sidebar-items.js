@@ -0,0 +1 @@
+window.SIDEBAR_ITEMS = {"fn":["file","forget","read"]};
\ No newline at end of file
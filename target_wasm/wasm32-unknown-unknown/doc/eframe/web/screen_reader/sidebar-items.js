@@ -0,0 +1 @@
+window.SIDEBAR_ITEMS = {"fn":["speak"]};
\ No newline at end of file
@@ -0,0 +1 @@
+window.SIDEBAR_ITEMS = {"fn":["is_safari_browser","location_hash","native_pixels_per_point","now_sec","open_url","percent_decode","system_theme","user_agent","vibrate","web_location"],"mod":["dropped_files","screen_reader","storage"],"struct":["PanicHandler","PanicSummary","WebLogger","WebRunner"]};
\ No newline at end of file
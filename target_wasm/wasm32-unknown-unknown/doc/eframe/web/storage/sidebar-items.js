@@ -0,0 +1 @@
+window.SIDEBAR_ITEMS = {"fn":["local_storage_get","local_storage_set"]};
\ No newline at end of file
@@ -0,0 +1,4 @@
+var searchIndex = new Map(JSON.parse('[["eframe",{"t":"SKPIPPPFPGFPPFPFPPPGIKPPGFEFGEGNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNONNNNONNOEOEEENNNNMNNNNNNNNNNNNNNNNNNNNNNNOMNOENNNOOONONNNNNNNNNNNNNNOONNONNOOOONNNNNNOONNNNMOONOOONNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNMOOECOEONNFFFFNNNNNNNNNNNNNNNNNNNNNNCNNNNNNNNNNNNNNNHHNNHNNNHHNNHCNCHNNNNNNNNNNNNNNNHHHHHHHHH","n":["APP_KEY","App","AppCreation","AppCreator","BestFirst","CompatibilityFirst","Continuous","CreationContext","Err","Error","Frame","Glow","IndexedDb","IntegrationInfo","LocalStorage","Location","Ok","OpenGL","Reactive","Renderer","Result","Storage","WebGl1","WebGl2","WebGlContextOption","WebInfo","WebLogger","WebOptions","WebRepaintMode","WebRunner","WebStorageBackend","as_any_mut","","auto_save_interval","","borrow","","","","","","","","","","","borrow_mut","","","","","","","","","","","clear_color","","clone","","","","","","","clone_into","","","","","","","clone_to_uninit","","","","","","","cpu_usage","default","","","","depth_buffer","display_handle","","dithering","egui","egui_ctx","egui_glow","emath","epaint","eq","","","","flush","fmt","","","","","","","","","","from","","","","","","","","","","","","from_str","get_proc_address","get_string","gl","","glow","hash","","","","host","hostname","info","integration_info","into","","","","","","","","","","","is_fullscreen","is_pointer_locked","is_web","location","max_dropped_file_bytes","on_exit","","origin","persist_egui_memory","","port","protocol","query","query_map","raw_display_handle","","raw_input_hook","","raw_window_handle","","renderer","repaint_mode","save","","set_fullscreen","set_pointer_locked","set_string","should_prevent_default","should_stop_propagation","storage","","storage_backend","storage_key","storage_mut","to_owned","","","","","","","to_string","","try_from","","","","","","","","","","","try_into","","","","","","","","","","","type_id","","","","","","","","","","","update","url","user_agent","wasm_bindgen","web","web_info","web_sys","webgl_context_option","window_handle","","PanicHandler","PanicSummary","WebLogger","WebRunner","add_event_listener","add_event_listener_ex","app_mut","borrow","","","","borrow_mut","","","","callstack","clone","","","clone_into","","","clone_to_uninit","","","destroy","dropped_files","enabled","flush","fmt","from","","","","has_panicked","","init","install","into","","","","is_safari_browser","location_hash","log","message","native_pixels_per_point","new","","","now_sec","open_url","panic_summary","","percent_decode","screen_reader","start","storage","system_theme","to_owned","","","try_from","","","","try_into","","","","type_id","","","","user_agent","vibrate","web_location","file","forget","read","speak","local_storage_get","local_storage_set"],"q":[[0,"eframe"],[232,"eframe::web"],[309,"eframe::web::dropped_files"],[312,"eframe::web::screen_reader"],[313,"eframe::web::storage"],[315,"eframe::epi"],[316,"core::any"],[317,"core::option"],[318,"core::time"],[319,"egui::style"],[320,"raw_window_handle::borrowed"],[321,"raw_window_handle"],[322,"core::result"],[323,"egui::context"],[324,"core::fmt"],[325,"egui_glow::painter"],[326,"alloc::string"],[327,"glow::web"],[328,"alloc::sync"],[329,"core::hash"],[330,"alloc::collections::btree::map"],[331,"egui::data::input"],[332,"alloc::boxed"],[333,"eframe::web::web_runner"],[334,"web_sys::features::gen_EventTarget"],[335,"wasm_bindgen"],[336,"wasm_bindgen::cast"],[337,"core::ops::function"],[338,"web_sys::features::gen_AddEventListenerOptions"],[339,"core::cell"],[340,"eframe::web::panic_handler"],[341,"eframe::web::web_logger"],[342,"log"],[343,"std::panic"],[344,"web_sys::features::gen_HtmlCanvasElement"],[345,"egui::memory::theme"],[346,"web_sys::features::gen_File"],[347,"core::ops::range"],[348,"alloc::vec"],[349,"core::future::future"],[350,"eframe::web::backend"]],"i":"``Cl`Ad0Ah`Hn``AjAf`0`253```44```````f000BhBfC`8475AlAnB`<543;7:8210<66;7:8210;7:8210;7:8210047:84534`5```;7:8Cf<8;99321==654<8;9321==96046`<8;22246654<8;9321=4443577277222264776455774405546554<8;93219=654<8;9321=654<8;9321=654<8;9321=723``1`564````Eh00FjFhFf3210301031031033`220210313212103``20`203``13``3``103210321032103`````````","f":"{{}b}``````````````````````````````{{{b{df}}}{{j{{b{dh}}}}}}0{{{b{f}}}l}0{b{{b{c}}}{}}0000000000{{{b{d}}}{{b{dc}}}{}}0000000000{{{b{f}}{b{n}}}{{Ab{A`}}}}0{{{b{Ad}}}Ad}{{{b{Af}}}Af}{{{b{Ah}}}Ah}{{{b{Aj}}}Aj}{{{b{Al}}}Al}{{{b{An}}}An}{{{b{B`}}}B`}{{b{b{dc}}}Bb{}}000000{{bBd}Bb}000000{B`j}{{}Bf}{{}Af}{{}Ah}{{}Aj}{BfBd}{{{b{Bh}}}{{Bn{BjBl}}}}{{{b{C`}}}{{Bn{BjBl}}}}{BfCb}`{BhCd}```{{{b{Ad}}{b{Ad}}}Cb}{{{b{Af}}{b{Af}}}Cb}{{{b{Ah}}{b{Ah}}}Cb}{{{b{Aj}}{b{Aj}}}Cb}{{{b{dCf}}}Bb}{{{b{Ad}}{b{dCh}}}Cj}{{{b{Af}}{b{dCh}}}Cj}{{{b{Ah}}{b{dCh}}}Cj}{{{b{Aj}}{b{dCh}}}Cj}0{{{b{Al}}{b{dCh}}}Cj}{{{b{An}}{b{dCh}}}Cj}{{{b{B`}}{b{dCh}}}Cj}{{{b{Cl}}{b{dCh}}}Cj}0{cc{}}0000000000{CnCl}{{{b{D`}}}{{Bn{AjDb}}}}{Bhj}{{{b{Cf}}{b{D`}}}{{j{Db}}}}{{{b{C`}}}{{j{{b{{Df{Dd}}}}}}}}2`{{{b{Ad}}{b{dc}}}BbDh}{{{b{Af}}{b{dc}}}BbDh}{{{b{Ah}}{b{dc}}}BbDh}{AnDb}00{{{b{C`}}}{{b{B`}}}}{BhB`}{{}c{}}0000000000{{{b{C`}}}Cb}00{AlAn}{Bfj}{{{b{df}}{j{{b{Dd}}}}}Bb}07{{{b{f}}}Cb}0888{AnDj}{b{{Bn{DlBl}}}}0{{{b{df}}{b{Cd}}{b{dDn}}}Bb}0{b{{Bn{E`Bl}}}}0{BfAj}{BfAh}{{{b{df}}{b{dCf}}}Bb}0{{{b{C`}}Cb}Bb}0{{{b{dCf}}{b{D`}}Db}Bb}{BfEb}0{{{b{C`}}}{{j{{b{Cf}}}}}}{Bhj}{BfAf}?{{{b{dC`}}}{{j{{b{dCf}}}}}}{bc{}}000000{bDb}0{c{{Bn{e}}}{}{}}0000000000{{}{{Bn{c}}}{}}0000000000{bEd}0000000000{{{b{df}}{b{Cd}}{b{dC`}}}Bb}{AnDb}{AlDb}``{B`Al}`{BfAd}{{{b{Bh}}}{{Bn{EfBl}}}}{{{b{C`}}}{{Bn{EfBl}}}}````{{{b{Eh}}{b{Ej}}{b{D`}}e}{{Bn{BbEl}}}En{{F`{c{b{d`}}}}}}{{{b{Eh}}{b{Ej}}{b{D`}}{b{Fb}}e}{{Bn{BbEl}}}En{{F`{c{b{d`}}{b{Eh}}}}}}{{{b{Eh}}}{{j{{Fd{c}}}}}f}{b{{b{c}}}{}}000{{{b{d}}}{{b{dc}}}{}}000{{{b{Ff}}}Db}{{{b{Fh}}}Fh}{{{b{Ff}}}Ff}{{{b{Eh}}}Eh}{{b{b{dc}}}Bb{}}00{{bBd}Bb}00{{{b{Eh}}}Bb}`{{{b{Fj}}{b{Fl}}}Cb}{{{b{Fj}}}Bb}{{{b{Ff}}{b{dCh}}}Cj}{cc{}}000{{{b{Fh}}}Cb}{{{b{Eh}}}Cb}{Fn{{Bn{BbG`}}}}{{}Fh}{{}c{}}000{{}Cb}{{}Db}{{{b{Fj}}{b{Gb}}}Bb}{{{b{Ff}}}Db}{{}A`}{FnFj}{{{b{Gd}}}Ff}{{}Eh}{{}Gf}{{{b{D`}}Cb}{{j{Bb}}}}{{{b{Fh}}}{{j{Ff}}}}{{{b{Eh}}}{{j{Ff}}}}{{{b{D`}}}Db}`{{{b{Eh}}GhBfGj}{{Bn{BbEl}}}}`{{}{{j{Gl}}}}{bc{}}00{c{{Bn{e}}}{}{}}000{{}{{Bn{c}}}{}}000{bEd}000{{}{{j{Db}}}}{GnBb}{{}An}{{{b{H`}}}{{j{Hb}}}}{{{b{H`}}}Bb}{{{b{H`}}{Hf{Hd}}}{{`{{Hl{}{{Hh{{Bn{{Hj{Bd}}El}}}}}}}}}}{{{b{D`}}}Bb}{{{b{D`}}}{{j{Db}}}}{{{b{D`}}{b{D`}}}Bb}","D":"ACf","p":[[1,"reference",null,null,1],[0,"mut"],[10,"App",0,315],[10,"Any",316],[6,"Option",317,null,1],[5,"Duration",318],[5,"Visuals",319],[1,"f32"],[1,"array"],[6,"WebGlContextOption",0,315],[6,"WebStorageBackend",0,315],[6,"WebRepaintMode",0,315],[6,"Renderer",0,315],[5,"WebInfo",0,315],[5,"Location",0,315],[5,"IntegrationInfo",0,315],[1,"unit"],[1,"u8"],[5,"WebOptions",0,315],[5,"CreationContext",0,315],[5,"DisplayHandle",320],[6,"HandleError",321],[6,"Result",322,null,1],[5,"Frame",0,315],[1,"bool"],[5,"Context",323],[10,"Storage",0,315],[5,"Formatter",324],[8,"Result",324],[6,"Error",0],[5,"PainterError",325],[1,"str"],[5,"String",326],[5,"Context",327],[5,"Arc",328,null,1],[10,"Hasher",329],[5,"BTreeMap",330],[6,"RawDisplayHandle",321],[5,"RawInput",331],[6,"RawWindowHandle",321],[5,"Box",332,null,1],[5,"TypeId",316],[5,"WindowHandle",320],[5,"WebRunner",232,333],[5,"EventTarget",334],[5,"JsValue",335],[10,"JsCast",336],[10,"FnMut",337],[5,"AddEventListenerOptions",338],[5,"RefMut",339],[5,"PanicSummary",232,340],[5,"PanicHandler",232,340],[5,"WebLogger",232,341],[5,"Metadata",342],[6,"LevelFilter",342],[5,"SetLoggerError",342],[5,"Record",342],[5,"PanicHookInfo",343],[1,"f64"],[5,"HtmlCanvasElement",344],[8,"AppCreator",0,315],[6,"Theme",345],[1,"u32"],[5,"DroppedFile",331],[5,"File",346],[1,"u64"],[5,"Range",347],[17,"Output"],[5,"Vec",348],[10,"Future",349,null,1],[8,"Result",0]],"r":[[0,315],[1,315],[3,315],[4,315],[5,315],[6,315],[7,315],[10,315],[11,315],[12,315],[13,315],[14,315],[15,315],[18,315],[19,315],[21,315],[22,315],[23,315],[24,315],[25,315],[26,341],[27,315],[28,315],[29,333],[30,315],[31,315],[32,315],[33,315],[34,315],[35,315],[36,315],[37,315],[38,315],[39,315],[40,315],[41,315],[42,315],[43,315],[44,315],[46,315],[47,315],[48,315],[49,315],[50,315],[51,315],[52,315],[53,315],[54,315],[55,315],[57,315],[58,315],[59,315],[60,315],[61,315],[62,315],[63,315],[64,315],[65,315],[66,315],[67,315],[68,315],[69,315],[70,315],[71,315],[72,315],[73,315],[74,315],[75,315],[76,315],[77,315],[78,315],[79,315],[80,315],[81,315],[82,315],[83,315],[84,315],[85,315],[86,315],[87,315],[88,315],[90,315],[94,315],[95,315],[96,315],[97,315],[98,315],[99,315],[100,315],[101,315],[102,315],[103,315],[104,315],[105,315],[106,315],[109,315],[110,315],[111,315],[112,315],[113,315],[114,315],[115,315],[116,315],[117,315],[118,315],[121,315],[122,315],[123,315],[124,315],[125,315],[127,315],[128,315],[129,315],[130,315],[131,315],[132,315],[133,315],[134,315],[135,315],[136,315],[137,315],[138,315],[139,315],[140,315],[141,315],[142,315],[143,315],[144,315],[146,315],[147,315],[148,315],[149,315],[150,315],[151,315],[152,315],[153,315],[154,315],[155,315],[156,315],[157,315],[158,315],[159,315],[160,315],[161,315],[162,315],[163,315],[164,315],[165,315],[166,315],[167,315],[168,315],[169,315],[170,315],[171,315],[172,315],[173,315],[174,315],[175,315],[176,315],[177,315],[178,315],[179,315],[180,315],[181,315],[182,315],[183,315],[184,315],[185,315],[186,315],[187,315],[189,315],[190,315],[191,315],[192,315],[193,315],[194,315],[195,315],[196,315],[197,315],[198,315],[200,315],[201,315],[202,315],[203,315],[204,315],[205,315],[206,315],[207,315],[208,315],[209,315],[211,315],[212,315],[213,315],[214,315],[215,315],[216,315],[217,315],[218,315],[219,315],[220,315],[222,315],[223,315],[224,315],[227,315],[229,315],[230,315],[231,315],[232,340],[233,340],[234,341],[235,333],[236,333],[237,333],[238,333],[239,341],[240,340],[241,340],[242,333],[243,341],[244,340],[245,340],[246,333],[247,340],[248,340],[249,340],[250,333],[251,340],[252,340],[253,333],[254,340],[255,340],[256,333],[257,333],[259,341],[260,341],[261,340],[262,341],[263,340],[264,340],[265,333],[266,340],[267,333],[268,341],[269,340],[270,341],[271,340],[272,340],[273,333],[276,341],[277,340],[279,341],[280,340],[281,333],[284,340],[285,333],[288,333],[291,340],[292,340],[293,333],[294,341],[295,340],[296,340],[297,333],[298,341],[299,340],[300,340],[301,333],[302,341],[303,340],[304,340],[305,333],[306,350],[308,350]],"b":[[102,"impl-Display-for-Renderer"],[103,"impl-Debug-for-Renderer"],[107,"impl-Debug-for-Error"],[108,"impl-Display-for-Error"]],"c":"OjAAAAAAAAA=","e":"OzAAAAEAAKAAFgAbAAAAHgAAACQAFQA8ABQAUgADAFcAAQBaAAAAXAAGAGQACQB5AAEAfwADAKEAAQClAAEAtQApAOIAAADlAAAA5wABAPAABwD5AAgABAECABUBAAAkAQ4A","P":[[35,"T"],[57,""],[66,"T"],[73,""],[109,"T"],[120,""],[127,"__H"],[130,""],[135,"U"],[146,""],[180,"T"],[187,""],[189,"U,T"],[200,"U"],[211,""],[236,"E,"],[238,"ConcreteApp"],[239,"T"],[247,""],[251,"T"],[254,""],[262,"T"],[266,""],[270,"U"],[274,""],[291,"T"],[294,"U,T"],[298,"U"],[302,""]]}]]'));
+if (typeof exports !== 'undefined') exports.searchIndex = searchIndex;
+else if (window.initSearch) window.initSearch(searchIndex);
+//{"start":39,"fragment_lengths":[10991]}
\ No newline at end of file
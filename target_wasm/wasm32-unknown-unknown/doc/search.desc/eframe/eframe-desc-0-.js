@@ -0,0 +1 @@
+searchState.loadedDescShard("eframe", 0, "eframe - the <code>egui</code> framework crate\n<code>Storage</code> key used for app\nImplement this trait to write apps that can be compiled …\nSomething went wrong in user code when creating the app.\nThis is how your app is created.\nUse WebGL2 first.\nUse WebGL1 first\nAsk the browser for an animation frame every frame, even …\nData that is passed to <code>AppCreator</code> that can be used to …\nContains the error value\nThe different problems that can occur when trying to run …\nRepresents the surroundings of your app.\nUse <code>egui_glow</code> renderer for <code>glow</code>.\n<code>IndexedDB</code>, which can hold much more, e.g. large layouts or …\nInformation about the integration passed to the use app …\n<code>localStorage</code>, which is limited to around 5 MB per site.\nInformation about the URL.\nContains the success value\nAn error from [<code>glutin</code>] when using <code>glow</code>.\nOnly repaint when there is new input, an animation, or a …\nWhat rendering backend to use.\nShort for <code>Result&lt;T, eframe::Error&gt;</code>.\nA place where you can store custom data in a way that …\nForce Use WebGL1.\nForce use WebGL2.\nWebGL Context options\nInformation about the web environment (if applicable).\nOptions when using <code>eframe</code> in a web page.\nWhen a web app is repainted, see <code>WebOptions::repaint_mode</code>.\nWhere a web app stores its <code>Storage</code>, see …\nGet a handle to the app.\nGet a handle to the app.\nTime between automatic calls to <code>Self::save</code>\nTime between automatic calls to <code>Self::save</code>\nBackground color values for the app, e.g. what is sent to …\nBackground color values for the app, e.g. what is sent to …\nSeconds of cpu usage (in seconds) on the previous frame.\nSets the number of bits in the depth buffer.\nControls whether to apply dithering to minimize banding …\nThe egui Context.\nwrite-to-disk or similar\nReturns the argument unchanged.\nReturns the argument unchanged.\nReturns the argument unchanged.\nReturns the argument unchanged.\nReturns the argument unchanged.\nReturns the argument unchanged.\nReturns the argument unchanged.\nReturns the argument unchanged.\nReturns the argument unchanged.\nReturns the argument unchanged.\nReturns the argument unchanged.\nThe <code>get_proc_address</code> wrapper of underlying GL context\nGet the value for the given key.\nA reference to the underlying <code>glow</code> (OpenGL) context.\nThe <code>glow::Context</code> allows you to initialize OpenGL …\nThe “#fragment” part of “…\n<code>location.host</code>\n<code>location.hostname</code>\nInformation about the integration.\nInformation about the surrounding environment.\nCalls <code>U::from(self)</code>.\nCalls <code>U::from(self)</code>.\nCalls <code>U::from(self)</code>.\nCalls <code>U::from(self)</code>.\nCalls <code>U::from(self)</code>.\nCalls <code>U::from(self)</code>.\nCalls <code>U::from(self)</code>.\nCalls <code>U::from(self)</code>.\nCalls <code>U::from(self)</code>.\nCalls <code>U::from(self)</code>.\nCalls <code>U::from(self)</code>.\nIs the canvas fullscreen?\nIs the pointer locked to the canvas, see …\nTrue if you are in a web environment.\nInformation about the URL.\nDropped files larger than this are not loaded into …\nCalled once on shutdown, after <code>Self::save</code>.\nCalled once on shutdown, after <code>Self::save</code>.\n<code>location.origin</code>\nControls whether or not the egui memory (window positions …\nControls whether or not the egui memory (window positions …\n<code>location.port</code>\n<code>location.protocol</code>\nThe “query” part of “…\nThe parsed “query” part of “…\nA hook for manipulating or filtering raw input before it …\nA hook for manipulating or filtering raw input before it …\nWhich renderer to use on the canvas.\nWhen to repaint the app.\nCalled on shutdown, and perhaps at regular intervals. …\nCalled on shutdown, and perhaps at regular intervals. …\nMake the canvas fill the screen, or leave fullscreen.\nLock the pointer to the canvas and hide it, e.g. for a …\nSet the value for the given key.\nWhether the web event corresponding to an egui event …\nIf the web event corresponding to an egui event should be …\nA place where you can store custom data in a way that …\nYou can use the storage to restore app state(requires the …\nWhere the app and the egui memory are persisted.\nPrefix for everything this app stores in <code>localStorage</code>, …\nA place where you can store custom data in a way that …\nCalled each time the UI needs repainting, which may be …\nThe full URL (<code>location.href</code>) without the hash, …\nThe browser user agent.\n<code>egui</code> bindings for web apps (compiling to WASM).\nInformation about the surrounding web environment.\nWhich version of WebGL context to select\nDetects panics, logs them using <code>console.error</code>, and stores …\nContains a summary about a panics.\nImplements <code>log::Log</code> to log messages to <code>console.log</code>, …\nThis is how <code>eframe</code> runs your web application\nConvenience function to reduce boilerplate and ensure that …\nConvenience function to reduce boilerplate and ensure that …\nGet mutable access to the concrete <code>App</code> we enclose.\nThe backtrace.\nShut down eframe and clean up resources.\nAccess to files dropped on the canvas. Files dropped on …\nReturns the argument unchanged.\nReturns the argument unchanged.\nReturns the argument unchanged.\nReturns the argument unchanged.\nHas there been a panic?\nHas there been a panic?\nInstall a new <code>WebLogger</code>, piping all <code>log</code> events to the web …\nInstall a panic hook.\nCalls <code>U::from(self)</code>.\nCalls <code>U::from(self)</code>.\nCalls <code>U::from(self)</code>.\nCalls <code>U::from(self)</code>.\nAre we running inside the Safari browser?\ne.g. “#fragment” part of “…\nThe panic message.\nThe native GUI scale factor, taking into account the …\nCreate a new <code>WebLogger</code> with the given filter, but don’t …\nConstruct a summary from a panic.\nWill install a panic handler that will catch and log any …\nCurrent time in seconds (since undefined point in time).\nOpen the given url in the browser.\nWhat was the panic message and callstack?\nWhat was the panic message and callstack?\nPercent-decodes a string.\nAccess to the browser screen reader.\nCreate the application, install callbacks, and start …\nAccess to local browser storage.\nAsk the browser about the preferred system theme.\nThe User-Agent of the user’s browser.\nVibrate the device, if it supports it.\nGet the <code>epi::Location</code> from the browser.\nThe browser <code>File</code> that was dropped.\nStop keeping a dropped file around, after which it can no …\nRead the bytes in <code>range</code> of a dropped file.\nSpeak the given text out loud.\nRead data from local storage.\nWrite data to local storage.")
\ No newline at end of file
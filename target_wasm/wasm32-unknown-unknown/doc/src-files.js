@@ -0,0 +1,3 @@
+var srcIndex = new Map(JSON.parse('[["eframe",["",[["web",[],["app_runner.rs","backend.rs","dropped_files.rs","events.rs","indexed_db.rs","input.rs","mod.rs","panic_handler.rs","screen_reader.rs","storage.rs","text_agent.rs","web_logger.rs","web_painter.rs","web_painter_glow.rs","web_runner.rs"]]],["epi.rs","lib.rs","stopwatch.rs"]]]]'));
+createSrcSidebar();
+//{"start":36,"fragment_lengths":[299]}
\ No newline at end of file
@@ -0,0 +1,9 @@
+(function() {
+    var implementors = Object.fromEntries([["eframe",[["impl <a class=\"trait\" href=\"https://doc.rust-lang.org/1.86.0/core/clone/trait.Clone.html\" title=\"trait core::clone::Clone\">Clone</a> for <a class=\"enum\" href=\"eframe/enum.Renderer.html\" title=\"enum eframe::Renderer\">Renderer</a>"],["impl <a class=\"trait\" href=\"https://doc.rust-lang.org/1.86.0/core/clone/trait.Clone.html\" title=\"trait core::clone::Clone\">Clone</a> for <a class=\"enum\" href=\"eframe/enum.WebGlContextOption.html\" title=\"enum eframe::WebGlContextOption\">WebGlContextOption</a>"],["impl <a class=\"trait\" href=\"https://doc.rust-lang.org/1.86.0/core/clone/trait.Clone.html\" title=\"trait core::clone::Clone\">Clone</a> for <a class=\"enum\" href=\"eframe/enum.WebRepaintMode.html\" title=\"enum eframe::WebRepaintMode\">WebRepaintMode</a>"],["impl <a class=\"trait\" href=\"https://doc.rust-lang.org/1.86.0/core/clone/trait.Clone.html\" title=\"trait core::clone::Clone\">Clone</a> for <a class=\"enum\" href=\"eframe/enum.WebStorageBackend.html\" title=\"enum eframe::WebStorageBackend\">WebStorageBackend</a>"],["impl <a class=\"trait\" href=\"https://doc.rust-lang.org/1.86.0/core/clone/trait.Clone.html\" title=\"trait core::clone::Clone\">Clone</a> for <a class=\"struct\" href=\"eframe/struct.IntegrationInfo.html\" title=\"struct eframe::IntegrationInfo\">IntegrationInfo</a>"],["impl <a class=\"trait\" href=\"https://doc.rust-lang.org/1.86.0/core/clone/trait.Clone.html\" title=\"trait core::clone::Clone\">Clone</a> for <a class=\"struct\" href=\"eframe/struct.Location.html\" title=\"struct eframe::Location\">Location</a>"],["impl <a class=\"trait\" href=\"https://doc.rust-lang.org/1.86.0/core/clone/trait.Clone.html\" title=\"trait core::clone::Clone\">Clone</a> for <a class=\"struct\" href=\"eframe/struct.WebInfo.html\" title=\"struct eframe::WebInfo\">WebInfo</a>"],["impl <a class=\"trait\" href=\"https://doc.rust-lang.org/1.86.0/core/clone/trait.Clone.html\" title=\"trait core::clone::Clone\">Clone</a> for <a class=\"struct\" href=\"eframe/web/struct.PanicHandler.html\" title=\"struct eframe::web::PanicHandler\">PanicHandler</a>"],["impl <a class=\"trait\" href=\"https://doc.rust-lang.org/1.86.0/core/clone/trait.Clone.html\" title=\"trait core::clone::Clone\">Clone</a> for <a class=\"struct\" href=\"eframe/web/struct.PanicSummary.html\" title=\"struct eframe::web::PanicSummary\">PanicSummary</a>"],["impl <a class=\"trait\" href=\"https://doc.rust-lang.org/1.86.0/core/clone/trait.Clone.html\" title=\"trait core::clone::Clone\">Clone</a> for <a class=\"struct\" href=\"eframe/web/struct.WebRunner.html\" title=\"struct eframe::web::WebRunner\">WebRunner</a>"]]]]);
+    if (window.register_implementors) {
+        window.register_implementors(implementors);
+    } else {
+        window.pending_implementors = implementors;
+    }
+})()
+//{"start":57,"fragment_lengths":[2645]}
\ No newline at end of file
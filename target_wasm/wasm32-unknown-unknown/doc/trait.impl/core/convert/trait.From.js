@@ -0,0 +1,9 @@
+(function() {
+    var implementors = Object.fromEntries([["eframe",[["impl <a class=\"trait\" href=\"https://doc.rust-lang.org/1.86.0/core/convert/trait.From.html\" title=\"trait core::convert::From\">From</a>&lt;PainterError&gt; for <a class=\"enum\" href=\"eframe/enum.Error.html\" title=\"enum eframe::Error\">Error</a>"]]]]);
+    if (window.register_implementors) {
+        window.register_implementors(implementors);
+    } else {
+        window.pending_implementors = implementors;
+    }
+})()
+//{"start":57,"fragment_lengths":[269]}
\ No newline at end of file
@@ -0,0 +1,9 @@
+(function() {
+    var implementors = Object.fromEntries([["eframe",[["impl <a class=\"trait\" href=\"https://doc.rust-lang.org/1.86.0/core/default/trait.Default.html\" title=\"trait core::default::Default\">Default</a> for <a class=\"enum\" href=\"eframe/enum.Renderer.html\" title=\"enum eframe::Renderer\">Renderer</a>"],["impl <a class=\"trait\" href=\"https://doc.rust-lang.org/1.86.0/core/default/trait.Default.html\" title=\"trait core::default::Default\">Default</a> for <a class=\"enum\" href=\"eframe/enum.WebRepaintMode.html\" title=\"enum eframe::WebRepaintMode\">WebRepaintMode</a>"],["impl <a class=\"trait\" href=\"https://doc.rust-lang.org/1.86.0/core/default/trait.Default.html\" title=\"trait core::default::Default\">Default</a> for <a class=\"enum\" href=\"eframe/enum.WebStorageBackend.html\" title=\"enum eframe::WebStorageBackend\">WebStorageBackend</a>"],["impl <a class=\"trait\" href=\"https://doc.rust-lang.org/1.86.0/core/default/trait.Default.html\" title=\"trait core::default::Default\">Default</a> for <a class=\"struct\" href=\"eframe/struct.WebOptions.html\" title=\"struct eframe::WebOptions\">WebOptions</a>"]]]]);
+    if (window.register_implementors) {
+        window.register_implementors(implementors);
+    } else {
+        window.pending_implementors = implementors;
+    }
+})()
+//{"start":57,"fragment_lengths":[1089]}
\ No newline at end of file
@@ -0,0 +1,9 @@
+(function() {
+    var implementors = Object.fromEntries([["eframe",[["impl !<a class=\"trait\" href=\"https://doc.rust-lang.org/1.86.0/core/marker/trait.Send.html\" title=\"trait core::marker::Send\">Send</a> for <a class=\"struct\" href=\"eframe/struct.Frame.html\" title=\"struct eframe::Frame\">Frame</a>",1,["eframe::epi::Frame"]],["impl !<a class=\"trait\" href=\"https://doc.rust-lang.org/1.86.0/core/marker/trait.Send.html\" title=\"trait core::marker::Send\">Send</a> for <a class=\"struct\" href=\"eframe/struct.WebOptions.html\" title=\"struct eframe::WebOptions\">WebOptions</a>",1,["eframe::epi::WebOptions"]],["impl !<a class=\"trait\" href=\"https://doc.rust-lang.org/1.86.0/core/marker/trait.Send.html\" title=\"trait core::marker::Send\">Send</a> for <a class=\"struct\" href=\"eframe/web/struct.WebRunner.html\" title=\"struct eframe::web::WebRunner\">WebRunner</a>",1,["eframe::web::web_runner::WebRunner"]],["impl <a class=\"trait\" href=\"https://doc.rust-lang.org/1.86.0/core/marker/trait.Send.html\" title=\"trait core::marker::Send\">Send</a> for <a class=\"enum\" href=\"eframe/enum.Error.html\" title=\"enum eframe::Error\">Error</a>",1,["eframe::Error"]],["impl <a class=\"trait\" href=\"https://doc.rust-lang.org/1.86.0/core/marker/trait.Send.html\" title=\"trait core::marker::Send\">Send</a> for <a class=\"enum\" href=\"eframe/enum.Renderer.html\" title=\"enum eframe::Renderer\">Renderer</a>",1,["eframe::epi::Renderer"]],["impl <a class=\"trait\" href=\"https://doc.rust-lang.org/1.86.0/core/marker/trait.Send.html\" title=\"trait core::marker::Send\">Send</a> for <a class=\"enum\" href=\"eframe/enum.WebGlContextOption.html\" title=\"enum eframe::WebGlContextOption\">WebGlContextOption</a>",1,["eframe::epi::WebGlContextOption"]],["impl <a class=\"trait\" href=\"https://doc.rust-lang.org/1.86.0/core/marker/trait.Send.html\" title=\"trait core::marker::Send\">Send</a> for <a class=\"enum\" href=\"eframe/enum.WebRepaintMode.html\" title=\"enum eframe::WebRepaintMode\">WebRepaintMode</a>",1,["eframe::epi::WebRepaintMode"]],["impl <a class=\"trait\" href=\"https://doc.rust-lang.org/1.86.0/core/marker/trait.Send.html\" title=\"trait core::marker::Send\">Send</a> for <a class=\"enum\" href=\"eframe/enum.WebStorageBackend.html\" title=\"enum eframe::WebStorageBackend\">WebStorageBackend</a>",1,["eframe::epi::WebStorageBackend"]],["impl <a class=\"trait\" href=\"https://doc.rust-lang.org/1.86.0/core/marker/trait.Send.html\" title=\"trait core::marker::Send\">Send</a> for <a class=\"struct\" href=\"eframe/struct.IntegrationInfo.html\" title=\"struct eframe::IntegrationInfo\">IntegrationInfo</a>",1,["eframe::epi::IntegrationInfo"]],["impl <a class=\"trait\" href=\"https://doc.rust-lang.org/1.86.0/core/marker/trait.Send.html\" title=\"trait core::marker::Send\">Send</a> for <a class=\"struct\" href=\"eframe/struct.Location.html\" title=\"struct eframe::Location\">Location</a>",1,["eframe::epi::Location"]],["impl <a class=\"trait\" href=\"https://doc.rust-lang.org/1.86.0/core/marker/trait.Send.html\" title=\"trait core::marker::Send\">Send</a> for <a class=\"struct\" href=\"eframe/struct.WebInfo.html\" title=\"struct eframe::WebInfo\">WebInfo</a>",1,["eframe::epi::WebInfo"]],["impl <a class=\"trait\" href=\"https://doc.rust-lang.org/1.86.0/core/marker/trait.Send.html\" title=\"trait core::marker::Send\">Send</a> for <a class=\"struct\" href=\"eframe/web/struct.PanicHandler.html\" title=\"struct eframe::web::PanicHandler\">PanicHandler</a>",1,["eframe::web::panic_handler::PanicHandler"]],["impl <a class=\"trait\" href=\"https://doc.rust-lang.org/1.86.0/core/marker/trait.Send.html\" title=\"trait core::marker::Send\">Send</a> for <a class=\"struct\" href=\"eframe/web/struct.PanicSummary.html\" title=\"struct eframe::web::PanicSummary\">PanicSummary</a>",1,["eframe::web::panic_handler::PanicSummary"]],["impl <a class=\"trait\" href=\"https://doc.rust-lang.org/1.86.0/core/marker/trait.Send.html\" title=\"trait core::marker::Send\">Send</a> for <a class=\"struct\" href=\"eframe/web/struct.WebLogger.html\" title=\"struct eframe::web::WebLogger\">WebLogger</a>",1,["eframe::web::web_logger::WebLogger"]],["impl&lt;'s&gt; !<a class=\"trait\" href=\"https://doc.rust-lang.org/1.86.0/core/marker/trait.Send.html\" title=\"trait core::marker::Send\">Send</a> for <a class=\"struct\" href=\"eframe/struct.CreationContext.html\" title=\"struct eframe::CreationContext\">CreationContext</a>&lt;'s&gt;",1,["eframe::epi::CreationContext"]]]]]);
+    if (window.register_implementors) {
+        window.register_implementors(implementors);
+    } else {
+        window.pending_implementors = implementors;
+    }
+})()
+//{"start":57,"fragment_lengths":[4437]}
\ No newline at end of file